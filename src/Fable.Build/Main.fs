@@ -56,6 +56,8 @@ Available commands:
             --ast-only              Run only the tests for the AST (can be run in watch mode)
             --no_std                Compile and run the tests without the standard library
             --threaded              Compile and run the tests with the threaded runtime
+            --arena                 Compile and run the tests with the arena allocator
+            --wasi                  Compile the tests to wasm32-wasip1 and run them with wasmtime
 
     standalone                      Compile standalone + worker version of Fable running
                                     on top of of Node.js
@@ -34,11 +34,19 @@ let handle (args: string list) =
     let astOnly = args |> List.contains "--ast-only"
     let noStd = args |> List.contains "--no_std"
     let threaded = args |> List.contains "--threaded"
+    let wasi = args |> List.contains "--wasi"
+    let arena = args |> List.contains "--arena"
     let noDotnet = args |> List.contains "--no-dotnet"
 
     if noStd && threaded then
         failwith "Cannot use --no-std and --threaded at the same time"
 
+    if wasi && (noStd || threaded) then
+        failwith "Cannot use --wasi with --no-std or --threaded"
+
+    if arena && (noStd || wasi) then
+        failwith "Cannot use --arena with --no-std or --wasi"
+
     BuildFableLibraryRust().Run(skipFableLibrary)
 
     if astOnly then
@@ -68,10 +76,24 @@ let handle (args: string list) =
         let cargoTestArgs =
             if noStd then
                 "cargo test --features no_std"
+            elif threaded && arena then
+                "cargo test --features threaded,arena"
             elif threaded then
                 "cargo test --features threaded"
+            elif arena then
+                "cargo test --features arena"
+            elif wasi then
+                "cargo test --target wasm32-wasip1"
             else
-                "cargo test"
+                // the default run also covers the serde support
+                "cargo test --features serde"
+
+        // WASI tests are run with wasmtime, which must be on the PATH
+        if wasi then
+            System.Environment.SetEnvironmentVariable(
+                "CARGO_TARGET_WASM32_WASIP1_RUNNER",
+                "wasmtime"
+            )
 
         let fableArgs =
             CmdLine.concat
@@ -83,7 +105,7 @@ let handle (args: string list) =
                     |> CmdLine.appendPrefix "--exclude" "Fable.Core"
                     |> CmdLine.appendRaw "--noCache"
                     |> CmdLine.appendPrefixIf
-                        noStd
+                        (noStd || wasi) // panics abort on wasm
                         "--define"
                         "NO_STD_NO_EXCEPTIONS"
 
@@ -9,3 +9,30 @@ let inline (!^) (x: ^t1) : ^t2 =
 /// Destructure a tuple of arguments and apply them to literal code as with EmitAttribute.
 /// E.g. `emitRustExpr (arg1, arg2) "$0 + $1"` becomes `arg1 + arg2`
 let emitRustExpr<'T> (args: obj) (code: string) : 'T = nativeOnly
+
+/// Runs a function in an arena allocation scope, where small allocations are freed in bulk.
+/// Requires the `arena` feature and the arena global allocator (see the fable-library-rust README).
+let withArena (f: unit -> 'T) : 'T = nativeOnly
+
+/// A Rust future (`Pin<Box<dyn Future<Output = 'T> + Send>>`), e.g. returned by an imported `async fn`.
+/// A future can only be awaited once, so pass it directly instead of reusing it.
+type Future<'T> =
+    interface
+    end
+
+type Async with
+    /// Awaits a Rust future inside an async computation.
+    static member AwaitFuture(future: Future<'T>) : Async<'T> = nativeOnly
+
+    /// Converts an async computation into a Rust future, so it can be spawned or awaited
+    /// by a Rust runtime (e.g. mounted as a tokio/axum handler).
+    static member ToFuture(computation: Async<'T>) : Future<'T> = nativeOnly
+
+[<RequireQualifiedAccess>]
+[<CompilationRepresentation(CompilationRepresentationFlags.ModuleSuffix)>]
+module Future =
+    /// Starts a Rust future as a task.
+    let toTask (future: Future<'T>) : Threading.Tasks.Task<'T> = nativeOnly
+
+    /// Converts a task into a Rust future.
+    let ofTask (task: Threading.Tasks.Task<'T>) : Future<'T> = nativeOnly
@@ -561,10 +561,12 @@ module TypeInfo =
         | Replacements.Util.IsEntity (Types.keyCollection) _
         | Replacements.Util.IsEntity (Types.valueCollection) _
         | Replacements.Util.IsEntity (Types.icollectionGeneric) _
+        | Replacements.Util.IsEntity (Types.stringRuneEnumerator) _
         // already wrapped
         | Replacements.Util.IsEntity (Types.regexMatch) _
         | Replacements.Util.IsEntity (Types.regexGroup) _
         | Replacements.Util.IsEntity (Types.regexCapture) _
+        | Replacements.Util.IsEntity (Types.rustFuture) _
         // | Replacements.Util.IsEntity (Types.regexMatchCollection) _
         // | Replacements.Util.IsEntity (Types.regexGroupCollection) _
         // | Replacements.Util.IsEntity (Types.regexCaptureCollection) _
@@ -588,6 +590,7 @@ module TypeInfo =
         | Fable.MetaType
         | Fable.Boolean
         | Fable.Char
+        | Replacements.Util.IsEntity (Types.rune) _
         | Fable.Number _ -> None
 
         // should be Rc-wrapped
@@ -598,7 +601,8 @@ module TypeInfo =
         // should be Arc-wrapped
         | Replacements.Util.IsEntity (Types.fsharpAsyncGeneric) _
         | Replacements.Util.IsEntity (Types.task) _
-        | Replacements.Util.IsEntity (Types.taskGeneric) _ -> Some Arc
+        | Replacements.Util.IsEntity (Types.taskGeneric) _
+        | Replacements.Util.IsEntity (Types.valueTaskGeneric) _ -> Some Arc
 
         // conditionally Rc-wrapped
         | Fable.Tuple(_, isStruct) ->
@@ -813,7 +817,7 @@ module TypeInfo =
         transformImportType com ctx [] "TimeOnly" "TimeOnly"
 
     let transformTimerType com ctx : Rust.Ty =
-        transformImportType com ctx [] "DateTime" "Timer"
+        transformImportType com ctx [] "Timer" "Timer"
 
     let transformAsyncType com ctx genArg : Rust.Ty =
         transformImportType com ctx [ genArg ] "Async" "Async"
@@ -827,8 +831,26 @@ module TypeInfo =
     let transformThreadType com ctx : Rust.Ty =
         transformImportType com ctx [] "Thread" "Thread"
 
+    // like .NET, tuples with more than 7 items keep the rest in a nested 8th item,
+    // as Rust only implements the standard traits for tuples up to 12 items
+    let rec nestTupleItems (mkTuple: 'T list -> 'T) (items: 'T list) =
+        if List.length items > 7 then
+            let items, rest = List.splitAt 7 items
+            items @ [ nestTupleItems mkTuple rest |> mkTuple ]
+        else
+            items
+
+    let rec getTupleItem (expr: Rust.Expr) (index: int) =
+        if index >= 7 then
+            getTupleItem (mkFieldExpr expr "7") (index - 7)
+        else
+            mkFieldExpr expr (index.ToString())
+
     let transformTupleType com ctx isStruct genArgs : Rust.Ty =
-        genArgs |> List.map (transformType com ctx) |> mkTupleTy
+        genArgs
+        |> List.map (transformType com ctx)
+        |> nestTupleItems mkTupleTy
+        |> mkTupleTy
 
     let transformOptionType com ctx genArg : Rust.Ty =
         transformGenericType com ctx [ genArg ] (rawIdent "Option")
@@ -921,6 +943,7 @@ module TypeInfo =
                 Types.icomparableGeneric
                 Types.iStructuralEquatable
                 Types.iStructuralComparable
+                Types.iPooledObjectPolicy
             ]
 
     let isDeclaredInterface fullName =
@@ -968,7 +991,10 @@ module TypeInfo =
         (entRef: Fable.EntityRef)
         =
         match entRef.FullName with
-        | "System.Text.Encoding" ->
+        | "System.Text.Encoding"
+        | "System.Text.UTF8Encoding"
+        | "System.Text.UnicodeEncoding"
+        | "System.Text.ASCIIEncoding" ->
             getLibraryImportName com ctx "Encoding" "Encoding"
         | _ -> getEntityFullName com ctx entRef
 
@@ -1049,6 +1075,15 @@ module TypeInfo =
             transformInterfaceType com ctx entRef genArgs
         | ent when ent.IsAbstractClass ->
             transformAbstractClassType com ctx entRef genArgs
+        // concrete encodings are represented by the Encoding trait too
+        | ent when
+            (match ent.FullName with
+             | "System.Text.UTF8Encoding"
+             | "System.Text.UnicodeEncoding"
+             | "System.Text.ASCIIEncoding" -> true
+             | _ -> false)
+            ->
+            transformAbstractClassType com ctx entRef genArgs
         | ent ->
             let entName = getEntityFullName com ctx entRef
             let genArgsOpt = transformGenArgs com ctx genArgs
@@ -1231,10 +1266,16 @@ module TypeInfo =
                                                                      [ t ]) ->
                 transformArrayType com ctx t
 
+            // runes are chars, as a Rust char is a Unicode scalar value
+            | Replacements.Util.IsEntity (Types.rune) (_, []) -> primitiveType "char"
+            | Replacements.Util.IsEntity (Types.stringRuneEnumerator) (_, []) ->
+                transformArrayType com ctx Fable.Char
+
             // pre-defined declared types
             | Replacements.Util.IsEntity (Types.fsharpAsyncGeneric) (_, [ t ]) ->
                 transformAsyncType com ctx t
-            | Replacements.Util.IsEntity (Types.taskGeneric) (_, [ t ]) ->
+            | Replacements.Util.IsEntity (Types.taskGeneric) (_, [ t ])
+            | Replacements.Util.IsEntity (Types.valueTaskGeneric) (_, [ t ]) ->
                 transformTaskType com ctx t
             | Replacements.Util.IsEntity (Types.taskBuilder) (_, []) ->
                 transformTaskBuilderType com ctx
@@ -1242,6 +1283,10 @@ module TypeInfo =
                 transformTaskBuilderType com ctx
             | Replacements.Util.IsEntity (Types.thread) (_, []) ->
                 transformThreadType com ctx
+            | Replacements.Util.IsEntity (Types.periodicTimer) (_, []) ->
+                transformImportType com ctx [] "PeriodicTimer" "PeriodicTimer"
+            | Replacements.Util.IsEntity (Types.rustFuture) (_, [ t ]) ->
+                transformImportType com ctx [ t ] "Async" "BoxFuture"
 
             // implemented regex types
             | Replacements.Util.IsEntity (Types.regexMatch) (_, []) ->
@@ -1257,6 +1302,94 @@ module TypeInfo =
             | Replacements.Util.IsEntity (Types.regexCaptureCollection) (_, []) ->
                 transformImportType com ctx [] "RegExp" "CaptureCollection"
 
+            // implemented globalization types
+            | Replacements.Util.IsEntity (Types.cultureInfo) (_, []) ->
+                transformImportType com ctx [] "Globalization" "CultureInfo"
+            | Replacements.Util.IsEntity (Types.numberFormatInfo) (_, []) ->
+                transformImportType com ctx [] "Globalization" "NumberFormatInfo"
+            | Replacements.Util.IsEntity (Types.dateTimeFormatInfo) (_, []) ->
+                transformImportType com ctx [] "Globalization" "DateTimeFormatInfo"
+            | Replacements.Util.IsEntity (Types.compareInfo) (_, []) ->
+                transformImportType com ctx [] "Globalization" "CompareInfo"
+            | Replacements.Util.IsEntity (Types.sortKey) (_, []) ->
+                transformImportType com ctx [] "Globalization" "SortKey"
+            | Replacements.Util.IsEntity (Types.regionInfo) (_, []) ->
+                transformImportType com ctx [] "Globalization" "RegionInfo"
+            | Replacements.Util.IsEntity (Types.stringInfo) (_, []) ->
+                transformImportType com ctx [] "Globalization" "StringInfo"
+            | Replacements.Util.IsEntity (Types.textElementEnumerator) (_, []) ->
+                transformImportType com ctx [] "Globalization" "TextElementEnumerator"
+            | Replacements.Util.IsEntity (Types.calendar) (_, [])
+            | Replacements.Util.IsEntity (Types.gregorianCalendar) (_, []) ->
+                transformImportType com ctx [] "Calendar" "Calendar"
+
+            // implemented random type
+            | Replacements.Util.IsEntity (Types.random) (_, []) ->
+                transformImportType com ctx [] "Random" "Random"
+
+            // implemented timer types, ElapsedEventArgs only keep their SignalTime
+            | Replacements.Util.IsEntity ("System.Threading.Timer") (_, []) ->
+                transformTimerType com ctx
+            | Replacements.Util.IsEntity ("System.Timers.ElapsedEventArgs") (_,
+                                                                           []) ->
+                transformDateTimeType com ctx
+
+            // implemented stopwatch type
+            | Replacements.Util.IsEntity (Types.stopwatch) (_, []) ->
+                transformImportType com ctx [] "Stopwatch" "Stopwatch"
+
+            // implemented time zone type
+            | Replacements.Util.IsEntity (Types.timeZoneInfo) (_, []) ->
+                transformImportType com ctx [] "TimeZoneInfo" "TimeZoneInfo"
+
+            // spans are represented as arrays
+            | Replacements.Util.IsEntity ("System.Span`1") (_, [ genArg ])
+            | Replacements.Util.IsEntity ("System.ReadOnlySpan`1") (_, [ genArg ]) ->
+                transformArrayType com ctx genArg
+
+            // implemented formattable strings and composite formats
+            | Replacements.Util.IsEntity (Types.formattableString) (_, []) ->
+                transformImportType com ctx [] "String" "FormattableString"
+            | Replacements.Util.IsEntity (Types.compositeFormat) (_, []) ->
+                transformImportType com ctx [] "String" "CompositeFormat"
+
+            // all streams share the same implementation
+            | Replacements.Util.IsEntity (Types.stream) (_, [])
+            | Replacements.Util.IsEntity (Types.memoryStream) (_, []) ->
+                transformImportType com ctx [] "IO" "Stream"
+            | Replacements.Util.IsEntity (Types.fileInfo) (_, []) ->
+                transformImportType com ctx [] "FileSystem" "FileInfo"
+
+            // implemented System.Buffers.Text types
+            | Replacements.Util.IsEntity (Types.standardFormat) (_, []) ->
+                transformImportType com ctx [] "Utf8Text" "StandardFormat"
+
+            // implemented object pool types
+            | Replacements.Util.IsEntity (Types.objectPool) (_, [ genArg ])
+            | Replacements.Util.IsEntity (Types.defaultObjectPool) (_, [ genArg ]) ->
+                transformImportType com ctx [ genArg ] "ObjectPool" "ObjectPool"
+
+            // implemented rate limiting types
+            | Fable.DeclaredType(entRef, [])
+                when entRef.FullName.StartsWith(Types.rateLimiting + ".")
+                ->
+                match entRef.FullName.Substring(Types.rateLimiting.Length + 1) with
+                | "RateLimitLease" ->
+                    transformImportType com ctx [] "RateLimiting" "RateLimitLease"
+                | "RateLimiterStatistics" ->
+                    transformImportType com ctx [] "RateLimiting" "RateLimiterStatistics"
+                | name when name.EndsWith("Options") ->
+                    transformImportType com ctx [] "RateLimiting" "RateLimiterOptions"
+                | _ -> transformImportType com ctx [] "RateLimiting" "RateLimiter"
+
+            // implemented metrics types, all instruments share the same implementation
+            | Fable.DeclaredType(entRef, _)
+                when entRef.FullName.StartsWith(Types.metrics + ".")
+                ->
+                match entRef.FullName.Substring(Types.metrics.Length + 1) with
+                | "Meter" -> transformImportType com ctx [] "Metrics" "Meter"
+                | _ -> transformImportType com ctx [] "Metrics" "Instrument"
+
             | Replacements.Util.IsEnumerator(entRef, genArgs) ->
                 // get IEnumerator interface from enumerator object
                 match tryFindInterface com Types.ienumeratorGeneric entRef with
@@ -1642,6 +1775,7 @@ module Util =
         | Replacements.Util.IsEntity (Types.keyCollection) _, IEnumerable _
         | Replacements.Util.IsEntity (Types.valueCollection) _, IEnumerable _
         | Replacements.Util.IsEntity (Types.icollectionGeneric) _, IEnumerable _
+        | Replacements.Util.IsEntity (Types.stringRuneEnumerator) _, IEnumerable _
         | Fable.Array _, IEnumerable _ ->
             makeLibCall com ctx None "Seq" "ofArray" [ expr ]
         | Fable.List _, IEnumerable _ ->
@@ -2088,16 +2222,11 @@ module Util =
             mkFloat64LitExpr (string 0.)
 
     let makeStaticString com ctx (value: Rust.Expr) =
-        makeLibCall com ctx None "String" "string" [ value ]
+        makeLibCall com ctx None "String" "fromStatic" [ value ]
 
     let makeStringFrom com ctx (value: Rust.Expr) =
         makeLibCall com ctx None "String" "fromString" [ value ]
 
-    let makeNull com ctx (typ: Fable.Type) =
-        //TODO: some other representation perhaps?
-        let genArgsOpt = transformGenArgs com ctx [ typ ]
-        makeLibCall com ctx genArgsOpt "Native" "defaultOf" []
-
     let makeOption (com: IRustCompiler) ctx r typ value isStruct =
         let expr =
             match value with
@@ -2112,6 +2241,41 @@ module Util =
         // else expr |> makeLrcPtrValue com ctx
         expr // all options are value options
 
+    let rec hasGenericParams (typ: Fable.Type) =
+        match typ with
+        | Fable.GenericParam _ -> true
+        | _ -> typ.Generics |> List.exists hasGenericParams
+
+    let makeNull com ctx r (typ: Fable.Type) =
+        //TODO: some other representation perhaps?
+        let genArgsOpt = transformGenArgs com ctx [ typ ]
+
+        let noDefault typeName =
+            $"Null or default value of %s{typeName} is not supported, the type has no default value"
+            |> addError com [] r
+
+            makeLibCall com ctx genArgsOpt "Native" "defaultOf" []
+
+        match typ with
+        | Fable.Option(genArg, isStruct) ->
+            makeOption com ctx None genArg None isStruct
+        | Fable.String -> makeLibCall com ctx genArgsOpt "Native" "getZero" []
+        // user types deriving Default get a default instance instead of a zeroed one
+        | Fable.DeclaredType(entRef, genArgs) when
+            (match entRef.Path with
+             | Fable.SourcePath _ -> true
+             | _ -> false)
+            && not (genArgs |> List.exists hasGenericParams)
+            ->
+            if isDefaultableType com Set.empty typ then
+                makeLibCall com ctx genArgsOpt "Native" "getZero" []
+            else
+                noDefault $"type %s{entRef.FullName}"
+        | Fable.LambdaType _
+        | Fable.DelegateType _ -> noDefault "function type"
+        // generic and library types have no known default, so they are zeroed
+        | _ -> makeLibCall com ctx genArgsOpt "Native" "defaultOf" []
+
     let makeArray (com: IRustCompiler) ctx r typ (exprs: Fable.Expr list) =
         match exprs with
         | [] ->
@@ -2207,6 +2371,7 @@ module Util =
         let expr =
             exprs
             |> List.map (transformLeaveContext com ctx None)
+            |> nestTupleItems mkTupleExpr
             |> mkTupleExpr
 
         if isStruct then
@@ -2314,7 +2479,7 @@ module Util =
             unimplemented ()
         | Fable.ThisValue typ -> makeThis com ctx r typ
         | Fable.TypeInfo(typ, _tags) -> makeTypeInfo com ctx r typ
-        | Fable.Null typ -> makeNull com ctx typ
+        | Fable.Null typ -> makeNull com ctx r typ
         | Fable.UnitConstant -> mkUnitExpr ()
         | Fable.BoolConstant b -> mkBoolLitExpr b //, ?loc=r)
         | Fable.CharConstant c -> mkCharLitExpr c //, ?loc=r)
@@ -2712,6 +2877,18 @@ module Util =
     let isModuleMember (com: IRustCompiler) (callInfo: Fable.CallInfo) =
         isDeclEntityKindOf com (fun ent -> ent.IsFSharpModule) callInfo
 
+    let hasClosureArg (selector: string) =
+        match selector with
+        | "NativeArray_::map"
+        | "NativeArray_::filter"
+        | "NativeArray_::choose"
+        | "NativeArray_::iterate"
+        | "NativeArray_::exists"
+        | "NativeArray_::forAll"
+        | "NativeArray_::tryFind"
+        | "NativeArray_::tryFindIndex" -> true
+        | _ -> false
+
     let transformCall
         (com: IRustCompiler)
         ctx
@@ -2762,7 +2939,35 @@ module Util =
                 callInfo.SignatureArgTypes
 
         let args =
-            transformCallArgs com ctx args callInfo.SignatureArgTypes argParams
+            match calleeExpr, args with
+            // monomorphized library functions take the closure directly
+            | Fable.Import(info, _, _), fnArg :: restArgs when
+                hasClosureArg info.Selector
+                ->
+                let restArgTypes =
+                    match callInfo.SignatureArgTypes with
+                    | _ :: restTypes -> restTypes
+                    | [] -> []
+
+                let fnExpr =
+                    match fnArg with
+                    | Fable.Lambda(arg, body, name) ->
+                        transformClosure com ctx name [ arg ] body |> fst
+                    | _ ->
+                        // function values are passed as `&dyn Fn`
+                        transformCallArgs com ctx [ fnArg ] [] []
+                        |> List.head
+                        |> mkDerefExpr
+                        |> mkAddrOfExpr
+
+                fnExpr :: transformCallArgs com ctx restArgs restArgTypes []
+            | _ ->
+                transformCallArgs
+                    com
+                    ctx
+                    args
+                    callInfo.SignatureArgTypes
+                    argParams
 
         match calleeExpr with
         // mutable module values (transformed as function calls)
@@ -2799,12 +3004,14 @@ module Util =
                         "Set_::empty"
                         "Map_::empty"
                         "Seq_::empty"
+                        "Convert_::parse2"
                     ]
 
             let genArgsOpt =
                 if needGenArgs |> Set.contains info.Selector then
                     match typ with
-                    | Fable.Tuple _ -> transformGenArgs com ctx [ typ ]
+                    | Fable.Tuple _
+                    | Fable.Number _ -> transformGenArgs com ctx [ typ ]
                     | _ -> transformGenArgs com ctx typ.Generics // callInfo.GenericArgs
                 else
                     None
@@ -2919,7 +3126,7 @@ module Util =
 
         | Fable.TupleIndex index ->
             let expr = transformCallee com ctx fableExpr
-            mkFieldExpr expr (index.ToString()) |> makeClone
+            getTupleItem expr index |> makeClone
 
         | Fable.OptionValue ->
             match fableExpr with
@@ -3352,17 +3559,34 @@ module Util =
         | Some expr ->
             let err = transformExpr com ctx expr
 
-            let msg =
-                match expr.Type with
-                | Fable.String -> err
-                | _ -> mkMethodCallExpr "get_Message" None err []
+            match expr.Type with
+            // the type name is kept for assertions on the exception type,
+            // except for System.Exception, which is also the type of reraised exceptions
+            | Fable.DeclaredType(entRef, _) when entRef.FullName <> Types.exception_ ->
+                let msg = mkMethodCallExpr "get_Message" None err []
 
-            mkMacroExpr
-                "panic"
-                [
-                    mkStrLitExpr "{}"
-                    msg
-                ]
+                makeLibCall
+                    com
+                    ctx
+                    None
+                    "Exception"
+                    "raise"
+                    [
+                        mkStrLitExpr entRef.FullName
+                        msg
+                    ]
+            | _ ->
+                let msg =
+                    match expr.Type with
+                    | Fable.String -> err
+                    | _ -> mkMethodCallExpr "get_Message" None err []
+
+                mkMacroExpr
+                    "panic"
+                    [
+                        mkStrLitExpr "{}"
+                        msg
+                    ]
 
     let transformCurry
         (com: IRustCompiler)
@@ -4372,7 +4596,7 @@ module Util =
         let fnBody = transformFunctionBody com ctx args body
         fnDecl, fnBody, genArgs
 
-    let transformLambda
+    let transformClosure
         com
         ctx
         (name: string option)
@@ -4439,6 +4663,16 @@ module Util =
                 let closureExpr = mkClosureExpr true fnDecl fnBody
                 mkStmtBlockExpr (cloneStmts @ [ closureExpr |> mkExprStmt ])
 
+        closureExpr, argCount
+
+    let transformLambda
+        com
+        ctx
+        (name: string option)
+        (args: Fable.Ident list)
+        (body: Fable.Expr)
+        =
+        let closureExpr, argCount = transformClosure com ctx name args body
         let funcWrap = getLibraryImportName com ctx "Native" ("Func" + argCount)
 
         makeCall
@@ -4669,7 +4903,11 @@ module Util =
                 | _ -> []
             // translate test methods attributes
             // TODO: support more test frameworks
-            elif a.Entity.FullName.EndsWith(".FactAttribute") then
+            elif
+                a.Entity.FullName.EndsWith(".FactAttribute")
+                || a.Entity.FullName = "NUnit.Framework.TestAttribute"
+                || a.Entity.FullName = "Microsoft.VisualStudio.TestTools.UnitTesting.TestMethodAttribute"
+            then
                 [ mkAttr "test" [] ]
             else
                 []
@@ -5346,6 +5584,140 @@ module Util =
             implItemFor "Display"
         ]
 
+    // library collections implement the trait by hand
+    let structuredFormatLibraryTypes =
+        set
+            [
+                "List_.List"
+                "Map_.Map"
+                "Set_.Set"
+            ]
+
+    let makeStructuredFormatTraitImpls com ctx (ent: Fable.Entity) self_ty genArgs =
+        // expected output:
+        // impl StructuredFormat for {self_ty} {
+        //     fn layout(&self, prec: i32) -> Layout {
+        //         match self { Self::Case(x0, x1) => unionLayout(prec, "Case", &[x0, x1]), ... }
+        //     }
+        // }
+        let isStructured =
+            (ent.IsFSharpRecord || ent.IsFSharpUnion)
+            && not (
+                isFableLibrary com
+                && Set.contains ent.FullName structuredFormatLibraryTypes
+            )
+
+        if not isStructured then
+            []
+        else
+            let importName name =
+                getLibraryImportName com ctx "Printf" name
+
+            let debugFormat = importName "DebugFormat"
+            let typeNameFormat = importName "TypeNameFormat"
+
+            let makeStrLiteral (s: string) =
+                "\"" + s.Replace("\\", "\\\\").Replace("\"", "\\\"") + "\""
+
+            let fieldFormat (typ: Fable.Type) (fieldExpr: string) =
+                let typ = FableTransforms.uncurryType typ
+
+                if Replacements.isStructuredFormatType com true typ then
+                    fieldExpr
+                elif
+                    isPrintableType com Set.empty typ
+                    && not (hasGenericParams typ)
+                then
+                    $"&{debugFormat}({fieldExpr})"
+                else
+                    $"&{typeNameFormat}({fieldExpr})"
+
+            let prec, body =
+                if ent.IsFSharpRecord then
+                    let fields =
+                        ent.FSharpFields
+                        |> List.map (fun field ->
+                            let fieldExpr = "&self." + sanitizeMember field.Name
+                            let value = fieldFormat field.FieldType fieldExpr
+
+                            $"({makeStrLiteral field.Name}, {value})"
+                        )
+                        |> String.concat ", "
+
+                    let recordLayout = importName "recordLayout"
+                    "_prec", $"{recordLayout}(&[{fields}])"
+                else
+                    let unionLayout = importName "unionLayout"
+
+                    let cases =
+                        ent.UnionCases
+                        |> List.map (fun uci ->
+                            let name = makeStrLiteral uci.Name
+
+                            let bindings, values =
+                                uci.UnionCaseFields
+                                |> List.mapi (fun i field ->
+                                    let x = $"x{i}"
+                                    x, fieldFormat field.FieldType x
+                                )
+                                |> List.unzip
+
+                            let pattern =
+                                if List.isEmpty bindings then
+                                    "Self::" + uci.Name
+                                else
+                                    let bindings = String.concat ", " bindings
+                                    $"Self::{uci.Name}({bindings})"
+
+                            let values = String.concat ", " values
+                            $"{pattern} => {unionLayout}(prec, {name}, &[{values}]),"
+                        )
+                        |> String.concat " "
+
+                    "prec", $"match self {{ {cases} }}"
+
+            let layoutTy =
+                mkGenericPathTy (importName "Layout" |> splitNameParts) None
+
+            let fnBody = [ body |> mkEmitExprStmt ] |> mkBlock |> Some
+
+            let fnDecl =
+                let inputs =
+                    [
+                        mkImplSelfParam false false
+                        mkParamFromType prec (primitiveType "i32") false false
+                    ]
+
+                mkFnDecl inputs (layoutTy |> mkFnRetTy)
+
+            let fnKind = mkFnKind DEFAULT_FN_HEADER fnDecl NO_GENERICS fnBody
+            let fnItem = mkFnAssocItem [] "layout" fnKind
+            let traitNameParts = importName "StructuredFormat" |> splitNameParts
+
+            let generics =
+                genArgs
+                |> List.choose (
+                    function
+                    | Fable.GenericParam(name, isMeasure, constraints) when
+                        not isMeasure
+                        ->
+                        let typeBounds = makeTypeBounds com ctx name constraints
+
+                        let formatBound =
+                            mkTypeTraitGenericBound traitNameParts None
+
+                        mkGenericParamFromName
+                            []
+                            name
+                            (typeBounds @ [ formatBound ] @ defaultTypeBounds)
+                        |> Some
+                    | _ -> None
+                )
+                |> mkGenerics
+
+            let ofTrait = mkGenericPath traitNameParts None |> mkTraitRef |> Some
+            [ mkImplItem [] "" self_ty generics [ fnItem ] ofTrait ]
+
     let op_impl_map =
         Map
             [
@@ -5577,6 +5949,9 @@ module Util =
             let hasToString = Set.contains "ToString" nonInterfaceMemberNames
             makeDisplayTraitImpls com ctx self_ty genArgs hasToString
 
+        let structuredFormatTraitImpls =
+            makeStructuredFormatTraitImpls com ctx ent self_ty genArgs
+
         let operatorTraitImpls =
             nonInterfaceMembers
             |> List.choose (
@@ -5619,6 +5994,7 @@ module Util =
 
         nonInterfaceImpls
         @ displayTraitImpls
+        @ structuredFormatTraitImpls
         @ operatorTraitImpls
         @ interfaceTraitImpls
 
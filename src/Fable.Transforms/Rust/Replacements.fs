@@ -236,6 +236,8 @@ let toString com (ctx: Context) r (args: Expr list) =
             Helper.LibCall(com, "BigInt", "toString", String, args)
         | Number(Decimal, _) ->
             Helper.LibCall(com, "Decimal", "toString", String, args)
+        | Number(_, NumberInfo.IsEnum ent) when ent.FullName = "System.DayOfWeek" ->
+            Helper.LibCall(com, "DateTime", "dayOfWeekToString", String, [ head ])
         // | Array _ | List _ ->
         //     Helper.LibCall(com, "Types", "seqToString", String, [head], ?loc=r)
         // | DeclaredType(ent, _) when ent.IsFSharpUnion || ent.IsFSharpRecord || ent.IsValueType ->
@@ -498,8 +500,8 @@ let applyOp (com: ICompiler) (ctx: Context) r t opName (args: Expr list) =
     match argTypes with
     // | Number(BigInt as kind,_)::_ ->
     //     Helper.LibCall(com, "BigInt", opName, t, args, argTypes, ?loc=r)
-    | Builtin(BclDateTime | BclDateTimeOffset | BclTimeOnly | BclTimeSpan) :: _ ->
-        nativeOp opName argTypes args
+    | Builtin(BclDateTime | BclDateTimeOffset | BclTimeOnly | BclTimeSpan) :: _
+    | [ Number _; Builtin BclTimeSpan ] -> nativeOp opName argTypes args
     | Builtin(FSharpSet _) :: _ ->
         let methName =
             match opName with
@@ -875,6 +877,7 @@ let makeMap (com: ICompiler) ctx r t args genArg =
 
 let rec getZero (com: ICompiler) (ctx: Context) (t: Type) =
     match t with
+    | Unit -> Value(UnitConstant, None)
     | Boolean -> makeBoolConst false
     | Number(BigInt, _) -> Helper.LibCall(com, "BigInt", "zero", t, [])
     | Number(Decimal, _) -> Helper.LibValue(com, "Decimal", "Zero", t)
@@ -883,6 +886,10 @@ let rec getZero (com: ICompiler) (ctx: Context) (t: Type) =
     | Char -> CharConstant '\u0000' |> makeValue None
     | String -> makeStrConst "" // TODO: Use null for string?
     | Array(typ, _) -> makeArray typ []
+    | Option(genArg, isStruct) -> Value(NewOption(None, genArg, isStruct), None)
+    | List genArg -> Value(NewList(None, genArg), None)
+    | Tuple(genArgs, isStruct) ->
+        makeTuple None isStruct (genArgs |> List.map (getZero com ctx))
     | Builtin BclDateTime -> Helper.LibCall(com, "DateTime", "zero", t, [])
     | Builtin BclDateTimeOffset ->
         Helper.LibCall(com, "DateTimeOffset", "zero", t, [])
@@ -890,7 +897,10 @@ let rec getZero (com: ICompiler) (ctx: Context) (t: Type) =
     | Builtin BclTimeOnly -> Helper.LibCall(com, "TimeOnly", "zero", t, [])
     | Builtin BclTimeSpan -> Helper.LibValue(com, "TimeSpan", "zero", t)
     | Builtin(FSharpSet genArg) -> makeSet com ctx None t [] genArg
+    | Builtin(FSharpMap(k, _)) -> makeMap com ctx None t [] k
     | Builtin BclGuid -> Helper.LibValue(com, "Guid", "empty", t)
+    | Builtin(BclDictionary _) -> Helper.LibCall(com, "HashMap", "new_empty", t, [])
+    | Builtin(BclHashSet _) -> Helper.LibCall(com, "HashSet", "new_empty", t, [])
     | Builtin(BclKeyValuePair(k, v)) ->
         makeTuple
             None
@@ -1033,6 +1043,23 @@ let fableCoreLib
           [ args; RequireStringConstOrTemplate com ctx r template ] ->
             let args = destructureTupleArgs [ args ]
             emitTemplate r t args false template |> Some
+        | "withArena", [ f ] ->
+            Helper.LibCall(com, "Arena", "withArena", t, args, ?loc = r)
+            |> Some
+        | "Async.AwaitFuture.Static", [ future ] ->
+            Helper.LibCall(com, "Async", "awaitFuture", t, args, ?loc = r)
+            |> Some
+        | "Async.ToFuture.Static", [ computation ] ->
+            Helper.LibCall(com, "Async", "toFuture", t, args, ?loc = r)
+            |> Some
+        | _ -> None
+    | "Fable.Core.RustInterop.FutureModule", _ ->
+        match i.CompiledName, args with
+        | "toTask", [ future ] ->
+            Helper.LibCall(com, "Task", "fromFuture", t, args, ?loc = r)
+            |> Some
+        | "ofTask", [ task ] ->
+            Helper.LibCall(com, "Task", "toFuture", t, args, ?loc = r) |> Some
         | _ -> None
     | "Fable.Core.Rust", _ ->
         match i.CompiledName, args with
@@ -1143,11 +1170,72 @@ let fsharpModule
 //         | _, false -> entityName, Naming.InstanceMemberPart(memberName, overloadSuffix)
 //     Naming.buildNameWithoutSanitation name memberPart |> Naming.checkJsKeywords
 
-let makeRustFormatString interpolated (fmt: string) =
+/// Number types that can be formatted with a .NET standard numeric format
+let (|FormattableNumber|_|) (t: Type) =
+    match t with
+    | Number(_, NumberInfo.IsEnum _) -> None
+    | Number((Int8 | UInt8 | Int16 | UInt16 | Int32 | UInt32 | Int64 | UInt64 | Int128 | UInt128), _)
+    | Number((NativeInt | UNativeInt | Float32 | Float64 | Decimal), _) -> Some()
+    | _ -> None
+
+// Applies the format specifier of a String.Format hole, like IFormattable.ToString
+let formatArg com (ctx: Context) r (arg: Expr) (format: string) =
+    match arg.Type with
+    | _ when format = "" -> toString com ctx None [ arg ]
+    | Number(_, NumberInfo.IsEnum _) -> toString com ctx None [ arg ]
+    | FormattableNumber ->
+        Helper.LibCall(com, "Globalization", "formatNumber", String, [ arg; makeStrConst format ])
+    | DeclaredType(ent, _) when ent.FullName = Types.timespan || ent.FullName = Types.guid ->
+        Helper.LibCall(com, "String", "formatValue", String, [ arg; makeStrConst format ])
+    | DeclaredType(ent, _) when
+        ent.FullName = Types.datetime
+        || ent.FullName = Types.datetimeOffset
+        || ent.FullName = Types.dateOnly
+        || ent.FullName = Types.timeOnly
+        ->
+        Helper.InstanceCall(arg, "toString", String, [ makeStrConst format ])
+    | Number _ ->
+        $"String.Format(): Format specifier '{format}' is ignored"
+        |> addWarning com ctx.InlinePath r
+
+        toString com ctx None [ arg ]
+    // the format is ignored by .NET too for types that are not IFormattable
+    | _ -> toString com ctx None [ arg ]
+
+/// Types that implement the StructuredFormat trait used by %A
+let rec isStructuredFormatType (com: ICompiler) genericParamsOk (t: Type) =
+    match t with
+    | Unit
+    | Boolean
+    | Char
+    | String
+    | Measure _ -> true
+    | Number(Float16, _) -> false
+    | Number(_, NumberInfo.IsEnum _) -> false
+    | Number _ -> true
+    | Option(genArg, _)
+    | Array(genArg, _)
+    | List genArg -> isStructuredFormatType com genericParamsOk genArg
+    | Tuple(genArgs, _) -> genArgs |> List.forall (isStructuredFormatType com genericParamsOk)
+    | Builtin(FSharpSet genArg) -> isStructuredFormatType com genericParamsOk genArg
+    | Builtin(FSharpMap(k, v))
+    | Builtin(FSharpResult(k, v))
+    | Builtin(BclKeyValuePair(k, v)) -> [ k; v ] |> List.forall (isStructuredFormatType com genericParamsOk)
+    | Builtin(FSharpChoice genArgs) -> genArgs |> List.forall (isStructuredFormatType com genericParamsOk)
+    | Builtin _ -> false
+    | DeclaredType(entRef, genArgs) ->
+        let ent = com.GetEntity(entRef)
+
+        (ent.IsFSharpRecord || ent.IsFSharpUnion)
+        && genArgs |> List.forall (isStructuredFormatType com genericParamsOk)
+    | GenericParam(_, isMeasure, _) -> isMeasure || genericParamsOk
+    | _ -> false
+
+let makeRustFormatString com ctx interpolated (argTypes: Type list) (fmt: string) =
     let pattern1 = @"([^%]?)%([0+\- ]*)(\*|\d+)?(\.\d+)?(\w)"
 
     let pattern2 =
-        @"([^%]?)%([0+\- ]*)(\*|\d+)?(\.\d+)?(?:P\(\)|(\w)(?:%P\(\))?)"
+        @"([^%]?)%([0+\- ]*)(\*|\d+)?(\.\d+)?(?:P\((?<format>[^)]*)\)|(\w)(?:%P\(\))?)"
 
     let pattern =
         if interpolated then
@@ -1171,49 +1259,183 @@ let makeRustFormatString interpolated (fmt: string) =
         else
             sign
 
-    let mutable argCount = 0
+    // each hole takes its arguments (the star width comes first)
+    // and returns the arguments passed on to the format macro
+    let holes = ResizeArray<int * (Expr list -> Expr list) option>()
+    let mutable restArgTypes = argTypes
+
+    let nextArgType () =
+        match restArgTypes with
+        | t :: rest ->
+            restArgTypes <- rest
+            Some t
+        | [] -> None
 
     let rustFmt =
         Regex.Replace(
             input,
             pattern,
             fun m ->
-                argCount <- argCount + 1
                 let g1 = m.Groups[1].Value
-                let g2 = m.Groups[2].Value |> formatFlags
-                let g3 = m.Groups[3].Value.Replace("*", "$") // width parameter
-                let g4 = m.Groups[4].Value
-                let g5 = m.Groups[5].Value
-
-                let g4 =
-                    if g4 = "" && (g5 = "f" || g5 = "F") then
-                        ".6"
-                    else
-                        g4
-
-                let g5 =
-                    match g5 with
-                    | "A" -> "?"
-                    | "B" -> "b"
-                    | ("o" | "x" | "X" | "e" | "E") as t -> t
-                    | _ -> ""
-
-                let argFmt =
-                    if g2 + g3 + g4 + g5 = "" then
-                        g1 + "{}"
+                let flags = m.Groups[2].Value
+                let width = m.Groups[3].Value
+                let precision = m.Groups[4].Value
+                let typeChar = m.Groups[5].Value
+                // the .NET alignment and format of an interpolated string hole
+                let dotnetFormat = m.Groups["format"]
+
+                let arity =
+                    if width = "*" then
+                        nextArgType () |> ignore
+                        2
                     else
-                        g1 + "{:" + g2 + g3 + g4 + g5 + "}"
+                        1
+
+                let argType = nextArgType ()
+
+                let uncast =
+                    function
+                    | TypeCast(e, Any) -> e
+                    | e -> e
+
+                let runtimeFormat libFn formatArgs (args: Expr list) =
+                    let width, arg =
+                        match args with
+                        | [ width; arg ] -> width, arg
+                        | _ ->
+                            let width =
+                                if width = "" then
+                                    -1
+                                else
+                                    int width
+
+                            makeIntConst width, List.head args
+
+                    [
+                        Helper.LibCall(
+                            com,
+                            "String",
+                            libFn,
+                            String,
+                            formatArgs (uncast arg) @ [ makeStrConst flags; width ]
+                        )
+                    ]
+
+                let formatNumber netFormat =
+                    runtimeFormat "printfNumber" (fun arg -> [ arg; makeStrConst netFormat ])
+
+                // Rust formats these differently than .NET, or can't format them at all
+                let runtimeHole =
+                    match typeChar, argType with
+                    | _ when dotnetFormat.Success && (dotnetFormat.Value <> "" || width <> "") ->
+                        let formatHole (args: Expr list) =
+                            let arg = uncast (List.head args)
+                            let str = formatArg com ctx None arg dotnetFormat.Value
+
+                            if width = "" then
+                                [ str ]
+                            else
+                                let alignment =
+                                    if flags.Contains("-") then
+                                        -(int width)
+                                    else
+                                        int width
+
+                                let alignArgs = [ str; makeIntConst alignment ]
+                                [ Helper.LibCall(com, "String", "alignString", String, alignArgs) ]
+
+                        Some formatHole
+                    | ("e" | "E" | "f" | "F" | "g" | "G"), Some FormattableNumber ->
+                        let precision =
+                            if precision = "" then
+                                "6"
+                            else
+                                precision.TrimStart('.')
+
+                        Some(formatNumber (typeChar + precision))
+                    | ("d" | "i"), Some FormattableNumber when width = "*" || flags.Contains(" ") ->
+                        Some(formatNumber "D")
+                    | "A", Some t when isStructuredFormatType com false t ->
+                        let anyToString arg =
+                            Helper.LibCall(com, "Printf", "anyToString", String, [ arg ])
+
+                        if width = "" && flags = "" then
+                            Some(fun args -> [ anyToString (uncast (List.head args)) ])
+                        else
+                            Some(runtimeFormat "printfPad" (fun arg -> [ anyToString arg ]))
+                    | _ when width = "*" ->
+                        Some(runtimeFormat "printfPad" (fun arg -> [ toString com ctx None [ arg ] ]))
+                    | _ -> None
+
+                holes.Add((arity, runtimeHole))
+
+                match runtimeHole with
+                | Some _ -> g1 + "{}"
+                | None ->
+                    let g2 = flags |> formatFlags
+                    let g3 = width
+                    let g4 = precision
+                    let g5 = typeChar
+
+                    let g4 =
+                        if g4 = "" && (g5 = "f" || g5 = "F") then
+                            ".6"
+                        else
+                            g4
+
+                    let g5 =
+                        match g5 with
+                        | "A" -> "?"
+                        | "B" -> "b"
+                        | ("o" | "x" | "X" | "e" | "E") as t -> t
+                        | _ -> ""
+
+                    let argFmt =
+                        if g2 + g3 + g4 + g5 = "" then
+                            g1 + "{}"
+                        else
+                            g1 + "{:" + g2 + g3 + g4 + g5 + "}"
 
-                argFmt
+                    argFmt
         )
 
-    rustFmt, argCount
+    rustFmt, List.ofSeq holes
+
+let makeFormatArgs holes (args: Expr list) =
+    let rec loop holes args =
+        match holes, args with
+        | (arity, hole) :: holes, _ when List.length args >= arity ->
+            let holeArgs, args = List.splitAt arity args
+
+            match hole with
+            | Some hole -> hole holeArgs @ loop holes args
+            | None -> holeArgs @ loop holes args
+        | _ -> args
+
+    loop holes args
+
+let makeRustFormatExpr com ctx r t (fmt: string) args macroExpr =
+    let argTypes, _ = uncurryLambdaType -1 [] t
+    let rustFmt, holes = makeRustFormatString com ctx false argTypes fmt
+
+    if holes |> List.forall (snd >> Option.isNone) then
+        let argCount = List.length holes + 1 + (List.length args) // +1 is for fmt
+        let applied = Extended(Curry(macroExpr, argCount), r)
+        curriedApply r t applied (args @ [ emitRawString rustFmt ])
+    else
+        // some arguments are formatted at runtime before being passed to the macro
+        let argCount = holes |> List.sumBy fst
+        let argTypes, returnType = uncurryLambdaType argCount [] t
+
+        let argIdents =
+            argTypes |> List.mapi (fun i t -> makeUniqueIdent ctx t $"arg{i}")
+
+        let formatArgs =
+            argIdents |> List.map IdentExpr |> makeFormatArgs holes
 
-let makeRustFormatExpr r t (fmt: string) args macroExpr =
-    let rustFmt, argCount = makeRustFormatString false fmt
-    let argCount = argCount + 1 + (List.length args) // +1 is for fmt
-    let applied = Extended(Curry(macroExpr, argCount), r)
-    curriedApply r t applied (args @ [ emitRawString rustFmt ])
+        let macroArgs = args @ [ emitRawString rustFmt ] @ formatArgs
+        let body = Helper.Application(macroExpr, returnType, macroArgs, ?loc = r)
+        makeLambda argIdents body
 
 let fsFormat
     (com: ICompiler)
@@ -1231,7 +1453,7 @@ let fsFormat
       None,
       [ StringConst fmt ] ->
         let macro = Helper.LibValue(com, "String", "sprintf!", Any)
-        macro |> makeRustFormatExpr r t fmt [] |> Some
+        macro |> makeRustFormatExpr com ctx r t fmt [] |> Some
     | ("PrintFormatToString" | "PrintFormatToStringThen"),
       None,
       [ MaybeCasted(template) ] -> template |> Some
@@ -1239,14 +1461,14 @@ let fsFormat
       None,
       [ cont; StringConst fmt ] ->
         let macro = Helper.LibValue(com, "String", "kprintf!", Any)
-        macro |> makeRustFormatExpr r t fmt [ cont ] |> Some
+        macro |> makeRustFormatExpr com ctx r t fmt [ cont ] |> Some
     | ("PrintFormatThen" | "PrintFormatToStringThen"),
       None,
       [ cont; MaybeCasted(template) ] ->
         Helper.Application(cont, t, [ template ], ?loc = r) |> Some
     | "PrintFormatToError", None, [ StringConst fmt ] ->
         let macro = makeIdentExpr "eprint!"
-        macro |> makeRustFormatExpr r t fmt [] |> Some
+        macro |> makeRustFormatExpr com ctx r t fmt [] |> Some
     | "PrintFormatToError",
       None,
       [ MaybeCasted(Value(StringTemplate(None, [ rustFmt ], templateArgs), _)) ] ->
@@ -1254,7 +1476,7 @@ let fsFormat
         "eprint!" |> emitFormat com r t formatArgs |> Some
     | "PrintFormatLineToError", None, [ StringConst fmt ] ->
         let macro = makeIdentExpr "eprintln!"
-        macro |> makeRustFormatExpr r t fmt [] |> Some
+        macro |> makeRustFormatExpr com ctx r t fmt [] |> Some
     | "PrintFormatLineToError",
       None,
       [ MaybeCasted(Value(StringTemplate(None, [ rustFmt ], templateArgs), _)) ] ->
@@ -1262,7 +1484,7 @@ let fsFormat
         "eprintln!" |> emitFormat com r t formatArgs |> Some
     | "PrintFormat", None, [ StringConst fmt ] ->
         let macro = makeIdentExpr "print!"
-        macro |> makeRustFormatExpr r t fmt [] |> Some
+        macro |> makeRustFormatExpr com ctx r t fmt [] |> Some
     | "PrintFormat",
       None,
       [ MaybeCasted(Value(StringTemplate(None, [ rustFmt ], templateArgs), _)) ] ->
@@ -1270,7 +1492,7 @@ let fsFormat
         "print!" |> emitFormat com r t formatArgs |> Some
     | "PrintFormatLine", None, [ StringConst fmt ] ->
         let macro = makeIdentExpr "println!"
-        macro |> makeRustFormatExpr r t fmt [] |> Some
+        macro |> makeRustFormatExpr com ctx r t fmt [] |> Some
     | "PrintFormatLine",
       None,
       [ MaybeCasted(Value(StringTemplate(None, [ rustFmt ], templateArgs), _)) ] ->
@@ -1278,7 +1500,7 @@ let fsFormat
         "println!" |> emitFormat com r t formatArgs |> Some
     | "PrintFormatToStringThenFail", None, [ StringConst fmt ] ->
         let macro = makeIdentExpr "panic!"
-        macro |> makeRustFormatExpr r t fmt [] |> Some
+        macro |> makeRustFormatExpr com ctx r t fmt [] |> Some
     | "PrintFormatToStringThenFail",
       None,
       [ MaybeCasted(Value(StringTemplate(None, [ rustFmt ], templateArgs), _)) ] ->
@@ -1287,7 +1509,7 @@ let fsFormat
     | "PrintFormatToStringBuilder", None, [ sb; StringConst fmt ] ->
         let cont = Helper.LibCall(com, "Util", "bprintf", t, [ sb ])
         let macro = Helper.LibValue(com, "String", "kprintf!", Any)
-        macro |> makeRustFormatExpr r t fmt [ cont ] |> Some
+        macro |> makeRustFormatExpr com ctx r t fmt [ cont ] |> Some
     | "PrintFormatToStringBuilder", None, [ sb; MaybeCasted(template) ] ->
         let cont = Helper.LibCall(com, "Util", "bprintf", t, [ sb ])
         Helper.Application(cont, t, [ template ], ?loc = r) |> Some
@@ -1305,7 +1527,7 @@ let fsFormat
             )
 
         let macro = Helper.LibValue(com, "String", "kprintf!", Any)
-        macro |> makeRustFormatExpr r t fmt [ cont ] |> Some
+        macro |> makeRustFormatExpr com ctx r t fmt [ cont ] |> Some
     | "PrintFormatToStringBuilderThen",
       None,
       [ cont; sb; MaybeCasted(template) ] ->
@@ -1325,11 +1547,28 @@ let fsFormat
     | ".ctor",
       _,
       (StringConst fmt) :: (Value(NewArray(ArrayValues templateArgs, _, _), _)) :: _ ->
-        let rustFmt, _count = makeRustFormatString true fmt
+        let argTypes =
+            templateArgs
+            |> List.map (
+                function
+                | TypeCast(e, Any) -> e.Type
+                | e -> e.Type
+            )
+
+        let rustFmt, holes = makeRustFormatString com ctx true argTypes fmt
+        let templateArgs = templateArgs |> makeFormatArgs holes
         StringTemplate(None, [ rustFmt ], templateArgs) |> makeValue r |> Some
     | ".ctor", _, [ format ] -> format |> Some // just passing along the format
     | _ -> None
 
+/// Integer types whose Checked operators and conversions raise an OverflowException
+let (|CheckedInteger|_|) (t: Type) =
+    match t with
+    | Number(_, NumberInfo.IsEnum _) -> None
+    | Number((Int8 | UInt8 | Int16 | UInt16 | Int32 | UInt32 | Int64 | UInt64 | Int128 | UInt128 | NativeInt | UNativeInt) as kind,
+             _) -> Some kind
+    | _ -> None
+
 let operators
     (com: ICompiler)
     (ctx: Context)
@@ -1339,6 +1578,9 @@ let operators
     (thisArg: Expr option)
     (args: Expr list)
     =
+    let isChecked =
+        i.DeclaringEntityFullName = "Microsoft.FSharp.Core.Operators.Checked"
+
     let math r t (args: Expr list) argTypes methName =
         let meth = Naming.lowerFirst methName
 
@@ -1372,6 +1614,28 @@ let operators
         TypeCast(arg, t) |> Some
     // Cast to unit to make sure nothing is returned when wrapped in a lambda, see #1360
     | "Ignore", _ -> Value(UnitConstant, r) |> Some
+    // Checked arithmetic, floats and decimals don't wrap
+    | (Operators.addition | Operators.subtraction | Operators.multiply as opName),
+      [ ExprType(CheckedInteger _); ExprType(CheckedInteger _) ] when isChecked ->
+        let meth =
+            match opName with
+            | Operators.addition -> "checkedAdd"
+            | Operators.subtraction -> "checkedSubtract"
+            | _ -> "checkedMultiply"
+
+        Helper.LibCall(com, "Numeric", meth, t, args, ?loc = r) |> Some
+    | Operators.unaryNegation, [ ExprType(CheckedInteger _) ] when isChecked ->
+        Helper.LibCall(com, "Numeric", "checkedNegate", t, args, ?loc = r)
+        |> Some
+    | ("ToSByte" | "ToByte" | "ToInt8" | "ToUInt8" | "ToInt16" | "ToUInt16" | "ToInt" | "ToUInt" | "ToInt32" | "ToUInt32" | "ToInt64" | "ToUInt64" | "ToIntPtr" | "ToUIntPtr"),
+      [ ExprType(CheckedInteger _ | Number((Float32 | Float64), _)) ] when
+        isChecked
+        ->
+        match t with
+        | CheckedInteger kind ->
+            Helper.LibCall(com, "Numeric", "checkedTo" + kind.ToString(), t, args, ?loc = r)
+            |> Some
+        | _ -> convertTo com ctx r t args |> Some
     // Number and String conversions
     | ("ToSByte" | "ToByte" | "ToInt8" | "ToUInt8" | "ToInt16" | "ToUInt16" | "ToInt" | "ToUInt" | "ToInt32" | "ToUInt32" | "ToInt64" | "ToUInt64" | "ToIntPtr" | "ToUIntPtr" | "ToSingle" | "ToDouble" | "ToDecimal"),
       [ arg ] -> convertTo com ctx r t args |> Some
@@ -1585,7 +1849,7 @@ let operators
                 ?loc = r
             )
             |> Some
-        | [ ExprType(Number(Decimal, _)); ExprType(Number(Int32, _)) ] ->
+        | [ ExprType(Number(Decimal, _)); ExprType(Number(Int32, NumberInfo.Empty)) ] ->
             Helper.LibCall(
                 com,
                 "Decimal",
@@ -1814,30 +2078,33 @@ let chars
                     "ToLower", "toLowerChar"
                     "ToLowerInvariant", "toLowerChar" ] methName,
       None,
-      [ c ] -> Helper.LibCall(com, "String", methName, Char, args) |> Some
+      (c :: _) -> Helper.LibCall(com, "String", methName, Char, [ c ]) |> Some
     | "ToString", None, [ ExprType(Char) ] -> toString com ctx r args |> Some
     | "ToString", Some c, [] -> toString com ctx r [ c ] |> Some
-    | ReplaceName [ "IsControl", "is_control"
-                    "IsDigit", "is_ascii_digit"
-                    "IsLetter", "is_alphabetic"
-                    "IsLetterOrDigit", "is_alphanumeric" // imprecise, TODO:
-                    "IsUpper", "is_uppercase"
-                    "IsLower", "is_lowercase"
-                    "IsNumber", "is_numeric"
-                    "IsPunctuation", "is_ascii_punctuation" // imprecise, TODO:
-                    "IsSeparator", "is_ascii_whitespace" // imprecise, TODO:
-                    "IsSymbol", "is_ascii_punctuation" // imprecise, TODO:
-                    "IsWhiteSpace", "is_whitespace" ] methName,
+    | ("IsControl"
+      | "IsDigit"
+      | "IsLetter"
+      | "IsLetterOrDigit"
+      | "IsUpper"
+      | "IsLower"
+      | "IsNumber"
+      | "IsPunctuation"
+      | "IsSeparator"
+      | "IsSymbol"
+      | "IsWhiteSpace"
+      | "GetUnicodeCategory"),
       None,
       args ->
+        let methName = Naming.lowerFirst i.CompiledName
+
         match args with
-        | [ c ] -> makeInstanceCall r t i c methName [] |> Some
-        | [ str; idx ] ->
+        | [ ExprType(Char) as c ] ->
+            Helper.LibCall(com, "Char", methName, t, [ c ], ?loc = r) |> Some
+        | [ ExprType(String) as str; idx ] ->
             let c = Helper.LibCall(com, "String", "getCharAt", Char, args)
-            makeInstanceCall r t i c methName [] |> Some
+            Helper.LibCall(com, "Char", methName, t, [ c ], ?loc = r) |> Some
         | _ -> None
 
-    // | "GetUnicodeCategory" , None, args -> //TODO:
     // | "IsHighSurrogate" | "IsLowSurrogate" | "IsSurrogate" ->
     //     let methName = Naming.lowerFirst i.CompiledName
     //     let methName = if List.length args > 1 then methName + "2" else methName
@@ -1847,9 +2114,66 @@ let chars
     //     Helper.LibCall(com, "Char", methName, t, args, i.SignatureArgTypes, ?loc=r) |> Some
     | _ -> None
 
+// System.Text.Rune is a Unicode scalar value, so runes are chars
+let runes
+    (com: ICompiler)
+    (ctx: Context)
+    r
+    t
+    (i: CallInfo)
+    (thisArg: Expr option)
+    (args: Expr list)
+    =
+    match i.CompiledName, thisArg, args with
+    | ".ctor", None, [ ExprType(Char) as c ] -> Some c
+    | ".ctor", None, [ ExprType(Number _) as n ] ->
+        Helper.LibCall(com, "Char", "fromCodePoint", t, [ n ], ?loc = r) |> Some
+    | "get_ReplacementChar", None, [] ->
+        CharConstant '\uFFFD' |> makeValue r |> Some
+    | "IsValid", None, [ ExprType(Number _) as n ] ->
+        Helper.LibCall(com, "Char", "isValidRune", t, [ n ], ?loc = r) |> Some
+    | "TryCreate", None, [ ExprType(Number _); _ ] ->
+        Helper.LibCall(com, "Char", "tryFromCodePoint", t, args, ?loc = r)
+        |> Some
+    | "GetRuneAt", None, [ str; idx ] ->
+        Helper.LibCall(com, "String", "getCharAt", t, args, ?loc = r) |> Some
+    | "get_IsAscii", Some c, [] -> makeInstanceCall r t i c "is_ascii" [] |> Some
+    | ReplaceName [ "get_Value", "toCodePoint"
+                    "get_IsBmp", "isBmp"
+                    "get_Plane", "plane"
+                    "get_Utf8SequenceLength", "utf8SequenceLength"
+                    "get_Utf16SequenceLength", "utf16SequenceLength" ] methName,
+      Some c,
+      [] -> Helper.LibCall(com, "Char", methName, t, [ c ], ?loc = r) |> Some
+    | ReplaceName [ "ToUpper", "toUpperChar"
+                    "ToUpperInvariant", "toUpperChar"
+                    "ToLower", "toLowerChar"
+                    "ToLowerInvariant", "toLowerChar" ] methName,
+      None,
+      (c :: _) -> Helper.LibCall(com, "String", methName, t, [ c ]) |> Some
+    | "ToString", Some c, [] -> toString com ctx r [ c ] |> Some
+    | ("IsControl"
+      | "IsDigit"
+      | "IsLetter"
+      | "IsLetterOrDigit"
+      | "IsUpper"
+      | "IsLower"
+      | "IsNumber"
+      | "IsPunctuation"
+      | "IsSeparator"
+      | "IsSymbol"
+      | "IsWhiteSpace"
+      | "GetUnicodeCategory"),
+      None,
+      [ c ] ->
+        let methName = Naming.lowerFirst i.CompiledName
+        Helper.LibCall(com, "Char", methName, t, [ c ], ?loc = r) |> Some
+    | _ -> None
+
 let getEnumerator com r t i (expr: Expr) =
     match expr.Type with
     | IsEntity (Types.keyCollection) _
+    | IsEntity (Types.stringRuneEnumerator) _
     | IsEntity (Types.valueCollection) _
     | IsEntity (Types.icollectionGeneric) _
     // | IsEntity (Types.regexMatchCollection) _
@@ -1881,6 +2205,118 @@ let getEnumerator com r t i (expr: Expr) =
         // Helper.LibCall(com, "Util", "getEnumerator", t, [toSeq com Any expr], ?loc=r)
         makeInstanceCall r t i expr "GetEnumerator" []
 
+// The arguments are converted to strings here, where their types are known
+let formatComposite com ctx r t (compositeFormat: Expr) (args: Expr list) =
+    let args =
+        match args with
+        | [ Value(NewArray(ArrayValues values, _, _), _) ] ->
+            values
+            |> List.map (
+                function
+                | TypeCast(e, Any) -> e
+                | e -> e
+            )
+        | _ -> args
+
+    let strArgs =
+        args
+        |> List.map (fun arg -> toString com ctx None [ arg ])
+        |> makeArray String
+
+    Helper.InstanceCall(compositeFormat, "apply", t, [ strArgs ], ?loc = r)
+
+// Each distinct (index, format) pair of a constant format string gets its own
+// argument, formatted here where the types of the arguments are known.
+// Alignment and escaped braces are handled by the runtime composite formatting.
+let formatString com (ctx: Context) r t (format: Expr) (args: Expr list) =
+    let args =
+        match args with
+        | [ Value(NewArray(ArrayValues values, _, _), _) ] -> values
+        | _ -> args
+
+    let args =
+        args
+        |> List.map (
+            function
+            | TypeCast(e, Any) -> e
+            | e -> e
+        )
+
+    match format with
+    | StringConst fmt ->
+        let holes = ResizeArray<int * string>()
+
+        let fmt =
+            Regex.Replace(
+                fmt,
+                @"\{\{|\}\}|\{(\d+)(\s*,\s*-?\d+\s*)?(?::([^{}]*))?\}",
+                fun (m: Match) ->
+                    if m.Groups[1].Success then
+                        let hole = int m.Groups[1].Value, m.Groups[3].Value
+
+                        if not (holes.Contains(hole)) then
+                            holes.Add(hole)
+
+                        "{" + string (holes.IndexOf(hole)) + m.Groups[2].Value + "}"
+                    else
+                        m.Value
+            )
+
+        // arguments used by several holes are evaluated only once
+        let bindings, args =
+            args
+            |> List.mapi (fun i arg ->
+                let uses = holes |> Seq.filter (fun (index, _) -> index = i) |> Seq.length
+
+                if uses > 1 && canHaveSideEffects arg then
+                    let ident = makeUniqueIdent ctx arg.Type $"arg{i}"
+                    Some(ident, arg), IdentExpr ident
+                else
+                    None, arg
+            )
+            |> List.unzip
+
+        let strArgs =
+            holes
+            |> Seq.map (fun (index, format) ->
+                match List.tryItem index args with
+                | Some arg -> formatArg com ctx r arg format
+                | None ->
+                    "String.Format(): Index (zero based) must be greater than or equal to zero and less than the size of the argument list"
+                    |> addErrorAndReturnNull com ctx.InlinePath r
+            )
+            |> Seq.toList
+            |> makeArray String
+
+        let expr =
+            Helper.LibCall(com, "String", "format", t, [ makeStrConst fmt; strArgs ], ?loc = r)
+
+        List.foldBack (fun (ident, value) body -> Let(ident, value, body)) (List.choose id bindings) expr
+    | _ ->
+        // the format specifiers of non-constant format strings are applied at runtime
+        let strArgs =
+            args |> List.map (fun arg -> toString com ctx None [ arg ]) |> makeArray String
+
+        Helper.LibCall(com, "String", "format", t, [ format; strArgs ], ?loc = r)
+
+let compositeFormats
+    (com: ICompiler)
+    (ctx: Context)
+    r
+    t
+    (i: CallInfo)
+    (thisArg: Expr option)
+    (args: Expr list)
+    =
+    match i.CompiledName, thisArg with
+    | "Parse", None ->
+        makeStaticLibCall com r t i "String" "CompositeFormat::parse" args
+        |> Some
+    | "get_Format", Some x -> makeInstanceCall r t i x "format" [] |> Some
+    | "get_MinimumArgumentCount", Some x ->
+        makeInstanceCall r t i x "minimumArgumentCount" [] |> Some
+    | _ -> None
+
 let strings
     (com: ICompiler)
     (ctx: Context)
@@ -1901,6 +2337,25 @@ let strings
             (options &&& 1 <> 0) || (options &&& 268435456 <> 0)
         | _ -> false
 
+    let ignoreCaseOptions (ignoreCase: Expr) =
+        match ignoreCase with
+        | BoolConst ignoreCase -> makeIntConst (if ignoreCase then 1 else 0)
+        | _ -> IfThenElse(ignoreCase, makeIntConst 1, makeIntConst 0, None)
+
+    let (|CultureInfo|_|) (arg: Expr) =
+        match arg with
+        | MaybeCasted(ExprTypeAs(IsEntity Types.cultureInfo _, culture)) -> Some culture
+        | _ -> None
+
+    // StringComparison arguments, except the constant ordinal ones,
+    // are passed to the runtime
+    let (|Comparison|_|) (arg: Expr) =
+        match arg with
+        | NumberConst(:? int as kind, _, NumberInfo.IsEnum _) when kind = 4 || kind = 5 -> None
+        | ExprType(Number(Int32, NumberInfo.IsEnum ent)) when ent.FullName = "System.StringComparison" ->
+            Some arg
+        | _ -> None
+
     match i.CompiledName, thisArg, args with
     | ".ctor", _, _ ->
         match i.SignatureArgTypes with
@@ -1918,11 +2373,66 @@ let strings
     | "get_Chars", Some c, _ ->
         Helper.LibCall(com, "String", "getCharAt", t, c :: args, ?loc = r)
         |> Some
+    | "Compare", None, [ ExprType String; ExprType String; Comparison _ ] ->
+        Helper.LibCall(com, "String", "compareUsing", t, args, ?loc = r) |> Some
+    | "Compare",
+      None,
+      [ ExprType String
+        ExprType(Number(Int32, _))
+        ExprType String
+        ExprType(Number(Int32, _))
+        ExprType(Number(Int32, _))
+        Comparison _ ] -> Helper.LibCall(com, "String", "compareUsing2", t, args, ?loc = r) |> Some
+    // culture-sensitive overloads, the ignoreCase flag maps to
+    // StringComparison.CurrentCultureIgnoreCase and CompareOptions.IgnoreCase
+    | "Compare", None, [ ExprType String; ExprType String ] ->
+        Helper.LibCall(com, "String", "compareUsing", t, args @ [ makeIntConst 0 ], ?loc = r)
+        |> Some
+    | "Compare", None, [ ExprType String; ExprType String; ExprType Boolean as ignoreCase ] ->
+        let args = (args |> List.take 2) @ [ ignoreCaseOptions ignoreCase ]
+        Helper.LibCall(com, "String", "compareUsing", t, args, ?loc = r) |> Some
+    | "Compare", None, [ ExprType String; ExprType String; CultureInfo _; ExprType(Number(_, NumberInfo.IsEnum _)) ] ->
+        Helper.LibCall(com, "String", "compareWith", t, args, ?loc = r) |> Some
+    | "Compare", None, [ ExprType String; ExprType String; ExprType Boolean as ignoreCase; CultureInfo culture ] ->
+        let args = (args |> List.take 2) @ [ culture; ignoreCaseOptions ignoreCase ]
+        Helper.LibCall(com, "String", "compareWith", t, args, ?loc = r) |> Some
+    | "Compare",
+      None,
+      [ ExprType String; ExprType(Number(Int32, _)); ExprType String; ExprType(Number(Int32, _)); ExprType(Number(Int32, _)) ] ->
+        Helper.LibCall(com, "String", "compareUsing2", t, args @ [ makeIntConst 0 ], ?loc = r)
+        |> Some
+    | "Compare",
+      None,
+      [ ExprType String
+        ExprType(Number(Int32, _))
+        ExprType String
+        ExprType(Number(Int32, _))
+        ExprType(Number(Int32, _))
+        ExprType Boolean as ignoreCase ] ->
+        let args = (args |> List.take 5) @ [ ignoreCaseOptions ignoreCase ]
+        Helper.LibCall(com, "String", "compareUsing2", t, args, ?loc = r) |> Some
+    | "Compare",
+      None,
+      [ ExprType String
+        ExprType(Number(Int32, _))
+        ExprType String
+        ExprType(Number(Int32, _))
+        ExprType(Number(Int32, _))
+        CultureInfo _
+        ExprType(Number(_, NumberInfo.IsEnum _)) ] ->
+        Helper.LibCall(com, "String", "compareWith2", t, args, ?loc = r) |> Some
+    | "Compare",
+      None,
+      [ ExprType String
+        ExprType(Number(Int32, _))
+        ExprType String
+        ExprType(Number(Int32, _))
+        ExprType(Number(Int32, _))
+        ExprType Boolean as ignoreCase
+        CultureInfo culture ] ->
+        let args = (args |> List.take 5) @ [ culture; ignoreCaseOptions ignoreCase ]
+        Helper.LibCall(com, "String", "compareWith2", t, args, ?loc = r) |> Some
     | ("Compare" | "CompareOrdinal"), None, _ ->
-        if i.CompiledName = "Compare" then
-            $"String.Compare will be compiled as String.CompareOrdinal"
-            |> addWarning com ctx.InlinePath r
-
         match args with
         | ExprType String :: ExprType String :: restArgs ->
             let args =
@@ -1942,18 +2452,15 @@ let strings
             |> Some
         | _ -> None
     | "CompareTo", Some c, [ ExprTypeAs(String, arg) ] ->
-        $"String.CompareTo will be compiled as String.CompareOrdinal"
-        |> addWarning com ctx.InlinePath r
-
         Helper.LibCall(
             com,
             "String",
-            "compareOrdinal",
+            "compareUsing",
             t,
             [
                 c
                 arg
-                makeBoolConst false
+                makeIntConst 0
             ],
             ?loc = r
         )
@@ -1961,14 +2468,7 @@ let strings
     | "Concat", None, _ ->
         match args with
         | [ ExprTypeAs(IEnumerable, arg) ] ->
-            Helper.LibCall(
-                com,
-                "String",
-                "concat",
-                t,
-                [ toArray com t arg ],
-                ?loc = r
-            )
+            Helper.LibCall(com, "String", "concatSeq", t, [ arg ], ?loc = r)
             |> Some
         | [ ExprType String; ExprType String ]
         | [ ExprType String; ExprType String; ExprType String ]
@@ -1987,6 +2487,9 @@ let strings
         | _ -> None
     | "Contains", Some c, _ ->
         match args with
+        | [ ExprType String; ExprType(Number(Int32, NumberInfo.IsEnum _)) ] ->
+            Helper.LibCall(com, "String", "containsUsing", t, c :: args, ?loc = r)
+            |> Some
         | [ ExprType Char ] ->
             Helper.LibCall(
                 com,
@@ -2013,6 +2516,9 @@ let strings
                 ?loc = r
             )
             |> Some
+        | [ ExprType String; Comparison _ ] ->
+            Helper.LibCall(com, "String", "endsWithUsing", t, c :: args, ?loc = r)
+            |> Some
         | ExprType String :: restArgs ->
             let args =
                 (args |> List.take 1)
@@ -2038,6 +2544,21 @@ let strings
                 ?loc = r
             )
             |> Some
+        | Some x, [ ExprTypeAs(String, y); Comparison cmp ]
+        | None, [ ExprTypeAs(String, x); ExprTypeAs(String, y); Comparison cmp ] ->
+            Helper.LibCall(
+                com,
+                "String",
+                "equalsUsing",
+                t,
+                [
+                    x
+                    y
+                    cmp
+                ],
+                ?loc = r
+            )
+            |> Some
         | Some x,
           [ ExprTypeAs(String, y)
             NumberConst(:? int as kind, _, NumberInfo.IsEnum _) ]
@@ -2045,11 +2566,7 @@ let strings
           [ ExprTypeAs(String, x)
             ExprTypeAs(String, y)
             NumberConst(:? int as kind, _, NumberInfo.IsEnum _) ] ->
-            if kind <> 4 && kind <> 5 then
-                $"String.Equals will be compiled with ordinal equality"
-                |> addWarning com ctx.InlinePath r
-
-            let ignoreCase = kind = 1 || kind = 3 || kind = 5
+            let ignoreCase = kind = 5
 
             Helper.LibCall(
                 com,
@@ -2065,20 +2582,35 @@ let strings
             )
             |> Some
         | _ -> None
+    | "Create", None, [ _length; _state; _action ] ->
+        Helper.LibCall(com, "String", "create", t, args, ?loc = r) |> Some
+    | "Format",
+      None,
+      (_provider :: (ExprType(IsEntity Types.compositeFormat _) as cf) :: restArgs) ->
+        formatComposite com ctx r t cf restArgs |> Some
     | "Format", None, _ ->
         match args with
-        | (ExprType String :: _) -> "format!" |> emitFormat com r t args |> Some
-        | (cultureInfo :: restArgs) ->
+        | (ExprTypeAs(String, format) :: restArgs) -> formatString com ctx r t format restArgs |> Some
+        | (cultureInfo :: format :: restArgs) ->
             $"String.Format(): Format provider argument is ignored"
             |> addWarning com ctx.InlinePath r
 
-            "format!" |> emitFormat com r t restArgs |> Some
+            formatString com ctx r t format restArgs |> Some
         | _ -> None
     | "GetEnumerator", Some c, _ -> getEnumerator com r t i c |> Some
     | ("IndexOf" | "LastIndexOf" | "IndexOfAny" | "LastIndexOfAny"), Some c, _ ->
         let suffixOpt =
             match args with
             | [ ExprType String ] -> Some ""
+            | [ ExprType String; ExprType(Number(Int32, NumberInfo.IsEnum _)) ] ->
+                Some "Using"
+            | [ ExprType String
+                ExprType(Number(Int32, _))
+                ExprType(Number(Int32, NumberInfo.IsEnum _)) ] -> Some "Using2"
+            | [ ExprType String
+                ExprType(Number(Int32, _))
+                ExprType(Number(Int32, _))
+                ExprType(Number(Int32, NumberInfo.IsEnum _)) ] -> Some "Using3"
             | [ ExprType String; ExprType(Number(Int32, _)) ] -> Some "2"
             | [ ExprType String
                 ExprType(Number(Int32, _))
@@ -2105,18 +2637,38 @@ let strings
         | _ -> None
     | "Insert", Some c, _ ->
         Helper.LibCall(com, "String", "insert", t, c :: args, ?loc = r) |> Some
+    | "Intern", None, [ str ] ->
+        Helper.LibCall(com, "String", "intern", t, [ str ], ?loc = r) |> Some
+    | "IsInterned", None, [ str ] ->
+        Helper.LibCall(com, "String", "isInterned", t, [ str ], ?loc = r) |> Some
     | "IsNullOrEmpty", None, _ ->
         Helper.LibCall(com, "String", "isEmpty", t, args, ?loc = r) |> Some
     | "IsNullOrWhiteSpace", None, _ ->
         Helper.LibCall(com, "String", "isWhitespace", t, args, ?loc = r) |> Some
+    | "IsNormalized", Some c, [] ->
+        Helper.LibCall(com, "String", "isNormalized", t, [ c ], ?loc = r) |> Some
+    | "IsNormalized", Some c, [ form ] ->
+        Helper.LibCall(com, "String", "isNormalized2", t, [ c; form ], ?loc = r) |> Some
+    | "Normalize", Some c, [] ->
+        Helper.LibCall(com, "String", "normalize", t, [ c ], ?loc = r) |> Some
+    | "Normalize", Some c, [ form ] ->
+        Helper.LibCall(com, "String", "normalize2", t, [ c; form ], ?loc = r) |> Some
+    | "Join", None, [ ExprTypeAs(String, sep); ExprTypeAs(IEnumerable, arg) ] ->
+        Helper.LibCall(
+            com,
+            "String",
+            "joinSeq",
+            t,
+            [
+                sep
+                arg
+            ],
+            ?loc = r
+        )
+        |> Some
     | "Join", None, _ ->
         let args =
             match args with
-            | [ ExprTypeAs(String, sep); ExprTypeAs(IEnumerable, arg) ] ->
-                [
-                    sep
-                    toArray com t arg
-                ]
             | [ ExprTypeAs(String, sep); ExprTypeAs(Array(String, _), arg) ] ->
                 [
                     sep
@@ -2226,12 +2778,12 @@ let strings
             Helper.LibCall(
                 com,
                 "String",
-                "split",
+                "splitChars",
                 t,
                 [
                     c
-                    makeStrConst ""
-                    makeIntConst -1
+                    makeArray Char []
+                    makeIntConst System.Int32.MaxValue
                     makeIntConst 0
                 ],
                 ?loc = r
@@ -2247,7 +2799,7 @@ let strings
                 [
                     c
                     arg1
-                    makeIntConst -1
+                    makeIntConst System.Int32.MaxValue
                     makeIntConst 0
                 ],
                 ?loc = r
@@ -2263,7 +2815,7 @@ let strings
                 [
                     c
                     arg1
-                    makeIntConst -1
+                    makeIntConst System.Int32.MaxValue
                     arg2
                 ],
                 ?loc = r
@@ -2296,7 +2848,7 @@ let strings
                 [
                     c
                     arg1
-                    makeIntConst -1
+                    makeIntConst System.Int32.MaxValue
                     makeIntConst 0
                 ],
                 ?loc = r
@@ -2312,7 +2864,7 @@ let strings
                 [
                     c
                     arg1
-                    makeIntConst -1
+                    makeIntConst System.Int32.MaxValue
                     arg2
                 ],
                 ?loc = r
@@ -2345,7 +2897,7 @@ let strings
                 [
                     c
                     makeArray Char [ arg1 ]
-                    makeIntConst -1
+                    makeIntConst System.Int32.MaxValue
                     makeIntConst 0
                 ],
                 ?loc = r
@@ -2361,7 +2913,7 @@ let strings
                 [
                     c
                     makeArray Char [ arg1 ]
-                    makeIntConst -1
+                    makeIntConst System.Int32.MaxValue
                     arg2
                 ],
                 ?loc = r
@@ -2394,7 +2946,7 @@ let strings
                 [
                     c
                     arg1
-                    makeIntConst -1
+                    makeIntConst System.Int32.MaxValue
                     makeIntConst 0
                 ],
                 ?loc = r
@@ -2410,7 +2962,7 @@ let strings
                 [
                     c
                     arg1
-                    makeIntConst -1
+                    makeIntConst System.Int32.MaxValue
                     arg2
                 ],
                 ?loc = r
@@ -2449,7 +3001,40 @@ let strings
             )
             |> Some
 
-        // TODO: handle arrays of string separators with more than one element
+        | [ ExprTypeAs(Array(String, _), arg1)
+            ExprTypeAs(Number(_, NumberInfo.IsEnum _), arg2) ] ->
+            Helper.LibCall(
+                com,
+                "String",
+                "splitStrings",
+                t,
+                [
+                    c
+                    arg1
+                    makeIntConst System.Int32.MaxValue
+                    arg2
+                ],
+                ?loc = r
+            )
+            |> Some
+        | [ ExprTypeAs(Array(String, _), arg1)
+            ExprTypeAs(Number(Int32, _), arg2)
+            ExprTypeAs(Number(_, NumberInfo.IsEnum _), arg3) ] ->
+            Helper.LibCall(
+                com,
+                "String",
+                "splitStrings",
+                t,
+                [
+                    c
+                    arg1
+                    arg2
+                    arg3
+                ],
+                ?loc = r
+            )
+            |> Some
+
         | _ -> None
     | "StartsWith", Some c, _ ->
         match args with
@@ -2463,6 +3048,9 @@ let strings
                 ?loc = r
             )
             |> Some
+        | [ ExprType String; Comparison _ ] ->
+            Helper.LibCall(com, "String", "startsWithUsing", t, c :: args, ?loc = r)
+            |> Some
         | ExprType String :: restArgs ->
             let args =
                 (args |> List.take 1)
@@ -2480,6 +3068,8 @@ let strings
             Helper.LibCall(com, "String", "substring2", t, c :: args, ?loc = r)
             |> Some
         | _ -> None
+    | "EnumerateRunes", Some c, [] ->
+        Helper.LibCall(com, "String", "toCharArray", t, [ c ], ?loc = r) |> Some
     | "ToCharArray", Some c, _ ->
         match args with
         | [] ->
@@ -2496,10 +3086,11 @@ let strings
             )
             |> Some
         | _ -> None
-    | ("ToLower" | "ToLowerInvariant"), Some c, args ->
-        Helper.LibCall(com, "String", "toLower", t, c :: args, ?loc = r) |> Some
-    | ("ToUpper" | "ToUpperInvariant"), Some c, args ->
-        Helper.LibCall(com, "String", "toUpper", t, c :: args, ?loc = r) |> Some
+    // the case mappings of the supported cultures are all the invariant ones
+    | ("ToLower" | "ToLowerInvariant"), Some c, _ ->
+        Helper.LibCall(com, "String", "toLower", t, [ c ], ?loc = r) |> Some
+    | ("ToUpper" | "ToUpperInvariant"), Some c, _ ->
+        Helper.LibCall(com, "String", "toUpper", t, [ c ], ?loc = r) |> Some
     | ("Trim" | "TrimStart" | "TrimEnd"), Some c, _ ->
         let methName = Naming.lowerFirst i.CompiledName
 
@@ -2540,6 +3131,19 @@ let stringModule
     (args: Expr list)
     =
     match i.CompiledName, args with
+    | "Concat", [ sep; ExprTypeAs(IEnumerable, arg) ] ->
+        Helper.LibCall(
+            com,
+            "String",
+            "joinSeq",
+            t,
+            [
+                sep
+                arg
+            ],
+            ?loc = r
+        )
+        |> Some
     | "Concat", [ sep; arg ] ->
         Helper.LibCall(
             com,
@@ -2575,10 +3179,27 @@ let stringBuilder
     (args: Expr list)
     =
     match i.CompiledName, thisArg, args with
+    | "AppendFormat",
+      Some sb,
+      (_provider :: (ExprType(IsEntity Types.compositeFormat _) as cf) :: restArgs) ->
+        let s = formatComposite com ctx None String cf restArgs
+
+        Helper.LibCall(
+            com,
+            "Util",
+            "sb_Append",
+            t,
+            [
+                sb
+                s
+            ],
+            ?loc = r
+        )
+        |> Some
     | "AppendFormat", Some sb, _ ->
         match args with
-        | (ExprType String :: _) ->
-            let s = "format!" |> emitFormat com None String args
+        | (ExprTypeAs(String, format) :: restArgs) ->
+            let s = formatString com ctx None String format restArgs
 
             Helper.LibCall(
                 com,
@@ -2592,11 +3213,11 @@ let stringBuilder
                 ?loc = r
             )
             |> Some
-        | (cultureInfo :: restArgs) ->
+        | (cultureInfo :: format :: restArgs) ->
             $"StringBuilder.AppendFormat(): Format provider argument is ignored"
             |> addWarning com ctx.InlinePath r
 
-            let s = "format!" |> emitFormat com None String restArgs
+            let s = formatString com ctx None String format restArgs
 
             Helper.LibCall(
                 com,
@@ -2615,7 +3236,7 @@ let stringBuilder
 
 let formattableString
     (com: ICompiler)
-    (_ctx: Context)
+    (ctx: Context)
     r
     (t: Type)
     (i: CallInfo)
@@ -2623,55 +3244,98 @@ let formattableString
     (args: Expr list)
     =
     match i.CompiledName, thisArg, args with
-    // Even if we're going to wrap it again to make it compatible with FormattableString API, we use a JS template string
-    // because the strings array will always have the same reference so it can be used as a key in a WeakMap
-    // Attention, if we change the shape of the object ({ strs, args }) we need to change the resolution
-    // of the FormattableString.GetStrings extension in Fable.Core too
+    // The string is rendered here, while the types of the boxed holes are still known
     | "Create",
       None,
-      [ StringConst str; Value(NewArray(ArrayValues args, _, _), _) ] ->
-        let matches =
-            Regex.Matches(str, @"\{\d+(.*?)\}")
-            |> Seq.cast<Match>
-            |> Seq.toArray
-
-        let hasFormat =
-            matches |> Array.exists (fun m -> m.Groups[1].Value.Length > 0)
-
-        let tag =
-            if not hasFormat then
-                Helper.LibValue(com, "String", "fmt", Any) |> Some
-            else
-                let fmtArg =
-                    matches
-                    |> Array.map (fun m -> makeStrConst m.Groups[1].Value)
-                    |> Array.toList
-                    |> makeArray String
-
-                Helper.LibCall(com, "String", "fmtWith", Any, [ fmtArg ])
-                |> Some
+      [ StringConst str; Value(NewArray(ArrayValues values, _, _), _) as holes ] ->
+        let value = formatString com ctx None String (makeStrConst str) values
 
-        let holes =
-            matches
-            |> Array.map (fun m ->
-                {|
-                    Index = m.Index
-                    Length = m.Length
-                |}
-            )
-
-        let template = makeStringTemplate tag str holes args |> makeValue r
-        // Use a type cast to keep the FormattableString type
-        TypeCast(template, t) |> Some
-    | "get_Format", Some x, _ ->
-        Helper.LibCall(com, "String", "getFormat", t, [ x ], ?loc = r) |> Some
+        Helper.LibCall(
+            com,
+            "String",
+            "FormattableString::new",
+            t,
+            [
+                makeStrConst str
+                holes
+                value
+            ],
+            ?loc = r
+        )
+        |> Some
+    | "Invariant", None, [ fs ]
+    | "CurrentCulture", None, [ fs ]
+    | "ToString", Some fs, _ -> makeInstanceCall r t i fs "toString" [] |> Some
+    | "get_Format", Some x, _ -> makeInstanceCall r t i x "format" [] |> Some
     | "get_ArgumentCount", Some x, _ ->
-        getFieldWith r t (getField x "args") "length" |> Some
+        makeInstanceCall r t i x "argumentCount" [] |> Some
     | "GetArgument", Some x, [ idx ] ->
-        getExpr r t (getField x "args") idx |> Some
-    | "GetArguments", Some x, [] -> getFieldWith r t x "args" |> Some
+        makeInstanceCall r t i x "getArgument" [ idx ] |> Some
+    | "GetArguments", Some x, [] ->
+        makeInstanceCall r t i x "getArguments" [] |> Some
+    | _ -> None
+
+// Chains of Seq.map, Seq.filter and Seq.choose are fused into a single
+// Seq.choose, so the pipeline runs through one enumerator instead of one per stage.
+let isPureFnExpr =
+    function
+    | Lambda _
+    | IdentExpr _ -> true
+    | _ -> false
+
+let (|SeqStage|_|) (expr: Expr) =
+    match expr with
+    | Call(Import({ Selector = ("Seq_::map" | "Seq_::filter" | "Seq_::choose") as selector },
+                  _,
+                  _),
+           { ThisArg = None; Args = [ fn; source ] },
+           _,
+           _) when isPureFnExpr fn ->
+        Some(selector.Substring("Seq_::".Length), fn, source)
     | _ -> None
 
+let seqChooser ctx (meth: string) (fn: Expr) =
+    match meth, fn.Type with
+    | "map", LambdaType(argType, retType) ->
+        let x = makeUniqueIdent ctx argType "x"
+        let value = CurriedApply(fn, [ IdentExpr x ], retType, None)
+        Lambda(x, Value(NewOption(Some value, retType, false), None), None)
+    | "filter", LambdaType(argType, retType) ->
+        let x = makeUniqueIdent ctx argType "x"
+        let cond = CurriedApply(fn, [ IdentExpr x ], retType, None)
+        let some = Value(NewOption(Some(IdentExpr x), argType, false), None)
+        let none = Value(NewOption(None, argType, false), None)
+        Lambda(x, IfThenElse(cond, some, none, None), None)
+    | _ -> fn
+
+let composeChoosers ctx (chooser1: Expr) (chooser2: Expr) =
+    match chooser1.Type, chooser2.Type with
+    | LambdaType(argType, midType), LambdaType(midArgType, retType) ->
+        let x = makeUniqueIdent ctx argType "x"
+        let y = makeUniqueIdent ctx midType "y"
+
+        let noneType =
+            match retType with
+            | Option(t, _) -> t
+            | t -> t
+
+        let body =
+            IfThenElse(
+                Test(IdentExpr y, OptionTest true, None),
+                CurriedApply(
+                    chooser2,
+                    [ Get(IdentExpr y, OptionValue, midArgType, None) ],
+                    retType,
+                    None
+                ),
+                Value(NewOption(None, noneType, false), None),
+                None
+            )
+
+        let value = CurriedApply(chooser1, [ IdentExpr x ], midType, None)
+        Lambda(x, Let(y, value, body), None)
+    | _ -> chooser2
+
 let seqModule
     (com: ICompiler)
     (ctx: Context)
@@ -2710,11 +3374,35 @@ let seqModule
             ?loc = r
         )
         |> Some
-    | ("Distinct" | "DistinctBy" | "Except" | "GroupBy" | "CountBy" as meth),
-      args ->
-        let meth = Naming.lowerFirst meth
+    | ("Map" | "Filter" | "Choose" as meth),
+      [ fn; SeqStage(innerMeth, innerFn, source) ] when isPureFnExpr fn ->
+        let chooser =
+            composeChoosers
+                ctx
+                (seqChooser ctx innerMeth innerFn)
+                (seqChooser ctx (Naming.lowerFirst meth) fn)
 
-        Helper.LibCall(com, "Seq", meth, t, args, i.SignatureArgTypes, ?loc = r)
+        Helper.LibCall(
+            com,
+            "Seq",
+            "choose",
+            t,
+            [
+                chooser
+                source
+            ],
+            [
+                chooser.Type
+                source.Type
+            ],
+            ?loc = r
+        )
+        |> Some
+    | ("Distinct" | "DistinctBy" | "Except" | "GroupBy" | "CountBy" as meth),
+      args ->
+        let meth = Naming.lowerFirst meth
+
+        Helper.LibCall(com, "Seq", meth, t, args, i.SignatureArgTypes, ?loc = r)
         |> Some
     | meth, _ ->
         let meth = Naming.lowerFirst meth
@@ -2832,6 +3520,14 @@ let resizeArrays
         |> Some
     | "get_Count", Some(MaybeCasted(ar)), _ ->
         Helper.LibCall(com, "NativeArray", "count", t, [ ar ], ?loc = r) |> Some
+    | "get_Capacity", Some(MaybeCasted(ar)), _ ->
+        Helper.LibCall(com, "NativeArray", "capacity", t, [ ar ], ?loc = r) |> Some
+    | "set_Capacity", Some(MaybeCasted(ar)), [ value ] ->
+        Helper.LibCall(com, "NativeArray", "setCapacity", t, [ ar; value ], ?loc = r) |> Some
+    | "EnsureCapacity", Some(MaybeCasted(ar)), [ capacity ] ->
+        Helper.LibCall(com, "NativeArray", "ensureCapacity", t, [ ar; capacity ], ?loc = r) |> Some
+    | "TrimExcess", Some(MaybeCasted(ar)), [] ->
+        Helper.LibCall(com, "NativeArray", "trimExcess", t, [ ar ], ?loc = r) |> Some
     | "Clear", Some(MaybeCasted(ar)), [] ->
         makeInstanceCall r t i (getMut ar) "clear" [] |> Some
     | "ConvertAll", Some ar, [ arg ] ->
@@ -3094,6 +3790,7 @@ let readOnlySpans
     (args: Expr list)
     =
     match i.CompiledName, args with
+    | ".ctor", [ arg ]
     | "op_Implicit", [ arg ] -> arg |> Some
     | _ -> None
 
@@ -3115,6 +3812,20 @@ let tuples
         | _ -> None
 
     match i.CompiledName, thisArg with
+    | ".ctor", _ when List.length args = 8 ->
+        // the 8th argument is the nested rest tuple, which F# flattens
+        let isStruct = i.DeclaringEntityFullName.StartsWith("System.ValueTuple")
+
+        match List.last args with
+        | Value(NewTuple(rest, _), _) ->
+            Value(NewTuple(List.take 7 args @ rest, isStruct), r) |> Some
+        | ExprType(Tuple(restArgs, _)) as rest ->
+            let rest =
+                restArgs
+                |> List.mapi (fun idx typ -> Get(rest, TupleIndex idx, typ, None))
+
+            Value(NewTuple(List.take 7 args @ rest, isStruct), r) |> Some
+        | _ -> Value(NewTuple(args, isStruct), r) |> Some
     | (".ctor" | "Create"), _ ->
         let isStruct = i.DeclaringEntityFullName.StartsWith("System.ValueTuple")
         Value(NewTuple(args, isStruct), r) |> Some
@@ -3125,12 +3836,65 @@ let tuples
     | "get_Item5", Some x -> Get(x, TupleIndex 4, t, r) |> Some
     | "get_Item6", Some x -> Get(x, TupleIndex 5, t, r) |> Some
     | "get_Item7", Some x -> Get(x, TupleIndex 6, t, r) |> Some
-    | "get_Rest", Some x -> Get(x, TupleIndex 7, t, r) |> Some
+    | "get_Rest", Some(ExprType(Tuple(genArgs, isStruct)) as x) ->
+        let rest =
+            genArgs
+            |> List.indexed
+            |> List.skip 7
+            |> List.map (fun (idx, typ) -> Get(x, TupleIndex idx, typ, None))
+
+        Value(NewTuple(rest, isStruct), r) |> Some
     // System.TupleExtensions
     | "ToValueTuple", _ -> changeKind true args
     | "ToTuple", _ -> changeKind false args
     | _ -> None
 
+let iTuples
+    (com: ICompiler)
+    (ctx: Context)
+    r
+    (t: Type)
+    (i: CallInfo)
+    (thisArg: Expr option)
+    (args: Expr list)
+    =
+    // tuples are only known statically, so look through the interface casts
+    let rec getTuple =
+        function
+        | TypeCast(e, _) -> getTuple e
+        | ExprType(Tuple(genArgs, _)) as e -> Some(e, genArgs)
+        | _ -> None
+
+    let getItem tuple genArgs idx =
+        TypeCast(Get(tuple, TupleIndex idx, List.item idx genArgs, None), t)
+
+    match i.CompiledName, thisArg |> Option.bind getTuple, args with
+    | "get_Length", Some(_, genArgs), _ ->
+        makeIntConst (List.length genArgs) |> Some
+    | "get_Item",
+      Some(tuple, genArgs),
+      [ Value(NumberConstant(:? int as idx, Int32, _), _) ] when
+        idx >= 0 && idx < List.length genArgs
+        ->
+        getItem tuple genArgs idx |> Some
+    | "get_Item", Some(tuple, genArgs), [ index ] ->
+        let outOfRange =
+            makeThrow
+                r
+                t
+                (str "Index was outside the bounds of the array. (Parameter 'index')")
+
+        List.init (List.length genArgs) id
+        |> List.rev
+        |> List.fold
+            (fun elseExpr idx ->
+                let cond = makeEqOp None index (makeIntConst idx) BinaryEqual
+                IfThenElse(cond, getItem tuple genArgs idx, elseExpr, r)
+            )
+            outOfRange
+        |> Some
+    | _ -> None
+
 let createArray (com: ICompiler) ctx r t i count value =
     match t, value with
     | Array(typ, _), None ->
@@ -3272,6 +4036,25 @@ let arrays
     //     Helper.LibCall(com, "Array", "sortInPlaceWithComparer", t, [ar; arg], i.SignatureArgTypes, ?loc=r) |> Some
     | _ -> None
 
+let isPrimitiveNumberArray (t: Type) =
+    match t with
+    | Array(Number(kind, _), _) ->
+        match kind with
+        | Int8
+        | UInt8
+        | Int16
+        | UInt16
+        | Int32
+        | UInt32
+        | Int64
+        | UInt64
+        | NativeInt
+        | UNativeInt
+        | Float32
+        | Float64 -> true
+        | _ -> false
+    | _ -> false
+
 let arrayModule
     (com: ICompiler)
     (ctx: Context)
@@ -3361,6 +4144,69 @@ let arrayModule
             ?loc = r
         )
         |> Some
+    // vectorized implementations for arrays of primitive numbers
+    | ("Sum" | "Average" | "Min" | "Max" as meth), [ ar ] when isPrimitiveNumberArray ar.Type ->
+        Helper.LibCall(
+            com,
+            "NativeArray",
+            Naming.lowerFirst meth,
+            t,
+            args,
+            i.SignatureArgTypes,
+            ?loc = r
+        )
+        |> Some
+    | "Contains", [ _; ar ] when isPrimitiveNumberArray ar.Type ->
+        Helper.LibCall(
+            com,
+            "NativeArray",
+            "containsValue",
+            t,
+            args,
+            i.SignatureArgTypes,
+            ?loc = r
+        )
+        |> Some
+    | "Fill", [ ar; _; _; _ ] when isPrimitiveNumberArray ar.Type ->
+        Helper.LibCall(
+            com,
+            "NativeArray",
+            "fill",
+            t,
+            args,
+            i.SignatureArgTypes,
+            ?loc = r
+        )
+        |> Some
+    // native implementations (closures are monomorphized,
+    // and uniquely owned arrays are updated in place)
+    | ("Map" | "Filter" | "Choose" | "Iterate" | "Exists" | "ForAll" | "TryFind" | "TryFindIndex" as meth),
+      [ fn; _ ] when
+        (match fn with
+         | Lambda(arg, _, _) -> arg.Type <> Unit
+         | _ -> true)
+        ->
+        Helper.LibCall(
+            com,
+            "NativeArray",
+            Naming.lowerFirst meth,
+            t,
+            args,
+            i.SignatureArgTypes,
+            ?loc = r
+        )
+        |> Some
+    | ("Append" | "Sort" as meth), _ ->
+        Helper.LibCall(
+            com,
+            "NativeArray",
+            Naming.lowerFirst meth,
+            t,
+            args,
+            i.SignatureArgTypes,
+            ?loc = r
+        )
+        |> Some
     | ("Distinct" | "DistinctBy" | "Except" | "GroupBy" | "CountBy" as meth),
       args ->
         let meth = Naming.lowerFirst meth
@@ -3620,8 +4466,33 @@ let results
     (_: Expr option)
     (args: Expr list)
     =
-    match i.CompiledName with
-    | ("Bind" | "Map" | "MapError") as meth ->
+    match i.CompiledName, args with
+    | "ToArray", [ c ] ->
+        let optType = Option(t.Generics.Head, true)
+        let opt = Helper.LibCall(com, "Result", "toOption", optType, [ c ])
+        Helper.LibCall(com, "Array", "ofOption", t, [ opt ], ?loc = r) |> Some
+    | "ToList", [ c ] ->
+        let optType = Option(t.Generics.Head, true)
+        let opt = Helper.LibCall(com, "Result", "toOption", optType, [ c ])
+        Helper.LibCall(com, "List", "ofOption", t, [ opt ], ?loc = r) |> Some
+    | "ToValueOption", _ ->
+        Helper.LibCall(com, "Result", "toOption", t, args, ?loc = r) |> Some
+    | ("Bind"
+      | "Map"
+      | "MapError"
+      | "IsOk"
+      | "IsError"
+      | "Contains"
+      | "Count"
+      | "DefaultValue"
+      | "DefaultWith"
+      | "Exists"
+      | "Fold"
+      | "FoldBack"
+      | "ForAll"
+      | "Iterate"
+      | "ToOption") as meth,
+      _ ->
         Helper.LibCall(
             com,
             "Result",
@@ -3663,11 +4534,11 @@ let options
     (args: Expr list)
     =
     match i.CompiledName, thisArg with
-    | "Some", _ ->
+    | ("Some" | "ValueSome"), _ ->
         NewOption(List.tryHead args, t.Generics.Head, isStruct)
         |> makeValue r
         |> Some
-    | "get_None", _ ->
+    | ("get_None" | "get_ValueNone"), _ ->
         NewOption(None, t.Generics.Head, isStruct) |> makeValue r |> Some
     | "get_Value", Some c -> Get(c, OptionValue, t, r) |> Some
     | "get_IsSome", Some c -> Test(c, OptionTest true, r) |> Some
@@ -3687,8 +4558,28 @@ let optionModule
     match i.CompiledName, args with
     | "None", _ -> NewOption(None, t, isStruct) |> makeValue r |> Some
     | "GetValue", [ c ] -> Get(c, OptionValue, t, r) |> Some
-    | ("OfObj" | "OfNullable"), _ -> None // TODO:
-    | ("ToObj" | "ToNullable"), _ -> None // TODO:
+    // options, value options and nullables share the same representation
+    | ("OfOption" | "ToOption" | "OfValueOption" | "ToValueOption"), [ c ] ->
+        Some c
+    | ("OfNullable" | "ToNullable"), [ c ] -> Some c
+    | "ToObj", [ c ] ->
+        match t with
+        // None is replaced by the default value of 'T, see Fable2Rust makeNull
+        | String
+        | DeclaredType({ Path = SourcePath _ }, _) ->
+            Helper.LibCall(
+                com,
+                "Option",
+                "defaultValue",
+                t,
+                [ Value(Null t, r); c ],
+                ?loc = r
+            )
+            |> Some
+        | _ ->
+            $"Option.toObj is not supported for %A{t}, it has no default value"
+            |> addErrorAndReturnNull com ctx.InlinePath r
+            |> Some
     | "IsSome", [ c ] -> Test(c, OptionTest true, r) |> Some
     | "IsNone", [ c ] -> Test(c, OptionTest false, r) |> Some
     | "ToArray", [ arg ] ->
@@ -3732,6 +4623,20 @@ let parseBool
         |> Some
     | _ -> None
 
+/// Matches a CultureInfo passed where an IFormatProvider is expected
+let (|CultureInfoArg|_|) (expr: Expr) =
+    match expr with
+    | MaybeCasted(ExprTypeAs(IsEntity Types.cultureInfo _, culture)) ->
+        Some culture
+    | _ -> None
+
+/// Matches a DateTimeFormatInfo argument used as format provider
+let (|DateTimeFormatInfoArg|_|) (expr: Expr) =
+    match expr with
+    | MaybeCasted(ExprTypeAs(IsEntity Types.dateTimeFormatInfo _, dtfi)) ->
+        Some dtfi
+    | _ -> None
+
 let parseNum
     (com: ICompiler)
     (ctx: Context)
@@ -3741,14 +4646,14 @@ let parseNum
     (thisArg: Expr option)
     (args: Expr list)
     =
-    let parseCall meth str args style =
-        let moduleName, memberName, withStyleArg =
+    let parseCall meth str args style culture =
+        let moduleName, memberName =
             match t with
-            | Number(Decimal, _) -> "Decimal", Naming.lowerFirst meth, false
-            | Number(BigInt, _) -> "BigInt", Naming.lowerFirst meth, false
-            | Number(kind, _) when meth = "Parse" ->
-                "Convert", Naming.lowerFirst meth + kind.ToString(), true
-            | _ -> "Convert", Naming.lowerFirst meth, true
+            | Number(Decimal, _) -> "Decimal", Naming.lowerFirst meth
+            | Number(BigInt, _) -> "BigInt", Naming.lowerFirst meth
+            | Number(kind, _) when meth = "Parse" && Option.isNone culture ->
+                "Convert", Naming.lowerFirst meth + kind.ToString()
+            | _ -> "Convert", Naming.lowerFirst meth
 
         let outValue =
             if meth = "TryParse" then
@@ -3756,17 +4661,35 @@ let parseNum
             else
                 []
 
-        let args =
-            if not withStyleArg then
-                [ str ] @ outValue
-            else
+        match culture with
+        | Some culture ->
+            let args =
+                [
+                    str
+                    makeIntConst style
+                    culture
+                ]
+                @ outValue
+
+            Helper.LibCall(com, moduleName, memberName + "2", t, args, ?loc = r)
+        | None ->
+            let args =
                 [
                     str
                     makeIntConst style
                 ]
                 @ outValue
 
-        Helper.LibCall(com, moduleName, memberName, t, args, ?loc = r)
+            Helper.LibCall(com, moduleName, memberName, t, args, ?loc = r)
+
+    let withProvider meth provider =
+        match provider with
+        | CultureInfoArg culture -> Some culture
+        | _ ->
+            $"%s{i.DeclaringEntityFullName}.%s{meth}(): provider argument is ignored"
+            |> addWarning com ctx.InlinePath r
+
+            None
 
     let isFloat =
         match i.SignatureArgTypes with
@@ -3798,25 +4721,20 @@ let parseNum
         operators com ctx r t i thisArg args
     | ("Parse" | "TryParse") as meth,
       str :: NumberConst(:? int as style, _, _) :: _ ->
-        let hexConst = int System.Globalization.NumberStyles.HexNumber
-        let intConst = int System.Globalization.NumberStyles.Integer
-
-        if style <> hexConst && style <> intConst then
-            $"%s{i.DeclaringEntityFullName}.%s{meth}(): NumberStyle %d{style} is ignored"
-            |> addWarning com ctx.InlinePath r
-
         let acceptedArgs =
             if meth = "Parse" then
                 2
             else
                 3
 
-        if List.length args > acceptedArgs then
-            // e.g. Double.Parse(string, style, IFormatProvider) etc.
-            $"%s{i.DeclaringEntityFullName}.%s{meth}(): provider argument is ignored"
-            |> addWarning com ctx.InlinePath r
+        let culture =
+            if List.length args > acceptedArgs then
+                // e.g. Double.Parse(string, style, IFormatProvider) etc.
+                withProvider meth (List.item 2 args)
+            else
+                None
 
-        parseCall meth str args style |> Some
+        parseCall meth str args style culture |> Some
     | ("Parse" | "TryParse") as meth, str :: _ ->
         let acceptedArgs =
             if meth = "Parse" then
@@ -3824,18 +4742,67 @@ let parseNum
             else
                 2
 
-        if List.length args > acceptedArgs then
-            // e.g. Double.Parse(string, IFormatProvider) etc.
-            $"%s{i.DeclaringEntityFullName}.%s{meth}(): provider argument is ignored"
-            |> addWarning com ctx.InlinePath r
+        let culture =
+            if List.length args > acceptedArgs then
+                // e.g. Double.Parse(string, IFormatProvider) etc.
+                withProvider meth (List.item 1 args)
+            else
+                None
+
+        // the default styles used by .NET when none is given
+        let style =
+            match t with
+            | Number(Decimal, _) -> System.Globalization.NumberStyles.Number
+            | Number((Float16 | Float32 | Float64), _) ->
+                System.Globalization.NumberStyles.Float
+                ||| System.Globalization.NumberStyles.AllowThousands
+            | _ -> System.Globalization.NumberStyles.Integer
 
-        let style = int System.Globalization.NumberStyles.Any
-        parseCall meth str args style |> Some
+        parseCall meth str args (int style) culture |> Some
     | "Pow", (thisArg :: restArgs) ->
         makeInstanceCall r t i thisArg "powf" restArgs |> Some
-    // | "ToString", [ExprTypeAs(String, fmt)] ->
-    //     let format = makeStrConst ("{0:" + fmt + "}")
-    //     Helper.LibCall(com, "String", "format", t, [format; thisArg.Value], [format.Type; thisArg.Value.Type], ?loc=r) |> Some
+    | "ToString", [ ExprTypeAs(String, format); CultureInfoArg culture ] ->
+        Helper.LibCall(
+            com,
+            "Globalization",
+            "formatNumber2",
+            t,
+            [
+                thisArg.Value
+                format
+                culture
+            ],
+            ?loc = r
+        )
+        |> Some
+    | "ToString", ExprTypeAs(String, format) :: _ ->
+        Helper.LibCall(
+            com,
+            "Globalization",
+            "formatNumber",
+            t,
+            [
+                thisArg.Value
+                format
+            ],
+            ?loc = r
+        )
+        |> Some
+    | "ToString", [ CultureInfoArg culture ] ->
+        let str = Helper.GlobalCall("String", String, [ thisArg.Value ], ?loc = r)
+
+        Helper.LibCall(
+            com,
+            "Globalization",
+            "fromInvariantNumber",
+            t,
+            [
+                str
+                culture
+            ],
+            ?loc = r
+        )
+        |> Some
     | "ToString", _ ->
         Helper.GlobalCall("String", String, [ thisArg.Value ], ?loc = r) |> Some
     | _ -> None
@@ -3886,28 +4853,7 @@ let decimals
             ?loc = r
         )
         |> Some
-    | "Parse", _ ->
-        Helper.LibCall(
-            com,
-            "Decimal",
-            "parse",
-            t,
-            args,
-            i.SignatureArgTypes,
-            ?loc = r
-        )
-        |> Some
-    | "TryParse", _ ->
-        Helper.LibCall(
-            com,
-            "Decimal",
-            "tryParse",
-            t,
-            args,
-            i.SignatureArgTypes,
-            ?loc = r
-        )
-        |> Some
+    | ("Parse" | "TryParse"), _ -> parseNum com ctx r t i thisArg args
     | Patterns.SetContains Operators.compareSet, [ left; right ] ->
         applyCompareOp com ctx r t i.CompiledName left right |> Some
     | Patterns.SetContains Operators.standardSet, _ ->
@@ -3963,7 +4909,7 @@ let decimals
                 ?loc = r
             )
             |> Some
-        | [ x; ExprTypeAs(Number(Int32, _), dp) ] ->
+        | [ x; ExprTypeAs(Number(Int32, NumberInfo.Empty), dp) ] ->
             Helper.LibCall(
                 com,
                 "Decimal",
@@ -3997,9 +4943,55 @@ let decimals
             )
             |> Some
         | _ -> None
-    // | "ToString", [ExprTypeAs(String, format)] ->
-    //     let format = makeStrConst ("{0:" + fmt + "}")
-    //     Helper.LibCall(com, "String", "format", t, [format; thisArg.Value], [format.Type; thisArg.Value.Type], ?loc=r) |> Some
+    | "ToString", [ ExprTypeAs(String, format); CultureInfoArg culture ] ->
+        Helper.LibCall(
+            com,
+            "Globalization",
+            "formatNumber2",
+            t,
+            [
+                thisArg.Value
+                format
+                culture
+            ],
+            ?loc = r
+        )
+        |> Some
+    | "ToString", ExprTypeAs(String, format) :: _ ->
+        Helper.LibCall(
+            com,
+            "Globalization",
+            "formatNumber",
+            t,
+            [
+                thisArg.Value
+                format
+            ],
+            ?loc = r
+        )
+        |> Some
+    | "ToString", [ CultureInfoArg culture ] ->
+        let str =
+            Helper.LibCall(
+                com,
+                "Decimal",
+                "toString",
+                String,
+                [ thisArg.Value ]
+            )
+
+        Helper.LibCall(
+            com,
+            "Globalization",
+            "fromInvariantNumber",
+            t,
+            [
+                str
+                culture
+            ],
+            ?loc = r
+        )
+        |> Some
     | "ToString", _ ->
         Helper.LibCall(
             com,
@@ -4023,7 +5015,15 @@ let bigints
     (args: Expr list)
     =
     match i.CompiledName, thisArg, args with
+    | ".ctor", None, [ ExprType(Array _); isUnsigned; isBigEndian ] ->
+        Helper.LibCall(com, "BigInt", "fromByteArray2", t, args, ?loc = r)
+        |> Some
     | ".ctor", None, [ arg ] -> convertTo com ctx r t args |> Some
+    | ("Parse" | "TryParse"), None, _ ->
+        parseNum com ctx r t i thisArg args
+    | "ToByteArray", Some x, [ isUnsigned; isBigEndian ] ->
+        Helper.LibCall(com, "BigInt", "toByteArray2", t, x :: args, ?loc = r)
+        |> Some
     | Patterns.SetContains Operators.compareSet, _, [ left; right ] ->
         applyCompareOp com ctx r t i.CompiledName left right |> Some
     | Patterns.SetContains Operators.standardSet, _, _ ->
@@ -4370,6 +5370,9 @@ let keyValuePairs
     | "get_Value", Some c -> Get(c, TupleIndex 1, t, r) |> Some
     | _ -> None
 
+let unsupportedComparer =
+    "Custom equality comparers are not supported, default equality will be used"
+
 let dictionaries
     (com: ICompiler)
     (ctx: Context)
@@ -4385,6 +5388,14 @@ let dictionaries
         | [] -> Helper.LibCall(com, "HashMap", "new_empty", t, args) |> Some
         | [ ExprType(Number _) ] ->
             Helper.LibCall(com, "HashMap", "new_with_capacity", t, args) |> Some
+        | [ ExprType(IEqualityComparer) ] ->
+            addWarning com ctx.InlinePath r unsupportedComparer
+            Helper.LibCall(com, "HashMap", "new_empty", t, []) |> Some
+        | [ ExprType(Number _) as capacity; ExprType(IEqualityComparer) ] ->
+            addWarning com ctx.InlinePath r unsupportedComparer
+
+            Helper.LibCall(com, "HashMap", "new_with_capacity", t, [ capacity ])
+            |> Some
         | [ ExprType(IEnumerable) ] ->
             let a = Helper.LibCall(com, "Seq", "toArray", t, args)
             Helper.LibCall(com, "HashMap", "new_from_array", t, [ a ]) |> Some
@@ -4430,6 +5441,14 @@ let hashSets
         | [] -> Helper.LibCall(com, "HashSet", "new_empty", t, args) |> Some
         | [ ExprType(Number _) ] ->
             Helper.LibCall(com, "HashSet", "new_with_capacity", t, args) |> Some
+        | [ ExprType(IEqualityComparer) ] ->
+            addWarning com ctx.InlinePath r unsupportedComparer
+            Helper.LibCall(com, "HashSet", "new_empty", t, []) |> Some
+        | [ ExprType(Number _) as capacity; ExprType(IEqualityComparer) ] ->
+            addWarning com ctx.InlinePath r unsupportedComparer
+
+            Helper.LibCall(com, "HashSet", "new_with_capacity", t, [ capacity ])
+            |> Some
         | [ ExprTypeAs(IEnumerable, arg) ] ->
             Helper.LibCall(
                 com,
@@ -4567,7 +5586,15 @@ let unchecked
     =
     match i.CompiledName, args with
     | "DefaultOf", _ ->
-        (genArg com ctx r 0 i.GenericArgs) |> getZero com ctx |> Some
+        let t = genArg com ctx r 0 i.GenericArgs
+
+        match t with
+        // user types and functions are checked for a Default impl by the backend
+        | DeclaredType({ Path = SourcePath _ }, _)
+        | LambdaType _
+        | DelegateType _ -> Value(Null t, r)
+        | _ -> getZero com ctx t
+        |> Some
     | "Hash", [ arg ] -> getHashCode com ctx r arg |> Some
     | "Equals", [ arg1; arg2 ] -> equals com ctx r arg1 arg2 |> Some
     | "Compare", [ arg1; arg2 ] -> compare com ctx r arg1 arg2 |> Some
@@ -4700,6 +5727,28 @@ let convert
     | "ToString", [ arg; ExprType(Number(Int32, _)) ] ->
         Helper.LibCall(com, "Convert", "toStringRadix", t, args, ?loc = r)
         |> Some
+    | "ToBase64String", [ arg; options ] ->
+        Helper.LibCall(com, "Convert", "toBase64StringWith", t, args, ?loc = r)
+        |> Some
+    | "ToBase64String", [ arg; offset; length ] ->
+        Helper.LibCall(
+            com,
+            "Convert",
+            "toBase64StringRange",
+            t,
+            args @ [ makeIntConst 0 ],
+            ?loc = r
+        )
+        |> Some
+    | "ToBase64String", [ arg; offset; length; options ] ->
+        Helper.LibCall(com, "Convert", "toBase64StringRange", t, args, ?loc = r)
+        |> Some
+    | "ToHexString", [ arg; offset; length ] ->
+        Helper.LibCall(com, "Convert", "toHexStringRange", t, args, ?loc = r)
+        |> Some
+    | "FromBase64CharArray", [ arg; offset; length ] ->
+        Helper.LibCall(com, "Convert", "fromBase64CharArray", t, args, ?loc = r)
+        |> Some
     | ("ToHexString" | "FromHexString" | "ToBase64String" | "FromBase64String"),
       [ arg ] ->
         Helper.LibCall(
@@ -4789,6 +5838,29 @@ let makeDateOrTimeMemberCall
     | Some callee -> makeInstanceCall r t i callee memberName args
     | None -> makeStaticMemberCall com r t i moduleName memberName args
 
+let makeParseExactCall com r t i moduleName meth (args: Expr list) =
+    let formats =
+        function
+        | ExprType(Array _) as formats -> formats
+        | format -> makeArray String [ format ]
+
+    // DateTimeStyles are ignored, only the out value is kept
+    let outValue rest =
+        if meth = "TryParseExact" then
+            List.tryLast rest |> Option.toList
+        else
+            []
+
+    let meth, args =
+        match args with
+        | str :: format :: CultureInfoArg culture :: rest ->
+            meth + "2", str :: formats format :: culture :: outValue rest
+        | str :: format :: _provider :: rest ->
+            meth, str :: formats format :: outValue rest
+        | _ -> meth, args
+
+    makeStaticMemberCall com r t i moduleName (Naming.lowerFirst meth) args
+
 let dateTimes
     (com: ICompiler)
     (ctx: Context)
@@ -4890,27 +5962,137 @@ let dateTimes
             r
         )
         |> Some
-    | meth ->
-        let meth = Naming.removeGetSetPrefix meth |> Naming.lowerFirst
-
-        makeDateOrTimeMemberCall com ctx r t i "DateTime" meth thisArg args
-        |> Some
-
-let dateTimeOffsets
-    (com: ICompiler)
-    (ctx: Context)
-    r
-    t
-    (i: CallInfo)
-    (thisArg: Expr option)
-    (args: Expr list)
-    =
-    match i.CompiledName with
-    | ".ctor" ->
-        match args with
-        | [] -> "new_empty" |> Some
-        | ExprType(Number(Int64, _)) :: _ -> "new_ticks" |> Some
-        | ExprType(DeclaredType(ent, [])) :: [] when
+    | "ToString" ->
+        match thisArg, args with
+        | Some callee, [ ExprTypeAs(String, format); CultureInfoArg culture ] ->
+            makeInstanceCall
+                r
+                t
+                i
+                callee
+                "toString2"
+                [
+                    format
+                    culture
+                ]
+            |> Some
+        | Some callee, [ CultureInfoArg culture ] ->
+            makeInstanceCall
+                r
+                t
+                i
+                callee
+                "toString2"
+                [
+                    makeStrConst ""
+                    culture
+                ]
+            |> Some
+        | Some callee, [ ExprTypeAs(String, format); DateTimeFormatInfoArg dtfi ] ->
+            makeInstanceCall
+                r
+                t
+                i
+                callee
+                "toString3"
+                [
+                    format
+                    dtfi
+                ]
+            |> Some
+        | Some callee, [ DateTimeFormatInfoArg dtfi ] ->
+            makeInstanceCall
+                r
+                t
+                i
+                callee
+                "toString3"
+                [
+                    makeStrConst ""
+                    dtfi
+                ]
+            |> Some
+        | Some callee, [] ->
+            makeInstanceCall r t i callee "toString" [ makeStrConst "" ]
+            |> Some
+        | _ ->
+            makeDateOrTimeMemberCall com ctx r t i "DateTime" "toString" thisArg args
+            |> Some
+    | "Parse" ->
+        match args with
+        | [ str; CultureInfoArg culture ] ->
+            makeStaticMemberCall
+                com
+                r
+                t
+                i
+                "DateTime"
+                "parse2"
+                [
+                    str
+                    culture
+                ]
+            |> Some
+        | [ str; CultureInfoArg culture; styles ] ->
+            makeStaticMemberCall
+                com
+                r
+                t
+                i
+                "DateTime"
+                "parse3"
+                [
+                    str
+                    culture
+                    styles
+                ]
+            |> Some
+        | _ ->
+            makeDateOrTimeMemberCall com ctx r t i "DateTime" "parse" thisArg args
+            |> Some
+    | "TryParse" ->
+        match args with
+        | [ str; CultureInfoArg culture; styles; outValue ] ->
+            makeStaticMemberCall
+                com
+                r
+                t
+                i
+                "DateTime"
+                "tryParse3"
+                [
+                    str
+                    culture
+                    styles
+                    outValue
+                ]
+            |> Some
+        | _ ->
+            makeDateOrTimeMemberCall com ctx r t i "DateTime" "tryParse" thisArg args
+            |> Some
+    | "ParseExact"
+    | "TryParseExact" as meth -> makeParseExactCall com r t i "DateTime" meth args |> Some
+    | meth ->
+        let meth = Naming.removeGetSetPrefix meth |> Naming.lowerFirst
+
+        makeDateOrTimeMemberCall com ctx r t i "DateTime" meth thisArg args
+        |> Some
+
+let dateTimeOffsets
+    (com: ICompiler)
+    (ctx: Context)
+    r
+    t
+    (i: CallInfo)
+    (thisArg: Expr option)
+    (args: Expr list)
+    =
+    match i.CompiledName with
+    | ".ctor" ->
+        match args with
+        | [] -> "new_empty" |> Some
+        | ExprType(Number(Int64, _)) :: _ -> "new_ticks" |> Some
+        | ExprType(DeclaredType(ent, [])) :: [] when
             ent.FullName = Types.datetime
             ->
             "new_datetime" |> Some
@@ -4947,6 +6129,10 @@ let dateTimeOffsets
         |> Option.map (fun meth ->
             makeStaticMemberCall com r t i "DateTimeOffset" meth args
         )
+    // implicit conversion from DateTime
+    | "op_Implicit" ->
+        makeStaticMemberCall com r t i "DateTimeOffset" "new_datetime" args
+        |> Some
     | "Compare"
     | "CompareTo"
     | "Equals"
@@ -4967,6 +6153,53 @@ let dateTimeOffsets
             r
         )
         |> Some
+    | "ToString" ->
+        match thisArg, args with
+        | Some callee, [ ExprTypeAs(String, format); CultureInfoArg culture ] ->
+            makeInstanceCall
+                r
+                t
+                i
+                callee
+                "toString2"
+                [
+                    format
+                    culture
+                ]
+            |> Some
+        | Some callee, [ CultureInfoArg culture ] ->
+            makeInstanceCall
+                r
+                t
+                i
+                callee
+                "toString2"
+                [
+                    makeStrConst ""
+                    culture
+                ]
+            |> Some
+        | _ ->
+            makeDateOrTimeMemberCall com ctx r t i "DateTimeOffset" "toString" thisArg args
+            |> Some
+    | "Parse" ->
+        match args with
+        | [ str; CultureInfoArg culture ]
+        | [ str; CultureInfoArg culture; _ ] ->
+            makeStaticMemberCall com r t i "DateTimeOffset" "parse2" [ str; culture ] |> Some
+        | _ ->
+            makeDateOrTimeMemberCall com ctx r t i "DateTimeOffset" "parse" thisArg args
+            |> Some
+    | "TryParse" ->
+        match args with
+        | [ str; CultureInfoArg culture; _; outValue ] ->
+            makeStaticMemberCall com r t i "DateTimeOffset" "tryParse2" [ str; culture; outValue ]
+            |> Some
+        | _ ->
+            makeDateOrTimeMemberCall com ctx r t i "DateTimeOffset" "tryParse" thisArg args
+            |> Some
+    | "ParseExact"
+    | "TryParseExact" as meth -> makeParseExactCall com r t i "DateTimeOffset" meth args |> Some
     | meth ->
         let meth = Naming.removeGetSetPrefix meth |> Naming.lowerFirst
 
@@ -5007,6 +6240,25 @@ let dateOnly
     | "GetHashCode" -> valueTypes com ctx r t i thisArg args
     | "ToDateTime" when args.Length = 2 ->
         makeInstanceCall r t i thisArg.Value "toDateTime2" args |> Some
+    | "Parse" ->
+        match args with
+        | [ str; CultureInfoArg culture ]
+        | [ str; CultureInfoArg culture; _ ] ->
+            makeStaticMemberCall com r t i "DateOnly" "parse2" [ str; culture ] |> Some
+        | _ ->
+            makeDateOrTimeMemberCall com ctx r t i "DateOnly" "parse" thisArg args
+            |> Some
+    | "TryParse" ->
+        match args with
+        | [ str; CultureInfoArg culture; outValue ]
+        | [ str; CultureInfoArg culture; _; outValue ] ->
+            makeStaticMemberCall com r t i "DateOnly" "tryParse2" [ str; culture; outValue ]
+            |> Some
+        | _ ->
+            makeDateOrTimeMemberCall com ctx r t i "DateOnly" "tryParse" thisArg args
+            |> Some
+    | "ParseExact"
+    | "TryParseExact" as meth -> makeParseExactCall com r t i "DateOnly" meth args |> Some
     | meth ->
         let meth = Naming.removeGetSetPrefix meth |> Naming.lowerFirst
 
@@ -5048,8 +6300,59 @@ let timeOnly
     | "CompareTo"
     | "Equals"
     | "GetHashCode" -> valueTypes com ctx r t i thisArg args
-    | "Add" when args.Length = 2 ->
-        makeInstanceCall r t i thisArg.Value "add2" args |> Some
+    | "Add"
+    | "AddHours"
+    | "AddMinutes" as meth when args.Length = 2 ->
+        let meth = Naming.lowerFirst meth + "2"
+        makeInstanceCall r t i thisArg.Value meth args |> Some
+    | "ToString" ->
+        match thisArg, args with
+        | Some callee, [ ExprTypeAs(String, format); CultureInfoArg culture ] ->
+            makeInstanceCall
+                r
+                t
+                i
+                callee
+                "toString2"
+                [
+                    format
+                    culture
+                ]
+            |> Some
+        | Some callee, [ CultureInfoArg culture ] ->
+            makeInstanceCall
+                r
+                t
+                i
+                callee
+                "toString2"
+                [
+                    makeStrConst ""
+                    culture
+                ]
+            |> Some
+        | _ ->
+            makeDateOrTimeMemberCall com ctx r t i "TimeOnly" "toString" thisArg args
+            |> Some
+    | "Parse" ->
+        match args with
+        | [ str; CultureInfoArg culture ]
+        | [ str; CultureInfoArg culture; _ ] ->
+            makeStaticMemberCall com r t i "TimeOnly" "parse2" [ str; culture ] |> Some
+        | _ ->
+            makeDateOrTimeMemberCall com ctx r t i "TimeOnly" "parse" thisArg args
+            |> Some
+    | "TryParse" ->
+        match args with
+        | [ str; CultureInfoArg culture; outValue ]
+        | [ str; CultureInfoArg culture; _; outValue ] ->
+            makeStaticMemberCall com r t i "TimeOnly" "tryParse2" [ str; culture; outValue ]
+            |> Some
+        | _ ->
+            makeDateOrTimeMemberCall com ctx r t i "TimeOnly" "tryParse" thisArg args
+            |> Some
+    | "ParseExact"
+    | "TryParseExact" as meth -> makeParseExactCall com r t i "TimeOnly" meth args |> Some
     | meth ->
         let meth = Naming.removeGetSetPrefix meth |> Naming.lowerFirst
 
@@ -5123,6 +6426,40 @@ let timeSpans
             r
         )
         |> Some
+    | "ToString" ->
+        match thisArg, args with
+        | Some callee, [ ExprTypeAs(String, format); CultureInfoArg culture ] ->
+            makeInstanceCall
+                r
+                t
+                i
+                callee
+                "to_string2"
+                [
+                    format
+                    culture
+                ]
+            |> Some
+        | _ ->
+            makeDateOrTimeMemberCall com ctx r t i "TimeSpan" "to_string" thisArg args
+            |> Some
+    | "ParseExact"
+    | "TryParseExact" as meth ->
+        let formats =
+            function
+            | ExprType(Array _) as formats -> formats
+            | format -> makeArray String [ format ]
+
+        // the format provider and TimeSpanStyles are ignored
+        let args =
+            match meth, args with
+            | "TryParseExact", str :: format :: _provider :: rest ->
+                str :: formats format :: (List.tryLast rest |> Option.toList)
+            | _, str :: format :: _ -> [ str; formats format ]
+            | _ -> args
+
+        let meth = Naming.applyCaseRule Fable.Core.CaseRules.SnakeCase meth
+        makeStaticMemberCall com r t i "TimeSpan" meth args |> Some
     | meth ->
         let meth =
             Naming.removeGetSetPrefix meth
@@ -5140,24 +6477,43 @@ let timers
     (thisArg: Expr option)
     (args: Expr list)
     =
+    // due times and periods are passed in milliseconds
+    let toMilliseconds (arg: Expr) =
+        match arg.Type with
+        | Builtin BclTimeSpan ->
+            let ms =
+                Helper.InstanceCall(arg, "total_milliseconds", Float64.Number, [])
+
+            TypeCast(ms, Int64.Number)
+        | _ -> TypeCast(arg, Int64.Number)
+
     match i.CompiledName, thisArg, args with
-    | ".ctor", _, _ ->
-        Helper.LibCall(
-            com,
-            "Timer",
-            "default",
-            t,
-            args,
-            i.SignatureArgTypes,
-            isConstructor = true,
-            ?loc = r
-        )
-        |> Some
-    | Naming.StartsWith "get_" meth, Some x, _ ->
-        getFieldWith r t x meth |> Some
-    | Naming.StartsWith "set_" meth, Some x, [ value ] ->
-        setExpr r x (makeStrConst meth) value |> Some
-    | meth, Some callee, args -> makeInstanceCall r t i callee meth args |> Some
+    // System.Threading.Timer
+    | ".ctor", None, [ callback; state; dueTime; period ] ->
+        let args =
+            [
+                callback
+                state
+                toMilliseconds dueTime
+                toMilliseconds period
+            ]
+
+        makeStaticLibCall com r t i "Timer" "Timer::new" args |> Some
+    | "Change", Some callee, [ dueTime; period ] ->
+        let args = [ toMilliseconds dueTime; toMilliseconds period ]
+        makeInstanceCall r t i callee "change" args |> Some
+    // System.Timers.Timer
+    | ".ctor", None, _ ->
+        bclNativeImpl com ctx r t i "Timer" "new" thisArg args |> Some
+    // the Elapsed event is erased to the timer, handlers are added
+    // with `add_Elapsed`, see controlExtensions
+    | "Elapsed", Some callee, [] -> Some callee
+    | Naming.StartsWith "set_" meth, Some callee, [ _ ] ->
+        makeInstanceCall r t i callee ("set_" + Naming.lowerFirst meth) args
+        |> Some
+    | meth, Some callee, _ ->
+        let meth = Naming.removeGetSetPrefix meth |> Naming.lowerFirst
+        makeInstanceCall r t i callee meth args |> Some
     | _ -> None
 
 let systemEnv
@@ -5173,79 +6529,6 @@ let systemEnv
     | "get_NewLine" -> Some(makeStrConst "\n")
     | _ -> None
 
-// Initial support, making at least InvariantCulture compile-able
-// to be used System.Double.Parse and System.Single.Parse
-// see https://github.com/fable-compiler/Fable/pull/1197#issuecomment-348034660
-let globalization
-    (com: ICompiler)
-    (ctx: Context)
-    (_: SourceLocation option)
-    t
-    (i: CallInfo)
-    (_: Expr option)
-    (_: Expr list)
-    =
-    match i.CompiledName with
-    | "get_InvariantCulture" ->
-        // System.Globalization namespace is not supported by Fable. The value InvariantCulture will be compiled to an empty object literal
-        ObjectExpr([], t, None) |> Some
-    | _ -> None
-
-let random
-    (com: ICompiler)
-    (ctx: Context)
-    r
-    t
-    (i: CallInfo)
-    (_: Expr option)
-    (args: Expr list)
-    =
-    match i.CompiledName with
-    | ".ctor" -> ObjectExpr([], t, None) |> Some
-    | "Next" ->
-        let min, max =
-            match args with
-            | [] -> makeIntConst 0, makeIntConst System.Int32.MaxValue
-            | [ max ] -> makeIntConst 0, max
-            | [ min; max ] -> min, max
-            | _ -> FableError "Unexpected arg count for Random.Next" |> raise
-
-        Helper.LibCall(
-            com,
-            "Util",
-            "randomNext",
-            t,
-            [
-                min
-                max
-            ],
-            [
-                min.Type
-                max.Type
-            ],
-            ?loc = r
-        )
-        |> Some
-    | "NextDouble" -> Helper.GlobalCall("Math", t, [], memb = "random") |> Some
-    | "NextBytes" ->
-        let byteArray =
-            match args with
-            | [ b ] -> b
-            | _ ->
-                FableError "Unexpected arg count for Random.NextBytes" |> raise
-
-        Helper.LibCall(
-            com,
-            "Util",
-            "randomBytes",
-            t,
-            [ byteArray ],
-            [ byteArray.Type ],
-            ?loc = r
-        )
-        |> Some
-    | _ -> None
-
 let cancels
     (com: ICompiler)
     (ctx: Context)
@@ -5314,6 +6597,21 @@ let tasks
         makeInstanceCall r t i callee "get_result" args |> Some
     | _ -> None
 
+let valueTasks
+    com
+    (ctx: Context)
+    r
+    t
+    (i: CallInfo)
+    (thisArg: Expr option)
+    (args: Expr list)
+    =
+    match i.CompiledName, thisArg with
+    | "AsTask", Some callee -> callee |> Some
+    | "get_Result", Some callee ->
+        makeInstanceCall r t i callee "get_result" args |> Some
+    | _ -> None
+
 let threads
     com
     (ctx: Context)
@@ -5334,6 +6632,33 @@ let threads
         makeInstanceCall r t i callee "join" args |> Some
     | _ -> None
 
+let periodicTimers
+    com
+    (ctx: Context)
+    r
+    t
+    (i: CallInfo)
+    (thisArg: Expr option)
+    (args: Expr list)
+    =
+    match i.CompiledName, thisArg, args with
+    | ".ctor", None, [ _ ] ->
+        makeStaticLibCall
+            com
+            r
+            t
+            i
+            "PeriodicTimer"
+            "PeriodicTimer::new"
+            args
+        |> Some
+    // the cancellation token is ignored
+    | "WaitForNextTickAsync", Some callee, _ ->
+        makeInstanceCall r t i callee "waitForNextTickAsync" [] |> Some
+    | "Dispose", Some callee, [] ->
+        makeInstanceCall r t i callee "dispose" [] |> Some
+    | _ -> None
+
 let activator
     (com: ICompiler)
     (ctx: Context)
@@ -5420,6 +6745,432 @@ let regex
         let meth = Naming.removeGetSetPrefix meth |> Naming.lowerFirst
         bclNativeImpl com ctx r t i "RegExp" meth thisArg args |> Some
 
+let globalization
+    com
+    (ctx: Context)
+    r
+    t
+    (i: CallInfo)
+    (thisArg: Expr option)
+    (args: Expr list)
+    =
+    match i.CompiledName with
+    | meth ->
+        let meth =
+            if meth = ".ctor" then
+                "new"
+            else
+                meth
+
+        let meth = Naming.removeGetSetPrefix meth |> Naming.lowerFirst
+        bclNativeImpl com ctx r t i "Globalization" meth thisArg args |> Some
+
+// the Gregorian calendar is the only calendar
+let calendars
+    com
+    (ctx: Context)
+    r
+    t
+    (i: CallInfo)
+    (thisArg: Expr option)
+    (args: Expr list)
+    =
+    match i.CompiledName with
+    | meth ->
+        let meth =
+            if meth = ".ctor" then
+                "new"
+            else
+                meth
+
+        let meth = Naming.removeGetSetPrefix meth |> Naming.lowerFirst
+        bclNativeImpl com ctx r t i "Calendar" meth thisArg args |> Some
+
+let random
+    com
+    (ctx: Context)
+    r
+    t
+    (i: CallInfo)
+    (thisArg: Expr option)
+    (args: Expr list)
+    =
+    match i.CompiledName with
+    | meth ->
+        let meth =
+            if meth = ".ctor" then
+                "new"
+            else
+                meth
+
+        let meth = Naming.removeGetSetPrefix meth |> Naming.lowerFirst
+        bclNativeImpl com ctx r t i "Random" meth thisArg args |> Some
+
+let stopwatch
+    com
+    (ctx: Context)
+    r
+    t
+    (i: CallInfo)
+    (thisArg: Expr option)
+    (args: Expr list)
+    =
+    match i.CompiledName with
+    | meth ->
+        let meth =
+            if meth = ".ctor" then
+                "new"
+            else
+                meth
+
+        let meth = Naming.removeGetSetPrefix meth |> Naming.lowerFirst
+        bclNativeImpl com ctx r t i "Stopwatch" meth thisArg args |> Some
+
+let timeZones
+    com
+    (ctx: Context)
+    r
+    t
+    (i: CallInfo)
+    (thisArg: Expr option)
+    (args: Expr list)
+    =
+    match i.CompiledName, args with
+    // the DateTimeOffset overload would have the same suffix
+    | "ConvertTime", [ ExprType(IsEntity Types.datetimeOffset _); _ ] ->
+        bclNativeImpl com ctx r t i "TimeZoneInfo" "convertTimeOffset" thisArg args
+        |> Some
+    | meth, _ ->
+        let meth = Naming.removeGetSetPrefix meth |> Naming.lowerFirst
+        bclNativeImpl com ctx r t i "TimeZoneInfo" meth thisArg args |> Some
+
+let isoWeeks
+    (com: ICompiler)
+    (ctx: Context)
+    r
+    t
+    (i: CallInfo)
+    (thisArg: Expr option)
+    (args: Expr list)
+    =
+    match i.CompiledName, args with
+    | "GetWeekOfYear", [ ExprType(IsEntity Types.dateOnly _) ] ->
+        Helper.LibCall(com, "DateOnly", "isoWeekOfYear", t, args, ?loc = r) |> Some
+    | "GetYear", [ ExprType(IsEntity Types.dateOnly _) ] ->
+        Helper.LibCall(com, "DateOnly", "isoYear", t, args, ?loc = r) |> Some
+    | "ToDateOnly", [ _; _; _ ] ->
+        Helper.LibCall(com, "DateOnly", "isoWeekToDateOnly", t, args, ?loc = r) |> Some
+    | "GetWeekOfYear", [ _ ] -> Helper.LibCall(com, "DateTime", "isoWeekOfYear", t, args, ?loc = r) |> Some
+    | "GetYear", [ _ ] -> Helper.LibCall(com, "DateTime", "isoYear", t, args, ?loc = r) |> Some
+    | "GetWeeksInYear", [ _ ] -> Helper.LibCall(com, "DateTime", "isoWeeksInYear", t, args, ?loc = r) |> Some
+    | "GetYearStart", [ _ ] -> Helper.LibCall(com, "DateTime", "isoYearStart", t, args, ?loc = r) |> Some
+    | "GetYearEnd", [ _ ] -> Helper.LibCall(com, "DateTime", "isoYearEnd", t, args, ?loc = r) |> Some
+    | "ToDateTime", [ _; _; _ ] ->
+        Helper.LibCall(com, "DateTime", "isoWeekToDateTime", t, args, ?loc = r) |> Some
+    | _ -> None
+
+let standardFormat
+    com
+    (ctx: Context)
+    r
+    t
+    (i: CallInfo)
+    (thisArg: Expr option)
+    (args: Expr list)
+    =
+    match i.CompiledName with
+    | meth ->
+        let meth =
+            match meth with
+            | ".ctor"
+            | "op_Implicit" -> "new"
+            | _ -> meth
+
+        let meth = Naming.removeGetSetPrefix meth |> Naming.lowerFirst
+        bclNativeImpl com ctx r t i "Utf8Text" meth thisArg args |> Some
+
+let utf8Text
+    (com: ICompiler)
+    (ctx: Context)
+    r
+    t
+    (i: CallInfo)
+    (thisArg: Expr option)
+    (args: Expr list)
+    =
+    match i.CompiledName, args with
+    | "TryParse", [ _; _; _ ] ->
+        Helper.LibCall(com, "Utf8Text", "tryParse", t, args, ?loc = r) |> Some
+    | "TryParse", [ _; _; _; _ ] ->
+        Helper.LibCall(com, "Utf8Text", "tryParse2", t, args, ?loc = r) |> Some
+    | "TryFormat", [ _; _; _ ] ->
+        Helper.LibCall(com, "Utf8Text", "tryFormat", t, args, ?loc = r) |> Some
+    | "TryFormat", [ _; _; _; _ ] ->
+        Helper.LibCall(com, "Utf8Text", "tryFormat2", t, args, ?loc = r) |> Some
+    | _ -> None
+
+let objectPools
+    (com: ICompiler)
+    (ctx: Context)
+    r
+    t
+    (i: CallInfo)
+    (thisArg: Expr option)
+    (args: Expr list)
+    =
+    match i.CompiledName, thisArg, args with
+    | (".ctor" | "Create"), None, _ ->
+        let meth = "ObjectPool::new" + getArgsSuffix thisArg args
+        makeStaticLibCall com r t i "ObjectPool" meth args |> Some
+    | "Get", Some callee, [] -> makeInstanceCall r t i callee "get" [] |> Some
+    | "Return", Some callee, [ obj ] ->
+        makeInstanceCall r t i callee "return_" [ obj ] |> Some
+    | _ -> None
+
+let pooledObjectPolicies
+    (com: ICompiler)
+    (ctx: Context)
+    r
+    t
+    (i: CallInfo)
+    (thisArg: Expr option)
+    (args: Expr list)
+    =
+    match i.CompiledName, thisArg with
+    | ("Create" | "Return" as meth), Some callee ->
+        makeInstanceCall r t i callee meth args |> Some
+    | _ -> None
+
+let streams
+    (com: ICompiler)
+    (ctx: Context)
+    r
+    t
+    (i: CallInfo)
+    (thisArg: Expr option)
+    (args: Expr list)
+    =
+    match i.CompiledName, thisArg, args with
+    | ".ctor", None, ([] | [ ExprType(Number _) ]) ->
+        makeStaticLibCall com r t i "IO" "Stream::memoryStream" [] |> Some
+    | ".ctor", None, [ ExprType(Array _) as buffer ] ->
+        makeStaticLibCall com r t i "IO" "Stream::memoryStreamFrom" [ buffer ]
+        |> Some
+    | Naming.StartsWith "get_" prop, Some x, [] ->
+        makeInstanceCall r t i x (Naming.lowerFirst prop) [] |> Some
+    | Naming.StartsWith "set_" prop, Some x, [ value ] ->
+        makeInstanceCall r t i x ("set_" + Naming.lowerFirst prop) [ value ]
+        |> Some
+    | ("Read" | "Write" | "ReadByte" | "WriteByte" | "Seek" | "SetLength" | "Flush" | "CopyTo" | "ToArray" | "Close" | "Dispose" as meth),
+      Some x,
+      _ -> makeInstanceCall r t i x (Naming.lowerFirst meth) args |> Some
+    | _ -> None
+
+// file timestamps are UTC DateTimes read from the file metadata
+let files
+    (com: ICompiler)
+    (ctx: Context)
+    r
+    t
+    (i: CallInfo)
+    (thisArg: Expr option)
+    (args: Expr list)
+    =
+    match i.CompiledName, thisArg, args with
+    | ".ctor", None, [ fileName ] ->
+        makeStaticLibCall com r t i "FileSystem" "FileInfo::new" [ fileName ]
+        |> Some
+    | Naming.StartsWith "get_" prop, Some x, [] ->
+        makeInstanceCall r t i x (Naming.lowerFirst prop) [] |> Some
+    | Naming.StartsWith "set_" prop, Some x, [ value ] ->
+        makeInstanceCall r t i x ("set_" + Naming.lowerFirst prop) [ value ]
+        |> Some
+    | "ToString", Some x, [] -> makeInstanceCall r t i x "toString" [] |> Some
+    | ("Exists" | "GetCreationTime" | "GetCreationTimeUtc" | "GetLastWriteTime" | "GetLastWriteTimeUtc" | "GetLastAccessTime" | "GetLastAccessTimeUtc" | "SetLastWriteTime" | "SetLastWriteTimeUtc" as meth),
+      None,
+      _ -> Helper.LibCall(com, "FileSystem", Naming.lowerFirst meth, t, args, ?loc = r) |> Some
+    | _ -> None
+
+// xUnit and NUnit assertions, so shared test suites can run with cargo test
+let asserts
+    (com: ICompiler)
+    (ctx: Context)
+    r
+    t
+    (i: CallInfo)
+    (thisArg: Expr option)
+    (args: Expr list)
+    =
+    let assertCall meth args =
+        Helper.LibCall(com, "Assert", meth, t, args, ?loc = r) |> Some
+
+    // arrays passed as IEnumerable<'T> are compared item by item
+    let (|ArrayArg|_|) =
+        function
+        | TypeCast(ExprType(Array _) as e, _)
+        | (ExprType(Array _) as e) -> Some e
+        | _ -> None
+
+    // the exception type is checked by its .NET name, see Exception.raise
+    let exceptionTypeName () =
+        match i.GenericArgs with
+        | [ DeclaredType(entRef, _) ] -> makeStrConst entRef.FullName
+        | _ -> makeStrConst Types.exception_
+
+    match i.CompiledName, args with
+    | ("Equal" | "AreEqual"),
+      [ ExprType(Number(Float64, _)) as e
+        a
+        ExprType(Number(Int32, _)) as precision ] ->
+        assertCall "equalPrecision" [ e; a; precision ]
+    | ("Equal" | "AreEqual"),
+      [ ExprType(Number(Float64, _)) as e
+        a
+        ExprType(Number(Float64, _)) as tolerance ] ->
+        assertCall "equalTolerance" [ e; a; tolerance ]
+    | ("Equal" | "AreEqual"), [ ArrayArg e; ArrayArg a ] ->
+        assertCall "seqEqual" [ e; a ]
+    | ("Equal" | "AreEqual"), [ e; a ] -> assertCall "equal" [ e; a ]
+    | ("NotEqual" | "AreNotEqual"), [ e; a ] -> assertCall "notEqual" [ e; a ]
+    | ("True" | "IsTrue"), cond :: _ -> assertCall "isTrue" [ cond ]
+    | ("False" | "IsFalse"), cond :: _ -> assertCall "isFalse" [ cond ]
+    | "Throws", [ f ] -> assertCall "throwsOfType" [ f; exceptionTypeName () ]
+    | "Throws", [ ExprType String as paramName; f ] ->
+        assertCall "throwsWith" [ f; exceptionTypeName (); paramName ]
+    | "ThrowsAny", [ f ] -> assertCall "throws" [ f ]
+    | _ -> None
+
+let rateLimiting
+    (com: ICompiler)
+    (ctx: Context)
+    r
+    t
+    (i: CallInfo)
+    (thisArg: Expr option)
+    (args: Expr list)
+    =
+    let fullName = i.DeclaringEntityFullName
+    let entityName = fullName.Substring(fullName.LastIndexOf('.') + 1)
+
+    match i.CompiledName, thisArg, args with
+    | ".ctor", None, [] when entityName.EndsWith("Options") ->
+        makeStaticLibCall
+            com
+            r
+            t
+            i
+            "RateLimiting"
+            "RateLimiterOptions::new_"
+            args
+        |> Some
+    | ".ctor", None, [ _ ] ->
+        let meth =
+            match entityName with
+            | "TokenBucketRateLimiter" -> "tokenBucket"
+            | "FixedWindowRateLimiter" -> "fixedWindow"
+            | "SlidingWindowRateLimiter" -> "slidingWindow"
+            | _ -> "concurrency"
+
+        Helper.LibCall(com, "RateLimiting", meth, t, args, ?loc = r) |> Some
+    // the cancellation token is ignored
+    | ("AttemptAcquire" | "AcquireAsync" as meth), Some callee, args ->
+        let permitCount =
+            match args with
+            | ExprType(Number _) as count :: _ -> count
+            | _ -> makeIntConst 1
+
+        makeInstanceCall r t i callee (Naming.lowerFirst meth) [ permitCount ]
+        |> Some
+    | Naming.StartsWith "set_" meth, Some callee, [ _ ] ->
+        makeInstanceCall r t i callee ("set_" + Naming.lowerFirst meth) args
+        |> Some
+    | meth, Some callee, _ ->
+        let meth = Naming.removeGetSetPrefix meth |> Naming.lowerFirst
+        makeInstanceCall r t i callee meth args |> Some
+    | _ -> None
+
+let metrics
+    (com: ICompiler)
+    (ctx: Context)
+    r
+    t
+    (i: CallInfo)
+    (thisArg: Expr option)
+    (args: Expr list)
+    =
+    // optional unit, description and version default to null
+    let strArg =
+        function
+        | Some(Value(Null _, _))
+        | None -> makeStrConst ""
+        | Some arg -> arg
+
+    let tagType = Tuple([ String; Any ], true)
+
+    match i.CompiledName, thisArg, args with
+    | ".ctor", None, ExprType(String) as name :: rest ->
+        let version = List.tryHead rest |> strArg
+
+        makeStaticLibCall com r t i "Metrics" "Meter::new" [ name; version ]
+        |> Some
+    | ("CreateCounter" | "CreateUpDownCounter" | "CreateHistogram" | "CreateGauge" as meth),
+      Some callee,
+      name :: rest ->
+        let unit = List.tryItem 0 rest |> strArg
+        let description = List.tryItem 1 rest |> strArg
+
+        makeInstanceCall
+            r
+            t
+            i
+            callee
+            (Naming.lowerFirst meth)
+            [ name; unit; description ]
+        |> Some
+    // only the overloads observing a single value are supported
+    | Naming.StartsWith "CreateObservable" _ as meth,
+      Some callee,
+      name :: (ExprType(DelegateType(_, Number _)) as observe) :: rest ->
+        let unit = List.tryItem 0 rest |> strArg
+        let description = List.tryItem 1 rest |> strArg
+
+        makeInstanceCall
+            r
+            t
+            i
+            callee
+            (Naming.lowerFirst meth)
+            [ name; observe; unit; description ]
+        |> Some
+    | ("Add" | "Record" as meth), Some callee, value :: tags ->
+        let tags =
+            match tags with
+            | [ ExprType(Array _) as tags ] -> Some tags
+            | tags when
+                tags
+                |> List.forall (
+                    function
+                    | ExprType(Builtin(BclKeyValuePair _)) -> true
+                    | _ -> false
+                )
+                ->
+                makeArray tagType tags |> Some
+            | _ -> None // TagList is not supported
+
+        tags
+        |> Option.map (fun tags ->
+            let meth = Naming.lowerFirst meth
+            makeInstanceCall r t i callee meth [ value; tags ]
+        )
+    | ("get_Name" | "get_Version" | "get_Unit" | "get_Description" | "get_Enabled" | "get_IsObservable" as meth),
+      Some callee,
+      [] ->
+        let meth = Naming.removeGetSetPrefix meth |> Naming.lowerFirst
+        makeInstanceCall r t i callee meth [] |> Some
+    | "Dispose", Some callee, [] ->
+        makeInstanceCall r t i callee "dispose" [] |> Some
+    | _ -> None
+
 let encoding
     (com: ICompiler)
     (ctx: Context)
@@ -5430,7 +7181,26 @@ let encoding
     (args: Expr list)
     =
     match i.CompiledName, thisArg, args with
-    | ("get_Unicode" | "get_UTF8"), _, _ ->
+    | ".ctor", _, _ ->
+        match i.DeclaringEntityFullName, args with
+        | "System.Text.UTF8Encoding", [] ->
+            Helper.LibCall(com, "Encoding", "newUTF8", t, [ makeBoolConst false; makeBoolConst false ], ?loc = r)
+            |> Some
+        | "System.Text.UTF8Encoding", [ bom ] ->
+            Helper.LibCall(com, "Encoding", "newUTF8", t, [ bom; makeBoolConst false ], ?loc = r)
+            |> Some
+        | "System.Text.UTF8Encoding", [ _; _ ] -> Helper.LibCall(com, "Encoding", "newUTF8", t, args, ?loc = r) |> Some
+        | "System.Text.UnicodeEncoding", [] ->
+            let args = [ makeBoolConst false; makeBoolConst true; makeBoolConst false ]
+            Helper.LibCall(com, "Encoding", "newUnicode", t, args, ?loc = r) |> Some
+        | "System.Text.UnicodeEncoding", [ _; _ ] ->
+            Helper.LibCall(com, "Encoding", "newUnicode", t, args @ [ makeBoolConst false ], ?loc = r)
+            |> Some
+        | "System.Text.UnicodeEncoding", [ _; _; _ ] ->
+            Helper.LibCall(com, "Encoding", "newUnicode", t, args, ?loc = r) |> Some
+        | "System.Text.ASCIIEncoding", [] -> Helper.LibCall(com, "Encoding", "get_ASCII", t, [], ?loc = r) |> Some
+        | _ -> None
+    | ("get_Unicode" | "get_BigEndianUnicode" | "get_UTF8" | "get_ASCII" | "get_Latin1"), None, _ ->
         Helper.LibCall(
             com,
             "Encoding",
@@ -5441,6 +7211,18 @@ let encoding
             ?loc = r
         )
         |> Some
+    | "GetEncoding", None, [ arg ] ->
+        let meth =
+            match arg.Type with
+            | Number _ -> "getEncodingFromCodePage"
+            | _ -> "getEncoding"
+
+        Helper.LibCall(com, "Encoding", meth, t, args, i.SignatureArgTypes, ?loc = r)
+        |> Some
+    | ("get_CodePage" | "get_WebName" as meth), Some callee, [] ->
+        let meth = Naming.removeGetSetPrefix meth |> Naming.lowerFirst
+        makeInstanceCall r t i callee meth [] |> Some
+    | "GetPreamble", Some callee, [] -> makeInstanceCall r t i callee "getPreamble" [] |> Some
     | ("GetBytes" | "GetByteCount"), Some callee, ExprType(Array(Char, _)) :: _ ->
         let meth = Naming.lowerFirst i.CompiledName + "FromChars"
 
@@ -6066,21 +7848,26 @@ let controlExtensions
     (thisArg: Expr option)
     (args: Expr list)
     =
-    match i.CompiledName with
-    | "AddToObservable" -> Some "add"
-    | "SubscribeToObservable" -> Some "subscribe"
-    | _ -> None
-    |> Option.map (fun meth ->
-        let args, argTypes =
-            thisArg
-            |> Option.map (fun thisArg ->
-                thisArg :: args, thisArg.Type :: i.SignatureArgTypes
-            )
-            |> Option.defaultValue (args, i.SignatureArgTypes)
-            |> fun (args, argTypes) -> List.rev args, List.rev argTypes
+    match i.CompiledName, thisArg with
+    // the Elapsed event of System.Timers.Timer is erased to the timer
+    | "AddToObservable", Some(ExprType(Builtin BclTimer) as timer) ->
+        Helper.InstanceCall(timer, "add_Elapsed", t, args) |> Some
+    | meth, _ ->
+        match meth with
+        | "AddToObservable" -> Some "add"
+        | "SubscribeToObservable" -> Some "subscribe"
+        | _ -> None
+        |> Option.map (fun meth ->
+            let args, argTypes =
+                thisArg
+                |> Option.map (fun thisArg ->
+                    thisArg :: args, thisArg.Type :: i.SignatureArgTypes
+                )
+                |> Option.defaultValue (args, i.SignatureArgTypes)
+                |> fun (args, argTypes) -> List.rev args, List.rev argTypes
 
-        Helper.LibCall(com, "Observable", meth, t, args, argTypes)
-    )
+            Helper.LibCall(com, "Observable", meth, t, args, argTypes)
+        )
 
 let types
     (com: ICompiler)
@@ -6373,6 +8160,8 @@ let tryField com t ownerTyp fieldName =
         match ent.FullName with
         | "System.BitConverter" ->
             Helper.LibCall(com, "BitConverter", meth, t, []) |> Some
+        | Types.stopwatch ->
+            makeStaticFieldCall com None t "Stopwatch" "Stopwatch" meth |> Some
         | _ -> None
     | _ -> None
 
@@ -6400,7 +8189,7 @@ let private replacedModules =
             Types.char, chars
             Types.string, strings
             "Microsoft.FSharp.Core.StringModule", stringModule
-            "System.FormattableString", formattableString
+            Types.formattableString, formattableString
             "System.Runtime.CompilerServices.FormattableStringFactory",
             formattableString
             "System.Text.StringBuilder", stringBuilder
@@ -6439,6 +8228,7 @@ let private replacedModules =
             Types.icollection, collections
             "System.Collections.Generic.CollectionExtensions",
             collectionExtensions
+            "System.Span`1", readOnlySpans
             "System.ReadOnlySpan`1", readOnlySpans
             Types.hashset, hashSets
             Types.stack, bclType
@@ -6481,22 +8271,54 @@ let private replacedModules =
             Types.dateOnly, dateOnly
             Types.timeOnly, timeOnly
             Types.timespan, timeSpans
+            Types.timeZoneInfo, timeZones
+            "System.Globalization.ISOWeek", isoWeeks
+            "System.Globalization.CharUnicodeInfo", chars
+            Types.rune, runes
+            Types.stringInfo, globalization
+            Types.textElementEnumerator, globalization
             "System.Timers.Timer", timers
+            "System.Threading.Timer", timers
             "System.Environment", systemEnv
-            "System.Globalization.CultureInfo", globalization
+            Types.cultureInfo, globalization
+            Types.numberFormatInfo, globalization
+            Types.dateTimeFormatInfo, globalization
+            Types.compareInfo, globalization
+            Types.sortKey, globalization
+            Types.regionInfo, globalization
+            Types.calendar, calendars
+            Types.gregorianCalendar, calendars
             "System.Random", random
+            Types.stopwatch, stopwatch
+            Types.compositeFormat, compositeFormats
+            Types.standardFormat, standardFormat
+            "System.Buffers.Text.Utf8Parser", utf8Text
+            "System.Buffers.Text.Utf8Formatter", utf8Text
+            "Microsoft.Extensions.ObjectPool.ObjectPool", objectPools
+            Types.objectPool, objectPools
+            Types.defaultObjectPool, objectPools
+            Types.iPooledObjectPolicy, pooledObjectPolicies
+            "Xunit.Assert", asserts
+            Types.stream, streams
+            Types.memoryStream, streams
+            "System.IO.File", files
+            Types.fileInfo, files
+            "NUnit.Framework.Assert", asserts
             "System.Threading.CancellationToken", cancels
             "System.Threading.CancellationTokenSource", cancels
             "System.Threading.Monitor", monitor
             Types.task, tasks
             Types.taskGeneric, tasks
+            Types.valueTaskGeneric, valueTasks
             Types.thread, threads
+            Types.periodicTimer, periodicTimers
             "System.Threading.Tasks.TaskCompletionSource`1", tasks
             "System.Runtime.CompilerServices.TaskAwaiter`1", tasks
             "System.Activator", activator
             "System.Text.Encoding", encoding
             "System.Text.UnicodeEncoding", encoding
             "System.Text.UTF8Encoding", encoding
+            "System.Text.ASCIIEncoding", encoding
             Types.regex, regex
             Types.regexMatch, regex
             Types.regexGroup, regex
@@ -6554,9 +8376,15 @@ let tryCall
         fableCoreLib com ctx r t info thisArg args
     | Naming.EndsWith "Exception" _ -> exceptions com ctx r t info thisArg args
     | "System.Timers.ElapsedEventArgs" -> thisArg // only signalTime is available here
+    | Naming.StartsWith Types.rateLimiting _ ->
+        rateLimiting com ctx r t info thisArg args
+    | Naming.StartsWith Types.metrics _ ->
+        metrics com ctx r t info thisArg args
     | Naming.StartsWith "System.Tuple" _
     | Naming.StartsWith "System.ValueTuple" _ ->
         tuples com ctx r t info thisArg args
+    | "System.Runtime.CompilerServices.ITuple" ->
+        iTuples com ctx r t info thisArg args
     | Naming.StartsWith "System.Action" _
     | Naming.StartsWith "System.Func" _
     | Naming.StartsWith "Microsoft.FSharp.Core.FSharpFunc" _
@@ -203,6 +203,9 @@ module Types =
     [<Literal>]
     let timeOnly = "System.TimeOnly"
 
+    [<Literal>]
+    let timeZoneInfo = "System.TimeZoneInfo"
+
     [<Literal>]
     let int8 = "System.SByte"
 
@@ -276,6 +279,64 @@ module Types =
     let regexCaptureCollection =
         "System.Text.RegularExpressions.CaptureCollection"
 
+    [<Literal>]
+    let cultureInfo = "System.Globalization.CultureInfo"
+
+    [<Literal>]
+    let numberFormatInfo = "System.Globalization.NumberFormatInfo"
+
+    [<Literal>]
+    let dateTimeFormatInfo = "System.Globalization.DateTimeFormatInfo"
+
+    [<Literal>]
+    let compareInfo = "System.Globalization.CompareInfo"
+
+    [<Literal>]
+    let sortKey = "System.Globalization.SortKey"
+
+    [<Literal>]
+    let regionInfo = "System.Globalization.RegionInfo"
+
+    [<Literal>]
+    let stringInfo = "System.Globalization.StringInfo"
+
+    [<Literal>]
+    let textElementEnumerator = "System.Globalization.TextElementEnumerator"
+
+    [<Literal>]
+    let calendar = "System.Globalization.Calendar"
+
+    [<Literal>]
+    let gregorianCalendar = "System.Globalization.GregorianCalendar"
+
+    [<Literal>]
+    let random = "System.Random"
+
+    [<Literal>]
+    let standardFormat = "System.Buffers.StandardFormat"
+
+    [<Literal>]
+    let objectPool = "Microsoft.Extensions.ObjectPool.ObjectPool`1"
+
+    [<Literal>]
+    let defaultObjectPool = "Microsoft.Extensions.ObjectPool.DefaultObjectPool`1"
+
+    [<Literal>]
+    let iPooledObjectPolicy =
+        "Microsoft.Extensions.ObjectPool.IPooledObjectPolicy`1"
+
+    [<Literal>]
+    let rateLimiting = "System.Threading.RateLimiting"
+
+    [<Literal>]
+    let metrics = "System.Diagnostics.Metrics"
+
+    [<Literal>]
+    let stopwatch = "System.Diagnostics.Stopwatch"
+
+    [<Literal>]
+    let periodicTimer = "System.Threading.PeriodicTimer"
+
     [<Literal>]
     let unit = "Microsoft.FSharp.Core.Unit"
 
@@ -373,6 +434,9 @@ module Types =
     [<Literal>]
     let taskGeneric = "System.Threading.Tasks.Task`1"
 
+    [<Literal>]
+    let valueTaskGeneric = "System.Threading.Tasks.ValueTask`1"
+
     [<Literal>]
     let thread = "System.Threading.Thread"
 
@@ -424,6 +488,30 @@ module Types =
     [<Literal>]
     let iformattable = "System.IFormattable"
 
+    [<Literal>]
+    let formattableString = "System.FormattableString"
+
+    [<Literal>]
+    let compositeFormat = "System.Text.CompositeFormat"
+
+    [<Literal>]
+    let rune = "System.Text.Rune"
+
+    [<Literal>]
+    let stringRuneEnumerator = "System.Text.StringRuneEnumerator"
+
+    [<Literal>]
+    let stream = "System.IO.Stream"
+
+    [<Literal>]
+    let memoryStream = "System.IO.MemoryStream"
+
+    [<Literal>]
+    let fileInfo = "System.IO.FileInfo"
+
+    [<Literal>]
+    let rustFuture = "Fable.Core.RustInterop.Future`1"
+
     [<Literal>]
     let iformatProvider = "System.IFormatProvider"
 
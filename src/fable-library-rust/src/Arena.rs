@@ -0,0 +1,159 @@
+#[cfg(all(feature = "arena", not(feature = "no_std")))]
+pub mod Arena_ {
+    // -----------------------------------------------------------
+    // Arena (bump) allocation scopes
+    // -----------------------------------------------------------
+
+    // Small allocations made inside a `withArena` scope are bump-allocated
+    // from 64 KB chunks, and deallocation only decrements the chunk's
+    // count of live blocks. A chunk is released as a whole once the scope
+    // has moved past it and all its blocks are freed, so values escaping
+    // the scope stay valid (they just keep their chunk alive).
+    // The scope only takes effect when `ArenaAlloc` is the global allocator (see README).
+
+    use crate::Native_::Func0;
+    use core::alloc::{GlobalAlloc, Layout};
+    use core::cell::Cell;
+    use core::ptr::null_mut;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use std::alloc::System;
+
+    const CHUNK_SIZE: usize = 64 * 1024;
+    const MAX_ARENA_SIZE: usize = 4 * 1024;
+    const MIN_ALIGN: usize = 16;
+    const TAG_SIZE: usize = core::mem::size_of::<*mut Chunk>();
+
+    // Chunk header, followed by the bump-allocated blocks.
+    // `live` counts the blocks not yet freed, plus one
+    // while the chunk is the current chunk of the thread.
+    #[repr(C, align(16))]
+    struct Chunk {
+        live: AtomicUsize,
+    }
+
+    // Every block is preceded by a tag with the address of its chunk,
+    // or null for blocks that come from the system allocator.
+
+    thread_local! {
+        static depth: Cell<usize> = const { Cell::new(0) };
+        static current: Cell<*mut Chunk> = const { Cell::new(null_mut()) };
+        static next_pos: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn chunk_layout() -> Layout {
+        unsafe { Layout::from_size_align_unchecked(CHUNK_SIZE, MIN_ALIGN) }
+    }
+
+    unsafe fn new_chunk() -> *mut Chunk {
+        let chunk = System.alloc(chunk_layout()) as *mut Chunk;
+        if !chunk.is_null() {
+            chunk.write(Chunk {
+                live: AtomicUsize::new(1),
+            });
+        }
+        chunk
+    }
+
+    unsafe fn release(chunk: *mut Chunk) {
+        if (*chunk).live.fetch_sub(1, Ordering::AcqRel) == 1 {
+            System.dealloc(chunk as *mut u8, chunk_layout());
+        }
+    }
+
+    fn align_up(pos: usize, align: usize) -> usize {
+        (pos + align - 1) & !(align - 1)
+    }
+
+    unsafe fn arena_alloc(layout: Layout, align: usize) -> *mut u8 {
+        let mut chunk = current.get();
+        let mut start = align_up(next_pos.get() + TAG_SIZE, align);
+        if chunk.is_null() || start + layout.size() > chunk as usize + CHUNK_SIZE {
+            let new = new_chunk();
+            if new.is_null() {
+                return null_mut();
+            }
+            if !chunk.is_null() {
+                release(chunk);
+            }
+            chunk = new;
+            current.set(chunk);
+            start = align_up(chunk as usize + core::mem::size_of::<Chunk>() + TAG_SIZE, align);
+        }
+        (*chunk).live.fetch_add(1, Ordering::Relaxed);
+        next_pos.set(start + layout.size());
+        let ptr = start as *mut u8;
+        (ptr.sub(TAG_SIZE) as *mut *mut Chunk).write(chunk);
+        ptr
+    }
+
+    fn system_layout(layout: Layout, align: usize) -> Option<Layout> {
+        let size = layout.size().checked_add(align)?;
+        Layout::from_size_align(size, align).ok()
+    }
+
+    fn in_scope() -> bool {
+        depth.try_with(|d| d.get() > 0).unwrap_or(false)
+    }
+
+    /// Global allocator that serves the allocations made inside `withArena` scopes.
+    /// Outside of them, it forwards to the system allocator.
+    pub struct ArenaAlloc;
+
+    unsafe impl GlobalAlloc for ArenaAlloc {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let align = layout.align().max(MIN_ALIGN);
+            if in_scope() && layout.size() + align <= MAX_ARENA_SIZE {
+                let ptr = arena_alloc(layout, align);
+                if !ptr.is_null() {
+                    return ptr;
+                }
+            }
+            match system_layout(layout, align) {
+                Some(sys_layout) => {
+                    let base = System.alloc(sys_layout);
+                    if base.is_null() {
+                        return base;
+                    }
+                    let ptr = base.add(align);
+                    (ptr.sub(TAG_SIZE) as *mut *mut Chunk).write(null_mut());
+                    ptr
+                }
+                None => null_mut(),
+            }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            let chunk = (ptr.sub(TAG_SIZE) as *const *mut Chunk).read();
+            if chunk.is_null() {
+                let align = layout.align().max(MIN_ALIGN);
+                let sys_layout = Layout::from_size_align_unchecked(layout.size() + align, align);
+                System.dealloc(ptr.sub(align), sys_layout);
+            } else {
+                release(chunk);
+            }
+        }
+    }
+
+    struct ScopeGuard;
+
+    impl Drop for ScopeGuard {
+        fn drop(&mut self) {
+            let d = depth.get() - 1;
+            depth.set(d);
+            if d == 0 {
+                let chunk = current.replace(null_mut());
+                if !chunk.is_null() {
+                    unsafe { release(chunk) };
+                }
+            }
+        }
+    }
+
+    /// Runs `f` in an arena scope. Scopes can be nested, and they
+    /// are per thread (other threads keep using the system allocator).
+    pub fn withArena<T: 'static>(f: Func0<T>) -> T {
+        depth.set(depth.get() + 1);
+        let _guard = ScopeGuard;
+        f()
+    }
+}
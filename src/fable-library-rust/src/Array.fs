@@ -1087,38 +1087,44 @@ let countBy<'T, 'Key when 'Key: equality>
     (xs: 'T[])
     : ('Key * int)[]
     =
+    // maps each key to its index, so each item is hashed only once
     let dict = System.Collections.Generic.Dictionary<'Key, int>()
     let keys = ResizeArray<'Key>()
+    let counts = ResizeArray<int>()
 
     for x in xs do
         let key = projection x
 
         match dict.TryGetValue(key) with
-        | true, prev -> dict[key] <- prev + 1
+        | true, i -> counts[i] <- counts[i] + 1
         | false, _ ->
-            dict[key] <- 1
+            dict.Add(key, keys.Count)
             keys.Add(key)
+            counts.Add(1)
 
-    keys |> asArray |> map (fun key -> key, dict[key])
+    keys |> asArray |> mapIndexed (fun i key -> key, counts[i])
 
 let groupBy<'T, 'Key when 'Key: equality>
     (projection: 'T -> 'Key)
     (xs: 'T[])
     : ('Key * 'T[])[]
     =
-    let dict = System.Collections.Generic.Dictionary<'Key, ResizeArray<'T>>()
+    // maps each key to its index, so each item is hashed only once
+    let dict = System.Collections.Generic.Dictionary<'Key, int>()
     let keys = ResizeArray<'Key>()
+    let groups = ResizeArray<ResizeArray<'T>>()
 
     for x in xs do
         let key = projection x
 
         match dict.TryGetValue(key) with
-        | true, prev -> prev.Add(x)
+        | true, i -> groups[i].Add(x)
         | false, _ ->
-            dict.Add(key, ResizeArray [| x |])
+            dict.Add(key, keys.Count)
             keys.Add(key)
+            groups.Add(ResizeArray [| x |])
 
-    keys |> asArray |> map (fun key -> key, dict[key] |> asArray)
+    keys |> asArray |> mapIndexed (fun i key -> key, groups[i] |> asArray)
 
 let insertAt (index: int) (y: 'T) (xs: 'T[]) : 'T[] =
     let len = xs.Length
@@ -0,0 +1,133 @@
+#[cfg(not(feature = "no_std"))]
+pub mod Assert_ {
+
+    // -----------------------------------------------------------
+    // Test assertions
+    // -----------------------------------------------------------
+
+    // Assertions panic with xUnit-like messages, so F# test suites report
+    // failures the same way when they are run natively with `cargo test`.
+
+    use crate::Exception_::{try_catch, try_catch_typed};
+    use crate::NativeArray_::Array;
+    use crate::Native_::{Func0, LrcPtr};
+    use crate::String_::string;
+    use crate::System::Exception;
+    use core::fmt::Debug;
+
+    pub fn equal<T: PartialEq + Debug>(expected: T, actual: T) {
+        if expected != actual {
+            panic!("Assert.Equal() Failure\nExpected: {:?}\nActual:   {:?}", expected, actual);
+        }
+    }
+
+    pub fn notEqual<T: PartialEq + Debug>(expected: T, actual: T) {
+        if expected == actual {
+            panic!("Assert.NotEqual() Failure\nExpected: Not {:?}\nActual:   {:?}", expected, actual);
+        }
+    }
+
+    pub fn isTrue(condition: bool) {
+        if !condition {
+            panic!("Assert.True() Failure\nExpected: True\nActual:   False");
+        }
+    }
+
+    pub fn isFalse(condition: bool) {
+        if condition {
+            panic!("Assert.False() Failure\nExpected: False\nActual:   True");
+        }
+    }
+
+    // like xUnit, both values are rounded to the given number of decimal places
+    pub fn equalPrecision(expected: f64, actual: f64, precision: i32) {
+        let scale = 10f64.powi(precision);
+        let e = (expected * scale).round() / scale;
+        let a = (actual * scale).round() / scale;
+        if e != a && !(e.is_nan() && a.is_nan()) {
+            panic!(
+                "Assert.Equal() Failure: Values are not within {} decimal places\nExpected: {} (rounded from {})\nActual:   {} (rounded from {})",
+                precision, e, expected, a, actual
+            );
+        }
+    }
+
+    pub fn equalTolerance(expected: f64, actual: f64, tolerance: f64) {
+        if (expected - actual).abs() > tolerance && !(expected.is_nan() && actual.is_nan()) {
+            panic!(
+                "Assert.Equal() Failure: Values are not within tolerance {}\nExpected: {}\nActual:   {}",
+                tolerance, expected, actual
+            );
+        }
+    }
+
+    // reports the first index where the collections differ
+    pub fn seqEqual<T: Clone + PartialEq + Debug>(expected: Array<T>, actual: Array<T>) {
+        let (e, a) = (expected.as_slice(), actual.as_slice());
+        match e.iter().zip(a.iter()).position(|(x, y)| x != y) {
+            Some(i) => panic!(
+                "Assert.Equal() Failure: Collections differ at index {}\nExpected: {:?}\nActual:   {:?}\nExpected item: {:?}\nActual item:   {:?}",
+                i, e, a, e[i], a[i]
+            ),
+            None if e.len() != a.len() => panic!(
+                "Assert.Equal() Failure: Collections differ in length ({} vs {})\nExpected: {:?}\nActual:   {:?}",
+                e.len(), a.len(), e, a
+            ),
+            None => (),
+        }
+    }
+
+    pub fn throws(f: Func0<()>) -> LrcPtr<Exception> {
+        let ex = try_catch(
+            std::panic::AssertUnwindSafe(|| {
+                f();
+                None
+            }),
+            Some,
+        );
+        match ex {
+            Some(ex) => ex,
+            None => panic!("Assert.Throws() Failure: No exception was thrown"),
+        }
+    }
+
+    // like xUnit, the exception must be exactly of the expected type;
+    // panics from the library don't carry a type, so they match any type
+    pub fn throwsOfType(f: Func0<()>, typeName: string) -> LrcPtr<Exception> {
+        let ex = try_catch_typed(
+            std::panic::AssertUnwindSafe(|| {
+                f();
+                None
+            }),
+            |ex, actual| Some((ex, actual)),
+        );
+        match ex {
+            Some((_, Some(actual))) if actual != typeName.as_str() => panic!(
+                "Assert.Throws() Failure: Exception type was not an exact match\nExpected: typeof({})\nActual:   typeof({})",
+                typeName, actual
+            ),
+            Some((ex, _)) => ex,
+            None => panic!(
+                "Assert.Throws() Failure: No exception was thrown\nExpected: typeof({})",
+                typeName
+            ),
+        }
+    }
+
+    // checks the parameter name that ArgumentException appends to its message
+    pub fn throwsWith(f: Func0<()>, typeName: string, paramName: string) -> LrcPtr<Exception> {
+        let ex = throwsOfType(f, typeName);
+        let message = ex.get_Message();
+        let actual = message
+            .strip_suffix("')")
+            .and_then(|s| s.rsplit_once(" (Parameter '"))
+            .map(|(_, name)| name);
+        if actual != Some(paramName.as_str()) {
+            panic!(
+                "Assert.Throws() Failure: Incorrect parameter name\nExpected: {:?}\nActual:   {:?}",
+                paramName, actual.unwrap_or("")
+            );
+        }
+        ex
+    }
+}
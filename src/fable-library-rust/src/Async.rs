@@ -1,3 +1,6 @@
+#[cfg(all(feature = "threaded", target_arch = "wasm32", not(target_feature = "atomics")))]
+compile_error!("The `threaded` feature requires a wasm target with threads (e.g. wasm32-wasip1-threads).");
+
 #[cfg(feature = "threaded")]
 pub mod Async_ {
     use std::future::{self, ready, Future};
@@ -65,6 +68,56 @@ pub mod Async_ {
             future: Arc::from(Mutex::from(a)),
         })
     }
+
+    // -----------------------------------------------------------
+    // Rust futures interop
+    // -----------------------------------------------------------
+
+    pub type BoxFuture<T> = futures::future::BoxFuture<'static, T>;
+
+    // Most Rust futures (tokio, hyper, reqwest...) are Send but not Sync.
+    // A future is only ever polled through `Pin<&mut Self>`, so sharing
+    // the wrapper between threads never gives access to `&F`.
+    pub struct SyncFuture<F>(F);
+
+    unsafe impl<F: Send> Sync for SyncFuture<F> {}
+
+    impl<F: Future> SyncFuture<F> {
+        pub fn new(fut: F) -> SyncFuture<F> {
+            SyncFuture(fut)
+        }
+    }
+
+    impl<F: Future> Future for SyncFuture<F> {
+        type Output = F::Output;
+
+        fn poll(
+            self: Pin<&mut Self>,
+            cx: &mut std::task::Context<'_>,
+        ) -> std::task::Poll<Self::Output> {
+            // SAFETY: the inner future is structurally pinned and never moved
+            unsafe { self.map_unchecked_mut(|s| &mut s.0) }.poll(cx)
+        }
+    }
+
+    pub fn awaitFuture<T: Send + Sync + 'static>(
+        fut: impl Future<Output = T> + Send + 'static,
+    ) -> Arc<Async<T>> {
+        let a: Pin<Box<dyn Future<Output = T> + Send + Sync + 'static>> =
+            Box::pin(SyncFuture::new(fut));
+        Arc::from(Async {
+            future: Arc::from(Mutex::from(a)),
+        })
+    }
+
+    // The returned future runs the computation on the caller's executor,
+    // so Rust futures awaited inside it can rely on e.g. a tokio runtime.
+    pub fn toFuture<T: Clone + Send + Sync + 'static>(a: Arc<Async<T>>) -> BoxFuture<T> {
+        Box::pin(async move {
+            let mut res = a.future.lock().await;
+            res.as_mut().await
+        })
+    }
 }
 
 #[cfg(feature = "threaded")]
@@ -200,6 +253,7 @@ pub mod Task_ {
 
     use futures::{Future, FutureExt};
 
+    use super::Async_::{BoxFuture, SyncFuture};
     use super::ThreadPool::try_init_and_get_pool;
     use crate::Native_::{Func0, Func1};
 
@@ -295,6 +349,13 @@ pub mod Task_ {
             }
         }
 
+        // a task completed externally with set_result
+        pub fn pending() -> Task<T> {
+            Task {
+                result: Arc::from(RwLock::from(TaskState::Running)),
+            }
+        }
+
         pub fn set_result(&self, value: T) {
             let mut m = self.result.write().unwrap();
             //eprintln!("{:?} set task result", thread::current().id());
@@ -383,6 +444,26 @@ pub mod Task_ {
         };
         Arc::from(t)
     }
+
+    // Tasks are hot, so the future is started right away on the thread pool.
+    // Futures that need a specific runtime (e.g. tokio) should be awaited
+    // from an async computation instead, see `Async_::awaitFuture`.
+    pub fn fromFuture<T: Clone + Send + Sync + 'static>(
+        fut: impl Future<Output = T> + Send + 'static,
+    ) -> Arc<Task<T>> {
+        let task = Arc::from(Task::new(SyncFuture::new(fut)));
+        Task::start(task.clone());
+        task
+    }
+
+    pub fn toFuture<T: Clone + Send + Sync + 'static>(t: Arc<Task<T>>) -> BoxFuture<T> {
+        Box::pin(async move {
+            if t.is_new() {
+                Task::start(t.clone());
+            }
+            (&*t).await
+        })
+    }
 }
 
 #[cfg(feature = "threaded")]
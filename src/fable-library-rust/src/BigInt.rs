@@ -1,8 +1,13 @@
 #[cfg(feature = "bigint")]
 pub mod BigInt_ {
+    use crate::Convert_::{self, TryParse};
+    #[cfg(feature = "decimal")]
     use crate::Decimal_::{decimal, truncate};
+    use crate::Globalization_::{
+        parse_radix_digits, AllowBinarySpecifier, AllowHexSpecifier, CultureInfo, NumberFormatInfo,
+    };
     use crate::NativeArray_::{array_from, Array};
-    use crate::Native_::{compare, Lrc, MutCell, ToString, Vec};
+    use crate::Native_::{compare, Lrc, LrcPtr, MutCell, ToString, Vec};
     use crate::String_::{string, toString as toString_1};
 
     use num_bigint::*;
@@ -57,13 +62,33 @@ pub mod BigInt_ {
         };
     }
 
-    macro_rules! shift_op {
+    macro_rules! div_op {
         ($op_trait:ident, $op_fn:ident, $op:tt) => {
+            impl core::ops::$op_trait for bigint {
+                type Output = Self;
+                #[inline]
+                fn $op_fn(self, rhs: Self) -> Self::Output {
+                    if rhs.is_zero() {
+                        panic!("Attempted to divide by zero.");
+                    }
+                    (self.as_ref() $op rhs.as_ref()).into()
+                }
+            }
+        };
+    }
+
+    // like in .NET, shifting by a negative amount shifts in the other direction
+    macro_rules! shift_op {
+        ($op_trait:ident, $op_fn:ident, $op:tt, $rev_op:tt) => {
             impl core::ops::$op_trait<i32> for bigint {
                 type Output = Self;
                 #[inline]
                 fn $op_fn(self, rhs: i32) -> Self::Output {
-                    (self.as_ref() $op rhs).into()
+                    if rhs < 0 {
+                        (self.as_ref() $rev_op rhs.unsigned_abs()).into()
+                    } else {
+                        (self.as_ref() $op rhs as u32).into()
+                    }
                 }
             }
         };
@@ -75,15 +100,15 @@ pub mod BigInt_ {
     bin_op!(Add, add, +);
     bin_op!(Sub, sub, -);
     bin_op!(Mul, mul, *);
-    bin_op!(Div, div, /);
-    bin_op!(Rem, rem, %);
+    div_op!(Div, div, /);
+    div_op!(Rem, rem, %);
 
     bin_op!(BitAnd, bitand, &);
     bin_op!(BitOr, bitor, |);
     bin_op!(BitXor, bitxor, ^);
 
-    shift_op!(Shl, shl, <<);
-    shift_op!(Shr, shr, >>);
+    shift_op!(Shl, shl, <<, >>);
+    shift_op!(Shr, shr, >>, <<);
 
     pub fn zero() -> bigint { BigInt::zero().into() }
     pub fn one() -> bigint { BigInt::one().into() }
@@ -127,7 +152,7 @@ pub mod BigInt_ {
     pub fn isPositive(x: bigint) -> bool { x.is_positive() }
     pub fn isEvenInteger(x: bigint) -> bool { x.is_even() }
     pub fn isOddInteger(x: bigint) -> bool { x.is_odd() }
-    pub fn isPow2(x: bigint) -> bool { (x.clone() & (x - one())).is_zero() }
+    pub fn isPow2(x: bigint) -> bool { x.is_positive() && (x.clone() & (x - one())).is_zero() }
 
     pub fn fromZero() -> bigint { BigInt::zero().into() }
     pub fn fromOne() -> bigint { BigInt::one().into() }
@@ -146,6 +171,7 @@ pub mod BigInt_ {
     pub fn fromFloat32(n: f32) -> bigint { BigInt::from_f32(n).unwrap().into() }
     pub fn fromFloat64(n: f64) -> bigint { BigInt::from_f64(n).unwrap().into() }
 
+    #[cfg(feature = "decimal")]
     pub fn fromDecimal(d: decimal) -> bigint {
         BigInt::from_str_radix(truncate(d).to_string().as_str(), 10).unwrap().into()
     }
@@ -160,18 +186,53 @@ pub mod BigInt_ {
         BigInt::from_u32(c as u32).unwrap().into()
     }
 
+    // accepts the same 0x, 0o and 0b prefixes as the integer conversions
     pub fn fromString(s: string) -> bigint {
-        BigInt::from_str_radix(s.trim(), 10).unwrap().into()
+        Convert_::from_string(s)
     }
 
     pub fn fromByteArray(bytes: Array<u8>) -> bigint {
         BigInt::from_signed_bytes_le(bytes.as_ref()).into()
     }
 
+    pub fn fromByteArray2(bytes: Array<u8>, isUnsigned: bool, isBigEndian: bool) -> bigint {
+        let n = match (isUnsigned, isBigEndian) {
+            (false, false) => BigInt::from_signed_bytes_le(bytes.as_ref()),
+            (false, true) => BigInt::from_signed_bytes_be(bytes.as_ref()),
+            (true, false) => BigInt::from_bytes_le(Sign::Plus, bytes.as_ref()),
+            (true, true) => BigInt::from_bytes_be(Sign::Plus, bytes.as_ref()),
+        };
+        n.into()
+    }
+
     pub fn toByteArray(x: bigint) -> Array<u8> {
         array_from(x.to_signed_bytes_le())
     }
 
+    pub fn toByteArray2(x: bigint, isUnsigned: bool, isBigEndian: bool) -> Array<u8> {
+        if isUnsigned && x.is_negative() {
+            panic!("Negative values do not have an unsigned representation.");
+        }
+        let bytes = match (isUnsigned, isBigEndian) {
+            (false, false) => x.to_signed_bytes_le(),
+            (false, true) => x.to_signed_bytes_be(),
+            (true, false) => x.to_bytes_le().1,
+            (true, true) => x.to_bytes_be().1,
+        };
+        array_from(bytes)
+    }
+
+    pub fn getByteCount(x: bigint, isUnsigned: bool) -> i32 {
+        if isUnsigned && x.is_negative() {
+            panic!("Negative values do not have an unsigned representation.");
+        }
+        if isUnsigned {
+            x.magnitude().to_bytes_le().len() as i32
+        } else {
+            x.to_signed_bytes_le().len() as i32
+        }
+    }
+
     pub fn toInt8(x: bigint) -> i8 { x.to_i8().unwrap() }
     pub fn toUInt8(x: bigint) -> u8 { x.to_u8().unwrap() }
     pub fn toInt16(x: bigint) -> i16 { x.to_i16().unwrap() }
@@ -185,6 +246,7 @@ pub mod BigInt_ {
     pub fn toFloat32(x: bigint) -> f32 { x.to_f32().unwrap() }
     pub fn toFloat64(x: bigint) -> f64 { x.to_f64().unwrap() }
 
+    #[cfg(feature = "decimal")]
     pub fn toDecimal(x: bigint) -> decimal {
         decimal::from_str_radix(x.to_string().as_str(), 10).unwrap()
     }
@@ -201,20 +263,46 @@ pub mod BigInt_ {
         toString_1(&x)
     }
 
-    pub fn tryParse(s: string, res: &MutCell<bigint>) -> bool {
-        match BigInt::from_str_radix(s.trim(), 10) {
-            Ok(d) => { res.set(d.into()); true },
-            Err(e) => false,
+    impl TryParse<bigint> for bigint {
+        fn try_parse(s: &str, radix: i32) -> Option<bigint> {
+            BigInt::from_str_radix(s, radix as u32).ok().map(bigint::from)
         }
-    }
 
-    pub fn parse(s: string) -> bigint {
-        match BigInt::from_str_radix(s.trim(), 10) {
-            Ok(d) => d.into(),
-            Err(e) => panic!("The input string {} was not in a correct format.", s),
+        fn try_parse_style(s: &str, style: i32, nfi: &NumberFormatInfo) -> Option<bigint> {
+            if (style & (AllowHexSpecifier | AllowBinarySpecifier)) != 0 {
+                // like in .NET, a leading digit with the high bit set makes the number negative
+                let (digits, radix) = parse_radix_digits(s, style)?;
+                let n = BigInt::from_str_radix(digits, radix).ok()?;
+                let high = digits.chars().next()?.to_digit(radix)? >= radix / 2;
+                if high {
+                    let bits = digits.len() * radix.trailing_zeros() as usize;
+                    Some((n - (BigInt::one() << bits)).into())
+                } else {
+                    Some(n.into())
+                }
+            } else {
+                let number = nfi.parse_number(s, style)?;
+                BigInt::from_str_radix(&number.to_integer_string()?, 10).ok().map(bigint::from)
+            }
         }
     }
 
+    pub fn tryParse(s: string, style: i32, res: &MutCell<bigint>) -> bool {
+        Convert_::tryParse(s, style, res)
+    }
+
+    pub fn tryParse2(s: string, style: i32, culture: LrcPtr<CultureInfo>, res: &MutCell<bigint>) -> bool {
+        Convert_::tryParse2(s, style, culture, res)
+    }
+
+    pub fn parse(s: string, style: i32) -> bigint {
+        Convert_::parse2(s, style, CultureInfo::currentCulture_())
+    }
+
+    pub fn parse2(s: string, style: i32, culture: LrcPtr<CultureInfo>) -> bigint {
+        Convert_::parse2(s, style, culture)
+    }
+
     pub fn pow(x: bigint, n: i32) -> bigint {
         if n < 0 {
             panic!("The exponent must be greater than or equal to zero.")
@@ -223,19 +311,31 @@ pub mod BigInt_ {
         }
     }
 
+    // the sign of the result is the sign of x^e, like the remainder operator
     pub fn modPow(x: bigint, e: bigint, m: bigint) -> bigint {
-        x.modpow(&e, &m).into()
+        if e.is_negative() {
+            panic!("The number must be greater than or equal to zero. (Parameter 'exponent')");
+        }
+        if m.is_zero() {
+            panic!("Attempted to divide by zero.");
+        }
+        let res = x.magnitude().modpow(e.magnitude(), m.magnitude());
+        let sign = if x.is_negative() && e.is_odd() { Sign::Minus } else { Sign::Plus };
+        BigInt::from_biguint(sign, res).into()
     }
 
     pub fn divRem(x: bigint, y: bigint) -> (bigint, bigint) {
+        if y.is_zero() {
+            panic!("Attempted to divide by zero.");
+        }
         let (div, rem) = x.div_rem(&y);
         (div.into(), rem.into())
     }
 
     pub fn divRemOut(x: bigint, y: bigint, remainder: &MutCell<bigint>) -> bigint {
-        let (div, rem) = x.div_rem(&y);
-        remainder.set(rem.into());
-        div.into()
+        let (div, rem) = divRem(x, y);
+        remainder.set(rem);
+        div
     }
 
     pub fn greatestCommonDivisor(x: bigint, y: bigint) -> bigint {
@@ -254,7 +354,9 @@ pub mod BigInt_ {
             if bits < 1024 {
                 x.to_f64().unwrap().log2()
             } else {
-                (bits - 1) as f64
+                // keep the 64 most significant bits, which fit in a float
+                let shift = bits - 64;
+                (x.as_ref() >> shift).to_f64().unwrap().log2() + shift as f64
             }
         }
     }
@@ -268,18 +370,30 @@ pub mod BigInt_ {
     }
 
     pub fn log(x: bigint, base: f64) -> f64 {
-        log2(x) / base.log2()
+        if base == 1.0 || base < 0.0 || base.is_nan() || x.is_negative() {
+            f64::NAN
+        } else if base == f64::INFINITY || base == 0.0 {
+            if x.is_one() { 0.0 } else { f64::NAN }
+        } else {
+            log2(x) / base.log2()
+        }
     }
 
     pub fn ilog2(x: bigint) -> bigint {
-        fromFloat64(log2(x))
+        if x.is_negative() {
+            panic!("Non-negative number required. (Parameter 'value')");
+        }
+        if x.is_zero() {
+            zero()
+        } else {
+            fromUInt64(x.bits() - 1)
+        }
     }
 
     // pub fn copySign
     // pub fn createChecked
     // pub fn createSaturating
     // pub fn createTruncating
-    // pub fn getByteCount
     // pub fn leadingZeroCount
     // pub fn popCount
     // pub fn rotateLeft
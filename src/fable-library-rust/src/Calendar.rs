@@ -0,0 +1,163 @@
+#[cfg(feature = "datetime")]
+pub mod Calendar_ {
+
+    // -----------------------------------------------------------
+    // System.Globalization.Calendar, GregorianCalendar
+    // -----------------------------------------------------------
+
+    // The Gregorian calendar is the only one implemented, it is also the
+    // calendar of every culture. CalendarWeekRule values are i32 constants.
+
+    use crate::{
+        DateTime_::{DateTime, DayOfWeek},
+        Native_::LrcPtr,
+    };
+    use chrono::NaiveDate;
+
+    pub const CalendarWeekRule_FirstDay: i32 = 0;
+    pub const CalendarWeekRule_FirstFullWeek: i32 = 1;
+    pub const CalendarWeekRule_FirstFourDayWeek: i32 = 2;
+
+    // the year before DateTime.MinValue is assumed to have 365 days, like in .NET
+    const days_in_year_before_min_supported_year: i32 = 365;
+
+    #[derive(Clone, Debug, Default)]
+    pub struct Calendar {}
+
+    pub type GregorianCalendar = Calendar;
+
+    fn check_year(year: i32) {
+        if !(1..=9999).contains(&year) {
+            panic!("Valid values are between 1 and 9999, inclusive. (Parameter 'year')");
+        }
+    }
+
+    // weeks start on `firstDayOfWeek`, the first week has at least `fullDays` days
+    fn week_of_year_full_days(time: &DateTime, firstDayOfWeek: i32, fullDays: i32) -> i32 {
+        let dayOfYear = time.dayOfYear() - 1;
+        let dayForJan1 = time.dayOfWeek() - dayOfYear % 7;
+        let mut offset = (firstDayOfWeek - dayForJan1 + 14) % 7;
+        if offset != 0 && offset >= fullDays {
+            offset -= 7;
+        }
+        let day = dayOfYear - offset;
+        if day >= 0 {
+            day / 7 + 1
+        } else if time.year() == 1 {
+            week_of_year_of_min_supported_date_time(firstDayOfWeek, fullDays)
+        } else {
+            // the last week of the previous year
+            let lastDayOfPreviousYear = time.date().addDays(-(dayOfYear + 1) as f64);
+            week_of_year_full_days(&lastDayOfPreviousYear, firstDayOfWeek, fullDays)
+        }
+    }
+
+    fn week_of_year_of_min_supported_date_time(firstDayOfWeek: i32, fullDays: i32) -> i32 {
+        let dayOfWeekOfFirstOfYear = DateTime::minValue().dayOfWeek();
+        let offset = (firstDayOfWeek + 7 - dayOfWeekOfFirstOfYear) % 7;
+        if offset == 0 || offset >= fullDays {
+            return 1;
+        }
+        let daysInYearBeforeMinSupportedYear = days_in_year_before_min_supported_year - 1;
+        let dayOfWeekOfFirstOfPreviousYear = dayOfWeekOfFirstOfYear - 1 - daysInYearBeforeMinSupportedYear % 7;
+        let daysInInitialPartialWeek = (firstDayOfWeek - dayOfWeekOfFirstOfPreviousYear + 14) % 7;
+        let mut day = daysInYearBeforeMinSupportedYear - daysInInitialPartialWeek;
+        if daysInInitialPartialWeek >= fullDays {
+            day += 7;
+        }
+        day / 7 + 1
+    }
+
+    impl Calendar {
+        pub fn new_() -> LrcPtr<Calendar> {
+            LrcPtr::new(Calendar {})
+        }
+
+        pub fn minSupportedDateTime(&self) -> DateTime {
+            DateTime::minValue()
+        }
+
+        pub fn maxSupportedDateTime(&self) -> DateTime {
+            DateTime::maxValue()
+        }
+
+        pub fn getWeekOfYear__nn(&self, time: DateTime, rule: i32, firstDayOfWeek: i32) -> i32 {
+            if DayOfWeek::try_from(firstDayOfWeek).is_err() {
+                panic!("Valid values are between Sunday and Saturday, inclusive. (Parameter 'firstDayOfWeek')");
+            }
+            match rule {
+                CalendarWeekRule_FirstDay => {
+                    let dayOfYear = time.dayOfYear() - 1;
+                    let dayForJan1 = time.dayOfWeek() - dayOfYear % 7;
+                    let offset = (dayForJan1 - firstDayOfWeek + 14) % 7;
+                    (dayOfYear + offset) / 7 + 1
+                }
+                CalendarWeekRule_FirstFullWeek => week_of_year_full_days(&time, firstDayOfWeek, 7),
+                CalendarWeekRule_FirstFourDayWeek => week_of_year_full_days(&time, firstDayOfWeek, 4),
+                _ => panic!("Valid values are between FirstDay and FirstFourDayWeek, inclusive. (Parameter 'rule')"),
+            }
+        }
+
+        pub fn getYear__(&self, time: DateTime) -> i32 {
+            time.year()
+        }
+
+        pub fn getMonth__(&self, time: DateTime) -> i32 {
+            time.month()
+        }
+
+        pub fn getDayOfMonth__(&self, time: DateTime) -> i32 {
+            time.day()
+        }
+
+        pub fn getDayOfWeek__(&self, time: DateTime) -> i32 {
+            time.dayOfWeek()
+        }
+
+        pub fn getDayOfYear__(&self, time: DateTime) -> i32 {
+            time.dayOfYear()
+        }
+
+        pub fn getDaysInMonth_nn(&self, year: i32, month: i32) -> i32 {
+            check_year(year);
+            if !(1..=12).contains(&month) {
+                panic!("Month must be between one and twelve. (Parameter 'month')");
+            }
+            DateTime::daysInMonth(year, month)
+        }
+
+        pub fn getDaysInYear_n(&self, year: i32) -> i32 {
+            if self.isLeapYear_n(year) {
+                366
+            } else {
+                365
+            }
+        }
+
+        pub fn getMonthsInYear_n(&self, year: i32) -> i32 {
+            check_year(year);
+            12
+        }
+
+        pub fn isLeapYear_n(&self, year: i32) -> bool {
+            check_year(year);
+            NaiveDate::from_ymd_opt(year, 2, 29).is_some()
+        }
+
+        pub fn addDays__n(&self, time: DateTime, days: i32) -> DateTime {
+            time.addDays(days as f64)
+        }
+
+        pub fn addWeeks__n(&self, time: DateTime, weeks: i32) -> DateTime {
+            time.addDays(weeks as f64 * 7.0)
+        }
+
+        pub fn addMonths__n(&self, time: DateTime, months: i32) -> DateTime {
+            time.addMonths(months)
+        }
+
+        pub fn addYears__n(&self, time: DateTime, years: i32) -> DateTime {
+            time.addYears(years)
+        }
+    }
+}
@@ -0,0 +1,188 @@
+pub mod Char_ {
+    use crate::Native_::MutCell;
+
+    // System.Globalization.UnicodeCategory values
+    pub const UppercaseLetter: i32 = 0;
+    pub const LowercaseLetter: i32 = 1;
+    pub const TitlecaseLetter: i32 = 2;
+    pub const ModifierLetter: i32 = 3;
+    pub const OtherLetter: i32 = 4;
+    pub const NonSpacingMark: i32 = 5;
+    pub const SpacingCombiningMark: i32 = 6;
+    pub const EnclosingMark: i32 = 7;
+    pub const DecimalDigitNumber: i32 = 8;
+    pub const LetterNumber: i32 = 9;
+    pub const OtherNumber: i32 = 10;
+    pub const SpaceSeparator: i32 = 11;
+    pub const LineSeparator: i32 = 12;
+    pub const ParagraphSeparator: i32 = 13;
+    pub const Control: i32 = 14;
+    pub const Format: i32 = 15;
+    pub const Surrogate: i32 = 16;
+    pub const PrivateUse: i32 = 17;
+    pub const ConnectorPunctuation: i32 = 18;
+    pub const DashPunctuation: i32 = 19;
+    pub const OpenPunctuation: i32 = 20;
+    pub const ClosePunctuation: i32 = 21;
+    pub const InitialQuotePunctuation: i32 = 22;
+    pub const FinalQuotePunctuation: i32 = 23;
+    pub const OtherPunctuation: i32 = 24;
+    pub const MathSymbol: i32 = 25;
+    pub const CurrencySymbol: i32 = 26;
+    pub const ModifierSymbol: i32 = 27;
+    pub const OtherSymbol: i32 = 28;
+    pub const OtherNotAssigned: i32 = 29;
+
+    #[cfg(feature = "globalization")]
+    pub fn getUnicodeCategory(c: char) -> i32 {
+        use unicode_general_category::{get_general_category, GeneralCategory as G};
+        match get_general_category(c) {
+            G::UppercaseLetter => UppercaseLetter,
+            G::LowercaseLetter => LowercaseLetter,
+            G::TitlecaseLetter => TitlecaseLetter,
+            G::ModifierLetter => ModifierLetter,
+            G::OtherLetter => OtherLetter,
+            G::NonspacingMark => NonSpacingMark,
+            G::SpacingMark => SpacingCombiningMark,
+            G::EnclosingMark => EnclosingMark,
+            G::DecimalNumber => DecimalDigitNumber,
+            G::LetterNumber => LetterNumber,
+            G::OtherNumber => OtherNumber,
+            G::SpaceSeparator => SpaceSeparator,
+            G::LineSeparator => LineSeparator,
+            G::ParagraphSeparator => ParagraphSeparator,
+            G::Control => Control,
+            G::Format => Format,
+            G::Surrogate => Surrogate,
+            G::PrivateUse => PrivateUse,
+            G::ConnectorPunctuation => ConnectorPunctuation,
+            G::DashPunctuation => DashPunctuation,
+            G::OpenPunctuation => OpenPunctuation,
+            G::ClosePunctuation => ClosePunctuation,
+            G::InitialPunctuation => InitialQuotePunctuation,
+            G::FinalPunctuation => FinalQuotePunctuation,
+            G::OtherPunctuation => OtherPunctuation,
+            G::MathSymbol => MathSymbol,
+            G::CurrencySymbol => CurrencySymbol,
+            G::ModifierSymbol => ModifierSymbol,
+            G::OtherSymbol => OtherSymbol,
+            _ => OtherNotAssigned,
+        }
+    }
+
+    // without the Unicode tables, only ASCII symbols and punctuation are told apart
+    #[cfg(not(feature = "globalization"))]
+    pub fn getUnicodeCategory(c: char) -> i32 {
+        match c {
+            _ if c.is_control() => Control,
+            _ if c.is_uppercase() => UppercaseLetter,
+            _ if c.is_lowercase() => LowercaseLetter,
+            _ if c.is_alphabetic() => OtherLetter,
+            _ if c.is_ascii_digit() => DecimalDigitNumber,
+            _ if c.is_numeric() => OtherNumber,
+            '\u{2028}' => LineSeparator,
+            '\u{2029}' => ParagraphSeparator,
+            _ if c.is_whitespace() => SpaceSeparator,
+            '_' => ConnectorPunctuation,
+            '-' => DashPunctuation,
+            '(' | '[' | '{' => OpenPunctuation,
+            ')' | ']' | '}' => ClosePunctuation,
+            '+' | '<' | '=' | '>' | '|' | '~' => MathSymbol,
+            '$' => CurrencySymbol,
+            '^' | '`' => ModifierSymbol,
+            _ if c.is_ascii_punctuation() => OtherPunctuation,
+            _ => OtherSymbol,
+        }
+    }
+
+    pub fn isLetter(c: char) -> bool {
+        (UppercaseLetter..=OtherLetter).contains(&getUnicodeCategory(c))
+    }
+
+    pub fn isDigit(c: char) -> bool {
+        getUnicodeCategory(c) == DecimalDigitNumber
+    }
+
+    pub fn isLetterOrDigit(c: char) -> bool {
+        let cat = getUnicodeCategory(c);
+        (UppercaseLetter..=OtherLetter).contains(&cat) || cat == DecimalDigitNumber
+    }
+
+    pub fn isNumber(c: char) -> bool {
+        (DecimalDigitNumber..=OtherNumber).contains(&getUnicodeCategory(c))
+    }
+
+    pub fn isUpper(c: char) -> bool {
+        getUnicodeCategory(c) == UppercaseLetter
+    }
+
+    pub fn isLower(c: char) -> bool {
+        getUnicodeCategory(c) == LowercaseLetter
+    }
+
+    pub fn isControl(c: char) -> bool {
+        getUnicodeCategory(c) == Control
+    }
+
+    pub fn isPunctuation(c: char) -> bool {
+        (ConnectorPunctuation..=OtherPunctuation).contains(&getUnicodeCategory(c))
+    }
+
+    pub fn isSeparator(c: char) -> bool {
+        (SpaceSeparator..=ParagraphSeparator).contains(&getUnicodeCategory(c))
+    }
+
+    pub fn isSymbol(c: char) -> bool {
+        (MathSymbol..=OtherSymbol).contains(&getUnicodeCategory(c))
+    }
+
+    pub fn isWhiteSpace(c: char) -> bool {
+        match c {
+            '\t'..='\r' | '\u{85}' => true,
+            _ => isSeparator(c),
+        }
+    }
+
+    // System.Text.Rune is a Unicode scalar value, the same as a Rust char
+
+    pub fn isValidRune(value: i32) -> bool {
+        value >= 0 && char::from_u32(value as u32).is_some()
+    }
+
+    pub fn fromCodePoint(value: i32) -> char {
+        match u32::try_from(value).ok().and_then(char::from_u32) {
+            Some(c) => c,
+            None => panic!("Specified argument was out of the range of valid values. (Parameter 'value')"),
+        }
+    }
+
+    pub fn tryFromCodePoint(value: i32, res: &MutCell<char>) -> bool {
+        match u32::try_from(value).ok().and_then(char::from_u32) {
+            Some(c) => {
+                res.set(c);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn toCodePoint(c: char) -> i32 {
+        c as i32
+    }
+
+    pub fn isBmp(c: char) -> bool {
+        (c as u32) < 0x10000
+    }
+
+    pub fn plane(c: char) -> i32 {
+        (c as i32) >> 16
+    }
+
+    pub fn utf8SequenceLength(c: char) -> i32 {
+        c.len_utf8() as i32
+    }
+
+    pub fn utf16SequenceLength(c: char) -> i32 {
+        c.len_utf16() as i32
+    }
+}
@@ -1,6 +1,9 @@
 #[cfg_attr(rustfmt, rustfmt::skip)]
 pub mod Convert_ {
-    use crate::Native_::{MutCell, ToString, Vec};
+    use crate::Globalization_::{
+        parse_radix_digits, AllowBinarySpecifier, AllowHexSpecifier, CultureInfo, NumberFormatInfo,
+    };
+    use crate::Native_::{alloc::format, LrcPtr, MutCell, String, ToString, Vec};
     use crate::NativeArray_::{array_from, Array};
     use crate::String_::{string, fromString, substring};
     use core::fmt::{Display, Binary, Octal, LowerHex};
@@ -8,15 +11,33 @@ pub mod Convert_ {
 
     pub trait TryParse<N>: PartialEq + Default {
         fn try_parse(s: &str, radix: i32) -> Option<N>;
+        // parses with a NumberStyles and the symbols of a culture
+        fn try_parse_style(s: &str, style: i32, nfi: &NumberFormatInfo) -> Option<N>;
+        // the OverflowException message for a well-formed number out of range
+        const OVERFLOW: &'static str = "Arithmetic operation resulted in an overflow.";
     }
 
     macro_rules! int_try_parse_impl {
-        ($($t:ty)*) => ($(
+        ($($t:ty, $u:ty, $name:literal);*) => ($(
             impl TryParse<$t> for $t {
+                const OVERFLOW: &'static str =
+                    concat!("Value was either too large or too small for ", $name, ".");
+
                 #[inline]
                 fn try_parse(s: &str, radix: i32) -> Option<$t> {
                     <$t>::from_str_radix(s, radix as u32).ok()
                 }
+
+                fn try_parse_style(s: &str, style: i32, nfi: &NumberFormatInfo) -> Option<$t> {
+                    if (style & (AllowHexSpecifier | AllowBinarySpecifier)) != 0 {
+                        // the digits are the two's complement bits for signed types
+                        let (digits, radix) = parse_radix_digits(s, style)?;
+                        <$u>::from_str_radix(digits, radix).ok().map(|n| n as $t)
+                    } else {
+                        let number = nfi.parse_number(s, style)?;
+                        <$t>::from_str(&number.to_integer_string()?).ok()
+                    }
+                }
             }
         )*)
     }
@@ -28,21 +49,34 @@ pub mod Convert_ {
                 fn try_parse(s: &str, _radix: i32) -> Option<$t> {
                     <$t>::from_str(s).ok()
                 }
+
+                fn try_parse_style(s: &str, style: i32, nfi: &NumberFormatInfo) -> Option<$t> {
+                    check_float_style(style);
+                    match nfi.parse_float_symbol(s, style) {
+                        Some(x) => Some(x as $t),
+                        None => {
+                            let number = nfi.parse_number(s, style)?;
+                            <$t>::from_str(&number.to_float_string()).ok()
+                        }
+                    }
+                }
             }
         )*)
     }
 
-    int_try_parse_impl!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+    int_try_parse_impl!(
+        i8, u8, "a signed byte"; i16, u16, "an Int16"; i32, u32, "an Int32";
+        i64, u64, "an Int64"; i128, u128, "an Int128"; isize, usize, "an Int64";
+        u8, u8, "an unsigned byte"; u16, u16, "a UInt16"; u32, u32, "a UInt32";
+        u64, u64, "a UInt64"; u128, u128, "a UInt128"; usize, usize, "a UInt64");
     float_try_parse_impl!(f32 f64);
 
-    const AllowHexSpecifier: i32 = 512;
-
-    fn radix_from_style(style: i32) -> i32 {
-        // TODO: more styles
+    pub(crate) fn check_float_style(style: i32) {
         if (style & AllowHexSpecifier) != 0 {
-            16
-        } else {
-            10
+            panic!("The number style AllowHexSpecifier is not supported on floating point data types.");
+        }
+        if (style & AllowBinarySpecifier) != 0 {
+            panic!("The number style AllowBinarySpecifier is not supported on floating point data types.");
         }
     }
 
@@ -60,8 +94,15 @@ pub mod Convert_ {
         }
         match N::try_parse(s.trim(), radix) {
             Some(d) => d,
-            None =>
-                panic!("The input string '{}' was not in a correct format.", s),
+            None => {
+                let digits = s.trim();
+                let digits = digits.strip_prefix(['+', '-']).unwrap_or(digits);
+                if !digits.is_empty() && digits.chars().all(|c| c.is_digit(radix as u32)) {
+                    panic!("{}", N::OVERFLOW)
+                } else {
+                    panic!("The input string '{}' was not in a correct format.", s)
+                }
+            }
         }
     }
 
@@ -73,63 +114,36 @@ pub mod Convert_ {
         }
     }
 
-    fn from_string<N: TryParse<N>>(s: string) -> N {
+    pub(crate) fn from_string<N: TryParse<N>>(s: string) -> N {
         let s = trim_separators(s);
         let (s, radix) = radix_from_string(s);
         from_string_radix(s, radix)
     }
 
-    fn from_style<N: TryParse<N>>(s: string, style: i32) -> N {
-        let radix = radix_from_style(style);
-        from_string_radix(s, radix)
-    }
-
-    fn from_style_i8(s: string, style: i32) -> i8 {
-        if (style & AllowHexSpecifier) != 0 {
-            from_style::<u8>(s, style) as i8
-        } else {
-            from_style(s, style)
-        }
-    }
-
-    fn from_style_i16(s: string, style: i32) -> i16 {
-        if (style & AllowHexSpecifier) != 0 {
-            from_style::<u16>(s, style) as i16
-        } else {
-            from_style(s, style)
-        }
-    }
-
-    fn from_style_i32(s: string, style: i32) -> i32 {
-        if (style & AllowHexSpecifier) != 0 {
-            from_style::<u32>(s, style) as i32
-        } else {
-            from_style(s, style)
-        }
-    }
-
-    fn from_style_i64(s: string, style: i32) -> i64 {
-        if (style & AllowHexSpecifier) != 0 {
-            from_style::<u64>(s, style) as i64
-        } else {
-            from_style(s, style)
-        }
-    }
-
-    fn from_style_i128(s: string, style: i32) -> i128 {
-        if (style & AllowHexSpecifier) != 0 {
-            from_style::<u128>(s, style) as i128
-        } else {
-            from_style(s, style)
+    fn from_style_culture<N: TryParse<N>>(s: string, style: i32, culture: &CultureInfo) -> N {
+        let nfi = culture.number_format();
+        match N::try_parse_style(s.as_str(), style, nfi) {
+            Some(n) => n,
+            None => {
+                // like .NET, tell a number out of range from a malformed one
+                let well_formed =
+                    if (style & (AllowHexSpecifier | AllowBinarySpecifier)) != 0 {
+                        parse_radix_digits(s.as_str(), style).is_some()
+                    } else {
+                        nfi.parse_number(s.as_str(), style).is_some()
+                    };
+                if well_formed {
+                    panic!("{}", N::OVERFLOW)
+                } else {
+                    panic!("The input string '{}' was not in a correct format.", s)
+                }
+            }
         }
     }
 
-    fn from_style_isize(s: string, style: i32) -> isize {
-        if (style & AllowHexSpecifier) != 0 {
-            from_style::<usize>(s, style) as isize
-        } else {
-            from_style(s, style)
-        }
+    // like in .NET, the current culture is used when no culture is given
+    fn from_style<N: TryParse<N>>(s: string, style: i32) -> N {
+        from_style_culture(s, style, &CultureInfo::currentCulture_())
     }
 
     // ----------------------------------------------------
@@ -151,12 +165,12 @@ pub mod Convert_ {
         }
     }
 
-    pub fn parseInt8(s: string, style: i32) -> i8 { from_style_i8(s, style) }
-    pub fn parseInt16(s: string, style: i32) -> i16 { from_style_i16(s, style) }
-    pub fn parseInt32(s: string, style: i32) -> i32 { from_style_i32(s, style) }
-    pub fn parseInt64(s: string, style: i32) -> i64 { from_style_i64(s, style) }
-    pub fn parseInt128(s: string, style: i32) -> i128 { from_style_i128(s, style) }
-    pub fn parseNativeInt(s: string, style: i32) -> isize { from_style_isize(s, style) }
+    pub fn parseInt8(s: string, style: i32) -> i8 { from_style(s, style) }
+    pub fn parseInt16(s: string, style: i32) -> i16 { from_style(s, style) }
+    pub fn parseInt32(s: string, style: i32) -> i32 { from_style(s, style) }
+    pub fn parseInt64(s: string, style: i32) -> i64 { from_style(s, style) }
+    pub fn parseInt128(s: string, style: i32) -> i128 { from_style(s, style) }
+    pub fn parseNativeInt(s: string, style: i32) -> isize { from_style(s, style) }
     pub fn parseUInt8(s: string, style: i32) -> u8 { from_style(s, style) }
     pub fn parseUInt16(s: string, style: i32) -> u16 { from_style(s, style) }
     pub fn parseUInt32(s: string, style: i32) -> u32 { from_style(s, style) }
@@ -167,6 +181,10 @@ pub mod Convert_ {
     pub fn parseFloat32(s: string, style: i32) -> f32 { from_style(s, style) }
     pub fn parseFloat64(s: string, style: i32) -> f64 { from_style(s, style) }
 
+    pub fn parse2<N: TryParse<N>>(s: string, style: i32, culture: LrcPtr<CultureInfo>) -> N {
+        from_style_culture(s, style, &culture)
+    }
+
     pub fn toInt8(s: string) -> i8 { from_string(s) }
     pub fn toInt16(s: string) -> i16 { from_string(s) }
     pub fn toInt32(s: string) -> i32 { from_string(s) }
@@ -218,10 +236,48 @@ pub mod Convert_ {
     }
 
     pub fn tryParse<N: TryParse<N>>(s: string, style: i32, res: &MutCell<N>) -> bool {
-        let radix = radix_from_style(style);
-        match N::try_parse(s.trim(), radix) {
+        tryParse2(s, style, CultureInfo::currentCulture_(), res)
+    }
+
+    pub fn tryParse2<N: TryParse<N>>(s: string, style: i32, culture: LrcPtr<CultureInfo>, res: &MutCell<N>) -> bool {
+        match N::try_parse_style(s.as_str(), style, culture.number_format()) {
             Some(d) => { res.set(d); true }
-            None => false,
+            None => { res.set(N::default()); false }
+        }
+    }
+
+    // -----------------------------------------------------------
+    // Result-based parsing, for Rust callers
+    // -----------------------------------------------------------
+
+    // Returned by the `try_parse_rs` functions instead of panicking,
+    // with the message of the exception thrown by `Parse` in .NET.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct ParseError(String);
+
+    impl ParseError {
+        pub fn new(message: String) -> ParseError {
+            ParseError(message)
+        }
+
+        pub fn message(&self) -> &str {
+            &self.0
+        }
+    }
+
+    impl core::fmt::Display for ParseError {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl core::error::Error for ParseError {}
+
+    // like Int32.Parse or Double.Parse, e.g. `try_parse_rs::<i32>("42")?`
+    pub fn try_parse_rs<N: TryParse<N>>(s: &str) -> Result<N, ParseError> {
+        match N::try_parse(s.trim(), 10) {
+            Some(d) => Ok(d),
+            None => Err(ParseError::new(format!("The input string '{}' was not in a correct format.", s))),
         }
     }
 
@@ -238,7 +294,20 @@ pub mod Convert_ {
         fromString(s)
     }
 
-    pub fn toHexString(bytes: Array<u8>) -> string {
+    fn check_range(count: usize, offset: i32, length: i32) -> (usize, usize) {
+        if length < 0 {
+            panic!("Index was out of range. Must be non-negative and less than the size of the collection. (Parameter 'length')");
+        }
+        if offset < 0 {
+            panic!("Value must be positive. (Parameter 'offset')");
+        }
+        if offset as usize + length as usize > count {
+            panic!("Offset and length must refer to a position in the string. (Parameter 'offset')");
+        }
+        (offset as usize, length as usize)
+    }
+
+    fn encode_hex(bytes: &[u8]) -> string {
         fn encode(d: u8) -> u8 {
             match d {
                 0..=9 => b'0' + d,
@@ -254,6 +323,15 @@ pub mod Convert_ {
         fromString(s)
     }
 
+    pub fn toHexString(bytes: Array<u8>) -> string {
+        encode_hex(&bytes)
+    }
+
+    pub fn toHexStringRange(bytes: Array<u8>, offset: i32, length: i32) -> string {
+        let (offset, length) = check_range(bytes.len(), offset, length);
+        encode_hex(&bytes.as_slice()[offset..offset + length])
+    }
+
     pub fn fromHexString(s: string) -> Array<u8> {
         fn decode(c: u8) -> u8 {
             match c {
@@ -264,6 +342,9 @@ pub mod Convert_ {
             }
         }
         let chars = s.as_bytes();
+        if !chars.len().is_multiple_of(2) {
+            panic!("The input is not a valid hex string as its length is not a multiple of 2.");
+        }
         let bytes = chars
             .chunks_exact(2)
             .map(|x| decode(x[0]) << 4 | decode(x[1]))
@@ -271,102 +352,106 @@ pub mod Convert_ {
         array_from(bytes)
     }
 
-    pub fn toBase64String(bytes: Array<u8>) -> string {
-        fn encode(d: u8) -> u8 {
-            match d {
+    const BASE64_LINE_LENGTH: usize = 76;
+
+    fn encode_base64(bytes: &[u8], insertLineBreaks: bool) -> String {
+        fn encode(d: u8) -> char {
+            let c = match d {
                 0..=25 => b'A' + d,
                 26..=51 => b'a' + d - 26,
                 52..=61 => b'0' + d - 52,
                 62 => b'+',
                 63 => b'/',
-                64 => b'=',
-                _ => unreachable!()
-            }
-        }
-        fn split(chunk: &[u8]) -> [u8; 4] {
-            match chunk.len() {
-                1 => [
-                    chunk[0] >> 2,
-                    (chunk[0] & 0x3) << 4,
-                    64,
-                    64
-                ],
-                2 => [
-                    chunk[0] >> 2,
-                    (chunk[0] & 0x3) << 4 | chunk[1] >> 4,
-                    (chunk[1] & 0xF) << 2,
-                    64
-                ],
-                3 => [
-                    chunk[0] >> 2,
-                    (chunk[0] & 0x3) << 4 | chunk[1] >> 4,
-                    (chunk[1] & 0xF) << 2 | chunk[2] >> 6,
-                    chunk[2] & 0x3F
-                ],
                 _ => unreachable!(),
+            };
+            c as char
+        }
+        let mut res = String::with_capacity(bytes.len().div_ceil(3) * 4);
+        let mut lineLength = 0;
+        for chunk in bytes.chunks(3) {
+            if insertLineBreaks && lineLength == BASE64_LINE_LENGTH {
+                res.push_str("\r\n");
+                lineLength = 0;
             }
+            let b0 = chunk[0];
+            let b1 = chunk.get(1).copied().unwrap_or(0);
+            let b2 = chunk.get(2).copied().unwrap_or(0);
+            res.push(encode(b0 >> 2));
+            res.push(encode((b0 & 0x3) << 4 | b1 >> 4));
+            res.push(if chunk.len() > 1 { encode((b1 & 0xF) << 2 | b2 >> 6) } else { '=' });
+            res.push(if chunk.len() > 2 { encode(b2 & 0x3F) } else { '=' });
+            lineLength += 4;
         }
-        let s = bytes
-            .chunks(3)
-            .flat_map(|chunk| split(chunk))
-            .map(|d| encode(d) as char)
-            .collect();
-        fromString(s)
+        res
     }
 
-    pub fn fromBase64String(s: string) -> Array<u8> {
-        fn decode(c: u8) -> u8 {
+    fn decode_base64(input: impl Iterator<Item = char>) -> Vec<u8> {
+        fn invalid() -> ! {
+            panic!("The input is not a valid Base-64 string as it contains a non-base 64 character, more than two padding characters, or an illegal character among the padding characters.")
+        }
+        fn decode(c: char) -> u32 {
             match c {
-                b'A'..=b'Z' => c - b'A',
-                b'a'..=b'z' => c - b'a' + 26,
-                b'0'..=b'9' => c - b'0' + 52,
-                b'+' => 62,
-                b'/' => 63,
-                b'=' => 0,
-                _ => panic!("The input is not a valid Base-64 string as it contains a non-base 64 character, more than two padding characters, or an illegal character among the padding characters."),
+                'A'..='Z' => c as u32 - 'A' as u32,
+                'a'..='z' => c as u32 - 'a' as u32 + 26,
+                '0'..='9' => c as u32 - '0' as u32 + 52,
+                '+' => 62,
+                '/' => 63,
+                _ => invalid(),
             }
         }
-        fn merge(base64Chunk: &[u8]) -> [u8; 3] {
-            let mut chunk = [0u8; 4];
-            for i in 0..base64Chunk.len() {
-                chunk[i] = decode(base64Chunk[i]);
-            }
-            match base64Chunk.len() {
-                1 => [
-                    (chunk[0] & 0x3F) << 2,
-                    0,
-                    0
-                ],
-                2 => [
-                    (chunk[0] & 0x3F) << 2 | chunk[1] >> 4,
-                    (chunk[1] & 0xF) << 4,
-                    0
-                ],
-                3 => [
-                    (chunk[0] & 0x3F) << 2 | chunk[1] >> 4,
-                    (chunk[1] & 0xF) << 4 | chunk[2] >> 2,
-                    (chunk[2] & 0x3) << 6,
-                ],
-                4 => [
-                    (chunk[0] &  0x3F) << 2 | chunk[1] >> 4,
-                    (chunk[1] & 0xF) << 4 | chunk[2] >> 2,
-                    (chunk[2] & 0x3) << 6 | chunk[3] & 0x3F,
-                ],
-                _ => unreachable!()
+        // whitespace is ignored anywhere in the input, as in .NET
+        let chars: Vec<char> = input
+            .filter(|c| !matches!(c, ' ' | '\t' | '\r' | '\n'))
+            .collect();
+        if !chars.len().is_multiple_of(4) {
+            panic!("Invalid length for a Base-64 char array or string.");
+        }
+        let padding = chars.iter().rev().take_while(|c| **c == '=').count();
+        if padding > 2 {
+            invalid();
+        }
+        let mut bytes = Vec::with_capacity(chars.len() / 4 * 3);
+        let quads = chars.len() / 4;
+        for (k, quad) in chars.chunks(4).enumerate() {
+            let n = if k + 1 == quads { 4 - padding } else { 4 };
+            let mut bits = 0u32;
+            for (i, c) in quad.iter().enumerate() {
+                bits = bits << 6 | if i < n { decode(*c) } else { 0 };
             }
+            let decoded = [(bits >> 16) as u8, (bits >> 8) as u8, bits as u8];
+            bytes.extend_from_slice(&decoded[..n - 1]);
         }
-        let chars = s.as_bytes();
-        let mut bytes: Vec<u8> = chars
-            .chunks(4)
-            .flat_map(merge)
-            .collect();
-        let padding_len =
-            if chars.len() >= 1 && chars[chars.len() - 1] == b'=' {
-                if chars.len() >= 2 && chars[chars.len() - 2] == b'=' { 2 }
-                else { 1 }
-            } else { 0 };
-        bytes.truncate(3 * (chars.len() - padding_len) / 4);
-        array_from(bytes)
+        bytes
+    }
+
+    pub fn toBase64String(bytes: Array<u8>) -> string {
+        fromString(encode_base64(&bytes, false))
+    }
+
+    fn check_base64_options(options: i32) {
+        if options & !1 != 0 {
+            panic!("Illegal enum value: {options}. (Parameter 'options')");
+        }
+    }
+
+    pub fn toBase64StringWith(bytes: Array<u8>, options: i32) -> string {
+        check_base64_options(options);
+        fromString(encode_base64(&bytes, options == 1))
+    }
+
+    pub fn toBase64StringRange(bytes: Array<u8>, offset: i32, length: i32, options: i32) -> string {
+        check_base64_options(options);
+        let (offset, length) = check_range(bytes.len(), offset, length);
+        fromString(encode_base64(&bytes.as_slice()[offset..offset + length], options == 1))
+    }
+
+    pub fn fromBase64String(s: string) -> Array<u8> {
+        array_from(decode_base64(s.chars()))
+    }
+
+    pub fn fromBase64CharArray(chars: Array<char>, offset: i32, length: i32) -> Array<u8> {
+        let (offset, length) = check_range(chars.len(), offset, length);
+        array_from(decode_base64(chars.as_slice()[offset..offset + length].iter().copied()))
     }
 
 }
@@ -1,16 +1,20 @@
 #[cfg(feature = "datetime")]
 pub mod DateOnly_ {
     use crate::{
-        DateTime_::{ticks_to_duration, DateTime, DateTimeKind},
-        Native_::{compare, MutCell, ToString},
+        DateTime_::{
+            culture_date_parse_patterns, expand_standard_format, iso_week_to_date, parse_date_time,
+            DateTime, DateTimeKind, DateTimeStyles_None, DayOfWeek,
+        },
+        Globalization_::CultureInfo,
+        NativeArray_::Array,
+        Native_::{compare, LrcPtr, MutCell, ToString},
         String_::{fromString, string},
         TimeOnly_::TimeOnly,
-        TimeSpan_::ticks_per_day,
     };
     use chrono::{DateTime as CDateTime, Datelike, Months, NaiveDate, NaiveTime, ParseResult};
 
     #[repr(transparent)]
-    #[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
     pub struct DateOnly(NaiveDate);
 
     impl core::fmt::Display for DateOnly {
@@ -19,6 +23,29 @@ pub mod DateOnly_ {
         }
     }
 
+    impl core::str::FromStr for DateOnly {
+        type Err = chrono::ParseError;
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::try_parse_str(s.trim()).map(DateOnly)
+        }
+    }
+
+    // ISO 8601 text, like the "o" format
+    #[cfg(feature = "serde")]
+    impl serde::Serialize for DateOnly {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(self.toString(string("o")).as_str())
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl<'de> serde::Deserialize<'de> for DateOnly {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let s = <crate::Native_::String as serde::Deserialize>::deserialize(deserializer)?;
+            s.parse().map_err(serde::de::Error::custom)
+        }
+    }
+
     pub fn compareTo(x: DateOnly, y: DateOnly) -> i32 {
         compare(&x, &y)
     }
@@ -31,14 +58,20 @@ pub mod DateOnly_ {
         DateOnly::minValue()
     }
 
+    // DateOnly.MaxValue.DayNumber
+    const max_day_number: i32 = 3_652_058;
+
     impl DateOnly {
         pub(crate) fn naive_date(&self) -> NaiveDate {
             self.0
         }
 
         pub fn new_ymd(y: i32, m: i32, d: i32) -> DateOnly {
-            let d = NaiveDate::from_ymd_opt(y, m as u32, d as u32).unwrap();
-            DateOnly(d)
+            let valid = (1..=9999).contains(&y) && (1..=12).contains(&m) && (1..=31).contains(&d);
+            match NaiveDate::from_ymd_opt(y, m as u32, d as u32) {
+                Some(d) if valid => DateOnly(d),
+                _ => panic!("Year, Month, and Day parameters describe an un-representable DateTime."),
+            }
         }
 
         pub fn minValue() -> DateOnly {
@@ -52,8 +85,10 @@ pub mod DateOnly_ {
         }
 
         pub fn fromDayNumber(days: i32) -> DateOnly {
-            let d = NaiveDate::from_num_days_from_ce_opt(days + 1).unwrap();
-            DateOnly(d)
+            if !(0..=max_day_number).contains(&days) {
+                panic!("Day number must be between 0 and DateOnly.MaxValue.DayNumber. (Parameter 'dayNumber')");
+            }
+            DateOnly(NaiveDate::from_num_days_from_ce_opt(days + 1).unwrap())
         }
 
         pub fn fromDateTime(dt: DateTime) -> DateOnly {
@@ -78,15 +113,11 @@ pub mod DateOnly_ {
         }
 
         pub fn dayOfWeek(&self) -> i32 {
-            match self.0.weekday() {
-                chrono::Weekday::Mon => 1,
-                chrono::Weekday::Tue => 2,
-                chrono::Weekday::Wed => 3,
-                chrono::Weekday::Thu => 4,
-                chrono::Weekday::Fri => 5,
-                chrono::Weekday::Sat => 6,
-                chrono::Weekday::Sun => 0,
-            }
+            self.day_of_week() as i32
+        }
+
+        pub fn day_of_week(&self) -> DayOfWeek {
+            DayOfWeek::from(self.0.weekday())
         }
 
         pub fn dayOfYear(&self) -> i32 {
@@ -94,24 +125,33 @@ pub mod DateOnly_ {
         }
 
         pub fn addDays(&self, days: i32) -> DateOnly {
-            let d = ticks_to_duration(days as i64 * ticks_per_day);
-            DateOnly(self.0 + d)
+            let dayNumber = self.dayNumber() as i64 + days as i64;
+            if !(0..=max_day_number as i64).contains(&dayNumber) {
+                panic!("Value to add was out of range. (Parameter 'value')");
+            }
+            Self::fromDayNumber(dayNumber as i32)
         }
 
+        // like DateTime.AddMonths, the day is clamped to the end of the month
         pub fn addMonths(&self, months: i32) -> DateOnly {
-            let ndt = if months < 0 {
-                self.0
-                    .checked_sub_months(Months::new(-months as u32))
-                    .unwrap()
+            if !(-120000..=120000).contains(&months) {
+                panic!("Months value must be between +/-120000. (Parameter 'months')");
+            }
+            let d = if months < 0 {
+                self.0.checked_sub_months(Months::new(-months as u32))
             } else {
-                self.0
-                    .checked_add_months(Months::new(months as u32))
-                    .unwrap()
+                self.0.checked_add_months(Months::new(months as u32))
             };
-            DateOnly(ndt)
+            match d {
+                Some(d) if (1..=9999).contains(&d.year()) => DateOnly(d),
+                _ => panic!("The added or subtracted value results in an un-representable DateTime. (Parameter 'months')"),
+            }
         }
 
         pub fn addYears(&self, years: i32) -> DateOnly {
+            if !(-10000..=10000).contains(&years) {
+                panic!("Years value must be between +/-10000. (Parameter 'years')");
+            }
             self.addMonths(years * 12)
         }
 
@@ -139,6 +179,27 @@ pub mod DateOnly_ {
             fromString(df.to_string())
         }
 
+        // the current culture's date patterns are tried first, then ISO 8601
+        fn try_parse_culture(s: &str, culture: &CultureInfo) -> Option<NaiveDate> {
+            let dtfi = culture.date_time_format();
+            culture_date_parse_patterns(dtfi)
+                .iter()
+                .filter_map(|pattern| parse_date_time(s, pattern, dtfi))
+                .find_map(|parsed| parsed.to_naive())
+                .map(|ndt| ndt.date())
+                .or_else(|| Self::try_parse_str(s).ok())
+                .filter(|nd| (1..=9999).contains(&nd.year()))
+        }
+
+        fn parse_error(s: &str, culture: &CultureInfo) -> ! {
+            // like .NET, a valid DateTime with a time of day is a different error
+            if DateTime::try_parse_culture(s.trim(), culture, DateTimeStyles_None).is_some() {
+                panic!("String '{}' contains parts which are not specific to the DateOnly.", s)
+            } else {
+                panic!("String '{}' was not recognized as a valid DateOnly.", s)
+            }
+        }
+
         fn try_parse_str(s: &str) -> ParseResult<NaiveDate> {
             s.parse::<NaiveDate>()
                 .or(NaiveDate::parse_from_str(s, "%m/%d/%Y"))
@@ -146,20 +207,96 @@ pub mod DateOnly_ {
         }
 
         pub fn tryParse(s: string, res: &MutCell<DateOnly>) -> bool {
-            match Self::try_parse_str(s.trim()) {
-                Ok(nd) => {
+            Self::tryParse2(s, CultureInfo::currentCulture_(), res)
+        }
+
+        pub fn tryParse2(s: string, culture: LrcPtr<CultureInfo>, res: &MutCell<DateOnly>) -> bool {
+            match Self::try_parse_culture(s.trim(), &culture) {
+                Some(nd) => {
                     res.set(DateOnly(nd));
                     true
                 }
-                Err(e) => false,
+                None => false,
             }
         }
 
         pub fn parse(s: string) -> DateOnly {
-            match Self::try_parse_str(s.trim()) {
-                Ok(nd) => DateOnly(nd),
-                Err(e) => panic!("The input string {} was not in a correct format.", s),
+            Self::parse2(s, CultureInfo::currentCulture_())
+        }
+
+        pub fn parse2(s: string, culture: LrcPtr<CultureInfo>) -> DateOnly {
+            match Self::try_parse_culture(s.trim(), &culture) {
+                Some(nd) => DateOnly(nd),
+                None => Self::parse_error(&s, &culture),
+            }
+        }
+
+        // DateTimeStyles are ignored, surrounding white space is always allowed
+        fn try_parse_exact(s: &str, formats: &[string], culture: &CultureInfo) -> Option<NaiveDate> {
+            let s = s.trim();
+            formats.iter().find_map(|format| {
+                let invariant = CultureInfo::invariantCulture_();
+                let (pattern, dtfi) = match format.as_str() {
+                    "o" | "O" => ("yyyy'-'MM'-'dd".to_string(), invariant.date_time_format()),
+                    "r" | "R" => ("ddd, dd MMM yyyy".to_string(), invariant.date_time_format()),
+                    format => {
+                        let dtfi = culture.date_time_format();
+                        let pattern = expand_standard_format(format, dtfi).unwrap_or_else(|| format.to_string());
+                        (pattern, dtfi)
+                    }
+                };
+                let parsed = parse_date_time(s, &pattern, dtfi)?;
+                let ndt = parsed.to_naive()?;
+                if ndt.time() != NaiveTime::MIN || parsed.offset_seconds.is_some() || parsed.is_utc {
+                    None
+                } else {
+                    Some(ndt.date())
+                }
+            })
+        }
+
+        pub fn parseExact(s: string, formats: Array<string>) -> DateOnly {
+            Self::parseExact2(s, formats, CultureInfo::currentCulture_())
+        }
+
+        pub fn parseExact2(s: string, formats: Array<string>, culture: LrcPtr<CultureInfo>) -> DateOnly {
+            match Self::try_parse_exact(&s, formats.as_slice(), &culture) {
+                Some(nd) => DateOnly(nd),
+                None => panic!("String '{}' was not recognized as a valid DateOnly.", s),
             }
         }
+
+        pub fn tryParseExact(s: string, formats: Array<string>, res: &MutCell<DateOnly>) -> bool {
+            Self::tryParseExact2(s, formats, CultureInfo::currentCulture_(), res)
+        }
+
+        pub fn tryParseExact2(
+            s: string,
+            formats: Array<string>,
+            culture: LrcPtr<CultureInfo>,
+            res: &MutCell<DateOnly>,
+        ) -> bool {
+            match Self::try_parse_exact(&s, formats.as_slice(), &culture) {
+                Some(nd) => {
+                    res.set(DateOnly(nd));
+                    true
+                }
+                None => false,
+            }
+        }
+    }
+
+    // ISOWeek methods taking or returning a DateOnly
+
+    pub fn isoWeekOfYear(d: DateOnly) -> i32 {
+        d.0.iso_week().week() as i32
+    }
+
+    pub fn isoYear(d: DateOnly) -> i32 {
+        d.0.iso_week().year()
+    }
+
+    pub fn isoWeekToDateOnly(year: i32, week: i32, dayOfWeek: i32) -> DateOnly {
+        DateOnly(iso_week_to_date(year, week, dayOfWeek))
     }
 }
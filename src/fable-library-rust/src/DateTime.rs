@@ -1,26 +1,345 @@
 #[cfg(feature = "datetime")]
 pub mod DateTime_ {
     use crate::{
+        Convert_::ParseError,
         DateOnly_::DateOnly,
         DateTimeOffset_::DateTimeOffset,
-        Native_::{compare, MutCell, ToString},
+        Globalization_::{CultureInfo, DateTimeFormatInfo},
+        NativeArray_::Array,
+        Native_::{alloc::format, compare, Lrc, LrcPtr, MutCell, String, ToString, Vec},
         String_::{fromString, string},
         TimeOnly_::TimeOnly,
-        TimeSpan_::{nanoseconds_per_tick, ticks_per_second, TimeSpan},
+        TimeSpan_::{nanoseconds_per_tick, ticks_per_day, ticks_per_millisecond, ticks_per_second, TimeSpan},
     };
     use chrono::{
         DateTime as CDateTime, Datelike, Duration, FixedOffset, Local, Months, NaiveDate,
-        NaiveDateTime, NaiveTime, Offset, ParseResult, TimeZone, Timelike, Utc, Weekday,
+        LocalResult, NaiveDateTime, NaiveTime, Offset, ParseResult, TimeZone, Timelike, Utc, Weekday,
     };
     use core::ops::{Add, Sub};
 
-    #[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+    pub const DateTimeStyles_None: i32 = 0;
+    pub const DateTimeStyles_AdjustToUniversal: i32 = 16;
+    pub const DateTimeStyles_AssumeLocal: i32 = 32;
+    pub const DateTimeStyles_AssumeUniversal: i32 = 64;
+    pub const DateTimeStyles_RoundtripKind: i32 = 128;
+
+    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
     pub enum DateTimeKind {
         Unspecified,
         Utc,
         Local,
     }
 
+    // System.DayOfWeek, DateTime.DayOfWeek is still an i32 in compiled code
+    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+    pub enum DayOfWeek {
+        Sunday = 0,
+        Monday = 1,
+        Tuesday = 2,
+        Wednesday = 3,
+        Thursday = 4,
+        Friday = 5,
+        Saturday = 6,
+    }
+
+    impl DayOfWeek {
+        pub fn name(&self) -> &'static str {
+            match self {
+                DayOfWeek::Sunday => "Sunday",
+                DayOfWeek::Monday => "Monday",
+                DayOfWeek::Tuesday => "Tuesday",
+                DayOfWeek::Wednesday => "Wednesday",
+                DayOfWeek::Thursday => "Thursday",
+                DayOfWeek::Friday => "Friday",
+                DayOfWeek::Saturday => "Saturday",
+            }
+        }
+    }
+
+    impl core::fmt::Display for DayOfWeek {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            f.write_str(self.name())
+        }
+    }
+
+    impl From<Weekday> for DayOfWeek {
+        fn from(weekday: Weekday) -> Self {
+            match weekday {
+                Weekday::Sun => DayOfWeek::Sunday,
+                Weekday::Mon => DayOfWeek::Monday,
+                Weekday::Tue => DayOfWeek::Tuesday,
+                Weekday::Wed => DayOfWeek::Wednesday,
+                Weekday::Thu => DayOfWeek::Thursday,
+                Weekday::Fri => DayOfWeek::Friday,
+                Weekday::Sat => DayOfWeek::Saturday,
+            }
+        }
+    }
+
+    impl From<DayOfWeek> for i32 {
+        fn from(day: DayOfWeek) -> Self {
+            day as i32
+        }
+    }
+
+    impl TryFrom<i32> for DayOfWeek {
+        type Error = i32;
+        fn try_from(value: i32) -> Result<Self, Self::Error> {
+            match value {
+                0 => Ok(DayOfWeek::Sunday),
+                1 => Ok(DayOfWeek::Monday),
+                2 => Ok(DayOfWeek::Tuesday),
+                3 => Ok(DayOfWeek::Wednesday),
+                4 => Ok(DayOfWeek::Thursday),
+                5 => Ok(DayOfWeek::Friday),
+                6 => Ok(DayOfWeek::Saturday),
+                _ => Err(value),
+            }
+        }
+    }
+
+    // DayOfWeek.ToString(), undefined values are printed as numbers like in .NET
+    pub fn dayOfWeekToString(day: i32) -> string {
+        match DayOfWeek::try_from(day) {
+            Ok(day) => string(day.name()),
+            Err(value) => fromString(value.to_string()),
+        }
+    }
+
+    // -----------------------------------------------------------
+    // System.TimeProvider
+    // -----------------------------------------------------------
+
+    // The clock and local time zone behind DateTime.Now, DateTime.UtcNow,
+    // DateTime.Today, DateTimeOffset.Now/UtcNow and local time conversions.
+    // Host code can install another one with `setTimeProvider`, e.g. a
+    // `FakeTimeProvider` to test scheduling logic. On wasm32-unknown-unknown
+    // the system provider reads the JS `Date` (chrono's `wasmbind` feature),
+    // hosts without JS can install a provider backed by their own clock.
+    pub trait TimeProvider {
+        fn get_utc_now(&self) -> CDateTime<Utc>;
+
+        // the UTC offset of the local time zone at a UTC time
+        fn get_local_offset(&self, utc: &NaiveDateTime) -> FixedOffset {
+            Local.offset_from_utc_datetime(utc)
+        }
+    }
+
+    pub struct SystemTimeProvider;
+
+    impl TimeProvider for SystemTimeProvider {
+        fn get_utc_now(&self) -> CDateTime<Utc> {
+            Utc::now()
+        }
+    }
+
+    // A manual clock, clones share the same current time and local offset.
+    // The local time zone is the system one until `setLocalOffset` is called.
+    #[derive(Clone, Debug)]
+    pub struct FakeTimeProvider {
+        now: LrcPtr<MutCell<CDateTime<Utc>>>,
+        localOffset: LrcPtr<MutCell<Option<FixedOffset>>>,
+    }
+
+    impl FakeTimeProvider {
+        pub fn new(start: DateTimeOffset) -> FakeTimeProvider {
+            FakeTimeProvider {
+                now: LrcPtr::new(MutCell::new(start.utcDateTime().ndt.and_utc())),
+                localOffset: LrcPtr::new(MutCell::new(None)),
+            }
+        }
+
+        pub fn setLocalOffset(&self, offset: TimeSpan) {
+            let secs = offset.ticks() / ticks_per_second;
+            match FixedOffset::east_opt(secs as i32) {
+                Some(offset) if secs.abs() <= 14 * 3600 && secs % 60 == 0 => self.localOffset.set(Some(offset)),
+                _ => panic!("Offset must be within plus or minus 14 hours. (Parameter 'offset')"),
+            }
+        }
+
+        pub fn setUtcNow(&self, value: DateTimeOffset) {
+            self.now.set(value.utcDateTime().ndt.and_utc())
+        }
+
+        pub fn advance(&self, delta: TimeSpan) {
+            let d = ticks_to_duration(delta.ticks());
+            self.now.set(self.now.get() + d)
+        }
+    }
+
+    impl TimeProvider for FakeTimeProvider {
+        fn get_utc_now(&self) -> CDateTime<Utc> {
+            self.now.get()
+        }
+
+        fn get_local_offset(&self, utc: &NaiveDateTime) -> FixedOffset {
+            match self.localOffset.get() {
+                Some(offset) => offset,
+                None => Local.offset_from_utc_datetime(utc),
+            }
+        }
+    }
+
+    // Settings shared by all threads, they are locked when std is available.
+    // Values are cloned out, so no lock is held while a provider is called.
+    #[cfg(not(feature = "no_std"))]
+    type Global<T> = std::sync::RwLock<MutCell<T>>;
+    #[cfg(feature = "no_std")]
+    type Global<T> = MutCell<T>;
+
+    #[cfg(not(feature = "no_std"))]
+    const fn new_global<T>(value: T) -> Global<T> {
+        std::sync::RwLock::new(MutCell::new(value))
+    }
+
+    #[cfg(feature = "no_std")]
+    const fn new_global<T>(value: T) -> Global<T> {
+        MutCell::new(value)
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    fn get_global<T: Clone>(global: &Global<T>) -> T {
+        global.read().unwrap_or_else(|e| e.into_inner()).get()
+    }
+
+    #[cfg(feature = "no_std")]
+    fn get_global<T: Clone>(global: &Global<T>) -> T {
+        global.get()
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    fn set_global<T>(global: &Global<T>, value: T) {
+        global.write().unwrap_or_else(|e| e.into_inner()).set(value)
+    }
+
+    #[cfg(feature = "no_std")]
+    fn set_global<T>(global: &Global<T>, value: T) {
+        global.set(value)
+    }
+
+    static TIME_PROVIDER: Global<Option<LrcPtr<dyn TimeProvider>>> = new_global(None);
+
+    pub fn setTimeProvider<P: TimeProvider + 'static>(provider: P) {
+        let provider: Lrc<dyn TimeProvider> = Lrc::new(provider);
+        set_global(&TIME_PROVIDER, Some(LrcPtr::from(provider)));
+    }
+
+    // restores the system clock
+    pub fn resetTimeProvider() {
+        set_global(&TIME_PROVIDER, None);
+    }
+
+    pub(crate) fn utc_now() -> CDateTime<Utc> {
+        match get_global(&TIME_PROVIDER) {
+            Some(provider) => provider.get_utc_now(),
+            None => Utc::now(),
+        }
+    }
+
+    pub(crate) fn local_now() -> CDateTime<FixedOffset> {
+        let now = utc_now();
+        now.with_timezone(&local_offset_from_utc(&now.naive_utc()))
+    }
+
+    // The system local offset is cached for the hour of UTC time of the last
+    // lookup, when there is no transition in that hour. `stable` tells if the
+    // offset is also the same a day before and after, so local times in that
+    // hour are neither ambiguous nor invalid. `clearCachedLocalOffset` (also
+    // TimeZoneInfo.ClearCachedData) drops it, e.g. after changing TZ.
+    #[derive(Clone, Copy)]
+    struct CachedLocalOffset {
+        hour: NaiveDateTime,
+        offset: FixedOffset,
+        stable: Option<bool>,
+    }
+
+    static LOCAL_OFFSET: Global<Option<CachedLocalOffset>> = new_global(None);
+
+    pub fn clearCachedLocalOffset() {
+        set_global(&LOCAL_OFFSET, None);
+    }
+
+    fn hour_start(utc: &NaiveDateTime) -> NaiveDateTime {
+        utc.date().and_hms_opt(utc.hour(), 0, 0).unwrap()
+    }
+
+    fn system_local_offset(utc: &NaiveDateTime) -> FixedOffset {
+        let hour = hour_start(utc);
+        match get_global(&LOCAL_OFFSET) {
+            Some(cached) if cached.hour == hour => cached.offset,
+            _ => {
+                let offset = Local.offset_from_utc_datetime(&hour);
+                let last = hour + Duration::hours(1) - Duration::nanoseconds(1);
+                if Local.offset_from_utc_datetime(&last) == offset {
+                    set_global(&LOCAL_OFFSET, Some(CachedLocalOffset { hour, offset, stable: None }));
+                    offset
+                } else {
+                    Local.offset_from_utc_datetime(utc)
+                }
+            }
+        }
+    }
+
+    // the cached offset of a local time, when it has a single one
+    fn cached_system_local_offset(ndt: &NaiveDateTime) -> Option<FixedOffset> {
+        let mut cached = get_global(&LOCAL_OFFSET)?;
+        let utc = *ndt - Duration::seconds(cached.offset.local_minus_utc() as i64);
+        if hour_start(&utc) != cached.hour {
+            return None;
+        }
+        let stable = match cached.stable {
+            Some(stable) => stable,
+            None => {
+                let day = Duration::days(1);
+                let stable = [cached.hour - day, cached.hour + day]
+                    .iter()
+                    .all(|utc| Local.offset_from_utc_datetime(utc) == cached.offset);
+                cached.stable = Some(stable);
+                set_global(&LOCAL_OFFSET, Some(cached));
+                stable
+            }
+        };
+        if stable {
+            Some(cached.offset)
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn local_offset_from_utc(utc: &NaiveDateTime) -> FixedOffset {
+        match get_global(&TIME_PROVIDER) {
+            Some(provider) => provider.get_local_offset(utc),
+            None => system_local_offset(utc),
+        }
+    }
+
+    // The UTC offsets a local time can have, like `offset_from_local_datetime`.
+    // For a provider, they are the offsets around that time (a day before and
+    // after) which map the time back to itself.
+    pub(crate) fn local_offsets(ndt: &NaiveDateTime) -> LocalResult<FixedOffset> {
+        match get_global(&TIME_PROVIDER) {
+            Some(provider) => {
+                let to_utc = |offset: FixedOffset| *ndt - Duration::seconds(offset.local_minus_utc() as i64);
+                let mut offsets: Vec<FixedOffset> = [*ndt - Duration::days(1), *ndt + Duration::days(1)]
+                    .iter()
+                    .map(|utc| provider.get_local_offset(utc))
+                    .filter(|&offset| provider.get_local_offset(&to_utc(offset)) == offset)
+                    .collect();
+                offsets.sort_by_key(|offset| -offset.local_minus_utc());
+                offsets.dedup();
+                match offsets[..] {
+                    [offset] => LocalResult::Single(offset),
+                    [o1, o2] => LocalResult::Ambiguous(o1, o2),
+                    _ => LocalResult::None,
+                }
+            }
+            None => match cached_system_local_offset(ndt) {
+                Some(offset) => LocalResult::Single(offset),
+                None => Local.offset_from_local_datetime(ndt),
+            },
+        }
+    }
+
     #[derive(Clone, Copy, Debug)]
     pub struct DateTime {
         ndt: NaiveDateTime,
@@ -33,15 +352,54 @@ pub mod DateTime_ {
         }
     }
 
+    impl core::str::FromStr for DateTime {
+        type Err = chrono::ParseError;
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::try_parse_str(s.trim())
+        }
+    }
+
+    // ISO 8601 text, like the "o" format, which keeps the kind
+    #[cfg(feature = "serde")]
+    impl serde::Serialize for DateTime {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(self.toString(string("o")).as_str())
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl<'de> serde::Deserialize<'de> for DateTime {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+            Self::try_parse_styles(s.trim(), DateTimeStyles_RoundtripKind).map_err(serde::de::Error::custom)
+        }
+    }
+
+    // like .NET, equality and ordering ignore the kind
+
     impl PartialEq for DateTime {
         fn eq(&self, other: &Self) -> bool {
             self.ticks() == other.ticks()
         }
     }
 
+    impl Eq for DateTime {}
+
     impl PartialOrd for DateTime {
         fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-            self.ticks().partial_cmp(&other.ticks())
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for DateTime {
+        fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+            self.ticks().cmp(&other.ticks())
+        }
+    }
+
+    impl core::hash::Hash for DateTime {
+        fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+            self.ticks().hash(state)
         }
     }
 
@@ -57,6 +415,65 @@ pub mod DateTime_ {
         DateTime::minValue()
     }
 
+    // ticks of 1899-12-30, the OLE Automation epoch
+    const oa_date_offset_ticks: i64 = 599_264_352_000_000_000;
+    // ticks of 0100-01-01, the smallest OLE Automation date
+    const oa_date_min_ticks: i64 = 31_241_376_000_000_000;
+    // ticks of 1601-01-01, the Win32 FileTime epoch
+    const file_time_offset_ticks: i64 = 504_911_232_000_000_000;
+
+    // ticks of 9999-12-31 23:59:59.9999999
+    pub(crate) const max_ticks: i64 = 3_155_378_975_999_999_999;
+
+    pub(crate) const fn expect_valid<T: Copy>(value: Option<T>) -> T {
+        match value {
+            Some(value) => value,
+            None => panic!("invalid date or time constant"),
+        }
+    }
+
+    // DateTime.MinValue, DateTime.MaxValue and DateTime.UnixEpoch
+    pub(crate) const min_ndt: NaiveDateTime = expect_valid(NaiveDate::from_ymd_opt(1, 1, 1)).and_time(NaiveTime::MIN);
+    pub(crate) const max_ndt: NaiveDateTime = expect_valid(NaiveDate::from_ymd_opt(9999, 12, 31))
+        .and_time(expect_valid(NaiveTime::from_hms_nano_opt(23, 59, 59, 999_999_900)));
+    pub(crate) const epoch_ndt: NaiveDateTime = expect_valid(NaiveDate::from_ymd_opt(1970, 1, 1)).and_time(NaiveTime::MIN);
+
+    // Ticks are computed directly from the day number and the time of day,
+    // the value of a tick is 100 nanoseconds since 0001-01-01.
+
+    // Like .NET, a leap second (23:59:60) is folded into the preceding second,
+    // chrono represents it as a nanosecond value past 999_999_999.
+    pub(crate) fn fold_leap_second<T: Timelike>(t: T) -> T {
+        match t.nanosecond().checked_sub(1_000_000_000) {
+            Some(nanos) => t.with_nanosecond(nanos).unwrap(),
+            None => t,
+        }
+    }
+
+    pub(crate) fn time_to_ticks(t: &NaiveTime) -> i64 {
+        let nanos = fold_leap_second(*t).nanosecond() as i64;
+        t.num_seconds_from_midnight() as i64 * ticks_per_second + nanos / nanoseconds_per_tick
+    }
+
+    pub(crate) fn naive_to_ticks(ndt: &NaiveDateTime) -> i64 {
+        let days = ndt.date().num_days_from_ce() as i64 - 1;
+        days * ticks_per_day + time_to_ticks(&ndt.time())
+    }
+
+    // the time of day of a number of ticks, wrapping around midnight
+    pub(crate) fn ticks_to_time(ticks: i64) -> NaiveTime {
+        let ticks = ticks.rem_euclid(ticks_per_day);
+        let secs = (ticks / ticks_per_second) as u32;
+        let nanos = (ticks % ticks_per_second * nanoseconds_per_tick) as u32;
+        NaiveTime::from_num_seconds_from_midnight_opt(secs, nanos).unwrap()
+    }
+
+    pub(crate) fn ticks_to_naive(ticks: i64) -> Option<NaiveDateTime> {
+        let days = i32::try_from(ticks.div_euclid(ticks_per_day) + 1).ok()?;
+        let date = NaiveDate::from_num_days_from_ce_opt(days)?;
+        Some(date.and_time(ticks_to_time(ticks)))
+    }
+
     pub(crate) fn ticks_to_duration(ticks: i64) -> Duration {
         let seconds = ticks / ticks_per_second;
         let subsecond = ticks % ticks_per_second;
@@ -66,26 +483,78 @@ pub mod DateTime_ {
     }
 
     pub(crate) fn duration_to_ticks(d: Duration) -> i64 {
-        let seconds = d.num_seconds();
-        let subsecond = d - Duration::seconds(seconds);
-        let ns = subsecond.num_nanoseconds().unwrap();
-        seconds * ticks_per_second + ns / nanoseconds_per_tick
+        let ticks = d.num_seconds().checked_mul(ticks_per_second);
+        match ticks.and_then(|ticks| ticks.checked_add(d.subsec_nanos() as i64 / nanoseconds_per_tick)) {
+            Some(ticks) => ticks,
+            None => panic!("TimeSpan overflowed because the duration is too long."),
+        }
+    }
+
+    // The UTC offset of a local time. Like .NET, an ambiguous local time is
+    // taken as standard time and an invalid one (skipped by a DST transition)
+    // uses the offset in effect before the transition.
+    pub(crate) fn local_offset(ndt: &NaiveDateTime) -> FixedOffset {
+        match local_offsets(ndt) {
+            LocalResult::Single(offset) => offset,
+            LocalResult::Ambiguous(o1, o2) => {
+                if o1.local_minus_utc() < o2.local_minus_utc() {
+                    o1
+                } else {
+                    o2
+                }
+            }
+            LocalResult::None => local_offset_from_utc(&(*ndt - Duration::days(1))),
+        }
     }
 
     impl DateTime {
         pub fn new(ndt: NaiveDateTime, kind: DateTimeKind) -> DateTime {
-            if ndt < Self::minValue().ndt || ndt > Self::maxValue().ndt {
-                panic!("Invalid datetime range");
+            let ndt = fold_leap_second(ndt);
+            if !Self::in_range(&ndt) {
+                panic!("Ticks must be between DateTime.MinValue.Ticks and DateTime.MaxValue.Ticks. (Parameter 'ticks')");
             }
             DateTime { ndt, kind }
         }
 
+        fn in_range(ndt: &NaiveDateTime) -> bool {
+            (1..=9999).contains(&ndt.year())
+        }
+
+        // validates the components like the .NET constructors do
+        fn naive_from_parts(
+            y: i32,
+            m: i32,
+            d: i32,
+            h: i32,
+            mins: i32,
+            secs: i32,
+            millis: i32,
+            micros: i32,
+        ) -> NaiveDateTime {
+            if !(1..=9999).contains(&y) || !(1..=12).contains(&m) || d < 1 || d > Self::daysInMonth(y, m) {
+                panic!("Year, Month, and Day parameters describe an un-representable DateTime.");
+            }
+            if !(0..24).contains(&h) || !(0..60).contains(&mins) || !(0..60).contains(&secs) {
+                panic!("Hour, Minute, and Second parameters describe an un-representable DateTime.");
+            }
+            if !(0..1000).contains(&millis) {
+                panic!("Valid values are between 0 and 999, inclusive. (Parameter 'millisecond')");
+            }
+            if !(0..1000).contains(&micros) {
+                panic!("Valid values are between 0 and 999, inclusive. (Parameter 'microsecond')");
+            }
+            let nanos = (millis * 1_000_000 + micros * 1_000) as u32;
+            NaiveDate::from_ymd_opt(y, m as u32, d as u32)
+                .and_then(|nd| nd.and_hms_nano_opt(h as u32, mins as u32, secs as u32, nanos))
+                .unwrap()
+        }
+
         pub fn new_kind(ndt: NaiveDateTime, kind: i32) -> DateTime {
             let dtKind = match kind {
                 0 => DateTimeKind::Unspecified,
                 1 => DateTimeKind::Utc,
                 2 => DateTimeKind::Local,
-                _ => panic!("Unsupported date kind. Only valid values are: 0 - Unspecified, 1 - Utc, 2 -> Local")
+                _ => panic!("Invalid DateTimeKind value. (Parameter 'kind')"),
             };
             Self::new(ndt, dtKind)
         }
@@ -95,12 +564,14 @@ pub mod DateTime_ {
         }
 
         pub fn new_ticks(ticks: i64) -> DateTime {
-            Self::minValue().add(TimeSpan::from_ticks(ticks))
+            Self::new_ticks_kind(ticks, 0)
         }
 
         pub fn new_ticks_kind(ticks: i64, kind: i32) -> DateTime {
-            let d = ticks_to_duration(ticks);
-            let ndt = Self::minValue().ndt + d;
+            if !(0..=max_ticks).contains(&ticks) {
+                panic!("Ticks must be between DateTime.MinValue.Ticks and DateTime.MaxValue.Ticks. (Parameter 'ticks')");
+            }
+            let ndt = ticks_to_naive(ticks).unwrap();
             Self::new_kind(ndt, kind)
         }
 
@@ -115,14 +586,12 @@ pub mod DateTime_ {
         }
 
         pub fn new_ymd(y: i32, m: i32, d: i32) -> DateTime {
-            let nd = NaiveDate::from_ymd_opt(y, m as u32, d as u32).unwrap();
-            let ndt = nd.and_hms_opt(0, 0, 0).unwrap();
+            let ndt = Self::naive_from_parts(y, m, d, 0, 0, 0, 0, 0);
             Self::new(ndt, DateTimeKind::Unspecified)
         }
 
         pub fn new_ymdhms(y: i32, m: i32, d: i32, h: i32, mins: i32, secs: i32) -> DateTime {
-            let nd = NaiveDate::from_ymd_opt(y, m as u32, d as u32).unwrap();
-            let ndt = nd.and_hms_opt(h as u32, mins as u32, secs as u32).unwrap();
+            let ndt = Self::naive_from_parts(y, m, d, h, mins, secs, 0, 0);
             Self::new(ndt, DateTimeKind::Unspecified)
         }
 
@@ -148,10 +617,7 @@ pub mod DateTime_ {
             secs: i32,
             millis: i32,
         ) -> DateTime {
-            let nd = NaiveDate::from_ymd_opt(y, m as u32, d as u32).unwrap();
-            let ndt = nd
-                .and_hms_milli_opt(h as u32, mins as u32, secs as u32, millis as u32)
-                .unwrap();
+            let ndt = Self::naive_from_parts(y, m, d, h, mins, secs, millis, 0);
             Self::new(ndt, DateTimeKind::Unspecified)
         }
 
@@ -179,15 +645,7 @@ pub mod DateTime_ {
             millis: i32,
             micros: i32,
         ) -> DateTime {
-            let nd = NaiveDate::from_ymd_opt(y, m as u32, d as u32).unwrap();
-            let ndt = nd
-                .and_hms_micro_opt(
-                    h as u32,
-                    mins as u32,
-                    secs as u32,
-                    (millis * 1000 + micros) as u32,
-                )
-                .unwrap();
+            let ndt = Self::naive_from_parts(y, m, d, h, mins, secs, millis, micros);
             Self::new(ndt, DateTimeKind::Unspecified)
         }
 
@@ -208,43 +666,46 @@ pub mod DateTime_ {
 
         pub fn now() -> DateTime {
             DateTime {
-                ndt: Local::now().naive_local(),
+                ndt: local_now().naive_local(),
                 kind: DateTimeKind::Local,
             }
         }
 
         pub fn utcNow() -> DateTime {
             DateTime {
-                ndt: Utc::now().naive_utc(),
+                ndt: utc_now().naive_utc(),
                 kind: DateTimeKind::Utc,
             }
         }
 
-        pub fn minValue() -> DateTime {
-            let nd = NaiveDate::from_ymd_opt(1, 1, 1).unwrap();
-            let ndt = nd.and_hms_opt(0, 0, 0).unwrap();
+        pub const fn minValue() -> DateTime {
             DateTime {
-                ndt,
+                ndt: min_ndt,
                 kind: DateTimeKind::Utc,
             }
         }
 
-        pub fn maxValue() -> DateTime {
-            let d = ticks_to_duration(1);
-            let nd = NaiveDate::from_ymd_opt(10000, 1, 1).unwrap();
-            let ndt = nd.and_hms_opt(0, 0, 0).unwrap() - d; // one tick before year 10000
+        pub const fn maxValue() -> DateTime {
             DateTime {
-                ndt,
+                ndt: max_ndt,
                 kind: DateTimeKind::Utc,
             }
         }
 
-        pub fn unixEpoch() -> DateTime {
-            let ndt = Utc.timestamp_millis_opt(0).unwrap().naive_utc();
-            Self::new(ndt, DateTimeKind::Utc)
+        pub const fn unixEpoch() -> DateTime {
+            DateTime {
+                ndt: epoch_ndt,
+                kind: DateTimeKind::Utc,
+            }
         }
 
         pub fn daysInMonth(year: i32, month: i32) -> i32 {
+            if !(1..=12).contains(&month) {
+                panic!("Month must be between one and twelve. (Parameter 'month')");
+            }
+            if !(1..=9999).contains(&year) {
+                panic!("Year must be between 1 and 9999. (Parameter 'year')");
+            }
             let (year2, month2) = if month == 12 {
                 (year + 1, 1)
             } else {
@@ -261,7 +722,7 @@ pub mod DateTime_ {
         }
 
         pub fn today() -> DateTime {
-            let cdt = Utc::now();
+            let cdt = utc_now();
             Self::new_ymdhms_kind(cdt.year(), cdt.month() as i32, cdt.day() as i32, 0, 0, 0, 1)
         }
 
@@ -269,19 +730,27 @@ pub mod DateTime_ {
             Self::new_ticks_kind(dt.ticks(), kind)
         }
 
+        // None is an overflowed value
+        fn checked_add(&self, ticks: Option<i64>) -> DateTime {
+            match ticks.and_then(|ticks| self.ticks().checked_add(ticks)) {
+                Some(ticks) if (0..=max_ticks).contains(&ticks) => DateTime {
+                    ndt: ticks_to_naive(ticks).unwrap(),
+                    kind: self.kind,
+                },
+                _ => panic!("The added or subtracted value results in an un-representable DateTime. (Parameter 'value')"),
+            }
+        }
+
         pub fn add(&self, ts: TimeSpan) -> DateTime {
-            let d = ticks_to_duration(ts.ticks());
-            Self::new(self.ndt + d, self.kind)
+            self.checked_add(Some(ts.ticks()))
         }
 
         pub fn subtract(&self, ts: TimeSpan) -> DateTime {
-            let d = ticks_to_duration(ts.ticks());
-            Self::new(self.ndt - d, self.kind)
+            self.checked_add(ts.ticks().checked_neg())
         }
 
         pub fn subtract2(&self, other: DateTime) -> TimeSpan {
-            let ticks = duration_to_ticks(self.ndt - other.ndt);
-            TimeSpan::from_ticks(ticks)
+            TimeSpan::from_ticks(self.ticks() - other.ticks())
         }
 
         pub fn kind(&self) -> i32 {
@@ -296,8 +765,12 @@ pub mod DateTime_ {
             self.kind
         }
 
+        pub(crate) fn naive_date_time(&self) -> NaiveDateTime {
+            self.ndt
+        }
+
         pub fn ticks(&self) -> i64 {
-            duration_to_ticks(self.ndt - Self::minValue().ndt)
+            naive_to_ticks(&self.ndt)
         }
 
         pub fn date(&self) -> DateTime {
@@ -312,24 +785,50 @@ pub mod DateTime_ {
             )
         }
 
+        // conversions past MinValue or MaxValue are clamped like in .NET
+        fn clamped(ndt: NaiveDateTime, kind: DateTimeKind) -> DateTime {
+            let ndt = ndt.clamp(min_ndt, max_ndt);
+            DateTime { ndt, kind }
+        }
+
         pub fn toLocalTime(&self) -> DateTime {
             let ndt = match self.kind {
-                DateTimeKind::Utc => Local.from_utc_datetime(&self.ndt).naive_local(),
                 DateTimeKind::Local => self.ndt,
-                DateTimeKind::Unspecified => Local.from_utc_datetime(&self.ndt).naive_local(),
+                DateTimeKind::Utc | DateTimeKind::Unspecified => {
+                    let offset = local_offset_from_utc(&self.ndt);
+                    self.ndt + Duration::seconds(offset.local_minus_utc() as i64)
+                }
             };
-            Self::new(ndt, DateTimeKind::Local)
+            Self::clamped(ndt, DateTimeKind::Local)
         }
 
         pub fn toUniversalTime(&self) -> DateTime {
             let ndt = match self.kind {
                 DateTimeKind::Utc => self.ndt,
-                DateTimeKind::Local => Local.from_local_datetime(&self.ndt).unwrap().naive_utc(),
-                DateTimeKind::Unspecified => {
-                    Local.from_local_datetime(&self.ndt).unwrap().naive_utc()
+                DateTimeKind::Local | DateTimeKind::Unspecified => {
+                    let offset = local_offset(&self.ndt);
+                    self.ndt - Duration::seconds(offset.local_minus_utc() as i64)
                 }
             };
-            Self::new(ndt, DateTimeKind::Utc)
+            Self::clamped(ndt, DateTimeKind::Utc)
+        }
+
+        // DST is detected by comparing with the standard offset of the year,
+        // the smallest offset in January or July (to work in both hemispheres).
+        pub fn isDaylightSavingTime(&self) -> bool {
+            match self.kind {
+                DateTimeKind::Utc => false,
+                DateTimeKind::Local | DateTimeKind::Unspecified => {
+                    let offset = local_offset(&self.ndt).local_minus_utc();
+                    let standard = [1, 7]
+                        .iter()
+                        .filter_map(|&month| NaiveDate::from_ymd_opt(self.year(), month, 1))
+                        .map(|d| local_offset_from_utc(&d.and_time(NaiveTime::MIN)).local_minus_utc())
+                        .min()
+                        .unwrap_or(offset);
+                    offset > standard
+                }
+            }
         }
 
         pub fn localDateTime(&self) -> DateTime {
@@ -377,46 +876,45 @@ pub mod DateTime_ {
         }
 
         pub fn timeOfDay(&self) -> TimeSpan {
-            let d = self.ndt.time() - NaiveTime::MIN;
-            TimeSpan::from_ticks(duration_to_ticks(d))
+            TimeSpan::from_ticks(time_to_ticks(&self.ndt.time()))
         }
 
         pub fn dayNumber(&self) -> i32 {
             self.ndt.num_days_from_ce()
         }
 
-        // todo implement as DayOfWeek enum https://docs.microsoft.com/en-us/dotnet/api/system.dayofweek?view=net-6.0
         pub fn dayOfWeek(&self) -> i32 {
-            let weekday = self.ndt.weekday();
-            match weekday {
-                Weekday::Mon => 1,
-                Weekday::Tue => 2,
-                Weekday::Wed => 3,
-                Weekday::Thu => 4,
-                Weekday::Fri => 5,
-                Weekday::Sat => 6,
-                Weekday::Sun => 0,
-            }
+            self.day_of_week() as i32
+        }
+
+        pub fn day_of_week(&self) -> DayOfWeek {
+            DayOfWeek::from(self.ndt.weekday())
         }
 
         pub fn dayOfYear(&self) -> i32 {
             self.ndt.ordinal() as i32
         }
 
+        // like .NET, the day is clamped to the last day of the resulting month
         pub fn addMonths(&self, months: i32) -> DateTime {
+            if !(-120000..=120000).contains(&months) {
+                panic!("Months value must be between +/-120000. (Parameter 'months')");
+            }
             let ndt = if months < 0 {
-                self.ndt
-                    .checked_sub_months(Months::new(-months as u32))
-                    .unwrap()
+                self.ndt.checked_sub_months(Months::new(-months as u32))
             } else {
-                self.ndt
-                    .checked_add_months(Months::new(months as u32))
-                    .unwrap()
+                self.ndt.checked_add_months(Months::new(months as u32))
             };
-            Self::new(ndt, self.kind)
+            match ndt {
+                Some(ndt) if Self::in_range(&ndt) => DateTime { ndt, kind: self.kind },
+                _ => panic!("The added or subtracted value results in an un-representable DateTime. (Parameter 'months')"),
+            }
         }
 
         pub fn addYears(&self, years: i32) -> DateTime {
+            if !(-10000..=10000).contains(&years) {
+                panic!("Years value must be between +/-10000. (Parameter 'years')");
+            }
             self.addMonths(years * 12)
         }
 
@@ -449,45 +947,150 @@ pub mod DateTime_ {
         }
 
         pub fn toString(&self, format: string) -> string {
-            let fmt = match format.as_str() {
-                "" => "%m/%d/%Y %H:%M:%S".to_string(),
-                "g" => "%m/%d/%Y %H:%M".to_string(),
-                "G" => "%m/%d/%Y %H:%M:%S".to_string(),
-                "o" | "O" => match self.kind {
-                    DateTimeKind::Utc => "%Y-%m-%dT%H:%M:%S%.fZ".to_string(),
-                    DateTimeKind::Local => "%Y-%m-%dT%H:%M:%S%.f%:z".to_string(),
-                    DateTimeKind::Unspecified => "%Y-%m-%dT%H:%M:%S%.f".to_string(),
-                },
-                //TODO: support more formats, custom formats, etc.
-                _ => format
-                    .replace("yyyy", "%Y")
-                    .replace("MM", "%m")
-                    .replace("dd", "%d")
-                    .replace("hh", "%H")
-                    .replace("mm", "%M")
-                    .replace("ss", "%S")
-                    .replace("ffffff", "%6f")
-                    .replace("fff", "%3f"),
+            self.toString2(format, CultureInfo::currentCulture_())
+        }
+
+        pub fn toOADate(&self) -> f64 {
+            const millis_per_day: i64 = ticks_per_day / ticks_per_millisecond;
+            let mut ticks = self.ticks();
+            if ticks == 0 {
+                return 0.0; // DateTime.MinValue is the zero OLE Automation date
+            }
+            if ticks < ticks_per_day {
+                ticks += oa_date_offset_ticks; // a time of day only
+            }
+            if ticks < oa_date_min_ticks {
+                panic!("Not a legal OleAut date.");
+            }
+            let mut millis = (ticks - oa_date_offset_ticks) / ticks_per_millisecond;
+            if millis < 0 {
+                // negative dates count whole days backward, but the time forward
+                let frac = millis % millis_per_day;
+                if frac != 0 {
+                    millis -= (millis_per_day + frac) * 2;
+                }
+            }
+            millis as f64 / millis_per_day as f64
+        }
+
+        pub fn fromOADate(d: f64) -> DateTime {
+            const millis_per_day: i64 = ticks_per_day / ticks_per_millisecond;
+            if !(d < 2958466.0 && d > -657435.0) {
+                panic!("Not a legal OleAut date.");
+            }
+            let dms = d * millis_per_day as f64 + if d >= 0.0 { 0.5 } else { -0.5 };
+            let mut millis = dms as i64;
+            if millis < 0 {
+                millis -= (millis % millis_per_day) * 2;
+            }
+            millis += oa_date_offset_ticks / ticks_per_millisecond;
+            if millis < 0 || millis * ticks_per_millisecond > max_ticks {
+                panic!("OleAut date did not convert to a DateTime correctly.");
+            }
+            Self::new_ticks_kind(millis * ticks_per_millisecond, 0)
+        }
+
+        // unspecified values are taken as UTC
+        pub fn toFileTimeUtc(&self) -> i64 {
+            let ticks = match self.kind {
+                DateTimeKind::Local => self.toUniversalTime().ticks(),
+                DateTimeKind::Utc | DateTimeKind::Unspecified => self.ticks(),
+            } - file_time_offset_ticks;
+            if ticks < 0 {
+                panic!("Not a valid Win32 FileTime.");
+            }
+            ticks
+        }
+
+        // unspecified values are taken as local times
+        pub fn toFileTime(&self) -> i64 {
+            self.toUniversalTime().toFileTimeUtc()
+        }
+
+        pub fn fromFileTimeUtc(fileTime: i64) -> DateTime {
+            if !(0..=max_ticks - file_time_offset_ticks).contains(&fileTime) {
+                panic!("Not a valid Win32 FileTime. (Parameter 'fileTime')");
+            }
+            Self::new_ticks_kind(fileTime + file_time_offset_ticks, 1)
+        }
+
+        pub fn fromFileTime(fileTime: i64) -> DateTime {
+            Self::fromFileTimeUtc(fileTime).toLocalTime()
+        }
+
+        fn try_parse_naive(s: &str) -> Option<NaiveDateTime> {
+            // general formats accepted by .NET with the invariant culture
+            const DATE_TIME_FORMATS: [&str; 7] = [
+                "%Y-%m-%d %H:%M:%S%.f",
+                "%Y-%m-%d %H:%M",
+                "%Y-%m-%dT%H:%M",
+                "%m/%d/%Y %H:%M:%S%.f",
+                "%m/%d/%Y %H:%M",
+                "%m/%d/%Y %I:%M:%S%.f %p",
+                "%m/%d/%Y %I:%M %p",
+            ];
+            const DATE_FORMATS: [&str; 3] = ["%Y-%m-%d", "%m/%d/%Y", "%Y/%m/%d"];
+            const TIME_FORMATS: [&str; 4] = ["%H:%M:%S%.f", "%H:%M", "%I:%M:%S%.f %p", "%I:%M %p"];
+            s.parse::<NaiveDateTime>()
+                .ok()
+                .or_else(|| {
+                    DATE_TIME_FORMATS
+                        .iter()
+                        .find_map(|f| NaiveDateTime::parse_from_str(s, f).ok())
+                })
+                .or_else(|| {
+                    DATE_FORMATS
+                        .iter()
+                        .find_map(|f| NaiveDate::parse_from_str(s, f).ok())
+                        .map(|d| d.and_time(NaiveTime::MIN))
+                })
+                .or_else(|| {
+                    // time-only strings are on the current date
+                    TIME_FORMATS
+                        .iter()
+                        .find_map(|f| NaiveTime::parse_from_str(s, f).ok())
+                        .map(|t| local_now().date_naive().and_time(t))
+                })
+        }
+
+        // Applies the DateTimeStyles flags to a parsed value. Like .NET, a value
+        // with an offset is adjusted to local time (or to UTC), while a value
+        // without one stays unspecified unless AssumeLocal/AssumeUniversal is set.
+        fn from_parsed(ndt: NaiveDateTime, offset_seconds: Option<i32>, is_utc: bool, styles: i32) -> DateTime {
+            let ndt = fold_leap_second(ndt);
+            let adjust_to_utc = styles & DateTimeStyles_AdjustToUniversal != 0;
+            let utc = match offset_seconds {
+                Some(offset) => ndt - Duration::seconds(offset as i64),
+                None if styles & DateTimeStyles_AssumeUniversal != 0 => ndt,
+                None if styles & DateTimeStyles_AssumeLocal != 0 => {
+                    let dt = Self::new(ndt, DateTimeKind::Local);
+                    return if adjust_to_utc { dt.toUniversalTime() } else { dt };
+                }
+                None => return Self::new(ndt, DateTimeKind::Unspecified),
             };
-            let cdt = self.to_cdt_fixed();
-            let df = cdt.format(&fmt);
-            fromString(df.to_string())
+            let dt = Self::new(utc, DateTimeKind::Utc);
+            if adjust_to_utc || (is_utc && styles & DateTimeStyles_RoundtripKind != 0) {
+                dt
+            } else {
+                dt.toLocalTime()
+            }
         }
 
-        fn try_parse_str(s: &str) -> ParseResult<DateTime> {
-            match s
-                .parse::<NaiveDateTime>()
-                .or(NaiveDateTime::parse_from_str(s, "%m/%d/%Y %H:%M:%S%.f"))
-                .or(NaiveDateTime::parse_from_str(s, "%m/%d/%Y %I:%M:%S %P"))
-            {
-                Ok(ndt) => Ok(Self::new(ndt, DateTimeKind::Unspecified)),
-                Err(e) => DateTimeOffset::try_parse_str(s).map(|cdt| {
-                    let ndt = Local.from_utc_datetime(&cdt.naive_utc()).naive_local();
-                    Self::new(ndt, DateTimeKind::Local)
+        fn try_parse_styles(s: &str, styles: i32) -> ParseResult<DateTime> {
+            match Self::try_parse_naive(s) {
+                Some(ndt) => Ok(Self::from_parsed(ndt, None, false, styles)),
+                None => DateTimeOffset::try_parse_str(s).map(|cdt| {
+                    let offset = cdt.offset().local_minus_utc();
+                    let is_utc = s.ends_with('Z') || s.ends_with('z');
+                    Self::from_parsed(cdt.naive_local(), Some(offset), is_utc, styles)
                 }),
             }
         }
 
+        fn try_parse_str(s: &str) -> ParseResult<DateTime> {
+            Self::try_parse_styles(s, DateTimeStyles_None)
+        }
+
         pub fn tryParse(s: string, res: &MutCell<DateTime>) -> bool {
             match Self::try_parse_str(s.trim()) {
                 Ok(dt) => {
@@ -499,19 +1102,27 @@ pub mod DateTime_ {
         }
 
         pub fn parse(s: string) -> DateTime {
-            match Self::try_parse_str(s.trim()) {
+            match Self::try_parse_rs(&s) {
                 Ok(dt) => dt,
-                Err(e) => panic!("The input string {} was not in a correct format.", s),
+                Err(e) => panic!("{}", e),
+            }
+        }
+
+        // like `parse`, for Rust callers
+        pub fn try_parse_rs(s: &str) -> Result<DateTime, ParseError> {
+            match Self::try_parse_str(s.trim()) {
+                Ok(dt) => Ok(dt),
+                Err(_) => Err(ParseError::new(format!("The input string {} was not in a correct format.", s))),
             }
         }
 
         pub(crate) fn to_cdt_fixed(&self) -> CDateTime<FixedOffset> {
-            let now = Local::now();
-            let localTz = now.offset();
             match self.kind {
                 DateTimeKind::Utc => Utc.from_utc_datetime(&self.ndt).into(),
-                DateTimeKind::Local => localTz.from_local_datetime(&self.ndt).unwrap(),
-                DateTimeKind::Unspecified => localTz.from_local_datetime(&self.ndt).unwrap(),
+                DateTimeKind::Local | DateTimeKind::Unspecified => {
+                    let offset = local_offset(&self.ndt);
+                    offset.from_utc_datetime(&(self.ndt - Duration::seconds(offset.local_minus_utc() as i64)))
+                }
             }
         }
     }
@@ -539,4 +1150,650 @@ pub mod DateTime_ {
             self.subtract2(rhs)
         }
     }
+
+    // -----------------------------------------------------------
+    // culture-aware formatting and parsing
+    // -----------------------------------------------------------
+
+    /// Expands a standard (single character) format specifier
+    /// into the equivalent custom format pattern for a culture.
+    pub(crate) fn expand_standard_format(format: &str, dtfi: &DateTimeFormatInfo) -> Option<String> {
+        let mut chars = format.chars();
+        let c = match (chars.next(), chars.next()) {
+            (Some(c), None) => c,
+            _ => return None,
+        };
+        let join = |a: string, b: string| {
+            let mut res = a.to_string();
+            res.push(' ');
+            res.push_str(b.as_str());
+            res
+        };
+        let pattern = match c {
+            'd' => dtfi.shortDatePattern().to_string(),
+            'D' => dtfi.longDatePattern().to_string(),
+            'f' => join(dtfi.longDatePattern(), dtfi.shortTimePattern()),
+            'F' | 'U' => dtfi.fullDateTimePattern().to_string(),
+            'g' => join(dtfi.shortDatePattern(), dtfi.shortTimePattern()),
+            'G' => join(dtfi.shortDatePattern(), dtfi.longTimePattern()),
+            'm' | 'M' => dtfi.monthDayPattern().to_string(),
+            'y' | 'Y' => dtfi.yearMonthPattern().to_string(),
+            't' => dtfi.shortTimePattern().to_string(),
+            'T' => dtfi.longTimePattern().to_string(),
+            'o' | 'O' => "yyyy'-'MM'-'dd'T'HH':'mm':'ss'.'fffffffK".to_string(),
+            'r' | 'R' => dtfi.rFC1123Pattern().to_string(),
+            's' => dtfi.sortableDateTimePattern().to_string(),
+            'u' => dtfi.universalSortableDateTimePattern().to_string(),
+            _ => panic!("Input string was not in a correct format."),
+        };
+        Some(pattern)
+    }
+
+    fn push_padded(res: &mut String, value: i64, width: usize) {
+        let digits = value.abs().to_string();
+        if value < 0 {
+            res.push('-');
+        }
+        for _ in digits.len()..width {
+            res.push('0');
+        }
+        res.push_str(&digits);
+    }
+
+    fn count_repeated(chars: &[char], i: usize) -> usize {
+        let c = chars[i];
+        chars[i..].iter().take_while(|&&x| x == c).count()
+    }
+
+    fn push_offset(res: &mut String, offset_seconds: i32, count: usize) {
+        res.push(if offset_seconds < 0 { '-' } else { '+' });
+        let minutes = offset_seconds.abs() / 60;
+        let (hours, minutes) = (minutes / 60, minutes % 60);
+        match count {
+            1 => push_padded(res, hours as i64, 1),
+            2 => push_padded(res, hours as i64, 2),
+            _ => {
+                push_padded(res, hours as i64, 2);
+                res.push(':');
+                push_padded(res, minutes as i64, 2);
+            }
+        }
+    }
+
+    /// Formats a date and time using a .NET custom format pattern,
+    /// taking names, designators and separators from the culture.
+    pub(crate) fn format_date_time(
+        ndt: &NaiveDateTime,
+        kind: DateTimeKind,
+        offset_seconds: i32,
+        format: &str,
+        dtfi: &DateTimeFormatInfo,
+    ) -> String {
+        let chars: Vec<char> = format.chars().collect();
+        let mut res = String::with_capacity(format.len() * 2);
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            let count = count_repeated(&chars, i);
+            match c {
+                'd' => match count {
+                    1 | 2 => push_padded(&mut res, ndt.day() as i64, count),
+                    3 => res.push_str(dtfi.abbreviated_day_name(ndt.weekday().num_days_from_sunday() as i32)),
+                    _ => res.push_str(dtfi.day_name(ndt.weekday().num_days_from_sunday() as i32)),
+                },
+                'M' => match count {
+                    1 | 2 => push_padded(&mut res, ndt.month() as i64, count),
+                    3 => res.push_str(dtfi.abbreviated_month_name(ndt.month() as i32)),
+                    _ => res.push_str(dtfi.month_name(ndt.month() as i32)),
+                },
+                'y' => match count {
+                    1 | 2 => push_padded(&mut res, (ndt.year() % 100) as i64, count),
+                    _ => push_padded(&mut res, ndt.year() as i64, count),
+                },
+                'h' => {
+                    let hour = match ndt.hour() % 12 {
+                        0 => 12,
+                        h => h,
+                    };
+                    push_padded(&mut res, hour as i64, count.min(2))
+                }
+                'H' => push_padded(&mut res, ndt.hour() as i64, count.min(2)),
+                'm' => push_padded(&mut res, ndt.minute() as i64, count.min(2)),
+                's' => push_padded(&mut res, ndt.second() as i64, count.min(2)),
+                'f' | 'F' => {
+                    if count > 7 {
+                        panic!("Input string was not in a correct format.");
+                    }
+                    let fraction = format!("{:07}", ndt.nanosecond() % 1_000_000_000 / 100);
+                    let digits = &fraction[..count];
+                    if c == 'f' {
+                        res.push_str(digits);
+                    } else {
+                        let digits = digits.trim_end_matches('0');
+                        if digits.is_empty() && res.ends_with('.') {
+                            res.pop();
+                        }
+                        res.push_str(digits);
+                    }
+                }
+                't' => {
+                    let designator = if ndt.hour() < 12 { dtfi.aMDesignator() } else { dtfi.pMDesignator() };
+                    if count == 1 {
+                        res.extend(designator.chars().take(1));
+                    } else {
+                        res.push_str(designator.as_str());
+                    }
+                }
+                'g' => res.push_str("A.D."),
+                'z' => push_offset(&mut res, offset_seconds, count),
+                'K' => match kind {
+                    DateTimeKind::Utc => res.push('Z'),
+                    DateTimeKind::Local => push_offset(&mut res, offset_seconds, 3),
+                    DateTimeKind::Unspecified => (),
+                },
+                ':' => res.push_str(dtfi.timeSeparator().as_str()),
+                '/' => res.push_str(dtfi.dateSeparator().as_str()),
+                '\'' | '"' => {
+                    let end = chars[i + 1..].iter().position(|&x| x == c);
+                    match end {
+                        Some(end) => {
+                            res.extend(&chars[i + 1..i + 1 + end]);
+                            i += end + 2;
+                        }
+                        None => panic!("Cannot find a matching quote character for the character '{}'.", c),
+                    }
+                    continue;
+                }
+                '\\' => {
+                    match chars.get(i + 1) {
+                        Some(&next) => res.push(next),
+                        None => panic!("Input string was not in a correct format."),
+                    }
+                    i += 2;
+                    continue;
+                }
+                '%' => {
+                    i += 1;
+                    continue;
+                }
+                _ => {
+                    res.push(c);
+                    i += 1;
+                    continue;
+                }
+            }
+            i += count;
+        }
+        res
+    }
+
+    #[derive(Clone, Copy, Debug, Default)]
+    pub(crate) struct ParsedDateTime {
+        pub year: Option<i32>,
+        pub month: Option<u32>,
+        pub day: Option<u32>,
+        pub hour: u32,
+        pub minute: u32,
+        pub second: u32,
+        pub nanosecond: u32,
+        pub pm: Option<bool>,
+        pub offset_seconds: Option<i32>,
+        pub is_utc: bool,
+    }
+
+    impl ParsedDateTime {
+        pub(crate) fn to_naive(&self) -> Option<NaiveDateTime> {
+            let today = local_now().date_naive();
+            let date = match (self.year, self.month, self.day) {
+                (None, None, None) => today,
+                (year, month, day) => NaiveDate::from_ymd_opt(
+                    year.unwrap_or(today.year()),
+                    month.unwrap_or(1),
+                    day.unwrap_or(1),
+                )?,
+            };
+            let hour = match self.pm {
+                Some(true) if self.hour < 12 => self.hour + 12,
+                Some(false) if self.hour == 12 => 0,
+                _ => self.hour,
+            };
+            let time = match self.second {
+                // a leap second, folded when the value is created
+                60 => NaiveTime::from_hms_nano_opt(hour, self.minute, 59, self.nanosecond + 1_000_000_000)?,
+                _ => NaiveTime::from_hms_nano_opt(hour, self.minute, self.second, self.nanosecond)?,
+            };
+            Some(date.and_time(time))
+        }
+    }
+
+    fn take_digits(s: &str, max: usize) -> Option<(u32, &str)> {
+        let len = s.chars().take(max).take_while(|c| c.is_ascii_digit()).count();
+        if len == 0 {
+            None
+        } else {
+            Some((s[..len].parse().ok()?, &s[len..]))
+        }
+    }
+
+    fn take_prefix_ci<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+        if prefix.is_empty() {
+            return None;
+        }
+        let mut rest = s.chars();
+        for p in prefix.chars() {
+            match rest.next() {
+                Some(c) if c.to_lowercase().eq(p.to_lowercase()) => (),
+                _ => return None,
+            }
+        }
+        Some(rest.as_str())
+    }
+
+    /// Matches the longest of the given names, returning its (0-based) index.
+    fn take_name<'a>(s: &'a str, names: &[&'static str]) -> Option<(usize, &'a str)> {
+        names
+            .iter()
+            .enumerate()
+            .filter_map(|(i, name)| take_prefix_ci(s, name).map(|rest| (i, rest)))
+            .min_by_key(|(_, rest)| rest.len())
+    }
+
+    fn take_offset(s: &str) -> Option<(i32, &str)> {
+        let sign = match s.chars().next()? {
+            '+' => 1,
+            '-' => -1,
+            _ => return None,
+        };
+        let (hours, rest) = take_digits(&s[1..], 2)?;
+        let (minutes, rest) = match rest.strip_prefix(':') {
+            Some(rest) => take_digits(rest, 2)?,
+            None => match take_digits(rest, 2) {
+                Some((minutes, rest)) => (minutes, rest),
+                None => (0, rest),
+            },
+        };
+        Some((sign * (hours * 3600 + minutes * 60) as i32, rest))
+    }
+
+    /// Parses a date and time using a .NET custom format pattern,
+    /// matching names, designators and separators from the culture.
+    pub(crate) fn parse_date_time(s: &str, format: &str, dtfi: &DateTimeFormatInfo) -> Option<ParsedDateTime> {
+        let chars: Vec<char> = format.chars().collect();
+        let mut res = ParsedDateTime::default();
+        let mut rest = s.trim();
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            let count = count_repeated(&chars, i);
+            match c {
+                'd' if count <= 2 => {
+                    let (day, r) = take_digits(rest, 2)?;
+                    res.day = Some(day);
+                    rest = r;
+                }
+                'd' => {
+                    let names = if count == 3 { &dtfi.abbreviated_day_names } else { &dtfi.day_names };
+                    let (_, r) = take_name(rest, names)
+                        .or_else(|| take_name(rest, &dtfi.abbreviated_day_names))?;
+                    rest = r;
+                }
+                'M' if count <= 2 => {
+                    let (month, r) = take_digits(rest, 2)?;
+                    res.month = Some(month);
+                    rest = r;
+                }
+                'M' => {
+                    let (month, r) = take_name(rest, &dtfi.month_names)
+                        .or_else(|| take_name(rest, &dtfi.abbreviated_month_names))?;
+                    res.month = Some(month as u32 + 1);
+                    rest = r;
+                }
+                'y' => {
+                    let (year, r) = take_digits(rest, if count <= 2 { 2 } else { count.max(4) })?;
+                    let digits = rest.len() - r.len();
+                    res.year = Some(match year {
+                        // two-digit years use the 2049 cut-off
+                        y if digits <= 2 && y < 50 => 2000 + y as i32,
+                        y if digits <= 2 => 1900 + y as i32,
+                        y => y as i32,
+                    });
+                    rest = r;
+                }
+                'h' | 'H' => {
+                    let (hour, r) = take_digits(rest, 2)?;
+                    res.hour = hour;
+                    rest = r;
+                }
+                'm' => {
+                    let (minute, r) = take_digits(rest, 2)?;
+                    res.minute = minute;
+                    rest = r;
+                }
+                's' => {
+                    let (second, r) = take_digits(rest, 2)?;
+                    res.second = second;
+                    rest = r;
+                }
+                'f' | 'F' => {
+                    let len = rest.chars().take(count).take_while(|c| c.is_ascii_digit()).count();
+                    if len == 0 && c == 'f' {
+                        return None;
+                    }
+                    let mut digits = rest[..len].to_string();
+                    while digits.len() < 9 {
+                        digits.push('0');
+                    }
+                    res.nanosecond = digits.parse().ok()?;
+                    rest = &rest[len..];
+                }
+                't' => {
+                    let am = dtfi.aMDesignator();
+                    let pm = dtfi.pMDesignator();
+                    if let Some(r) = take_prefix_ci(rest, am.as_str()) {
+                        res.pm = Some(false);
+                        rest = r;
+                    } else if let Some(r) = take_prefix_ci(rest, pm.as_str()) {
+                        res.pm = Some(true);
+                        rest = r;
+                    } else if !am.is_empty() {
+                        return None;
+                    }
+                }
+                'z' => {
+                    let (offset, r) = take_offset(rest)?;
+                    res.offset_seconds = Some(offset);
+                    rest = r;
+                }
+                'K' => {
+                    if let Some(r) = take_prefix_ci(rest, "Z") {
+                        res.is_utc = true;
+                        res.offset_seconds = Some(0);
+                        rest = r;
+                    } else if let Some((offset, r)) = take_offset(rest) {
+                        res.offset_seconds = Some(offset);
+                        rest = r;
+                    }
+                }
+                ':' | '/' => {
+                    let sep = if c == ':' { dtfi.timeSeparator() } else { dtfi.dateSeparator() };
+                    rest = rest.strip_prefix(sep.as_str())?;
+                }
+                '\'' | '"' => {
+                    let end = chars[i + 1..].iter().position(|&x| x == c)?;
+                    let literal: String = chars[i + 1..i + 1 + end].iter().collect();
+                    rest = take_prefix_ci(rest, literal.trim())
+                        .or(if literal.trim().is_empty() { Some(rest) } else { None })?
+                        .trim_start();
+                    i += end + 2;
+                    continue;
+                }
+                '\\' => {
+                    let next = *chars.get(i + 1)?;
+                    rest = rest.strip_prefix(next)?;
+                    i += 2;
+                    continue;
+                }
+                '%' => {
+                    i += 1;
+                    continue;
+                }
+                _ if c.is_whitespace() => {
+                    rest = rest.trim_start();
+                    i += 1;
+                    continue;
+                }
+                _ => {
+                    match rest.strip_prefix(c) {
+                        Some(r) => rest = r,
+                        // an optional fraction may drop its separator
+                        None if chars.get(i + 1) == Some(&'F') => (),
+                        None => return None,
+                    }
+                    i += 1;
+                    continue;
+                }
+            }
+            i += count;
+        }
+        let rest = rest.trim();
+        if rest.is_empty() {
+            Some(res)
+        } else if res.pm.is_none() && take_prefix_ci(rest, dtfi.aMDesignator().as_str()) == Some("") {
+            // designators are accepted even when the pattern has none
+            res.pm = Some(false);
+            Some(res)
+        } else if res.pm.is_none() && take_prefix_ci(rest, dtfi.pMDesignator().as_str()) == Some("") {
+            res.pm = Some(true);
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    fn culture_date_patterns(dtfi: &DateTimeFormatInfo) -> [String; 3] {
+        // day names are optional when parsing long dates
+        let long_date = dtfi.longDatePattern();
+        let without_day_name = long_date.replace("dddd, ", "").replace("dddd ", "").replace("dddd", "");
+        [dtfi.shortDatePattern().to_string(), long_date.to_string(), without_day_name]
+    }
+
+    // the patterns of a culture without a time of day, used by DateOnly
+    pub(crate) fn culture_date_parse_patterns(dtfi: &DateTimeFormatInfo) -> Vec<String> {
+        let mut patterns = culture_date_patterns(dtfi).to_vec();
+        patterns.push(dtfi.monthDayPattern().to_string());
+        patterns.push(dtfi.yearMonthPattern().to_string());
+        patterns
+    }
+
+    pub(crate) fn culture_parse_patterns(dtfi: &DateTimeFormatInfo) -> Vec<String> {
+        let dates = culture_date_patterns(dtfi);
+        let times = [dtfi.longTimePattern(), dtfi.shortTimePattern()];
+        let mut patterns = Vec::new();
+        for date in dates.iter() {
+            for time in times.iter() {
+                let mut pattern = date.to_string();
+                pattern.push(' ');
+                pattern.push_str(time.as_str());
+                patterns.push(pattern);
+            }
+            patterns.push(date.clone());
+        }
+        patterns.push(dtfi.monthDayPattern().to_string());
+        patterns.push(dtfi.yearMonthPattern().to_string());
+        for time in times.iter() {
+            patterns.push(time.to_string());
+        }
+        patterns
+    }
+
+    impl DateTime {
+        pub fn toString2(&self, format: string, culture: LrcPtr<CultureInfo>) -> string {
+            self.format_with(format.as_str(), culture.date_time_format())
+        }
+
+        // ToString with a DateTimeFormatInfo as format provider
+        pub fn toString3(&self, format: string, dtfi: LrcPtr<DateTimeFormatInfo>) -> string {
+            self.format_with(format.as_str(), &dtfi)
+        }
+
+        fn format_with(&self, format: &str, dtfi: &DateTimeFormatInfo) -> string {
+            // like .NET, only "U" converts to UTC ("r" and "u" format the value as is)
+            let dt = match format {
+                "U" => self.toUniversalTime(),
+                _ => *self,
+            };
+            let offset = dt.to_cdt_fixed().offset().local_minus_utc();
+            let res = match format {
+                "" => {
+                    let pattern = expand_standard_format("G", dtfi).unwrap();
+                    format_date_time(&dt.ndt, dt.kind, offset, &pattern, dtfi)
+                }
+                "o" | "O" | "r" | "R" | "s" | "u" => {
+                    let invariant = CultureInfo::invariantCulture_();
+                    let dtfi = invariant.date_time_format();
+                    let pattern = expand_standard_format(format, dtfi).unwrap();
+                    format_date_time(&dt.ndt, dt.kind, offset, &pattern, dtfi)
+                }
+                _ => match expand_standard_format(format, dtfi) {
+                    Some(pattern) => format_date_time(&dt.ndt, dt.kind, offset, &pattern, dtfi),
+                    None => format_date_time(&dt.ndt, dt.kind, offset, format, dtfi),
+                },
+            };
+            fromString(res)
+        }
+
+        pub(crate) fn try_parse_culture(s: &str, culture: &CultureInfo, styles: i32) -> Option<DateTime> {
+            let dtfi = culture.date_time_format();
+            culture_parse_patterns(dtfi)
+                .iter()
+                .filter_map(|pattern| parse_date_time(s, pattern, dtfi))
+                .find_map(|parsed| {
+                    let ndt = parsed.to_naive()?;
+                    Some(Self::from_parsed(ndt, parsed.offset_seconds, parsed.is_utc, styles))
+                })
+                .or_else(|| Self::try_parse_styles(s, styles).ok())
+        }
+
+        pub fn tryParse2(s: string, culture: LrcPtr<CultureInfo>, res: &MutCell<DateTime>) -> bool {
+            Self::tryParse3(s, culture, DateTimeStyles_None, res)
+        }
+
+        pub fn tryParse3(s: string, culture: LrcPtr<CultureInfo>, styles: i32, res: &MutCell<DateTime>) -> bool {
+            match Self::try_parse_culture(s.trim(), &culture, styles) {
+                Some(dt) => {
+                    res.set(dt);
+                    true
+                }
+                None => false,
+            }
+        }
+
+        pub fn parse2(s: string, culture: LrcPtr<CultureInfo>) -> DateTime {
+            Self::parse3(s, culture, DateTimeStyles_None)
+        }
+
+        pub fn parse3(s: string, culture: LrcPtr<CultureInfo>, styles: i32) -> DateTime {
+            match Self::try_parse_culture(s.trim(), &culture, styles) {
+                Some(dt) => dt,
+                None => panic!("String '{}' was not recognized as a valid DateTime.", s),
+            }
+        }
+
+        // DateTimeStyles are ignored, surrounding white space is always allowed.
+        // Like .NET, a parsed offset or 'Z' gives a local time.
+        fn try_parse_exact(s: &str, formats: &[string], culture: &CultureInfo) -> Option<DateTime> {
+            formats.iter().find_map(|format| {
+                let dtfi = match format.as_str() {
+                    "o" | "O" | "r" | "R" | "s" | "u" => CultureInfo::invariantCulture_().date_time_format(),
+                    _ => culture.date_time_format(),
+                };
+                let parsed = match expand_standard_format(format.as_str(), dtfi) {
+                    Some(pattern) => parse_date_time(s, &pattern, dtfi),
+                    None => parse_date_time(s, format.as_str(), dtfi),
+                }?;
+                let ndt = parsed.to_naive()?;
+                Some(Self::from_parsed(ndt, parsed.offset_seconds, parsed.is_utc, DateTimeStyles_None))
+            })
+        }
+
+        pub fn parseExact(s: string, formats: Array<string>) -> DateTime {
+            Self::parseExact2(s, formats, CultureInfo::currentCulture_())
+        }
+
+        pub fn parseExact2(s: string, formats: Array<string>, culture: LrcPtr<CultureInfo>) -> DateTime {
+            match Self::try_parse_exact(&s, formats.as_slice(), &culture) {
+                Some(dt) => dt,
+                None => panic!("String '{}' was not recognized as a valid DateTime.", s),
+            }
+        }
+
+        pub fn tryParseExact(s: string, formats: Array<string>, res: &MutCell<DateTime>) -> bool {
+            Self::tryParseExact2(s, formats, CultureInfo::currentCulture_(), res)
+        }
+
+        pub fn tryParseExact2(
+            s: string,
+            formats: Array<string>,
+            culture: LrcPtr<CultureInfo>,
+            res: &MutCell<DateTime>,
+        ) -> bool {
+            match Self::try_parse_exact(&s, formats.as_slice(), &culture) {
+                Some(dt) => {
+                    res.set(dt);
+                    true
+                }
+                None => false,
+            }
+        }
+    }
+
+    // -----------------------------------------------------------
+    // System.Globalization.ISOWeek
+    // -----------------------------------------------------------
+
+    // ISO 8601 weeks start on Monday, week 1 is the week with the year's first Thursday
+
+    fn check_iso_year(year: i32) {
+        if !(1..=9999).contains(&year) {
+            panic!("Valid values are between 1 and 9999, inclusive. (Parameter 'year')");
+        }
+    }
+
+    // Monday is 1 and Sunday is 7
+    fn iso_weekday(dayOfWeek: i32) -> i64 {
+        if dayOfWeek == 0 {
+            7
+        } else {
+            dayOfWeek as i64
+        }
+    }
+
+    // like .NET, a week past the end of the year overflows into the next one
+    pub(crate) fn iso_week_to_date(year: i32, week: i32, dayOfWeek: i32) -> NaiveDate {
+        check_iso_year(year);
+        if !(1..=53).contains(&week) {
+            panic!("Valid values are between 1 and 53, inclusive. (Parameter 'week')");
+        }
+        if !(0..=7).contains(&dayOfWeek) {
+            panic!("Valid values are between 0 and 7, inclusive. (Parameter 'dayOfWeek')");
+        }
+        let jan4 = NaiveDate::from_ymd_opt(year, 1, 4).unwrap();
+        let correction = jan4.weekday().number_from_monday() as i64 + 3;
+        let ordinal = week as i64 * 7 + iso_weekday(dayOfWeek) - correction;
+        let date = jan4 + Duration::days(ordinal - 4);
+        if !(1..=9999).contains(&date.year()) {
+            panic!("The added or subtracted value results in an un-representable DateTime. (Parameter 'value')");
+        }
+        date
+    }
+
+    pub fn isoWeekOfYear(dt: DateTime) -> i32 {
+        dt.ndt.iso_week().week() as i32
+    }
+
+    pub fn isoYear(dt: DateTime) -> i32 {
+        dt.ndt.iso_week().year()
+    }
+
+    pub fn isoWeeksInYear(year: i32) -> i32 {
+        check_iso_year(year);
+        NaiveDate::from_ymd_opt(year, 12, 28).unwrap().iso_week().week() as i32
+    }
+
+    pub fn isoYearStart(year: i32) -> DateTime {
+        let date = iso_week_to_date(year, 1, DayOfWeek::Monday as i32);
+        DateTime::new(date.and_time(NaiveTime::MIN), DateTimeKind::Unspecified)
+    }
+
+    // the last tick of the last week
+    pub fn isoYearEnd(year: i32) -> DateTime {
+        let date = iso_week_to_date(year, isoWeeksInYear(year), DayOfWeek::Sunday as i32);
+        let ndt = date.and_time(NaiveTime::MIN) + ticks_to_duration(ticks_per_day - 1);
+        DateTime::new(ndt, DateTimeKind::Unspecified)
+    }
+
+    pub fn isoWeekToDateTime(year: i32, week: i32, dayOfWeek: i32) -> DateTime {
+        let date = iso_week_to_date(year, week, dayOfWeek);
+        DateTime::new(date.and_time(NaiveTime::MIN), DateTimeKind::Unspecified)
+    }
 }
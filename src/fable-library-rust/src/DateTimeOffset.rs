@@ -2,8 +2,15 @@
 pub mod DateTimeOffset_ {
     use crate::{
         DateOnly_::DateOnly,
-        DateTime_::{duration_to_ticks, ticks_to_duration, DateTime, DateTimeKind},
-        Native_::{compare, MutCell, ToString},
+        DateTime_::{
+            culture_parse_patterns, duration_to_ticks, epoch_ndt, expand_standard_format, expect_valid,
+            fold_leap_second, format_date_time, local_now, local_offset_from_utc, max_ndt, min_ndt, naive_to_ticks,
+            parse_date_time, ticks_to_duration, utc_now, DateTime, DateTimeKind, DateTimeStyles_None, DayOfWeek,
+            ParsedDateTime,
+        },
+        Globalization_::{CultureInfo, DateTimeFormatInfo},
+        NativeArray_::Array,
+        Native_::{alloc::format, compare, LrcPtr, MutCell, String, ToString},
         String_::{fromString, string},
         TimeOnly_::TimeOnly,
         TimeSpan_::{
@@ -11,11 +18,17 @@ pub mod DateTimeOffset_ {
         },
     };
     use chrono::{
-        DateTime as CDateTime, Datelike, FixedOffset, Local, Months, NaiveDate, NaiveDateTime,
+        DateTime as CDateTime, Datelike, FixedOffset, Months, NaiveDate, NaiveDateTime,
         NaiveTime, ParseResult, TimeZone, Timelike, Utc,
     };
     use core::ops::{Add, Sub};
 
+    // the Unix times of DateTimeOffset.MinValue and MaxValue
+    const unix_min_seconds: i64 = -62_135_596_800;
+    const unix_max_seconds: i64 = 253_402_300_799;
+
+    const utc_offset: FixedOffset = expect_valid(FixedOffset::east_opt(0));
+
     #[repr(transparent)]
     #[derive(Clone, Copy, Debug)]
     pub struct DateTimeOffset(CDateTime<FixedOffset>);
@@ -26,15 +39,54 @@ pub mod DateTimeOffset_ {
         }
     }
 
+    impl core::str::FromStr for DateTimeOffset {
+        type Err = chrono::ParseError;
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::try_parse_str(s.trim()).map(DateTimeOffset)
+        }
+    }
+
+    // ISO 8601 text, like the "o" format
+    #[cfg(feature = "serde")]
+    impl serde::Serialize for DateTimeOffset {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(self.toString(string("o")).as_str())
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl<'de> serde::Deserialize<'de> for DateTimeOffset {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let s = <crate::Native_::String as serde::Deserialize>::deserialize(deserializer)?;
+            s.parse().map_err(serde::de::Error::custom)
+        }
+    }
+
+    // like .NET, equality and ordering only consider the UTC instant
+
     impl PartialEq for DateTimeOffset {
         fn eq(&self, other: &Self) -> bool {
             self.utcDateTime() == other.utcDateTime()
         }
     }
 
+    impl Eq for DateTimeOffset {}
+
     impl PartialOrd for DateTimeOffset {
         fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-            self.utcDateTime().partial_cmp(&other.utcDateTime())
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for DateTimeOffset {
+        fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+            self.utcDateTime().cmp(&other.utcDateTime())
+        }
+    }
+
+    impl core::hash::Hash for DateTimeOffset {
+        fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+            self.utcDateTime().hash(state)
         }
     }
 
@@ -51,34 +103,50 @@ pub mod DateTimeOffset_ {
     }
 
     impl DateTimeOffset {
-        fn validate(ndt: NaiveDateTime, offset: TimeSpan) {
-            let min_ndt = Self::minValue().0.naive_utc();
-            let max_ndt = Self::maxValue().0.naive_utc();
+        fn validate_offset(offset: TimeSpan) {
             if offset.ticks() % ticks_per_minute != 0 {
                 panic!("Offset must be specified in whole minutes.");
             }
             if offset.ticks().abs() > 14 * ticks_per_hour {
                 panic!("Offset must be within plus or minus 14 hours.");
             }
-            if ndt < min_ndt || ndt > max_ndt {
+        }
+
+        fn in_range(ndt: &NaiveDateTime) -> bool {
+            *ndt >= min_ndt && *ndt <= max_ndt
+        }
+
+        // like .NET, both the UTC and the local time must be valid DateTimes
+        fn validate(ndt: NaiveDateTime, offset: TimeSpan) {
+            Self::validate_offset(offset);
+            if !Self::in_range(&ndt) {
                 panic!("Invalid datetime range.");
             }
+            if !Self::in_range(&(ndt - ticks_to_duration(offset.ticks()))) {
+                panic!("The UTC time represented when the offset is applied must be between year 0 and 10,000. (Parameter 'offset')");
+            }
         }
 
         pub fn new_utc(ndt: NaiveDateTime, offset: TimeSpan) -> DateTimeOffset {
-            Self::validate(ndt, offset);
+            let ndt = fold_leap_second(ndt);
+            Self::validate_offset(offset);
+            if !Self::in_range(&(ndt + ticks_to_duration(offset.ticks()))) {
+                panic!("The added or subtracted value results in an un-representable DateTime. (Parameter 'value')");
+            }
             let ofs = FixedOffset::east_opt(offset.total_seconds() as i32).unwrap();
             let cdt = ofs.from_utc_datetime(&ndt);
             DateTimeOffset(cdt.into())
         }
 
         pub fn new_local(ndt: NaiveDateTime, offset: TimeSpan) -> DateTimeOffset {
+            let ndt = fold_leap_second(ndt);
             Self::validate(ndt, offset);
             let ofs = FixedOffset::east_opt(offset.total_seconds() as i32).unwrap();
             let cdt = ofs.from_local_datetime(&ndt).unwrap();
             DateTimeOffset(cdt.into())
         }
 
+        // the same instant, with another offset
         pub fn toOffset(&self, offset: TimeSpan) -> DateTimeOffset {
             Self::new_utc(self.0.naive_utc(), offset)
         }
@@ -93,9 +161,11 @@ pub mod DateTimeOffset_ {
             Self::new_local(ndt, offset)
         }
 
+        // also the implicit conversion: UTC values get a zero offset,
+        // local and unspecified values the local offset at that time
         pub fn new_datetime(dt: DateTime) -> DateTimeOffset {
-            let cdt = dt.to_cdt_fixed();
-            DateTimeOffset(cdt.into())
+            let offset = dt.to_cdt_fixed().offset().local_minus_utc();
+            Self::new_local(dt.naive_date_time(), TimeSpan::from_seconds(offset as f64))
         }
 
         pub fn new_datetime2(dt: DateTime, offset: TimeSpan) -> DateTimeOffset {
@@ -171,43 +241,47 @@ pub mod DateTimeOffset_ {
         }
 
         pub fn fromUnixTimeSeconds(seconds: i64) -> DateTimeOffset {
+            if !(unix_min_seconds..=unix_max_seconds).contains(&seconds) {
+                panic!(
+                    "Valid values are between {} and {}, inclusive. (Parameter 'seconds')",
+                    unix_min_seconds, unix_max_seconds
+                );
+            }
             let cdt = Utc.timestamp_opt(seconds, 0).unwrap();
             DateTimeOffset(cdt.into())
         }
 
         pub fn fromUnixTimeMilliseconds(millis: i64) -> DateTimeOffset {
+            let min_millis = unix_min_seconds * 1000;
+            let max_millis = unix_max_seconds * 1000 + 999;
+            if millis < min_millis || millis > max_millis {
+                panic!(
+                    "Valid values are between {} and {}, inclusive. (Parameter 'milliseconds')",
+                    min_millis, max_millis
+                );
+            }
             let cdt = Utc.timestamp_millis_opt(millis).unwrap();
             DateTimeOffset(cdt.into())
         }
 
         pub fn now() -> DateTimeOffset {
-            let now = Local::now();
-            let localTz = now.offset();
-            DateTimeOffset(now.with_timezone(localTz).into())
+            DateTimeOffset(local_now())
         }
 
         pub fn utcNow() -> DateTimeOffset {
-            DateTimeOffset(Utc::now().into())
+            DateTimeOffset(utc_now().into())
         }
 
-        pub fn minValue() -> DateTimeOffset {
-            let nd = NaiveDate::from_ymd_opt(1, 1, 1).unwrap();
-            let ndt = nd.and_hms_opt(0, 0, 0).unwrap();
-            let cdt = Utc.from_utc_datetime(&ndt);
-            DateTimeOffset(cdt.into())
+        pub const fn minValue() -> DateTimeOffset {
+            DateTimeOffset(CDateTime::from_naive_utc_and_offset(min_ndt, utc_offset))
         }
 
-        pub fn maxValue() -> DateTimeOffset {
-            let d = ticks_to_duration(1);
-            let nd = NaiveDate::from_ymd_opt(10000, 1, 1).unwrap();
-            let ndt = nd.and_hms_opt(0, 0, 0).unwrap() - d; // one tick before year 10000
-            let cdt = Utc.from_utc_datetime(&ndt);
-            DateTimeOffset(cdt.into())
+        pub const fn maxValue() -> DateTimeOffset {
+            DateTimeOffset(CDateTime::from_naive_utc_and_offset(max_ndt, utc_offset))
         }
 
-        pub fn unixEpoch() -> DateTimeOffset {
-            let cdt = Utc.timestamp_millis_opt(0).unwrap();
-            DateTimeOffset(cdt.into())
+        pub const fn unixEpoch() -> DateTimeOffset {
+            DateTimeOffset(CDateTime::from_naive_utc_and_offset(epoch_ndt, utc_offset))
         }
 
         pub fn add(&self, ts: TimeSpan) -> DateTimeOffset {
@@ -234,11 +308,11 @@ pub mod DateTimeOffset_ {
         }
 
         pub fn ticks(&self) -> i64 {
-            duration_to_ticks(self.0.naive_local() - Self::minValue().0.naive_utc())
+            naive_to_ticks(&self.0.naive_local())
         }
 
         pub fn utcTicks(&self) -> i64 {
-            duration_to_ticks(self.0.naive_utc() - Self::minValue().0.naive_utc())
+            naive_to_ticks(&self.0.naive_utc())
         }
 
         pub fn date(&self) -> DateTime {
@@ -249,10 +323,10 @@ pub mod DateTimeOffset_ {
             DateTime::new(self.0.naive_local(), DateTimeKind::Unspecified)
         }
 
+        // uses the local offset at that time, not the current one
         pub fn toLocalTime(&self) -> DateTimeOffset {
-            let now = Local::now();
-            let localTz = now.offset();
-            DateTimeOffset(self.0.with_timezone(localTz).into())
+            let offset = local_offset_from_utc(&self.0.naive_utc());
+            DateTimeOffset(self.0.with_timezone(&offset))
         }
 
         pub fn toUniversalTime(&self) -> DateTimeOffset {
@@ -260,7 +334,7 @@ pub mod DateTimeOffset_ {
         }
 
         pub fn localDateTime(&self) -> DateTime {
-            let ndt = Local.from_utc_datetime(&self.0.naive_utc()).naive_local();
+            let ndt = self.toLocalTime().0.naive_local();
             DateTime::new(ndt, DateTimeKind::Local)
         }
 
@@ -325,17 +399,12 @@ pub mod DateTimeOffset_ {
             self.0.num_days_from_ce()
         }
 
-        // todo implement as DayOfWeek enum https://docs.microsoft.com/en-us/dotnet/api/system.dayofweek?view=net-6.0
         pub fn dayOfWeek(&self) -> i32 {
-            match self.0.weekday() {
-                chrono::Weekday::Mon => 1,
-                chrono::Weekday::Tue => 2,
-                chrono::Weekday::Wed => 3,
-                chrono::Weekday::Thu => 4,
-                chrono::Weekday::Fri => 5,
-                chrono::Weekday::Sat => 6,
-                chrono::Weekday::Sun => 0,
-            }
+            self.day_of_week() as i32
+        }
+
+        pub fn day_of_week(&self) -> DayOfWeek {
+            DayOfWeek::from(self.0.weekday())
         }
 
         pub fn dayOfYear(&self) -> i32 {
@@ -388,32 +457,31 @@ pub mod DateTimeOffset_ {
         }
 
         pub fn toString(&self, format: string) -> string {
-            let fmt = match format.as_str() {
-                "" => "%m/%d/%Y %H:%M:%S %:z".to_string(),
-                "g" => "%m/%d/%Y %H:%M".to_string(),
-                "G" => "%m/%d/%Y %H:%M:%S".to_string(),
-                "o" | "O" => "%Y-%m-%dT%H:%M:%S%.f%:z".to_string(),
-                //TODO: support more formats, custom formats, etc.
-                _ => format
-                    .replace("yyyy", "%Y")
-                    .replace("MM", "%m")
-                    .replace("dd", "%d")
-                    .replace("hh", "%H")
-                    .replace("mm", "%M")
-                    .replace("ss", "%S")
-                    .replace("ffffff", "%6f")
-                    .replace("fff", "%3f"),
+            self.toString2(format, CultureInfo::currentCulture_())
+        }
+
+        pub fn toString2(&self, format: string, culture: LrcPtr<CultureInfo>) -> string {
+            let dtfi = culture.date_time_format();
+            let invariant = || CultureInfo::invariantCulture_().date_time_format();
+            // like .NET, "r" and "u" convert to UTC, "K" is always the offset
+            let (dto, pattern, dtfi) = match format.as_str() {
+                "" => (*self, default_pattern(dtfi), dtfi),
+                "o" | "O" | "s" => (*self, expand_standard_format(&format, invariant()).unwrap(), invariant()),
+                "r" | "R" | "u" => (self.toUniversalTime(), expand_standard_format(&format, invariant()).unwrap(), invariant()),
+                "U" => panic!("Input string was not in a correct format."),
+                _ => match expand_standard_format(&format, dtfi) {
+                    Some(pattern) => (*self, pattern, dtfi),
+                    None => (*self, format.to_string(), dtfi),
+                },
             };
-            let df = self.0.format(&fmt);
-            fromString(df.to_string())
+            let offset = dto.0.offset().local_minus_utc();
+            let ndt = dto.0.naive_local();
+            fromString(format_date_time(&ndt, DateTimeKind::Local, offset, &pattern, dtfi))
         }
 
         fn local_time_from_str(s: &str, fmt: &str) -> ParseResult<CDateTime<FixedOffset>> {
-            let now = Local::now();
-            let localTz = now.offset();
             let ndt = NaiveDateTime::parse_from_str(s, fmt)?;
-            let loc = localTz.from_local_datetime(&ndt).unwrap();
-            Ok(loc)
+            Ok(DateTime::new(ndt, DateTimeKind::Local).to_cdt_fixed())
         }
 
         pub(crate) fn try_parse_str(s: &str) -> ParseResult<CDateTime<FixedOffset>> {
@@ -421,26 +489,118 @@ pub mod DateTimeOffset_ {
                 .or(CDateTime::parse_from_str(s, "%m/%d/%Y %H:%M:%S%.f %#z"))
                 .or(Self::local_time_from_str(s, "%m/%d/%Y %H:%M:%S%.f"))
                 .or(Self::local_time_from_str(s, "%m/%d/%Y %I:%M:%S %P"))
+                .map(fold_leap_second)
+        }
+
+        // a value without an offset is a local time, like in .NET
+        fn from_parsed(parsed: ParsedDateTime) -> Option<DateTimeOffset> {
+            let ndt = parsed.to_naive()?;
+            let cdt = match parsed.offset_seconds {
+                Some(offset) => FixedOffset::east_opt(offset)?.from_local_datetime(&ndt).single()?,
+                None => DateTime::new(ndt, DateTimeKind::Local).to_cdt_fixed(),
+            };
+            Some(DateTimeOffset(fold_leap_second(cdt)))
+        }
+
+        // the culture's DateTime patterns, with or without a trailing offset
+        fn try_parse_culture(s: &str, culture: &CultureInfo) -> Option<DateTimeOffset> {
+            let dtfi = culture.date_time_format();
+            culture_parse_patterns(dtfi)
+                .iter()
+                .flat_map(|pattern| [format!("{} zzz", pattern), pattern.clone()])
+                .filter_map(|pattern| parse_date_time(s, &pattern, dtfi))
+                .find_map(Self::from_parsed)
+                .or_else(|| Self::try_parse_str(s).ok().map(DateTimeOffset))
+                .or_else(|| {
+                    // other DateTime formats, e.g. a date only
+                    let dt = DateTime::try_parse_culture(s, culture, DateTimeStyles_None)?;
+                    Some(Self::new_datetime(dt))
+                })
         }
 
         pub fn tryParse(s: string, res: &MutCell<DateTimeOffset>) -> bool {
-            match Self::try_parse_str(s.trim()) {
-                Ok(dt) => {
-                    res.set(DateTimeOffset(dt.into()));
+            Self::tryParse2(s, CultureInfo::currentCulture_(), res)
+        }
+
+        pub fn tryParse2(s: string, culture: LrcPtr<CultureInfo>, res: &MutCell<DateTimeOffset>) -> bool {
+            match Self::try_parse_culture(s.trim(), &culture) {
+                Some(dto) => {
+                    res.set(dto);
                     true
                 }
-                Err(e) => false,
+                None => false,
             }
         }
 
         pub fn parse(s: string) -> DateTimeOffset {
-            match Self::try_parse_str(s.trim()) {
-                Ok(dt) => DateTimeOffset(dt.into()),
-                Err(e) => panic!("The input string {} was not in a correct format.", s),
+            Self::parse2(s, CultureInfo::currentCulture_())
+        }
+
+        pub fn parse2(s: string, culture: LrcPtr<CultureInfo>) -> DateTimeOffset {
+            match Self::try_parse_culture(s.trim(), &culture) {
+                Some(dto) => dto,
+                None => panic!("String '{}' was not recognized as a valid DateTime.", s),
+            }
+        }
+
+        // DateTimeStyles are ignored, surrounding white space is always allowed
+        fn try_parse_exact(s: &str, formats: &[string], culture: &CultureInfo) -> Option<DateTimeOffset> {
+            formats.iter().find_map(|format| {
+                let dtfi = match format.as_str() {
+                    "o" | "O" | "r" | "R" | "s" | "u" => CultureInfo::invariantCulture_().date_time_format(),
+                    _ => culture.date_time_format(),
+                };
+                let parsed = match format.as_str() {
+                    "" => parse_date_time(s, &default_pattern(dtfi), dtfi),
+                    _ => match expand_standard_format(format.as_str(), dtfi) {
+                        Some(pattern) => parse_date_time(s, &pattern, dtfi),
+                        None => parse_date_time(s, format.as_str(), dtfi),
+                    },
+                }?;
+                match format.as_str() {
+                    // these formats are always UTC
+                    "r" | "R" | "u" => Self::from_parsed(ParsedDateTime { offset_seconds: Some(0), ..parsed }),
+                    _ => Self::from_parsed(parsed),
+                }
+            })
+        }
+
+        pub fn parseExact(s: string, formats: Array<string>) -> DateTimeOffset {
+            Self::parseExact2(s, formats, CultureInfo::currentCulture_())
+        }
+
+        pub fn parseExact2(s: string, formats: Array<string>, culture: LrcPtr<CultureInfo>) -> DateTimeOffset {
+            match Self::try_parse_exact(&s, formats.as_slice(), &culture) {
+                Some(dto) => dto,
+                None => panic!("String '{}' was not recognized as a valid DateTime.", s),
+            }
+        }
+
+        pub fn tryParseExact(s: string, formats: Array<string>, res: &MutCell<DateTimeOffset>) -> bool {
+            Self::tryParseExact2(s, formats, CultureInfo::currentCulture_(), res)
+        }
+
+        pub fn tryParseExact2(
+            s: string,
+            formats: Array<string>,
+            culture: LrcPtr<CultureInfo>,
+            res: &MutCell<DateTimeOffset>,
+        ) -> bool {
+            match Self::try_parse_exact(&s, formats.as_slice(), &culture) {
+                Some(dto) => {
+                    res.set(dto);
+                    true
+                }
+                None => false,
             }
         }
     }
 
+    // the default format of DateTimeOffset is "G" followed by the offset
+    fn default_pattern(dtfi: &DateTimeFormatInfo) -> String {
+        format!("{} zzz", expand_standard_format("G", dtfi).unwrap())
+    }
+
     impl Add<TimeSpan> for DateTimeOffset {
         type Output = DateTimeOffset;
 
@@ -1,8 +1,10 @@
 #[cfg(feature = "decimal")]
 pub mod Decimal_ {
-    use crate::Native_::{compare, Lrc, MutCell, Vec};
+    use crate::Convert_::{self, TryParse};
+    use crate::Globalization_::{CultureInfo, FormatNumber, NumberFormatInfo, NumberValue};
+    use crate::Native_::{alloc::format, compare, Lrc, LrcPtr, MutCell, Vec};
     use crate::NativeArray_::{new_array, Array};
-    use crate::String_::{string, toString as toString_1};
+    use crate::String_::{fromString as fromString_1, string, toString as toString_1};
     use core::cmp::Ordering;
 
     use rust_decimal::prelude::*;
@@ -14,6 +16,16 @@ pub mod Decimal_ {
     pub const MaxValue: decimal = Decimal::MAX;
     pub const MinValue: decimal = Decimal::MIN;
 
+    const MaxScale: u32 = 28;
+
+    fn overflow() -> ! {
+        panic!("Value was either too large or too small for a Decimal.")
+    }
+
+    fn invalid_bits() -> ! {
+        panic!("Decimal byte array constructor requires an array of length four containing valid decimal bytes.")
+    }
+
     // pub fn getHashCode(x: decimal) = x.GetHashCode()
     pub fn equals(x: decimal, y: decimal) -> bool { x.eq(&y) }
     pub fn compareTo(x: decimal, y: decimal) -> i32 { compare(&x, &y) }
@@ -38,11 +50,24 @@ pub mod Decimal_ {
     pub fn truncate(x: decimal) -> decimal { x.trunc() }
     pub fn pown(x: decimal, n: i32) -> decimal { x.powi(n as i64) }
 
-    pub fn add(x: decimal, y: decimal) -> decimal { x + y }
-    pub fn subtract(x: decimal, y: decimal) -> decimal { x - y }
-    pub fn multiply(x: decimal, y: decimal) -> decimal { x * y }
-    pub fn divide(x: decimal, y: decimal) -> decimal { x / y }
-    pub fn remainder(x: decimal, y: decimal) -> decimal { x % y }
+    pub fn add(x: decimal, y: decimal) -> decimal { x.checked_add(y).unwrap_or_else(|| overflow()) }
+    pub fn subtract(x: decimal, y: decimal) -> decimal { x.checked_sub(y).unwrap_or_else(|| overflow()) }
+    pub fn multiply(x: decimal, y: decimal) -> decimal { x.checked_mul(y).unwrap_or_else(|| overflow()) }
+
+    pub fn divide(x: decimal, y: decimal) -> decimal {
+        if y.is_zero() {
+            panic!("Attempted to divide by zero.")
+        }
+        x.checked_div(y).unwrap_or_else(|| overflow())
+    }
+
+    pub fn remainder(x: decimal, y: decimal) -> decimal {
+        if y.is_zero() {
+            panic!("Attempted to divide by zero.")
+        }
+        x.checked_rem(y).unwrap_or_else(|| overflow())
+    }
+
     pub fn negate(x: decimal) -> decimal { -x }
 
     pub fn isNegative(x: decimal) -> bool { x.is_sign_negative() }
@@ -52,16 +77,25 @@ pub mod Decimal_ {
     // pub fn isOddInteger(x: decimal) -> bool { false } //TODO:
     // pub fn isCanonical(x: decimal) -> bool { false } //TODO:
 
-    pub fn toInt8(x: decimal) -> i8 { x.to_i8().unwrap() }
-    pub fn toUInt8(x: decimal) -> u8 { x.to_u8().unwrap() }
-    pub fn toInt16(x: decimal) -> i16 { x.to_i16().unwrap() }
-    pub fn toUInt16(x: decimal) -> u16 { x.to_u16().unwrap() }
-    pub fn toInt32(x: decimal) -> i32 { x.to_i32().unwrap() }
-    pub fn toUInt32(x: decimal) -> u32 { x.to_u32().unwrap() }
-    pub fn toInt64(x: decimal) -> i64 { x.to_i64().unwrap() }
-    pub fn toUInt64(x: decimal) -> u64 { x.to_u64().unwrap() }
-    pub fn toNativeInt(x: decimal) -> isize { x.to_isize().unwrap() }
-    pub fn toUNativeInt(x: decimal) -> usize { x.to_usize().unwrap() }
+    // the fractional part is truncated, like in .NET
+    fn to_int<T>(n: Option<T>, typeName: &str) -> T {
+        match n {
+            Some(n) => n,
+            None => panic!("Value was either too large or too small for {} {}.",
+                if typeName.starts_with(['I', 'U']) { "an" } else { "a" }, typeName),
+        }
+    }
+
+    pub fn toInt8(x: decimal) -> i8 { to_int(x.trunc().to_i8(), "SByte") }
+    pub fn toUInt8(x: decimal) -> u8 { to_int(x.trunc().to_u8(), "Byte") }
+    pub fn toInt16(x: decimal) -> i16 { to_int(x.trunc().to_i16(), "Int16") }
+    pub fn toUInt16(x: decimal) -> u16 { to_int(x.trunc().to_u16(), "UInt16") }
+    pub fn toInt32(x: decimal) -> i32 { to_int(x.trunc().to_i32(), "Int32") }
+    pub fn toUInt32(x: decimal) -> u32 { to_int(x.trunc().to_u32(), "UInt32") }
+    pub fn toInt64(x: decimal) -> i64 { to_int(x.trunc().to_i64(), "Int64") }
+    pub fn toUInt64(x: decimal) -> u64 { to_int(x.trunc().to_u64(), "UInt64") }
+    pub fn toNativeInt(x: decimal) -> isize { to_int(x.trunc().to_isize(), "Int64") }
+    pub fn toUNativeInt(x: decimal) -> usize { to_int(x.trunc().to_usize(), "UInt64") }
 
     pub fn toFloat32(x: decimal) -> f32 { x.to_f32().unwrap() }
     pub fn toFloat64(x: decimal) -> f64 { x.to_f64().unwrap() }
@@ -74,24 +108,56 @@ pub mod Decimal_ {
         core::char::from_u32(x.to_u32().unwrap()).unwrap()
     }
 
+    // The scale is kept ("1.10" stays "1.10"),
+    // and like in .NET a negative zero is formatted as zero.
     pub fn toString(x: decimal) -> string {
-        toString_1(&x)
+        if x.is_zero() {
+            toString_1(x.abs())
+        } else {
+            toString_1(x)
+        }
     }
 
-    pub fn tryParse(s: string, res: &MutCell<Decimal>) -> bool {
-        match Decimal::from_str(s.trim()) {
-            Ok(d) => { res.set(d); true },
-            Err(e) => false,
+    impl FormatNumber for decimal {
+        fn number_value(&self) -> NumberValue {
+            NumberValue::Decimal {
+                negative: self.is_sign_negative() && !self.is_zero(),
+                mantissa: self.mantissa().unsigned_abs(),
+                scale: self.scale(),
+            }
         }
     }
 
-    pub fn parse(s: string) -> decimal {
-        match Decimal::from_str(s.trim()) {
-            Ok(d) => d,
-            Err(e) => panic!("The input string {} was not in a correct format.", s),
+    impl TryParse<decimal> for decimal {
+        const OVERFLOW: &'static str = "Value was either too large or too small for a Decimal.";
+
+        fn try_parse(s: &str, _radix: i32) -> Option<decimal> {
+            Decimal::from_str(s.trim()).ok()
+        }
+
+        fn try_parse_style(s: &str, style: i32, nfi: &NumberFormatInfo) -> Option<decimal> {
+            Convert_::check_float_style(style);
+            let number = nfi.parse_number(s, style)?;
+            Decimal::from_str(&number.to_fixed_string()?).ok()
         }
     }
 
+    pub fn tryParse(s: string, style: i32, res: &MutCell<decimal>) -> bool {
+        Convert_::tryParse(s, style, res)
+    }
+
+    pub fn tryParse2(s: string, style: i32, culture: LrcPtr<CultureInfo>, res: &MutCell<decimal>) -> bool {
+        Convert_::tryParse2(s, style, culture, res)
+    }
+
+    pub fn parse(s: string, style: i32) -> decimal {
+        Convert_::parse2(s, style, CultureInfo::currentCulture_())
+    }
+
+    pub fn parse2(s: string, style: i32, culture: LrcPtr<CultureInfo>) -> decimal {
+        Convert_::parse2(s, style, culture)
+    }
+
     pub fn fromInt8(n: i8) -> decimal { Decimal::from_i8(n).unwrap() }
     pub fn fromUInt8(n: u8) -> decimal { Decimal::from_u8(n).unwrap() }
     pub fn fromInt16(n: i16) -> decimal { Decimal::from_i16(n).unwrap() }
@@ -103,8 +169,35 @@ pub mod Decimal_ {
     pub fn fromNativeInt(n: isize) -> decimal { Decimal::from_isize(n).unwrap() }
     pub fn fromUNativeInt(n: usize) -> decimal { Decimal::from_usize(n).unwrap() }
 
-    pub fn fromFloat32(n: f32) -> decimal { Decimal::from_f32(n).unwrap() }
-    pub fn fromFloat64(n: f64) -> decimal { Decimal::from_f64(n).unwrap() }
+    // Like in .NET, a float is rounded to its significant digits
+    // (7 for float32, 15 for float64), and to at most 28 decimal places.
+    fn from_float(n: f64, digits: usize) -> decimal {
+        if !n.is_finite() || n.abs() >= 7.922816251426434e28 {
+            overflow()
+        }
+        let s = format!("{:.*e}", digits - 1, n.abs());
+        let (m, e) = s.split_once('e').unwrap();
+        let mut mantissa: i128 = m.replace('.', "").parse().unwrap();
+        let mut scale = digits as i32 - 1 - e.parse::<i32>().unwrap();
+        if scale < 0 {
+            mantissa *= 10i128.pow((-scale) as u32);
+            scale = 0;
+        } else if scale > MaxScale as i32 {
+            let drop = (scale - MaxScale as i32) as u32;
+            mantissa = if drop > digits as u32 {
+                0
+            } else {
+                let d = Decimal::from_i128_with_scale(mantissa, drop);
+                d.round_dp_with_strategy(0, RoundingStrategy::MidpointNearestEven).mantissa()
+            };
+            scale = MaxScale as i32;
+        }
+        let d = Decimal::from_i128_with_scale(mantissa, scale as u32).normalize();
+        if n < 0.0 { -d } else { d }
+    }
+
+    pub fn fromFloat32(n: f32) -> decimal { from_float(n as f64, 7) }
+    pub fn fromFloat64(n: f64) -> decimal { from_float(n, 15) }
 
     pub fn fromDecimal(d: decimal) -> decimal { d }
 
@@ -121,16 +214,26 @@ pub mod Decimal_ {
     }
 
     pub fn fromParts(low: i32, mid: i32, high: i32, isNegative: bool, scale: u8) -> decimal {
+        if scale as u32 > MaxScale {
+            panic!("Decimal's scale value must be between 0 and 28, inclusive. (Parameter 'scale')")
+        }
         Decimal::from_parts(low as u32, mid as u32, high as u32, isNegative, scale as u32)
     }
 
+    // signExp: bits 16-23 hold the scale and bit 31 the sign, the other bits must be zero
     pub fn fromInts(low: i32, mid: i32, high: i32, signExp: i32) -> decimal {
         let isNegative = signExp < 0;
-        let scale = ((signExp >> 16) & 0x7F) as u8;
-        fromParts(low, mid, high, isNegative, scale)
+        let scale = (signExp >> 16) & 0xFF;
+        if (signExp & 0x7F00FFFF) != 0 || scale as u32 > MaxScale {
+            invalid_bits()
+        }
+        fromParts(low, mid, high, isNegative, scale as u8)
     }
 
     pub fn fromIntArray(bits: Array<i32>) -> decimal {
+        if bits.len() != 4 {
+            invalid_bits()
+        }
         fromInts(bits[0], bits[1], bits[2], bits[3])
     }
 
@@ -139,39 +242,41 @@ pub mod Decimal_ {
         let low = du.lo as i32;
         let mid = du.mid as i32;
         let high = du.hi as i32;
-        let scale = du.scale as i32;
-        let signExp =
-            if du.negative { -(scale << 16) } else { scale << 16 };
+        let scale = (du.scale as i32) << 16;
+        let signExp = if du.negative { scale | i32::MIN } else { scale };
         new_array(&[low, mid, high, signExp])
     }
 
+    // Rounds half to even (banker's rounding), like in .NET
     pub fn round(x: decimal) -> decimal { x.round() }
 
     pub fn roundTo(x: decimal, dp: i32) -> decimal {
-        x.round_dp(dp as u32)
+        roundToMode(x, dp, 0)
     }
 
-    pub enum MidpointRounding {
-        ToEven = 0,
-        AwayFromZero = 1,
-        ToZero = 2,
-        ToNegativeInfinity = 3,
-        ToPositiveInfinity = 4
-    }
+    // MidpointRounding
+    const ToEven: i32 = 0;
+    const AwayFromZero: i32 = 1;
+    const ToZero: i32 = 2;
+    const ToNegativeInfinity: i32 = 3;
+    const ToPositiveInfinity: i32 = 4;
 
-    pub fn roundToMode(x: decimal, dp: i32, mode: MidpointRounding) -> decimal {
+    pub fn roundToMode(x: decimal, dp: i32, mode: i32) -> decimal {
+        if dp < 0 || dp as u32 > MaxScale {
+            panic!("Rounding digits must be between 0 and 28, inclusive. (Parameter 'decimals')")
+        }
         let strategy = match mode {
-            MidpointRounding::ToEven => RoundingStrategy::MidpointNearestEven,
-            MidpointRounding::AwayFromZero => RoundingStrategy::MidpointAwayFromZero,
-            MidpointRounding::ToZero => RoundingStrategy::ToZero,
-            MidpointRounding::ToNegativeInfinity => RoundingStrategy::ToNegativeInfinity,
-            MidpointRounding::ToPositiveInfinity => RoundingStrategy::ToPositiveInfinity,
-            // _ => RoundingStrategy::MidpointNearestEven,
+            ToEven => RoundingStrategy::MidpointNearestEven,
+            AwayFromZero => RoundingStrategy::MidpointAwayFromZero,
+            ToZero => RoundingStrategy::ToZero,
+            ToNegativeInfinity => RoundingStrategy::ToNegativeInfinity,
+            ToPositiveInfinity => RoundingStrategy::ToPositiveInfinity,
+            _ => panic!("The value '{}' is not valid for this usage of the type MidpointRounding. (Parameter 'mode')", mode),
         };
         x.round_dp_with_strategy(dp as u32, strategy)
     }
 
-    pub fn roundMode(x: decimal, mode: MidpointRounding) -> decimal {
+    pub fn roundMode(x: decimal, mode: i32) -> decimal {
         roundToMode(x, 0, mode)
     }
 
@@ -1,67 +1,21 @@
 pub mod Encoding_ {
     use crate::NativeArray_::{array_from, Array};
-    use crate::Native_::{Lrc, LrcPtr, MutCell, String, Vec};
+    use crate::Native_::{alloc::{format, vec}, Lrc, LrcPtr, MutCell, String, Vec};
     use crate::String_::{fromChars2, fromSlice, fromString, string, substring2_safe};
 
+    // Encodings implement the conversions of a whole string or byte slice,
+    // the overloads for ranges and char arrays are provided on top of them.
     pub trait Encoding {
-        fn getBytes(&self, s: string) -> Array<u8>;
-        fn getBytes2(&self, s: string, index: i32, count: i32) -> Array<u8>;
-        fn getBytesFromChars(&self, chars: Array<char>) -> Array<u8>;
-        fn getBytesFromChars2(&self, chars: Array<char>, index: i32, count: i32) -> Array<u8>;
-        fn getByteCount(&self, s: string) -> i32;
-        fn getByteCount2(&self, s: string, index: i32, count: i32) -> i32;
-        fn getByteCountFromChars(&self, chars: Array<char>) -> i32;
-        fn getByteCountFromChars2(&self, chars: Array<char>, index: i32, count: i32) -> i32;
-        fn getChars(&self, bytes: Array<u8>) -> Array<char>;
-        fn getChars2(&self, bytes: Array<u8>, index: i32, count: i32) -> Array<char>;
-        fn getCharCount(&self, bytes: Array<u8>) -> i32;
-        fn getCharCount2(&self, bytes: Array<u8>, index: i32, count: i32) -> i32;
+        fn get_bytes(&self, s: &str) -> Vec<u8>;
+        fn get_string(&self, bytes: &[u8]) -> String;
         fn getMaxByteCount(&self, charCount: i32) -> i32;
         fn getMaxCharCount(&self, byteCount: i32) -> i32;
-        fn getString(&self, bytes: Array<u8>) -> string;
-        fn getString2(&self, bytes: Array<u8>, index: i32, count: i32) -> string;
-    }
-
-    #[inline]
-    fn as_u16_slice(bytes: &[u8]) -> &[u16] {
-        unsafe { core::mem::transmute(&bytes[0..(bytes.len() >> 1)]) }
-    }
-
-    #[inline]
-    fn get_slice(bytes: &[u8], index: i32, count: i32) -> &[u8] {
-        &bytes[index as usize..(index + count) as usize]
-    }
+        fn getPreamble(&self) -> Array<u8>;
+        fn codePage(&self) -> i32;
+        fn webName(&self) -> string;
 
-    pub struct UTF16LE {}
-
-    pub fn get_Unicode() -> LrcPtr<dyn Encoding> {
-        static utf16le: MutCell<Option<LrcPtr<dyn Encoding>>> = MutCell::new(None);
-        utf16le.get_or_init(move || LrcPtr::from(Lrc::from(UTF16LE {}) as Lrc<dyn Encoding>))
-    }
-
-    impl UTF16LE {
-        fn get_string(&self, bytes: &[u8]) -> String {
-            let chars = as_u16_slice(&bytes);
-            match String::from_utf16(chars) {
-                Ok(mut s) => {
-                    if bytes.len() & 1 == 1 {
-                        let lastByte = bytes[bytes.len() - 1];
-                        s.push(lastByte as char);
-                    }
-                    s
-                }
-                Err(e) => panic!("{}", e),
-            }
-        }
-    }
-
-    impl Encoding for UTF16LE {
         fn getBytes(&self, s: string) -> Array<u8> {
-            let bytes: Vec<u8> = s
-                .encode_utf16()
-                .flat_map(|c| [(c & 0xFF) as u8, (c >> 8) as u8])
-                .collect();
-            array_from(bytes)
+            array_from(self.get_bytes(&s))
         }
 
         fn getBytes2(&self, s: string, index: i32, count: i32) -> Array<u8> {
@@ -77,7 +31,7 @@ pub mod Encoding_ {
         }
 
         fn getByteCount(&self, s: string) -> i32 {
-            (s.encode_utf16().count() * 2) as i32
+            self.get_bytes(&s).len() as i32
         }
 
         fn getByteCount2(&self, s: string, index: i32, count: i32) -> i32 {
@@ -112,6 +66,89 @@ pub mod Encoding_ {
             s.chars().count() as i32
         }
 
+        fn getString(&self, bytes: Array<u8>) -> string {
+            fromString(self.get_string(&bytes))
+        }
+
+        fn getString2(&self, bytes: Array<u8>, index: i32, count: i32) -> string {
+            fromString(self.get_string(get_slice(&bytes, index, count)))
+        }
+    }
+
+    #[inline]
+    fn get_slice(bytes: &[u8], index: i32, count: i32) -> &[u8] {
+        &bytes[index as usize..(index + count) as usize]
+    }
+
+    fn shared(encoding: impl Encoding + 'static) -> LrcPtr<dyn Encoding> {
+        LrcPtr::from(Lrc::from(encoding) as Lrc<dyn Encoding>)
+    }
+
+    fn invalid_bytes(bytes: &[u8], index: usize) -> ! {
+        let hex: Vec<String> = bytes.iter().map(|b| format!("{:02X}", b)).collect();
+        panic!("Unable to translate bytes [{}] at index {} from specified code page to Unicode.", hex.join("]["), index)
+    }
+
+    // -----------------------------------------------------------
+    // UTF-16
+    // -----------------------------------------------------------
+
+    pub struct UTF16 {
+        bigEndian: bool,
+        byteOrderMark: bool,
+        throwOnInvalid: bool,
+    }
+
+    pub fn get_Unicode() -> LrcPtr<dyn Encoding> {
+        static utf16le: MutCell<Option<LrcPtr<dyn Encoding>>> = MutCell::new(None);
+        utf16le.get_or_init(move || newUnicode(false, true, false))
+    }
+
+    pub fn get_BigEndianUnicode() -> LrcPtr<dyn Encoding> {
+        static utf16be: MutCell<Option<LrcPtr<dyn Encoding>>> = MutCell::new(None);
+        utf16be.get_or_init(move || newUnicode(true, true, false))
+    }
+
+    pub fn newUnicode(bigEndian: bool, byteOrderMark: bool, throwOnInvalid: bool) -> LrcPtr<dyn Encoding> {
+        shared(UTF16 { bigEndian, byteOrderMark, throwOnInvalid })
+    }
+
+    impl Encoding for UTF16 {
+        fn get_bytes(&self, s: &str) -> Vec<u8> {
+            s.encode_utf16()
+                .flat_map(|c| if self.bigEndian { c.to_be_bytes() } else { c.to_le_bytes() })
+                .collect()
+        }
+
+        // Invalid surrogates and a trailing odd byte are replaced with U+FFFD.
+        fn get_string(&self, bytes: &[u8]) -> String {
+            let units = bytes.chunks_exact(2).map(|b| {
+                if self.bigEndian { u16::from_be_bytes([b[0], b[1]]) } else { u16::from_le_bytes([b[0], b[1]]) }
+            });
+            let mut s = String::with_capacity(bytes.len() / 2);
+            for (i, c) in char::decode_utf16(units).enumerate() {
+                match c {
+                    Ok(c) => s.push(c),
+                    Err(e) if self.throwOnInvalid => {
+                        let pos = bytes.len().min(i * 2);
+                        invalid_bytes(&e.unpaired_surrogate().to_le_bytes(), pos)
+                    }
+                    Err(_) => s.push(char::REPLACEMENT_CHARACTER),
+                }
+            }
+            if bytes.len() & 1 == 1 {
+                if self.throwOnInvalid {
+                    invalid_bytes(&bytes[bytes.len() - 1..], bytes.len() - 1)
+                }
+                s.push(char::REPLACEMENT_CHARACTER);
+            }
+            s
+        }
+
+        fn getByteCount(&self, s: string) -> i32 {
+            (s.encode_utf16().count() * 2) as i32
+        }
+
         fn getMaxByteCount(&self, charCount: i32) -> i32 {
             (charCount + 1) * 2
         }
@@ -120,102 +157,358 @@ pub mod Encoding_ {
             (byteCount + 1) / 2 + 1
         }
 
-        fn getString(&self, bytes: Array<u8>) -> string {
-            let s = self.get_string(&bytes);
-            fromString(s)
+        fn getPreamble(&self) -> Array<u8> {
+            match (self.byteOrderMark, self.bigEndian) {
+                (false, _) => array_from(Vec::new()),
+                (true, false) => array_from(vec![0xFF, 0xFE]),
+                (true, true) => array_from(vec![0xFE, 0xFF]),
+            }
         }
 
-        fn getString2(&self, bytes: Array<u8>, index: i32, count: i32) -> string {
-            let s = self.get_string(get_slice(&bytes, index, count));
-            fromString(s)
+        fn codePage(&self) -> i32 {
+            if self.bigEndian { 1201 } else { 1200 }
+        }
+
+        fn webName(&self) -> string {
+            string(if self.bigEndian { "utf-16BE" } else { "utf-16" })
         }
     }
 
-    pub struct UTF8 {}
+    // -----------------------------------------------------------
+    // UTF-8
+    // -----------------------------------------------------------
+
+    pub struct UTF8 {
+        byteOrderMark: bool,
+        throwOnInvalid: bool,
+    }
 
     pub fn get_UTF8() -> LrcPtr<dyn Encoding> {
         static utf8: MutCell<Option<LrcPtr<dyn Encoding>>> = MutCell::new(None);
-        utf8.get_or_init(move || LrcPtr::from(Lrc::from(UTF8 {}) as Lrc<dyn Encoding>))
+        utf8.get_or_init(move || newUTF8(true, false))
+    }
+
+    pub fn newUTF8(byteOrderMark: bool, throwOnInvalid: bool) -> LrcPtr<dyn Encoding> {
+        shared(UTF8 { byteOrderMark, throwOnInvalid })
     }
 
     impl UTF8 {
-        fn get_string(bytes: &[u8]) -> &str {
+        // Invalid sequences are replaced with U+FFFD, valid input is not copied.
+        fn decode<R>(&self, bytes: &[u8], f: impl FnOnce(&str) -> R) -> R {
             match core::str::from_utf8(bytes) {
-                Ok(s) => s,
-                Err(e) => panic!("{}", e),
+                Ok(s) => f(s),
+                Err(e) if self.throwOnInvalid => {
+                    let pos = e.valid_up_to();
+                    let len = e.error_len().unwrap_or(bytes.len() - pos);
+                    invalid_bytes(&bytes[pos..pos + len], pos)
+                }
+                Err(_) => f(&String::from_utf8_lossy(bytes)),
             }
         }
     }
 
     impl Encoding for UTF8 {
-        fn getBytes(&self, s: string) -> Array<u8> {
-            array_from(s.as_bytes().to_vec())
+        fn get_bytes(&self, s: &str) -> Vec<u8> {
+            s.as_bytes().to_vec()
         }
 
-        fn getBytes2(&self, s: string, index: i32, count: i32) -> Array<u8> {
-            self.getBytes(substring2_safe(s, index, count))
+        fn get_string(&self, bytes: &[u8]) -> String {
+            self.decode(bytes, |s| String::from(s))
         }
 
-        fn getBytesFromChars(&self, chars: Array<char>) -> Array<u8> {
-            self.getBytes(fromString(chars.iter().collect()))
+        fn getByteCount(&self, s: string) -> i32 {
+            s.len() as i32
         }
 
-        fn getBytesFromChars2(&self, chars: Array<char>, index: i32, count: i32) -> Array<u8> {
-            self.getBytes(fromChars2(chars, index, count))
+        fn getCharCount(&self, bytes: Array<u8>) -> i32 {
+            self.decode(&bytes, |s| s.chars().count() as i32)
         }
 
-        fn getByteCount(&self, s: string) -> i32 {
-            s.as_bytes().len() as i32
+        fn getCharCount2(&self, bytes: Array<u8>, index: i32, count: i32) -> i32 {
+            self.decode(get_slice(&bytes, index, count), |s| s.chars().count() as i32)
         }
 
-        fn getByteCount2(&self, s: string, index: i32, count: i32) -> i32 {
-            self.getByteCount(substring2_safe(s, index, count))
+        fn getMaxByteCount(&self, charCount: i32) -> i32 {
+            (charCount + 1) * 3
         }
 
-        fn getByteCountFromChars(&self, chars: Array<char>) -> i32 {
-            self.getByteCount(fromString(chars.iter().collect()))
+        fn getMaxCharCount(&self, byteCount: i32) -> i32 {
+            byteCount + 1
         }
 
-        fn getByteCountFromChars2(&self, chars: Array<char>, index: i32, count: i32) -> i32 {
-            self.getByteCount(fromChars2(chars, index, count))
+        fn getString(&self, bytes: Array<u8>) -> string {
+            self.decode(&bytes, fromSlice)
         }
 
-        fn getChars(&self, bytes: Array<u8>) -> Array<char> {
-            let s = UTF8::get_string(&bytes);
-            array_from(s.chars().collect())
+        fn getString2(&self, bytes: Array<u8>, index: i32, count: i32) -> string {
+            self.decode(get_slice(&bytes, index, count), fromSlice)
         }
 
-        fn getChars2(&self, bytes: Array<u8>, index: i32, count: i32) -> Array<char> {
-            let s = UTF8::get_string(get_slice(&bytes, index, count));
-            array_from(s.chars().collect())
+        fn getPreamble(&self) -> Array<u8> {
+            if self.byteOrderMark {
+                array_from(vec![0xEF, 0xBB, 0xBF])
+            } else {
+                array_from(Vec::new())
+            }
         }
 
-        fn getCharCount(&self, bytes: Array<u8>) -> i32 {
-            let s = UTF8::get_string(&bytes);
+        fn codePage(&self) -> i32 {
+            65001
+        }
+
+        fn webName(&self) -> string {
+            string("utf-8")
+        }
+    }
+
+    // -----------------------------------------------------------
+    // ASCII and Latin-1
+    // -----------------------------------------------------------
+
+    // Single byte encodings of the first 128 or 256 code points.
+    // Other chars are encoded as '?', and ASCII decodes bytes above 0x7F as '?'.
+    pub struct SingleByte {
+        maxChar: u32,
+        codePage: i32,
+        webName: &'static str,
+    }
+
+    pub fn get_ASCII() -> LrcPtr<dyn Encoding> {
+        static ascii: MutCell<Option<LrcPtr<dyn Encoding>>> = MutCell::new(None);
+        ascii.get_or_init(move || shared(SingleByte { maxChar: 0x7F, codePage: 20127, webName: "us-ascii" }))
+    }
+
+    pub fn get_Latin1() -> LrcPtr<dyn Encoding> {
+        static latin1: MutCell<Option<LrcPtr<dyn Encoding>>> = MutCell::new(None);
+        latin1.get_or_init(move || shared(SingleByte { maxChar: 0xFF, codePage: 28591, webName: "iso-8859-1" }))
+    }
+
+    impl Encoding for SingleByte {
+        fn get_bytes(&self, s: &str) -> Vec<u8> {
+            s.chars()
+                .map(|c| if c as u32 <= self.maxChar { c as u8 } else { b'?' })
+                .collect()
+        }
+
+        fn get_string(&self, bytes: &[u8]) -> String {
+            bytes.iter()
+                .map(|&b| if b as u32 <= self.maxChar { b as char } else { '?' })
+                .collect()
+        }
+
+        fn getByteCount(&self, s: string) -> i32 {
             s.chars().count() as i32
         }
 
+        fn getCharCount(&self, bytes: Array<u8>) -> i32 {
+            bytes.len() as i32
+        }
+
         fn getCharCount2(&self, bytes: Array<u8>, index: i32, count: i32) -> i32 {
-            let s = UTF8::get_string(get_slice(&bytes, index, count));
-            s.chars().count() as i32
+            get_slice(&bytes, index, count).len() as i32
         }
 
         fn getMaxByteCount(&self, charCount: i32) -> i32 {
-            (charCount + 1) * 3
+            charCount + 1
+        }
+
+        fn getMaxCharCount(&self, byteCount: i32) -> i32 {
+            byteCount
+        }
+
+        fn getPreamble(&self) -> Array<u8> {
+            array_from(Vec::new())
+        }
+
+        fn codePage(&self) -> i32 {
+            self.codePage
+        }
+
+        fn webName(&self) -> string {
+            string(self.webName)
+        }
+    }
+
+    // -----------------------------------------------------------
+    // Encodings by name or code page
+    // -----------------------------------------------------------
+
+    fn builtin_encoding(name: &str) -> Option<LrcPtr<dyn Encoding>> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "utf-8" | "utf8" => Some(get_UTF8()),
+            "utf-16" | "utf-16le" | "unicode" => Some(get_Unicode()),
+            "utf-16be" | "unicodefffe" => Some(get_BigEndianUnicode()),
+            "us-ascii" | "ascii" => Some(get_ASCII()),
+            "iso-8859-1" | "latin1" => Some(get_Latin1()),
+            _ => None,
+        }
+    }
+
+    fn builtin_codepage(codepage: i32) -> Option<LrcPtr<dyn Encoding>> {
+        match codepage {
+            1200 => Some(get_Unicode()),
+            1201 => Some(get_BigEndianUnicode()),
+            20127 => Some(get_ASCII()),
+            28591 => Some(get_Latin1()),
+            65001 => Some(get_UTF8()),
+            _ => None,
+        }
+    }
+
+    #[cfg(not(feature = "codepages"))]
+    fn find_encoding(_label: &str) -> Option<LrcPtr<dyn Encoding>> {
+        None
+    }
+
+    #[cfg(not(feature = "codepages"))]
+    fn find_codepage(_codepage: i32) -> Option<LrcPtr<dyn Encoding>> {
+        None
+    }
+
+    pub fn getEncoding(name: string) -> LrcPtr<dyn Encoding> {
+        match builtin_encoding(&name).or_else(|| find_encoding(name.trim())) {
+            Some(encoding) => encoding,
+            None => panic!("'{}' is not a supported encoding name. (Parameter 'name')", name),
+        }
+    }
+
+    pub fn getEncodingFromCodePage(codepage: i32) -> LrcPtr<dyn Encoding> {
+        match builtin_codepage(codepage).or_else(|| find_codepage(codepage)) {
+            Some(encoding) => encoding,
+            None => panic!("No data is available for encoding {}. (Parameter 'codepage')", codepage),
+        }
+    }
+
+    #[cfg(feature = "codepages")]
+    pub struct CodePage {
+        encoding: &'static encoding_rs::Encoding,
+    }
+
+    #[cfg(feature = "codepages")]
+    const CODEPAGES: &[(i32, &str)] = &[
+        (866, "ibm866"),
+        (874, "windows-874"),
+        (932, "shift_jis"),
+        (936, "gbk"),
+        (949, "euc-kr"),
+        (950, "big5"),
+        (1250, "windows-1250"),
+        (1251, "windows-1251"),
+        (1252, "windows-1252"),
+        (1253, "windows-1253"),
+        (1254, "windows-1254"),
+        (1255, "windows-1255"),
+        (1256, "windows-1256"),
+        (1257, "windows-1257"),
+        (1258, "windows-1258"),
+        (10000, "macintosh"),
+        (10017, "x-mac-cyrillic"),
+        (20866, "koi8-r"),
+        (20932, "euc-jp"),
+        (21866, "koi8-u"),
+        (28591, "iso-8859-1"),
+        (28592, "iso-8859-2"),
+        (28593, "iso-8859-3"),
+        (28594, "iso-8859-4"),
+        (28595, "iso-8859-5"),
+        (28596, "iso-8859-6"),
+        (28597, "iso-8859-7"),
+        (28598, "iso-8859-8"),
+        (28599, "iso-8859-9"),
+        (28603, "iso-8859-13"),
+        (28605, "iso-8859-15"),
+        (38598, "iso-8859-8-i"),
+        (50220, "iso-2022-jp"),
+        (51932, "euc-jp"),
+        (54936, "gb18030"),
+    ];
+
+    #[cfg(feature = "codepages")]
+    fn from_encoding(encoding: &'static encoding_rs::Encoding) -> Option<LrcPtr<dyn Encoding>> {
+        if encoding == encoding_rs::UTF_8 {
+            Some(get_UTF8())
+        } else if encoding == encoding_rs::UTF_16LE {
+            Some(get_Unicode())
+        } else if encoding == encoding_rs::UTF_16BE {
+            Some(get_BigEndianUnicode())
+        } else if encoding.output_encoding() != encoding {
+            // decode-only encodings (replacement) are not supported
+            None
+        } else {
+            Some(shared(CodePage { encoding }))
+        }
+    }
+
+    #[cfg(feature = "codepages")]
+    fn find_encoding(label: &str) -> Option<LrcPtr<dyn Encoding>> {
+        encoding_rs::Encoding::for_label(label.as_bytes()).and_then(from_encoding)
+    }
+
+    #[cfg(feature = "codepages")]
+    fn find_codepage(codepage: i32) -> Option<LrcPtr<dyn Encoding>> {
+        CODEPAGES
+            .iter()
+            .find(|(cp, _)| *cp == codepage)
+            .and_then(|(_, label)| encoding_rs::Encoding::for_label(label.as_bytes()))
+            .and_then(from_encoding)
+    }
+
+    #[cfg(feature = "codepages")]
+    impl Encoding for CodePage {
+        fn get_bytes(&self, s: &str) -> Vec<u8> {
+            let mut encoder = self.encoding.new_encoder();
+            let mut bytes: Vec<u8> = Vec::with_capacity(s.len());
+            let mut src = s;
+            loop {
+                let needed = encoder
+                    .max_buffer_length_from_utf8_without_replacement(src.len())
+                    .unwrap_or(src.len() * 4 + 8);
+                bytes.reserve(needed);
+                let (result, read) =
+                    encoder.encode_from_utf8_to_vec_without_replacement(src, &mut bytes, true);
+                src = &src[read..];
+                match result {
+                    encoding_rs::EncoderResult::InputEmpty => break,
+                    encoding_rs::EncoderResult::OutputFull => (),
+                    // same fallback as .NET's default replacement
+                    encoding_rs::EncoderResult::Unmappable(_) => bytes.push(b'?'),
+                }
+            }
+            bytes
+        }
+
+        fn get_string(&self, bytes: &[u8]) -> String {
+            let (s, _) = self.encoding.decode_without_bom_handling(bytes);
+            s.into_owned()
+        }
+
+        fn getMaxByteCount(&self, charCount: i32) -> i32 {
+            if self.encoding.is_single_byte() {
+                charCount + 1
+            } else if self.encoding == encoding_rs::GB18030 {
+                (charCount + 1) * 4
+            } else {
+                (charCount + 1) * 2
+            }
         }
 
         fn getMaxCharCount(&self, byteCount: i32) -> i32 {
             byteCount + 1
         }
 
-        fn getString(&self, bytes: Array<u8>) -> string {
-            let s = UTF8::get_string(&bytes);
-            fromSlice(s)
+        fn getPreamble(&self) -> Array<u8> {
+            array_from(Vec::new())
         }
 
-        fn getString2(&self, bytes: Array<u8>, index: i32, count: i32) -> string {
-            let s = UTF8::get_string(get_slice(&bytes, index, count));
-            fromSlice(s)
+        fn codePage(&self) -> i32 {
+            CODEPAGES
+                .iter()
+                .find(|(_, label)| encoding_rs::Encoding::for_label(label.as_bytes()) == Some(self.encoding))
+                .map_or(0, |(cp, _)| *cp)
+        }
+
+        fn webName(&self) -> string {
+            fromString(self.encoding.name().to_ascii_lowercase())
         }
     }
 }
@@ -4,6 +4,19 @@ pub mod Exception_ {
     use crate::System::Exception;
     use crate::Util_::new_Exception;
 
+    // The .NET type name of the last exception raised from F# code, which is
+    // otherwise lost when the exception is turned into a panic message.
+    #[cfg(not(feature = "no_std"))]
+    thread_local! {
+        static thrown_type: core::cell::Cell<Option<&'static str>> = const { core::cell::Cell::new(None) };
+    }
+
+    pub fn raise(typeName: &'static str, message: string) -> ! {
+        #[cfg(not(feature = "no_std"))]
+        thrown_type.with(|t| t.set(Some(typeName)));
+        panic!("{}", message)
+    }
+
     #[cfg(feature = "no_std")]
     pub fn try_catch<F, G, R>(try_f: F, catch_f: G) -> R
     where
@@ -18,6 +31,17 @@ pub mod Exception_ {
     where
         F: FnOnce() -> R + core::panic::UnwindSafe,
         G: FnOnce(LrcPtr<Exception>) -> R,
+    {
+        try_catch_typed(try_f, |ex, _| catch_f(ex))
+    }
+
+    /// Like try_catch, but also passes the .NET type name of the exception,
+    /// which is only known for exceptions raised from F# code.
+    #[cfg(not(feature = "no_std"))]
+    pub fn try_catch_typed<F, G, R>(try_f: F, catch_f: G) -> R
+    where
+        F: FnOnce() -> R + core::panic::UnwindSafe,
+        G: FnOnce(LrcPtr<Exception>, Option<&'static str>) -> R,
     {
         fn get_ex(err: Box<dyn Any>) -> LrcPtr<Exception> {
             match err.downcast_ref::<&'static str>() {
@@ -31,13 +55,14 @@ pub mod Exception_ {
                 },
             }
         }
+        thrown_type.with(|t| t.set(None));
         let prev_hook = std::panic::take_hook();
         std::panic::set_hook(Box::new(|_| {}));
         let result = std::panic::catch_unwind(try_f);
         std::panic::set_hook(prev_hook);
         match result {
             Ok(res) => res,
-            Err(err) => catch_f(get_ex(err)),
+            Err(err) => catch_f(get_ex(err), thrown_type.with(|t| t.take())),
         }
     }
 
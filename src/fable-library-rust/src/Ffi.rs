@@ -0,0 +1,153 @@
+#[cfg(not(feature = "no_std"))]
+pub mod Ffi_ {
+    // -----------------------------------------------------------
+    // Helpers for exposing generated functions over `extern "C"`
+    // -----------------------------------------------------------
+
+    use crate::Exception_::try_catch;
+    use crate::String_::{fromString, string};
+    use core::ffi::{c_char, c_void};
+    use core::panic::UnwindSafe;
+    use std::cell::RefCell;
+    use std::ffi::{CStr, CString};
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum ErrorCode {
+        Ok = 0,
+        Exception = 1,
+        NullPointer = 2,
+        InvalidUtf8 = 3,
+        InvalidString = 4,
+    }
+
+    /// Opaque pointer to a boxed Rust value, safe to pass through C code.
+    /// It owns the value, so it is not Copy, to avoid freeing it twice.
+    #[repr(C)]
+    #[derive(Debug)]
+    pub struct Handle<T> {
+        ptr: *mut c_void,
+        _marker: core::marker::PhantomData<T>,
+    }
+
+    impl<T> Handle<T> {
+        pub fn new(value: T) -> Self {
+            let ptr = Box::into_raw(Box::new(value)) as *mut c_void;
+            Handle {
+                ptr,
+                _marker: core::marker::PhantomData,
+            }
+        }
+
+        pub fn null() -> Self {
+            Handle {
+                ptr: core::ptr::null_mut(),
+                _marker: core::marker::PhantomData,
+            }
+        }
+
+        pub fn is_null(&self) -> bool {
+            self.ptr.is_null()
+        }
+
+        /// # Safety
+        /// The handle must have been created by `Handle::new` and not yet freed.
+        pub unsafe fn as_ref<'a>(&self) -> Result<&'a T, ErrorCode> {
+            (self.ptr as *const T).as_ref().ok_or(ErrorCode::NullPointer)
+        }
+
+        /// # Safety
+        /// The handle must have been created by `Handle::new` and not yet freed.
+        /// It must not be used after this call.
+        pub unsafe fn free(self) {
+            if !self.ptr.is_null() {
+                drop(Box::from_raw(self.ptr as *mut T));
+            }
+        }
+    }
+
+    thread_local! {
+        static last_error: RefCell<Option<CString>> = const { RefCell::new(None) };
+    }
+
+    fn set_last_error(message: &str) {
+        let message = CString::new(message.replace('\0', " ")).unwrap_or_default();
+        last_error.with(|e| *e.borrow_mut() = Some(message));
+    }
+
+    fn set_error_code(code: ErrorCode) -> ErrorCode {
+        match code {
+            ErrorCode::Ok => (),
+            ErrorCode::Exception => (), // message already set
+            ErrorCode::NullPointer => set_last_error("Value cannot be null."),
+            ErrorCode::InvalidUtf8 => set_last_error("String is not valid UTF-8."),
+            ErrorCode::InvalidString => set_last_error("String contains a null character."),
+        }
+        code
+    }
+
+    /// Runs `f` at the FFI boundary, catching panics and F# exceptions.
+    /// On success the result is written to `out` (when not null).
+    /// On failure the error message is available from `fable_last_error_message`.
+    ///
+    /// # Safety
+    /// `out` must be null or valid for writes of `R`, and properly aligned.
+    pub unsafe fn catch_panic<F, R>(out: *mut R, f: F) -> ErrorCode
+    where
+        F: FnOnce() -> Result<R, ErrorCode> + UnwindSafe,
+    {
+        let res = try_catch(f, |ex| {
+            set_last_error(ex.get_Message().as_str());
+            Err(ErrorCode::Exception)
+        });
+        match res {
+            Ok(value) => {
+                if !out.is_null() {
+                    out.write(value);
+                }
+                ErrorCode::Ok
+            }
+            Err(code) => set_error_code(code),
+        }
+    }
+
+    /// # Safety
+    /// `s` must be null or point to a null-terminated string.
+    pub unsafe fn string_from_c(s: *const c_char) -> Result<string, ErrorCode> {
+        if s.is_null() {
+            Err(ErrorCode::NullPointer)
+        } else {
+            match CStr::from_ptr(s).to_str() {
+                Ok(s) => Ok(fromString(s.to_string())),
+                Err(_) => Err(ErrorCode::InvalidUtf8),
+            }
+        }
+    }
+
+    /// Returns a UTF-8 null-terminated copy of `s`, to be released with `fable_free_string`.
+    pub fn string_to_c(s: string) -> Result<*mut c_char, ErrorCode> {
+        match CString::new(s.as_str()) {
+            Ok(s) => Ok(s.into_raw()),
+            Err(_) => Err(ErrorCode::InvalidString),
+        }
+    }
+
+    /// # Safety
+    /// `s` must be null or a string returned by `string_to_c`.
+    #[no_mangle]
+    pub unsafe extern "C" fn fable_free_string(s: *mut c_char) {
+        if !s.is_null() {
+            drop(CString::from_raw(s));
+        }
+    }
+
+    /// Returns the last error message on the current thread, or null.
+    /// The pointer is valid until the next failing call on the same thread.
+    #[no_mangle]
+    pub extern "C" fn fable_last_error_message() -> *const c_char {
+        last_error.with(|e| match e.borrow().as_ref() {
+            Some(message) => message.as_ptr(),
+            None => core::ptr::null(),
+        })
+    }
+}
@@ -0,0 +1,2550 @@
+pub mod Globalization_ {
+    #[cfg(feature = "datetime")]
+    use crate::Calendar_::Calendar;
+    use crate::{
+        NativeArray_::{array_from, Array},
+        Native_::{alloc::format, Any, Lrc, LrcPtr, MutCell, String, ToString, Vec},
+        String_::{
+            compare_ignore_case, ends_with_ignore_case, fold_case, fromString, hash_ignore_case,
+            starts_with_ignore_case, string,
+        },
+    };
+
+    #[derive(Clone, Debug)]
+    pub struct NumberFormatInfo {
+        number_decimal_separator: &'static str,
+        number_group_separator: &'static str,
+        number_group_sizes: &'static [i32],
+        number_decimal_digits: i32,
+        number_negative_pattern: i32,
+        negative_sign: &'static str,
+        positive_sign: &'static str,
+        percent_symbol: &'static str,
+        per_mille_symbol: &'static str,
+        nan_symbol: &'static str,
+        positive_infinity_symbol: &'static str,
+        negative_infinity_symbol: &'static str,
+        currency_symbol: &'static str,
+        currency_decimal_digits: i32,
+        currency_positive_pattern: i32,
+        currency_negative_pattern: i32,
+        percent_decimal_digits: i32,
+        percent_positive_pattern: i32,
+        percent_negative_pattern: i32,
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct DateTimeFormatInfo {
+        pub(crate) day_names: [&'static str; 7],
+        pub(crate) abbreviated_day_names: [&'static str; 7],
+        pub(crate) month_names: [&'static str; 12],
+        pub(crate) abbreviated_month_names: [&'static str; 12],
+        am_designator: &'static str,
+        pm_designator: &'static str,
+        date_separator: &'static str,
+        time_separator: &'static str,
+        short_date_pattern: &'static str,
+        long_date_pattern: &'static str,
+        short_time_pattern: &'static str,
+        long_time_pattern: &'static str,
+        month_day_pattern: &'static str,
+        year_month_pattern: &'static str,
+        first_day_of_week: i32,
+        calendar_week_rule: i32,
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct CultureInfo {
+        name: &'static str,
+        english_name: &'static str,
+        native_name: &'static str,
+        is_neutral: bool,
+        number_format: &'static NumberFormatInfo,
+        date_time_format: &'static DateTimeFormatInfo,
+    }
+
+    fn to_array<T: Clone>(xs: &[T]) -> Array<T> {
+        array_from(xs.to_vec())
+    }
+
+    fn to_string_array(xs: &[&'static str]) -> Array<string> {
+        array_from(xs.iter().map(|s| string(s)).collect())
+    }
+
+    impl NumberFormatInfo {
+        pub fn invariantInfo_() -> LrcPtr<NumberFormatInfo> {
+            LrcPtr::new(INVARIANT_NUMBER_FORMAT.clone())
+        }
+
+        pub fn currentInfo_() -> LrcPtr<NumberFormatInfo> {
+            CultureInfo::currentCulture_().numberFormat()
+        }
+
+        pub fn numberDecimalSeparator(&self) -> string {
+            string(self.number_decimal_separator)
+        }
+
+        pub fn numberGroupSeparator(&self) -> string {
+            string(self.number_group_separator)
+        }
+
+        pub fn numberGroupSizes(&self) -> Array<i32> {
+            to_array(self.number_group_sizes)
+        }
+
+        pub fn numberDecimalDigits(&self) -> i32 {
+            self.number_decimal_digits
+        }
+
+        pub fn numberNegativePattern(&self) -> i32 {
+            self.number_negative_pattern
+        }
+
+        pub fn negativeSign(&self) -> string {
+            string(self.negative_sign)
+        }
+
+        pub fn positiveSign(&self) -> string {
+            string(self.positive_sign)
+        }
+
+        pub fn percentSymbol(&self) -> string {
+            string(self.percent_symbol)
+        }
+
+        pub fn perMilleSymbol(&self) -> string {
+            string(self.per_mille_symbol)
+        }
+
+        pub fn naNSymbol(&self) -> string {
+            string(self.nan_symbol)
+        }
+
+        pub fn positiveInfinitySymbol(&self) -> string {
+            string(self.positive_infinity_symbol)
+        }
+
+        pub fn negativeInfinitySymbol(&self) -> string {
+            string(self.negative_infinity_symbol)
+        }
+
+        pub fn currencySymbol(&self) -> string {
+            string(self.currency_symbol)
+        }
+
+        pub fn currencyDecimalSeparator(&self) -> string {
+            string(self.number_decimal_separator)
+        }
+
+        pub fn currencyGroupSeparator(&self) -> string {
+            string(self.number_group_separator)
+        }
+
+        pub fn currencyGroupSizes(&self) -> Array<i32> {
+            to_array(self.number_group_sizes)
+        }
+
+        pub fn currencyDecimalDigits(&self) -> i32 {
+            self.currency_decimal_digits
+        }
+
+        pub fn currencyPositivePattern(&self) -> i32 {
+            self.currency_positive_pattern
+        }
+
+        pub fn currencyNegativePattern(&self) -> i32 {
+            self.currency_negative_pattern
+        }
+
+        pub fn percentDecimalSeparator(&self) -> string {
+            string(self.number_decimal_separator)
+        }
+
+        pub fn percentGroupSeparator(&self) -> string {
+            string(self.number_group_separator)
+        }
+
+        pub fn percentGroupSizes(&self) -> Array<i32> {
+            to_array(self.number_group_sizes)
+        }
+
+        pub fn percentDecimalDigits(&self) -> i32 {
+            self.percent_decimal_digits
+        }
+
+        pub fn percentPositivePattern(&self) -> i32 {
+            self.percent_positive_pattern
+        }
+
+        pub fn percentNegativePattern(&self) -> i32 {
+            self.percent_negative_pattern
+        }
+
+        pub fn isReadOnly(&self) -> bool {
+            true
+        }
+
+        /// Rewrites a culture-formatted number into the invariant form
+        /// understood by the Rust parsers (group separators are dropped).
+        pub(crate) fn to_invariant(&self, s: &str) -> String {
+            let s = s.trim();
+            if s == self.nan_symbol {
+                return "NaN".to_string();
+            }
+            if s == self.positive_infinity_symbol {
+                return "Infinity".to_string();
+            }
+            if s == self.negative_infinity_symbol {
+                return "-Infinity".to_string();
+            }
+            let mut res = String::with_capacity(s.len());
+            let mut rest = s;
+            while let Some(c) = rest.chars().next() {
+                if rest.starts_with(self.number_group_separator) {
+                    rest = &rest[self.number_group_separator.len()..];
+                } else if rest.starts_with(self.number_decimal_separator) {
+                    res.push('.');
+                    rest = &rest[self.number_decimal_separator.len()..];
+                } else if rest.starts_with(self.negative_sign) {
+                    res.push('-');
+                    rest = &rest[self.negative_sign.len()..];
+                } else if rest.starts_with(self.positive_sign) {
+                    res.push('+');
+                    rest = &rest[self.positive_sign.len()..];
+                } else {
+                    res.push(c);
+                    rest = &rest[c.len_utf8()..];
+                }
+            }
+            res
+        }
+
+        /// Rewrites an invariant-formatted number using this culture's
+        /// decimal separator, signs and special value symbols.
+        pub(crate) fn from_invariant(&self, s: &str) -> String {
+            match s {
+                "NaN" => return self.nan_symbol.to_string(),
+                "Infinity" | "inf" => return self.positive_infinity_symbol.to_string(),
+                "-Infinity" | "-inf" => return self.negative_infinity_symbol.to_string(),
+                _ => (),
+            }
+            let mut res = String::with_capacity(s.len());
+            for c in s.chars() {
+                match c {
+                    '.' => res.push_str(self.number_decimal_separator),
+                    '-' => res.push_str(self.negative_sign),
+                    '+' => res.push_str(self.positive_sign),
+                    _ => res.push(c),
+                }
+            }
+            res
+        }
+    }
+
+    // ------------------------------------------------------------------
+    // Number parsing
+    // ------------------------------------------------------------------
+
+    // NumberStyles
+    pub(crate) const AllowLeadingWhite: i32 = 0x0001;
+    pub(crate) const AllowTrailingWhite: i32 = 0x0002;
+    pub(crate) const AllowLeadingSign: i32 = 0x0004;
+    pub(crate) const AllowTrailingSign: i32 = 0x0008;
+    pub(crate) const AllowParentheses: i32 = 0x0010;
+    pub(crate) const AllowDecimalPoint: i32 = 0x0020;
+    pub(crate) const AllowThousands: i32 = 0x0040;
+    pub(crate) const AllowExponent: i32 = 0x0080;
+    pub(crate) const AllowCurrencySymbol: i32 = 0x0100;
+    pub(crate) const AllowHexSpecifier: i32 = 0x0200;
+    pub(crate) const AllowBinarySpecifier: i32 = 0x0400;
+
+    /// A number parsed with a NumberStyles, the value is digits * 10^exponent
+    pub(crate) struct ParsedNumber {
+        negative: bool,
+        digits: String,
+        exponent: i32,
+    }
+
+    impl ParsedNumber {
+        fn is_zero(&self) -> bool {
+            self.digits.bytes().all(|d| d == b'0')
+        }
+
+        /// The invariant form understood by the Rust float parsers
+        pub(crate) fn to_float_string(&self) -> String {
+            let sign = if self.negative { "-" } else { "" };
+            format!("{}{}e{}", sign, self.digits, self.exponent)
+        }
+
+        /// The invariant integer, or None when the fractional part is not zero
+        pub(crate) fn to_integer_string(&self) -> Option<String> {
+            let sign = if self.negative && !self.is_zero() { "-" } else { "" };
+            let (int, frac) = self.split_digits()?;
+            if !frac.bytes().all(|d| d == b'0') {
+                return None;
+            }
+            let int = int.trim_start_matches('0');
+            let int = if int.is_empty() { "0" } else { int };
+            Some(format!("{}{}", sign, int))
+        }
+
+        /// The invariant form without exponent, keeping the trailing zeros
+        pub(crate) fn to_fixed_string(&self) -> Option<String> {
+            let sign = if self.negative { "-" } else { "" };
+            let (int, frac) = self.split_digits()?;
+            let int = if int.is_empty() { "0".to_string() } else { int };
+            if frac.is_empty() {
+                Some(format!("{}{}", sign, int))
+            } else {
+                Some(format!("{}{}.{}", sign, int, frac))
+            }
+        }
+
+        // The integral and fractional digits, or None when the number is too large.
+        // The leading zeros of a number too small for any type are limited.
+        fn split_digits(&self) -> Option<(String, String)> {
+            let len = self.digits.len();
+            if self.exponent >= 0 {
+                if self.exponent > 64 && !self.is_zero() {
+                    return None;
+                }
+                let zeros = "0".repeat(self.exponent.min(64) as usize);
+                return Some((format!("{}{}", self.digits, zeros), String::new()));
+            }
+            let scale = self.exponent.unsigned_abs() as usize;
+            if scale > len + 64 {
+                let digits = self.digits.trim_start_matches('0');
+                return Some((String::new(), format!("{}{}", "0".repeat(64), digits)));
+            }
+            if scale <= len {
+                let (int, frac) = self.digits.split_at(len - scale);
+                Some((int.to_string(), frac.to_string()))
+            } else {
+                Some((String::new(), format!("{}{}", "0".repeat(scale - len), self.digits)))
+            }
+        }
+    }
+
+    fn is_white(c: char) -> bool {
+        matches!(c, ' ' | '\t'..='\r')
+    }
+
+    /// The digits of a hexadecimal or binary number, with their radix
+    pub(crate) fn parse_radix_digits(s: &str, style: i32) -> Option<(&str, u32)> {
+        let mut s = s;
+        if (style & AllowLeadingWhite) != 0 {
+            s = s.trim_start_matches(is_white);
+        }
+        if (style & AllowTrailingWhite) != 0 {
+            s = s.trim_end_matches(is_white);
+        }
+        let radix = if (style & AllowHexSpecifier) != 0 { 16 } else { 2 };
+        if s.is_empty() || !s.chars().all(|c| c.is_digit(radix)) {
+            return None;
+        }
+        Some((s, radix))
+    }
+
+    impl NumberFormatInfo {
+        /// Parses a number like .NET does with the given NumberStyles:
+        /// [ws][$][sign][digits,]digits[.[digits]][e[sign]digits][$][sign][ws]
+        /// where the sign can also be given with parentheses.
+        pub(crate) fn parse_number(&self, s: &str, style: i32) -> Option<ParsedNumber> {
+            let allow = |flag: i32| (style & flag) != 0;
+            let mut rest = s;
+            let skip = |rest: &mut &str, symbol: &str| {
+                if !symbol.is_empty() && rest.starts_with(symbol) {
+                    *rest = &rest[symbol.len()..];
+                    true
+                } else {
+                    false
+                }
+            };
+            let mut negative = false;
+            let mut signed = false;
+            let mut parens = false;
+            let mut currency = false;
+            // leading sign, parentheses, currency symbol and white space
+            loop {
+                if allow(AllowLeadingWhite) && !signed && rest.starts_with(is_white) {
+                    rest = rest.trim_start_matches(is_white);
+                } else if allow(AllowLeadingSign) && !signed && skip(&mut rest, self.negative_sign) {
+                    negative = true;
+                    signed = true;
+                } else if allow(AllowLeadingSign) && !signed && skip(&mut rest, self.positive_sign) {
+                    signed = true;
+                } else if allow(AllowParentheses) && !signed && skip(&mut rest, "(") {
+                    negative = true;
+                    signed = true;
+                    parens = true;
+                } else if allow(AllowCurrencySymbol) && !currency && skip(&mut rest, self.currency_symbol) {
+                    currency = true;
+                } else {
+                    break;
+                }
+            }
+            // integral and fractional digits
+            let mut digits = String::new();
+            let mut exponent: i32 = 0;
+            let mut has_digits = false;
+            let mut fraction = false;
+            while let Some(c) = rest.chars().next() {
+                if c.is_ascii_digit() {
+                    digits.push(c);
+                    has_digits = true;
+                    if fraction {
+                        exponent -= 1;
+                    }
+                    rest = &rest[1..];
+                } else if allow(AllowDecimalPoint) && !fraction && skip(&mut rest, self.number_decimal_separator) {
+                    fraction = true;
+                } else if allow(AllowThousands) && has_digits && !fraction
+                    && skip(&mut rest, self.number_group_separator)
+                {
+                    continue;
+                } else {
+                    break;
+                }
+            }
+            if !has_digits {
+                return None;
+            }
+            // exponent
+            if allow(AllowExponent) && rest.starts_with(['e', 'E']) {
+                let mut exp = &rest[1..];
+                let exp_negative = skip(&mut exp, self.negative_sign);
+                if !exp_negative {
+                    skip(&mut exp, self.positive_sign);
+                }
+                let exp_digits = exp.len() - exp.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+                if exp_digits > 0 {
+                    let value = exp[..exp_digits].bytes().fold(0i32, |n, d| {
+                        n.saturating_mul(10).saturating_add((d - b'0') as i32)
+                    });
+                    let value = if exp_negative { -value } else { value };
+                    exponent = exponent.saturating_add(value);
+                    rest = &exp[exp_digits..];
+                }
+            }
+            // trailing sign, parentheses, currency symbol and white space
+            loop {
+                if allow(AllowTrailingWhite) && rest.starts_with(is_white) {
+                    rest = rest.trim_start_matches(is_white);
+                } else if allow(AllowTrailingSign) && !signed && skip(&mut rest, self.negative_sign) {
+                    negative = true;
+                    signed = true;
+                } else if allow(AllowTrailingSign) && !signed && skip(&mut rest, self.positive_sign) {
+                    signed = true;
+                } else if parens && skip(&mut rest, ")") {
+                    parens = false;
+                } else if allow(AllowCurrencySymbol) && !currency && skip(&mut rest, self.currency_symbol) {
+                    currency = true;
+                } else {
+                    break;
+                }
+            }
+            if !rest.is_empty() || parens {
+                return None;
+            }
+            Some(ParsedNumber { negative, digits, exponent })
+        }
+
+        /// Parses the NaN and infinity symbols accepted by Double.Parse
+        pub(crate) fn parse_float_symbol(&self, s: &str, style: i32) -> Option<f64> {
+            let mut s = s;
+            if (style & AllowLeadingWhite) != 0 {
+                s = s.trim_start_matches(is_white);
+            }
+            if (style & AllowTrailingWhite) != 0 {
+                s = s.trim_end_matches(is_white);
+            }
+            let is = |symbol: &str| s.eq_ignore_ascii_case(symbol);
+            if is(self.nan_symbol) {
+                Some(f64::NAN)
+            } else if is(self.positive_infinity_symbol) || is("∞") {
+                Some(f64::INFINITY)
+            } else if is(self.negative_infinity_symbol) || is("-∞") {
+                Some(f64::NEG_INFINITY)
+            } else {
+                None
+            }
+        }
+    }
+
+    impl NumberFormatInfo {
+        fn group_digits(&self, digits: &str, res: &mut String) {
+            // group sizes apply from the decimal separator leftwards,
+            // the last size repeats and a size of 0 stops the grouping
+            let mut groups: Vec<&str> = Vec::new();
+            let mut rest = digits;
+            let mut sizes = self.number_group_sizes.iter();
+            let mut size = 0;
+            loop {
+                if let Some(&next) = sizes.next() {
+                    size = next as usize;
+                }
+                if size == 0 || rest.len() <= size {
+                    groups.push(rest);
+                    break;
+                }
+                let (head, tail) = rest.split_at(rest.len() - size);
+                groups.push(tail);
+                rest = head;
+            }
+            for (i, group) in groups.iter().rev().enumerate() {
+                if i > 0 {
+                    res.push_str(self.number_group_separator);
+                }
+                res.push_str(group);
+            }
+        }
+
+        /// Formats the absolute amount given in invariant fixed-point
+        /// form (e.g. "1234.50") using the culture currency patterns.
+        pub(crate) fn format_currency(&self, negative: bool, amount: &str) -> String {
+            let (int_part, frac_part) = match amount.split_once('.') {
+                Some((i, f)) => (i, f),
+                None => (amount, ""),
+            };
+            let mut number = String::with_capacity(amount.len() + 8);
+            self.group_digits(int_part, &mut number);
+            if !frac_part.is_empty() {
+                number.push_str(self.number_decimal_separator);
+                number.push_str(frac_part);
+            }
+            let negative = negative && amount.chars().any(|c| c != '0' && c != '.');
+            let pattern = if negative {
+                match self.currency_negative_pattern {
+                    0 => "($n)",
+                    1 => "-$n",
+                    2 => "$-n",
+                    3 => "$n-",
+                    4 => "(n$)",
+                    5 => "-n$",
+                    6 => "n-$",
+                    7 => "n$-",
+                    8 => "-n $",
+                    9 => "-$ n",
+                    10 => "n $-",
+                    11 => "$ n-",
+                    12 => "$ -n",
+                    13 => "n- $",
+                    14 => "($ n)",
+                    15 => "(n $)",
+                    _ => "$- n",
+                }
+            } else {
+                match self.currency_positive_pattern {
+                    0 => "$n",
+                    1 => "n$",
+                    2 => "$ n",
+                    _ => "n $",
+                }
+            };
+            let mut res = String::with_capacity(number.len() + 8);
+            for c in pattern.chars() {
+                match c {
+                    '$' => res.push_str(self.currency_symbol),
+                    'n' => res.push_str(&number),
+                    '-' => res.push_str(self.negative_sign),
+                    ' ' => res.push('\u{a0}'),
+                    _ => res.push(c),
+                }
+            }
+            res
+        }
+
+        fn apply_pattern(&self, pattern: &str, number: &str, res: &mut String) {
+            for c in pattern.chars() {
+                match c {
+                    '#' => res.push_str(number),
+                    '-' => res.push_str(self.negative_sign),
+                    '%' => res.push_str(self.percent_symbol),
+                    _ => res.push(c),
+                }
+            }
+        }
+
+        fn push_exponent(&self, res: &mut String, exp: i32, symbol: char, min_digits: usize, positive_sign: bool) {
+            res.push(symbol);
+            if exp < 0 {
+                res.push_str(self.negative_sign);
+            } else if positive_sign {
+                res.push_str(self.positive_sign);
+            }
+            res.push_str(&format!("{:01$}", exp.unsigned_abs(), min_digits));
+        }
+
+        fn push_group_separator(&self, res: &mut String, separators: &mut Vec<i32>, dig_pos: i32) {
+            if dig_pos > 1 && separators.last() == Some(&(dig_pos - 1)) {
+                res.push_str(self.number_group_separator);
+                separators.pop();
+            }
+        }
+
+        /// Formats a number with a custom numeric format string
+        /// (digit placeholders, scaling, literals and sections).
+        fn format_custom(&self, mut number: Digits, format: &str) -> String {
+            let fmt: Vec<char> = format.chars().collect();
+            let len = fmt.len();
+            let value_section = match (number.digits.is_empty(), number.negative) {
+                (true, _) => 2,
+                (false, true) => 1,
+                (false, false) => 0,
+            };
+            let mut section = find_section(&fmt, value_section);
+            let mut digit_count;
+            let mut decimal_pos;
+            let mut first_digit;
+            let mut last_digit;
+            let mut scientific;
+            let mut thousand_seps;
+            loop {
+                digit_count = 0;
+                decimal_pos = -1;
+                first_digit = i32::MAX;
+                last_digit = 0;
+                scientific = false;
+                thousand_seps = false;
+                let mut thousand_pos = -1;
+                let mut thousand_count = 0;
+                let mut scale_adjust = 0;
+                let mut src = section;
+                while src < len && fmt[src] != ';' {
+                    let ch = fmt[src];
+                    src += 1;
+                    match ch {
+                        '#' => digit_count += 1,
+                        '0' => {
+                            if first_digit == i32::MAX {
+                                first_digit = digit_count;
+                            }
+                            digit_count += 1;
+                            last_digit = digit_count;
+                        }
+                        '.' if decimal_pos < 0 => decimal_pos = digit_count,
+                        ',' if digit_count > 0 && decimal_pos < 0 => {
+                            // consecutive separators right before the decimal point scale by 1000
+                            if thousand_pos == digit_count {
+                                thousand_count += 1;
+                            } else {
+                                thousand_seps = thousand_seps || thousand_pos >= 0;
+                                thousand_pos = digit_count;
+                                thousand_count = 1;
+                            }
+                        }
+                        '%' => scale_adjust += 2,
+                        '\u{2030}' => scale_adjust += 3,
+                        '\'' | '"' => {
+                            while src < len {
+                                src += 1;
+                                if fmt[src - 1] == ch {
+                                    break;
+                                }
+                            }
+                        }
+                        '\\' if src < len => src += 1,
+                        'E' | 'e' => {
+                            let zero_follows = match fmt.get(src) {
+                                Some('0') => true,
+                                Some('+' | '-') => fmt.get(src + 1) == Some(&'0'),
+                                _ => false,
+                            };
+                            if zero_follows {
+                                src += 1;
+                                while src < len && fmt[src] == '0' {
+                                    src += 1;
+                                }
+                                scientific = true;
+                            }
+                        }
+                        _ => (),
+                    }
+                }
+                if decimal_pos < 0 {
+                    decimal_pos = digit_count;
+                }
+                if thousand_pos >= 0 {
+                    if thousand_pos == decimal_pos {
+                        scale_adjust -= thousand_count * 3;
+                    } else {
+                        thousand_seps = true;
+                    }
+                }
+                if !number.digits.is_empty() {
+                    number.scale += scale_adjust;
+                    let pos = if scientific { digit_count } else { number.scale + digit_count - decimal_pos };
+                    number.round(pos);
+                    if number.digits.is_empty() {
+                        // rounded to zero, so the zero section applies
+                        let zero_section = find_section(&fmt, 2);
+                        if zero_section != section {
+                            section = zero_section;
+                            continue;
+                        }
+                    }
+                } else {
+                    if !matches!(number.kind, NumberKind::Float(_)) {
+                        number.negative = false;
+                    }
+                    number.scale = 0;
+                }
+                break;
+            }
+
+            let first_digit = if first_digit < decimal_pos { decimal_pos - first_digit } else { 0 };
+            let last_digit = if last_digit > decimal_pos { decimal_pos - last_digit } else { 0 };
+            let (mut dig_pos, mut adjust) = if scientific {
+                (decimal_pos, 0)
+            } else {
+                (number.scale.max(decimal_pos), number.scale - decimal_pos)
+            };
+
+            // digit positions, counted leftwards from the decimal point,
+            // that are followed by a group separator (innermost last)
+            let mut separators: Vec<i32> = Vec::new();
+            if thousand_seps && !self.number_group_separator.is_empty() {
+                let sizes = self.number_group_sizes;
+                let mut index = 0;
+                let mut size = sizes.first().copied().unwrap_or(0);
+                let mut total = size;
+                let num_digits = first_digit.max(dig_pos + adjust.min(0));
+                while num_digits > total && size != 0 {
+                    separators.push(total);
+                    if index + 1 < sizes.len() {
+                        index += 1;
+                        size = sizes[index];
+                    }
+                    total += size;
+                }
+            }
+
+            let mut res = String::new();
+            if number.negative && section == 0 && number.scale != 0 {
+                res.push_str(self.negative_sign);
+            }
+            let mut decimal_written = false;
+            let mut digits = number.digits.iter().map(|&d| d as char).peekable();
+            let mut src = section;
+            while src < len && fmt[src] != ';' {
+                let ch = fmt[src];
+                src += 1;
+                // integer digits that have no placeholder of their own
+                if adjust > 0 && matches!(ch, '#' | '0' | '.') {
+                    while adjust > 0 {
+                        res.push(digits.next().unwrap_or('0'));
+                        self.push_group_separator(&mut res, &mut separators, dig_pos);
+                        dig_pos -= 1;
+                        adjust -= 1;
+                    }
+                }
+                match ch {
+                    '#' | '0' => {
+                        let digit = if adjust < 0 {
+                            adjust += 1;
+                            if dig_pos <= first_digit { Some('0') } else { None }
+                        } else {
+                            match digits.next() {
+                                Some(d) => Some(d),
+                                None if dig_pos > last_digit => Some('0'),
+                                None => None,
+                            }
+                        };
+                        if let Some(digit) = digit {
+                            res.push(digit);
+                            self.push_group_separator(&mut res, &mut separators, dig_pos);
+                        }
+                        dig_pos -= 1;
+                    }
+                    '.' => {
+                        // repeated decimal points are not echoed
+                        if dig_pos == 0 && !decimal_written && (last_digit < 0 || (decimal_pos < digit_count && digits.peek().is_some())) {
+                            res.push_str(self.number_decimal_separator);
+                            decimal_written = true;
+                        }
+                    }
+                    '\u{2030}' => res.push_str(self.per_mille_symbol),
+                    '%' => res.push_str(self.percent_symbol),
+                    ',' => (),
+                    '\'' | '"' => {
+                        while src < len && fmt[src] != ch {
+                            res.push(fmt[src]);
+                            src += 1;
+                        }
+                        if src < len {
+                            src += 1;
+                        }
+                    }
+                    '\\' => {
+                        if src < len {
+                            res.push(fmt[src]);
+                            src += 1;
+                        }
+                    }
+                    'E' | 'e' if scientific => {
+                        let positive_sign = match (fmt.get(src), fmt.get(src + 1)) {
+                            (Some('0'), _) => false,
+                            (Some('+'), Some('0')) => true,
+                            (Some('-'), Some('0')) => false,
+                            _ => {
+                                res.push(ch);
+                                continue;
+                            }
+                        };
+                        if fmt[src] != '0' {
+                            src += 1;
+                        }
+                        let mut min_digits = 0;
+                        while src < len && fmt[src] == '0' {
+                            min_digits += 1;
+                            src += 1;
+                        }
+                        let exp = if number.digits.is_empty() { 0 } else { number.scale - decimal_pos };
+                        self.push_exponent(&mut res, exp, ch, min_digits.min(10), positive_sign);
+                        scientific = false;
+                    }
+                    'E' | 'e' => {
+                        res.push(ch);
+                        if let Some(&sign @ ('+' | '-')) = fmt.get(src) {
+                            res.push(sign);
+                            src += 1;
+                        }
+                        while src < len && fmt[src] == '0' {
+                            res.push('0');
+                            src += 1;
+                        }
+                    }
+                    _ => res.push(ch),
+                }
+            }
+            if number.negative && section == 0 && number.scale == 0 && !res.is_empty() {
+                res.insert_str(0, self.negative_sign);
+            }
+            res
+        }
+
+        /// Formats a number with a standard or custom numeric format string,
+        /// following the rounding and layout rules of .NET.
+        pub(crate) fn format_number(&self, value: NumberValue, format: &str) -> String {
+            if let NumberValue::Float { value, .. } = value {
+                if value.is_nan() {
+                    return self.nan_symbol.to_string();
+                }
+                if value.is_infinite() {
+                    let symbol = if value > 0.0 { self.positive_infinity_symbol } else { self.negative_infinity_symbol };
+                    return symbol.to_string();
+                }
+            }
+            let Some((symbol, precision)) = parse_standard_format(format) else {
+                let number = match value {
+                    // custom formats show at most 15 (or 7) significant digits of a float
+                    NumberValue::Float { value, single } => {
+                        let mut number = Digits::from_float(value, single, false);
+                        number.round(if single { 7 } else { 15 });
+                        number
+                    }
+                    NumberValue::Integer { negative, magnitude, .. } => Digits::from_integer(negative, magnitude),
+                    NumberValue::Decimal { negative, mantissa, scale } => Digits::from_decimal(negative, mantissa, scale),
+                };
+                return self.format_custom(number, format);
+            };
+            let mut number = match value {
+                NumberValue::Float { value, single } => {
+                    let shortest = match symbol {
+                        'R' | 'r' => true,
+                        'G' | 'g' => precision.unwrap_or(0) == 0,
+                        _ => false,
+                    };
+                    Digits::from_float(value, single, shortest)
+                }
+                NumberValue::Integer { negative, magnitude, bits } => match symbol {
+                    'D' | 'd' => {
+                        let digits = magnitude.to_string();
+                        let mut res = String::with_capacity(digits.len() + 1);
+                        if negative {
+                            res.push_str(self.negative_sign);
+                        }
+                        let width = precision.unwrap_or(0) as usize;
+                        res.push_str(&format!("{:0>1$}", digits, width));
+                        return res;
+                    }
+                    'X' => return format!("{:01$X}", bits, precision.unwrap_or(0) as usize),
+                    'x' => return format!("{:01$x}", bits, precision.unwrap_or(0) as usize),
+                    _ => Digits::from_integer(negative, magnitude),
+                },
+                NumberValue::Decimal { negative, mantissa, scale } => Digits::from_decimal(negative, mantissa, scale),
+            };
+            let mut res = String::new();
+            match symbol {
+                'C' | 'c' => {
+                    let decimals = precision.unwrap_or(self.currency_decimal_digits);
+                    number.round(number.scale + decimals);
+                    let (int_part, frac_part) = number.fixed(decimals);
+                    let amount = if frac_part.is_empty() { int_part } else { format!("{}.{}", int_part, frac_part) };
+                    res = self.format_currency(number.negative, &amount);
+                }
+                'F' | 'f' => {
+                    let decimals = precision.unwrap_or(self.number_decimal_digits);
+                    number.round(number.scale + decimals);
+                    if number.negative {
+                        res.push_str(self.negative_sign);
+                    }
+                    let (int_part, frac_part) = number.fixed(decimals);
+                    res.push_str(&int_part);
+                    if !frac_part.is_empty() {
+                        res.push_str(self.number_decimal_separator);
+                        res.push_str(&frac_part);
+                    }
+                }
+                'N' | 'n' | 'P' | 'p' => {
+                    let percent = symbol == 'P' || symbol == 'p';
+                    if percent {
+                        number.scale += 2;
+                    }
+                    let default_decimals = if percent { self.percent_decimal_digits } else { self.number_decimal_digits };
+                    let decimals = precision.unwrap_or(default_decimals);
+                    number.round(number.scale + decimals);
+                    let (int_part, frac_part) = number.fixed(decimals);
+                    let mut grouped = String::new();
+                    self.group_digits(&int_part, &mut grouped);
+                    if !frac_part.is_empty() {
+                        grouped.push_str(self.number_decimal_separator);
+                        grouped.push_str(&frac_part);
+                    }
+                    let pattern = match (percent, number.negative) {
+                        (false, false) => "#",
+                        (false, true) => NUMBER_NEGATIVE_PATTERNS[self.number_negative_pattern as usize],
+                        (true, false) => PERCENT_POSITIVE_PATTERNS[self.percent_positive_pattern as usize],
+                        (true, true) => PERCENT_NEGATIVE_PATTERNS[self.percent_negative_pattern as usize],
+                    };
+                    self.apply_pattern(pattern, &grouped, &mut res);
+                }
+                'E' | 'e' => {
+                    let decimals = precision.unwrap_or(6);
+                    number.round(decimals + 1);
+                    if number.negative {
+                        res.push_str(self.negative_sign);
+                    }
+                    let mut digits = number.digits.iter().map(|&d| d as char).chain(core::iter::repeat('0'));
+                    res.extend(digits.next());
+                    if decimals > 0 {
+                        res.push_str(self.number_decimal_separator);
+                        res.extend(digits.take(decimals as usize));
+                    }
+                    let exp = if number.digits.is_empty() { 0 } else { number.scale - 1 };
+                    self.push_exponent(&mut res, exp, symbol, 3, true);
+                }
+                'G' | 'g' | 'R' | 'r' => {
+                    let exp_symbol = if symbol == 'g' || symbol == 'r' { 'e' } else { 'E' };
+                    // trailing zeros of decimals are significant for the default format
+                    let exact = number.kind == NumberKind::Decimal && precision.is_none();
+                    let max_digits = match (precision, number.kind) {
+                        (Some(p), _) if p > 0 && symbol != 'R' && symbol != 'r' => p,
+                        (_, NumberKind::Float(p)) => (number.digits.len() as i32).max(p),
+                        _ => number.digits.len() as i32,
+                    };
+                    if !exact {
+                        number.round(max_digits);
+                    }
+                    if number.negative && !(exact && number.digits.is_empty()) {
+                        res.push_str(self.negative_sign);
+                    }
+                    let scientific = !exact && (number.scale > max_digits || number.scale < -3);
+                    let int_digits = if scientific { 1 } else { number.scale };
+                    let mut digits = number.digits.iter().map(|&d| d as char);
+                    if int_digits > 0 {
+                        for _ in 0..int_digits {
+                            res.push(digits.next().unwrap_or('0'));
+                        }
+                    } else {
+                        res.push('0');
+                    }
+                    let rest: String = digits.collect();
+                    if !rest.is_empty() || int_digits < 0 {
+                        res.push_str(self.number_decimal_separator);
+                        for _ in int_digits..0 {
+                            res.push('0');
+                        }
+                        res.push_str(&rest);
+                    }
+                    if scientific {
+                        self.push_exponent(&mut res, number.scale - 1, exp_symbol, 2, true);
+                    }
+                }
+                _ => panic!("Format specifier was invalid."),
+            }
+            res
+        }
+    }
+
+    // ------------------------------------------------------------------
+    // Standard numeric format strings
+    // ------------------------------------------------------------------
+
+    const NUMBER_NEGATIVE_PATTERNS: [&str; 5] = ["(#)", "-#", "- #", "#-", "# -"];
+    const PERCENT_POSITIVE_PATTERNS: [&str; 4] = ["# %", "#%", "%#", "% #"];
+    const PERCENT_NEGATIVE_PATTERNS: [&str; 12] =
+        ["-# %", "-#%", "-%#", "%-#", "%#-", "#-%", "#%-", "-% #", "# %-", "% #-", "% -#", "#- %"];
+
+    /// The value of a number to format, as given by its type
+    #[derive(Clone, Copy)]
+    pub enum NumberValue {
+        Integer { negative: bool, magnitude: u128, bits: u128 },
+        Float { value: f64, single: bool },
+        Decimal { negative: bool, mantissa: u128, scale: u32 },
+    }
+
+    pub trait FormatNumber {
+        fn number_value(&self) -> NumberValue;
+    }
+
+    macro_rules! integer_format_number {
+        ($($t:ty, $u:ty);*) => {$(
+            impl FormatNumber for $t {
+                fn number_value(&self) -> NumberValue {
+                    let x = *self;
+                    #[allow(unused_comparisons)]
+                    let negative = x < 0;
+                    let magnitude = if negative { (x as i128).unsigned_abs() } else { x as u128 };
+                    NumberValue::Integer { negative, magnitude, bits: x as $u as u128 }
+                }
+            }
+        )*};
+    }
+
+    integer_format_number!(i8, u8; i16, u16; i32, u32; i64, u64; i128, u128; isize, usize;
+        u8, u8; u16, u16; u32, u32; u64, u64; u128, u128; usize, usize);
+
+    impl FormatNumber for f32 {
+        fn number_value(&self) -> NumberValue {
+            NumberValue::Float { value: *self as f64, single: true }
+        }
+    }
+
+    impl FormatNumber for f64 {
+        fn number_value(&self) -> NumberValue {
+            NumberValue::Float { value: *self, single: false }
+        }
+    }
+
+    impl FormatNumber for NumberValue {
+        fn number_value(&self) -> NumberValue {
+            *self
+        }
+    }
+
+    #[derive(Clone, Copy, PartialEq)]
+    enum NumberKind {
+        Integer,
+        Float(i32), // significant digits shown by the "G" format
+        Decimal,
+    }
+
+    /// The decimal digits of a number, valued 0.d1d2d3... * 10^scale
+    struct Digits {
+        negative: bool,
+        digits: Vec<u8>,
+        scale: i32,
+        kind: NumberKind,
+    }
+
+    impl Digits {
+        fn from_integer(negative: bool, magnitude: u128) -> Digits {
+            let digits = if magnitude == 0 { Vec::new() } else { magnitude.to_string().into_bytes() };
+            Digits { negative, scale: digits.len() as i32, digits, kind: NumberKind::Integer }
+        }
+
+        fn from_decimal(negative: bool, mantissa: u128, scale: u32) -> Digits {
+            let mut number = Digits::from_integer(negative, mantissa);
+            number.scale -= scale as i32;
+            number.kind = NumberKind::Decimal;
+            number
+        }
+
+        // Either the shortest round-trip digits, or the exact decimal
+        // expansion (a double has at most 767 significant digits).
+        fn from_float(value: f64, single: bool, shortest: bool) -> Digits {
+            let s = match (shortest, single) {
+                (true, true) => format!("{:e}", value.abs() as f32),
+                (true, false) => format!("{:e}", value.abs()),
+                (false, _) => format!("{:.767e}", value.abs()),
+            };
+            let (mantissa, exp) = s.split_once('e').unwrap_or((&s, "0"));
+            let mut digits: Vec<u8> = mantissa.bytes().filter(u8::is_ascii_digit).collect();
+            while digits.last() == Some(&b'0') {
+                digits.pop();
+            }
+            let scale = if digits.is_empty() { 0 } else { exp.parse::<i32>().unwrap_or(0) + 1 };
+            let precision = if single { 7 } else { 15 };
+            Digits { negative: value.is_sign_negative(), digits, scale, kind: NumberKind::Float(precision) }
+        }
+
+        /// Keeps the first `pos` digits, rounding half away from zero.
+        fn round(&mut self, pos: i32) {
+            let len = self.digits.len();
+            let mut i = pos.clamp(0, len as i32) as usize;
+            if i as i32 == pos && i < len && self.digits[i] >= b'5' {
+                while i > 0 && self.digits[i - 1] == b'9' {
+                    i -= 1;
+                }
+                if i > 0 {
+                    self.digits[i - 1] += 1;
+                } else {
+                    self.scale += 1;
+                    self.digits[0] = b'1';
+                    i = 1;
+                }
+            } else {
+                while i > 0 && self.digits[i - 1] == b'0' {
+                    i -= 1;
+                }
+            }
+            if i == 0 {
+                // only floating-point zeros keep their sign
+                if !matches!(self.kind, NumberKind::Float(_)) {
+                    self.negative = false;
+                }
+                self.scale = 0;
+            }
+            self.digits.truncate(i);
+        }
+
+        /// The integer part and the first `decimals` fraction digits.
+        fn fixed(&self, decimals: i32) -> (String, String) {
+            let digit = |i: i32| match usize::try_from(i) {
+                Ok(i) => self.digits.get(i).map_or('0', |&d| d as char),
+                Err(_) => '0',
+            };
+            let int_part = if self.scale > 0 { (0..self.scale).map(digit).collect() } else { "0".to_string() };
+            let frac_part = (self.scale..self.scale + decimals).map(digit).collect();
+            (int_part, frac_part)
+        }
+    }
+
+    // A letter followed by an optional precision of up to 9 digits,
+    // anything else is a custom format
+    fn parse_standard_format(format: &str) -> Option<(char, Option<i32>)> {
+        let mut chars = format.chars();
+        match chars.next() {
+            None => Some(('G', None)),
+            Some(c) if c.is_ascii_alphabetic() => match chars.as_str() {
+                "" => Some((c, None)),
+                digits if digits.bytes().all(|b| b.is_ascii_digit()) => match digits.parse() {
+                    Ok(precision) if precision <= 999_999_999 => Some((c, Some(precision))),
+                    _ => panic!("Precision cannot be larger than 999,999,999."),
+                },
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    // Start of the section used for positive (0), negative (1) or zero (2) values
+    fn find_section(format: &[char], section: i32) -> usize {
+        let mut section = section;
+        let mut src = 0;
+        while section > 0 && src < format.len() {
+            let ch = format[src];
+            src += 1;
+            match ch {
+                '\'' | '"' => {
+                    while src < format.len() {
+                        src += 1;
+                        if format[src - 1] == ch {
+                            break;
+                        }
+                    }
+                }
+                '\\' if src < format.len() => src += 1,
+                ';' => {
+                    section -= 1;
+                    if section == 0 && src < format.len() && format[src] != ';' {
+                        return src;
+                    }
+                }
+                _ => (),
+            }
+        }
+        0
+    }
+
+    impl DateTimeFormatInfo {
+        pub fn invariantInfo_() -> LrcPtr<DateTimeFormatInfo> {
+            LrcPtr::new(INVARIANT_DATE_TIME_FORMAT.clone())
+        }
+
+        pub fn currentInfo_() -> LrcPtr<DateTimeFormatInfo> {
+            CultureInfo::currentCulture_().dateTimeFormat()
+        }
+
+        pub fn dayNames(&self) -> Array<string> {
+            to_string_array(&self.day_names)
+        }
+
+        pub fn abbreviatedDayNames(&self) -> Array<string> {
+            to_string_array(&self.abbreviated_day_names)
+        }
+
+        pub fn monthNames(&self) -> Array<string> {
+            // .NET month name arrays have a 13th (empty) entry
+            let mut names: Vec<string> = self.month_names.iter().map(|s| string(s)).collect();
+            names.push(string(""));
+            array_from(names)
+        }
+
+        pub fn abbreviatedMonthNames(&self) -> Array<string> {
+            let mut names: Vec<string> =
+                self.abbreviated_month_names.iter().map(|s| string(s)).collect();
+            names.push(string(""));
+            array_from(names)
+        }
+
+        pub fn getDayName_n(&self, dayOfWeek: i32) -> string {
+            string(self.day_name(dayOfWeek))
+        }
+
+        pub fn getAbbreviatedDayName_n(&self, dayOfWeek: i32) -> string {
+            string(self.abbreviated_day_name(dayOfWeek))
+        }
+
+        pub fn getMonthName_n(&self, month: i32) -> string {
+            string(self.month_name(month))
+        }
+
+        pub fn getAbbreviatedMonthName_n(&self, month: i32) -> string {
+            string(self.abbreviated_month_name(month))
+        }
+
+        pub fn aMDesignator(&self) -> string {
+            string(self.am_designator)
+        }
+
+        pub fn pMDesignator(&self) -> string {
+            string(self.pm_designator)
+        }
+
+        pub fn dateSeparator(&self) -> string {
+            string(self.date_separator)
+        }
+
+        pub fn timeSeparator(&self) -> string {
+            string(self.time_separator)
+        }
+
+        pub fn shortDatePattern(&self) -> string {
+            string(self.short_date_pattern)
+        }
+
+        pub fn longDatePattern(&self) -> string {
+            string(self.long_date_pattern)
+        }
+
+        pub fn shortTimePattern(&self) -> string {
+            string(self.short_time_pattern)
+        }
+
+        pub fn longTimePattern(&self) -> string {
+            string(self.long_time_pattern)
+        }
+
+        pub fn fullDateTimePattern(&self) -> string {
+            fromString(self.full_date_time_pattern())
+        }
+
+        pub fn monthDayPattern(&self) -> string {
+            string(self.month_day_pattern)
+        }
+
+        pub fn yearMonthPattern(&self) -> string {
+            string(self.year_month_pattern)
+        }
+
+        pub fn sortableDateTimePattern(&self) -> string {
+            string("yyyy'-'MM'-'dd'T'HH':'mm':'ss")
+        }
+
+        pub fn universalSortableDateTimePattern(&self) -> string {
+            string("yyyy'-'MM'-'dd HH':'mm':'ss'Z'")
+        }
+
+        pub fn rFC1123Pattern(&self) -> string {
+            string("ddd, dd MMM yyyy HH':'mm':'ss 'GMT'")
+        }
+
+        pub fn firstDayOfWeek(&self) -> i32 {
+            self.first_day_of_week
+        }
+
+        pub fn calendarWeekRule(&self) -> i32 {
+            self.calendar_week_rule
+        }
+
+        #[cfg(feature = "datetime")]
+        pub fn calendar(&self) -> LrcPtr<Calendar> {
+            Calendar::new_()
+        }
+
+        pub fn isReadOnly(&self) -> bool {
+            true
+        }
+
+        pub(crate) fn day_name(&self, dayOfWeek: i32) -> &'static str {
+            match self.day_names.get(dayOfWeek as usize) {
+                Some(name) => name,
+                None => panic!("Specified argument was out of the range of valid values. (Parameter 'dayofweek')"),
+            }
+        }
+
+        pub(crate) fn abbreviated_day_name(&self, dayOfWeek: i32) -> &'static str {
+            match self.abbreviated_day_names.get(dayOfWeek as usize) {
+                Some(name) => name,
+                None => panic!("Specified argument was out of the range of valid values. (Parameter 'dayofweek')"),
+            }
+        }
+
+        pub(crate) fn month_name(&self, month: i32) -> &'static str {
+            match month {
+                1..=12 => self.month_names[(month - 1) as usize],
+                13 => "",
+                _ => panic!("Specified argument was out of the range of valid values. (Parameter 'month')"),
+            }
+        }
+
+        pub(crate) fn abbreviated_month_name(&self, month: i32) -> &'static str {
+            match month {
+                1..=12 => self.abbreviated_month_names[(month - 1) as usize],
+                13 => "",
+                _ => panic!("Specified argument was out of the range of valid values. (Parameter 'month')"),
+            }
+        }
+
+        pub(crate) fn full_date_time_pattern(&self) -> String {
+            let mut res = self.long_date_pattern.to_string();
+            res.push(' ');
+            res.push_str(self.long_time_pattern);
+            res
+        }
+    }
+
+    impl core::fmt::Display for CultureInfo {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            write!(f, "{}", self.name)
+        }
+    }
+
+    impl CultureInfo {
+        pub fn new__s(name: string) -> LrcPtr<CultureInfo> {
+            Self::getCultureInfo__s(name)
+        }
+
+        pub fn getCultureInfo__s(name: string) -> LrcPtr<CultureInfo> {
+            match find_culture(name.as_str()) {
+                Some(culture) => LrcPtr::new(culture.clone()),
+                None => panic!(
+                    "Culture is not supported. (Parameter 'name')\n{} is an invalid culture identifier.",
+                    name
+                ),
+            }
+        }
+
+        pub fn invariantCulture_() -> LrcPtr<CultureInfo> {
+            static invariant: MutCell<Option<LrcPtr<CultureInfo>>> = MutCell::new(None);
+            invariant.get_or_init(|| LrcPtr::new(INVARIANT_CULTURE.clone()))
+        }
+
+        pub fn currentCulture_() -> LrcPtr<CultureInfo> {
+            match CURRENT_CULTURE.get() {
+                Some(culture) => culture,
+                None => Self::invariantCulture_(),
+            }
+        }
+
+        pub fn currentCulture___(culture: LrcPtr<CultureInfo>) {
+            CURRENT_CULTURE.set(Some(culture))
+        }
+
+        pub fn currentUICulture_() -> LrcPtr<CultureInfo> {
+            Self::currentCulture_()
+        }
+
+        pub fn currentUICulture___(culture: LrcPtr<CultureInfo>) {
+            Self::currentCulture___(culture)
+        }
+
+        pub fn name(&self) -> string {
+            string(self.name)
+        }
+
+        pub fn displayName(&self) -> string {
+            string(self.english_name)
+        }
+
+        pub fn englishName(&self) -> string {
+            string(self.english_name)
+        }
+
+        pub fn nativeName(&self) -> string {
+            string(self.native_name)
+        }
+
+        pub fn twoLetterISOLanguageName(&self) -> string {
+            match self.name.split('-').next() {
+                Some("") | None => string("iv"),
+                Some(lang) => string(lang),
+            }
+        }
+
+        pub fn isNeutralCulture(&self) -> bool {
+            self.is_neutral
+        }
+
+        pub fn parent(&self) -> LrcPtr<CultureInfo> {
+            // cultures without a neutral entry fall back to the invariant culture
+            match self.name.rsplit_once('-').and_then(|(lang, _)| find_culture(lang)) {
+                Some(culture) => LrcPtr::new(culture.clone()),
+                None => Self::invariantCulture_(),
+            }
+        }
+
+        pub fn numberFormat(&self) -> LrcPtr<NumberFormatInfo> {
+            LrcPtr::new(self.number_format.clone())
+        }
+
+        pub fn dateTimeFormat(&self) -> LrcPtr<DateTimeFormatInfo> {
+            LrcPtr::new(self.date_time_format.clone())
+        }
+
+        pub fn compareInfo(&self) -> LrcPtr<CompareInfo> {
+            LrcPtr::new(CompareInfo::new(self.name))
+        }
+
+        #[cfg(feature = "datetime")]
+        pub fn calendar(&self) -> LrcPtr<Calendar> {
+            Calendar::new_()
+        }
+
+        pub fn toString(&self) -> string {
+            string(self.name)
+        }
+
+        pub(crate) fn number_format(&self) -> &'static NumberFormatInfo {
+            self.number_format
+        }
+
+        pub(crate) fn date_time_format(&self) -> &'static DateTimeFormatInfo {
+            self.date_time_format
+        }
+    }
+
+    static CURRENT_CULTURE: MutCell<Option<LrcPtr<CultureInfo>>> = MutCell::new(None);
+
+    fn find_culture(name: &str) -> Option<&'static CultureInfo> {
+        if name.is_empty() {
+            return Some(&INVARIANT_CULTURE);
+        }
+        let name = name.replace('_', "-");
+        CULTURES
+            .iter()
+            .find(|culture| culture.name.eq_ignore_ascii_case(&name))
+    }
+
+    // ------------------------------------------------------------------
+    // Collation
+    // ------------------------------------------------------------------
+
+    pub const CompareOptions_None: i32 = 0;
+    pub const CompareOptions_IgnoreCase: i32 = 1;
+    pub const CompareOptions_IgnoreNonSpace: i32 = 2;
+    pub const CompareOptions_IgnoreSymbols: i32 = 4;
+    pub const CompareOptions_IgnoreKanaType: i32 = 8;
+    pub const CompareOptions_IgnoreWidth: i32 = 16;
+    pub const CompareOptions_OrdinalIgnoreCase: i32 = 0x10000000;
+    pub const CompareOptions_StringSort: i32 = 0x20000000;
+    pub const CompareOptions_Ordinal: i32 = 0x40000000;
+
+    // primary weight groups, in collation order
+    const GROUP_SPACE: u32 = 2;
+    const GROUP_SYMBOL: u32 = 3;
+    const GROUP_DIGIT: u32 = 4;
+    const GROUP_LETTER: u32 = 5;
+
+    /// Letters sorted as separate letters right after their base letter.
+    const ES_TAILORING: &[(char, char)] = &[('ñ', 'n')];
+
+    #[derive(Clone, Copy, Debug)]
+    struct CollationElement {
+        primary: u32,
+        secondary: u32,
+        tertiary: u8,
+        pos: usize,
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct CompareInfo {
+        name: &'static str,
+        tailoring: &'static [(char, char)],
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct SortKey {
+        key_data: Vec<u8>,
+        original: string,
+    }
+
+    fn is_ordinal(options: i32) -> bool {
+        options & (CompareOptions_Ordinal | CompareOptions_OrdinalIgnoreCase) != 0
+    }
+
+    fn to_ordinal(s: &str, options: i32) -> String {
+        if options & CompareOptions_OrdinalIgnoreCase != 0 {
+            s.chars().map(fold_case).collect()
+        } else {
+            s.to_string()
+        }
+    }
+
+    fn to_sign(ord: core::cmp::Ordering) -> i32 {
+        match ord {
+            core::cmp::Ordering::Less => -1,
+            core::cmp::Ordering::Equal => 0,
+            core::cmp::Ordering::Greater => 1,
+        }
+    }
+
+    #[cfg(feature = "globalization")]
+    fn decompose<F: FnMut(char)>(c: char, compat: bool, f: F) {
+        if compat {
+            unicode_normalization::char::decompose_compatible(c, f)
+        } else {
+            unicode_normalization::char::decompose_canonical(c, f)
+        }
+    }
+
+    #[cfg(not(feature = "globalization"))]
+    fn decompose<F: FnMut(char)>(c: char, compat: bool, mut f: F) {
+        f(c)
+    }
+
+    #[cfg(feature = "globalization")]
+    fn is_combining(c: char) -> bool {
+        unicode_normalization::char::is_combining_mark(c)
+    }
+
+    #[cfg(not(feature = "globalization"))]
+    fn is_combining(c: char) -> bool {
+        matches!(c, '\u{0300}'..='\u{036F}')
+    }
+
+    fn lower_char(c: char) -> char {
+        c.to_lowercase().next().unwrap_or(c)
+    }
+
+    impl CompareInfo {
+        fn new(name: &'static str) -> CompareInfo {
+            let tailoring = match name.split('-').next() {
+                Some("es") => ES_TAILORING,
+                _ => &[],
+            };
+            CompareInfo { name, tailoring }
+        }
+
+        pub fn getCompareInfo__s(name: string) -> LrcPtr<CompareInfo> {
+            CultureInfo::getCultureInfo__s(name).compareInfo()
+        }
+
+        pub fn name(&self) -> string {
+            string(self.name)
+        }
+
+        pub fn toString(&self) -> string {
+            string(self.name)
+        }
+
+        fn push_element(&self, res: &mut Vec<CollationElement>, c: char, pos: usize, options: i32) {
+            let (group, value) = if c.is_whitespace() {
+                (GROUP_SPACE, c as u32)
+            } else if c.is_numeric() {
+                (GROUP_DIGIT, c.to_digit(10).unwrap_or(c as u32))
+            } else if c.is_alphabetic() {
+                (GROUP_LETTER, (lower_char(c) as u32) << 1)
+            } else {
+                (GROUP_SYMBOL, c as u32)
+            };
+            if group < GROUP_DIGIT && options & CompareOptions_IgnoreSymbols != 0 {
+                return;
+            }
+            let tertiary = if c.is_uppercase() { 1 } else { 0 };
+            res.push(CollationElement { primary: group << 24 | value, secondary: 0, tertiary, pos })
+        }
+
+        fn push_expansion(&self, res: &mut Vec<CollationElement>, c: char, pos: usize, options: i32) -> bool {
+            let upper = c.is_uppercase();
+            let (chars, secondary): (&[char], u32) = match lower_char(c) {
+                'ß' => (&['s', 's'], 0),
+                'æ' => (&['a', 'e'], 0),
+                'œ' => (&['o', 'e'], 0),
+                'ø' => (&['o'], 0x0338),
+                'ł' => (&['l'], 0x0335),
+                'đ' => (&['d'], 0x0335),
+                _ => return false,
+            };
+            let start = res.len();
+            for &e in chars {
+                self.push_element(res, e, pos, options);
+            }
+            for elem in res[start..].iter_mut() {
+                elem.secondary = secondary;
+                // expansions differ from their spelled out form at the tertiary level
+                elem.tertiary = if upper { 3 } else { 2 };
+            }
+            true
+        }
+
+        fn collation_elements(&self, s: &str, options: i32) -> Vec<CollationElement> {
+            let compat = options & (CompareOptions_IgnoreWidth | CompareOptions_IgnoreKanaType) != 0;
+            let mut res: Vec<CollationElement> = Vec::with_capacity(s.len());
+            for (pos, c) in s.chars().enumerate() {
+                let lower = lower_char(c);
+                if let Some(&(_, base)) = self.tailoring.iter().find(|(t, _)| *t == lower) {
+                    let value = ((base as u32) << 1) + 1;
+                    let tertiary = if c.is_uppercase() { 1 } else { 0 };
+                    let primary = GROUP_LETTER << 24 | value;
+                    res.push(CollationElement { primary, secondary: 0, tertiary, pos });
+                } else if !self.push_expansion(&mut res, c, pos, options) {
+                    decompose(c, compat, |d| {
+                        if is_combining(d) {
+                            // diacritics only carry a secondary weight
+                            if let Some(last) = res.last_mut() {
+                                if last.secondary == 0 {
+                                    last.secondary = d as u32;
+                                }
+                            }
+                        } else if !d.is_control() {
+                            self.push_element(&mut res, d, pos, options);
+                        }
+                    });
+                }
+            }
+            res
+        }
+
+        fn same_element(a: &CollationElement, b: &CollationElement, options: i32) -> bool {
+            a.primary == b.primary
+                && (options & CompareOptions_IgnoreNonSpace != 0 || a.secondary == b.secondary)
+                && (options & CompareOptions_IgnoreCase != 0 || a.tertiary == b.tertiary)
+        }
+
+        fn sort_key_data(&self, s: &str, options: i32) -> Vec<u8> {
+            if is_ordinal(options) {
+                return to_ordinal(s, options).into_bytes();
+            }
+            let elems = self.collation_elements(s, options);
+            let mut key = Vec::with_capacity(elems.len() * 8 + 2);
+            for e in elems.iter() {
+                key.extend_from_slice(&e.primary.to_be_bytes());
+            }
+            if options & CompareOptions_IgnoreNonSpace == 0 {
+                key.push(1);
+                for e in elems.iter() {
+                    key.extend_from_slice(&e.secondary.to_be_bytes()[1..]);
+                }
+            }
+            if options & CompareOptions_IgnoreCase == 0 {
+                key.push(1);
+                for e in elems.iter() {
+                    key.push(e.tertiary);
+                }
+            }
+            key
+        }
+
+        pub fn compare_ss(&self, s1: string, s2: string) -> i32 {
+            self.compare_ssn(s1, s2, CompareOptions_None)
+        }
+
+        pub fn compare_ssn(&self, s1: string, s2: string, options: i32) -> i32 {
+            if options & CompareOptions_OrdinalIgnoreCase != 0 {
+                return to_sign(compare_ignore_case(s1.as_str(), s2.as_str()));
+            }
+            let key1 = self.sort_key_data(s1.as_str(), options);
+            let key2 = self.sort_key_data(s2.as_str(), options);
+            to_sign(key1.cmp(&key2))
+        }
+
+        pub fn getSortKey_s(&self, s: string) -> LrcPtr<SortKey> {
+            self.getSortKey_sn(s, CompareOptions_None)
+        }
+
+        pub fn getSortKey_sn(&self, s: string, options: i32) -> LrcPtr<SortKey> {
+            let key_data = self.sort_key_data(s.as_str(), options);
+            LrcPtr::new(SortKey { key_data, original: s })
+        }
+
+        pub fn getHashCode_sn(&self, s: string, options: i32) -> i32 {
+            if options & CompareOptions_OrdinalIgnoreCase != 0 {
+                return hash_ignore_case(s.as_str());
+            }
+            let key_data = self.sort_key_data(s.as_str(), options);
+            // FNV-1a
+            let mut hash: u32 = 0x811c9dc5;
+            for b in key_data {
+                hash = (hash ^ b as u32).wrapping_mul(0x01000193);
+            }
+            hash as i32
+        }
+
+        fn find(&self, source: &str, value: &str, options: i32, last: bool) -> i32 {
+            let elems = self.collation_elements(source, options);
+            let pattern = self.collation_elements(value, options);
+            if pattern.is_empty() {
+                return if last { source.chars().count() as i32 } else { 0 };
+            }
+            if pattern.len() > elems.len() {
+                return -1;
+            }
+            let is_match = |k: &usize| {
+                elems[*k..*k + pattern.len()]
+                    .iter()
+                    .zip(pattern.iter())
+                    .all(|(a, b)| Self::same_element(a, b, options))
+            };
+            let mut starts = 0..(elems.len() - pattern.len() + 1);
+            let found = if last { starts.rfind(is_match) } else { starts.find(is_match) };
+            match found {
+                Some(k) => elems[k].pos as i32,
+                None => -1,
+            }
+        }
+
+        pub fn indexOf_ss(&self, source: string, value: string) -> i32 {
+            self.indexOf_ssn(source, value, CompareOptions_None)
+        }
+
+        pub fn indexOf_ssn(&self, source: string, value: string, options: i32) -> i32 {
+            if is_ordinal(options) {
+                let source = to_ordinal(source.as_str(), options);
+                let value = to_ordinal(value.as_str(), options);
+                match source.split_once(value.as_str()) {
+                    Some((prefix, _)) => prefix.chars().count() as i32,
+                    None => -1,
+                }
+            } else {
+                self.find(source.as_str(), value.as_str(), options, false)
+            }
+        }
+
+        pub fn indexOf_sc(&self, source: string, value: char) -> i32 {
+            self.indexOf_scn(source, value, CompareOptions_None)
+        }
+
+        pub fn indexOf_scn(&self, source: string, value: char, options: i32) -> i32 {
+            let mut buf = [0u8; 4];
+            let value = value.encode_utf8(&mut buf);
+            self.indexOf_ssn(source, crate::String_::fromSlice(value), options)
+        }
+
+        pub fn lastIndexOf_ss(&self, source: string, value: string) -> i32 {
+            self.lastIndexOf_ssn(source, value, CompareOptions_None)
+        }
+
+        pub fn lastIndexOf_ssn(&self, source: string, value: string, options: i32) -> i32 {
+            if is_ordinal(options) {
+                let source = to_ordinal(source.as_str(), options);
+                let value = to_ordinal(value.as_str(), options);
+                match source.rsplit_once(value.as_str()) {
+                    Some((prefix, _)) => prefix.chars().count() as i32,
+                    None => -1,
+                }
+            } else {
+                self.find(source.as_str(), value.as_str(), options, true)
+            }
+        }
+
+        pub fn isPrefix_ss(&self, source: string, prefix: string) -> bool {
+            self.isPrefix_ssn(source, prefix, CompareOptions_None)
+        }
+
+        pub fn isPrefix_ssn(&self, source: string, prefix: string, options: i32) -> bool {
+            if options & CompareOptions_OrdinalIgnoreCase != 0 {
+                return starts_with_ignore_case(source.as_str(), prefix.as_str());
+            }
+            if is_ordinal(options) {
+                return source.starts_with(prefix.as_str());
+            }
+            let elems = self.collation_elements(source.as_str(), options);
+            let pattern = self.collation_elements(prefix.as_str(), options);
+            pattern.len() <= elems.len()
+                && elems.iter().zip(pattern.iter()).all(|(a, b)| Self::same_element(a, b, options))
+        }
+
+        pub fn isSuffix_ss(&self, source: string, suffix: string) -> bool {
+            self.isSuffix_ssn(source, suffix, CompareOptions_None)
+        }
+
+        pub fn isSuffix_ssn(&self, source: string, suffix: string, options: i32) -> bool {
+            if options & CompareOptions_OrdinalIgnoreCase != 0 {
+                return ends_with_ignore_case(source.as_str(), suffix.as_str());
+            }
+            if is_ordinal(options) {
+                return source.ends_with(suffix.as_str());
+            }
+            let elems = self.collation_elements(source.as_str(), options);
+            let pattern = self.collation_elements(suffix.as_str(), options);
+            pattern.len() <= elems.len()
+                && elems.iter().rev().zip(pattern.iter().rev()).all(|(a, b)| Self::same_element(a, b, options))
+        }
+    }
+
+    impl SortKey {
+        pub fn keyData(&self) -> Array<u8> {
+            array_from(self.key_data.clone())
+        }
+
+        pub fn originalString(&self) -> string {
+            self.original.clone()
+        }
+
+        pub fn compare____(sortkey1: LrcPtr<SortKey>, sortkey2: LrcPtr<SortKey>) -> i32 {
+            to_sign(sortkey1.key_data.cmp(&sortkey2.key_data))
+        }
+    }
+
+    pub fn getCultures() -> Array<LrcPtr<CultureInfo>> {
+        let mut cultures = Vec::with_capacity(CULTURES.len() + 1);
+        cultures.push(CultureInfo::invariantCulture_());
+        for culture in CULTURES.iter() {
+            cultures.push(LrcPtr::new(culture.clone()));
+        }
+        array_from(cultures)
+    }
+
+    pub fn toInvariantNumber(s: string, culture: LrcPtr<CultureInfo>) -> string {
+        fromString(culture.number_format.to_invariant(s.as_str()))
+    }
+
+    pub fn fromInvariantNumber(s: string, culture: LrcPtr<CultureInfo>) -> string {
+        fromString(culture.number_format.from_invariant(s.as_str()))
+    }
+
+    pub fn formatNumber<T: FormatNumber>(value: T, format: string) -> string {
+        formatNumber2(value, format, CultureInfo::currentCulture_())
+    }
+
+    pub fn formatNumber2<T: FormatNumber>(value: T, format: string, culture: LrcPtr<CultureInfo>) -> string {
+        fromString(culture.number_format.format_number(value.number_value(), format.as_str()))
+    }
+
+    // ------------------------------------------------------------------
+    // Regions
+    // ------------------------------------------------------------------
+
+    #[derive(Clone, Debug)]
+    struct RegionData {
+        name: &'static str,
+        three_letter_name: &'static str,
+        english_name: &'static str,
+        native_name: &'static str,
+        currency_symbol: &'static str,
+        iso_currency_symbol: &'static str,
+        currency_english_name: &'static str,
+        is_metric: bool,
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct RegionInfo {
+        name: string,
+        data: &'static RegionData,
+    }
+
+    impl core::fmt::Display for RegionInfo {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            write!(f, "{}", self.name)
+        }
+    }
+
+    fn find_region(name: &str) -> Option<&'static RegionData> {
+        REGIONS.iter().find(|region| region.name.eq_ignore_ascii_case(name))
+    }
+
+    impl RegionInfo {
+        pub fn new__s(name: string) -> LrcPtr<RegionInfo> {
+            let data = match name.rsplit_once(['-', '_']) {
+                // a specific culture name, e.g. "en-US"
+                Some((_, region)) if find_culture(name.as_str()).is_some() => find_region(region),
+                Some(_) => None,
+                None => find_region(name.as_str()),
+            };
+            match data {
+                Some(data) => {
+                    let name = match find_culture(name.as_str()) {
+                        Some(culture) => string(culture.name),
+                        None => string(data.name),
+                    };
+                    LrcPtr::new(RegionInfo { name, data })
+                }
+                None => panic!(
+                    "Culture is not supported. (Parameter 'name')\n{} is an invalid culture identifier.",
+                    name
+                ),
+            }
+        }
+
+        pub fn currentRegion_() -> LrcPtr<RegionInfo> {
+            let culture = CultureInfo::currentCulture_();
+            match culture.name.rsplit_once('-').and_then(|(_, region)| find_region(region)) {
+                Some(data) => LrcPtr::new(RegionInfo { name: string(culture.name), data }),
+                None => LrcPtr::new(RegionInfo { name: string("IV"), data: &INVARIANT_REGION }),
+            }
+        }
+
+        pub fn name(&self) -> string {
+            self.name.clone()
+        }
+
+        pub fn twoLetterISORegionName(&self) -> string {
+            string(self.data.name)
+        }
+
+        pub fn threeLetterISORegionName(&self) -> string {
+            string(self.data.three_letter_name)
+        }
+
+        pub fn displayName(&self) -> string {
+            string(self.data.english_name)
+        }
+
+        pub fn englishName(&self) -> string {
+            string(self.data.english_name)
+        }
+
+        pub fn nativeName(&self) -> string {
+            string(self.data.native_name)
+        }
+
+        pub fn currencySymbol(&self) -> string {
+            string(self.data.currency_symbol)
+        }
+
+        pub fn iSOCurrencySymbol(&self) -> string {
+            string(self.data.iso_currency_symbol)
+        }
+
+        pub fn currencyEnglishName(&self) -> string {
+            string(self.data.currency_english_name)
+        }
+
+        pub fn isMetric(&self) -> bool {
+            self.data.is_metric
+        }
+
+        pub fn toString(&self) -> string {
+            self.name.clone()
+        }
+    }
+
+    // ------------------------------------------------------------------
+    // Text elements (extended grapheme clusters)
+    // ------------------------------------------------------------------
+
+    // Returns the text elements of a string with their starting char indices
+    #[cfg(feature = "globalization")]
+    fn text_elements(s: &str) -> Vec<(i32, &str)> {
+        use unicode_segmentation::UnicodeSegmentation;
+        let mut index = 0;
+        s.graphemes(true)
+            .map(|g| {
+                let start = index;
+                index += g.chars().count() as i32;
+                (start, g)
+            })
+            .collect()
+    }
+
+    // without the Unicode tables, combining marks are not joined to their base char
+    #[cfg(not(feature = "globalization"))]
+    fn text_elements(s: &str) -> Vec<(i32, &str)> {
+        s.char_indices()
+            .enumerate()
+            .map(|(i, (pos, c))| (i as i32, &s[pos..pos + c.len_utf8()]))
+            .collect()
+    }
+
+    fn out_of_range(name: &str, value: i32) -> ! {
+        panic!(
+            "Specified argument was out of the range of valid values. (Parameter '{}')\nActual value was {}.",
+            name, value
+        )
+    }
+
+    fn text_elements_from(s: &str, index: i32) -> Vec<(i32, &str)> {
+        let length = s.chars().count() as i32;
+        if index < 0 || index > length {
+            panic!("Index was out of range. Must be non-negative and less than the size of the collection. (Parameter 'index')");
+        }
+        let pos = s.char_indices().nth(index as usize).map_or(s.len(), |(pos, _)| pos);
+        text_elements(&s[pos..])
+            .into_iter()
+            .map(|(i, g)| (i + index, g))
+            .collect()
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct StringInfo {
+        value: string,
+    }
+
+    impl StringInfo {
+        pub fn new_() -> LrcPtr<StringInfo> {
+            LrcPtr::new(StringInfo { value: string("") })
+        }
+
+        pub fn new__s(value: string) -> LrcPtr<StringInfo> {
+            LrcPtr::new(StringInfo { value })
+        }
+
+        pub fn string(&self) -> string {
+            self.value.clone()
+        }
+
+        pub fn lengthInTextElements(&self) -> i32 {
+            text_elements(self.value.as_str()).len() as i32
+        }
+
+        pub fn substringByTextElements_n(&self, startingTextElement: i32) -> string {
+            let elements = text_elements(self.value.as_str());
+            if startingTextElement < 0 || startingTextElement as usize >= elements.len() {
+                out_of_range("startingTextElement", startingTextElement);
+            }
+            let s: String = elements[startingTextElement as usize..]
+                .iter()
+                .map(|(_, g)| *g)
+                .collect();
+            fromString(s)
+        }
+
+        pub fn substringByTextElements_nn(&self, startingTextElement: i32, lengthInTextElements: i32) -> string {
+            let elements = text_elements(self.value.as_str());
+            if startingTextElement < 0 || startingTextElement as usize >= elements.len() {
+                out_of_range("startingTextElement", startingTextElement);
+            }
+            let start = startingTextElement as usize;
+            if lengthInTextElements < 0 || lengthInTextElements as usize > elements.len() - start {
+                out_of_range("lengthInTextElements", lengthInTextElements);
+            }
+            let s: String = elements[start..start + lengthInTextElements as usize]
+                .iter()
+                .map(|(_, g)| *g)
+                .collect();
+            fromString(s)
+        }
+
+        pub fn getNextTextElement__s(s: string) -> string {
+            Self::getNextTextElement__sn(s, 0)
+        }
+
+        pub fn getNextTextElement__sn(s: string, index: i32) -> string {
+            match text_elements_from(s.as_str(), index).first() {
+                Some((_, g)) => fromString(g.to_string()),
+                None => string(""),
+            }
+        }
+
+        pub fn getNextTextElementLength__s(s: string) -> i32 {
+            Self::getNextTextElementLength__sn(s, 0)
+        }
+
+        pub fn getNextTextElementLength__sn(s: string, index: i32) -> i32 {
+            match text_elements_from(s.as_str(), index).first() {
+                Some((_, g)) => g.chars().count() as i32,
+                None => 0,
+            }
+        }
+
+        pub fn parseCombiningCharacters__s(s: string) -> Array<i32> {
+            let indexes = text_elements(s.as_str()).iter().map(|(i, _)| *i).collect();
+            array_from(indexes)
+        }
+
+        pub fn getTextElementEnumerator__s(s: string) -> LrcPtr<TextElementEnumerator> {
+            Self::getTextElementEnumerator__sn(s, 0)
+        }
+
+        pub fn getTextElementEnumerator__sn(s: string, index: i32) -> LrcPtr<TextElementEnumerator> {
+            let elements = text_elements_from(s.as_str(), index)
+                .into_iter()
+                .map(|(i, g)| (i, fromString(g.to_string())))
+                .collect();
+            LrcPtr::new(TextElementEnumerator { elements, pos: MutCell::new(-1) })
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct TextElementEnumerator {
+        elements: Vec<(i32, string)>,
+        pos: MutCell<isize>,
+    }
+
+    impl TextElementEnumerator {
+        fn element(&self) -> &(i32, string) {
+            let pos = self.pos.get();
+            if pos < 0 || pos as usize >= self.elements.len() {
+                panic!("Enumeration has either not started or has already finished.");
+            }
+            &self.elements[pos as usize]
+        }
+
+        pub fn moveNext(&self) -> bool {
+            let pos = self.pos.get();
+            if (pos + 1) as usize <= self.elements.len() {
+                self.pos.set(pos + 1);
+            }
+            ((pos + 1) as usize) < self.elements.len()
+        }
+
+        pub fn current(&self) -> LrcPtr<dyn Any> {
+            let element: Lrc<dyn Any> = Lrc::new(self.getTextElement());
+            LrcPtr::from(element)
+        }
+
+        pub fn getTextElement(&self) -> string {
+            self.element().1.clone()
+        }
+
+        pub fn elementIndex(&self) -> i32 {
+            self.element().0
+        }
+
+        pub fn reset(&self) {
+            self.pos.set(-1);
+        }
+    }
+
+    // ------------------------------------------------------------------
+    // Culture data
+    // ------------------------------------------------------------------
+
+    const GROUP_3: &[i32] = &[3];
+
+    const ENGLISH_DAY_NAMES: [&str; 7] = [
+        "Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday",
+    ];
+    const ENGLISH_ABBREVIATED_DAY_NAMES: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+    const ENGLISH_MONTH_NAMES: [&str; 12] = [
+        "January", "February", "March", "April", "May", "June", "July", "August", "September",
+        "October", "November", "December",
+    ];
+    const ENGLISH_ABBREVIATED_MONTH_NAMES: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    const INVARIANT_NUMBER_FORMAT: NumberFormatInfo = NumberFormatInfo {
+        number_decimal_separator: ".",
+        number_group_separator: ",",
+        number_group_sizes: GROUP_3,
+        number_decimal_digits: 2,
+        number_negative_pattern: 1,
+        negative_sign: "-",
+        positive_sign: "+",
+        percent_symbol: "%",
+        per_mille_symbol: "‰",
+        nan_symbol: "NaN",
+        positive_infinity_symbol: "Infinity",
+        negative_infinity_symbol: "-Infinity",
+        currency_symbol: "¤",
+        currency_decimal_digits: 2,
+        currency_positive_pattern: 0,
+        currency_negative_pattern: 0,
+        percent_decimal_digits: 2,
+        percent_positive_pattern: 0,
+        percent_negative_pattern: 0,
+    };
+
+    const INVARIANT_DATE_TIME_FORMAT: DateTimeFormatInfo = DateTimeFormatInfo {
+        day_names: ENGLISH_DAY_NAMES,
+        abbreviated_day_names: ENGLISH_ABBREVIATED_DAY_NAMES,
+        month_names: ENGLISH_MONTH_NAMES,
+        abbreviated_month_names: ENGLISH_ABBREVIATED_MONTH_NAMES,
+        am_designator: "AM",
+        pm_designator: "PM",
+        date_separator: "/",
+        time_separator: ":",
+        short_date_pattern: "MM/dd/yyyy",
+        long_date_pattern: "dddd, dd MMMM yyyy",
+        short_time_pattern: "HH:mm",
+        long_time_pattern: "HH:mm:ss",
+        month_day_pattern: "MMMM dd",
+        year_month_pattern: "yyyy MMMM",
+        first_day_of_week: 0,
+        calendar_week_rule: 0,
+    };
+
+    static INVARIANT_CULTURE: CultureInfo = CultureInfo {
+        name: "",
+        english_name: "Invariant Language (Invariant Country)",
+        native_name: "Invariant Language (Invariant Country)",
+        is_neutral: false,
+        number_format: &INVARIANT_NUMBER_FORMAT,
+        date_time_format: &INVARIANT_DATE_TIME_FORMAT,
+    };
+
+    static INVARIANT_REGION: RegionData = region("IV", "IVC", "Invariant Country", "Invariant Country", "¤", "XDR", "International Monetary Fund", true);
+
+    #[cfg(not(feature = "globalization"))]
+    static REGIONS: [RegionData; 0] = [];
+
+    #[cfg(feature = "globalization")]
+    static REGIONS: [RegionData; 12] = [
+        region("US", "USA", "United States", "United States", "$", "USD", "US Dollar", false),
+        region("GB", "GBR", "United Kingdom", "United Kingdom", "£", "GBP", "British Pound", true),
+        region("DE", "DEU", "Germany", "Deutschland", "€", "EUR", "Euro", true),
+        region("FR", "FRA", "France", "France", "€", "EUR", "Euro", true),
+        region("ES", "ESP", "Spain", "España", "€", "EUR", "Euro", true),
+        region("IT", "ITA", "Italy", "Italia", "€", "EUR", "Euro", true),
+        region("BR", "BRA", "Brazil", "Brasil", "R$", "BRL", "Brazilian Real", true),
+        region("NL", "NLD", "Netherlands", "Nederland", "€", "EUR", "Euro", true),
+        region("JP", "JPN", "Japan", "日本", "￥", "JPY", "Japanese Yen", true),
+        region("CH", "CHE", "Switzerland", "Schweiz", "CHF", "CHF", "Swiss Franc", true),
+        region("CA", "CAN", "Canada", "Canada", "$", "CAD", "Canadian Dollar", true),
+        region("AU", "AUS", "Australia", "Australia", "$", "AUD", "Australian Dollar", true),
+    ];
+
+    const fn region(
+        name: &'static str,
+        three_letter_name: &'static str,
+        english_name: &'static str,
+        native_name: &'static str,
+        currency_symbol: &'static str,
+        iso_currency_symbol: &'static str,
+        currency_english_name: &'static str,
+        is_metric: bool,
+    ) -> RegionData {
+        RegionData {
+            name,
+            three_letter_name,
+            english_name,
+            native_name,
+            currency_symbol,
+            iso_currency_symbol,
+            currency_english_name,
+            is_metric,
+        }
+    }
+
+    #[cfg(not(feature = "globalization"))]
+    static CULTURES: [CultureInfo; 0] = [];
+
+    #[cfg(feature = "globalization")]
+    static CULTURES: [CultureInfo; 17] = [
+        culture("en", "English", "English", true, &EN_US_NUMBER_FORMAT, &EN_US_DATE_TIME_FORMAT),
+        culture("en-US", "English (United States)", "English (United States)", false, &EN_US_NUMBER_FORMAT, &EN_US_DATE_TIME_FORMAT),
+        culture("en-GB", "English (United Kingdom)", "English (United Kingdom)", false, &EN_GB_NUMBER_FORMAT, &EN_GB_DATE_TIME_FORMAT),
+        culture("de", "German", "Deutsch", true, &DE_NUMBER_FORMAT, &DE_DATE_TIME_FORMAT),
+        culture("de-DE", "German (Germany)", "Deutsch (Deutschland)", false, &DE_NUMBER_FORMAT, &DE_DATE_TIME_FORMAT),
+        culture("fr", "French", "français", true, &FR_NUMBER_FORMAT, &FR_DATE_TIME_FORMAT),
+        culture("fr-FR", "French (France)", "français (France)", false, &FR_NUMBER_FORMAT, &FR_DATE_TIME_FORMAT),
+        culture("es", "Spanish", "español", true, &ES_NUMBER_FORMAT, &ES_DATE_TIME_FORMAT),
+        culture("es-ES", "Spanish (Spain)", "español (España)", false, &ES_NUMBER_FORMAT, &ES_DATE_TIME_FORMAT),
+        culture("it", "Italian", "italiano", true, &IT_NUMBER_FORMAT, &IT_DATE_TIME_FORMAT),
+        culture("it-IT", "Italian (Italy)", "italiano (Italia)", false, &IT_NUMBER_FORMAT, &IT_DATE_TIME_FORMAT),
+        culture("pt", "Portuguese", "português", true, &PT_BR_NUMBER_FORMAT, &PT_BR_DATE_TIME_FORMAT),
+        culture("pt-BR", "Portuguese (Brazil)", "português (Brasil)", false, &PT_BR_NUMBER_FORMAT, &PT_BR_DATE_TIME_FORMAT),
+        culture("nl", "Dutch", "Nederlands", true, &NL_NUMBER_FORMAT, &NL_DATE_TIME_FORMAT),
+        culture("nl-NL", "Dutch (Netherlands)", "Nederlands (Nederland)", false, &NL_NUMBER_FORMAT, &NL_DATE_TIME_FORMAT),
+        culture("ja", "Japanese", "日本語", true, &JA_NUMBER_FORMAT, &JA_DATE_TIME_FORMAT),
+        culture("ja-JP", "Japanese (Japan)", "日本語 (日本)", false, &JA_NUMBER_FORMAT, &JA_DATE_TIME_FORMAT),
+    ];
+
+    #[cfg(feature = "globalization")]
+    const fn culture(
+        name: &'static str,
+        english_name: &'static str,
+        native_name: &'static str,
+        is_neutral: bool,
+        number_format: &'static NumberFormatInfo,
+        date_time_format: &'static DateTimeFormatInfo,
+    ) -> CultureInfo {
+        CultureInfo { name, english_name, native_name, is_neutral, number_format, date_time_format }
+    }
+
+    /// Number format of cultures using "1.234,5" with a trailing currency symbol.
+    #[cfg(feature = "globalization")]
+    const fn euro_number_format(group_separator: &'static str) -> NumberFormatInfo {
+        NumberFormatInfo {
+            number_decimal_separator: ",",
+            number_group_separator: group_separator,
+            number_group_sizes: GROUP_3,
+            number_decimal_digits: 2,
+            number_negative_pattern: 1,
+            negative_sign: "-",
+            positive_sign: "+",
+            percent_symbol: "%",
+            per_mille_symbol: "‰",
+            nan_symbol: "NaN",
+            positive_infinity_symbol: "∞",
+            negative_infinity_symbol: "-∞",
+            currency_symbol: "€",
+            currency_decimal_digits: 2,
+            currency_positive_pattern: 3,
+            currency_negative_pattern: 8,
+            percent_decimal_digits: 2,
+            percent_positive_pattern: 0,
+            percent_negative_pattern: 0,
+        }
+    }
+
+    #[cfg(feature = "globalization")]
+    const EN_US_NUMBER_FORMAT: NumberFormatInfo = NumberFormatInfo {
+        positive_infinity_symbol: "∞",
+        negative_infinity_symbol: "-∞",
+        currency_symbol: "$",
+        currency_negative_pattern: 1,
+        percent_positive_pattern: 1,
+        percent_negative_pattern: 1,
+        ..INVARIANT_NUMBER_FORMAT
+    };
+
+    #[cfg(feature = "globalization")]
+    const EN_US_DATE_TIME_FORMAT: DateTimeFormatInfo = DateTimeFormatInfo {
+        short_date_pattern: "M/d/yyyy",
+        long_date_pattern: "dddd, MMMM d, yyyy",
+        short_time_pattern: "h:mm tt",
+        long_time_pattern: "h:mm:ss tt",
+        month_day_pattern: "MMMM d",
+        year_month_pattern: "MMMM yyyy",
+        ..INVARIANT_DATE_TIME_FORMAT
+    };
+
+    #[cfg(feature = "globalization")]
+    const EN_GB_NUMBER_FORMAT: NumberFormatInfo = NumberFormatInfo {
+        currency_symbol: "£",
+        ..EN_US_NUMBER_FORMAT
+    };
+
+    #[cfg(feature = "globalization")]
+    const EN_GB_DATE_TIME_FORMAT: DateTimeFormatInfo = DateTimeFormatInfo {
+        am_designator: "am",
+        pm_designator: "pm",
+        short_date_pattern: "dd/MM/yyyy",
+        long_date_pattern: "dddd, d MMMM yyyy",
+        short_time_pattern: "HH:mm",
+        long_time_pattern: "HH:mm:ss",
+        month_day_pattern: "d MMMM",
+        year_month_pattern: "MMMM yyyy",
+        first_day_of_week: 1,
+        calendar_week_rule: 2,
+        ..INVARIANT_DATE_TIME_FORMAT
+    };
+
+    #[cfg(feature = "globalization")]
+    const DE_NUMBER_FORMAT: NumberFormatInfo = euro_number_format(".");
+
+    #[cfg(feature = "globalization")]
+    const DE_DATE_TIME_FORMAT: DateTimeFormatInfo = DateTimeFormatInfo {
+        day_names: ["Sonntag", "Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag"],
+        abbreviated_day_names: ["So.", "Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa."],
+        month_names: [
+            "Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September",
+            "Oktober", "November", "Dezember",
+        ],
+        abbreviated_month_names: [
+            "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.", "Dez.",
+        ],
+        am_designator: "AM",
+        pm_designator: "PM",
+        date_separator: ".",
+        time_separator: ":",
+        short_date_pattern: "dd.MM.yyyy",
+        long_date_pattern: "dddd, d. MMMM yyyy",
+        short_time_pattern: "HH:mm",
+        long_time_pattern: "HH:mm:ss",
+        month_day_pattern: "d. MMMM",
+        year_month_pattern: "MMMM yyyy",
+        first_day_of_week: 1,
+        calendar_week_rule: 2,
+    };
+
+    #[cfg(feature = "globalization")]
+    const FR_NUMBER_FORMAT: NumberFormatInfo = euro_number_format("\u{202F}");
+
+    #[cfg(feature = "globalization")]
+    const FR_DATE_TIME_FORMAT: DateTimeFormatInfo = DateTimeFormatInfo {
+        day_names: ["dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi"],
+        abbreviated_day_names: ["dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam."],
+        month_names: [
+            "janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre",
+            "octobre", "novembre", "décembre",
+        ],
+        abbreviated_month_names: [
+            "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.", "déc.",
+        ],
+        am_designator: "AM",
+        pm_designator: "PM",
+        date_separator: "/",
+        time_separator: ":",
+        short_date_pattern: "dd/MM/yyyy",
+        long_date_pattern: "dddd d MMMM yyyy",
+        short_time_pattern: "HH:mm",
+        long_time_pattern: "HH:mm:ss",
+        month_day_pattern: "d MMMM",
+        year_month_pattern: "MMMM yyyy",
+        first_day_of_week: 1,
+        calendar_week_rule: 2,
+    };
+
+    #[cfg(feature = "globalization")]
+    const ES_NUMBER_FORMAT: NumberFormatInfo = euro_number_format(".");
+
+    #[cfg(feature = "globalization")]
+    const ES_DATE_TIME_FORMAT: DateTimeFormatInfo = DateTimeFormatInfo {
+        day_names: ["domingo", "lunes", "martes", "miércoles", "jueves", "viernes", "sábado"],
+        abbreviated_day_names: ["dom", "lun", "mar", "mié", "jue", "vie", "sáb"],
+        month_names: [
+            "enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre",
+            "octubre", "noviembre", "diciembre",
+        ],
+        abbreviated_month_names: [
+            "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+        ],
+        am_designator: "a.\u{a0}m.",
+        pm_designator: "p.\u{a0}m.",
+        date_separator: "/",
+        time_separator: ":",
+        short_date_pattern: "dd/MM/yyyy",
+        long_date_pattern: "dddd, d 'de' MMMM 'de' yyyy",
+        short_time_pattern: "H:mm",
+        long_time_pattern: "H:mm:ss",
+        month_day_pattern: "d 'de' MMMM",
+        year_month_pattern: "MMMM 'de' yyyy",
+        first_day_of_week: 1,
+        calendar_week_rule: 2,
+    };
+
+    #[cfg(feature = "globalization")]
+    const IT_NUMBER_FORMAT: NumberFormatInfo = euro_number_format(".");
+
+    #[cfg(feature = "globalization")]
+    const IT_DATE_TIME_FORMAT: DateTimeFormatInfo = DateTimeFormatInfo {
+        day_names: ["domenica", "lunedì", "martedì", "mercoledì", "giovedì", "venerdì", "sabato"],
+        abbreviated_day_names: ["dom", "lun", "mar", "mer", "gio", "ven", "sab"],
+        month_names: [
+            "gennaio", "febbraio", "marzo", "aprile", "maggio", "giugno", "luglio", "agosto",
+            "settembre", "ottobre", "novembre", "dicembre",
+        ],
+        abbreviated_month_names: [
+            "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+        ],
+        am_designator: "AM",
+        pm_designator: "PM",
+        date_separator: "/",
+        time_separator: ":",
+        short_date_pattern: "dd/MM/yyyy",
+        long_date_pattern: "dddd d MMMM yyyy",
+        short_time_pattern: "HH:mm",
+        long_time_pattern: "HH:mm:ss",
+        month_day_pattern: "d MMMM",
+        year_month_pattern: "MMMM yyyy",
+        first_day_of_week: 1,
+        calendar_week_rule: 2,
+    };
+
+    #[cfg(feature = "globalization")]
+    const PT_BR_NUMBER_FORMAT: NumberFormatInfo = NumberFormatInfo {
+        currency_symbol: "R$",
+        currency_positive_pattern: 2,
+        currency_negative_pattern: 9,
+        ..euro_number_format(".")
+    };
+
+    #[cfg(feature = "globalization")]
+    const PT_BR_DATE_TIME_FORMAT: DateTimeFormatInfo = DateTimeFormatInfo {
+        day_names: [
+            "domingo", "segunda-feira", "terça-feira", "quarta-feira", "quinta-feira", "sexta-feira", "sábado",
+        ],
+        abbreviated_day_names: ["dom.", "seg.", "ter.", "qua.", "qui.", "sex.", "sáb."],
+        month_names: [
+            "janeiro", "fevereiro", "março", "abril", "maio", "junho", "julho", "agosto", "setembro",
+            "outubro", "novembro", "dezembro",
+        ],
+        abbreviated_month_names: [
+            "jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.", "dez.",
+        ],
+        am_designator: "AM",
+        pm_designator: "PM",
+        date_separator: "/",
+        time_separator: ":",
+        short_date_pattern: "dd/MM/yyyy",
+        long_date_pattern: "dddd, d 'de' MMMM 'de' yyyy",
+        short_time_pattern: "HH:mm",
+        long_time_pattern: "HH:mm:ss",
+        month_day_pattern: "d 'de' MMMM",
+        year_month_pattern: "MMMM 'de' yyyy",
+        first_day_of_week: 0,
+        calendar_week_rule: 0,
+    };
+
+    #[cfg(feature = "globalization")]
+    const NL_NUMBER_FORMAT: NumberFormatInfo = NumberFormatInfo {
+        currency_positive_pattern: 2,
+        currency_negative_pattern: 12,
+        ..euro_number_format(".")
+    };
+
+    #[cfg(feature = "globalization")]
+    const NL_DATE_TIME_FORMAT: DateTimeFormatInfo = DateTimeFormatInfo {
+        day_names: ["zondag", "maandag", "dinsdag", "woensdag", "donderdag", "vrijdag", "zaterdag"],
+        abbreviated_day_names: ["zo", "ma", "di", "wo", "do", "vr", "za"],
+        month_names: [
+            "januari", "februari", "maart", "april", "mei", "juni", "juli", "augustus", "september",
+            "oktober", "november", "december",
+        ],
+        abbreviated_month_names: [
+            "jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec",
+        ],
+        am_designator: "a.m.",
+        pm_designator: "p.m.",
+        date_separator: "-",
+        time_separator: ":",
+        short_date_pattern: "d-M-yyyy",
+        long_date_pattern: "dddd d MMMM yyyy",
+        short_time_pattern: "HH:mm",
+        long_time_pattern: "HH:mm:ss",
+        month_day_pattern: "d MMMM",
+        year_month_pattern: "MMMM yyyy",
+        first_day_of_week: 1,
+        calendar_week_rule: 2,
+    };
+
+    #[cfg(feature = "globalization")]
+    const JA_NUMBER_FORMAT: NumberFormatInfo = NumberFormatInfo {
+        currency_symbol: "￥",
+        currency_decimal_digits: 0,
+        currency_negative_pattern: 1,
+        percent_positive_pattern: 1,
+        percent_negative_pattern: 1,
+        ..EN_US_NUMBER_FORMAT
+    };
+
+    #[cfg(feature = "globalization")]
+    const JA_DATE_TIME_FORMAT: DateTimeFormatInfo = DateTimeFormatInfo {
+        day_names: ["日曜日", "月曜日", "火曜日", "水曜日", "木曜日", "金曜日", "土曜日"],
+        abbreviated_day_names: ["日", "月", "火", "水", "木", "金", "土"],
+        month_names: [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        abbreviated_month_names: [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        am_designator: "午前",
+        pm_designator: "午後",
+        date_separator: "/",
+        time_separator: ":",
+        short_date_pattern: "yyyy/MM/dd",
+        long_date_pattern: "yyyy年M月d日dddd",
+        short_time_pattern: "H:mm",
+        long_time_pattern: "H:mm:ss",
+        month_day_pattern: "M月d日",
+        year_month_pattern: "yyyy年M月",
+        first_day_of_week: 0,
+        calendar_week_rule: 0,
+    };
+}
@@ -1,12 +1,13 @@
 #[cfg(feature = "guid")]
 pub mod Guid_ {
+    use crate::Convert_::ParseError;
     use crate::NativeArray_::{new_array, Array};
-    use crate::Native_::{compare, MutCell};
+    use crate::Native_::{compare, fill_entropy, MutCell, ToString};
     use crate::String_::{string, toString};
-    use uuid::Uuid;
+    use uuid::{Builder, Uuid};
 
-    #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
-    pub struct Guid(Uuid);
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Guid(pub(crate) Uuid);
 
     pub const empty: Guid = Guid(Uuid::nil());
 
@@ -16,6 +17,13 @@ pub mod Guid_ {
         }
     }
 
+    impl core::str::FromStr for Guid {
+        type Err = uuid::Error;
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Uuid::parse_str(s.trim()).map(Guid)
+        }
+    }
+
     pub fn compareTo(x: Guid, y: Guid) -> i32 {
         compare(&x, &y)
     }
@@ -25,7 +33,9 @@ pub mod Guid_ {
     }
 
     pub fn new_guid() -> Guid {
-        Guid(Uuid::new_v4())
+        let mut bytes = [0u8; 16];
+        fill_entropy(&mut bytes);
+        Guid(Builder::from_random_bytes(bytes).into_uuid())
     }
 
     pub fn new_from_array(a: Array<u8>) -> Guid {
@@ -43,12 +53,20 @@ pub mod Guid_ {
     }
 
     pub fn parse(s: string) -> Guid {
-        match Uuid::parse_str(s.trim()) {
-            Ok(uuid) => Guid(uuid),
+        match try_parse_rs(&s) {
+            Ok(guid) => guid,
             Err(e) => panic!("{}", e),
         }
     }
 
+    // like `parse`, for Rust callers
+    pub fn try_parse_rs(s: &str) -> Result<Guid, ParseError> {
+        match Uuid::parse_str(s.trim()) {
+            Ok(uuid) => Ok(Guid(uuid)),
+            Err(e) => Err(ParseError::new(e.to_string())),
+        }
+    }
+
     pub fn toByteArray(x: Guid) -> Array<u8> {
         new_array(&x.0.to_bytes_le())
     }
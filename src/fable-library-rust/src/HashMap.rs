@@ -20,6 +20,22 @@ pub mod HashMap_ {
     #[derive(Clone, Debug, Default)] //, PartialEq, PartialOrd, Eq, Hash, Ord)]
     pub struct HashMap<K: Clone, V: Clone>(Lrc<MutHashMap<K, V>>);
 
+    // like .NET, equality is reference equality
+
+    impl<K: Clone, V: Clone> PartialEq for HashMap<K, V> {
+        fn eq(&self, other: &Self) -> bool {
+            Lrc::ptr_eq(&self.0, &other.0)
+        }
+    }
+
+    impl<K: Clone, V: Clone> Eq for HashMap<K, V> {}
+
+    impl<K: Clone, V: Clone> Hash for HashMap<K, V> {
+        fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+            (Lrc::as_ptr(&self.0) as *const () as usize).hash(state)
+        }
+    }
+
     impl<K: Clone, V: Clone> core::ops::Deref for HashMap<K, V> {
         type Target = Lrc<MutHashMap<K, V>>;
         fn deref(&self) -> &Self::Target {
@@ -33,13 +49,35 @@ pub mod HashMap_ {
         }
     }
 
+    impl<K: Clone, V: Clone> From<collections::HashMap<K, V>> for HashMap<K, V> {
+        fn from(map: collections::HashMap<K, V>) -> Self {
+            HashMap(mkRefMut(map))
+        }
+    }
+
+    impl<K: Clone + Eq + Hash, V: Clone> From<HashMap<K, V>> for collections::HashMap<K, V> {
+        fn from(dict: HashMap<K, V>) -> Self {
+            dict.get_mut().clone()
+        }
+    }
+
     pub fn new_empty<K: Clone, V: Clone>() -> HashMap<K, V> {
         HashMap(mkRefMut(collections::HashMap::new()))
     }
 
+    fn check_capacity(capacity: i32) -> usize {
+        if capacity < 0 {
+            panic!("Non-negative number required. (Parameter 'capacity')");
+        }
+        capacity as usize
+    }
+
+    // The backing table keeps its load factor below 7/8, so a table created
+    // with (or grown to) a given capacity holds that many items without rehashing.
+
     pub fn new_with_capacity<K: Clone, V: Clone>(capacity: i32) -> HashMap<K, V> {
         HashMap(mkRefMut(collections::HashMap::with_capacity(
-            capacity as usize,
+            check_capacity(capacity),
         )))
     }
 
@@ -90,6 +128,19 @@ pub mod HashMap_ {
         dict.get_mut().clear();
     }
 
+    pub fn ensureCapacity<K: Eq + Hash + Clone, V: Clone>(dict: HashMap<K, V>, capacity: i32) -> i32 {
+        let capacity = check_capacity(capacity);
+        let len = dict.len();
+        if capacity > len {
+            dict.get_mut().reserve(capacity - len);
+        }
+        dict.capacity() as i32
+    }
+
+    pub fn trimExcess<K: Eq + Hash + Clone, V: Clone>(dict: HashMap<K, V>) {
+        dict.get_mut().shrink_to_fit();
+    }
+
     pub fn get<K: Eq + Hash + Clone, V: Clone>(dict: HashMap<K, V>, k: K) -> V {
         match dict.get_mut().get(&k) {
             Some(v) => v.clone(),
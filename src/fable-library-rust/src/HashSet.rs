@@ -20,6 +20,22 @@ pub mod HashSet_ {
     #[derive(Clone, Debug, Default)] //, PartialEq, PartialOrd, Eq, Hash, Ord)]
     pub struct HashSet<T: Clone>(Lrc<MutHashSet<T>>);
 
+    // like .NET, equality is reference equality
+
+    impl<T: Clone> PartialEq for HashSet<T> {
+        fn eq(&self, other: &Self) -> bool {
+            Lrc::ptr_eq(&self.0, &other.0)
+        }
+    }
+
+    impl<T: Clone> Eq for HashSet<T> {}
+
+    impl<T: Clone> Hash for HashSet<T> {
+        fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+            (Lrc::as_ptr(&self.0) as *const () as usize).hash(state)
+        }
+    }
+
     impl<T: Clone> core::ops::Deref for HashSet<T> {
         type Target = Lrc<MutHashSet<T>>;
         fn deref(&self) -> &Self::Target {
@@ -33,13 +49,35 @@ pub mod HashSet_ {
         }
     }
 
+    impl<T: Clone> From<collections::HashSet<T>> for HashSet<T> {
+        fn from(set: collections::HashSet<T>) -> Self {
+            HashSet(mkRefMut(set))
+        }
+    }
+
+    impl<T: Clone + Eq + Hash> From<HashSet<T>> for collections::HashSet<T> {
+        fn from(set: HashSet<T>) -> Self {
+            set.get_mut().clone()
+        }
+    }
+
     pub fn new_empty<T: Clone>() -> HashSet<T> {
         HashSet(mkRefMut(collections::HashSet::new()))
     }
 
+    fn check_capacity(capacity: i32) -> usize {
+        if capacity < 0 {
+            panic!("Non-negative number required. (Parameter 'capacity')");
+        }
+        capacity as usize
+    }
+
+    // The backing table keeps its load factor below 7/8, so a table created
+    // with (or grown to) a given capacity holds that many items without rehashing.
+
     pub fn new_with_capacity<T: Clone>(capacity: i32) -> HashSet<T> {
         HashSet(mkRefMut(collections::HashSet::with_capacity(
-            capacity as usize,
+            check_capacity(capacity),
         )))
     }
 
@@ -71,6 +109,19 @@ pub mod HashSet_ {
         set.get_mut().clear();
     }
 
+    pub fn ensureCapacity<T: Eq + Hash + Clone>(set: HashSet<T>, capacity: i32) -> i32 {
+        let capacity = check_capacity(capacity);
+        let len = set.len();
+        if capacity > len {
+            set.get_mut().reserve(capacity - len);
+        }
+        set.capacity() as i32
+    }
+
+    pub fn trimExcess<T: Eq + Hash + Clone>(set: HashSet<T>) {
+        set.get_mut().shrink_to_fit();
+    }
+
     pub fn entries<T: Clone>(set: HashSet<T>) -> Array<T> {
         array_from(Vec::from_iter(set.iter().cloned()))
     }
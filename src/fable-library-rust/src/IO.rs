@@ -0,0 +1,723 @@
+#[cfg(not(feature = "no_std"))]
+pub mod IO_ {
+
+    // -----------------------------------------------------------
+    // Streams
+    // -----------------------------------------------------------
+
+    // A Stream wraps either an in-memory buffer (MemoryStream), or any
+    // std::io reader/writer, so Rust I/O types can be passed to F# code
+    // as .NET streams. In the other direction, `&Stream` implements the
+    // std::io Read, Write and Seek traits, so library streams can be used
+    // with any Rust crate built on std::io (e.g. `io::copy(&mut &*stream, ..)`).
+    // As the .NET `read`, `write`, `seek` and `flush` methods take precedence
+    // in method calls, the trait methods are called as `Read::read(..)` etc.
+
+    use crate::Interfaces_::System::IDisposable;
+    use crate::NativeArray_::{array_from, Array};
+    use crate::Native_::{LrcPtr, MutCell, Vec};
+
+    use core::fmt::{Debug, Formatter};
+    use std::io::{self, Cursor, Read, Seek, SeekFrom, Write};
+
+    // SeekOrigin values
+    const BEGIN: i32 = 0;
+    const CURRENT: i32 = 1;
+    const END: i32 = 2;
+
+    pub trait ReadWriteSeek: Read + Write + Seek {}
+
+    impl<T: Read + Write + Seek> ReadWriteSeek for T {}
+
+    fn unsupported<T>() -> io::Result<T> {
+        Err(io::Error::from(io::ErrorKind::Unsupported))
+    }
+
+    // adapters filling in the operations a std::io type doesn't support
+    struct ReadOnly<R>(R);
+    struct WriteOnly<W>(W);
+    struct ReadSeek<R>(R);
+
+    impl<R: Read> Read for ReadOnly<R> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.0.read(buf)
+        }
+    }
+
+    impl<R> Write for ReadOnly<R> {
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            unsupported()
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<R> Seek for ReadOnly<R> {
+        fn seek(&mut self, _pos: SeekFrom) -> io::Result<u64> {
+            unsupported()
+        }
+    }
+
+    impl<W> Read for WriteOnly<W> {
+        fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+            unsupported()
+        }
+    }
+
+    impl<W: Write> Write for WriteOnly<W> {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.write(buf)
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            self.0.flush()
+        }
+    }
+
+    impl<W> Seek for WriteOnly<W> {
+        fn seek(&mut self, _pos: SeekFrom) -> io::Result<u64> {
+            unsupported()
+        }
+    }
+
+    impl<R: Read> Read for ReadSeek<R> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.0.read(buf)
+        }
+    }
+
+    impl<R> Write for ReadSeek<R> {
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            unsupported()
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<R: Seek> Seek for ReadSeek<R> {
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            self.0.seek(pos)
+        }
+    }
+
+    // A MemoryStream created over an array writes through to that array,
+    // and like in .NET, it can't grow past the length of the array.
+    struct FixedMemory {
+        buffer: Array<u8>,
+        position: u64,
+        length: usize,
+    }
+
+    fn not_expandable<T>() -> io::Result<T> {
+        Err(io::Error::other("Memory stream is not expandable."))
+    }
+
+    impl FixedMemory {
+        fn set_length(&mut self, length: usize) -> io::Result<()> {
+            if length > self.buffer.len() {
+                return not_expandable();
+            }
+            if length > self.length {
+                self.buffer.get_mut()[self.length..length].fill(0);
+            }
+            self.length = length;
+            Ok(())
+        }
+    }
+
+    impl Read for FixedMemory {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let start = (self.position as usize).min(self.length);
+            let n = buf.len().min(self.length - start);
+            buf[..n].copy_from_slice(&self.buffer.as_slice()[start..start + n]);
+            self.position += n as u64;
+            Ok(n)
+        }
+    }
+
+    impl Write for FixedMemory {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let start = self.position as usize;
+            let end = start.checked_add(buf.len()).filter(|end| *end <= self.buffer.len());
+            let Some(end) = end else { return not_expandable() };
+            if end > self.length {
+                self.set_length(end)?;
+            }
+            self.buffer.get_mut()[start..end].copy_from_slice(buf);
+            self.position = end as u64;
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Seek for FixedMemory {
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            let (base, offset) = match pos {
+                SeekFrom::Start(n) => {
+                    self.position = n;
+                    return Ok(n);
+                }
+                SeekFrom::End(n) => (self.length as u64, n),
+                SeekFrom::Current(n) => (self.position, n),
+            };
+            match base.checked_add_signed(offset) {
+                Some(n) => {
+                    self.position = n;
+                    Ok(n)
+                }
+                None => Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "An attempt was made to move the position before the beginning of the stream.",
+                )),
+            }
+        }
+    }
+
+    enum Inner {
+        Memory(Cursor<Vec<u8>>),
+        Fixed(FixedMemory),
+        Io(Box<dyn ReadWriteSeek>),
+    }
+
+    impl Read for Inner {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self {
+                Inner::Memory(c) => c.read(buf),
+                Inner::Fixed(m) => m.read(buf),
+                Inner::Io(s) => s.read(buf),
+            }
+        }
+    }
+
+    impl Write for Inner {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            match self {
+                Inner::Memory(c) => c.write(buf),
+                Inner::Fixed(m) => m.write(buf),
+                Inner::Io(s) => s.write(buf),
+            }
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            match self {
+                Inner::Memory(c) => c.flush(),
+                Inner::Fixed(m) => m.flush(),
+                Inner::Io(s) => s.flush(),
+            }
+        }
+    }
+
+    impl Seek for Inner {
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            match self {
+                Inner::Memory(c) => c.seek(pos),
+                Inner::Fixed(m) => m.seek(pos),
+                Inner::Io(s) => s.seek(pos),
+            }
+        }
+    }
+
+    pub struct Stream {
+        inner: MutCell<Inner>,
+        readable: bool,
+        writable: bool,
+        seekable: bool,
+        disposed: MutCell<bool>,
+    }
+
+    impl Debug for Stream {
+        fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+            f.debug_struct("Stream")
+                .field("canRead", &self.canRead())
+                .field("canWrite", &self.canWrite())
+                .field("canSeek", &self.canSeek())
+                .finish()
+        }
+    }
+
+    fn io_panic(e: io::Error) -> ! {
+        match e.kind() {
+            io::ErrorKind::Unsupported => panic!("Specified method is not supported."),
+            _ => panic!("{}", e),
+        }
+    }
+
+    impl Stream {
+        fn new(inner: Inner, readable: bool, writable: bool, seekable: bool) -> LrcPtr<Stream> {
+            LrcPtr::new(Stream {
+                inner: MutCell::new(inner),
+                readable,
+                writable,
+                seekable,
+                disposed: MutCell::new(false),
+            })
+        }
+
+        // -----------------------------------------------------------
+        // std::io adapters
+        // -----------------------------------------------------------
+
+        pub fn fromReadWriteSeek<T: Read + Write + Seek + 'static>(inner: T) -> LrcPtr<Stream> {
+            Stream::new(Inner::Io(Box::new(inner)), true, true, true)
+        }
+
+        pub fn fromReadSeek<T: Read + Seek + 'static>(inner: T) -> LrcPtr<Stream> {
+            Stream::new(Inner::Io(Box::new(ReadSeek(inner))), true, false, true)
+        }
+
+        pub fn fromRead<T: Read + 'static>(inner: T) -> LrcPtr<Stream> {
+            Stream::new(Inner::Io(Box::new(ReadOnly(inner))), true, false, false)
+        }
+
+        pub fn fromWrite<T: Write + 'static>(inner: T) -> LrcPtr<Stream> {
+            Stream::new(Inner::Io(Box::new(WriteOnly(inner))), false, true, false)
+        }
+
+        // -----------------------------------------------------------
+        // MemoryStream
+        // -----------------------------------------------------------
+
+        pub fn memoryStream() -> LrcPtr<Stream> {
+            Stream::new(Inner::Memory(Cursor::new(Vec::new())), true, true, true)
+        }
+
+        pub fn memoryStreamFrom(buffer: Array<u8>) -> LrcPtr<Stream> {
+            let length = buffer.len();
+            let memory = FixedMemory { buffer, position: 0, length };
+            Stream::new(Inner::Fixed(memory), true, true, true)
+        }
+
+        pub fn toArray(&self) -> Array<u8> {
+            match self.inner.as_ref() {
+                Inner::Memory(c) => array_from(c.get_ref().clone()),
+                Inner::Fixed(m) => array_from(m.buffer.as_slice()[..m.length].to_vec()),
+                Inner::Io(_) => panic!("Specified method is not supported."),
+            }
+        }
+
+        // -----------------------------------------------------------
+        // Stream members
+        // -----------------------------------------------------------
+
+        fn check_disposed(&self) {
+            if self.disposed.get() {
+                panic!("Cannot access a closed Stream.");
+            }
+        }
+
+        fn check_readable(&self) {
+            self.check_disposed();
+            if !self.readable {
+                panic!("Stream does not support reading.");
+            }
+        }
+
+        fn check_writable(&self) {
+            self.check_disposed();
+            if !self.writable {
+                panic!("Stream does not support writing.");
+            }
+        }
+
+        fn check_seekable(&self) {
+            self.check_disposed();
+            if !self.seekable {
+                panic!("Stream does not support seeking.");
+            }
+        }
+
+        fn check_range(buffer: &Array<u8>, offset: i32, count: i32) -> core::ops::Range<usize> {
+            if offset < 0 || count < 0 || offset as usize + count as usize > buffer.len() {
+                panic!("Offset and length were out of bounds for the array or count is greater than the number of elements from index to the end of the source collection.");
+            }
+            offset as usize..(offset + count) as usize
+        }
+
+        pub fn canRead(&self) -> bool {
+            self.readable && !self.disposed.get()
+        }
+
+        pub fn canWrite(&self) -> bool {
+            self.writable && !self.disposed.get()
+        }
+
+        pub fn canSeek(&self) -> bool {
+            self.seekable && !self.disposed.get()
+        }
+
+        pub fn length(&self) -> i64 {
+            self.check_seekable();
+            let inner = self.inner.get_mut();
+            match inner {
+                Inner::Memory(c) => c.get_ref().len() as i64,
+                Inner::Fixed(m) => m.length as i64,
+                Inner::Io(s) => {
+                    let pos = s.stream_position().unwrap_or_else(|e| io_panic(e));
+                    let len = s.seek(SeekFrom::End(0)).unwrap_or_else(|e| io_panic(e));
+                    s.seek(SeekFrom::Start(pos)).unwrap_or_else(|e| io_panic(e));
+                    len as i64
+                }
+            }
+        }
+
+        pub fn setLength(&self, value: i64) {
+            self.check_seekable();
+            self.check_writable();
+            match self.inner.get_mut() {
+                Inner::Memory(c) => {
+                    c.get_mut().resize(value as usize, 0);
+                    if c.position() > value as u64 {
+                        c.set_position(value as u64);
+                    }
+                }
+                Inner::Fixed(m) => {
+                    m.set_length(value as usize).unwrap_or_else(|e| io_panic(e));
+                    m.position = m.position.min(value as u64);
+                }
+                Inner::Io(_) => panic!("Specified method is not supported."),
+            }
+        }
+
+        pub fn position(&self) -> i64 {
+            self.check_seekable();
+            let pos = self.inner.get_mut().stream_position();
+            pos.unwrap_or_else(|e| io_panic(e)) as i64
+        }
+
+        pub fn set_position(&self, value: i64) {
+            if value < 0 {
+                panic!("Non-negative number required. (Parameter 'value')");
+            }
+            self.seek(value, BEGIN);
+        }
+
+        pub fn seek(&self, offset: i64, origin: i32) -> i64 {
+            self.check_seekable();
+            let pos = match origin {
+                BEGIN if offset >= 0 => SeekFrom::Start(offset as u64),
+                BEGIN => panic!("An attempt was made to move the position before the beginning of the stream."),
+                CURRENT => SeekFrom::Current(offset),
+                END => SeekFrom::End(offset),
+                _ => panic!("Invalid seek origin. (Parameter 'origin')"),
+            };
+            let pos = self.inner.get_mut().seek(pos);
+            pos.unwrap_or_else(|e| io_panic(e)) as i64
+        }
+
+        pub fn read(&self, buffer: Array<u8>, offset: i32, count: i32) -> i32 {
+            self.check_readable();
+            let range = Stream::check_range(&buffer, offset, count);
+            let n = self.inner.get_mut().read(&mut buffer.get_mut()[range]);
+            n.unwrap_or_else(|e| io_panic(e)) as i32
+        }
+
+        pub fn readByte(&self) -> i32 {
+            self.check_readable();
+            let mut buf = [0u8; 1];
+            match self.inner.get_mut().read(&mut buf) {
+                Ok(0) => -1,
+                Ok(_) => buf[0] as i32,
+                Err(e) => io_panic(e),
+            }
+        }
+
+        pub fn write(&self, buffer: Array<u8>, offset: i32, count: i32) {
+            self.check_writable();
+            let range = Stream::check_range(&buffer, offset, count);
+            let res = self.inner.get_mut().write_all(&buffer.as_slice()[range]);
+            res.unwrap_or_else(|e| io_panic(e))
+        }
+
+        pub fn writeByte(&self, value: u8) {
+            self.check_writable();
+            let res = self.inner.get_mut().write_all(&[value]);
+            res.unwrap_or_else(|e| io_panic(e))
+        }
+
+        pub fn flush(&self) {
+            self.check_disposed();
+            self.inner.get_mut().flush().unwrap_or_else(|e| io_panic(e))
+        }
+
+        pub fn copyTo(&self, destination: LrcPtr<Stream>) {
+            self.check_readable();
+            destination.check_writable();
+            let target: &Stream = &destination;
+            if core::ptr::eq(self, target) {
+                // the rest of the stream is read before it is written back,
+                // so the copy doesn't keep reading what it has just written
+                let mut rest = Vec::new();
+                self.inner.get_mut().read_to_end(&mut rest).unwrap_or_else(|e| io_panic(e));
+                let res = self.inner.get_mut().write_all(&rest);
+                return res.unwrap_or_else(|e| io_panic(e));
+            }
+            let mut buf = [0u8; 81920];
+            loop {
+                let n = self.inner.get_mut().read(&mut buf).unwrap_or_else(|e| io_panic(e));
+                if n == 0 {
+                    break;
+                }
+                let res = destination.inner.get_mut().write_all(&buf[..n]);
+                res.unwrap_or_else(|e| io_panic(e));
+            }
+        }
+
+        pub fn close(&self) {
+            self.dispose()
+        }
+
+        pub fn dispose(&self) {
+            if !self.disposed.get() {
+                let _ = self.inner.get_mut().flush();
+                self.disposed.set(true);
+            }
+        }
+    }
+
+    impl IDisposable for Stream {
+        fn Dispose(&self) {
+            self.dispose()
+        }
+    }
+
+    // -----------------------------------------------------------
+    // std::io traits
+    // -----------------------------------------------------------
+
+    fn check_io(stream: &Stream, supported: bool) -> io::Result<()> {
+        if stream.disposed.get() {
+            Err(io::Error::other("Cannot access a closed Stream."))
+        } else if !supported {
+            unsupported()
+        } else {
+            Ok(())
+        }
+    }
+
+    impl Read for &Stream {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            check_io(self, self.readable)?;
+            self.inner.get_mut().read(buf)
+        }
+    }
+
+    impl Write for &Stream {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            check_io(self, self.writable)?;
+            self.inner.get_mut().write(buf)
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            check_io(self, true)?;
+            self.inner.get_mut().flush()
+        }
+    }
+
+    impl Seek for &Stream {
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            check_io(self, self.seekable)?;
+            self.inner.get_mut().seek(pos)
+        }
+    }
+}
+
+#[cfg(all(not(feature = "no_std"), feature = "datetime"))]
+pub mod FileSystem_ {
+
+    // -----------------------------------------------------------
+    // System.IO.File, System.IO.FileInfo
+    // -----------------------------------------------------------
+
+    // File timestamps are read from `std::fs::Metadata` as UTC DateTimes.
+    // Like in .NET, the times of a missing file are 1601-01-01 (the Win32
+    // FileTime epoch), and the creation time falls back to the last write
+    // time on file systems that don't record it.
+
+    use crate::DateTime_::{DateTime, DateTimeKind};
+    use crate::Native_::{LrcPtr, ToString};
+    use crate::String_::{fromString, string};
+
+    use std::fs::{self, Metadata, OpenOptions};
+    use std::io;
+    use std::path::{Path, PathBuf};
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    const ticks_per_second: u64 = 10_000_000;
+
+    fn to_date_time(time: SystemTime) -> DateTime {
+        let ticks = match time.duration_since(UNIX_EPOCH) {
+            Ok(d) => (d.as_nanos() / 100) as i64,
+            Err(e) => -((e.duration().as_nanos() / 100) as i64),
+        };
+        DateTime::new_ticks_kind(DateTime::unixEpoch().ticks() + ticks, 1)
+    }
+
+    // like .NET, only local times are converted, unspecified times are UTC
+    fn to_system_time(dt: DateTime) -> SystemTime {
+        let dt = match dt.kind_enum() {
+            DateTimeKind::Local => dt.toUniversalTime(),
+            _ => dt,
+        };
+        let ticks = dt.ticks() - DateTime::unixEpoch().ticks();
+        let abs = ticks.unsigned_abs();
+        let d = Duration::new(abs / ticks_per_second, (abs % ticks_per_second * 100) as u32);
+        if ticks >= 0 {
+            UNIX_EPOCH + d
+        } else {
+            UNIX_EPOCH - d
+        }
+    }
+
+    fn full_path(path: &str) -> PathBuf {
+        match std::env::current_dir() {
+            Ok(dir) => dir.join(path),
+            Err(_) => PathBuf::from(path),
+        }
+    }
+
+    fn file_not_found(path: &str) -> ! {
+        panic!("Could not find file '{}'.", full_path(path).display())
+    }
+
+    fn metadata(path: &str) -> Option<Metadata> {
+        fs::metadata(path).ok()
+    }
+
+    fn time_or_default(path: &str, get: fn(&Metadata) -> io::Result<SystemTime>) -> DateTime {
+        match metadata(path) {
+            Some(m) => get(&m).or_else(|_| m.modified()).map(to_date_time).unwrap_or_else(|e| panic!("{}", e)),
+            None => DateTime::fromFileTimeUtc(0),
+        }
+    }
+
+    fn set_last_write_time(path: &str, time: DateTime) {
+        let file = OpenOptions::new().write(true).open(path).or_else(|_| fs::File::open(path));
+        let res = file.and_then(|file| file.set_modified(to_system_time(time)));
+        match res {
+            Ok(()) => (),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => file_not_found(path),
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    pub fn exists(path: string) -> bool {
+        Path::new(path.as_str()).is_file()
+    }
+
+    pub fn getCreationTimeUtc(path: string) -> DateTime {
+        time_or_default(&path, Metadata::created)
+    }
+
+    pub fn getCreationTime(path: string) -> DateTime {
+        getCreationTimeUtc(path).toLocalTime()
+    }
+
+    pub fn getLastWriteTimeUtc(path: string) -> DateTime {
+        time_or_default(&path, Metadata::modified)
+    }
+
+    pub fn getLastWriteTime(path: string) -> DateTime {
+        getLastWriteTimeUtc(path).toLocalTime()
+    }
+
+    pub fn getLastAccessTimeUtc(path: string) -> DateTime {
+        time_or_default(&path, Metadata::accessed)
+    }
+
+    pub fn getLastAccessTime(path: string) -> DateTime {
+        getLastAccessTimeUtc(path).toLocalTime()
+    }
+
+    pub fn setLastWriteTimeUtc(path: string, lastWriteTimeUtc: DateTime) {
+        set_last_write_time(&path, lastWriteTimeUtc)
+    }
+
+    // an unspecified time is a local time here
+    pub fn setLastWriteTime(path: string, lastWriteTime: DateTime) {
+        let time = match lastWriteTime.kind_enum() {
+            DateTimeKind::Unspecified => DateTime::new(lastWriteTime.naive_date_time(), DateTimeKind::Local),
+            _ => lastWriteTime,
+        };
+        set_last_write_time(&path, time)
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct FileInfo {
+        path: string,
+    }
+
+    impl FileInfo {
+        pub fn new(fileName: string) -> LrcPtr<FileInfo> {
+            if fileName.is_empty() {
+                panic!("The path is empty. (Parameter 'path')");
+            }
+            LrcPtr::new(FileInfo { path: fileName })
+        }
+
+        pub fn name(&self) -> string {
+            match Path::new(self.path.as_str()).file_name() {
+                Some(name) => fromString(name.to_string_lossy().to_string()),
+                None => string(""),
+            }
+        }
+
+        pub fn fullName(&self) -> string {
+            fromString(full_path(&self.path).to_string_lossy().to_string())
+        }
+
+        pub fn directoryName(&self) -> string {
+            match full_path(&self.path).parent() {
+                Some(dir) => fromString(dir.to_string_lossy().to_string()),
+                None => string(""),
+            }
+        }
+
+        pub fn exists(&self) -> bool {
+            exists(self.path.clone())
+        }
+
+        pub fn length(&self) -> i64 {
+            match metadata(&self.path) {
+                Some(m) if m.is_file() => m.len() as i64,
+                _ => file_not_found(&self.path),
+            }
+        }
+
+        pub fn creationTimeUtc(&self) -> DateTime {
+            getCreationTimeUtc(self.path.clone())
+        }
+
+        pub fn creationTime(&self) -> DateTime {
+            getCreationTime(self.path.clone())
+        }
+
+        pub fn lastWriteTimeUtc(&self) -> DateTime {
+            getLastWriteTimeUtc(self.path.clone())
+        }
+
+        pub fn lastWriteTime(&self) -> DateTime {
+            getLastWriteTime(self.path.clone())
+        }
+
+        pub fn lastAccessTimeUtc(&self) -> DateTime {
+            getLastAccessTimeUtc(self.path.clone())
+        }
+
+        pub fn lastAccessTime(&self) -> DateTime {
+            getLastAccessTime(self.path.clone())
+        }
+
+        pub fn set_lastWriteTimeUtc(&self, value: DateTime) {
+            setLastWriteTimeUtc(self.path.clone(), value)
+        }
+
+        pub fn set_lastWriteTime(&self, value: DateTime) {
+            setLastWriteTime(self.path.clone(), value)
+        }
+
+        pub fn toString(&self) -> string {
+            self.path.clone()
+        }
+    }
+}
@@ -82,3 +82,13 @@ module System =
                 abstract ContainsKey: 'K -> bool
                 abstract TryGetValue: 'K * byref<'V> -> bool
                 abstract Remove: 'K -> bool
+
+module Microsoft =
+
+    module Extensions =
+
+        module ObjectPool =
+
+            type IPooledObjectPolicy<'T> =
+                abstract Create: unit -> 'T
+                abstract Return: 'T -> bool
@@ -35,6 +35,26 @@ pub mod ListExt {
         }
     }
 
+    impl<T: Clone> From<Vec<T>> for List<T> {
+        fn from(vec: Vec<T>) -> Self {
+            let mut lst: List<T> = empty();
+            for item in vec.into_iter().rev() {
+                lst = cons(item, lst);
+            }
+            lst
+        }
+    }
+
+    impl<T: Clone> From<&[T]> for List<T> {
+        fn from(slice: &[T]) -> Self {
+            let mut lst: List<T> = empty();
+            for item in slice.iter().rev() {
+                lst = cons(item.clone(), lst);
+            }
+            lst
+        }
+    }
+
     impl<T: Clone> FromIterator<T> for List<T> {
         fn from_iter<U: IntoIterator<Item = T>>(iter: U) -> Self {
             let mut lst: List<T> = empty();
@@ -63,7 +83,7 @@ pub mod ListExt {
 }
 
 pub mod SetExt {
-    use crate::Native_::{makeCompare, seq_to_iter, Func2, Vec};
+    use crate::Native_::{alloc::collections::BTreeSet, makeCompare, seq_to_iter, Func2, Vec};
     use crate::Set_::{add, compareTo, empty, equals, toSeq, Set};
     use core::cmp::Ordering;
     use core::hash::{Hash, Hasher};
@@ -143,11 +163,23 @@ pub mod SetExt {
             self.into_iter().collect()
         }
     }
+
+    impl<T: Clone + PartialOrd> From<BTreeSet<T>> for Set<T> {
+        fn from(set: BTreeSet<T>) -> Self {
+            set.into_iter().collect()
+        }
+    }
+
+    impl<T: Clone + Ord> From<Set<T>> for BTreeSet<T> {
+        fn from(set: Set<T>) -> Self {
+            set.into_iter().collect()
+        }
+    }
 }
 
 pub mod MapExt {
     use crate::Map_::{add, compareTo, empty, equals, iterate, toSeq, Map};
-    use crate::Native_::{makeCompare, seq_to_iter, Func2, Vec};
+    use crate::Native_::{alloc::collections::BTreeMap, makeCompare, seq_to_iter, Func2, Vec};
     use core::cmp::Ordering;
     use core::hash::{Hash, Hasher};
 
@@ -201,6 +233,18 @@ pub mod MapExt {
         }
     }
 
+    impl<K: Clone + PartialOrd, V: Clone> From<Vec<(K, V)>> for Map<K, V> {
+        fn from(vec: Vec<(K, V)>) -> Self {
+            vec.into_iter().collect()
+        }
+    }
+
+    impl<K: Clone + PartialOrd, V: Clone> From<BTreeMap<K, V>> for Map<K, V> {
+        fn from(map: BTreeMap<K, V>) -> Self {
+            map.into_iter().collect()
+        }
+    }
+
     impl<K: Clone + PartialOrd, V: Clone> FromIterator<(K, V)> for Map<K, V> {
         fn from_iter<U: IntoIterator<Item = (K, V)>>(iter: U) -> Self {
             let mut map: Map<K, V> = empty();
@@ -216,4 +260,55 @@ pub mod MapExt {
             self.into_iter().collect()
         }
     }
+
+    impl<K: Clone + Ord, V: Clone> From<Map<K, V>> for BTreeMap<K, V> {
+        fn from(map: Map<K, V>) -> Self {
+            map.into_iter().collect()
+        }
+    }
+}
+
+/// Interop with F# sequences (`seq<'T>`).
+///
+/// A `seq<T>` is an `LrcPtr<dyn IEnumerable<T>>` (`Rc` by default, `Arc` with
+/// the `atomic` or `threaded` features), so any Rust type implementing
+/// [`IEnumerable`] can be passed to generated functions expecting sequences.
+/// The trait is object-safe; implementors must be `'static`, and
+/// `GetEnumerator` must return a fresh enumerator on every call, since
+/// sequences can be enumerated more than once.
+pub mod SeqExt {
+    pub use crate::Interfaces_::System::Collections::Generic::{
+        IEnumerable_1 as IEnumerable, IEnumerator_1 as IEnumerator,
+    };
+    pub use crate::Native_::{iter_to_seq, seq, seq_to_iter};
+    use crate::Native_::{mkMut, Func0, Lrc, LrcPtr};
+    use crate::Seq_::Enumerable::fromFunction;
+
+    /// Wraps a re-iterable collection (e.g. `Vec<T>`, `BTreeSet<T>`, a range),
+    /// cloning it each time a new enumerator is requested.
+    #[derive(Clone, Debug)]
+    pub struct Enumerable<C>(pub C);
+
+    impl<T, C> IEnumerable<T> for Enumerable<C>
+    where
+        T: Clone + 'static,
+        C: Clone + IntoIterator<Item = T>,
+        C::IntoIter: 'static,
+    {
+        fn GetEnumerator(&self) -> LrcPtr<dyn IEnumerator<T>> {
+            let iter = mkMut(self.0.clone().into_iter());
+            fromFunction(Func0::new(move || iter.get_mut().next()))
+        }
+    }
+
+    /// Converts a re-iterable collection into a `seq<T>`.
+    /// Unlike `iter_to_seq`, the resulting sequence can be enumerated many times.
+    pub fn to_seq<T, C>(items: C) -> seq<T>
+    where
+        T: Clone + 'static,
+        C: Clone + IntoIterator<Item = T> + 'static,
+        C::IntoIter: 'static,
+    {
+        LrcPtr::from(Lrc::new(Enumerable(items)) as Lrc<dyn IEnumerable<T>>)
+    }
 }
@@ -879,44 +879,53 @@ let countBy<'T, 'Key when 'Key: equality>
     (xs: 'T list)
     : ('Key * int) list
     =
+    // maps each key to its index, so each item is hashed only once
     let dict = System.Collections.Generic.Dictionary<'Key, int>()
     let keys = ResizeArray<'Key>()
+    let counts = ResizeArray<int>()
 
     xs
     |> iterate (fun x ->
         let key = projection x
 
         match dict.TryGetValue(key) with
-        | true, prev -> dict[key] <- prev + 1
+        | true, i -> counts[i] <- counts[i] + 1
         | false, _ ->
-            dict[key] <- 1
+            dict.Add(key, keys.Count)
             keys.Add(key)
+            counts.Add(1)
     )
 
-    keys |> asArray |> Array.map (fun key -> key, dict[key]) |> ofArray
+    keys
+    |> asArray
+    |> Array.mapi (fun i key -> key, counts[i])
+    |> ofArray
 
 let groupBy<'T, 'Key when 'Key: equality>
     (projection: 'T -> 'Key)
     (xs: 'T list)
     : ('Key * 'T list) list
     =
-    let dict = System.Collections.Generic.Dictionary<'Key, ResizeArray<'T>>()
+    // maps each key to its index, so each item is hashed only once
+    let dict = System.Collections.Generic.Dictionary<'Key, int>()
     let keys = ResizeArray<'Key>()
+    let groups = ResizeArray<ResizeArray<'T>>()
 
     xs
     |> iterate (fun x ->
         let key = projection x
 
         match dict.TryGetValue(key) with
-        | true, prev -> prev.Add(x)
+        | true, i -> groups[i].Add(x)
         | false, _ ->
-            dict.Add(key, ResizeArray [| x |])
+            dict.Add(key, keys.Count)
             keys.Add(key)
+            groups.Add(ResizeArray [| x |])
     )
 
     keys
     |> asArray
-    |> Array.map (fun key -> key, dict[key] |> asArray |> ofArray)
+    |> Array.mapi (fun i key -> key, groups[i] |> asArray |> ofArray)
     |> ofArray
 
 let insertAt (index: int) (y: 'T) (xs: 'T list) : 'T list =
@@ -509,14 +509,43 @@ let toArray (m: Map<'K, 'V>) =
 let toList (m: Map<'K, 'V>) =
     foldBack (fun k v acc -> (k, v) :: acc) m []
 
-let ofArray xs =
-    Array.fold (fun acc (k, v) -> add k v acc) empty xs
+// Bulk construction from sorted arrays
 
-let ofList xs =
-    List.fold (fun acc (k, v) -> add k v acc) empty xs
+let rec private ofSortedArrayAux (arr: ('K * 'V)[]) lo hi : Map<'K, 'V> =
+    if lo > hi then
+        empty
+    else
+        let mid = lo + (hi - lo) / 2
+        let l = ofSortedArrayAux arr lo (mid - 1)
+        let r = ofSortedArrayAux arr (mid + 1) hi
+        let k, v = arr[mid]
+        mk l k v r
+
+// builds a balanced tree from an array sorted by keys without duplicates
+let private ofSortedArray (arr: ('K * 'V)[]) =
+    ofSortedArrayAux arr 0 (arr.Length - 1)
+
+// keeps the last of equal keys, same as repeated add
+let private distinctSorted (arr: ('K * 'V)[]) =
+    let res = ResizeArray<'K * 'V>(arr.Length)
+    let last = arr.Length - 1
+
+    for i = 0 to last do
+        if i = last || compare (fst arr[i]) (fst arr[i + 1]) <> 0 then
+            res.Add(arr[i])
+
+    res |> asArray
+
+let ofArray (xs: ('K * 'V)[]) =
+    // the sort is stable, so later values of equal keys stay last
+    xs
+    |> Array.sortWith (fun (k1, _) (k2, _) -> compare k1 k2)
+    |> distinctSorted
+    |> ofSortedArray
+
+let ofList (xs: ('K * 'V) list) = xs |> List.toArray |> ofArray
 
-let ofSeq xs =
-    Seq.fold (fun acc (k, v) -> add k v acc) empty xs
+let ofSeq (xs: ('K * 'V) seq) = xs |> Seq.toArray |> ofArray
 
 /// Imperative left-to-right iterators.
 [<NoEquality; NoComparison>]
@@ -0,0 +1,353 @@
+#[cfg(not(feature = "no_std"))]
+pub mod Metrics_ {
+
+    // -----------------------------------------------------------
+    // System.Diagnostics.Metrics
+    // -----------------------------------------------------------
+
+    // Meters and instruments only record measurements while a listener is
+    // registered with `addListener`. Listeners are the exporter hook for the
+    // Rust side: a listener can forward measurements to the `metrics` crate,
+    // OpenTelemetry, or any other backend. Like in .NET, meters are kept
+    // alive until they are disposed, so observable instruments can be polled
+    // with `recordObservableInstruments`.
+
+    use crate::Interfaces_::System::IDisposable;
+    use crate::NativeArray_::Array;
+    use crate::Native_::{Any, Func0, Lrc, LrcPtr, MutCell, ToString, Vec};
+    use crate::String_::{fromString, string};
+
+    use core::fmt::{Debug, Formatter};
+    use std::sync::Mutex;
+
+    pub type Tag = (string, LrcPtr<dyn Any>);
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub enum InstrumentKind {
+        Counter,
+        UpDownCounter,
+        Histogram,
+        Gauge,
+        ObservableCounter,
+        ObservableUpDownCounter,
+        ObservableGauge,
+    }
+
+    impl InstrumentKind {
+        pub fn isObservable(&self) -> bool {
+            matches!(
+                self,
+                InstrumentKind::ObservableCounter
+                    | InstrumentKind::ObservableUpDownCounter
+                    | InstrumentKind::ObservableGauge
+            )
+        }
+    }
+
+    // Measurements are reported to listeners as f64 values
+    pub trait Measurement: Copy + 'static {
+        fn to_f64(self) -> f64;
+    }
+
+    macro_rules! measurement {
+        ($($t:ty),*) => {
+            $(impl Measurement for $t {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            })*
+        };
+    }
+
+    measurement!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+
+    pub trait MeterListener {
+        // called when an instrument is created, e.g. to register its description
+        fn instrument_published(&self, _instrument: &Instrument) {}
+
+        fn measurement_recorded(&self, instrument: &Instrument, value: f64, tags: &[(string, string)]);
+    }
+
+    // The registries are shared by all threads, so every access takes the lock.
+    // The lock is never held while a listener or an instrument callback runs.
+    static LISTENERS: Mutex<MutCell<Vec<LrcPtr<dyn MeterListener>>>> = Mutex::new(MutCell::new(Vec::new()));
+    static METERS: Mutex<MutCell<Vec<LrcPtr<Meter>>>> = Mutex::new(MutCell::new(Vec::new()));
+
+    fn locked<T, R>(registry: &Mutex<MutCell<Vec<T>>>, f: impl FnOnce(&mut Vec<T>) -> R) -> R {
+        let guard = registry.lock().unwrap_or_else(|e| e.into_inner());
+        f(guard.get_mut())
+    }
+
+    // returns the registered listener, to be passed to `removeListener`
+    pub fn addListener<L: MeterListener + 'static>(listener: L) -> LrcPtr<dyn MeterListener> {
+        let listener: Lrc<dyn MeterListener> = Lrc::new(listener);
+        let listener: LrcPtr<dyn MeterListener> = LrcPtr::from(listener);
+        locked(&LISTENERS, |l| l.push(listener.clone()));
+        listener
+    }
+
+    pub fn removeListener(listener: &LrcPtr<dyn MeterListener>) {
+        locked(&LISTENERS, |l| l.retain(|l| !ptr_eq(l, listener)));
+    }
+
+    pub fn recordObservableInstruments() {
+        let meters = locked(&METERS, |m| m.clone());
+        for meter in meters.iter() {
+            meter.recordObservableInstruments();
+        }
+    }
+
+    fn ptr_eq<T: ?Sized>(x: &LrcPtr<T>, y: &LrcPtr<T>) -> bool {
+        core::ptr::addr_eq(x.as_ref(), y.as_ref())
+    }
+
+    fn listeners() -> Vec<LrcPtr<dyn MeterListener>> {
+        // cloned, so listeners can be added or removed from a callback
+        locked(&LISTENERS, |l| l.clone())
+    }
+
+    // boxed tag values are formatted when they are primitive types
+    fn tagValue(value: &LrcPtr<dyn Any>) -> string {
+        macro_rules! try_format {
+            ($($t:ty),*) => {
+                $(if let Some(v) = value.as_ref().downcast_ref::<$t>() {
+                    return fromString(v.to_string());
+                })*
+            };
+        }
+        try_format!(string, bool, char, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+        string("")
+    }
+
+    // -----------------------------------------------------------
+    // Meter
+    // -----------------------------------------------------------
+
+    pub struct Meter {
+        name: string,
+        version: string,
+        instruments: MutCell<Vec<LrcPtr<Instrument>>>,
+        disposed: MutCell<bool>,
+    }
+
+    impl Debug for Meter {
+        fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+            f.debug_struct("Meter")
+                .field("name", &self.name)
+                .field("version", &self.version)
+                .finish()
+        }
+    }
+
+    impl Meter {
+        pub fn new(name: string, version: string) -> LrcPtr<Meter> {
+            let meter = LrcPtr::new(Meter {
+                name,
+                version,
+                instruments: MutCell::new(Vec::new()),
+                disposed: MutCell::new(false),
+            });
+            locked(&METERS, |m| m.push(meter.clone()));
+            meter
+        }
+
+        pub fn name(&self) -> string {
+            self.name.clone()
+        }
+
+        pub fn version(&self) -> string {
+            self.version.clone()
+        }
+
+        fn create(
+            &self,
+            kind: InstrumentKind,
+            name: string,
+            unit: string,
+            description: string,
+            observe: Option<Func0<f64>>,
+        ) -> LrcPtr<Instrument> {
+            let instrument = LrcPtr::new(Instrument {
+                meterName: self.name.clone(),
+                meterVersion: self.version.clone(),
+                name,
+                unit,
+                description,
+                kind,
+                observe,
+                enabled: MutCell::new(!self.disposed.get()),
+            });
+            if instrument.enabled.get() {
+                self.instruments.get_mut().push(instrument.clone());
+                for listener in listeners().iter() {
+                    listener.instrument_published(&instrument);
+                }
+            }
+            instrument
+        }
+
+        pub fn createCounter(&self, name: string, unit: string, description: string) -> LrcPtr<Instrument> {
+            self.create(InstrumentKind::Counter, name, unit, description, None)
+        }
+
+        pub fn createUpDownCounter(&self, name: string, unit: string, description: string) -> LrcPtr<Instrument> {
+            self.create(InstrumentKind::UpDownCounter, name, unit, description, None)
+        }
+
+        pub fn createHistogram(&self, name: string, unit: string, description: string) -> LrcPtr<Instrument> {
+            self.create(InstrumentKind::Histogram, name, unit, description, None)
+        }
+
+        pub fn createGauge(&self, name: string, unit: string, description: string) -> LrcPtr<Instrument> {
+            self.create(InstrumentKind::Gauge, name, unit, description, None)
+        }
+
+        fn createObservable<T: Measurement>(
+            &self,
+            kind: InstrumentKind,
+            name: string,
+            observe: Func0<T>,
+            unit: string,
+            description: string,
+        ) -> LrcPtr<Instrument> {
+            let observe = Func0::new(move || observe().to_f64());
+            self.create(kind, name, unit, description, Some(observe))
+        }
+
+        pub fn createObservableCounter<T: Measurement>(
+            &self,
+            name: string,
+            observe: Func0<T>,
+            unit: string,
+            description: string,
+        ) -> LrcPtr<Instrument> {
+            self.createObservable(InstrumentKind::ObservableCounter, name, observe, unit, description)
+        }
+
+        pub fn createObservableUpDownCounter<T: Measurement>(
+            &self,
+            name: string,
+            observe: Func0<T>,
+            unit: string,
+            description: string,
+        ) -> LrcPtr<Instrument> {
+            self.createObservable(InstrumentKind::ObservableUpDownCounter, name, observe, unit, description)
+        }
+
+        pub fn createObservableGauge<T: Measurement>(
+            &self,
+            name: string,
+            observe: Func0<T>,
+            unit: string,
+            description: string,
+        ) -> LrcPtr<Instrument> {
+            self.createObservable(InstrumentKind::ObservableGauge, name, observe, unit, description)
+        }
+
+        pub fn recordObservableInstruments(&self) {
+            let instruments = self.instruments.get_mut().clone();
+            for instrument in instruments.iter() {
+                if let Some(observe) = &instrument.observe {
+                    instrument.publish(observe(), &[]);
+                }
+            }
+        }
+
+        pub fn dispose(&self) {
+            if !self.disposed.get() {
+                self.disposed.set(true);
+                for instrument in self.instruments.get_mut().drain(..) {
+                    instrument.enabled.set(false);
+                }
+                locked(&METERS, |m| m.retain(|m| !core::ptr::eq(m.as_ref(), self)));
+            }
+        }
+    }
+
+    impl IDisposable for Meter {
+        fn Dispose(&self) {
+            self.dispose()
+        }
+    }
+
+    // -----------------------------------------------------------
+    // Instruments
+    // -----------------------------------------------------------
+
+    // All instrument types (Counter<T>, Histogram<T>, ObservableGauge<T>...)
+    // share the same implementation, `kind` tells them apart.
+    pub struct Instrument {
+        meterName: string,
+        meterVersion: string,
+        name: string,
+        unit: string,
+        description: string,
+        kind: InstrumentKind,
+        observe: Option<Func0<f64>>,
+        enabled: MutCell<bool>,
+    }
+
+    impl Debug for Instrument {
+        fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+            f.debug_struct("Instrument")
+                .field("meter", &self.meterName)
+                .field("name", &self.name)
+                .field("kind", &self.kind)
+                .finish()
+        }
+    }
+
+    impl Instrument {
+        pub fn meterName(&self) -> string {
+            self.meterName.clone()
+        }
+
+        pub fn meterVersion(&self) -> string {
+            self.meterVersion.clone()
+        }
+
+        pub fn name(&self) -> string {
+            self.name.clone()
+        }
+
+        pub fn unit(&self) -> string {
+            self.unit.clone()
+        }
+
+        pub fn description(&self) -> string {
+            self.description.clone()
+        }
+
+        pub fn kind(&self) -> InstrumentKind {
+            self.kind
+        }
+
+        pub fn enabled(&self) -> bool {
+            self.enabled.get() && !locked(&LISTENERS, |l| l.is_empty())
+        }
+
+        pub fn isObservable(&self) -> bool {
+            self.kind.isObservable()
+        }
+
+        fn publish(&self, value: f64, tags: &[Tag]) {
+            if self.enabled() {
+                let tags: Vec<(string, string)> =
+                    tags.iter().map(|(k, v)| (k.clone(), tagValue(v))).collect();
+                for listener in listeners().iter() {
+                    listener.measurement_recorded(self, value, &tags);
+                }
+            }
+        }
+
+        // Counter<T>.Add, UpDownCounter<T>.Add
+        pub fn add<T: Measurement>(&self, delta: T, tags: Array<Tag>) {
+            self.publish(delta.to_f64(), tags.as_slice())
+        }
+
+        // Histogram<T>.Record, Gauge<T>.Record
+        pub fn record<T: Measurement>(&self, value: T, tags: Array<Tag>) {
+            self.publish(value.to_f64(), tags.as_slice())
+        }
+    }
+}
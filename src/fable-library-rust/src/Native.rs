@@ -72,6 +72,8 @@ pub mod Native_ {
 
     pub fn ignore<T>(arg: &T) -> () {}
 
+    // only used for types the compiler cannot build a default value for
+    // (e.g. generic parameters), so it is only safe for types without references
     pub fn defaultOf<T>() -> T {
         unsafe { core::mem::zeroed() } // will panic on Rc/Arc/Box
     }
@@ -236,6 +238,31 @@ pub mod Native_ {
         LrcPtr::new(MutCell::from(x))
     }
 
+    // -----------------------------------------------------------
+    // Entropy
+    // -----------------------------------------------------------
+
+    static entropy_source: MutCell<Option<fn(&mut [u8])>> = MutCell::new(None);
+
+    /// Registers the random source used by Guid.NewGuid and System.Random,
+    /// e.g. a hardware RNG on embedded targets built without the `std` feature.
+    pub fn set_entropy_source(source: fn(&mut [u8])) {
+        entropy_source.set(Some(source));
+    }
+
+    pub(crate) fn fill_entropy(buf: &mut [u8]) {
+        match entropy_source.get() {
+            Some(source) => source(buf),
+            #[cfg(feature = "std")]
+            None => match getrandom::getrandom(buf) {
+                Ok(()) => (),
+                Err(e) => panic!("{}", e),
+            },
+            #[cfg(not(feature = "std"))]
+            None => panic!("No entropy source is available, use set_entropy_source to register one."),
+        }
+    }
+
     // -----------------------------------------------------------
     // Sequences
     // -----------------------------------------------------------
@@ -1,5 +1,6 @@
 pub mod NativeArray_ {
-    use crate::Native_::{alloc, mkRefMut, Lrc, LrcPtr, MutCell, Vec};
+    use crate::Native_::{alloc, compare, mkRefMut, Lrc, LrcPtr, MutCell, Vec};
+    use core::cmp::Ordering;
 
     // -----------------------------------------------------------
     // Arrays
@@ -37,12 +38,40 @@ pub mod NativeArray_ {
         }
     }
 
+    impl<T: Clone> From<&[T]> for Array<T> {
+        fn from(a: &[T]) -> Self {
+            array_from(a.to_vec())
+        }
+    }
+
+    impl<T: Clone, const N: usize> From<[T; N]> for Array<T> {
+        fn from(a: [T; N]) -> Self {
+            array_from(Vec::from(a))
+        }
+    }
+
     impl<T: Clone> Into<Vec<T>> for Array<T> {
         fn into(self) -> Vec<T> {
             self.get().iter().map(|item| item.clone()).collect()
         }
     }
 
+    impl<T: Clone, const N: usize> TryFrom<Array<T>> for [T; N] {
+        type Error = Array<T>;
+        fn try_from(a: Array<T>) -> Result<Self, Self::Error> {
+            let v: Vec<T> = a.get().to_vec();
+            v.try_into().map_err(|_| a)
+        }
+    }
+
+    impl<T: Clone> Array<T> {
+        /// Returns true if there are no other references to this array,
+        /// so it can be modified in place without anyone observing it.
+        pub fn is_unique(&self) -> bool {
+            Lrc::strong_count(&self.0) == 1
+        }
+    }
+
     pub fn array_from<T: Clone>(v: Vec<T>) -> Array<T> {
         Array(mkRefMut(v))
     }
@@ -90,4 +119,298 @@ pub mod NativeArray_ {
     pub fn clear<T: Clone>(a: Array<T>) {
         a.get_mut().clear();
     }
+
+    pub fn capacity<T: Clone>(a: Array<T>) -> i32 {
+        a.capacity() as i32
+    }
+
+    pub fn setCapacity<T: Clone>(a: Array<T>, capacity: i32) {
+        let v = a.get_mut();
+        if capacity < v.len() as i32 {
+            panic!("capacity was less than the current size. (Parameter 'value')");
+        }
+        let capacity = capacity as usize;
+        if capacity > v.capacity() {
+            v.reserve_exact(capacity - v.len());
+        } else {
+            v.shrink_to(capacity);
+        }
+    }
+
+    pub fn ensureCapacity<T: Clone>(a: Array<T>, capacity: i32) -> i32 {
+        if capacity < 0 {
+            panic!("Non-negative number required. (Parameter 'capacity')");
+        }
+        let v = a.get_mut();
+        v.reserve((capacity as usize).saturating_sub(v.len()));
+        v.capacity() as i32
+    }
+
+    pub fn trimExcess<T: Clone>(a: Array<T>) {
+        a.get_mut().shrink_to_fit();
+    }
+
+    // -----------------------------------------------------------
+    // Monomorphized fast paths for the Array module
+    // -----------------------------------------------------------
+
+    // The functions below take the mapping/predicate as a generic `Fn`
+    // instead of a boxed `Func1`, so the compiler can pass lambdas
+    // that are used only once without allocating a closure object.
+    // Elements are cloned one at a time, the same as in Array.fs,
+    // so the callback can safely access the source array.
+
+    fn item<T: Clone>(a: &Array<T>, i: usize) -> T {
+        a[i as i32].clone()
+    }
+
+    // When the source array is uniquely owned (e.g. an intermediate
+    // result in a pipeline), its buffer is reused instead of copied.
+
+    pub fn map<T: Clone, U: Clone, F: Fn(T) -> U>(mapping: F, a: Array<T>) -> Array<U> {
+        if a.is_unique() {
+            // moves the items, and reuses the allocation if the layouts match
+            let v = core::mem::take(a.get_mut());
+            return array_from(v.into_iter().map(mapping).collect());
+        }
+        let len = a.len();
+        let mut res = Vec::with_capacity(len);
+        for i in 0..len {
+            res.push(mapping(item(&a, i)));
+        }
+        array_from(res)
+    }
+
+    pub fn filter<T: Clone, F: Fn(T) -> bool>(predicate: F, a: Array<T>) -> Array<T> {
+        if a.is_unique() {
+            a.get_mut().retain(|x| predicate(x.clone()));
+            return a;
+        }
+        let mut res = Vec::new();
+        for i in 0..a.len() {
+            let x = item(&a, i);
+            if predicate(x.clone()) {
+                res.push(x);
+            }
+        }
+        array_from(res)
+    }
+
+    pub fn choose<T: Clone, U: Clone, F: Fn(T) -> Option<U>>(chooser: F, a: Array<T>) -> Array<U> {
+        if a.is_unique() {
+            let v = core::mem::take(a.get_mut());
+            return array_from(v.into_iter().filter_map(chooser).collect());
+        }
+        let mut res = Vec::new();
+        for i in 0..a.len() {
+            if let Some(x) = chooser(item(&a, i)) {
+                res.push(x);
+            }
+        }
+        array_from(res)
+    }
+
+    pub fn iterate<T: Clone, F: Fn(T)>(action: F, a: Array<T>) {
+        for i in 0..a.len() {
+            action(item(&a, i));
+        }
+    }
+
+    pub fn exists<T: Clone, F: Fn(T) -> bool>(predicate: F, a: Array<T>) -> bool {
+        for i in 0..a.len() {
+            if predicate(item(&a, i)) {
+                return true;
+            }
+        }
+        false
+    }
+
+    pub fn forAll<T: Clone, F: Fn(T) -> bool>(predicate: F, a: Array<T>) -> bool {
+        for i in 0..a.len() {
+            if !predicate(item(&a, i)) {
+                return false;
+            }
+        }
+        true
+    }
+
+    pub fn tryFind<T: Clone, F: Fn(T) -> bool>(predicate: F, a: Array<T>) -> Option<T> {
+        for i in 0..a.len() {
+            let x = item(&a, i);
+            if predicate(x.clone()) {
+                return Some(x);
+            }
+        }
+        None
+    }
+
+    pub fn tryFindIndex<T: Clone, F: Fn(T) -> bool>(predicate: F, a: Array<T>) -> Option<i32> {
+        for i in 0..a.len() {
+            if predicate(item(&a, i)) {
+                return Some(i as i32);
+            }
+        }
+        None
+    }
+
+    pub fn append<T: Clone>(a1: Array<T>, a2: Array<T>) -> Array<T> {
+        if a1.is_unique() {
+            a1.get_mut().extend_from_slice(&a2);
+            return a1;
+        }
+        let mut res = Vec::with_capacity(a1.len() + a2.len());
+        res.extend_from_slice(&a1);
+        res.extend_from_slice(&a2);
+        array_from(res)
+    }
+
+    pub fn sort<T: Clone + PartialOrd>(a: Array<T>) -> Array<T> {
+        let res = if a.is_unique() { a } else { new_copy(a) };
+        res.get_mut()
+            .sort_by(|x, y| match compare(x.clone(), y.clone()) {
+                i if i < 0 => Ordering::Less,
+                i if i > 0 => Ordering::Greater,
+                _ => Ordering::Equal,
+            });
+        res
+    }
+
+    // -----------------------------------------------------------
+    // Vectorized operations on primitive numeric arrays
+    // -----------------------------------------------------------
+
+    // The lane loops below keep independent accumulators, so the backend
+    // can compile them to SIMD instructions. Integer results are the same
+    // as with a sequential loop (wrapping arithmetic is associative), but
+    // floating-point sums are rounded differently, so floats only use the
+    // lane loops with the `simd` feature. With `simd` and `std` on x86_64,
+    // the lane loops are also compiled for AVX2 and used when available.
+
+    const LANES: usize = 8;
+
+    pub trait Numeric: Copy + PartialOrd + Default {
+        const IS_FLOAT: bool;
+        fn add(self, y: Self) -> Self;
+        fn div_int(self, n: i32) -> Self;
+    }
+
+    macro_rules! integer_numeric {
+        ($($t:ty),*) => {$(
+            impl Numeric for $t {
+                const IS_FLOAT: bool = false;
+                #[inline(always)]
+                fn add(self, y: Self) -> Self { self.wrapping_add(y) }
+                fn div_int(self, n: i32) -> Self { self / (n as $t) }
+            }
+        )*};
+    }
+
+    macro_rules! float_numeric {
+        ($($t:ty),*) => {$(
+            impl Numeric for $t {
+                const IS_FLOAT: bool = true;
+                #[inline(always)]
+                fn add(self, y: Self) -> Self { self + y }
+                fn div_int(self, n: i32) -> Self { self / (n as $t) }
+            }
+        )*};
+    }
+
+    integer_numeric!(i8, u8, i16, u16, i32, u32, i64, u64, isize, usize);
+    float_numeric!(f32, f64);
+
+    #[inline(always)]
+    fn fold_lanes<T: Numeric, F: Fn(T, T) -> T>(xs: &[T], init: T, f: &F) -> T {
+        let mut acc = [init; LANES];
+        let chunks = xs.chunks_exact(LANES);
+        let rest = chunks.remainder();
+        for chunk in chunks {
+            for j in 0..LANES {
+                acc[j] = f(acc[j], chunk[j]);
+            }
+        }
+        let res = acc.iter().fold(init, |res, &x| f(res, x));
+        rest.iter().fold(res, |res, &x| f(res, x))
+    }
+
+    #[inline(always)]
+    fn any_lanes<T: Numeric>(xs: &[T], value: T) -> bool {
+        let chunks = xs.chunks_exact(LANES);
+        let rest = chunks.remainder();
+        for chunk in chunks {
+            let mut found = false;
+            for &x in chunk {
+                found |= x == value;
+            }
+            if found {
+                return true;
+            }
+        }
+        rest.contains(&value)
+    }
+
+    #[cfg(all(feature = "simd", not(feature = "no_std"), target_arch = "x86_64"))]
+    #[target_feature(enable = "avx2")]
+    unsafe fn fold_lanes_avx2<T: Numeric, F: Fn(T, T) -> T>(xs: &[T], init: T, f: &F) -> T {
+        fold_lanes(xs, init, f)
+    }
+
+    #[cfg(all(feature = "simd", not(feature = "no_std"), target_arch = "x86_64"))]
+    #[target_feature(enable = "avx2")]
+    unsafe fn any_lanes_avx2<T: Numeric>(xs: &[T], value: T) -> bool {
+        any_lanes(xs, value)
+    }
+
+    fn fold_numeric<T: Numeric, F: Fn(T, T) -> T>(xs: &[T], init: T, f: F) -> T {
+        if T::IS_FLOAT && !cfg!(feature = "simd") {
+            return xs.iter().fold(init, |acc, &x| f(acc, x));
+        }
+        #[cfg(all(feature = "simd", not(feature = "no_std"), target_arch = "x86_64"))]
+        if std::is_x86_feature_detected!("avx2") {
+            return unsafe { fold_lanes_avx2(xs, init, &f) };
+        }
+        fold_lanes(xs, init, &f)
+    }
+
+    fn reduce_numeric<T: Numeric, F: Fn(T, T) -> T>(a: &Array<T>, f: F) -> T {
+        match a.first() {
+            Some(&x) => fold_numeric(a, x, f),
+            None => panic!("The input array was empty."),
+        }
+    }
+
+    pub fn sum<T: Numeric>(a: Array<T>) -> T {
+        fold_numeric(&a, T::default(), T::add)
+    }
+
+    pub fn average<T: Numeric>(a: Array<T>) -> T {
+        if a.is_empty() {
+            panic!("The input array was empty. (Parameter 'array')");
+        }
+        sum(a.clone()).div_int(a.len() as i32)
+    }
+
+    pub fn min<T: Numeric>(a: Array<T>) -> T {
+        reduce_numeric(&a, |x, y| if x < y { x } else { y })
+    }
+
+    pub fn max<T: Numeric>(a: Array<T>) -> T {
+        reduce_numeric(&a, |x, y| if x > y { x } else { y })
+    }
+
+    pub fn containsValue<T: Numeric>(value: T, a: Array<T>) -> bool {
+        #[cfg(all(feature = "simd", not(feature = "no_std"), target_arch = "x86_64"))]
+        if std::is_x86_feature_detected!("avx2") {
+            return unsafe { any_lanes_avx2(&a, value) };
+        }
+        any_lanes(&a, value)
+    }
+
+    pub fn fill<T: Numeric>(a: Array<T>, targetIndex: i32, count: i32, value: T) {
+        if targetIndex < 0 || count < 0 || targetIndex as usize + count as usize > a.len() {
+            panic!("The index was outside the range of elements in the list. (Parameter 'index')");
+        }
+        let start = targetIndex as usize;
+        a.get_mut()[start..start + count as usize].fill(value);
+    }
 }
@@ -19,4 +19,101 @@ pub mod Numeric_ {
     {
         if abs(x.clone()) < abs(y.clone()) { x } else { y }
     }
+
+    // ----------------------------------------------------
+    // Checked arithmetic (the F# Checked module)
+    // ----------------------------------------------------
+
+    fn overflow() -> ! {
+        panic!("Arithmetic operation resulted in an overflow.")
+    }
+
+    pub trait CheckedOps: Sized {
+        fn checked_add(self, y: Self) -> Option<Self>;
+        fn checked_sub(self, y: Self) -> Option<Self>;
+        fn checked_mul(self, y: Self) -> Option<Self>;
+        fn checked_neg(self) -> Option<Self>;
+    }
+
+    macro_rules! checked_ops_impl {
+        ($($t:ty)*) => ($(
+            impl CheckedOps for $t {
+                #[inline]
+                fn checked_add(self, y: Self) -> Option<Self> { <$t>::checked_add(self, y) }
+                #[inline]
+                fn checked_sub(self, y: Self) -> Option<Self> { <$t>::checked_sub(self, y) }
+                #[inline]
+                fn checked_mul(self, y: Self) -> Option<Self> { <$t>::checked_mul(self, y) }
+                #[inline]
+                fn checked_neg(self) -> Option<Self> { <$t>::checked_neg(self) }
+            }
+        )*)
+    }
+
+    checked_ops_impl!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+    pub fn checkedAdd<T: CheckedOps>(x: T, y: T) -> T {
+        x.checked_add(y).unwrap_or_else(|| overflow())
+    }
+
+    pub fn checkedSubtract<T: CheckedOps>(x: T, y: T) -> T {
+        x.checked_sub(y).unwrap_or_else(|| overflow())
+    }
+
+    pub fn checkedMultiply<T: CheckedOps>(x: T, y: T) -> T {
+        x.checked_mul(y).unwrap_or_else(|| overflow())
+    }
+
+    pub fn checkedNegate<T: CheckedOps>(x: T) -> T {
+        x.checked_neg().unwrap_or_else(|| overflow())
+    }
+
+    pub trait CheckedCast<T> {
+        fn checked_cast(self) -> Option<T>;
+    }
+
+    macro_rules! checked_cast_impl {
+        ($($t:ty)*) => ($(
+            checked_cast_impl!(@ints $t; i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+            checked_cast_impl!(@floats $t; f32 f64);
+        )*);
+        (@ints $t:ty; $($s:ty)*) => ($(
+            impl CheckedCast<$t> for $s {
+                #[inline]
+                fn checked_cast(self) -> Option<$t> {
+                    <$t>::try_from(self).ok()
+                }
+            }
+        )*);
+        (@floats $t:ty; $($s:ty)*) => ($(
+            impl CheckedCast<$t> for $s {
+                // truncates towards zero, NaN and infinities overflow
+                fn checked_cast(self) -> Option<$t> {
+                    let x = self as f64;
+                    if x > -1.0 && x < u128::MAX as f64 {
+                        <$t>::try_from(x as u128).ok()
+                    } else if x >= i128::MIN as f64 && x < 0.0 {
+                        <$t>::try_from(x as i128).ok()
+                    } else {
+                        None
+                    }
+                }
+            }
+        )*);
+    }
+
+    checked_cast_impl!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+    pub fn checkedToInt8<S: CheckedCast<i8>>(x: S) -> i8 { x.checked_cast().unwrap_or_else(|| overflow()) }
+    pub fn checkedToUInt8<S: CheckedCast<u8>>(x: S) -> u8 { x.checked_cast().unwrap_or_else(|| overflow()) }
+    pub fn checkedToInt16<S: CheckedCast<i16>>(x: S) -> i16 { x.checked_cast().unwrap_or_else(|| overflow()) }
+    pub fn checkedToUInt16<S: CheckedCast<u16>>(x: S) -> u16 { x.checked_cast().unwrap_or_else(|| overflow()) }
+    pub fn checkedToInt32<S: CheckedCast<i32>>(x: S) -> i32 { x.checked_cast().unwrap_or_else(|| overflow()) }
+    pub fn checkedToUInt32<S: CheckedCast<u32>>(x: S) -> u32 { x.checked_cast().unwrap_or_else(|| overflow()) }
+    pub fn checkedToInt64<S: CheckedCast<i64>>(x: S) -> i64 { x.checked_cast().unwrap_or_else(|| overflow()) }
+    pub fn checkedToUInt64<S: CheckedCast<u64>>(x: S) -> u64 { x.checked_cast().unwrap_or_else(|| overflow()) }
+    pub fn checkedToInt128<S: CheckedCast<i128>>(x: S) -> i128 { x.checked_cast().unwrap_or_else(|| overflow()) }
+    pub fn checkedToUInt128<S: CheckedCast<u128>>(x: S) -> u128 { x.checked_cast().unwrap_or_else(|| overflow()) }
+    pub fn checkedToNativeInt<S: CheckedCast<isize>>(x: S) -> isize { x.checked_cast().unwrap_or_else(|| overflow()) }
+    pub fn checkedToUNativeInt<S: CheckedCast<usize>>(x: S) -> usize { x.checked_cast().unwrap_or_else(|| overflow()) }
 }
@@ -0,0 +1,93 @@
+pub mod ObjectPool_ {
+
+    // -----------------------------------------------------------
+    // Object pools
+    // -----------------------------------------------------------
+
+    // Pooled objects are kept in a bounded stack. `get` takes the most
+    // recently returned object, or asks the policy to create a new one,
+    // and `return_` keeps the object only if the policy accepts it and
+    // the pool is not full. With the "threaded" feature the stack is
+    // guarded by a mutex, so a pool can be shared between threads.
+
+    use crate::Interfaces_::Microsoft::Extensions::ObjectPool::IPooledObjectPolicy_1;
+    use crate::Native_::{LrcPtr, Vec};
+
+    #[cfg(not(feature = "threaded"))]
+    use crate::Native_::MutCell;
+    #[cfg(feature = "threaded")]
+    use std::sync::Mutex;
+
+    #[cfg(feature = "threaded")]
+    type Items<T> = Mutex<Vec<T>>;
+    #[cfg(not(feature = "threaded"))]
+    type Items<T> = MutCell<Vec<T>>;
+
+    pub struct ObjectPool<T: Clone + 'static> {
+        policy: LrcPtr<dyn IPooledObjectPolicy_1<T>>,
+        maximumRetained: usize,
+        items: Items<T>,
+    }
+
+    // same default as .NET (twice the processor count)
+    fn default_maximum_retained() -> i32 {
+        #[cfg(not(feature = "no_std"))]
+        let count = std::thread::available_parallelism().map_or(1, |n| n.get());
+        #[cfg(feature = "no_std")]
+        let count = 4;
+        (count * 2) as i32
+    }
+
+    impl<T: Clone + 'static> ObjectPool<T> {
+        pub fn new___(policy: LrcPtr<dyn IPooledObjectPolicy_1<T>>) -> LrcPtr<ObjectPool<T>> {
+            Self::new___n(policy, default_maximum_retained())
+        }
+
+        pub fn new___n(
+            policy: LrcPtr<dyn IPooledObjectPolicy_1<T>>,
+            maximumRetained: i32,
+        ) -> LrcPtr<ObjectPool<T>> {
+            if maximumRetained < 0 {
+                panic!("Non-negative number required. (Parameter 'maximumRetained')");
+            }
+            LrcPtr::new(ObjectPool {
+                policy,
+                maximumRetained: maximumRetained as usize,
+                items: Items::new(Vec::new()),
+            })
+        }
+
+        #[cfg(feature = "threaded")]
+        fn with_items<R>(&self, f: impl FnOnce(&mut Vec<T>) -> R) -> R {
+            let mut items = self.items.lock().unwrap_or_else(|e| e.into_inner());
+            f(&mut items)
+        }
+
+        #[cfg(not(feature = "threaded"))]
+        fn with_items<R>(&self, f: impl FnOnce(&mut Vec<T>) -> R) -> R {
+            f(self.items.get_mut())
+        }
+
+        pub fn get(&self) -> T {
+            // the policy is called outside of the lock
+            match self.with_items(|items| items.pop()) {
+                Some(obj) => obj,
+                None => self.policy.Create(),
+            }
+        }
+
+        pub fn return_(&self, obj: T) {
+            if self.policy.Return(obj.clone()) {
+                self.with_items(|items| {
+                    if items.len() < self.maximumRetained {
+                        items.push(obj);
+                    }
+                });
+            }
+        }
+
+        pub fn count(&self) -> i32 {
+            self.with_items(|items| items.len() as i32)
+        }
+    }
+}
@@ -134,18 +134,15 @@ let orElseWith<'T>
 // moved to List.ofOption to avoid dependency
 // let toList<'T> (opt: 'T option): 'T list = List.ofOption
 
-// let ofNullable<'T>(x: 'T): 'T option =
-//     if x != null then Some(x) else None
+// nullables are already represented as options
+// let ofNullable<'T>(x: 'T): 'T option = x
+// let toNullable<'T> (opt: 'T option): 'T = opt
 
-// let ofObj<'T>(x: 'T): 'T option =
-//     if x != null then Some(x) else None
+// values can't be null, so they are always Some
+let ofObj<'T> (x: 'T) : 'T option = Some x
 
-// let toNullable<'T> (opt: 'T option): 'T =
-//     match opt with
-//     | Some x -> x
-//     | None -> null //defaultOf('T)
-
-// let toObj<'T> (opt: 'T option): 'T =
+// toObj is inlined as defaultValue, so 'T is known to have a default value
+// let toObj<'T> (opt: 'T option) : 'T =
 //     match opt with
 //     | Some x -> x
 //     | None -> null //defaultOf('T)
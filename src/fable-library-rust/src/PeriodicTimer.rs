@@ -0,0 +1,133 @@
+#[cfg(feature = "threaded")]
+pub mod PeriodicTimer_ {
+
+    // -----------------------------------------------------------
+    // System.Threading.PeriodicTimer
+    // -----------------------------------------------------------
+
+    // A background thread ticks on schedule until the timer is disposed.
+    // Like in .NET, ticks are not queued: a tick that occurs while nobody
+    // is waiting completes the next `waitForNextTickAsync` right away, and
+    // further ticks are coalesced with it.
+
+    use crate::Interfaces_::System::IDisposable;
+    use crate::Native_::LrcPtr;
+    use crate::Task_::Task;
+    use crate::TimeSpan_::{ticks_per_millisecond, TimeSpan};
+
+    use core::fmt::{Debug, Formatter, Result};
+    use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+    use std::time::{Duration, Instant};
+
+    struct State {
+        tickPending: bool,
+        waiter: Option<Arc<Task<bool>>>,
+        disposed: bool,
+    }
+
+    type Shared = Arc<(Mutex<State>, Condvar)>;
+
+    fn lock(shared: &Shared) -> MutexGuard<'_, State> {
+        shared.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub struct PeriodicTimer {
+        shared: Shared,
+    }
+
+    impl Debug for PeriodicTimer {
+        fn fmt(&self, f: &mut Formatter) -> Result {
+            f.debug_struct("PeriodicTimer").finish_non_exhaustive()
+        }
+    }
+
+    fn validate_period(period: TimeSpan) -> Duration {
+        let ms = period.ticks() / ticks_per_millisecond;
+        if ms < 1 || ms > u32::MAX as i64 - 1 {
+            panic!("Specified argument was out of the range of valid values. (Parameter 'period')");
+        }
+        Duration::from_nanos(period.ticks() as u64 * 100)
+    }
+
+    fn start_ticking(shared: Shared, period: Duration) {
+        std::thread::spawn(move || {
+            let mut next = Instant::now() + period;
+            let mut s = lock(&shared);
+            loop {
+                if s.disposed {
+                    return;
+                }
+                let now = Instant::now();
+                if now < next {
+                    s = shared.1.wait_timeout(s, next - now).unwrap_or_else(|e| e.into_inner()).0;
+                    continue;
+                }
+                // missed ticks are skipped, the schedule is kept
+                while next <= now {
+                    next += period;
+                }
+                match s.waiter.take() {
+                    Some(task) => task.set_result(true),
+                    None => s.tickPending = true,
+                }
+            }
+        });
+    }
+
+    impl PeriodicTimer {
+        pub fn new(period: TimeSpan) -> LrcPtr<PeriodicTimer> {
+            let period = validate_period(period);
+            let state = State {
+                tickPending: false,
+                waiter: None,
+                disposed: false,
+            };
+            let shared = Arc::new((Mutex::new(state), Condvar::new()));
+            start_ticking(shared.clone(), period);
+            LrcPtr::new(PeriodicTimer { shared })
+        }
+
+        // completes with true on the next tick, or false once disposed
+        pub fn waitForNextTickAsync(&self) -> Arc<Task<bool>> {
+            let mut s = lock(&self.shared);
+            if s.waiter.is_some() {
+                panic!("Operation is not valid due to the current state of the object.");
+            }
+            if s.tickPending {
+                s.tickPending = false;
+                return Arc::new(Task::from_result(true));
+            }
+            if s.disposed {
+                return Arc::new(Task::from_result(false));
+            }
+            let task = Arc::new(Task::pending());
+            s.waiter = Some(task.clone());
+            task
+        }
+
+        pub fn dispose(&self) {
+            let mut s = lock(&self.shared);
+            if !s.disposed {
+                s.disposed = true;
+                s.tickPending = false;
+                if let Some(task) = s.waiter.take() {
+                    task.set_result(false);
+                }
+                self.shared.1.notify_all();
+            }
+        }
+    }
+
+    impl IDisposable for PeriodicTimer {
+        fn Dispose(&self) {
+            self.dispose()
+        }
+    }
+
+    // stops the background thread when the timer is not disposed
+    impl Drop for PeriodicTimer {
+        fn drop(&mut self) {
+            self.dispose()
+        }
+    }
+}
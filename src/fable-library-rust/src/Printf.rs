@@ -0,0 +1,492 @@
+pub mod Printf_ {
+    use crate::Globalization_::{formatNumber2, CultureInfo, FormatNumber};
+    use crate::List_::List;
+    use crate::Map_::Map;
+    use crate::NativeArray_::Array;
+    use crate::Native_::{alloc::format, Arc, Box_, MutCell, Rc, String, ToString, Vec};
+    use crate::Set_::Set;
+    use crate::String_::{fromString, string};
+
+    // -----------------------------------------------------------
+    // Layouts, ported from FSharp.Core's structured formatting (sformat.fs)
+    // -----------------------------------------------------------
+
+    // Precedence of a nested value, decides when it is put in brackets
+    pub const BracketIfTupleOrNotAtomic: i32 = 2;
+    pub const BracketIfTuple: i32 = 3;
+    pub const NoBracket: i32 = 4;
+
+    // The %A output is squashed to fit this width
+    const PrintWidth: isize = 80;
+    // Lists, arrays and sequences only show this many items
+    const PrintLength: usize = 100;
+
+    #[derive(Clone, Copy)]
+    pub enum Joint {
+        Unbreakable,
+        Breakable(isize),
+        Broken(isize),
+    }
+
+    // A leaf juxtaposed on one side is not separated by a space from its neighbour
+    #[derive(Clone)]
+    pub enum Layout {
+        Leaf(bool, String, bool),
+        Node(Box_<Layout>, Box_<Layout>, Joint),
+    }
+
+    fn juxt_left(layout: &Layout) -> bool {
+        match layout {
+            Layout::Leaf(jl, _, _) => *jl,
+            Layout::Node(l, _, _) => juxt_left(l),
+        }
+    }
+
+    fn juxt_right(layout: &Layout) -> bool {
+        match layout {
+            Layout::Leaf(_, _, jr) => *jr,
+            Layout::Node(_, r, _) => juxt_right(r),
+        }
+    }
+
+    fn node(l: Layout, r: Layout, joint: Joint) -> Layout {
+        Layout::Node(Box_::new(l), Box_::new(r), joint)
+    }
+
+    pub fn wordL(text: &str) -> Layout {
+        Layout::Leaf(false, text.to_string(), false)
+    }
+
+    fn left_l(text: &str) -> Layout {
+        Layout::Leaf(false, text.to_string(), true)
+    }
+
+    fn right_l(text: &str) -> Layout {
+        Layout::Leaf(true, text.to_string(), false)
+    }
+
+    fn empty_l() -> Layout {
+        Layout::Leaf(true, String::new(), true)
+    }
+
+    // x ^^ y
+    fn unbreakable(l: Layout, r: Layout) -> Layout {
+        node(l, r, Joint::Unbreakable)
+    }
+
+    fn sep_list_l(sep: &str, layouts: Vec<Layout>) -> Layout {
+        let mut layouts = layouts.into_iter();
+        match layouts.next() {
+            None => empty_l(),
+            Some(first) => layouts.fold(first, |prefix, layout| {
+                node(unbreakable(prefix, right_l(sep)), layout, Joint::Breakable(0))
+            }),
+        }
+    }
+
+    fn above_list_l(layouts: Vec<Layout>) -> Layout {
+        let mut layouts = layouts.into_iter();
+        match layouts.next() {
+            None => empty_l(),
+            Some(first) => layouts.fold(first, |above, layout| node(above, layout, Joint::Broken(0))),
+        }
+    }
+
+    fn bracket_l(layout: Layout) -> Layout {
+        unbreakable(unbreakable(left_l("("), layout), right_l(")"))
+    }
+
+    fn bracket_if_l(cond: bool, layout: Layout) -> Layout {
+        if cond { bracket_l(layout) } else { layout }
+    }
+
+    fn list_l(left: &str, layouts: Vec<Layout>, right: &str) -> Layout {
+        unbreakable(unbreakable(left_l(left), sep_list_l(";", layouts)), right_l(right))
+    }
+
+    fn bounded_items<T, I: Iterator<Item = T>>(items: I, prec: i32, item_l: impl Fn(&T, i32) -> Layout) -> Vec<Layout> {
+        let mut res = Vec::new();
+        for (i, item) in items.enumerate() {
+            if i == PrintLength {
+                res.push(wordL("..."));
+                break;
+            }
+            res.push(item_l(&item, prec));
+        }
+        res
+    }
+
+    fn seq_l(word: &str, items: Vec<Layout>, prec: i32) -> Layout {
+        let layout = node(wordL(word), list_l("[", items, "]"), Joint::Breakable(2));
+        bracket_if_l(prec <= BracketIfTupleOrNotAtomic, layout)
+    }
+
+    pub fn tupleLayout(prec: i32, items: &[&dyn StructuredFormat]) -> Layout {
+        let items = items.iter().map(|x| x.layout(BracketIfTuple)).collect();
+        bracket_if_l(prec <= BracketIfTuple, sep_list_l(",", items))
+    }
+
+    pub fn recordLayout(fields: &[(&str, &dyn StructuredFormat)]) -> Layout {
+        let items = fields.iter().map(|(name, x)| {
+            let name_l = unbreakable(wordL(name), wordL("="));
+            node(name_l, x.layout(BracketIfTuple), Joint::Breakable(1))
+        });
+        unbreakable(unbreakable(wordL("{"), above_list_l(items.collect())), wordL("}"))
+    }
+
+    pub fn unionLayout(prec: i32, name: &str, fields: &[&dyn StructuredFormat]) -> Layout {
+        let fields_l = match fields {
+            [] => return wordL(name),
+            [x] => x.layout(BracketIfTupleOrNotAtomic),
+            _ => bracket_l(sep_list_l(",", fields.iter().map(|x| x.layout(BracketIfTuple)).collect())),
+        };
+        let layout = node(wordL(name), fields_l, Joint::Breakable(2));
+        bracket_if_l(prec <= BracketIfTupleOrNotAtomic, layout)
+    }
+
+    // -----------------------------------------------------------
+    // Squashing and rendering
+    // -----------------------------------------------------------
+
+    // Savings of the pending breaks, negative once a break is forced
+    struct Breaks {
+        next: usize,
+        outer: usize,
+        stack: Vec<isize>,
+    }
+
+    impl Breaks {
+        fn push(&mut self, saving: isize) {
+            self.stack.truncate(self.next);
+            self.stack.push(saving);
+            self.next += 1;
+        }
+
+        fn pop(&mut self) -> bool {
+            let top_broke = self.stack[self.next - 1] < 0;
+            if self.outer == self.next {
+                self.outer -= 1;
+            }
+            self.next -= 1;
+            top_broke
+        }
+
+        fn force(&mut self) -> Option<isize> {
+            if self.outer == self.next {
+                None // all broken
+            } else {
+                let saving = self.stack[self.outer];
+                self.stack[self.outer] = -saving;
+                self.outer += 1;
+                Some(saving)
+            }
+        }
+    }
+
+    // Breaks the breakable joints needed to fit the layout in the width,
+    // returns the position in the line and the width of the last line
+    fn fit(breaks: &mut Breaks, pos: isize, layout: &mut Layout) -> (isize, isize) {
+        match layout {
+            Layout::Leaf(_, text, _) => {
+                let width = text.chars().count() as isize;
+                let mut pos = pos;
+                while pos + width > PrintWidth {
+                    match breaks.force() {
+                        Some(saving) => pos -= saving,
+                        None => break,
+                    }
+                }
+                (pos + width, width)
+            }
+            Layout::Node(l, r, joint) => {
+                let mid = if juxt_right(l) || juxt_left(r) { 0 } else { 1 };
+                match *joint {
+                    Joint::Unbreakable => {
+                        let (pos, offset_l) = fit(breaks, pos, l);
+                        let (pos, offset_r) = fit(breaks, pos + mid, r);
+                        (pos, offset_l + mid + offset_r)
+                    }
+                    Joint::Broken(indent) => {
+                        let (pos, offset_l) = fit(breaks, pos, l);
+                        let (pos, offset_r) = fit(breaks, pos - offset_l + indent, r);
+                        (pos, indent + offset_r)
+                    }
+                    Joint::Breakable(indent) => {
+                        let (pos, offset_l) = fit(breaks, pos, l);
+                        let saving = offset_l + mid - indent;
+                        if saving > 0 {
+                            breaks.push(saving);
+                            let (pos, offset_r) = fit(breaks, pos + mid, r);
+                            if breaks.pop() {
+                                *joint = Joint::Broken(indent);
+                                (pos, indent + offset_r)
+                            } else {
+                                (pos, offset_l + mid + offset_r)
+                            }
+                        } else {
+                            let (pos, offset_r) = fit(breaks, pos + mid, r);
+                            (pos, offset_l + mid + offset_r)
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn render(res: &mut String, pos: isize, i: isize, layout: &Layout) -> isize {
+        match layout {
+            Layout::Leaf(_, text, _) => {
+                res.push_str(text);
+                i + text.chars().count() as isize
+            }
+            Layout::Node(l, r, Joint::Broken(indent)) => {
+                render(res, pos, i, l);
+                let pos = pos + indent;
+                res.push('\n');
+                res.extend(core::iter::repeat_n(' ', pos.max(0) as usize));
+                render(res, pos, pos, r)
+            }
+            Layout::Node(l, r, _) => {
+                let mut i = render(res, pos, i, l);
+                if !(juxt_right(l) || juxt_left(r)) {
+                    res.push(' ');
+                    i += 1;
+                }
+                render(res, i, i, r)
+            }
+        }
+    }
+
+    pub fn layoutToString(layout: Layout) -> string {
+        let mut layout = layout;
+        let mut breaks = Breaks { next: 0, outer: 0, stack: Vec::new() };
+        fit(&mut breaks, 0, &mut layout);
+        let mut res = String::new();
+        render(&mut res, 0, 0, &layout);
+        fromString(res)
+    }
+
+    // Formats a value like the %A format specifier
+    pub fn anyToString<T: StructuredFormat>(x: T) -> string {
+        layoutToString(x.layout(BracketIfTuple))
+    }
+
+    // -----------------------------------------------------------
+    // Structured formatting of values
+    // -----------------------------------------------------------
+
+    pub trait StructuredFormat {
+        fn layout(&self, prec: i32) -> Layout;
+    }
+
+    // Values of other types are shown with their Debug formatting
+    pub struct DebugFormat<'a, T>(pub &'a T);
+
+    impl<T: core::fmt::Debug> StructuredFormat for DebugFormat<'_, T> {
+        fn layout(&self, _prec: i32) -> Layout {
+            wordL(&format!("{:?}", self.0))
+        }
+    }
+
+    // Values that cannot be printed are shown with their type name
+    pub struct TypeNameFormat<'a, T: ?Sized>(pub &'a T);
+
+    impl<T: ?Sized> StructuredFormat for TypeNameFormat<'_, T> {
+        fn layout(&self, _prec: i32) -> Layout {
+            wordL(core::any::type_name::<T>())
+        }
+    }
+
+    macro_rules! integer_structured_format {
+        ($($t:ty => $suffix:literal),*) => {$(
+            impl StructuredFormat for $t {
+                fn layout(&self, _prec: i32) -> Layout {
+                    wordL(&format!("{}{}", self, $suffix))
+                }
+            }
+        )*};
+    }
+
+    integer_structured_format!(
+        i8 => "y", u8 => "uy", i16 => "s", u16 => "us", i32 => "", u32 => "u",
+        i64 => "L", u64 => "UL", i128 => "", u128 => "", isize => "n", usize => "un"
+    );
+
+    fn float_layout<T: FormatNumber>(x: T, is_nan: bool, is_infinite: bool, negative: bool, suffix: &str) -> Layout {
+        let s = if is_nan {
+            "nan".to_string()
+        } else if is_infinite {
+            (if negative { "-infinity" } else { "infinity" }).to_string()
+        } else {
+            let s = formatNumber2(x, string("g10"), CultureInfo::invariantCulture_());
+            if s.chars().all(|c| c.is_ascii_digit() || c == '-') {
+                format!("{}.0", s)
+            } else {
+                s.to_string()
+            }
+        };
+        wordL(&format!("{}{}", s, suffix))
+    }
+
+    impl StructuredFormat for f32 {
+        fn layout(&self, _prec: i32) -> Layout {
+            float_layout(*self, self.is_nan(), self.is_infinite(), *self < 0.0, "f")
+        }
+    }
+
+    impl StructuredFormat for f64 {
+        fn layout(&self, _prec: i32) -> Layout {
+            float_layout(*self, self.is_nan(), self.is_infinite(), *self < 0.0, "")
+        }
+    }
+
+    #[cfg(feature = "decimal")]
+    impl StructuredFormat for crate::Decimal_::decimal {
+        fn layout(&self, _prec: i32) -> Layout {
+            wordL(&format!("{}M", crate::Decimal_::toString(*self)))
+        }
+    }
+
+    #[cfg(feature = "bigint")]
+    impl StructuredFormat for crate::BigInt_::bigint {
+        fn layout(&self, _prec: i32) -> Layout {
+            wordL(&format!("{}I", self))
+        }
+    }
+
+    impl StructuredFormat for bool {
+        fn layout(&self, _prec: i32) -> Layout {
+            wordL(if *self { "true" } else { "false" })
+        }
+    }
+
+    impl StructuredFormat for () {
+        fn layout(&self, _prec: i32) -> Layout {
+            wordL("()")
+        }
+    }
+
+    impl StructuredFormat for char {
+        fn layout(&self, _prec: i32) -> Layout {
+            let c = *self;
+            let s = match c {
+                '\'' => "\\'".to_string(),
+                '\\' => "\\\\".to_string(),
+                '\u{8}' => "\\b".to_string(),
+                _ if c.is_control() => format!("\\{:03}", c as u32),
+                _ => c.to_string(),
+            };
+            wordL(&format!("'{}'", s))
+        }
+    }
+
+    impl StructuredFormat for string {
+        fn layout(&self, _prec: i32) -> Layout {
+            wordL(&format!("\"{}\"", self))
+        }
+    }
+
+    impl<T: StructuredFormat> StructuredFormat for Option<T> {
+        fn layout(&self, prec: i32) -> Layout {
+            match self {
+                Some(x) => unionLayout(prec, "Some", &[x]),
+                None => wordL("None"),
+            }
+        }
+    }
+
+    impl<T: StructuredFormat, E: StructuredFormat> StructuredFormat for Result<T, E> {
+        fn layout(&self, prec: i32) -> Layout {
+            match self {
+                Ok(x) => unionLayout(prec, "Ok", &[x]),
+                Err(e) => unionLayout(prec, "Error", &[e]),
+            }
+        }
+    }
+
+    macro_rules! tuple_structured_format {
+        ($($t:ident $i:tt),+) => {
+            impl<$($t: StructuredFormat),+> StructuredFormat for ($($t,)+) {
+                fn layout(&self, prec: i32) -> Layout {
+                    tupleLayout(prec, &[$(&self.$i),+])
+                }
+            }
+        };
+    }
+
+    tuple_structured_format!(T0 0);
+    tuple_structured_format!(T0 0, T1 1);
+    tuple_structured_format!(T0 0, T1 1, T2 2);
+    tuple_structured_format!(T0 0, T1 1, T2 2, T3 3);
+    tuple_structured_format!(T0 0, T1 1, T2 2, T3 3, T4 4);
+    tuple_structured_format!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5);
+    tuple_structured_format!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6);
+    tuple_structured_format!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7);
+    tuple_structured_format!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8);
+    tuple_structured_format!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9);
+    tuple_structured_format!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9, T10 10);
+    tuple_structured_format!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9, T10 10, T11 11);
+
+    impl<T: Clone + StructuredFormat> StructuredFormat for Array<T> {
+        fn layout(&self, _prec: i32) -> Layout {
+            let items = bounded_items(self.iter(), BracketIfTuple, |x, prec| x.layout(prec));
+            list_l("[|", items, "|]")
+        }
+    }
+
+    impl<T: Clone + StructuredFormat + 'static> StructuredFormat for List<T> {
+        fn layout(&self, _prec: i32) -> Layout {
+            let items = bounded_items(self.into_iter(), BracketIfTuple, |x, prec| x.layout(prec));
+            list_l("[", items, "]")
+        }
+    }
+
+    impl<T: Clone + PartialOrd + StructuredFormat + 'static> StructuredFormat for Set<T> {
+        fn layout(&self, prec: i32) -> Layout {
+            let items = bounded_items(self.into_iter(), BracketIfTuple, |x, prec| x.layout(prec));
+            seq_l("set", items, prec)
+        }
+    }
+
+    impl<K, V> StructuredFormat for Map<K, V>
+    where
+        K: Clone + PartialOrd + StructuredFormat + 'static,
+        V: Clone + StructuredFormat + 'static,
+    {
+        fn layout(&self, prec: i32) -> Layout {
+            let items = bounded_items(self.into_iter(), BracketIfTuple, |x, prec| x.layout(prec));
+            seq_l("map", items, prec)
+        }
+    }
+
+    impl<T: Clone + StructuredFormat> StructuredFormat for MutCell<T> {
+        fn layout(&self, prec: i32) -> Layout {
+            self.as_ref().layout(prec)
+        }
+    }
+
+    impl<T: StructuredFormat> StructuredFormat for Rc<T> {
+        fn layout(&self, prec: i32) -> Layout {
+            self.as_ref().layout(prec)
+        }
+    }
+
+    impl<T: StructuredFormat> StructuredFormat for Arc<T> {
+        fn layout(&self, prec: i32) -> Layout {
+            self.as_ref().layout(prec)
+        }
+    }
+
+    impl<T: StructuredFormat> StructuredFormat for Box_<T> {
+        fn layout(&self, prec: i32) -> Layout {
+            self.as_ref().layout(prec)
+        }
+    }
+
+    #[cfg(feature = "lrc_ptr")]
+    impl<T: StructuredFormat> StructuredFormat for crate::Native_::LrcPtr<T> {
+        fn layout(&self, prec: i32) -> Layout {
+            self.as_ref().layout(prec)
+        }
+    }
+}
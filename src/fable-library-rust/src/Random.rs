@@ -0,0 +1,92 @@
+#[cfg(feature = "random")]
+pub mod Random_ {
+    use crate::NativeArray_::Array;
+    use crate::Native_::{fill_entropy, LrcPtr, MutCell};
+
+    // SplitMix64 generator, seeded from the registered entropy source
+    #[derive(Debug)]
+    pub struct Random {
+        state: MutCell<u64>,
+    }
+
+    fn entropy() -> u64 {
+        let mut bytes = [0u8; 8];
+        fill_entropy(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    impl Random {
+        fn from_seed(seed: u64) -> LrcPtr<Random> {
+            LrcPtr::new(Random {
+                state: MutCell::new(seed),
+            })
+        }
+
+        pub fn new_() -> LrcPtr<Random> {
+            Self::from_seed(entropy())
+        }
+
+        pub fn new__n(seed: i32) -> LrcPtr<Random> {
+            Self::from_seed(seed as u64)
+        }
+
+        pub fn shared_() -> LrcPtr<Random> {
+            static shared: MutCell<Option<LrcPtr<Random>>> = MutCell::new(None);
+            shared.get_or_init(Self::new_)
+        }
+
+        fn next_u64(&self) -> u64 {
+            let state = self.state.get().wrapping_add(0x9E3779B97F4A7C15);
+            self.state.set(state);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+            z ^ (z >> 31)
+        }
+
+        fn next_range(&self, minValue: i64, maxValue: i64) -> i64 {
+            let range = (maxValue - minValue) as u64;
+            if range == 0 {
+                minValue
+            } else {
+                minValue + (self.next_u64() % range) as i64
+            }
+        }
+
+        pub fn next(&self) -> i32 {
+            self.next_range(0, i32::MAX as i64) as i32
+        }
+
+        pub fn next_n(&self, maxValue: i32) -> i32 {
+            if maxValue < 0 {
+                panic!("'maxValue' must be greater than zero. (Parameter 'maxValue')");
+            }
+            self.next_range(0, maxValue as i64) as i32
+        }
+
+        pub fn next_nn(&self, minValue: i32, maxValue: i32) -> i32 {
+            if minValue > maxValue {
+                panic!("'minValue' cannot be greater than maxValue. (Parameter 'minValue')");
+            }
+            self.next_range(minValue as i64, maxValue as i64) as i32
+        }
+
+        pub fn nextInt64(&self) -> i64 {
+            self.next_range(0, i64::MAX)
+        }
+
+        pub fn nextDouble(&self) -> f64 {
+            (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+        }
+
+        pub fn nextSingle(&self) -> f32 {
+            (self.next_u64() >> 40) as f32 / (1u32 << 24) as f32
+        }
+
+        pub fn nextBytes_a(&self, buffer: Array<u8>) {
+            for b in buffer.get_mut().iter_mut() {
+                *b = self.next_u64() as u8;
+            }
+        }
+    }
+}
@@ -0,0 +1,522 @@
+#[cfg(not(feature = "no_std"))]
+pub mod RateLimiting_ {
+
+    // -----------------------------------------------------------
+    // Rate limiters
+    // -----------------------------------------------------------
+
+    // All four limiter kinds share the same state: a number of available
+    // permits, and (with the "threaded" feature) a queue of pending
+    // `acquireAsync` requests. Replenishing limiters refill their permits
+    // lazily, from the time elapsed since the last replenishment, whenever
+    // they are accessed. While requests are queued, a background thread
+    // replenishes them on schedule so that the queue keeps draining.
+
+    use crate::Interfaces_::System::IDisposable;
+    use crate::Native_::{LrcPtr, MutCell, Vec};
+    use crate::TimeSpan_::TimeSpan;
+
+    use core::fmt::{Debug, Formatter, Result};
+    use core::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex, MutexGuard};
+    use std::time::{Duration, Instant};
+
+    #[cfg(feature = "threaded")]
+    use crate::Task_::Task;
+    #[cfg(feature = "threaded")]
+    use std::collections::VecDeque;
+
+    // QueueProcessingOrder values
+    const OLDEST_FIRST: i32 = 0;
+    const NEWEST_FIRST: i32 = 1;
+
+    // -----------------------------------------------------------
+    // Options
+    // -----------------------------------------------------------
+
+    // Superset of the TokenBucket, FixedWindow, SlidingWindow
+    // and Concurrency limiter options
+    #[derive(Clone, Debug)]
+    pub struct RateLimiterOptions {
+        tokenLimit: MutCell<i32>,
+        tokensPerPeriod: MutCell<i32>,
+        replenishmentPeriod: MutCell<TimeSpan>,
+        permitLimit: MutCell<i32>,
+        window: MutCell<TimeSpan>,
+        segmentsPerWindow: MutCell<i32>,
+        queueLimit: MutCell<i32>,
+        queueProcessingOrder: MutCell<i32>,
+        autoReplenishment: MutCell<bool>,
+    }
+
+    macro_rules! option_property {
+        ($name:ident, $setter:ident, $ty:ty) => {
+            pub fn $name(&self) -> $ty {
+                self.$name.get()
+            }
+
+            pub fn $setter(&self, value: $ty) {
+                self.$name.set(value)
+            }
+        };
+    }
+
+    impl RateLimiterOptions {
+        pub fn new_() -> LrcPtr<RateLimiterOptions> {
+            LrcPtr::new(RateLimiterOptions {
+                tokenLimit: MutCell::new(0),
+                tokensPerPeriod: MutCell::new(0),
+                replenishmentPeriod: MutCell::new(TimeSpan::new_ticks(0)),
+                permitLimit: MutCell::new(0),
+                window: MutCell::new(TimeSpan::new_ticks(0)),
+                segmentsPerWindow: MutCell::new(0),
+                queueLimit: MutCell::new(0),
+                queueProcessingOrder: MutCell::new(OLDEST_FIRST),
+                autoReplenishment: MutCell::new(true),
+            })
+        }
+
+        option_property!(tokenLimit, set_tokenLimit, i32);
+        option_property!(tokensPerPeriod, set_tokensPerPeriod, i32);
+        option_property!(replenishmentPeriod, set_replenishmentPeriod, TimeSpan);
+        option_property!(permitLimit, set_permitLimit, i32);
+        option_property!(window, set_window, TimeSpan);
+        option_property!(segmentsPerWindow, set_segmentsPerWindow, i32);
+        option_property!(queueLimit, set_queueLimit, i32);
+        option_property!(queueProcessingOrder, set_queueProcessingOrder, i32);
+        option_property!(autoReplenishment, set_autoReplenishment, bool);
+    }
+
+    fn check_options(valid: bool, name: &str, constraint: &str) {
+        if !valid {
+            panic!("{name} must be set to a value {constraint}. (Parameter 'options')");
+        }
+    }
+
+    fn to_duration(ts: TimeSpan) -> Duration {
+        Duration::from_nanos(ts.ticks() as u64 * 100)
+    }
+
+    // -----------------------------------------------------------
+    // Leases and statistics
+    // -----------------------------------------------------------
+
+    pub struct RateLimitLease {
+        isAcquired: bool,
+        // permits returned to a concurrency limiter on dispose
+        release: Option<(Arc<Mutex<State>>, i32)>,
+        disposed: AtomicBool,
+    }
+
+    impl RateLimitLease {
+        fn new(isAcquired: bool, release: Option<(Arc<Mutex<State>>, i32)>) -> LrcPtr<RateLimitLease> {
+            LrcPtr::new(RateLimitLease {
+                isAcquired,
+                release,
+                disposed: AtomicBool::new(false),
+            })
+        }
+
+        pub fn isAcquired(&self) -> bool {
+            self.isAcquired
+        }
+
+        pub fn dispose(&self) {
+            if !self.disposed.swap(true, Ordering::AcqRel) {
+                if let Some((state, count)) = &self.release {
+                    let mut s = lock(state);
+                    s.available += count;
+                    s.grant_queued(state);
+                }
+            }
+        }
+    }
+
+    impl Debug for RateLimitLease {
+        fn fmt(&self, f: &mut Formatter) -> Result {
+            f.debug_struct("RateLimitLease").field("isAcquired", &self.isAcquired).finish()
+        }
+    }
+
+    impl IDisposable for RateLimitLease {
+        fn Dispose(&self) {
+            self.dispose()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct RateLimiterStatistics {
+        currentAvailablePermits: i64,
+        currentQueuedCount: i64,
+        totalFailedLeases: i64,
+        totalSuccessfulLeases: i64,
+    }
+
+    impl RateLimiterStatistics {
+        pub fn currentAvailablePermits(&self) -> i64 {
+            self.currentAvailablePermits
+        }
+
+        pub fn currentQueuedCount(&self) -> i64 {
+            self.currentQueuedCount
+        }
+
+        pub fn totalFailedLeases(&self) -> i64 {
+            self.totalFailedLeases
+        }
+
+        pub fn totalSuccessfulLeases(&self) -> i64 {
+            self.totalSuccessfulLeases
+        }
+    }
+
+    // -----------------------------------------------------------
+    // Limiter state
+    // -----------------------------------------------------------
+
+    enum Kind {
+        TokenBucket { tokensPerPeriod: i32 },
+        FixedWindow,
+        // permits acquired in each segment, returned when the segment expires
+        SlidingWindow { segments: Vec<i32>, current: usize },
+        Concurrency,
+    }
+
+    #[cfg(feature = "threaded")]
+    struct Waiter {
+        count: i32,
+        task: Arc<Task<LrcPtr<RateLimitLease>>>,
+    }
+
+    struct State {
+        kind: Kind,
+        limit: i32,
+        available: i32,
+        queueLimit: i32,
+        queueOrder: i32,
+        queuedCount: i32,
+        #[cfg(feature = "threaded")]
+        queue: VecDeque<Waiter>,
+        autoReplenishment: bool,
+        period: Duration,
+        lastReplenish: Instant,
+        successful: i64,
+        failed: i64,
+        disposed: bool,
+        #[cfg(feature = "threaded")]
+        timerRunning: bool,
+    }
+
+    fn lock(state: &Arc<Mutex<State>>) -> MutexGuard<'_, State> {
+        state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    impl State {
+        fn is_replenishing(&self) -> bool {
+            !matches!(self.kind, Kind::Concurrency)
+        }
+
+        fn replenish_once(&mut self) {
+            match &mut self.kind {
+                Kind::TokenBucket { tokensPerPeriod } => {
+                    self.available = self.limit.min(self.available + *tokensPerPeriod);
+                }
+                Kind::FixedWindow => {
+                    self.available = self.limit;
+                }
+                Kind::SlidingWindow { segments, current } => {
+                    *current = (*current + 1) % segments.len();
+                    self.available += segments[*current];
+                    segments[*current] = 0;
+                }
+                Kind::Concurrency => (),
+            }
+        }
+
+        // applies the replenishments due since the last one
+        fn refresh(&mut self, state: &Arc<Mutex<State>>) {
+            if !self.autoReplenishment || !self.is_replenishing() {
+                return;
+            }
+            let now = Instant::now();
+            let elapsed = now.duration_since(self.lastReplenish);
+            let periods = if self.period.is_zero() {
+                1
+            } else {
+                (elapsed.as_nanos() / self.period.as_nanos()) as u64
+            };
+            if periods == 0 {
+                return;
+            }
+            // more steps than needed to fill up do not change the outcome
+            let steps = match &self.kind {
+                Kind::SlidingWindow { segments, .. } => periods.min(segments.len() as u64),
+                _ => periods.min(self.limit as u64 + 1),
+            };
+            for _ in 0..steps {
+                self.replenish_once();
+            }
+            self.lastReplenish = if self.period.is_zero() {
+                now
+            } else {
+                let remainder = elapsed.as_nanos() % self.period.as_nanos();
+                now - Duration::from_nanos(remainder as u64)
+            };
+            self.grant_queued(state);
+        }
+
+        fn can_grant(&self, count: i32) -> bool {
+            if count == 0 {
+                self.available > 0
+            } else {
+                self.available >= count && (self.queuedCount == 0 || self.queueOrder == NEWEST_FIRST)
+            }
+        }
+
+        fn grant(&mut self, count: i32, state: &Arc<Mutex<State>>) -> LrcPtr<RateLimitLease> {
+            self.available -= count;
+            self.successful += 1;
+            match &mut self.kind {
+                Kind::SlidingWindow { segments, current } => {
+                    segments[*current] += count;
+                    RateLimitLease::new(true, None)
+                }
+                Kind::Concurrency if count > 0 => RateLimitLease::new(true, Some((state.clone(), count))),
+                _ => RateLimitLease::new(true, None),
+            }
+        }
+
+        fn fail(&mut self) -> LrcPtr<RateLimitLease> {
+            self.failed += 1;
+            RateLimitLease::new(false, None)
+        }
+
+        #[cfg(feature = "threaded")]
+        fn grant_queued(&mut self, state: &Arc<Mutex<State>>) {
+            loop {
+                let next = if self.queueOrder == OLDEST_FIRST {
+                    self.queue.front()
+                } else {
+                    self.queue.back()
+                };
+                match next {
+                    Some(waiter) if self.available >= waiter.count => {
+                        let waiter = if self.queueOrder == OLDEST_FIRST {
+                            self.queue.pop_front()
+                        } else {
+                            self.queue.pop_back()
+                        };
+                        let waiter = waiter.unwrap();
+                        self.queuedCount -= waiter.count;
+                        let lease = self.grant(waiter.count, state);
+                        waiter.task.set_result(lease);
+                    }
+                    _ => break,
+                }
+            }
+        }
+
+        #[cfg(not(feature = "threaded"))]
+        fn grant_queued(&mut self, _state: &Arc<Mutex<State>>) {}
+
+        #[cfg(feature = "threaded")]
+        fn fail_queued(&mut self, waiter: Waiter) {
+            self.queuedCount -= waiter.count;
+            let lease = self.fail();
+            waiter.task.set_result(lease);
+        }
+    }
+
+    // -----------------------------------------------------------
+    // Limiters
+    // -----------------------------------------------------------
+
+    pub struct RateLimiter {
+        state: Arc<Mutex<State>>,
+    }
+
+    fn new_limiter(
+        kind: Kind,
+        limit: i32,
+        period: TimeSpan,
+        options: &RateLimiterOptions,
+    ) -> LrcPtr<RateLimiter> {
+        check_options(options.queueLimit() >= 0, "QueueLimit", "greater than or equal to 0");
+        let state = State {
+            kind,
+            limit,
+            available: limit,
+            queueLimit: options.queueLimit(),
+            queueOrder: options.queueProcessingOrder(),
+            queuedCount: 0,
+            #[cfg(feature = "threaded")]
+            queue: VecDeque::new(),
+            autoReplenishment: options.autoReplenishment(),
+            period: to_duration(period),
+            lastReplenish: Instant::now(),
+            successful: 0,
+            failed: 0,
+            disposed: false,
+            #[cfg(feature = "threaded")]
+            timerRunning: false,
+        };
+        LrcPtr::new(RateLimiter {
+            state: Arc::new(Mutex::new(state)),
+        })
+    }
+
+    pub fn tokenBucket(options: LrcPtr<RateLimiterOptions>) -> LrcPtr<RateLimiter> {
+        check_options(options.tokenLimit() > 0, "TokenLimit", "greater than 0");
+        check_options(options.tokensPerPeriod() > 0, "TokensPerPeriod", "greater than 0");
+        check_options(
+            options.replenishmentPeriod().ticks() > 0,
+            "ReplenishmentPeriod",
+            "greater than TimeSpan.Zero",
+        );
+        let kind = Kind::TokenBucket {
+            tokensPerPeriod: options.tokensPerPeriod(),
+        };
+        new_limiter(kind, options.tokenLimit(), options.replenishmentPeriod(), &options)
+    }
+
+    pub fn fixedWindow(options: LrcPtr<RateLimiterOptions>) -> LrcPtr<RateLimiter> {
+        check_options(options.permitLimit() > 0, "PermitLimit", "greater than 0");
+        check_options(options.window().ticks() >= 0, "Window", "greater than or equal to TimeSpan.Zero");
+        new_limiter(Kind::FixedWindow, options.permitLimit(), options.window(), &options)
+    }
+
+    pub fn slidingWindow(options: LrcPtr<RateLimiterOptions>) -> LrcPtr<RateLimiter> {
+        check_options(options.permitLimit() > 0, "PermitLimit", "greater than 0");
+        check_options(options.segmentsPerWindow() > 0, "SegmentsPerWindow", "greater than 0");
+        check_options(options.window().ticks() >= 0, "Window", "greater than or equal to TimeSpan.Zero");
+        let segments = options.segmentsPerWindow();
+        let kind = Kind::SlidingWindow {
+            segments: core::iter::repeat_n(0, segments as usize).collect(),
+            current: 0,
+        };
+        let period = TimeSpan::new_ticks(options.window().ticks() / segments as i64);
+        new_limiter(kind, options.permitLimit(), period, &options)
+    }
+
+    pub fn concurrency(options: LrcPtr<RateLimiterOptions>) -> LrcPtr<RateLimiter> {
+        check_options(options.permitLimit() >= 0, "PermitLimit", "greater than or equal to 0");
+        let limiter = new_limiter(Kind::Concurrency, options.permitLimit(), TimeSpan::new_ticks(0), &options);
+        lock(&limiter.state).autoReplenishment = false;
+        limiter
+    }
+
+    impl RateLimiter {
+        fn lock_checked(&self, permitCount: i32) -> MutexGuard<'_, State> {
+            let mut s = lock(&self.state);
+            if s.disposed {
+                panic!("Cannot access a disposed object.");
+            }
+            if permitCount < 0 || permitCount > s.limit {
+                panic!("{permitCount} permits exceeds the permit limit of {}. (Parameter 'permitCount')", s.limit);
+            }
+            s.refresh(&self.state);
+            s
+        }
+
+        pub fn attemptAcquire(&self, permitCount: i32) -> LrcPtr<RateLimitLease> {
+            let mut s = self.lock_checked(permitCount);
+            if s.can_grant(permitCount) {
+                s.grant(permitCount, &self.state)
+            } else {
+                s.fail()
+            }
+        }
+
+        #[cfg(feature = "threaded")]
+        pub fn acquireAsync(&self, permitCount: i32) -> Arc<Task<LrcPtr<RateLimitLease>>> {
+            let mut s = self.lock_checked(permitCount);
+            if s.can_grant(permitCount) {
+                let lease = s.grant(permitCount, &self.state);
+                return Arc::new(Task::from_result(lease));
+            }
+            if permitCount > s.queueLimit {
+                let lease = s.fail();
+                return Arc::new(Task::from_result(lease));
+            }
+            // newest-first queues make room by failing the oldest requests
+            if s.queueOrder == NEWEST_FIRST {
+                while s.queuedCount + permitCount > s.queueLimit {
+                    let waiter = s.queue.pop_front().unwrap();
+                    s.fail_queued(waiter);
+                }
+            } else if s.queuedCount + permitCount > s.queueLimit {
+                let lease = s.fail();
+                return Arc::new(Task::from_result(lease));
+            }
+            let task = Arc::new(Task::pending());
+            s.queuedCount += permitCount;
+            s.queue.push_back(Waiter {
+                count: permitCount,
+                task: task.clone(),
+            });
+            if s.autoReplenishment && s.is_replenishing() && !s.timerRunning {
+                s.timerRunning = true;
+                start_timer(self.state.clone(), s.period);
+            }
+            task
+        }
+
+        // replenishes manually, when auto-replenishment is disabled
+        pub fn tryReplenish(&self) -> bool {
+            let mut s = lock(&self.state);
+            if s.autoReplenishment || !s.is_replenishing() {
+                return false;
+            }
+            s.replenish_once();
+            s.grant_queued(&self.state);
+            true
+        }
+
+        pub fn getStatistics(&self) -> LrcPtr<RateLimiterStatistics> {
+            let mut s = lock(&self.state);
+            s.refresh(&self.state);
+            LrcPtr::new(RateLimiterStatistics {
+                currentAvailablePermits: s.available as i64,
+                currentQueuedCount: s.queuedCount as i64,
+                totalFailedLeases: s.failed,
+                totalSuccessfulLeases: s.successful,
+            })
+        }
+
+        pub fn dispose(&self) {
+            let mut s = lock(&self.state);
+            s.disposed = true;
+            #[cfg(feature = "threaded")]
+            while let Some(waiter) = s.queue.pop_front() {
+                s.fail_queued(waiter);
+            }
+        }
+    }
+
+    impl Debug for RateLimiter {
+        fn fmt(&self, f: &mut Formatter) -> Result {
+            f.debug_struct("RateLimiter").finish_non_exhaustive()
+        }
+    }
+
+    impl IDisposable for RateLimiter {
+        fn Dispose(&self) {
+            self.dispose()
+        }
+    }
+
+    // replenishes on schedule until the queue is drained
+    #[cfg(feature = "threaded")]
+    fn start_timer(state: Arc<Mutex<State>>, period: Duration) {
+        let tick = period.max(Duration::from_millis(1));
+        std::thread::spawn(move || loop {
+            std::thread::sleep(tick);
+            let mut s = lock(&state);
+            s.refresh(&state);
+            if s.disposed || s.queue.is_empty() {
+                s.timerRunning = false;
+                return;
+            }
+        });
+    }
+}
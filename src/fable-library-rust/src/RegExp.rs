@@ -1,26 +1,40 @@
 #[cfg(feature = "regexp")]
 pub mod RegExp_ {
-    use core::borrow::{Borrow, BorrowMut};
-
     use crate::{
         Interfaces_::System::Collections::Generic::{IEnumerable_1, IEnumerator_1},
         NativeArray_::{array_from, new_array, new_empty, Array},
-        Native_::{Func1, LrcPtr, ToString, Vec},
-        Seq_::ofArray,
-        String_::{fromSlice, fromString, get_char_pos, string, substring, substring2},
+        Native_::{alloc::format, mkMut, Func0, Func1, LrcPtr, MutCell, String, ToString, Vec},
+        Seq_::{ofArray, Enumerable::fromFunction},
+        String_::{fromSlice, fromString, get_char_pos, string, substring2},
     };
+    #[cfg(not(feature = "no_std"))]
+    use std::sync::{Mutex, MutexGuard};
+
+    // RegexOptions
+    const IgnoreCase: i32 = 0x0001;
+    const Multiline: i32 = 0x0002;
+    const ExplicitCapture: i32 = 0x0004;
+    const Compiled: i32 = 0x0008;
+    const Singleline: i32 = 0x0010;
+    const IgnorePatternWhitespace: i32 = 0x0020;
+    const ECMAScript: i32 = 0x0100;
+    const CultureInvariant: i32 = 0x0200;
+    const NonBacktracking: i32 = 0x0400;
 
     #[derive(Clone, Debug)]
     pub struct Regex {
         regex_: regex::Regex,
+        pattern: string,
         options: i32,
+        // the regex crate group index and the name of each .NET group
+        groups: Vec<(usize, string)>,
     }
 
     #[derive(Clone, Debug)]
     pub struct Capture {
         text: string,
-        index: i32,
-        length: i32,
+        start: usize,
+        end: usize,
     }
 
     #[derive(Clone, Debug)]
@@ -40,8 +54,15 @@ pub mod RegExp_ {
     #[derive(Clone, Debug)]
     pub struct GroupCollection(Array<Group>);
 
+    // The matches are found when the collection is enumerated,
+    // and all at once when it is counted or indexed
     #[derive(Clone, Debug)]
-    pub struct MatchCollection(Array<Match>);
+    pub struct MatchCollection {
+        regex: Regex,
+        input: string,
+        start: usize,
+        matches: MutCell<Option<Array<Match>>>,
+    }
 
     pub type MatchEvaluator = Func1<Match, string>;
 
@@ -52,42 +73,497 @@ pub mod RegExp_ {
         }
     }
 
+    // -----------------------------------------------------------
+    // .NET pattern syntax
+    // -----------------------------------------------------------
+
+    // {n}, {n,} and {n,m} are quantifiers, any other '{' is a literal in .NET
+    fn is_quantifier(chars: &[char], i: usize) -> bool {
+        let digits = |mut j: usize| {
+            while j < chars.len() && chars[j].is_ascii_digit() {
+                j += 1;
+            }
+            j
+        };
+        let j = digits(i + 1);
+        if j == i + 1 {
+            return false;
+        }
+        let j = if chars.get(j) == Some(&',') { digits(j + 1) } else { j };
+        chars.get(j) == Some(&'}')
+    }
+
+    // Translates the escape after the backslash at chars[i - 1]
+    fn translate_escape(chars: &[char], i: usize, in_class: bool, out: &mut String) -> usize {
+        match chars.get(i) {
+            // \Z also matches before a final newline in .NET
+            Some('Z') if !in_class => {
+                out.push_str(r"\z");
+                i + 1
+            }
+            Some('e') => {
+                out.push_str(r"\x1B");
+                i + 1
+            }
+            Some('b') if in_class => {
+                out.push_str(r"\x08");
+                i + 1
+            }
+            Some('c') if chars.get(i + 1).is_some_and(|c| c.is_ascii_alphabetic()) => {
+                let code = chars[i + 1].to_ascii_uppercase() as u32 % 32;
+                out.push_str(&format!("\\x{:02X}", code));
+                i + 2
+            }
+            Some('0') => {
+                // octal escapes
+                let mut j = i + 1;
+                let mut code = 0;
+                while j < i + 3 && j < chars.len() && ('0'..='7').contains(&chars[j]) {
+                    code = code * 8 + chars[j] as u32 - '0' as u32;
+                    j += 1;
+                }
+                out.push_str(&format!("\\x{:02X}", code));
+                j
+            }
+            Some(&c) => {
+                out.push('\\');
+                out.push(c);
+                let mut j = i + 1;
+                if matches!(c, 'p' | 'P' | 'x' | 'u') && chars.get(j) == Some(&'{') {
+                    while j < chars.len() {
+                        out.push(chars[j]);
+                        j += 1;
+                        if chars[j - 1] == '}' {
+                            break;
+                        }
+                    }
+                }
+                j
+            }
+            None => {
+                out.push('\\');
+                i
+            }
+        }
+    }
+
+    // In .NET character classes '[' is a literal unless it starts a subtraction,
+    // and '&&', '--' and '~~' are not set operations
+    fn translate_class(chars: &[char], i: usize, extended: bool, out: &mut String) -> usize {
+        out.push('[');
+        let mut j = i + 1;
+        if chars.get(j) == Some(&'^') {
+            out.push('^');
+            j += 1;
+        }
+        if chars.get(j) == Some(&']') {
+            out.push_str(r"\]");
+            j += 1;
+        }
+        while j < chars.len() {
+            match chars[j] {
+                ']' => {
+                    out.push(']');
+                    return j + 1;
+                }
+                '\\' => j = translate_escape(chars, j + 1, true, out),
+                '-' if chars.get(j + 1) == Some(&'[') => {
+                    out.push_str("--");
+                    j = translate_class(chars, j + 1, extended, out);
+                }
+                '-' if chars.get(j + 1) == Some(&'-') => {
+                    out.push_str(r"\-");
+                    j += 1;
+                }
+                c @ ('[' | '&' | '~' | '#') => {
+                    out.push('\\');
+                    out.push(c);
+                    j += 1;
+                }
+                // white space is not ignored in classes in .NET
+                c if extended && c.is_whitespace() => {
+                    out.push_str(&format!("\\x{{{:X}}}", c as u32));
+                    j += 1;
+                }
+                c => {
+                    out.push(c);
+                    j += 1;
+                }
+            }
+        }
+        j
+    }
+
+    // Translates a "(?" construct: comments, quoted group names and
+    // inline options, including the explicit capture option
+    fn translate_group(
+        chars: &[char],
+        i: usize,
+        explicit: &mut bool,
+        extended: &mut bool,
+        out: &mut String,
+    ) -> (usize, bool) {
+        let j = i + 2;
+        match chars.get(j) {
+            Some('#') => {
+                let mut k = j;
+                while k < chars.len() && chars[k] != ')' {
+                    k += 1;
+                }
+                (k + 1, false)
+            }
+            Some('\'') => {
+                out.push_str("(?P<");
+                let mut k = j + 1;
+                while k < chars.len() && chars[k] != '\'' {
+                    out.push(chars[k]);
+                    k += 1;
+                }
+                out.push('>');
+                (k + 1, true)
+            }
+            _ => {
+                let mut k = j;
+                let mut negate = false;
+                let mut on = String::new();
+                let mut off = String::new();
+                let mut new_explicit = *explicit;
+                let mut new_extended = *extended;
+                while let Some(&f) = chars.get(k) {
+                    match f {
+                        '-' => negate = true,
+                        'n' => new_explicit = !negate,
+                        'i' | 'm' | 's' | 'x' => {
+                            if f == 'x' {
+                                new_extended = !negate;
+                            }
+                            if negate { off.push(f) } else { on.push(f) }
+                        }
+                        _ => break,
+                    }
+                    k += 1;
+                }
+                let is_group = chars.get(k) == Some(&':');
+                if k == j || !(is_group || chars.get(k) == Some(&')')) {
+                    out.push_str("(?");
+                    return (j, true);
+                }
+                *explicit = new_explicit;
+                *extended = new_extended;
+                if !on.is_empty() || !off.is_empty() {
+                    out.push_str("(?");
+                    out.push_str(&on);
+                    if !off.is_empty() {
+                        out.push('-');
+                        out.push_str(&off);
+                    }
+                    out.push(if is_group { ':' } else { ')' });
+                } else if is_group {
+                    out.push_str("(?:");
+                }
+                (k + 1, is_group)
+            }
+        }
+    }
+
+    fn translate_pattern(pattern: &str, options: i32) -> String {
+        let chars: Vec<char> = pattern.chars().collect();
+        let mut out = String::new();
+        let mut explicit = (options & ExplicitCapture) != 0;
+        let mut extended = (options & IgnorePatternWhitespace) != 0;
+        // the options to restore at the end of each group
+        let mut stack: Vec<(bool, bool)> = Vec::new();
+        let mut i = 0;
+        while i < chars.len() {
+            match chars[i] {
+                '\\' => i = translate_escape(&chars, i + 1, false, &mut out),
+                '[' => i = translate_class(&chars, i, extended, &mut out),
+                '{' if !is_quantifier(&chars, i) => {
+                    out.push_str(r"\{");
+                    i += 1;
+                }
+                '#' if extended => {
+                    while i < chars.len() && chars[i] != '\n' {
+                        out.push(chars[i]);
+                        i += 1;
+                    }
+                }
+                '(' if chars.get(i + 1) == Some(&'?') => {
+                    let saved = (explicit, extended);
+                    let (next, is_group) =
+                        translate_group(&chars, i, &mut explicit, &mut extended, &mut out);
+                    if is_group {
+                        stack.push(saved);
+                    }
+                    i = next;
+                }
+                '(' => {
+                    stack.push((explicit, extended));
+                    out.push_str(if explicit { "(?:" } else { "(" });
+                    i += 1;
+                }
+                ')' => {
+                    if let Some((e, x)) = stack.pop() {
+                        explicit = e;
+                        extended = x;
+                    }
+                    out.push(')');
+                    i += 1;
+                }
+                c => {
+                    out.push(c);
+                    i += 1;
+                }
+            }
+        }
+        out
+    }
+
+    // .NET numbers the unnamed groups first, then the named groups
+    fn dotnet_groups(regex: &regex::Regex) -> Vec<(usize, string)> {
+        let names: Vec<Option<&str>> = regex.capture_names().collect();
+        let unnamed = names.iter().enumerate().filter(|(_, n)| n.is_none());
+        let named = names.iter().enumerate().filter(|(_, n)| n.is_some());
+        unnamed
+            .chain(named)
+            .enumerate()
+            .map(|(number, (i, name))| match name {
+                Some(name) => (i, fromSlice(name)),
+                None => (i, fromString(number.to_string())),
+            })
+            .collect()
+    }
+
+    fn compile(pat: &str, options: i32) -> regex::Regex {
+        // Supported RegexOptions:
+        // * IgnoreCase:               0x0001
+        // * Multiline:                0x0002
+        // * ExplicitCapture:          0x0004
+        // * Compiled:                 0x0008 (ignored)
+        // * Singleline:               0x0010
+        // * IgnorePatternWhitespace:  0x0020
+        // * ECMAScript:               0x0100 (ignored)
+        // * CultureInvariant:         0x0200 (ignored)
+        // * NonBacktracking:          0x0400 (ignored, the regex crate never backtracks)
+        let supported = IgnoreCase
+            | Multiline
+            | ExplicitCapture
+            | Compiled
+            | Singleline
+            | IgnorePatternWhitespace
+            | ECMAScript
+            | CultureInvariant
+            | NonBacktracking;
+        if (options & !supported) != 0 {
+            panic!("Unsupported Regex Option");
+        }
+        let translated = translate_pattern(pat, options);
+        regex::RegexBuilder::new(&translated)
+            .case_insensitive((options & IgnoreCase) != 0)
+            .multi_line((options & Multiline) != 0)
+            .dot_matches_new_line((options & Singleline) != 0)
+            .ignore_whitespace((options & IgnorePatternWhitespace) != 0)
+            .build()
+            .unwrap_or_else(|e| panic!("Invalid pattern '{}'. {}", pat, e))
+    }
+
+    // -----------------------------------------------------------
+    // Cache
+    // -----------------------------------------------------------
+
+    // Like in .NET, the static methods keep the most recently used
+    // patterns compiled, up to Regex.CacheSize entries. The compiled
+    // regex is shared, so the cache can be used from any thread.
+    // Without std there is no Mutex, so the patterns are always compiled.
+
+    #[cfg(not(feature = "no_std"))]
+    struct RegexCache {
+        size: usize,
+        // most recently used first
+        items: Vec<(String, i32, regex::Regex)>,
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    static cache: Mutex<RegexCache> = Mutex::new(RegexCache {
+        size: 15,
+        items: Vec::new(),
+    });
+
+    #[cfg(not(feature = "no_std"))]
+    fn lock_cache() -> MutexGuard<'static, RegexCache> {
+        cache.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    fn cache_size() -> usize {
+        lock_cache().size
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    fn set_cache_size(size: usize) {
+        let mut c = lock_cache();
+        c.size = size;
+        c.items.truncate(size);
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    fn cached(pat: &str, options: i32) -> regex::Regex {
+        let mut c = lock_cache();
+        match c.items.iter().position(|(p, o, _)| p == pat && *o == options) {
+            Some(i) => {
+                let item = c.items.remove(i);
+                let regex = item.2.clone();
+                c.items.insert(0, item);
+                regex
+            }
+            None => {
+                drop(c);
+                let regex = compile(pat, options);
+                let mut c = lock_cache();
+                if c.size > 0 {
+                    c.items.insert(0, (pat.to_string(), options, regex.clone()));
+                    let size = c.size;
+                    c.items.truncate(size);
+                }
+                regex
+            }
+        }
+    }
+
+    #[cfg(feature = "no_std")]
+    static cache_size_: core::sync::atomic::AtomicUsize = core::sync::atomic::AtomicUsize::new(15);
+
+    #[cfg(feature = "no_std")]
+    fn cache_size() -> usize {
+        cache_size_.load(core::sync::atomic::Ordering::Relaxed)
+    }
+
+    #[cfg(feature = "no_std")]
+    fn set_cache_size(size: usize) {
+        cache_size_.store(size, core::sync::atomic::Ordering::Relaxed)
+    }
+
+    #[cfg(feature = "no_std")]
+    fn cached(pat: &str, options: i32) -> regex::Regex {
+        compile(pat, options)
+    }
+
+    // -----------------------------------------------------------
+    // Matching
+    // -----------------------------------------------------------
+
+    // Finds the next match from the position, which like in .NET moves one
+    // character further after an empty match. There is no position left
+    // after an empty match at the end of the input.
+    fn next_captures<'h>(
+        regex: &regex::Regex,
+        input: &'h str,
+        pos: &mut Option<usize>,
+    ) -> Option<regex::Captures<'h>> {
+        let captures = regex.captures_at(input, (*pos)?);
+        *pos = captures.as_ref().and_then(|captures| {
+            let m = captures.get(0).unwrap();
+            if m.is_empty() {
+                input[m.end()..].chars().next().map(|c| m.end() + c.len_utf8())
+            } else {
+                Some(m.end())
+            }
+        });
+        captures
+    }
+
+    struct CapturesIter<'r, 'h> {
+        regex: &'r regex::Regex,
+        input: &'h str,
+        pos: Option<usize>,
+    }
+
+    impl<'r, 'h> Iterator for CapturesIter<'r, 'h> {
+        type Item = regex::Captures<'h>;
+        fn next(&mut self) -> Option<Self::Item> {
+            next_captures(self.regex, self.input, &mut self.pos)
+        }
+    }
+
+    struct MatchIter {
+        regex: Regex,
+        input: string,
+        pos: Option<usize>,
+    }
+
+    impl Iterator for MatchIter {
+        type Item = Match;
+        fn next(&mut self) -> Option<Self::Item> {
+            let captures = next_captures(&self.regex.regex_, self.input.as_str(), &mut self.pos)?;
+            Some(Match::new(&self.input, &self.regex.groups, &captures))
+        }
+    }
+
+    // -----------------------------------------------------------
+    // Replacement patterns
+    // -----------------------------------------------------------
+
+    #[derive(Clone, Debug)]
+    enum Substitution {
+        Literal(String),
+        Group(usize),
+        LastGroup,
+        Prefix,
+        Suffix,
+        Input,
+    }
+
+    fn expand(
+        parts: &[Substitution],
+        groups: &[(usize, string)],
+        input: &str,
+        captures: &regex::Captures,
+        res: &mut String,
+    ) {
+        let m = captures.get(0).unwrap();
+        let group = |number: usize| captures.get(groups[number].0).map_or("", |g| g.as_str());
+        for part in parts {
+            match part {
+                Substitution::Literal(s) => res.push_str(s),
+                Substitution::Group(number) => res.push_str(group(*number)),
+                Substitution::LastGroup => res.push_str(group(groups.len() - 1)),
+                Substitution::Prefix => res.push_str(&input[..m.start()]),
+                Substitution::Suffix => res.push_str(&input[m.end()..]),
+                Substitution::Input => res.push_str(input),
+            }
+        }
+    }
+
+    // -----------------------------------------------------------
+    // Captures, groups and matches
+    // -----------------------------------------------------------
+
     impl Capture {
         pub fn index(&self) -> i32 {
-            self.index
+            self.text[..self.start].chars().count() as i32
         }
         pub fn length(&self) -> i32 {
-            self.length
+            self.text[self.start..self.end].chars().count() as i32
         }
         pub fn value(&self) -> string {
-            substring2(self.text.clone(), self.index, self.length)
+            fromSlice(&self.text[self.start..self.end])
         }
     }
 
     impl Group {
-        pub fn new(text: string, n: Option<&str>, m: Option<regex::Match>) -> Group {
-            match m {
+        pub fn new(text: string, name: string, m: Option<regex::Match>) -> Group {
+            let captures = match m {
                 Some(m) => {
-                    let name = fromString(n.unwrap_or("").to_string());
-                    let capture = Capture {
-                        text,
-                        index: m.start() as i32,
-                        length: m.len() as i32,
-                    };
-                    Group {
-                        name,
-                        captures: CaptureCollection(new_array(&[capture])),
-                    }
+                    let capture = Capture { text, start: m.start(), end: m.end() };
+                    CaptureCollection(new_array(&[capture]))
                 }
-                None => Group {
-                    name: string(""),
-                    captures: CaptureCollection(new_empty()),
-                },
-            }
+                None => CaptureCollection(new_empty()),
+            };
+            Group { name, captures }
         }
 
         pub fn empty() -> Group {
-            Group::new(string(""), None, None)
+            Group::new(string(""), string(""), None)
         }
 
         pub fn name(&self) -> string {
@@ -128,15 +604,10 @@ pub mod RegExp_ {
     }
 
     impl Match {
-        pub fn new(
-            text: &string,
-            captureNames: &regex::CaptureNames,
-            captures: &regex::Captures,
-        ) -> Match {
-            let groups = captures
+        fn new(text: &string, groups: &[(usize, string)], captures: &regex::Captures) -> Match {
+            let groups = groups
                 .iter()
-                .zip(captureNames.clone())
-                .map(|(m, n)| Group::new(text.clone(), n, m))
+                .map(|(i, name)| Group::new(text.clone(), name.clone(), captures.get(*i)))
                 .collect::<Vec<Group>>();
             Match {
                 groups: GroupCollection(array_from(groups)),
@@ -144,7 +615,7 @@ pub mod RegExp_ {
         }
 
         pub fn empty() -> Match {
-            let empty = Group::new(string(""), None, None);
+            let empty = Group::new(string(""), string("0"), None);
             Match {
                 groups: GroupCollection(new_array(&[empty])),
             }
@@ -179,74 +650,155 @@ pub mod RegExp_ {
         }
     }
 
+    // -----------------------------------------------------------
+    // Regex
+    // -----------------------------------------------------------
+
     impl Regex {
         pub fn new__s(pat: string) -> LrcPtr<Regex> {
-            let regex_ = regex::Regex::new(pat.as_str()).unwrap();
-            LrcPtr::new(Regex { regex_, options: 0 })
+            Regex::new__sn(pat, 0)
         }
 
         pub fn new__sn(pat: string, options: i32) -> LrcPtr<Regex> {
-            // Supported RegexOptions:
-            // * IgnoreCase:               0x0001
-            // * Multiline:                0x0002
-            // * Compiled:                 0x0008 (ignored)
-            // * Singleline:               0x0010
-            // * IgnorePatternWhitespace:  0x0020
-            // * ECMAScript:               0x0100 (ignored)
-            if ((options & !(1 | 2 | 8 | 16 | 32 | 256)) != 0) {
-                panic!("Unsupported Regex Option");
-            }
-            let regex_ = regex::RegexBuilder::new(pat.as_str())
-                .case_insensitive((options & 1) != 0)
-                .multi_line((options & 2) != 0)
-                .dot_matches_new_line((options & 16) != 0)
-                .ignore_whitespace((options & 32) != 0)
-                //.unicode(false)
-                .build()
-                .unwrap();
-            LrcPtr::new(Regex { regex_, options })
+            let regex_ = compile(pat.as_str(), options);
+            Regex::from_regex(regex_, pat, options)
+        }
+
+        fn from_regex(regex_: regex::Regex, pat: string, options: i32) -> LrcPtr<Regex> {
+            let groups = dotnet_groups(&regex_);
+            LrcPtr::new(Regex { regex_, pattern: pat, options, groups })
+        }
+
+        // used by the static methods
+        fn cached(pat: string, options: i32) -> LrcPtr<Regex> {
+            let regex_ = cached(pat.as_str(), options);
+            Regex::from_regex(regex_, pat, options)
+        }
+
+        pub fn cacheSize_() -> i32 {
+            cache_size() as i32
+        }
+
+        pub fn cacheSize__n(value: i32) {
+            if value < 0 {
+                panic!("Specified argument was out of the range of valid values. (Parameter 'value')");
+            }
+            set_cache_size(value as usize);
         }
 
         pub fn escape__s(str: string) -> string {
-            fromString(regex::escape(str.as_str()))
+            let mut res = String::with_capacity(str.len());
+            for c in str.chars() {
+                match c {
+                    '\\' | '*' | '+' | '?' | '|' | '{' | '[' | '(' | ')' | '^' | '$' | '.' | '#'
+                    | ' ' => {
+                        res.push('\\');
+                        res.push(c);
+                    }
+                    '\t' => res.push_str(r"\t"),
+                    '\n' => res.push_str(r"\n"),
+                    '\r' => res.push_str(r"\r"),
+                    '\x0C' => res.push_str(r"\f"),
+                    c => res.push(c),
+                }
+            }
+            fromString(res)
         }
 
         pub fn unescape__s(str: string) -> string {
-            let pat = string(r"\\([\-\[\]\/\{\}\(\)\*\+\?\.\\\^\$\|])");
-            let reg = Regex::new__s(pat);
-            fromString(reg.replace_all(str.as_str(), "$1").to_string())
+            let chars: Vec<char> = str.chars().collect();
+            let invalid = |offset: usize, msg: &str| -> ! {
+                panic!("Invalid pattern '{}' at offset {}. {}", str, offset, msg)
+            };
+            let hex = |i: usize, n: usize| -> (char, usize) {
+                let digits: String = chars.iter().skip(i).take(n).collect();
+                match u32::from_str_radix(&digits, 16).ok().and_then(char::from_u32) {
+                    Some(c) if digits.len() == n && digits.chars().all(|d| d.is_ascii_hexdigit()) => {
+                        (c, i + n)
+                    }
+                    _ => invalid(i + digits.len(), "Insufficient hexadecimal digits."),
+                }
+            };
+            let mut res = String::with_capacity(str.len());
+            let mut i = 0;
+            while i < chars.len() {
+                if chars[i] != '\\' {
+                    res.push(chars[i]);
+                    i += 1;
+                    continue;
+                }
+                let (c, next) = match chars.get(i + 1) {
+                    None => invalid(i + 1, "Illegal \\ at end of pattern."),
+                    Some(&d) if ('0'..='7').contains(&d) => {
+                        let mut j = i + 1;
+                        let mut code = 0;
+                        while j < i + 4 && j < chars.len() && ('0'..='7').contains(&chars[j]) {
+                            code = code * 8 + chars[j] as u32 - '0' as u32;
+                            j += 1;
+                        }
+                        (char::from_u32(code & 0xFF).unwrap(), j)
+                    }
+                    Some('x') => hex(i + 2, 2),
+                    Some('u') => hex(i + 2, 4),
+                    Some('a') => ('\x07', i + 2),
+                    Some('b') => ('\x08', i + 2),
+                    Some('e') => ('\x1B', i + 2),
+                    Some('f') => ('\x0C', i + 2),
+                    Some('n') => ('\n', i + 2),
+                    Some('r') => ('\r', i + 2),
+                    Some('t') => ('\t', i + 2),
+                    Some('v') => ('\x0B', i + 2),
+                    Some('c') => match chars.get(i + 2) {
+                        Some(c) if c.is_ascii_alphabetic() => {
+                            let code = c.to_ascii_uppercase() as u8 - b'@';
+                            (code as char, i + 3)
+                        }
+                        _ => invalid(i + 2, "Unrecognized control character."),
+                    },
+                    Some(&c) if c.is_alphanumeric() || c == '_' => {
+                        let msg = format!("Unrecognized escape sequence \\{}.", c);
+                        invalid(i + 2, &msg)
+                    }
+                    Some(&c) => (c, i + 2),
+                };
+                res.push(c);
+                i = next;
+            }
+            fromString(res)
+        }
+
+        pub fn toString(&self) -> string {
+            self.pattern.clone()
+        }
+
+        fn captures_from<'r, 'h>(&'r self, input: &'h str, pos: usize) -> CapturesIter<'r, 'h> {
+            CapturesIter { regex: &self.regex_, input, pos: Some(pos) }
+        }
+
+        fn group_number(&self, name: &str) -> Option<usize> {
+            self.groups.iter().position(|(_, n)| n.as_str() == name)
         }
 
         pub fn getGroupNames(&self) -> Array<string> {
-            let names: Vec<string> = self
-                .capture_names()
-                .filter_map(|o| o.map(fromSlice))
-                .collect();
+            let names: Vec<string> = self.groups.iter().map(|(_, n)| n.clone()).collect();
             array_from(names)
         }
 
         pub fn getGroupNumbers(&self) -> Array<i32> {
-            let numbers: Vec<i32> = self
-                .capture_names()
-                .enumerate()
-                .filter_map(|(i, o)| o.map(|s| i as i32))
-                .collect();
+            let numbers: Vec<i32> = (0..self.groups.len() as i32).collect();
             array_from(numbers)
         }
 
         pub fn groupNameFromNumber(&self, i: i32) -> string {
-            match self.capture_names().nth(i as usize).flatten() {
-                Some(s) => fromSlice(s),
-                None => string(""),
+            match self.groups.get(i as usize) {
+                Some((_, name)) if i >= 0 => name.clone(),
+                _ => string(""),
             }
         }
 
         pub fn groupNumberFromName(&self, name: string) -> i32 {
-            match self.capture_names().enumerate().find(|(i, o)| match o {
-                Some(s) => *s == name.as_str(),
-                None => false,
-            }) {
-                Some((i, o)) => i as i32,
+            match self.group_number(name.as_str()) {
+                Some(number) => number as i32,
                 None => -1,
             }
         }
@@ -261,84 +813,77 @@ pub mod RegExp_ {
         }
 
         pub fn isMatch__ss(input: string, pat: string) -> bool {
-            let reg = Regex::new__s(pat);
+            let reg = Regex::cached(pat, 0);
             reg.isMatch_s(input)
         }
 
         pub fn isMatch__ssn(input: string, pat: string, options: i32) -> bool {
-            let reg = Regex::new__sn(pat, options);
+            let reg = Regex::cached(pat, options);
             reg.isMatch_s(input)
         }
 
         pub fn count_s(&self, input: string) -> i32 {
-            self.find_iter(input.as_str()).count() as i32
+            self.captures_from(input.as_str(), 0).count() as i32
         }
 
         pub fn count__ss(input: string, pat: string) -> i32 {
-            let reg = Regex::new__s(pat);
+            let reg = Regex::cached(pat, 0);
             reg.count_s(input)
         }
 
         pub fn count__ssn(input: string, pat: string, options: i32) -> i32 {
-            let reg = Regex::new__sn(pat, options);
+            let reg = Regex::cached(pat, options);
             reg.count_s(input)
         }
 
         pub fn match_s(&self, input: string) -> Match {
-            match self.captures(input.as_str()) {
-                Some(captures) => Match::new(&input, &self.capture_names(), &captures),
-                None => Match::empty(),
-            }
+            self.match_sn(input, 0)
         }
 
         pub fn match_sn(&self, input: string, start: i32) -> Match {
             let (pos, n) = get_char_pos(&input, start);
             match self.captures_at(input.as_str(), pos) {
-                Some(captures) => Match::new(&input, &self.capture_names(), &captures),
+                Some(captures) => Match::new(&input, &self.groups, &captures),
                 None => Match::empty(),
             }
         }
 
         pub fn match_snn(&self, input: string, start: i32, count: i32) -> Match {
             let input = substring2(input, 0, start + count);
-            let (pos, n) = get_char_pos(&input, start);
-            match self.captures_at(input.as_str(), pos) {
-                Some(captures) => Match::new(&input, &self.capture_names(), &captures),
-                None => Match::empty(),
-            }
+            self.match_sn(input, start)
         }
 
         pub fn match__ss(input: string, pat: string) -> Match {
-            let reg = Regex::new__s(pat);
+            let reg = Regex::cached(pat, 0);
             reg.match_s(input)
         }
 
         pub fn match__ssn(input: string, pat: string, options: i32) -> Match {
-            let reg = Regex::new__sn(pat, options);
+            let reg = Regex::cached(pat, options);
             reg.match_s(input)
         }
 
         pub fn matches_s(&self, input: string) -> LrcPtr<MatchCollection> {
-            let names = self.capture_names();
-            let matches = self
-                .captures_iter(input.as_str())
-                .map(|captures| Match::new(&input, &names, &captures))
-                .collect::<Vec<Match>>();
-            LrcPtr::new(MatchCollection(array_from(matches)))
+            self.matches_sn(input, 0)
         }
 
         pub fn matches_sn(&self, input: string, start: i32) -> LrcPtr<MatchCollection> {
-            let input = substring(input, start);
-            self.matches_s(input)
+            let (pos, n) = get_char_pos(&input, start);
+            LrcPtr::new(MatchCollection {
+                regex: self.clone(),
+                input,
+                start: pos,
+                matches: MutCell::new(None),
+            })
         }
 
         pub fn matches__ss(input: string, pat: string) -> LrcPtr<MatchCollection> {
-            let reg = Regex::new__s(pat);
+            let reg = Regex::cached(pat, 0);
             reg.matches_s(input)
         }
 
         pub fn matches__ssn(input: string, pat: string, options: i32) -> LrcPtr<MatchCollection> {
-            let reg = Regex::new__sn(pat, options);
+            let reg = Regex::cached(pat, options);
             reg.matches_s(input)
         }
 
@@ -346,40 +891,109 @@ pub mod RegExp_ {
             self.options
         }
 
+        // Replaces at most count matches (all if count is -1) found from start
+        fn replace_with<F>(&self, input: &string, count: i32, start: i32, mut f: F) -> string
+        where
+            F: FnMut(&regex::Captures, &mut String),
+        {
+            let (pos, n) = get_char_pos(input, start);
+            let count = if count < 0 { usize::MAX } else { count as usize };
+            let mut res = String::new();
+            let mut last = 0;
+            for captures in self.captures_from(input.as_str(), pos).take(count) {
+                let m = captures.get(0).unwrap();
+                res.push_str(&input[last..m.start()]);
+                f(&captures, &mut res);
+                last = m.end();
+            }
+            res.push_str(&input[last..]);
+            fromString(res)
+        }
+
+        // Parses a .NET replacement pattern, where an invalid substitution is a literal
+        fn parse_replacement(&self, rep: &str) -> Vec<Substitution> {
+            let chars: Vec<char> = rep.chars().collect();
+            let mut parts = Vec::new();
+            let mut literal = String::new();
+            let mut i = 0;
+            while i < chars.len() {
+                if chars[i] != '$' || i + 1 == chars.len() {
+                    literal.push(chars[i]);
+                    i += 1;
+                    continue;
+                }
+                let (part, next) = match chars[i + 1] {
+                    '$' => (None, i + 2),
+                    '&' => (Some(Substitution::Group(0)), i + 2),
+                    '`' => (Some(Substitution::Prefix), i + 2),
+                    '\'' => (Some(Substitution::Suffix), i + 2),
+                    '+' => (Some(Substitution::LastGroup), i + 2),
+                    '_' => (Some(Substitution::Input), i + 2),
+                    '{' => match chars[i + 2..].iter().position(|&c| c == '}') {
+                        Some(len) => {
+                            let name: String = chars[i + 2..i + 2 + len].iter().collect();
+                            let group = self.group_number(&name).map(Substitution::Group);
+                            (group, i + 3 + len)
+                        }
+                        None => (None, i + 1),
+                    },
+                    c if c.is_ascii_digit() => {
+                        let mut j = i + 1;
+                        while j < chars.len() && chars[j].is_ascii_digit() {
+                            j += 1;
+                        }
+                        let number: String = chars[i + 1..j].iter().collect();
+                        let group = number
+                            .parse::<usize>()
+                            .ok()
+                            .filter(|n| *n < self.groups.len())
+                            .map(Substitution::Group);
+                        (group, j)
+                    }
+                    _ => (None, i + 1),
+                };
+                match part {
+                    Some(part) => {
+                        if !literal.is_empty() {
+                            parts.push(Substitution::Literal(core::mem::take(&mut literal)));
+                        }
+                        parts.push(part);
+                        i = next;
+                    }
+                    None => {
+                        // "$$" is an escaped '$', otherwise the '$' is a literal
+                        literal.push('$');
+                        i = if chars[i + 1] == '$' { i + 2 } else { i + 1 };
+                    }
+                }
+            }
+            if !literal.is_empty() {
+                parts.push(Substitution::Literal(literal));
+            }
+            parts
+        }
+
         pub fn replace_ss(&self, input: string, rep: string) -> string {
-            let str = self.replace_all(input.as_str(), rep.as_str());
-            fromString(str.to_string())
+            self.replace_ssnn(input, rep, -1, 0)
         }
 
         pub fn replace_ssn(&self, input: string, rep: string, count: i32) -> string {
-            let str = self.replacen(input.as_str(), count as usize, rep.as_str());
-            fromString(str.to_string())
+            self.replace_ssnn(input, rep, count, 0)
         }
 
         pub fn replace_ssnn(&self, input: string, rep: string, count: i32, start: i32) -> string {
-            let (pos, n) = get_char_pos(&input, start);
-            let (prefix, input) = input.split_at(pos);
-            let str = self.replacen(input, count as usize, rep.as_str());
-            let s = [prefix, str.borrow()].join("");
-            fromString(s)
+            let parts = self.parse_replacement(rep.as_str());
+            self.replace_with(&input, count, start, |captures, res| {
+                expand(&parts, &self.groups, input.as_str(), captures, res)
+            })
         }
 
         pub fn replace_sf(&self, input: string, eval: MatchEvaluator) -> string {
-            let names = self.capture_names();
-            let str = self.replace_all(input.as_str(), |captures: &regex::Captures| {
-                eval(Match::new(&input, &names, captures))
-            });
-            fromString(str.to_string())
+            self.replace_sfnn(input, eval, -1, 0)
         }
 
         pub fn replace_sfn(&self, input: string, eval: MatchEvaluator, count: i32) -> string {
-            let names = self.capture_names();
-            let str = self.replacen(
-                input.as_str(),
-                count as usize,
-                |captures: &regex::Captures| eval(Match::new(&input, &names, captures)),
-            );
-            fromString(str.to_string())
+            self.replace_sfnn(input, eval, count, 0)
         }
 
         pub fn replace_sfnn(
@@ -389,31 +1003,23 @@ pub mod RegExp_ {
             count: i32,
             start: i32,
         ) -> string {
-            let (pos, n) = get_char_pos(&input, start);
-            let (prefix, input) = input.split_at(pos);
-            let input = fromSlice(input);
-            let names = self.capture_names();
-            let str = self.replacen(
-                input.as_str(),
-                count as usize,
-                |captures: &regex::Captures| eval(Match::new(&input, &names, captures)),
-            );
-            let s = [prefix, str.borrow()].join("");
-            fromString(s)
+            self.replace_with(&input, count, start, |captures, res| {
+                res.push_str(&eval(Match::new(&input, &self.groups, captures)))
+            })
         }
 
         pub fn replace__sss(input: string, pat: string, rep: string) -> string {
-            let reg = Regex::new__s(pat);
+            let reg = Regex::cached(pat, 0);
             reg.replace_ss(input, rep)
         }
 
         pub fn replace__sssn(input: string, pat: string, rep: string, options: i32) -> string {
-            let reg = Regex::new__sn(pat, options);
+            let reg = Regex::cached(pat, options);
             reg.replace_ss(input, rep)
         }
 
         pub fn replace__ssf(input: string, pat: string, eval: MatchEvaluator) -> string {
-            let reg = Regex::new__s(pat);
+            let reg = Regex::cached(pat, 0);
             reg.replace_sf(input, eval)
         }
 
@@ -423,39 +1029,56 @@ pub mod RegExp_ {
             eval: MatchEvaluator,
             options: i32,
         ) -> string {
-            let reg = Regex::new__sn(pat, options);
+            let reg = Regex::cached(pat, options);
             reg.replace_sf(input, eval)
         }
 
         pub fn split_s(&self, input: string) -> Array<string> {
-            let parts: Vec<string> = self.split(input.as_str()).map(fromSlice).collect();
-            array_from(parts)
+            self.split_snn(input, 0, 0)
         }
 
         pub fn split_sn(&self, input: string, count: i32) -> Array<string> {
-            let parts: Vec<string> = self
-                .splitn(input.as_str(), count as usize)
-                .map(fromSlice)
-                .collect();
-            array_from(parts)
+            self.split_snn(input, count, 0)
         }
 
+        // Like in .NET, the text of the captured groups is included in the result
         pub fn split_snn(&self, input: string, count: i32, start: i32) -> Array<string> {
-            let input = substring(input, start);
-            self.split_sn(input, count)
+            if count < 0 {
+                panic!("Specified argument was out of the range of valid values. (Parameter 'count')");
+            }
+            let (pos, n) = get_char_pos(&input, start);
+            let count = if count == 0 { usize::MAX } else { count as usize - 1 };
+            let mut parts = Vec::new();
+            let mut last = 0;
+            for captures in self.captures_from(input.as_str(), pos).take(count) {
+                let m = captures.get(0).unwrap();
+                parts.push(fromSlice(&input[last..m.start()]));
+                for (i, _) in self.groups.iter().skip(1) {
+                    if let Some(g) = captures.get(*i) {
+                        parts.push(fromSlice(g.as_str()));
+                    }
+                }
+                last = m.end();
+            }
+            parts.push(fromSlice(&input[last..]));
+            array_from(parts)
         }
 
         pub fn split__ss(input: string, pat: string) -> Array<string> {
-            let reg = Regex::new__s(pat);
+            let reg = Regex::cached(pat, 0);
             reg.split_s(input)
         }
 
         pub fn split__ssn(input: string, pat: string, options: i32) -> Array<string> {
-            let reg = Regex::new__sn(pat, options);
+            let reg = Regex::cached(pat, options);
             reg.split_s(input)
         }
     }
 
+    // -----------------------------------------------------------
+    // Collections
+    // -----------------------------------------------------------
+
     impl CaptureCollection {
         pub fn count(&self) -> i32 {
             self.0.len() as i32
@@ -478,7 +1101,7 @@ pub mod RegExp_ {
         }
 
         pub fn item_n(&self, index: i32) -> Group {
-            if index >= 0 || index < self.count() {
+            if index >= 0 && index < self.count() {
                 self.0[index].clone()
             } else {
                 Group::empty()
@@ -486,25 +1109,15 @@ pub mod RegExp_ {
         }
 
         pub fn item_s(&self, name: string) -> Group {
-            let count = self.count();
-            let mut g: Option<Group> = None;
             // linear search should be fast enough
-            for i in 0..count {
-                if self.0[i].name() == name {
-                    g = Some(self.0[i].clone());
-                }
+            match self.0.iter().find(|g| g.name == name) {
+                Some(g) => g.clone(),
+                None => Group::empty(),
             }
-            g.unwrap_or(Group::empty())
         }
 
         pub fn keys(&self) -> Array<string> {
-            let count = self.count();
-            let mut names = Vec::new();
-            for i in 0..count {
-                if self.0[i].name().len() > 0 {
-                    names.push(self.0[i].name());
-                }
-            }
+            let names: Vec<string> = self.0.iter().map(|g| g.name()).collect();
             array_from(names)
         }
 
@@ -520,18 +1133,38 @@ pub mod RegExp_ {
     }
 
     impl MatchCollection {
+        fn iter(&self) -> MatchIter {
+            MatchIter {
+                regex: self.regex.clone(),
+                input: self.input.clone(),
+                pos: Some(self.start),
+            }
+        }
+
+        fn matches(&self) -> Array<Match> {
+            match self.matches.get() {
+                Some(matches) => matches,
+                None => {
+                    let matches = array_from(self.iter().collect());
+                    self.matches.set(Some(matches.clone()));
+                    matches
+                }
+            }
+        }
+
         pub fn count(&self) -> i32 {
-            self.0.len() as i32
+            self.matches().len() as i32
         }
 
         pub fn item_n(&self, index: i32) -> Match {
-            self.0[index].clone()
+            self.matches()[index].clone()
         }
     }
 
     impl IEnumerable_1<Match> for MatchCollection {
         fn GetEnumerator(&self) -> LrcPtr<dyn IEnumerator_1<Match>> {
-            ofArray(self.0.clone()).GetEnumerator()
+            let iter = mkMut(self.iter());
+            fromFunction(Func0::new(move || iter.get_mut().next()))
         }
     }
 }
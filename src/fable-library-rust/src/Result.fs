@@ -14,3 +14,69 @@ let bind binder result =
     match result with
     | Ok x -> binder x
     | Error e -> Error e
+
+let isOk result =
+    match result with
+    | Ok _ -> true
+    | Error _ -> false
+
+let isError result =
+    match result with
+    | Ok _ -> false
+    | Error _ -> true
+
+let contains value result =
+    match result with
+    | Ok x -> x = value
+    | Error _ -> false
+
+let count result =
+    match result with
+    | Ok _ -> 1
+    | Error _ -> 0
+
+let defaultValue defaultValue result =
+    match result with
+    | Ok x -> x
+    | Error _ -> defaultValue
+
+let defaultWith defThunk result =
+    match result with
+    | Ok x -> x
+    | Error e -> defThunk e
+
+let exists predicate result =
+    match result with
+    | Ok x -> predicate x
+    | Error _ -> false
+
+let fold folder state result =
+    match result with
+    | Ok x -> folder state x
+    | Error _ -> state
+
+let foldBack folder result state =
+    match result with
+    | Ok x -> folder x state
+    | Error _ -> state
+
+let forAll predicate result =
+    match result with
+    | Ok x -> predicate x
+    | Error _ -> true
+
+let iterate action result =
+    match result with
+    | Ok x -> action x
+    | Error _ -> ()
+
+let toOption result =
+    match result with
+    | Ok x -> Some x
+    | Error _ -> None
+
+// moved to Array.ofOption to avoid dependency
+// let toArray result = Array.ofOption (toOption result)
+
+// moved to List.ofOption to avoid dependency
+// let toList result = List.ofOption (toOption result)
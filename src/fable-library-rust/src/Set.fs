@@ -328,6 +328,93 @@ let rec filterAux f (s: Set<'T>) acc =
 
 let filter f s = filterAux f s empty
 
+let toArray (s: Set<'T>) =
+    let len = count s
+    let res = ResizeArray<_>(len)
+    iterate (fun x -> res.Add(x)) s
+    res |> asArray
+
+// Bulk construction and linear merges of sorted arrays
+
+let rec private ofSortedArrayAux (arr: 'T[]) lo hi : Set<'T> =
+    if lo > hi then
+        empty
+    else
+        let mid = lo + (hi - lo) / 2
+        let l = ofSortedArrayAux arr lo (mid - 1)
+        let r = ofSortedArrayAux arr (mid + 1) hi
+        mk l arr[mid] r
+
+// builds a balanced tree from a sorted array without duplicates
+let private ofSortedArray (arr: 'T[]) = ofSortedArrayAux arr 0 (arr.Length - 1)
+
+// keeps the first of equal elements, same as repeated add
+let private distinctSorted (arr: 'T[]) =
+    let res = ResizeArray<'T>(arr.Length)
+
+    for i = 0 to arr.Length - 1 do
+        if i = 0 || compare arr[i - 1] arr[i] <> 0 then
+            res.Add(arr[i])
+
+    res |> asArray
+
+let private mergeSorted
+    keepLeft
+    keepBoth
+    keepRight
+    (xs: 'T[])
+    (ys: 'T[])
+    : 'T[]
+    =
+    let res = ResizeArray<'T>()
+    let mutable i = 0
+    let mutable j = 0
+
+    while i < xs.Length && j < ys.Length do
+        let c = compare xs[i] ys[j]
+
+        if c < 0 then
+            if keepLeft then
+                res.Add(xs[i])
+
+            i <- i + 1
+        elif c > 0 then
+            if keepRight then
+                res.Add(ys[j])
+
+            j <- j + 1
+        else
+            if keepBoth then
+                res.Add(xs[i])
+
+            i <- i + 1
+            j <- j + 1
+
+    if keepLeft then
+        for k = i to xs.Length - 1 do
+            res.Add(xs[k])
+
+    if keepRight then
+        for k = j to ys.Length - 1 do
+            res.Add(ys[k])
+
+    res |> asArray
+
+[<Literal>]
+let private mergeTolerance = 4
+
+// linear merges are faster than tree operations when both trees
+// have similar sizes (heights), otherwise it's better to only
+// walk the smaller tree
+let private canMerge (s1: Set<'T>) (s2: Set<'T>) =
+    let h1 = height s1
+    let h2 = height s2
+    h1 > 1 && h2 > 1 && abs (h1 - h2) <= mergeTolerance
+
+let private merge keepLeft keepBoth keepRight (s1: Set<'T>) (s2: Set<'T>) =
+    mergeSorted keepLeft keepBoth keepRight (toArray s1) (toArray s2)
+    |> ofSortedArray
+
 let rec diffAux (s: Set<'T>) (acc: Set<'T>) =
     match acc |> getRoot with
     | None -> acc
@@ -340,9 +427,13 @@ let rec diffAux (s: Set<'T>) (acc: Set<'T>) =
             else
                 diffAux t.Left (diffAux t.Right (remove t.Key acc))
 
-let difference a b = diffAux b a
+let difference a b =
+    if canMerge a b then
+        merge true false false a b
+    else
+        diffAux b a
 
-let rec union (s1: Set<'T>) (s2: Set<'T>) =
+let rec unionAux (s1: Set<'T>) (s2: Set<'T>) =
     // Perf: tried bruteForce for low heights, but nothing significant
     match s1 |> getRoot with
     | None -> s2
@@ -362,10 +453,16 @@ let rec union (s1: Set<'T>) (s2: Set<'T>) =
                 t1.Height > t2.Height
             then
                 let lo, _, hi = split t1.Key s2 in
-                balance (union t1.Left lo) t1.Key (union t1.Right hi)
+                balance (unionAux t1.Left lo) t1.Key (unionAux t1.Right hi)
             else
                 let lo, _, hi = split t2.Key s1 in
-                balance (union t2.Left lo) t2.Key (union t2.Right hi)
+                balance (unionAux t2.Left lo) t2.Key (unionAux t2.Right hi)
+
+let union (s1: Set<'T>) (s2: Set<'T>) =
+    if canMerge s1 s2 then
+        merge true true true s1 s2
+    else
+        unionAux s1 s2
 
 let unionMany (sets: seq<Set<'T>>) = Seq.fold union empty sets
 
@@ -392,6 +489,8 @@ let rec intersectionAux b (s: Set<'T>) acc =
 let intersect a b =
     if isEmpty b then
         b
+    elif canMerge a b then
+        merge false true false a b
     else
         intersectionAux b a empty
 
@@ -619,22 +718,14 @@ let copyToArray s (arr: _[]) i =
         )
         s
 
-let toArray (s: Set<'T>) =
-    let len = count s
-    let res = ResizeArray<_>(len)
-    iterate (fun x -> res.Add(x)) s
-    res |> asArray
-
 let toList (s: Set<'T>) = foldBack (fun k acc -> k :: acc) s []
 
-let ofArray xs =
-    Array.fold (fun acc k -> add k acc) empty xs
+let ofArray (xs: 'T[]) =
+    xs |> Array.sort |> distinctSorted |> ofSortedArray
 
-let ofList xs =
-    List.fold (fun acc k -> add k acc) empty xs
+let ofList (xs: 'T list) = xs |> List.toArray |> ofArray
 
-let ofSeq xs =
-    Seq.fold (fun acc k -> add k acc) empty xs
+let ofSeq (xs: 'T seq) = xs |> Seq.toArray |> ofArray
 
 let toSeq (s: Set<'T>) =
     Seq.delay (fun () ->
@@ -0,0 +1,120 @@
+#[cfg(not(feature = "no_std"))]
+pub mod Stopwatch_ {
+
+    // -----------------------------------------------------------
+    // System.Diagnostics.Stopwatch
+    // -----------------------------------------------------------
+
+    // Timestamps are nanoseconds of the monotonic `std::time::Instant` clock,
+    // counted from the first timestamp taken by the process. Unlike DateTime,
+    // they are not affected by changes of the system clock.
+
+    use crate::Native_::{LrcPtr, MutCell};
+    use crate::TimeSpan_::{ticks_per_second, TimeSpan};
+
+    use std::time::Instant;
+
+    const frequency: i64 = 1_000_000_000;
+
+    fn origin() -> Instant {
+        static start: MutCell<Option<Instant>> = MutCell::new(None);
+        start.get_or_init(Instant::now)
+    }
+
+    fn timestamp() -> i64 {
+        origin().elapsed().as_nanos() as i64
+    }
+
+    // timestamp ticks to TimeSpan ticks
+    fn to_time_span(ticks: i64) -> TimeSpan {
+        TimeSpan::new_ticks(ticks / (frequency / ticks_per_second))
+    }
+
+    #[derive(Debug)]
+    pub struct Stopwatch {
+        elapsed: MutCell<i64>,
+        startTimestamp: MutCell<i64>,
+        isRunning: MutCell<bool>,
+    }
+
+    impl Stopwatch {
+        pub fn new_() -> LrcPtr<Stopwatch> {
+            LrcPtr::new(Stopwatch {
+                elapsed: MutCell::new(0),
+                startTimestamp: MutCell::new(0),
+                isRunning: MutCell::new(false),
+            })
+        }
+
+        pub fn startNew_() -> LrcPtr<Stopwatch> {
+            let sw = Self::new_();
+            sw.start();
+            sw
+        }
+
+        pub fn frequency() -> i64 {
+            frequency
+        }
+
+        pub fn isHighResolution() -> bool {
+            true
+        }
+
+        pub fn getTimestamp_() -> i64 {
+            timestamp()
+        }
+
+        pub fn getElapsedTime__n(startingTimestamp: i64) -> TimeSpan {
+            Self::getElapsedTime__nn(startingTimestamp, timestamp())
+        }
+
+        pub fn getElapsedTime__nn(startingTimestamp: i64, endingTimestamp: i64) -> TimeSpan {
+            to_time_span(endingTimestamp - startingTimestamp)
+        }
+
+        pub fn start(&self) {
+            if !self.isRunning.get() {
+                self.startTimestamp.set(timestamp());
+                self.isRunning.set(true);
+            }
+        }
+
+        pub fn stop(&self) {
+            if self.isRunning.get() {
+                self.elapsed.set(self.elapsedTicks());
+                self.isRunning.set(false);
+            }
+        }
+
+        pub fn reset(&self) {
+            self.elapsed.set(0);
+            self.isRunning.set(false);
+        }
+
+        pub fn restart(&self) {
+            self.elapsed.set(0);
+            self.startTimestamp.set(timestamp());
+            self.isRunning.set(true);
+        }
+
+        pub fn isRunning(&self) -> bool {
+            self.isRunning.get()
+        }
+
+        pub fn elapsedTicks(&self) -> i64 {
+            if self.isRunning.get() {
+                self.elapsed.get() + timestamp() - self.startTimestamp.get()
+            } else {
+                self.elapsed.get()
+            }
+        }
+
+        pub fn elapsed(&self) -> TimeSpan {
+            to_time_span(self.elapsedTicks())
+        }
+
+        pub fn elapsedMilliseconds(&self) -> i64 {
+            self.elapsedTicks() / (frequency / 1000)
+        }
+    }
+}
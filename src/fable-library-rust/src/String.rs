@@ -5,8 +5,10 @@ pub mod String_ {
     // Strings
     // -----------------------------------------------------------
 
-    use crate::Native_::{compare, Func1, Func2, Lrc, String, ToString, Vec};
+    use crate::Native_::{alloc::format, compare, seq, seq_to_iter, Any, Func1, Func2, Lrc, LrcPtr, String, ToString, Vec};
     use crate::NativeArray_::{array_from, Array};
+    use crate::Globalization_::{formatNumber2, CompareInfo, CompareOptions_IgnoreCase, CompareOptions_None, CompareOptions_Ordinal, CompareOptions_OrdinalIgnoreCase, CultureInfo, FormatNumber, NumberValue};
+    use crate::Utf8Text_::{StandardFormat, Utf8Format};
 
     use core::cmp::Ordering;
     use core::hash::{Hash, Hasher};
@@ -31,6 +33,11 @@ pub mod String_ {
         }
 
         pub fn string(s: &'static str) -> string {
+            fromStatic(s)
+        }
+
+        // Literals are copied to the heap, use enum_string to avoid that.
+        pub fn fromStatic(s: &'static str) -> string {
             LrcStr(Lrc::from(s))
         }
 
@@ -51,7 +58,7 @@ pub mod String_ {
     // -----------------------------------------------------------
     // Enum string type: enum of static/small/alloc string.
     // -----------------------------------------------------------
-    // TODO: maybe intern strings, maybe add length in chars.
+    // TODO: maybe add length in chars.
 
     mod EnumString {
         use crate::Native_::{Lrc, String};
@@ -80,6 +87,11 @@ pub mod String_ {
         }
 
         pub fn string(s: &'static str) -> string {
+            fromStatic(s)
+        }
+
+        // Literals are borrowed, so they can also be used in consts.
+        pub const fn fromStatic(s: &'static str) -> string {
             LrcStr::Static(s)
         }
 
@@ -130,6 +142,55 @@ pub mod String_ {
     #[cfg(not(feature = "enum_string"))]
     pub use HeapString::*;
 
+    // -----------------------------------------------------------
+    // Intern pool: a per-thread set of interned strings.
+    // -----------------------------------------------------------
+
+    #[cfg(not(feature = "no_std"))]
+    mod InternPool {
+        use super::string;
+        use std::cell::RefCell;
+        use std::collections::HashSet;
+
+        // interned strings are never evicted, as in .NET
+        thread_local! {
+            static interned: RefCell<HashSet<string>> = RefCell::new(HashSet::new());
+        }
+
+        pub fn intern(s: string) -> string {
+            interned.with(|p| {
+                let mut p = p.borrow_mut();
+                match p.get(s.as_str()) {
+                    Some(res) => res.clone(),
+                    None => {
+                        p.insert(s.clone());
+                        s
+                    }
+                }
+            })
+        }
+
+        // Not interned strings give null, which is the empty string here.
+        pub fn isInterned(s: string) -> string {
+            interned.with(|p| p.borrow().get(s.as_str()).cloned()).unwrap_or_default()
+        }
+    }
+
+    #[cfg(feature = "no_std")]
+    mod InternPool {
+        use super::string;
+
+        pub fn intern(s: string) -> string {
+            s // no pooling when no_std
+        }
+
+        pub fn isInterned(_s: string) -> string {
+            string("")
+        }
+    }
+
+    pub use InternPool::{intern, isInterned};
+
     // -----------------------------------------------------------
     // macros
     // -----------------------------------------------------------
@@ -157,6 +218,13 @@ pub mod String_ {
     // traits
     // -----------------------------------------------------------
 
+    // consistent with Hash and Eq, so sets of strings can be searched by &str
+    impl core::borrow::Borrow<str> for string {
+        fn borrow(&self) -> &str {
+            self.as_str()
+        }
+    }
+
     impl core::convert::AsRef<str> for string {
         fn as_ref(&self) -> &str {
             self.as_str()
@@ -190,7 +258,7 @@ pub mod String_ {
 
     impl From<&'static str> for string {
         fn from(s: &'static str) -> Self {
-            string(s)
+            fromStatic(s)
         }
     }
 
@@ -206,6 +274,18 @@ pub mod String_ {
         }
     }
 
+    impl From<string> for String {
+        fn from(s: string) -> Self {
+            String::from(s.as_str())
+        }
+    }
+
+    impl From<&string> for String {
+        fn from(s: &string) -> Self {
+            String::from(s.as_str())
+        }
+    }
+
     impl Hash for string {
         #[inline]
         fn hash<H: Hasher>(&self, state: &mut H) {
@@ -268,12 +348,26 @@ pub mod String_ {
         unsafe { core::char::from_u32_unchecked(code) }
     }
 
+    // Simple (one to one) case mappings, like .NET, so that the
+    // length of a string never changes when changing its case.
+
     pub fn toLowerChar(c: char) -> char {
-        c.to_lowercase().next().unwrap()
+        if c.is_ascii() {
+            c.to_ascii_lowercase()
+        } else if c == '\u{130}' {
+            // the full mapping adds a combining dot above
+            'i'
+        } else {
+            let mut lower = c.to_lowercase();
+            match (lower.next(), lower.next()) {
+                (Some(l), None) => l,
+                _ => c,
+            }
+        }
     }
 
     pub fn toUpperChar(c: char) -> char {
-        c.to_uppercase().next().unwrap()
+        fold_case(c)
     }
 
     pub fn ofChar(c: char) -> string {
@@ -321,6 +415,15 @@ pub mod String_ {
         fromIter(a.iter().copied().skip(i as usize).take(count as usize))
     }
 
+    pub fn create<S: 'static>(length: i32, state: S, action: Func2<Array<char>, S, ()>) -> string {
+        if length < 0 {
+            panic!("Non-negative number required. (Parameter 'length')");
+        }
+        let chars: Array<char> = array_from(core::iter::repeat_n('\0', length as usize).collect());
+        action(chars.clone(), state);
+        fromChars(chars)
+    }
+
     pub fn containsChar(s: string, c: char) -> bool {
         s.contains(c)
     }
@@ -329,9 +432,62 @@ pub mod String_ {
         s.contains(p.as_str())
     }
 
+    // -----------------------------------------------------------
+    // Case-insensitive ordinal comparisons (OrdinalIgnoreCase)
+    // -----------------------------------------------------------
+
+    // Simple (one to one) uppercase mapping, same as .NET ordinal casing,
+    // so strings are compared char by char without allocating copies.
+    pub fn fold_case(c: char) -> char {
+        if c.is_ascii() {
+            c.to_ascii_uppercase()
+        } else {
+            let mut upper = c.to_uppercase();
+            match (upper.next(), upper.next()) {
+                (Some(u), None) => u,
+                _ => c,
+            }
+        }
+    }
+
+    fn fold_chars(s: &str) -> impl DoubleEndedIterator<Item = char> + '_ {
+        s.chars().map(fold_case)
+    }
+
+    pub fn equals_ignore_case(s1: &str, s2: &str) -> bool {
+        if s1.is_ascii() && s2.is_ascii() {
+            s1.eq_ignore_ascii_case(s2)
+        } else {
+            fold_chars(s1).eq(fold_chars(s2))
+        }
+    }
+
+    pub fn compare_ignore_case(s1: &str, s2: &str) -> Ordering {
+        fold_chars(s1).cmp(fold_chars(s2))
+    }
+
+    pub fn starts_with_ignore_case(s: &str, prefix: &str) -> bool {
+        let mut chars = fold_chars(s);
+        fold_chars(prefix).all(|c| chars.next() == Some(c))
+    }
+
+    pub fn ends_with_ignore_case(s: &str, suffix: &str) -> bool {
+        let mut chars = fold_chars(s).rev();
+        fold_chars(suffix).rev().all(|c| chars.next() == Some(c))
+    }
+
+    // Hash consistent with equals_ignore_case (FNV-1a)
+    pub fn hash_ignore_case(s: &str) -> i32 {
+        let mut hash: u32 = 0x811c9dc5;
+        for c in fold_chars(s) {
+            hash = (hash ^ c as u32).wrapping_mul(0x01000193);
+        }
+        hash as i32
+    }
+
     pub fn equalsOrdinal(s1: string, s2: string, ignoreCase: bool) -> bool {
         if ignoreCase {
-            s1.to_uppercase().eq(&s2.to_uppercase())
+            equals_ignore_case(&s1, &s2)
         } else {
             s1.eq(&s2)
         }
@@ -339,7 +495,7 @@ pub mod String_ {
 
     pub fn compareOrdinal(s1: string, s2: string, ignoreCase: bool) -> i32 {
         if ignoreCase {
-            compare(&s1.to_uppercase(), &s2.to_uppercase())
+            compare_ignore_case(&s1, &s2) as i32
         } else {
             compare(&s1, &s2)
         }
@@ -357,7 +513,7 @@ pub mod String_ {
 
     pub fn startsWith(s: string, p: string, ignoreCase: bool) -> bool {
         if ignoreCase {
-            s.to_uppercase().starts_with(&p.to_uppercase())
+            starts_with_ignore_case(&s, &p)
         } else {
             s.starts_with(p.as_str())
         }
@@ -369,12 +525,141 @@ pub mod String_ {
 
     pub fn endsWith(s: string, p: string, ignoreCase: bool) -> bool {
         if ignoreCase {
-            s.to_uppercase().ends_with(&p.to_uppercase())
+            ends_with_ignore_case(&s, &p)
         } else {
             s.ends_with(p.as_str())
         }
     }
 
+    // -----------------------------------------------------------
+    // StringComparison
+    // -----------------------------------------------------------
+
+    pub const StringComparison_CurrentCulture: i32 = 0;
+    pub const StringComparison_CurrentCultureIgnoreCase: i32 = 1;
+    pub const StringComparison_InvariantCulture: i32 = 2;
+    pub const StringComparison_InvariantCultureIgnoreCase: i32 = 3;
+    pub const StringComparison_Ordinal: i32 = 4;
+    pub const StringComparison_OrdinalIgnoreCase: i32 = 5;
+
+    // Comparisons are done by the CompareInfo of the culture,
+    // ordinal comparisons of whole strings are done here.
+    fn compare_info(comparisonType: i32) -> (LrcPtr<CompareInfo>, i32) {
+        let culture = match comparisonType {
+            StringComparison_CurrentCulture | StringComparison_CurrentCultureIgnoreCase => CultureInfo::currentCulture_(),
+            StringComparison_InvariantCulture..=StringComparison_OrdinalIgnoreCase => CultureInfo::invariantCulture_(),
+            _ => panic!("The string comparison type passed in is currently not supported. (Parameter 'comparisonType')"),
+        };
+        let options = match comparisonType {
+            StringComparison_CurrentCultureIgnoreCase | StringComparison_InvariantCultureIgnoreCase => CompareOptions_IgnoreCase,
+            StringComparison_Ordinal => CompareOptions_Ordinal,
+            StringComparison_OrdinalIgnoreCase => CompareOptions_OrdinalIgnoreCase,
+            _ => CompareOptions_None,
+        };
+        (culture.compareInfo(), options)
+    }
+
+    fn is_ordinal(comparisonType: i32) -> Option<bool> {
+        match comparisonType {
+            StringComparison_Ordinal => Some(false),
+            StringComparison_OrdinalIgnoreCase => Some(true),
+            _ => None,
+        }
+    }
+
+    pub fn compareUsing(s1: string, s2: string, comparisonType: i32) -> i32 {
+        match is_ordinal(comparisonType) {
+            Some(ignoreCase) => compareOrdinal(s1, s2, ignoreCase),
+            None => {
+                let (ci, options) = compare_info(comparisonType);
+                ci.compare_ssn(s1, s2, options)
+            }
+        }
+    }
+
+    pub fn compareUsing2(s1: string, i1: i32, s2: string, i2: i32, count: i32, comparisonType: i32) -> i32 {
+        let s1 = substring2(s1, i1, count);
+        let s2 = substring2(s2, i2, count);
+        compareUsing(s1, s2, comparisonType)
+    }
+
+    pub fn compareWith(s1: string, s2: string, culture: LrcPtr<CultureInfo>, options: i32) -> i32 {
+        culture.compareInfo().compare_ssn(s1, s2, options)
+    }
+
+    pub fn compareWith2(s1: string, i1: i32, s2: string, i2: i32, count: i32, culture: LrcPtr<CultureInfo>, options: i32) -> i32 {
+        let s1 = substring2(s1, i1, count);
+        let s2 = substring2(s2, i2, count);
+        compareWith(s1, s2, culture, options)
+    }
+
+    pub fn equalsUsing(s1: string, s2: string, comparisonType: i32) -> bool {
+        match is_ordinal(comparisonType) {
+            Some(ignoreCase) => equalsOrdinal(s1, s2, ignoreCase),
+            None => compareUsing(s1, s2, comparisonType) == 0,
+        }
+    }
+
+    pub fn startsWithUsing(s: string, p: string, comparisonType: i32) -> bool {
+        match is_ordinal(comparisonType) {
+            Some(ignoreCase) => startsWith(s, p, ignoreCase),
+            None => {
+                let (ci, options) = compare_info(comparisonType);
+                ci.isPrefix_ssn(s, p, options)
+            }
+        }
+    }
+
+    pub fn endsWithUsing(s: string, p: string, comparisonType: i32) -> bool {
+        match is_ordinal(comparisonType) {
+            Some(ignoreCase) => endsWith(s, p, ignoreCase),
+            None => {
+                let (ci, options) = compare_info(comparisonType);
+                ci.isSuffix_ssn(s, p, options)
+            }
+        }
+    }
+
+    pub fn containsUsing(s: string, p: string, comparisonType: i32) -> bool {
+        indexOfUsing(s, p, comparisonType) >= 0
+    }
+
+    pub fn indexOfUsing(s: string, p: string, comparisonType: i32) -> i32 {
+        let (ci, options) = compare_info(comparisonType);
+        ci.indexOf_ssn(s, p, options)
+    }
+
+    pub fn indexOfUsing2(s: string, p: string, i: i32, comparisonType: i32) -> i32 {
+        match indexOfUsing(substring(s, i), p, comparisonType) {
+            -1 => -1,
+            k => i + k,
+        }
+    }
+
+    pub fn indexOfUsing3(s: string, p: string, i: i32, count: i32, comparisonType: i32) -> i32 {
+        match indexOfUsing(substring2(s, i, count), p, comparisonType) {
+            -1 => -1,
+            k => i + k,
+        }
+    }
+
+    pub fn lastIndexOfUsing(s: string, p: string, comparisonType: i32) -> i32 {
+        let (ci, options) = compare_info(comparisonType);
+        ci.lastIndexOf_ssn(s, p, options)
+    }
+
+    pub fn lastIndexOfUsing2(s: string, p: string, i: i32, comparisonType: i32) -> i32 {
+        lastIndexOfUsing(substring2(s, 0, i + 1), p, comparisonType)
+    }
+
+    pub fn lastIndexOfUsing3(s: string, p: string, i: i32, count: i32, comparisonType: i32) -> i32 {
+        let start = i - count + 1;
+        match lastIndexOfUsing(substring2(s, start, count), p, comparisonType) {
+            -1 => -1,
+            k => start + k,
+        }
+    }
+
     pub fn isEmpty(s: string) -> bool {
         s.is_empty()
     }
@@ -383,6 +668,59 @@ pub mod String_ {
         s.trim().is_empty()
     }
 
+    // -----------------------------------------------------------
+    // Unicode normalization
+    // -----------------------------------------------------------
+
+    pub const NormalizationForm_FormC: i32 = 1;
+    pub const NormalizationForm_FormD: i32 = 2;
+    pub const NormalizationForm_FormKC: i32 = 5;
+    pub const NormalizationForm_FormKD: i32 = 6;
+
+    #[cfg(feature = "globalization")]
+    fn invalid_normalization_form() -> ! {
+        panic!("Invalid or unsupported normalization form. (Parameter 'normalizationForm')")
+    }
+
+    #[cfg(feature = "globalization")]
+    pub fn isNormalized(s: string) -> bool {
+        isNormalized2(s, NormalizationForm_FormC)
+    }
+
+    #[cfg(feature = "globalization")]
+    pub fn isNormalized2(s: string, normalizationForm: i32) -> bool {
+        use unicode_normalization::{is_nfc, is_nfd, is_nfkc, is_nfkd};
+        match normalizationForm {
+            NormalizationForm_FormC | NormalizationForm_FormD | NormalizationForm_FormKC | NormalizationForm_FormKD
+                if s.is_ascii() => true,
+            NormalizationForm_FormC => is_nfc(&s),
+            NormalizationForm_FormD => is_nfd(&s),
+            NormalizationForm_FormKC => is_nfkc(&s),
+            NormalizationForm_FormKD => is_nfkd(&s),
+            _ => invalid_normalization_form(),
+        }
+    }
+
+    #[cfg(feature = "globalization")]
+    pub fn normalize(s: string) -> string {
+        normalize2(s, NormalizationForm_FormC)
+    }
+
+    // ASCII strings and already normalized strings are returned as is
+    #[cfg(feature = "globalization")]
+    pub fn normalize2(s: string, normalizationForm: i32) -> string {
+        use unicode_normalization::UnicodeNormalization;
+        if isNormalized2(s.clone(), normalizationForm) {
+            return s;
+        }
+        match normalizationForm {
+            NormalizationForm_FormC => fromIter(s.nfc()),
+            NormalizationForm_FormD => fromIter(s.nfd()),
+            NormalizationForm_FormKC => fromIter(s.nfkc()),
+            _ => fromIter(s.nfkd()),
+        }
+    }
+
     pub fn trim(s: string) -> string {
         fromSlice(s.trim())
     }
@@ -420,20 +758,67 @@ pub mod String_ {
     }
 
     pub fn toLower(s: string) -> string {
-        fromString(s.to_lowercase())
+        if s.is_ascii() {
+            fromString(s.to_ascii_lowercase())
+        } else {
+            fromIter(s.chars().map(toLowerChar))
+        }
     }
 
     pub fn toUpper(s: string) -> string {
-        fromString(s.to_uppercase())
+        if s.is_ascii() {
+            fromString(s.to_ascii_uppercase())
+        } else {
+            fromIter(s.chars().map(toUpperChar))
+        }
     }
 
+    // The total length is computed first, so the result
+    // is written into a single pre-sized buffer.
+
     pub fn concat(a: Array<string>) -> string {
-        fromIter(a.iter().flat_map(|s| s.chars()))
+        let len = a.iter().map(|s| s.len()).sum();
+        let mut res = String::with_capacity(len);
+        for s in a.iter() {
+            res.push_str(s);
+        }
+        fromString(res)
     }
 
     pub fn join(sep: string, a: Array<string>) -> string {
-        let v: Vec<&str> = a.iter().map(|s| s.as_str()).collect();
-        fromString(v.join(&sep))
+        let count = a.len();
+        let len = a.iter().map(|s| s.len()).sum::<usize>()
+            + sep.len() * count.saturating_sub(1);
+        let mut res = String::with_capacity(len);
+        for (i, s) in a.iter().enumerate() {
+            if i > 0 {
+                res.push_str(&sep);
+            }
+            res.push_str(s);
+        }
+        fromString(res)
+    }
+
+    // Sequences are consumed in a single pass, without
+    // collecting the items into an intermediate array.
+
+    pub fn concatSeq(xs: seq<string>) -> string {
+        let mut res = String::new();
+        for s in seq_to_iter(&xs) {
+            res.push_str(&s);
+        }
+        fromString(res)
+    }
+
+    pub fn joinSeq(sep: string, xs: seq<string>) -> string {
+        let mut res = String::new();
+        for (i, s) in seq_to_iter(&xs).enumerate() {
+            if i > 0 {
+                res.push_str(&sep);
+            }
+            res.push_str(&s);
+        }
+        fromString(res)
     }
 
     pub fn replace(s: string, old: string, new: string) -> string {
@@ -618,55 +1003,94 @@ pub mod String_ {
         }
     }
 
-    fn withSplitOptions(a: Vec<&str>, count: i32, options: i32) -> Array<string> {
-        let mut a: Vec<&str> = a;
-        if (options & 1 != 0) {
-            a = a.into_iter().filter(|s| !s.is_empty()).collect();
+    // Splits at the given (start, length) separator positions, following
+    // .NET for the count limit and the RemoveEmptyEntries/TrimEntries options.
+    fn splitAt(s: &str, seps: &[(usize, usize)], count: i32, options: i32) -> Array<string> {
+        if count < 0 {
+            panic!("Count cannot be less than zero. (Parameter 'count')");
         }
-        if (options & 2 != 0) {
-            a = a.into_iter().map(|s| s.trim()).collect();
-        }
-        if (count >= 0) {
-            a = a.into_iter().take(count as usize).collect()
+        let removeEmpty = options & 1 != 0;
+        let entry = |i: usize, j: usize| {
+            if options & 2 != 0 { s[i..j].trim() } else { &s[i..j] }
+        };
+        let mut res: Vec<string> = Vec::new();
+        if count > 0 {
+            let count = count as usize;
+            let mut pos = 0;
+            let mut i = 0;
+            if count > 1 {
+                while i < seps.len() && res.len() + 1 < count {
+                    let (start, len) = seps[i];
+                    let e = entry(pos, start);
+                    if !e.is_empty() || !removeEmpty {
+                        res.push(fromSlice(e));
+                    }
+                    pos = start + len;
+                    i += 1;
+                }
+                // skip the empty entries before the last one
+                while removeEmpty && i < seps.len() && entry(pos, seps[i].0).is_empty() {
+                    pos = seps[i].0 + seps[i].1;
+                    i += 1;
+                }
+            }
+            let e = entry(pos, s.len());
+            if !e.is_empty() || !removeEmpty {
+                res.push(fromSlice(e));
+            }
         }
-        let a = a.into_iter().map(|s| fromSlice(s)).collect();
-        array_from(a)
+        array_from(res)
+    }
+
+    fn charSeparators(s: &str, pred: impl Fn(char) -> bool) -> Vec<(usize, usize)> {
+        s.char_indices()
+            .filter(|(_, c)| pred(*c))
+            .map(|(i, c)| (i, c.len_utf8()))
+            .collect()
     }
 
+    // An empty separator doesn't split, like in .NET.
     pub fn split(s: string, p: string, count: i32, options: i32) -> Array<string> {
-        let a: Vec<&str> =
-            if (count >= 0) && (options & 1 == 0) {
-                if p.is_empty() {
-                    s.splitn(count as usize, char::is_whitespace).collect()
-                } else {
-                    s.splitn(count as usize, p.as_str()).collect()
-                }
+        let seps: Vec<(usize, usize)> =
+            if p.is_empty() {
+                Vec::new()
             } else {
-                if p.is_empty() {
-                    s.split(char::is_whitespace).collect()
-                } else {
-                    s.split(p.as_str()).collect()
-                }
+                s.match_indices(p.as_str()).map(|(i, m)| (i, m.len())).collect()
             };
-        withSplitOptions(a, count, options)
+        splitAt(&s, &seps, count, options)
     }
 
+    // An empty array of separators splits at whitespace.
     pub fn splitChars(s: string, p: Array<char>, count: i32, options: i32) -> Array<string> {
-        let a: Vec<&str> =
-            if (count >= 0) && (options & 1 == 0) {
-                if p.is_empty() {
-                    s.splitn(count as usize, char::is_whitespace).collect()
-                } else {
-                    s.splitn(count as usize, p.as_slice()).collect()
-                }
+        let seps =
+            if p.is_empty() {
+                charSeparators(&s, char::is_whitespace)
             } else {
-                if p.is_empty() {
-                    s.split(char::is_whitespace).collect()
-                } else {
-                    s.split(p.as_slice()).collect()
-                }
+                charSeparators(&s, |c| p.contains(&c))
             };
-        withSplitOptions(a, count, options)
+        splitAt(&s, &seps, count, options)
+    }
+
+    // At each position the first matching separator wins, empty ones are ignored.
+    pub fn splitStrings(s: string, p: Array<string>, count: i32, options: i32) -> Array<string> {
+        if p.is_empty() {
+            return splitChars(s, array_from(Vec::new()), count, options);
+        }
+        let mut seps: Vec<(usize, usize)> = Vec::new();
+        let mut i = 0;
+        while i < s.len() {
+            let rest = &s[i..];
+            match p.iter().find(|sep| !sep.is_empty() && rest.starts_with(sep.as_str())) {
+                Some(sep) => {
+                    seps.push((i, sep.len()));
+                    i += sep.len();
+                }
+                None => {
+                    i += rest.chars().next().map_or(1, |c| c.len_utf8());
+                }
+            }
+        }
+        splitAt(&s, &seps, count, options)
     }
 
     pub fn toCharArray(s: string) -> Array<char> {
@@ -682,8 +1106,11 @@ pub mod String_ {
     // -----------------------------------------------------------
 
     pub fn collect(mapping: Func1<char, string>, s: string) -> string {
-        let v: Vec<string> = s.chars().map(|c| mapping(c)).collect();
-        fromIter(v.iter().flat_map(|s| s.chars()))
+        let mut res = String::with_capacity(s.len());
+        for c in s.chars() {
+            res.push_str(&mapping(c));
+        }
+        fromString(res)
     }
 
     pub fn exists(predicate: Func1<char, bool>, s: string) -> bool {
@@ -724,4 +1151,286 @@ pub mod String_ {
         // fromString(s.repeat(count as usize))
         fromIter(core::iter::repeat(&s).take(count as usize).flat_map(|s| s.chars()))
     }
+
+    // -----------------------------------------------------------
+    // FormattableString
+    // -----------------------------------------------------------
+
+    // The holes are boxed so they can be inspected, and the string is
+    // rendered once at creation, when the argument types are still known.
+
+    #[derive(Clone)]
+    pub struct FormattableString {
+        format: string,
+        args: Array<LrcPtr<dyn Any>>,
+        value: string,
+    }
+
+    impl FormattableString {
+        pub fn new(format: string, args: Array<LrcPtr<dyn Any>>, value: string) -> LrcPtr<FormattableString> {
+            LrcPtr::new(FormattableString { format, args, value })
+        }
+
+        pub fn format(&self) -> string {
+            self.format.clone()
+        }
+
+        pub fn argumentCount(&self) -> i32 {
+            self.args.len() as i32
+        }
+
+        pub fn getArgument(&self, index: i32) -> LrcPtr<dyn Any> {
+            self.args[index].clone()
+        }
+
+        pub fn getArguments(&self) -> Array<LrcPtr<dyn Any>> {
+            array_from(self.args.to_vec())
+        }
+
+        pub fn toString(&self) -> string {
+            self.value.clone()
+        }
+    }
+
+    impl core::fmt::Display for FormattableString {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            write!(f, "{}", self.value)
+        }
+    }
+
+    impl core::fmt::Debug for FormattableString {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            f.debug_struct("FormattableString")
+                .field("format", &self.format)
+                .field("argumentCount", &self.args.len())
+                .finish()
+        }
+    }
+
+    // -----------------------------------------------------------
+    // CompositeFormat
+    // -----------------------------------------------------------
+
+    // The format string is parsed once into literal and hole segments.
+    // The arguments are converted to strings at the call site, where the
+    // format specifiers of constant format strings are applied. The format
+    // specifiers left in the holes are applied to the numbers parsed back.
+
+    #[derive(Clone, Debug)]
+    enum Segment {
+        Literal(String),
+        Hole { index: usize, alignment: i32, format: Option<String> },
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct CompositeFormat {
+        format: string,
+        segments: Vec<Segment>,
+        minimumArgumentCount: i32,
+    }
+
+    fn invalid_format() -> ! {
+        panic!("Input string was not in a correct format.")
+    }
+
+    fn parse_segments(format: &str) -> Vec<Segment> {
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+        let mut chars = format.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '}' => invalid_format(),
+                '{' => {
+                    let mut hole = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some('{') | None => invalid_format(),
+                            Some(c) => hole.push(c),
+                        }
+                    }
+                    // {index[,alignment][:format]}
+                    let (spec, format) = match hole.split_once(':') {
+                        Some((spec, format)) => (spec, Some(format.to_string())),
+                        None => (hole.as_str(), None),
+                    };
+                    let mut parts = spec.splitn(2, ',');
+                    let index = parts.next().unwrap_or("").trim();
+                    let index = index.parse::<usize>().unwrap_or_else(|_| invalid_format());
+                    let alignment = match parts.next() {
+                        Some(a) => a.trim().parse::<i32>().unwrap_or_else(|_| invalid_format()),
+                        None => 0,
+                    };
+                    if !literal.is_empty() {
+                        segments.push(Segment::Literal(core::mem::take(&mut literal)));
+                    }
+                    segments.push(Segment::Hole { index, alignment, format });
+                }
+                c => literal.push(c),
+            }
+        }
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(literal));
+        }
+        segments
+    }
+
+    // Positive alignments pad on the left, negative ones on the right
+    fn push_aligned(res: &mut String, s: &str, alignment: i32) {
+        let width = alignment.unsigned_abs() as usize;
+        let pad = width.saturating_sub(s.chars().count());
+        if alignment > 0 {
+            res.extend(core::iter::repeat_n(' ', pad));
+        }
+        res.push_str(s);
+        if alignment < 0 {
+            res.extend(core::iter::repeat_n(' ', pad));
+        }
+    }
+
+    // Parses an argument formatted by toString back to a number,
+    // decimals are kept exact as the digits are not rounded again
+    fn parse_number(s: &str) -> Option<NumberValue> {
+        let digits = s.strip_prefix('-').unwrap_or(s);
+        if !digits.starts_with(|c: char| c.is_ascii_digit()) {
+            return None;
+        }
+        if let Ok(x) = s.parse::<i32>() {
+            return Some(x.number_value());
+        }
+        if let Ok(x) = s.parse::<i64>() {
+            return Some(x.number_value());
+        }
+        if let Some((int, frac)) = digits.split_once('.') {
+            let all_digits = |p: &str| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit());
+            if all_digits(int) && all_digits(frac) {
+                let mantissa = int.bytes().chain(frac.bytes()).try_fold(0u128, |acc, b| {
+                    acc.checked_mul(10)?.checked_add((b - b'0') as u128)
+                });
+                if let Some(mantissa) = mantissa {
+                    let negative = s.starts_with('-');
+                    return Some(NumberValue::Decimal { negative, mantissa, scale: frac.len() as u32 });
+                }
+            }
+        }
+        s.parse::<f64>().ok().map(|x| x.number_value())
+    }
+
+    // Like .NET, the format is ignored by values that are not formattable
+    fn format_hole(s: &str, format: &str) -> String {
+        match parse_number(s) {
+            Some(value) => formatNumber2(value, fromSlice(format), CultureInfo::currentCulture_()).to_string(),
+            None => s.to_string(),
+        }
+    }
+
+    impl CompositeFormat {
+        pub fn parse(format: string) -> LrcPtr<CompositeFormat> {
+            let segments = parse_segments(format.as_str());
+            let minimumArgumentCount = segments
+                .iter()
+                .map(|s| match s {
+                    Segment::Hole { index, .. } => *index as i32 + 1,
+                    Segment::Literal(_) => 0,
+                })
+                .max()
+                .unwrap_or(0);
+            LrcPtr::new(CompositeFormat { format, segments, minimumArgumentCount })
+        }
+
+        pub fn format(&self) -> string {
+            self.format.clone()
+        }
+
+        pub fn minimumArgumentCount(&self) -> i32 {
+            self.minimumArgumentCount
+        }
+
+        pub fn apply(&self, args: Array<string>) -> string {
+            if (args.len() as i32) < self.minimumArgumentCount {
+                panic!("Index (zero based) must be greater than or equal to zero and less than the size of the argument list.");
+            }
+            let mut res = String::new();
+            for segment in self.segments.iter() {
+                match segment {
+                    Segment::Literal(s) => res.push_str(s),
+                    Segment::Hole { index, alignment, format } => {
+                        let arg = args[*index as i32].as_str();
+                        match format {
+                            Some(format) => push_aligned(&mut res, &format_hole(arg, format), *alignment),
+                            None => push_aligned(&mut res, arg, *alignment),
+                        }
+                    }
+                }
+            }
+            fromString(res)
+        }
+    }
+
+    // String.Format
+    pub fn format(format: string, args: Array<string>) -> string {
+        CompositeFormat::parse(format).apply(args)
+    }
+
+    // Aligns a formatted hole of String.Format or of an interpolated string
+    pub fn alignString(s: string, alignment: i32) -> string {
+        let mut res = String::new();
+        push_aligned(&mut res, s.as_str(), alignment);
+        fromString(res)
+    }
+
+    // Formats a single argument of String.Format with a standard format specifier
+    pub fn formatValue<T: Utf8Format>(value: T, format: string) -> string {
+        let format = StandardFormat::parse__s(format);
+        let mut res = String::new();
+        match value.format_utf8(format, &mut res) {
+            Ok(()) => fromString(res),
+            Err(_) => panic!("Format specifier was invalid."),
+        }
+    }
+
+    // Pads a printf hole to its width, zeros go after the sign of a number
+    fn printf_pad(s: &str, flags: &str, width: i32, is_number: bool) -> string {
+        let pad = (width.max(0) as usize).saturating_sub(s.chars().count());
+        if pad == 0 {
+            fromSlice(s)
+        } else if flags.contains('-') {
+            fromIter(s.chars().chain(core::iter::repeat_n(' ', pad)))
+        } else if flags.contains('0') && is_number {
+            let sign = if s.starts_with(['-', '+', ' ']) { 1 } else { 0 };
+            let (sign, digits) = s.split_at(sign);
+            fromIter(sign.chars().chain(core::iter::repeat_n('0', pad)).chain(digits.chars()))
+        } else {
+            fromIter(core::iter::repeat_n(' ', pad).chain(s.chars()))
+        }
+    }
+
+    // Formats a printf hole with the '-', '0' and '*' flags and a width
+    pub fn printfPad(s: string, flags: string, width: i32) -> string {
+        printf_pad(&s, &flags, width, false)
+    }
+
+    // Formats a numeric printf hole like F#, with a .NET format string
+    // in the invariant culture and the '+', ' ', '-' and '0' flags
+    pub fn printfNumber<T: FormatNumber>(value: T, format: string, flags: string, width: i32) -> string {
+        let s = formatNumber2(value, format, CultureInfo::invariantCulture_());
+        let s = if s.starts_with('-') {
+            s
+        } else if flags.contains('+') {
+            fromString(format!("+{s}"))
+        } else if flags.contains(' ') {
+            fromString(format!(" {s}"))
+        } else {
+            s
+        };
+        printf_pad(&s, &flags, width, true)
+    }
 }
@@ -2,23 +2,31 @@ namespace System.Text
 
 open Global_
 
+// The chars are kept in a single growable buffer, so appending doesn't
+// fragment, and indexing, inserting and removing don't need a copy.
 type StringBuilder(value: string, capacity: int) =
-    let buf = ResizeArray<string>(capacity)
+    let buf = ResizeArray<char>(max capacity value.Length)
 
     do
         if not (System.String.IsNullOrEmpty value) then
-            buf.Add(value)
+            buf.AddRange(value.ToCharArray())
 
     new(capacity: int) = StringBuilder("", capacity)
     new(value: string) = StringBuilder(value, 16)
     new() = StringBuilder("", 16)
 
     member x.Append(s: string) =
-        buf.Add(s)
+        if not (System.String.IsNullOrEmpty s) then
+            buf.AddRange(s.ToCharArray())
+
         x
 
     member x.Append(o: bool) = x.Append(string o)
-    member x.Append(c: char) = x.Append(string c)
+
+    member x.Append(c: char) =
+        buf.Add(c)
+        x
+
     member x.Append(o: int8) = x.Append(string o)
     member x.Append(o: byte) = x.Append(string o)
     member x.Append(o: int16) = x.Append(string o)
@@ -30,23 +38,142 @@ type StringBuilder(value: string, capacity: int) =
     member x.Append(o: float32) = x.Append(string o)
     member x.Append(o: float) = x.Append(string o)
 
+    member x.Append(c: char, repeatCount: int) =
+        if repeatCount < 0 then
+            invalidArg "repeatCount" "Count cannot be less than zero."
+
+        for _ in 1..repeatCount do
+            buf.Add(c)
+
+        x
+
     member x.Append(s: string, index: int, count: int) =
         x.Append(s.Substring(index, count))
 
-    member x.Append(cs: char[]) = x.Append(System.String(cs))
+    member x.Append(cs: char[]) =
+        buf.AddRange(cs)
+        x
+
+    member x.Append(cs: char[], index: int, count: int) =
+        x.Append(System.String(cs, index, count))
+
     member x.Append(sb: StringBuilder) = x.Append(sb.ToString())
     // member x.Append(o: obj) = x.Append(string o)
     // member x.AppendFormat(fmt: string, o: obj) = x.Append(System.String.Format(fmt, o))
     // member x.AppendFormat(provider: System.IFormatProvider, fmt: string, o: obj) = x.Append(System.String.Format(provider, fmt, o))
+
+    member x.AppendJoin(separator: string, values: string[]) =
+        x.Append(System.String.Join(separator, values))
+
+    member x.AppendJoin(separator: char, values: string[]) =
+        x.Append(System.String.Join(string separator, values))
+
     member x.AppendLine() = x.Append(System.Environment.NewLine)
     member x.AppendLine(s: string) = x.Append(s).AppendLine()
 
+    member x.Insert(index: int, s: string) =
+        if index < 0 || index > buf.Count then
+            invalidArg "index" "Index was out of range. Must be non-negative and less than or equal to the size of the collection."
+
+        if not (System.String.IsNullOrEmpty s) then
+            buf.InsertRange(index, s.ToCharArray())
+
+        x
+
+    member x.Insert(index: int, s: string, count: int) =
+        if count < 0 then
+            invalidArg "count" "Count cannot be less than zero."
+
+        x.Insert(index, System.String.Concat(Array.create count s))
+
+    member x.Insert(index: int, c: char) = x.Insert(index, string c)
+    member x.Insert(index: int, cs: char[]) = x.Insert(index, System.String(cs))
+    member x.Insert(index: int, o: bool) = x.Insert(index, string o)
+    member x.Insert(index: int, o: int32) = x.Insert(index, string o)
+    member x.Insert(index: int, o: int64) = x.Insert(index, string o)
+    member x.Insert(index: int, o: float) = x.Insert(index, string o)
+
+    member x.Remove(startIndex: int, length: int) =
+        if startIndex < 0 || length < 0 || startIndex + length > buf.Count then
+            invalidArg "length" "Index was out of range. Must be non-negative and less than or equal to the size of the collection."
+
+        buf.RemoveRange(startIndex, length)
+        x
+
+    member x.Replace(oldValue: string, newValue: string, startIndex: int, count: int) =
+        if startIndex < 0 || count < 0 || startIndex + count > buf.Count then
+            invalidArg "count" "Index was out of range. Must be non-negative and less than or equal to the size of the collection."
+
+        if System.String.IsNullOrEmpty oldValue then
+            invalidArg "oldValue" "String cannot be of zero length."
+
+        let s = System.String(asArray buf, startIndex, count)
+        let replaced = s.Replace(oldValue, (if isNull newValue then "" else newValue))
+
+        if replaced <> s then
+            buf.RemoveRange(startIndex, count)
+            buf.InsertRange(startIndex, replaced.ToCharArray())
+
+        x
+
+    member x.Replace(oldValue: string, newValue: string) =
+        x.Replace(oldValue, newValue, 0, buf.Count)
+
+    member x.Replace(oldChar: char, newChar: char, startIndex: int, count: int) =
+        if startIndex < 0 || count < 0 || startIndex + count > buf.Count then
+            invalidArg "count" "Index was out of range. Must be non-negative and less than or equal to the size of the collection."
+
+        for i in startIndex .. startIndex + count - 1 do
+            if buf[i] = oldChar then
+                buf[i] <- newChar
+
+        x
+
+    member x.Replace(oldChar: char, newChar: char) =
+        x.Replace(oldChar, newChar, 0, buf.Count)
+
     member x.Clear() =
         buf.Clear()
         x
 
-    member x.Length = buf |> asArray |> Array.sumBy (fun s -> s.Length)
-    override _.ToString() = System.String.Concat(buf |> asArray)
+    member x.Chars
+        with get (index: int) = buf[index]
+        and set (index: int) (value: char) = buf[index] <- value
+
+    member x.Length
+        with get () = buf.Count
+        and set (value: int) =
+            if value < 0 then
+                invalidArg "value" "Length cannot be less than zero."
+
+            if value < buf.Count then
+                buf.RemoveRange(value, buf.Count - value)
+            else
+                x.Append('\000', value - buf.Count) |> ignore
+
+    member x.Capacity
+        with get () = buf.Capacity
+        and set (value: int) =
+            if value < buf.Count then
+                invalidArg "value" "Capacity is less than this StringBuilder's current length."
+
+            buf.Capacity <- value
+
+    member x.MaxCapacity = System.Int32.MaxValue
+
+    member x.EnsureCapacity(capacity: int) =
+        if capacity < 0 then
+            invalidArg "capacity" "Capacity must be positive."
+
+        if buf.Capacity < capacity then
+            buf.Capacity <- capacity
+
+        buf.Capacity
+
+    member x.CopyTo(sourceIndex: int, destination: char[], destinationIndex: int, count: int) =
+        for i in 0 .. count - 1 do
+            destination[destinationIndex + i] <- buf[sourceIndex + i]
+
+    override _.ToString() = System.String(asArray buf)
 
-    member x.ToString(index: int, count: int) =
-        x.ToString().Substring(index, count)
+    member x.ToString(index: int, count: int) = System.String(asArray buf, index, count)
@@ -1,16 +1,21 @@
 #[cfg(feature = "datetime")]
 pub mod TimeOnly_ {
     use crate::{
-        DateTime_::{duration_to_ticks, ticks_to_duration, DateTime},
-        Native_::{compare, MutCell, ToString},
+        DateTime_::{
+            fold_leap_second, format_date_time, parse_date_time, ticks_to_duration, time_to_ticks, DateTime,
+            DateTimeKind, DateTimeStyles_None,
+        },
+        Globalization_::{CultureInfo, DateTimeFormatInfo},
+        NativeArray_::Array,
+        Native_::{alloc::format, compare, LrcPtr, MutCell, String, ToString},
         String_::{fromString, string},
-        TimeSpan_::{nanoseconds_per_tick, ticks_per_day, TimeSpan},
+        TimeSpan_::{nanoseconds_per_tick, ticks_per_day, ticks_per_hour, ticks_per_minute, TimeSpan},
     };
-    use chrono::{DateTime as CDateTime, NaiveTime, ParseResult, Timelike};
+    use chrono::{DateTime as CDateTime, NaiveDate, NaiveTime, ParseResult, Timelike};
     use core::ops::Sub;
 
     #[repr(transparent)]
-    #[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
     pub struct TimeOnly(NaiveTime);
 
     impl core::fmt::Display for TimeOnly {
@@ -19,6 +24,29 @@ pub mod TimeOnly_ {
         }
     }
 
+    impl core::str::FromStr for TimeOnly {
+        type Err = chrono::ParseError;
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::try_parse_str(s.trim()).map(|nt| TimeOnly(fold_leap_second(nt)))
+        }
+    }
+
+    // ISO 8601 text, like the "o" format
+    #[cfg(feature = "serde")]
+    impl serde::Serialize for TimeOnly {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(self.toString(string("o")).as_str())
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl<'de> serde::Deserialize<'de> for TimeOnly {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let s = <crate::Native_::String as serde::Deserialize>::deserialize(deserializer)?;
+            s.parse().map_err(serde::de::Error::custom)
+        }
+    }
+
     pub fn compareTo(x: TimeOnly, y: TimeOnly) -> i32 {
         compare(&x, &y)
     }
@@ -146,34 +174,34 @@ pub mod TimeOnly_ {
         }
 
         pub fn ticks(&self) -> i64 {
-            duration_to_ticks(self.0 - NaiveTime::MIN)
+            time_to_ticks(&self.0)
         }
 
         pub fn toTimeSpan(&self) -> TimeSpan {
             TimeSpan::new_ticks(self.ticks())
         }
 
-        pub fn add(&self, ts: TimeSpan) -> TimeOnly {
-            let ticks = (self.ticks() + ts.ticks()) % ticks_per_day;
-            let ticks = if ticks < 0 {
-                ticks + ticks_per_day
+        // the added ticks wrap around midnight, like in .NET
+        fn add_ticks(&self, ticks: i64) -> (TimeOnly, i32) {
+            let days = (ticks / ticks_per_day) as i32;
+            let ticks = self.ticks() + ticks % ticks_per_day;
+            if ticks < 0 {
+                (Self::new_ticks(ticks + ticks_per_day), days - 1)
+            } else if ticks >= ticks_per_day {
+                (Self::new_ticks(ticks - ticks_per_day), days + 1)
             } else {
-                ticks
-            };
-            Self::new_ticks(ticks)
+                (Self::new_ticks(ticks), days)
+            }
+        }
+
+        pub fn add(&self, ts: TimeSpan) -> TimeOnly {
+            self.add_ticks(ts.ticks()).0
         }
 
         pub fn add2(&self, ts: TimeSpan, res: &MutCell<i32>) -> TimeOnly {
-            let ticks = self.ticks() + ts.ticks();
-            let days = ticks / ticks_per_day;
-            let ticks = ticks % ticks_per_day;
-            let (days, ticks) = if ticks < 0 {
-                (days - 1, ticks + ticks_per_day)
-            } else {
-                (days, ticks)
-            };
-            res.set(days as i32);
-            Self::new_ticks(ticks)
+            let (t, days) = self.add_ticks(ts.ticks());
+            res.set(days);
+            t
         }
 
         pub fn op_Subtraction(t1: TimeOnly, t2: TimeOnly) -> TimeSpan {
@@ -187,50 +215,154 @@ pub mod TimeOnly_ {
         }
 
         pub fn addHours(&self, hours: f64) -> TimeOnly {
-            self.add(TimeSpan::from_hours(hours))
+            self.add_ticks((hours * ticks_per_hour as f64) as i64).0
+        }
+
+        pub fn addHours2(&self, hours: f64, res: &MutCell<i32>) -> TimeOnly {
+            let (t, days) = self.add_ticks((hours * ticks_per_hour as f64) as i64);
+            res.set(days);
+            t
         }
 
         pub fn addMinutes(&self, minutes: f64) -> TimeOnly {
-            self.add(TimeSpan::from_minutes(minutes))
+            self.add_ticks((minutes * ticks_per_minute as f64) as i64).0
+        }
+
+        pub fn addMinutes2(&self, minutes: f64, res: &MutCell<i32>) -> TimeOnly {
+            let (t, days) = self.add_ticks((minutes * ticks_per_minute as f64) as i64);
+            res.set(days);
+            t
         }
 
         pub fn toString(&self, format: string) -> string {
-            let fmt = match format.as_str() {
-                "" | "t" => "%H:%M".to_string(),
-                "o" | "O" => "%H:%M:%S%.f".to_string(),
-                "r" | "R" | "T" => "%H:%M:%S".to_string(),
-                //TODO: support more formats, custom formats, etc.
-                _ => format
-                    .replace("hh", "%H")
-                    .replace("mm", "%M")
-                    .replace("ss", "%S")
-                    .replace("ffffff", "%6f")
-                    .replace("fff", "%3f"),
-            };
-            let df = self.0.format(&fmt);
-            fromString(df.to_string())
+            self.toString2(format, CultureInfo::currentCulture_())
+        }
+
+        pub fn toString2(&self, format: string, culture: LrcPtr<CultureInfo>) -> string {
+            let (pattern, dtfi) = time_pattern(format.as_str(), &culture);
+            let ndt = NaiveDate::from_ymd_opt(1, 1, 1).unwrap().and_time(self.0);
+            fromString(format_date_time(&ndt, DateTimeKind::Unspecified, 0, &pattern, dtfi))
         }
 
         fn try_parse_str(s: &str) -> ParseResult<NaiveTime> {
             s.parse::<NaiveTime>()
-                .or(NaiveTime::parse_from_str(s, "%m/%d/%Y %H:%M:%S%.f"))
-                .or(NaiveTime::parse_from_str(s, "%m/%d/%Y %I:%M:%S %P"))
+        }
+
+        // the culture's long (with an optional fraction) and short time patterns,
+        // then ISO 8601
+        fn try_parse_culture(s: &str, culture: &CultureInfo) -> Option<NaiveTime> {
+            let dtfi = culture.date_time_format();
+            let long_time = format!("{}.FFFFFFF", dtfi.longTimePattern());
+            [long_time, dtfi.shortTimePattern().to_string()]
+                .iter()
+                .filter_map(|pattern| parse_date_time(s, pattern, dtfi))
+                .find_map(|parsed| parsed.to_naive())
+                .map(|ndt| ndt.time())
+                .or_else(|| Self::try_parse_str(s).ok())
+                .filter(|nt| nt.nanosecond() < 1_000_000_000) // no leap seconds
+        }
+
+        fn parse_error(s: &str, culture: &CultureInfo) -> ! {
+            // like .NET, a valid DateTime with a date is a different error
+            if DateTime::try_parse_culture(s.trim(), culture, DateTimeStyles_None).is_some() {
+                panic!("String '{}' contains parts which are not specific to the TimeOnly.", s)
+            } else {
+                panic!("String '{}' was not recognized as a valid TimeOnly.", s)
+            }
         }
 
         pub fn tryParse(s: string, res: &MutCell<TimeOnly>) -> bool {
-            match Self::try_parse_str(s.trim()) {
-                Ok(nt) => {
+            Self::tryParse2(s, CultureInfo::currentCulture_(), res)
+        }
+
+        pub fn tryParse2(s: string, culture: LrcPtr<CultureInfo>, res: &MutCell<TimeOnly>) -> bool {
+            match Self::try_parse_culture(s.trim(), &culture) {
+                Some(nt) => {
                     res.set(TimeOnly(nt));
                     true
                 }
-                Err(e) => false,
+                None => false,
             }
         }
 
         pub fn parse(s: string) -> TimeOnly {
-            match Self::try_parse_str(s.trim()) {
-                Ok(nt) => TimeOnly(nt),
-                Err(e) => panic!("The input string {} was not in a correct format.", s),
+            Self::parse2(s, CultureInfo::currentCulture_())
+        }
+
+        pub fn parse2(s: string, culture: LrcPtr<CultureInfo>) -> TimeOnly {
+            match Self::try_parse_culture(s.trim(), &culture) {
+                Some(nt) => TimeOnly(nt),
+                None => Self::parse_error(&s, &culture),
+            }
+        }
+
+        // DateTimeStyles are ignored, surrounding white space is always allowed
+        fn try_parse_exact(s: &str, formats: &[string], culture: &CultureInfo) -> Option<NaiveTime> {
+            formats.iter().find_map(|format| {
+                let (pattern, dtfi) = time_pattern(format.as_str(), culture);
+                let parsed = parse_date_time(s, &pattern, dtfi)?;
+                parsed.to_naive().map(|ndt| ndt.time())
+            })
+        }
+
+        pub fn parseExact(s: string, formats: Array<string>) -> TimeOnly {
+            Self::parseExact2(s, formats, CultureInfo::currentCulture_())
+        }
+
+        pub fn parseExact2(s: string, formats: Array<string>, culture: LrcPtr<CultureInfo>) -> TimeOnly {
+            match Self::try_parse_exact(&s, formats.as_slice(), &culture) {
+                Some(nt) => TimeOnly(nt),
+                None => panic!("String '{}' was not recognized as a valid TimeOnly.", s),
+            }
+        }
+
+        pub fn tryParseExact(s: string, formats: Array<string>, res: &MutCell<TimeOnly>) -> bool {
+            Self::tryParseExact2(s, formats, CultureInfo::currentCulture_(), res)
+        }
+
+        pub fn tryParseExact2(
+            s: string,
+            formats: Array<string>,
+            culture: LrcPtr<CultureInfo>,
+            res: &MutCell<TimeOnly>,
+        ) -> bool {
+            match Self::try_parse_exact(&s, formats.as_slice(), &culture) {
+                Some(nt) => {
+                    res.set(TimeOnly(nt));
+                    true
+                }
+                None => false,
+            }
+        }
+    }
+
+    // The custom pattern of a TimeOnly format. Like .NET, standard formats are
+    // "t", "T", "o" and "r", and custom formats cannot have date specifiers.
+    fn time_pattern(format: &str, culture: &CultureInfo) -> (String, &'static DateTimeFormatInfo) {
+        let dtfi = culture.date_time_format();
+        let invariant = || CultureInfo::invariantCulture_().date_time_format();
+        match format {
+            "" | "t" => (dtfi.shortTimePattern().to_string(), dtfi),
+            "T" => (dtfi.longTimePattern().to_string(), dtfi),
+            "o" | "O" => ("HH':'mm':'ss'.'fffffff".to_string(), invariant()),
+            "r" | "R" => ("HH':'mm':'ss".to_string(), invariant()),
+            _ if format.chars().count() == 1 => panic!("Input string was not in a correct format."),
+            _ => {
+                let mut quote = None;
+                let mut escaped = false;
+                for c in format.chars() {
+                    match (quote, c) {
+                        _ if escaped => escaped = false,
+                        (None, '\\') => escaped = true,
+                        (None, '\'' | '"') => quote = Some(c),
+                        (Some(q), _) if c == q => quote = None,
+                        (None, 'd' | 'M' | 'y' | '/' | 'z' | 'K') => {
+                            panic!("Input string was not in a correct format.")
+                        }
+                        _ => (),
+                    }
+                }
+                (format.to_string(), dtfi)
             }
         }
     }
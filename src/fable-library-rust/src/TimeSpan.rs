@@ -1,9 +1,13 @@
 pub mod TimeSpan_ {
-    use crate::Native_::{compare, MutCell, ToString, Vec};
+    use crate::Convert_::ParseError;
+    use crate::Globalization_::CultureInfo;
+    use crate::NativeArray_::Array;
+    use crate::Native_::{alloc::format, compare, LrcPtr, MutCell, String, ToString, Vec};
     use crate::String_::{fromString, string};
+    use core::fmt::Write;
     use core::ops::{Add, Div, Mul, Sub};
 
-    #[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
     pub struct TimeSpan {
         ticks: i64,
     }
@@ -26,6 +30,130 @@ pub mod TimeSpan_ {
         }
     }
 
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub struct ParseTimeSpanError;
+
+    impl core::fmt::Display for ParseTimeSpanError {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            write!(f, "String was not recognized as a valid TimeSpan.")
+        }
+    }
+
+    impl core::error::Error for ParseTimeSpanError {}
+
+    impl core::str::FromStr for TimeSpan {
+        type Err = ParseTimeSpanError;
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::try_parse_str(s).map_err(|_| ParseTimeSpanError)
+        }
+    }
+
+    // ISO 8601 duration text, e.g. "P1DT2H30M" or "-PT0.5S"
+    #[cfg(feature = "serde")]
+    impl serde::Serialize for TimeSpan {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&to_iso8601_duration(self.ticks))
+        }
+    }
+
+    // also accepts the constant ("c") format, used by System.Text.Json
+    #[cfg(feature = "serde")]
+    impl<'de> serde::Deserialize<'de> for TimeSpan {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let s = <crate::Native_::String as serde::Deserialize>::deserialize(deserializer)?;
+            let s = s.trim();
+            match parse_iso8601_duration(s) {
+                Some(ticks) => Ok(TimeSpan::from_ticks(ticks)),
+                None => s.parse().map_err(serde::de::Error::custom),
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    fn to_iso8601_duration(ticks: i64) -> crate::Native_::String {
+        let t = ticks.unsigned_abs();
+        let days = t / ticks_per_day as u64;
+        let hours = t / ticks_per_hour as u64 % 24;
+        let mins = t / ticks_per_minute as u64 % 60;
+        let secs = t / ticks_per_second as u64 % 60;
+        let frac = t % ticks_per_second as u64;
+        let mut res = crate::Native_::String::new();
+        if ticks < 0 {
+            res.push('-');
+        }
+        res.push('P');
+        if days > 0 {
+            let _ = write!(res, "{}D", days);
+        }
+        if hours > 0 || mins > 0 || secs > 0 || frac > 0 || days == 0 {
+            res.push('T');
+            if hours > 0 {
+                let _ = write!(res, "{}H", hours);
+            }
+            if mins > 0 {
+                let _ = write!(res, "{}M", mins);
+            }
+            if secs > 0 || frac > 0 || (hours == 0 && mins == 0) {
+                let _ = write!(res, "{}", secs);
+                if frac > 0 {
+                    let digits = format_args!("{:07}", frac).to_string();
+                    let _ = write!(res, ".{}", digits.trim_end_matches('0'));
+                }
+                res.push('S');
+            }
+        }
+        res
+    }
+
+    // days, hours, minutes and seconds only, years and months have no fixed length
+    #[cfg(feature = "serde")]
+    fn parse_iso8601_duration(s: &str) -> Option<i64> {
+        let (isNeg, s) = match s.strip_prefix('-') {
+            Some(s) => (true, s),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let s = s.strip_prefix('P').or(s.strip_prefix('p'))?;
+        let (date, time) = match s.find(['T', 't']) {
+            Some(i) => (&s[..i], Some(&s[i + 1..])),
+            None => (s, None),
+        };
+        if date.is_empty() && time.is_none_or(str::is_empty) {
+            return None;
+        }
+        let mut ticks: i64 = 0;
+        let mut add = |part: &str, units: &[(char, i64)]| -> Option<()> {
+            let mut rest = part;
+            let mut last = 0;
+            while !rest.is_empty() {
+                let i = rest.find(|c: char| c.is_ascii_alphabetic())?;
+                let (num, unit) = (&rest[..i], rest[i..].chars().next()?.to_ascii_uppercase());
+                let pos = units.iter().position(|(u, _)| *u == unit)?;
+                if num.is_empty() || pos < last {
+                    return None;
+                }
+                let scale = units[pos].1;
+                let value = if unit == 'S' {
+                    let secs = num.replace(',', ".").parse::<f64>().ok()?;
+                    (secs * scale as f64).round() as i64
+                } else {
+                    num.parse::<i64>().ok()?.checked_mul(scale)?
+                };
+                ticks = ticks.checked_add(value)?;
+                last = pos + 1;
+                rest = &rest[i + 1..];
+            }
+            Some(())
+        };
+        add(date, &[('W', 7 * ticks_per_day), ('D', ticks_per_day)])?;
+        if let Some(time) = time {
+            if time.is_empty() {
+                return None;
+            }
+            add(time, &[('H', ticks_per_hour), ('M', ticks_per_minute), ('S', ticks_per_second)])?;
+        }
+        Some(if isNeg { -ticks } else { ticks })
+    }
+
     pub fn compareTo(x: TimeSpan, y: TimeSpan) -> i32 {
         compare(&x, &y)
     }
@@ -163,77 +291,38 @@ pub mod TimeSpan_ {
         }
 
         pub fn to_string(&self, format: string) -> string {
+            self.to_string2(format, CultureInfo::currentCulture_())
+        }
+
+        // the culture only gives the decimal separator of "g" and "G"
+        pub fn to_string2(&self, format: string, culture: LrcPtr<CultureInfo>) -> string {
             let sign = if self.ticks < 0 { "-" } else { "" };
-            let days = self.days().abs();
-            let days = if days == 0 {
-                "".to_string()
-            } else {
-                format_args!("{}.", days).to_string()
-            };
-            let hours = self.hours().abs();
-            let mins = self.minutes().abs();
-            let secs = self.seconds().abs();
-            let frac = (self.ticks % ticks_per_second).abs();
-            let frac = if frac == 0 {
-                "".to_string()
-            } else {
-                format_args!(".{:07}", frac).to_string()
-            };
+            let t = Components::new(self.ticks);
             let s = match format.as_str() {
-                "" | "c" => format_args!(
-                    "{}{}{:02}:{:02}:{:02}{}",
-                    sign, days, hours, mins, secs, frac
-                )
-                .to_string(),
-                //TODO: support more formats, custom formats, etc.
-                _ => format_args!(
-                    "{}{}{:02}:{:02}:{:02}{}",
-                    sign, days, hours, mins, secs, frac
-                )
-                .to_string(),
+                "" | "c" | "t" | "T" => {
+                    let days = if t.days > 0 { format!("{}.", t.days) } else { String::new() };
+                    let frac = if t.frac > 0 { format!(".{:07}", t.frac) } else { String::new() };
+                    format!("{}{}{:02}:{:02}:{:02}{}", sign, days, t.hours, t.mins, t.secs, frac)
+                }
+                "g" => {
+                    let sep = culture.number_format().numberDecimalSeparator();
+                    let days = if t.days > 0 { format!("{}:", t.days) } else { String::new() };
+                    let frac = format!("{:07}", t.frac);
+                    let frac = frac.trim_end_matches('0');
+                    let frac = if frac.is_empty() { String::new() } else { format!("{}{}", sep, frac) };
+                    format!("{}{}{}:{:02}:{:02}{}", sign, days, t.hours, t.mins, t.secs, frac)
+                }
+                "G" => {
+                    let sep = culture.number_format().numberDecimalSeparator();
+                    format!("{}{}:{:02}:{:02}:{:02}{}{:07}", sign, t.days, t.hours, t.mins, t.secs, sep, t.frac)
+                }
+                _ => format_custom(&t, format.as_str()),
             };
             fromString(s)
         }
 
-        fn try_parse_str(s: &str) -> Result<TimeSpan, ()> {
-            let error = Err(());
-            let s = s.trim();
-            let isNeg = s.starts_with('-');
-            let s = if isNeg { &s[1..] } else { s };
-            let hms = s.split(':').collect::<Vec<&str>>();
-            if s.contains('-') || hms.len() > 3 {
-                error
-            } else {
-                let (d, h, m, s) = if hms.len() == 1 {
-                    (hms[0], "0", "0", "0")
-                } else {
-                    let (d, h) = if let Some(dh) = hms[0].split_once('.') {
-                        dh
-                    } else {
-                        ("0", hms[0])
-                    };
-                    let m = hms[1];
-                    let s = if hms.len() > 2 { hms[2] } else { "0" };
-                    (d, h, m, s)
-                };
-                let d = d.parse::<u32>();
-                let h = h.parse::<u32>();
-                let m = m.parse::<u32>();
-                let s = s.parse::<f64>();
-                match (d, h, m, s) {
-                    (Ok(d), Ok(h), Ok(m), Ok(s))
-                        if d < 10675200 && h < 24 && m < 60 && s < 60.0 =>
-                    {
-                        let ticks = d as i64 * ticks_per_day
-                            + h as i64 * ticks_per_hour
-                            + m as i64 * ticks_per_minute
-                            + (s * ticks_per_second as f64) as i64;
-                        let ticks = if isNeg { -ticks } else { ticks };
-                        Ok(Self::from_ticks(ticks))
-                    }
-                    _ => error,
-                }
-            }
+        fn try_parse_str(s: &str) -> Result<TimeSpan, ParseFailure> {
+            parse_components(s.trim(), &GENERAL_FORMS)
         }
 
         pub fn try_parse(s: string, res: &MutCell<TimeSpan>) -> bool {
@@ -249,11 +338,216 @@ pub mod TimeSpan_ {
         pub fn parse(s: string) -> TimeSpan {
             match Self::try_parse_str(s.trim()) {
                 Ok(ts) => ts,
-                Err(e) => panic!("String '{}' was not recognized as a valid TimeSpan.", s),
+                Err(e) => parse_error(&s, e),
+            }
+        }
+
+        // like `parse`, for Rust callers
+        pub fn try_parse_rs(s: &str) -> Result<TimeSpan, ParseError> {
+            Self::try_parse_str(s.trim()).map_err(|e| to_parse_error(s, e))
+        }
+
+        // only the standard formats are supported, the format provider is ignored
+        fn parse_exact_str(s: &str, formats: &[string]) -> Result<TimeSpan, ParseFailure> {
+            let mut res = Err(ParseFailure::Format);
+            for format in formats.iter() {
+                let forms: &[&str] = match format.as_str() {
+                    "c" | "t" | "T" => &CONSTANT_FORMS,
+                    "g" => &SHORT_FORMS,
+                    "G" => &LONG_FORMS,
+                    _ => &[],
+                };
+                res = parse_components(s, forms);
+                if res.is_ok() {
+                    break;
+                }
+            }
+            res
+        }
+
+        pub fn parse_exact(s: string, formats: Array<string>) -> TimeSpan {
+            match Self::parse_exact_str(s.trim(), formats.as_slice()) {
+                Ok(ts) => ts,
+                Err(e) => parse_error(&s, e),
+            }
+        }
+
+        pub fn try_parse_exact(s: string, formats: Array<string>, res: &MutCell<TimeSpan>) -> bool {
+            match Self::parse_exact_str(s.trim(), formats.as_slice()) {
+                Ok(ts) => {
+                    res.set(ts);
+                    true
+                }
+                Err(e) => false,
             }
         }
     }
 
+    // -----------------------------------------------------------
+    // formatting
+    // -----------------------------------------------------------
+
+    // the absolute values of the parts of a TimeSpan
+    struct Components {
+        days: u64,
+        hours: u64,
+        mins: u64,
+        secs: u64,
+        frac: u64,
+    }
+
+    impl Components {
+        fn new(ticks: i64) -> Components {
+            let t = ticks.unsigned_abs();
+            Components {
+                days: t / ticks_per_day as u64,
+                hours: t / ticks_per_hour as u64 % 24,
+                mins: t / ticks_per_minute as u64 % 60,
+                secs: t / ticks_per_second as u64 % 60,
+                frac: t % ticks_per_second as u64,
+            }
+        }
+    }
+
+    fn format_error() -> ! {
+        panic!("Input string was not in a correct format.")
+    }
+
+    fn push_padded(res: &mut String, value: u64, width: usize) {
+        let _ = write!(res, "{:0width$}", value, width = width);
+    }
+
+    // Like .NET, custom formats have no sign and any literal must be quoted
+    // or escaped, e.g. "d\\.hh\\:mm\\:ss" or "hh':'mm"
+    fn format_custom(t: &Components, format: &str) -> String {
+        let chars: Vec<char> = format.chars().collect();
+        if chars.len() == 1 {
+            format_error(); // not a standard format
+        }
+        let mut res = String::new();
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            let count = chars[i..].iter().take_while(|&&x| x == c).count();
+            match c {
+                'd' if count <= 8 => push_padded(&mut res, t.days, count),
+                'h' if count <= 2 => push_padded(&mut res, t.hours, count),
+                'm' if count <= 2 => push_padded(&mut res, t.mins, count),
+                's' if count <= 2 => push_padded(&mut res, t.secs, count),
+                'f' if count <= 7 => push_padded(&mut res, t.frac / 10_u64.pow(7 - count as u32), count),
+                'F' if count <= 7 => {
+                    let mut digits = String::new();
+                    push_padded(&mut digits, t.frac / 10_u64.pow(7 - count as u32), count);
+                    res.push_str(digits.trim_end_matches('0'));
+                }
+                '\'' | '"' => {
+                    let end = chars[i + 1..].iter().position(|&x| x == c).unwrap_or_else(|| format_error());
+                    res.extend(&chars[i + 1..i + 1 + end]);
+                    i += end + 2;
+                    continue;
+                }
+                '\\' => {
+                    let next = *chars.get(i + 1).unwrap_or_else(|| format_error());
+                    res.push(next);
+                    i += 2;
+                    continue;
+                }
+                // a single custom specifier, e.g. "%h"
+                '%' if !matches!(chars.get(i + 1), None | Some('%')) => {
+                    i += 1;
+                    continue;
+                }
+                _ => format_error(),
+            }
+            i += count;
+        }
+        res
+    }
+
+    // -----------------------------------------------------------
+    // parsing
+    // -----------------------------------------------------------
+
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    enum ParseFailure {
+        Format,
+        Overflow,
+    }
+
+    fn to_parse_error(s: &str, e: ParseFailure) -> ParseError {
+        ParseError::new(match e {
+            ParseFailure::Format => format!("String '{}' was not recognized as a valid TimeSpan.", s),
+            ParseFailure::Overflow => format!(
+                "The TimeSpan string '{}' could not be parsed because at least one of the numeric components is out of range or contains too many digits.",
+                s
+            ),
+        })
+    }
+
+    fn parse_error(s: &str, e: ParseFailure) -> ! {
+        panic!("{}", to_parse_error(s, e))
+    }
+
+    // The accepted forms, as the separators between the numeric components:
+    // d, h:m, h:m:s, d.h:m, d.h:m:s, h:m:s.f, d.h:m:s.f, d:h:m:s and d:h:m:s.f
+    const GENERAL_FORMS: [&str; 9] = ["", ":", "::", ".:", ".::", "::.", ".::.", ":::", ":::."];
+    // "c": [-][d.]hh:mm:ss[.fffffff]
+    const CONSTANT_FORMS: [&str; 4] = ["::", ".::", "::.", ".::."];
+    // "g": [-][d:]h:mm:ss[.FFFFFFF]
+    const SHORT_FORMS: [&str; 4] = ["::", ":::", "::.", ":::."];
+    // "G": [-]d:hh:mm:ss.fffffff
+    const LONG_FORMS: [&str; 1] = [":::."];
+
+    fn parse_components(s: &str, forms: &[&str]) -> Result<TimeSpan, ParseFailure> {
+        let (isNeg, s) = match s.strip_prefix('-') {
+            Some(s) => (true, s),
+            None => (false, s),
+        };
+        // numbers separated by single '.' or ':' characters
+        let mut numbers: Vec<&str> = Vec::new();
+        let mut separators = String::new();
+        let mut start = 0;
+        for (i, c) in s.char_indices() {
+            match c {
+                '0'..='9' => (),
+                '.' | ':' => {
+                    numbers.push(&s[start..i]);
+                    separators.push(c);
+                    start = i + 1;
+                }
+                _ => return Err(ParseFailure::Format),
+            }
+        }
+        numbers.push(&s[start..]);
+        if numbers.iter().any(|n| n.is_empty()) || !forms.contains(&separators.as_str()) {
+            return Err(ParseFailure::Format);
+        }
+        let has_days = numbers.len() == 1 || separators.starts_with('.') || separators.starts_with(":::");
+        let has_fraction = separators.len() > 1 && separators.ends_with('.');
+        let value = |n: &str| n.parse::<i64>().map_err(|_| ParseFailure::Overflow);
+        let mut numbers = numbers.into_iter();
+        let days = if has_days { value(numbers.next().unwrap())? } else { 0 };
+        let hours = numbers.next().map_or(Ok(0), value)?;
+        let mins = numbers.next().map_or(Ok(0), value)?;
+        let (secs, frac) = if has_fraction {
+            let secs = value(numbers.next().unwrap())?;
+            (secs, numbers.next().unwrap())
+        } else {
+            (numbers.next().map_or(Ok(0), value)?, "")
+        };
+        if days > 10_675_199 || hours > 23 || mins > 59 || secs > 59 || frac.len() > 7 {
+            return Err(ParseFailure::Overflow);
+        }
+        let frac_ticks = if frac.is_empty() { 0 } else { value(frac)? * 10_i64.pow(7 - frac.len() as u32) };
+        let ticks = (days as i128 * ticks_per_day as i128)
+            + (hours * ticks_per_hour + mins * ticks_per_minute + secs * ticks_per_second + frac_ticks) as i128;
+        let ticks = if isNeg { -ticks } else { ticks };
+        match i64::try_from(ticks) {
+            Ok(ticks) => Ok(TimeSpan::from_ticks(ticks)),
+            Err(_) => Err(ParseFailure::Overflow),
+        }
+    }
+
     impl Add<TimeSpan> for TimeSpan {
         type Output = TimeSpan;
 
@@ -292,13 +586,38 @@ pub mod TimeSpan_ {
         }
     }
 
+    // like .NET, the ticks are rounded to even and checked for overflow
+    fn from_double_ticks(ticks: f64) -> TimeSpan {
+        if ticks.is_nan() || ticks < i64::MIN as f64 || ticks > i64::MAX as f64 {
+            panic!("TimeSpan overflowed because the duration is too long.");
+        }
+        // core-only rounding, the cast truncates towards zero
+        let whole = ticks as i64;
+        let frac = ticks - whole as f64;
+        let (half, step) = if frac < 0.0 { (-frac, -1) } else { (frac, 1) };
+        if half > 0.5 || (half == 0.5 && whole % 2 != 0) {
+            TimeSpan::from_ticks(whole + step)
+        } else {
+            TimeSpan::from_ticks(whole)
+        }
+    }
+
     impl Mul<f64> for TimeSpan {
         type Output = TimeSpan;
 
         fn mul(self, rhs: f64) -> Self::Output {
-            TimeSpan {
-                ticks: ((self.ticks as f64) * rhs) as i64,
+            if rhs.is_nan() {
+                panic!("TimeSpan does not accept floating point Not-a-Number values. (Parameter 'factor')");
             }
+            from_double_ticks(self.ticks as f64 * rhs)
+        }
+    }
+
+    impl Mul<TimeSpan> for f64 {
+        type Output = TimeSpan;
+
+        fn mul(self, rhs: TimeSpan) -> Self::Output {
+            rhs * self
         }
     }
 
@@ -306,9 +625,10 @@ pub mod TimeSpan_ {
         type Output = TimeSpan;
 
         fn div(self, rhs: f64) -> Self::Output {
-            TimeSpan {
-                ticks: ((self.ticks as f64) / rhs) as i64,
+            if rhs.is_nan() {
+                panic!("TimeSpan does not accept floating point Not-a-Number values. (Parameter 'divisor')");
             }
+            from_double_ticks(self.ticks as f64 / rhs)
         }
     }
 }
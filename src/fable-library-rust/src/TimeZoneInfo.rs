@@ -0,0 +1,311 @@
+#[cfg(feature = "datetime")]
+pub mod TimeZoneInfo_ {
+
+    // -----------------------------------------------------------
+    // System.TimeZoneInfo
+    // -----------------------------------------------------------
+
+    // The local and UTC time zones are always available. Other IANA time
+    // zones (e.g. "Europe/Paris") are looked up in the tz database of the
+    // `chrono-tz` crate, enabled with the `timezones` feature.
+
+    use crate::{
+        DateTimeOffset_::DateTimeOffset,
+        DateTime_::{clearCachedLocalOffset, local_offset_from_utc, local_offsets, DateTime, DateTimeKind},
+        Native_::{LrcPtr, MutCell, String, ToString},
+        String_::{fromString, string},
+        TimeSpan_::TimeSpan,
+    };
+    use chrono::{
+        Datelike, Duration, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, NaiveTime,
+        Offset, TimeZone, Utc,
+    };
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum Zone {
+        Utc,
+        Local,
+        #[cfg(feature = "timezones")]
+        Tz(chrono_tz::Tz),
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct TimeZoneInfo {
+        id: string,
+        zone: Zone,
+    }
+
+    impl core::fmt::Display for TimeZoneInfo {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            write!(f, "{}", self.id)
+        }
+    }
+
+    impl PartialEq for TimeZoneInfo {
+        fn eq(&self, other: &Self) -> bool {
+            self.id == other.id
+        }
+    }
+
+    // Like an ambiguous time is taken as standard time, the smallest offset wins
+    fn standard_offset(o1: FixedOffset, o2: FixedOffset) -> FixedOffset {
+        if o1.local_minus_utc() < o2.local_minus_utc() {
+            o1
+        } else {
+            o2
+        }
+    }
+
+    fn local_result_offset<O: Offset>(res: LocalResult<O>) -> Option<FixedOffset> {
+        match res {
+            LocalResult::Single(o) => Some(o.fix()),
+            LocalResult::Ambiguous(o1, o2) => Some(standard_offset(o1.fix(), o2.fix())),
+            LocalResult::None => None,
+        }
+    }
+
+    #[cfg(feature = "no_std")]
+    fn local_id() -> string {
+        string("UTC")
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    fn local_id() -> string {
+        // the IANA name of the local time zone, when it can be found
+        let tz = std::env::var("TZ").ok().map(|tz| tz.trim_start_matches(':').to_string());
+        let link = std::fs::read_link("/etc/localtime").ok().and_then(|path| {
+            let path = path.to_string_lossy().to_string();
+            path.find("zoneinfo/").map(|i| path[i + 9..].to_string())
+        });
+        match tz.filter(|tz| !tz.is_empty()).or(link) {
+            Some(id) => fromString(id),
+            None => string("Local"),
+        }
+    }
+
+    impl TimeZoneInfo {
+        pub fn local_() -> LrcPtr<TimeZoneInfo> {
+            static local: MutCell<Option<LrcPtr<TimeZoneInfo>>> = MutCell::new(None);
+            local.get_or_init(|| {
+                LrcPtr::new(TimeZoneInfo {
+                    id: local_id(),
+                    zone: Zone::Local,
+                })
+            })
+        }
+
+        pub fn utc_() -> LrcPtr<TimeZoneInfo> {
+            static utc: MutCell<Option<LrcPtr<TimeZoneInfo>>> = MutCell::new(None);
+            utc.get_or_init(|| {
+                LrcPtr::new(TimeZoneInfo {
+                    id: string("UTC"),
+                    zone: Zone::Utc,
+                })
+            })
+        }
+
+        fn try_find(id: &str) -> Option<LrcPtr<TimeZoneInfo>> {
+            match id {
+                "UTC" | "Etc/UTC" => Some(Self::utc_()),
+                _ if id == Self::local_().id.as_str() => Some(Self::local_()),
+                #[cfg(feature = "timezones")]
+                _ => id.parse::<chrono_tz::Tz>().ok().map(|tz| {
+                    LrcPtr::new(TimeZoneInfo {
+                        id: fromString(tz.name().to_string()),
+                        zone: Zone::Tz(tz),
+                    })
+                }),
+                #[cfg(not(feature = "timezones"))]
+                _ => None,
+            }
+        }
+
+        pub fn clearCachedData_() {
+            clearCachedLocalOffset()
+        }
+
+        pub fn findSystemTimeZoneById__s(id: string) -> LrcPtr<TimeZoneInfo> {
+            match Self::try_find(id.as_str()) {
+                Some(tz) => tz,
+                None => panic!("The time zone ID '{}' was not found on the local computer.", id),
+            }
+        }
+
+        pub fn tryFindSystemTimeZoneById__s_(id: string, res: &MutCell<LrcPtr<TimeZoneInfo>>) -> bool {
+            match Self::try_find(id.as_str()) {
+                Some(tz) => {
+                    res.set(tz);
+                    true
+                }
+                None => false,
+            }
+        }
+
+        pub fn id(&self) -> string {
+            self.id.clone()
+        }
+
+        pub fn displayName(&self) -> string {
+            self.id.clone()
+        }
+
+        pub fn standardName(&self) -> string {
+            self.id.clone()
+        }
+
+        pub fn toString(&self) -> string {
+            self.id.clone()
+        }
+
+        // offset of a UTC time in this time zone
+        fn offset_from_utc(&self, utc: &NaiveDateTime) -> FixedOffset {
+            match self.zone {
+                Zone::Utc => Utc.fix(),
+                Zone::Local => local_offset_from_utc(utc),
+                #[cfg(feature = "timezones")]
+                Zone::Tz(tz) => tz.offset_from_utc_datetime(utc).fix(),
+            }
+        }
+
+        // offset of a time in this time zone, None when the time is invalid
+        fn offset_from_local(&self, ndt: &NaiveDateTime) -> Option<FixedOffset> {
+            match self.zone {
+                Zone::Utc => Some(Utc.fix()),
+                Zone::Local => local_result_offset(local_offsets(ndt)),
+                #[cfg(feature = "timezones")]
+                Zone::Tz(tz) => local_result_offset(tz.offset_from_local_datetime(ndt)),
+            }
+        }
+
+        fn offsets_in_year(&self, year: i32) -> (FixedOffset, FixedOffset) {
+            let offset = |month| {
+                let ndt = NaiveDate::from_ymd_opt(year, month, 1).unwrap().and_time(NaiveTime::MIN);
+                self.offset_from_utc(&ndt)
+            };
+            (offset(1), offset(7))
+        }
+
+        pub fn baseUtcOffset(&self) -> TimeSpan {
+            let (jan, jul) = self.offsets_in_year(DateTime::utcNow().year());
+            TimeSpan::from_seconds(standard_offset(jan, jul).local_minus_utc() as f64)
+        }
+
+        pub fn supportsDaylightSavingTime(&self) -> bool {
+            let (jan, jul) = self.offsets_in_year(DateTime::utcNow().year());
+            jan != jul
+        }
+
+        fn kind(&self) -> DateTimeKind {
+            match self.zone {
+                Zone::Utc => DateTimeKind::Utc,
+                Zone::Local => DateTimeKind::Local,
+                #[cfg(feature = "timezones")]
+                Zone::Tz(_) => DateTimeKind::Unspecified,
+            }
+        }
+
+        // the time in this time zone, as UTC
+        fn to_utc(&self, ndt: NaiveDateTime) -> NaiveDateTime {
+            match self.offset_from_local(&ndt) {
+                Some(offset) => ndt - Duration::seconds(offset.local_minus_utc() as i64),
+                None => panic!(
+                    "The supplied DateTime represents an invalid time. For example, when the clock is adjusted forward, any time in the period that is skipped is invalid. (Parameter 'dateTime')"
+                ),
+            }
+        }
+
+        fn from_utc(&self, utc: NaiveDateTime) -> DateTime {
+            let offset = self.offset_from_utc(&utc);
+            let ndt = utc + Duration::seconds(offset.local_minus_utc() as i64);
+            DateTime::new(ndt, self.kind())
+        }
+
+        // the UTC time of a DateTime, unspecified values are in this time zone
+        fn utc_of(&self, dt: &DateTime) -> NaiveDateTime {
+            match dt.kind_enum() {
+                DateTimeKind::Utc => dt.naive_date_time(),
+                DateTimeKind::Local => Self::local_().to_utc(dt.naive_date_time()),
+                DateTimeKind::Unspecified => self.to_utc(dt.naive_date_time()),
+            }
+        }
+
+        pub fn getUtcOffset__(&self, dt: DateTime) -> TimeSpan {
+            let offset = self.offset_from_utc(&self.utc_of(&dt));
+            TimeSpan::from_seconds(offset.local_minus_utc() as f64)
+        }
+
+        pub fn isDaylightSavingTime__(&self, dt: DateTime) -> bool {
+            let (jan, jul) = self.offsets_in_year(dt.year());
+            let offset = self.offset_from_utc(&self.utc_of(&dt));
+            offset != standard_offset(jan, jul)
+        }
+
+        pub fn isInvalidTime__(&self, dt: DateTime) -> bool {
+            dt.kind_enum() == DateTimeKind::Unspecified && self.offset_from_local(&dt.naive_date_time()).is_none()
+        }
+
+        pub fn isAmbiguousTime__(&self, dt: DateTime) -> bool {
+            let ndt = match dt.kind_enum() {
+                DateTimeKind::Unspecified => dt.naive_date_time(),
+                _ => self.from_utc(self.utc_of(&dt)).naive_date_time(),
+            };
+            match self.zone {
+                Zone::Utc => false,
+                Zone::Local => matches!(local_offsets(&ndt), LocalResult::Ambiguous(..)),
+                #[cfg(feature = "timezones")]
+                Zone::Tz(tz) => matches!(tz.offset_from_local_datetime(&ndt), LocalResult::Ambiguous(..)),
+            }
+        }
+
+        pub fn convertTimeFromUtc____(dt: DateTime, destination: LrcPtr<TimeZoneInfo>) -> DateTime {
+            if dt.kind_enum() == DateTimeKind::Local {
+                panic!("The supplied DateTime must have the Kind property set to Utc or Unspecified. (Parameter 'dateTime')");
+            }
+            destination.from_utc(dt.naive_date_time())
+        }
+
+        pub fn convertTimeToUtc___(dt: DateTime) -> DateTime {
+            DateTime::new(Self::local_().utc_of(&dt), DateTimeKind::Utc)
+        }
+
+        pub fn convertTimeToUtc____(dt: DateTime, source: LrcPtr<TimeZoneInfo>) -> DateTime {
+            Self::convertTime_____(dt, source, Self::utc_())
+        }
+
+        // unspecified values are local times
+        pub fn convertTime____(dt: DateTime, destination: LrcPtr<TimeZoneInfo>) -> DateTime {
+            destination.from_utc(Self::local_().utc_of(&dt))
+        }
+
+        pub fn convertTime_____(
+            dt: DateTime,
+            source: LrcPtr<TimeZoneInfo>,
+            destination: LrcPtr<TimeZoneInfo>,
+        ) -> DateTime {
+            let valid = match dt.kind_enum() {
+                DateTimeKind::Local => source.zone == Zone::Local,
+                DateTimeKind::Utc => source.zone == Zone::Utc,
+                DateTimeKind::Unspecified => true,
+            };
+            if !valid {
+                panic!("The conversion could not be completed because the supplied DateTime did not have the Kind property set correctly.  For example, when the Kind property is DateTimeKind.Local, the source time zone must be TimeZoneInfo.Local. (Parameter 'sourceTimeZone')");
+            }
+            destination.from_utc(source.utc_of(&dt))
+        }
+
+        pub fn convertTimeOffset____(dto: DateTimeOffset, destination: LrcPtr<TimeZoneInfo>) -> DateTimeOffset {
+            let utc = dto.utcDateTime().naive_date_time();
+            let offset = destination.offset_from_utc(&utc);
+            DateTimeOffset::new_utc(utc, TimeSpan::from_seconds(offset.local_minus_utc() as f64))
+        }
+
+        pub fn convertTimeBySystemTimeZoneId___s(dt: DateTime, destinationId: string) -> DateTime {
+            Self::convertTime____(dt, Self::findSystemTimeZoneById__s(destinationId))
+        }
+
+        pub fn convertTimeBySystemTimeZoneId___ss(dt: DateTime, sourceId: string, destinationId: string) -> DateTime {
+            let source = Self::findSystemTimeZoneById__s(sourceId);
+            Self::convertTime_____(dt, source, Self::findSystemTimeZoneById__s(destinationId))
+        }
+    }
+}
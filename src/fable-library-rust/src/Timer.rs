@@ -0,0 +1,249 @@
+#[cfg(all(feature = "threaded", feature = "datetime"))]
+pub mod Timer_ {
+
+    // -----------------------------------------------------------
+    // System.Threading.Timer, System.Timers.Timer
+    // -----------------------------------------------------------
+
+    // Both timers share the same implementation: a dedicated background
+    // thread waits for the due time, runs the callback, and schedules the
+    // next call one period later. Callbacks run on the timer thread, one at
+    // a time. System.Timers.Timer raises its Elapsed handlers from the
+    // callback, its ElapsedEventArgs are represented by their SignalTime.
+
+    use crate::DateTime_::DateTime;
+    use crate::Interfaces_::System::IDisposable;
+    use crate::Native_::{Func0, Func1, LrcPtr, MutCell, Vec};
+
+    use core::fmt::{Debug, Formatter, Result};
+    use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+    use std::time::{Duration, Instant};
+
+    const infinite: i64 = -1;
+    const max_milliseconds: i64 = 0xfffffffe;
+
+    struct Schedule {
+        next: Option<Instant>,
+        period: Option<Duration>,
+        disposed: bool,
+    }
+
+    type Shared = Arc<(Mutex<Schedule>, Condvar)>;
+
+    fn lock(shared: &Shared) -> MutexGuard<'_, Schedule> {
+        shared.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn start_thread(shared: Shared, callback: Func0<()>) {
+        std::thread::spawn(move || {
+            let mut s = lock(&shared);
+            loop {
+                if s.disposed {
+                    return;
+                }
+                let now = Instant::now();
+                match s.next {
+                    None => {
+                        s = shared.1.wait(s).unwrap_or_else(|e| e.into_inner());
+                    }
+                    Some(due) if now < due => {
+                        s = shared.1.wait_timeout(s, due - now).unwrap_or_else(|e| e.into_inner()).0;
+                    }
+                    Some(_) => {
+                        s.next = s.period.map(|period| now + period);
+                        drop(s);
+                        callback();
+                        s = lock(&shared);
+                    }
+                }
+            }
+        });
+    }
+
+    // validates a due time or a period, in milliseconds
+    fn to_duration(ms: i64, paramName: &str) -> Option<Duration> {
+        if ms < infinite {
+            panic!("Number must be either non-negative and less than or equal to Int32.MaxValue or -1. (Parameter '{}')", paramName);
+        }
+        if ms > max_milliseconds {
+            panic!("Time-out interval must be less than 2^32-2. (Parameter '{}')", paramName);
+        }
+        if ms == infinite {
+            None
+        } else {
+            Some(Duration::from_millis(ms as u64))
+        }
+    }
+
+    pub struct Timer {
+        shared: Shared,
+        // System.Timers.Timer
+        interval: MutCell<f64>,
+        autoReset: MutCell<bool>,
+        handlers: Arc<Mutex<Vec<Func1<DateTime, ()>>>>,
+    }
+
+    impl Debug for Timer {
+        fn fmt(&self, f: &mut Formatter) -> Result {
+            f.debug_struct("Timer").finish_non_exhaustive()
+        }
+    }
+
+    impl Timer {
+        fn create(callback: Func0<()>, handlers: Arc<Mutex<Vec<Func1<DateTime, ()>>>>, interval: f64) -> LrcPtr<Timer> {
+            let schedule = Schedule {
+                next: None,
+                period: None,
+                disposed: false,
+            };
+            let shared = Arc::new((Mutex::new(schedule), Condvar::new()));
+            start_thread(shared.clone(), callback);
+            LrcPtr::new(Timer {
+                shared,
+                interval: MutCell::new(interval),
+                autoReset: MutCell::new(true),
+                handlers,
+            })
+        }
+
+        // -----------------------------------------------------------
+        // System.Threading.Timer
+        // -----------------------------------------------------------
+
+        pub fn new<T: Clone + 'static>(callback: Func1<T, ()>, state: T, dueTime: i64, period: i64) -> LrcPtr<Timer> {
+            let dueTime = to_duration(dueTime, "dueTime");
+            let period = to_duration(period, "period");
+            let callback = Func0::new(move || callback(state.clone()));
+            let timer = Self::create(callback, Arc::new(Mutex::new(Vec::new())), 100.0);
+            timer.schedule(dueTime, period);
+            timer
+        }
+
+        fn schedule(&self, dueTime: Option<Duration>, period: Option<Duration>) -> bool {
+            let mut s = lock(&self.shared);
+            if s.disposed {
+                return false;
+            }
+            s.next = dueTime.map(|dueTime| Instant::now() + dueTime);
+            // a zero period calls the callback only once
+            s.period = period.filter(|period| !period.is_zero());
+            self.shared.1.notify_all();
+            true
+        }
+
+        pub fn change(&self, dueTime: i64, period: i64) -> bool {
+            let dueTime = to_duration(dueTime, "dueTime");
+            let period = to_duration(period, "period");
+            self.schedule(dueTime, period)
+        }
+
+        pub fn dispose(&self) {
+            let mut s = lock(&self.shared);
+            s.disposed = true;
+            s.next = None;
+            self.shared.1.notify_all();
+        }
+
+        // -----------------------------------------------------------
+        // System.Timers.Timer
+        // -----------------------------------------------------------
+
+        pub fn new_() -> LrcPtr<Timer> {
+            Self::new__n(100.0)
+        }
+
+        pub fn new__n(interval: f64) -> LrcPtr<Timer> {
+            if !(interval > 0.0 && interval.ceil() <= i32::MAX as f64) {
+                panic!("Invalid value '{}' for parameter 'interval'.", interval);
+            }
+            let handlers: Arc<Mutex<Vec<Func1<DateTime, ()>>>> = Arc::new(Mutex::new(Vec::new()));
+            let elapsed = handlers.clone();
+            let callback = Func0::new(move || {
+                let handlers = elapsed.lock().unwrap_or_else(|e| e.into_inner()).clone();
+                let signalTime = DateTime::now();
+                for handler in handlers.iter() {
+                    handler(signalTime);
+                }
+            });
+            Self::create(callback, handlers, interval)
+        }
+
+        pub fn add_Elapsed(&self, handler: Func1<DateTime, ()>) {
+            self.handlers.lock().unwrap_or_else(|e| e.into_inner()).push(handler);
+        }
+
+        fn restart(&self) {
+            let interval = Duration::from_millis(self.interval.get().ceil() as u64);
+            let period = if self.autoReset.get() { Some(interval) } else { None };
+            self.schedule(Some(interval), period);
+        }
+
+        pub fn interval(&self) -> f64 {
+            self.interval.get()
+        }
+
+        pub fn set_interval(&self, value: f64) {
+            if !(value > 0.0 && value.ceil() <= i32::MAX as f64) {
+                panic!("'{}' is not a valid value for 'Interval'. 'Interval' must be greater than 0.", value);
+            }
+            self.interval.set(value);
+            if self.enabled() {
+                self.restart();
+            }
+        }
+
+        pub fn autoReset(&self) -> bool {
+            self.autoReset.get()
+        }
+
+        pub fn set_autoReset(&self, value: bool) {
+            self.autoReset.set(value);
+            if self.enabled() {
+                self.restart();
+            }
+        }
+
+        // a timer that is not auto-reset disables itself when it elapses
+        pub fn enabled(&self) -> bool {
+            lock(&self.shared).next.is_some()
+        }
+
+        pub fn set_enabled(&self, value: bool) {
+            if value && lock(&self.shared).disposed {
+                panic!("Cannot access a disposed object.\nObject name: 'Timer'.");
+            }
+            if value != self.enabled() {
+                if value {
+                    self.restart();
+                } else {
+                    self.schedule(None, None);
+                }
+            }
+        }
+
+        pub fn start(&self) {
+            self.set_enabled(true)
+        }
+
+        pub fn stop(&self) {
+            self.set_enabled(false)
+        }
+
+        pub fn close(&self) {
+            self.dispose()
+        }
+    }
+
+    impl IDisposable for Timer {
+        fn Dispose(&self) {
+            self.dispose()
+        }
+    }
+
+    // stops the background thread when the timer is not disposed
+    impl Drop for Timer {
+        fn drop(&mut self) {
+            self.dispose()
+        }
+    }
+}
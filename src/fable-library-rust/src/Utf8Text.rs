@@ -0,0 +1,947 @@
+pub mod Utf8Text_ {
+    // -----------------------------------------------------------
+    // System.Buffers.Text (Utf8Parser and Utf8Formatter)
+    // -----------------------------------------------------------
+
+    // Spans are compiled as arrays, so the parsers read from the start
+    // of the source array and the formatters write to the start of the
+    // destination array. Formatting goes through a fixed stack buffer,
+    // so neither direction allocates.
+
+    use crate::NativeArray_::Array;
+    use crate::Native_::MutCell;
+    use crate::String_::{fromString, string};
+    use core::fmt::{Result, Write};
+
+    const no_precision: u8 = u8::MAX;
+    const max_precision: u8 = 99;
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    pub struct StandardFormat {
+        symbol: char,
+        precision: u8,
+    }
+
+    impl core::fmt::Display for StandardFormat {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> Result {
+            if self.symbol != '\0' {
+                write!(f, "{}", self.symbol)?;
+                if self.hasPrecision() {
+                    write!(f, "{}", self.precision)?;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    impl StandardFormat {
+        pub fn new_() -> StandardFormat {
+            StandardFormat::default()
+        }
+
+        pub fn new__c(symbol: char) -> StandardFormat {
+            Self::new__cn(symbol, no_precision)
+        }
+
+        pub fn new__cn(symbol: char, precision: u8) -> StandardFormat {
+            if precision != no_precision && precision > max_precision {
+                panic!("Precision cannot be larger than 99. (Parameter 'precision')");
+            }
+            if !symbol.is_ascii() {
+                panic!("Format symbol must be ASCII. (Parameter 'symbol')");
+            }
+            StandardFormat { symbol, precision }
+        }
+
+        fn try_parse_str(s: &str) -> Option<StandardFormat> {
+            let mut chars = s.chars();
+            match chars.next() {
+                None => Some(StandardFormat::default()),
+                Some(symbol) if symbol.is_ascii_alphabetic() => {
+                    let rest = chars.as_str();
+                    if rest.is_empty() {
+                        Some(Self::new__c(symbol))
+                    } else if rest.len() <= 2 && rest.bytes().all(|b| b.is_ascii_digit()) {
+                        Some(Self::new__cn(symbol, rest.parse().ok()?))
+                    } else {
+                        None
+                    }
+                }
+                Some(_) => None,
+            }
+        }
+
+        pub fn parse__s(s: string) -> StandardFormat {
+            match Self::try_parse_str(s.as_str()) {
+                Some(format) => format,
+                None => panic!("Format specifier was invalid."),
+            }
+        }
+
+        pub fn tryParse__s_(s: string, res: &MutCell<StandardFormat>) -> bool {
+            match Self::try_parse_str(s.as_str()) {
+                Some(format) => {
+                    res.set(format);
+                    true
+                }
+                None => false,
+            }
+        }
+
+        pub fn symbol(&self) -> char {
+            self.symbol
+        }
+
+        pub fn precision(&self) -> u8 {
+            self.precision
+        }
+
+        pub fn hasPrecision(&self) -> bool {
+            self.precision != no_precision
+        }
+
+        pub fn isDefault(&self) -> bool {
+            self.symbol == '\0' && self.precision == 0
+        }
+
+        pub fn toString(&self) -> string {
+            use crate::Native_::ToString;
+            fromString(self.to_string())
+        }
+
+        fn precision_or(&self, default: usize) -> usize {
+            if self.hasPrecision() {
+                self.precision as usize
+            } else {
+                default
+            }
+        }
+    }
+
+    fn invalid_format() -> ! {
+        panic!("Format specifier was invalid.")
+    }
+
+    // -----------------------------------------------------------
+    // Formatting
+    // -----------------------------------------------------------
+
+    struct Utf8Buffer {
+        bytes: [u8; 512],
+        len: usize,
+    }
+
+    impl Write for Utf8Buffer {
+        fn write_str(&mut self, s: &str) -> Result {
+            let end = self.len + s.len();
+            if end > self.bytes.len() {
+                return Err(core::fmt::Error);
+            }
+            self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    impl Utf8Buffer {
+        fn as_bytes(&self) -> &[u8] {
+            &self.bytes[..self.len]
+        }
+    }
+
+    pub trait Utf8Format {
+        fn format_utf8(&self, format: StandardFormat, w: &mut impl Write) -> Result;
+    }
+
+    pub fn tryFormat<T: Utf8Format>(value: T, destination: Array<u8>, bytesWritten: &MutCell<i32>) -> bool {
+        tryFormat2(value, destination, bytesWritten, StandardFormat::default())
+    }
+
+    pub fn tryFormat2<T: Utf8Format>(
+        value: T,
+        destination: Array<u8>,
+        bytesWritten: &MutCell<i32>,
+        format: StandardFormat,
+    ) -> bool {
+        let mut buf = Utf8Buffer {
+            bytes: [0; 512],
+            len: 0,
+        };
+        let written = value.format_utf8(format, &mut buf).is_ok() && buf.len <= destination.len();
+        if written {
+            destination.get_mut()[..buf.len].copy_from_slice(buf.as_bytes());
+            bytesWritten.set(buf.len as i32);
+        } else {
+            bytesWritten.set(0);
+        }
+        written
+    }
+
+    fn write_grouped(w: &mut impl Write, digits: &str) -> Result {
+        for (i, c) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                w.write_char(',')?;
+            }
+            w.write_char(c)?;
+        }
+        Ok(())
+    }
+
+    fn write_zeros(w: &mut impl Write, count: usize) -> Result {
+        for _ in 0..count {
+            w.write_char('0')?;
+        }
+        Ok(())
+    }
+
+    fn format_integer(
+        w: &mut impl Write,
+        format: StandardFormat,
+        negative: bool,
+        magnitude: u64,
+        bits: u64,
+    ) -> Result {
+        let mut digits = Utf8Buffer {
+            bytes: [0; 512],
+            len: 0,
+        };
+        match format.symbol {
+            '\0' | 'G' | 'g' | 'D' | 'd' => {
+                write!(digits, "{}", magnitude)?;
+                if negative {
+                    w.write_char('-')?;
+                }
+                write_zeros(w, format.precision_or(0).saturating_sub(digits.len))?;
+                w.write_str(digits_str(&digits))
+            }
+            'N' | 'n' => {
+                write!(digits, "{}", magnitude)?;
+                if negative {
+                    w.write_char('-')?;
+                }
+                write_grouped(w, digits_str(&digits))?;
+                let decimals = format.precision_or(0);
+                if decimals > 0 {
+                    w.write_char('.')?;
+                    write_zeros(w, decimals)?;
+                }
+                Ok(())
+            }
+            'X' => {
+                write!(digits, "{:X}", bits)?;
+                write_zeros(w, format.precision_or(0).saturating_sub(digits.len))?;
+                w.write_str(digits_str(&digits))
+            }
+            'x' => {
+                write!(digits, "{:x}", bits)?;
+                write_zeros(w, format.precision_or(0).saturating_sub(digits.len))?;
+                w.write_str(digits_str(&digits))
+            }
+            _ => invalid_format(),
+        }
+    }
+
+    fn digits_str(buf: &Utf8Buffer) -> &str {
+        // only ASCII is ever written to the buffer
+        core::str::from_utf8(buf.as_bytes()).unwrap_or_default()
+    }
+
+    macro_rules! integer_format {
+        ($($t:ty, $u:ty);*) => {$(
+            impl Utf8Format for $t {
+                fn format_utf8(&self, format: StandardFormat, w: &mut impl Write) -> Result {
+                    let x = *self;
+                    #[allow(unused_comparisons)]
+                    let negative = x < 0;
+                    format_integer(w, format, negative, (x as i128).unsigned_abs() as u64, x as $u as u64)
+                }
+            }
+        )*};
+    }
+
+    integer_format!(i8, u8; i16, u16; i32, u32; i64, u64; u8, u8; u16, u16; u32, u32; u64, u64);
+
+    impl Utf8Format for bool {
+        fn format_utf8(&self, format: StandardFormat, w: &mut impl Write) -> Result {
+            match (format.symbol, *self) {
+                ('\0' | 'G', true) => w.write_str("True"),
+                ('\0' | 'G', false) => w.write_str("False"),
+                ('l', true) => w.write_str("true"),
+                ('l', false) => w.write_str("false"),
+                _ => invalid_format(),
+            }
+        }
+    }
+
+    // Writes the shortest round-trip digits the way .NET does for the "G" format:
+    // fixed-point notation, unless the exponent is too large or too small.
+    fn format_general(w: &mut impl Write, digits: &str, exp: i32, max_digits: usize, upper: bool) -> Result {
+        let scale = exp + 1;
+        let max_digits = max_digits.max(digits.len()) as i32;
+        if scale > max_digits || scale < -3 {
+            let (first, rest) = digits.split_at(1);
+            w.write_str(first)?;
+            if !rest.is_empty() {
+                w.write_char('.')?;
+                w.write_str(rest)?;
+            }
+            let e = if upper { 'E' } else { 'e' };
+            let sign = if exp < 0 { '-' } else { '+' };
+            write!(w, "{}{}{:02}", e, sign, exp.unsigned_abs())
+        } else if scale <= 0 {
+            w.write_str("0.")?;
+            write_zeros(w, (-scale) as usize)?;
+            w.write_str(digits)
+        } else if scale as usize >= digits.len() {
+            w.write_str(digits)?;
+            write_zeros(w, scale as usize - digits.len())
+        } else {
+            let (int, frac) = digits.split_at(scale as usize);
+            w.write_str(int)?;
+            w.write_char('.')?;
+            w.write_str(frac)
+        }
+    }
+
+    // Splits Rust's exponent notation (e.g. "1.2345e-7") into digits and exponent.
+    fn split_exponent(buf: &Utf8Buffer) -> (Utf8Buffer, i32) {
+        let s = digits_str(buf);
+        let (mantissa, exp) = s.split_once('e').unwrap_or((s, "0"));
+        let mut digits = Utf8Buffer {
+            bytes: [0; 512],
+            len: 0,
+        };
+        for c in mantissa.chars().filter(|c| c.is_ascii_digit()) {
+            let _ = digits.write_char(c);
+        }
+        (digits, exp.parse().unwrap_or(0))
+    }
+
+    macro_rules! float_format {
+        ($($t:ty, $max_digits:expr);*) => {$(
+            impl Utf8Format for $t {
+                fn format_utf8(&self, format: StandardFormat, w: &mut impl Write) -> Result {
+                    let x = *self;
+                    if x.is_nan() {
+                        return w.write_str("NaN");
+                    }
+                    if x.is_sign_negative() {
+                        w.write_char('-')?;
+                    }
+                    if x.is_infinite() {
+                        return w.write_str("Infinity");
+                    }
+                    let x = x.abs();
+                    let mut buf = Utf8Buffer { bytes: [0; 512], len: 0 };
+                    match format.symbol {
+                        '\0' | 'G' | 'g' | 'R' | 'r' => {
+                            let max_digits = match format.symbol {
+                                'G' | 'g' if format.hasPrecision() && format.precision > 0 => {
+                                    write!(buf, "{:.*e}", format.precision as usize - 1, x)?;
+                                    format.precision as usize
+                                }
+                                _ => {
+                                    write!(buf, "{:e}", x)?;
+                                    $max_digits
+                                }
+                            };
+                            let (digits, exp) = split_exponent(&buf);
+                            let digits = digits_str(&digits).trim_end_matches('0');
+                            let digits = if digits.is_empty() { "0" } else { digits };
+                            let exp = if digits == "0" { 0 } else { exp };
+                            format_general(w, digits, exp, max_digits, format.symbol != 'g')
+                        }
+                        'F' | 'f' => write!(w, "{:.*}", format.precision_or(2), x),
+                        'N' | 'n' => {
+                            write!(buf, "{:.*}", format.precision_or(2), x)?;
+                            let s = digits_str(&buf);
+                            let (int, frac) = s.split_once('.').unwrap_or((s, ""));
+                            write_grouped(w, int)?;
+                            if !frac.is_empty() {
+                                w.write_char('.')?;
+                                w.write_str(frac)?;
+                            }
+                            Ok(())
+                        }
+                        'E' | 'e' => {
+                            write!(buf, "{:.*e}", format.precision_or(6), x)?;
+                            let s = digits_str(&buf);
+                            let (mantissa, exp) = s.split_once('e').unwrap_or((s, "0"));
+                            let exp: i32 = exp.parse().unwrap_or(0);
+                            let sign = if exp < 0 { '-' } else { '+' };
+                            let e = if format.symbol == 'E' { 'E' } else { 'e' };
+                            write!(w, "{}{}{}{:03}", mantissa, e, sign, exp.unsigned_abs())
+                        }
+                        _ => invalid_format(),
+                    }
+                }
+            }
+        )*};
+    }
+
+    float_format!(f32, 7; f64, 15);
+
+    #[cfg(feature = "guid")]
+    impl Utf8Format for crate::Guid_::Guid {
+        fn format_utf8(&self, format: StandardFormat, w: &mut impl Write) -> Result {
+            let uuid = self.0;
+            match format.symbol {
+                '\0' | 'D' => write!(w, "{}", uuid.hyphenated()),
+                'N' => write!(w, "{}", uuid.simple()),
+                'B' => write!(w, "{}", uuid.braced()),
+                'P' => write!(w, "({})", uuid.hyphenated()),
+                _ => invalid_format(),
+            }
+        }
+    }
+
+    impl Utf8Format for crate::TimeSpan_::TimeSpan {
+        fn format_utf8(&self, format: StandardFormat, w: &mut impl Write) -> Result {
+            use crate::TimeSpan_::*;
+            let ticks = self.ticks();
+            let t = ticks.unsigned_abs();
+            let (tpd, tph, tpm, tps) = (
+                ticks_per_day as u64,
+                ticks_per_hour as u64,
+                ticks_per_minute as u64,
+                ticks_per_second as u64,
+            );
+            let days = t / tpd;
+            let hours = t % tpd / tph;
+            let mins = t % tph / tpm;
+            let secs = t % tpm / tps;
+            let frac = t % tps;
+            if ticks < 0 {
+                w.write_char('-')?;
+            }
+            match format.symbol {
+                '\0' | 'c' | 't' | 'T' => {
+                    if days > 0 {
+                        write!(w, "{}.", days)?;
+                    }
+                    write!(w, "{:02}:{:02}:{:02}", hours, mins, secs)?;
+                    if frac > 0 {
+                        write!(w, ".{:07}", frac)?;
+                    }
+                    Ok(())
+                }
+                'g' => {
+                    if days > 0 {
+                        write!(w, "{}:", days)?;
+                    }
+                    write!(w, "{}:{:02}:{:02}", hours, mins, secs)?;
+                    if frac > 0 {
+                        let mut buf = Utf8Buffer { bytes: [0; 512], len: 0 };
+                        write!(buf, "{:07}", frac)?;
+                        write!(w, ".{}", digits_str(&buf).trim_end_matches('0'))?;
+                    }
+                    Ok(())
+                }
+                'G' => write!(w, "{}:{:02}:{:02}:{:02}.{:07}", days, hours, mins, secs, frac),
+                _ => invalid_format(),
+            }
+        }
+    }
+
+    const day_names: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+    const month_names: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    #[cfg(feature = "datetime")]
+    impl Utf8Format for crate::DateTime_::DateTime {
+        fn format_utf8(&self, format: StandardFormat, w: &mut impl Write) -> Result {
+            use crate::TimeSpan_::{ticks_per_minute, ticks_per_second};
+            let (y, mo, d) = (self.year(), self.month(), self.day());
+            let (h, mi, s) = (self.hour(), self.minute(), self.second());
+            match format.symbol {
+                '\0' | 'G' => write!(w, "{:02}/{:02}/{:04} {:02}:{:02}:{:02}", mo, d, y, h, mi, s),
+                'R' | 'l' => {
+                    let day = day_names[self.dayOfWeek() as usize];
+                    let month = month_names[mo as usize - 1];
+                    if format.symbol == 'R' {
+                        write!(w, "{}, {:02} {} {:04} {:02}:{:02}:{:02} GMT", day, d, month, y, h, mi, s)
+                    } else {
+                        let (day, month) = (day.as_bytes(), month.as_bytes());
+                        write!(
+                            w,
+                            "{}{}{}, {:02} {}{}{} {:04} {:02}:{:02}:{:02} gmt",
+                            day[0].to_ascii_lowercase() as char, day[1] as char, day[2] as char,
+                            d,
+                            month[0].to_ascii_lowercase() as char, month[1] as char, month[2] as char,
+                            y, h, mi, s
+                        )
+                    }
+                }
+                'O' => {
+                    let frac = self.ticks() % ticks_per_second;
+                    write!(w, "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:07}", y, mo, d, h, mi, s, frac)?;
+                    match self.kind() {
+                        1 => w.write_char('Z'),
+                        2 => {
+                            let offset = (self.ticks() - self.toUniversalTime().ticks()) / ticks_per_minute;
+                            let sign = if offset < 0 { '-' } else { '+' };
+                            let offset = offset.unsigned_abs();
+                            write!(w, "{}{:02}:{:02}", sign, offset / 60, offset % 60)
+                        }
+                        _ => Ok(()),
+                    }
+                }
+                _ => invalid_format(),
+            }
+        }
+    }
+
+    // -----------------------------------------------------------
+    // Parsing
+    // -----------------------------------------------------------
+
+    pub trait Utf8Parse: Sized {
+        /// Parses a value from the start of `s`, returning it with the number of bytes consumed.
+        fn parse_utf8(s: &[u8], format: char) -> Option<(Self, usize)>;
+    }
+
+    pub fn tryParse<T: Utf8Parse>(source: Array<u8>, value: &MutCell<T>, bytesConsumed: &MutCell<i32>) -> bool {
+        tryParse2(source, value, bytesConsumed, '\0')
+    }
+
+    pub fn tryParse2<T: Utf8Parse>(
+        source: Array<u8>,
+        value: &MutCell<T>,
+        bytesConsumed: &MutCell<i32>,
+        format: char,
+    ) -> bool {
+        match T::parse_utf8(source.as_slice(), format) {
+            Some((res, consumed)) => {
+                value.set(res);
+                bytesConsumed.set(consumed as i32);
+                true
+            }
+            None => {
+                bytesConsumed.set(0);
+                false
+            }
+        }
+    }
+
+    fn parse_digits(s: &[u8], pos: &mut usize, max_digits: usize) -> Option<u64> {
+        let start = *pos;
+        let mut res: u64 = 0;
+        while *pos < s.len() && *pos - start < max_digits && s[*pos].is_ascii_digit() {
+            res = res.checked_mul(10)?.checked_add((s[*pos] - b'0') as u64)?;
+            *pos += 1;
+        }
+        if *pos == start {
+            None
+        } else {
+            Some(res)
+        }
+    }
+
+    fn parse_fixed(s: &[u8], pos: &mut usize, digits: usize) -> Option<u64> {
+        let start = *pos;
+        let res = parse_digits(s, pos, digits)?;
+        if *pos - start == digits {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    fn expect(s: &[u8], pos: &mut usize, b: u8) -> Option<()> {
+        if *pos < s.len() && s[*pos] == b {
+            *pos += 1;
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn parse_sign(s: &[u8], pos: &mut usize) -> bool {
+        match s.get(*pos) {
+            Some(b'-') => {
+                *pos += 1;
+                true
+            }
+            Some(b'+') => {
+                *pos += 1;
+                false
+            }
+            _ => false,
+        }
+    }
+
+    // Returns the sign and magnitude of a decimal integer, with optional group separators
+    fn parse_decimal(s: &[u8], groups: bool) -> Option<(bool, u64, usize)> {
+        let mut pos = 0;
+        let negative = parse_sign(s, &mut pos);
+        let start = pos;
+        let mut res: u64 = 0;
+        while pos < s.len() {
+            match s[pos] {
+                c @ b'0'..=b'9' => res = res.checked_mul(10)?.checked_add((c - b'0') as u64)?,
+                b',' if groups && pos > start => (),
+                _ => break,
+            }
+            pos += 1;
+        }
+        if pos == start {
+            return None;
+        }
+        if groups && pos < s.len() && s[pos] == b'.' {
+            // fractional digits are allowed as long as they are zeros
+            pos += 1;
+            while pos < s.len() && s[pos].is_ascii_digit() {
+                if s[pos] != b'0' {
+                    return None;
+                }
+                pos += 1;
+            }
+        }
+        Some((negative, res, pos))
+    }
+
+    fn parse_hex(s: &[u8], max_digits: usize) -> Option<(u64, usize)> {
+        let mut pos = 0;
+        let mut res: u64 = 0;
+        while pos < s.len() {
+            let d = match s[pos] {
+                c @ b'0'..=b'9' => c - b'0',
+                c @ b'a'..=b'f' => c - b'a' + 10,
+                c @ b'A'..=b'F' => c - b'A' + 10,
+                _ => break,
+            };
+            if pos == max_digits {
+                return None; // overflow
+            }
+            res = (res << 4) | d as u64;
+            pos += 1;
+        }
+        if pos == 0 {
+            None
+        } else {
+            Some((res, pos))
+        }
+    }
+
+    macro_rules! integer_parse {
+        ($($t:ty, $u:ty);*) => {$(
+            impl Utf8Parse for $t {
+                fn parse_utf8(s: &[u8], format: char) -> Option<(Self, usize)> {
+                    match format {
+                        '\0' | 'G' | 'g' | 'D' | 'd' | 'N' | 'n' => {
+                            let groups = matches!(format, 'N' | 'n');
+                            let (negative, magnitude, n) = parse_decimal(s, groups)?;
+                            let x = if negative { -(magnitude as i128) } else { magnitude as i128 };
+                            Some((<$t>::try_from(x).ok()?, n))
+                        }
+                        'X' | 'x' => {
+                            let (x, n) = parse_hex(s, core::mem::size_of::<$t>() * 2)?;
+                            Some((x as $u as $t, n))
+                        }
+                        _ => invalid_format(),
+                    }
+                }
+            }
+        )*};
+    }
+
+    integer_parse!(i8, u8; i16, u16; i32, u32; i64, u64; u8, u8; u16, u16; u32, u32; u64, u64);
+
+    impl Utf8Parse for bool {
+        fn parse_utf8(s: &[u8], format: char) -> Option<(Self, usize)> {
+            let matches = |word: &str| {
+                s.len() >= word.len()
+                    && match format {
+                        'l' => &s[..word.len()] == word.as_bytes(),
+                        '\0' | 'G' => s[..word.len()].eq_ignore_ascii_case(word.as_bytes()),
+                        _ => invalid_format(),
+                    }
+            };
+            if matches("true") {
+                Some((true, 4))
+            } else if matches("false") {
+                Some((false, 5))
+            } else {
+                None
+            }
+        }
+    }
+
+    fn float_length(s: &[u8], exponent: bool) -> Option<usize> {
+        let mut pos = 0;
+        parse_sign(s, &mut pos);
+        let int_start = pos;
+        while pos < s.len() && s[pos].is_ascii_digit() {
+            pos += 1;
+        }
+        let mut digits = pos - int_start;
+        if pos < s.len() && s[pos] == b'.' {
+            let frac_start = pos + 1;
+            let mut end = frac_start;
+            while end < s.len() && s[end].is_ascii_digit() {
+                end += 1;
+            }
+            digits += end - frac_start;
+            if digits > 0 {
+                pos = end;
+            }
+        }
+        if digits == 0 {
+            return None;
+        }
+        if exponent && pos < s.len() && (s[pos] == b'e' || s[pos] == b'E') {
+            let mut end = pos + 1;
+            if end < s.len() && (s[end] == b'+' || s[end] == b'-') {
+                end += 1;
+            }
+            let exp_start = end;
+            while end < s.len() && s[end].is_ascii_digit() {
+                end += 1;
+            }
+            if end > exp_start {
+                pos = end;
+            }
+        }
+        Some(pos)
+    }
+
+    macro_rules! float_parse {
+        ($($t:ty),*) => {$(
+            impl Utf8Parse for $t {
+                fn parse_utf8(s: &[u8], format: char) -> Option<(Self, usize)> {
+                    let exponent = match format {
+                        '\0' | 'G' | 'g' | 'E' | 'e' => true,
+                        'F' | 'f' => false,
+                        _ => invalid_format(),
+                    };
+                    let n = float_length(s, exponent)?;
+                    let x = core::str::from_utf8(&s[..n]).ok()?.parse::<$t>().ok()?;
+                    Some((x, n))
+                }
+            }
+        )*};
+    }
+
+    float_parse!(f32, f64);
+
+    #[cfg(feature = "guid")]
+    impl Utf8Parse for crate::Guid_::Guid {
+        fn parse_utf8(s: &[u8], format: char) -> Option<(Self, usize)> {
+            let (open, close, len) = match format {
+                '\0' | 'D' => (None, None, 36),
+                'N' => (None, None, 32),
+                'B' => (Some(b'{'), Some(b'}'), 38),
+                'P' => (Some(b'('), Some(b')'), 38),
+                _ => invalid_format(),
+            };
+            if s.len() < len || open.is_some_and(|c| s[0] != c) || close.is_some_and(|c| s[len - 1] != c) {
+                return None;
+            }
+            let inner = if open.is_some() { &s[1..len - 1] } else { &s[..len] };
+            if inner.len() == 36 && [8, 13, 18, 23].iter().any(|&i| inner[i] != b'-') {
+                return None;
+            }
+            let uuid = uuid::Uuid::try_parse_ascii(inner).ok()?;
+            Some((crate::Guid_::Guid(uuid), len))
+        }
+    }
+
+    impl Utf8Parse for crate::TimeSpan_::TimeSpan {
+        fn parse_utf8(s: &[u8], format: char) -> Option<(Self, usize)> {
+            use crate::TimeSpan_::*;
+            let mut pos = 0;
+            let negative = s.first() == Some(&b'-');
+            if negative {
+                pos += 1;
+            }
+            let first = parse_digits(s, &mut pos, 8)?;
+            let (days, hours, mins, secs) = match format {
+                '\0' | 'c' | 't' | 'T' => {
+                    let (days, hours) = if expect(s, &mut pos, b'.').is_some() {
+                        (first, parse_digits(s, &mut pos, 2)?)
+                    } else {
+                        (0, first)
+                    };
+                    expect(s, &mut pos, b':')?;
+                    let mins = parse_digits(s, &mut pos, 2)?;
+                    expect(s, &mut pos, b':')?;
+                    (days, hours, mins, parse_digits(s, &mut pos, 2)?)
+                }
+                'g' | 'G' => {
+                    expect(s, &mut pos, b':')?;
+                    let second = parse_digits(s, &mut pos, 2)?;
+                    expect(s, &mut pos, b':')?;
+                    let third = parse_digits(s, &mut pos, 2)?;
+                    let mut with_days = pos;
+                    if expect(s, &mut with_days, b':').is_some() {
+                        if let Some(fourth) = parse_digits(s, &mut with_days, 2) {
+                            pos = with_days;
+                            (first, second, third, fourth)
+                        } else {
+                            (0, first, second, third)
+                        }
+                    } else if format == 'G' {
+                        return None;
+                    } else {
+                        (0, first, second, third)
+                    }
+                }
+                _ => invalid_format(),
+            };
+            let mut frac = 0;
+            let mut frac_pos = pos;
+            if expect(s, &mut frac_pos, b'.').is_some() {
+                let start = frac_pos;
+                if let Some(f) = parse_digits(s, &mut frac_pos, 7) {
+                    frac = f * 10u64.pow(7 - (frac_pos - start) as u32);
+                    pos = frac_pos;
+                }
+            }
+            if hours > 23 || mins > 59 || secs > 59 || days > 10675199 {
+                return None;
+            }
+            let ticks = days as i64 * ticks_per_day
+                + hours as i64 * ticks_per_hour
+                + mins as i64 * ticks_per_minute
+                + secs as i64 * ticks_per_second
+                + frac as i64;
+            let ticks = if negative { -ticks } else { ticks };
+            Some((TimeSpan::from_ticks(ticks), pos))
+        }
+    }
+
+    // days since 0001-01-01 (proleptic Gregorian calendar)
+    fn days_from_civil(y: u64, m: u64, d: u64) -> u64 {
+        let (y, m) = if m <= 2 { (y - 1, m + 9) } else { (y, m - 3) };
+        let era = y / 400;
+        let yoe = y % 400;
+        let doy = (153 * m + 2) / 5 + d - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146097 + doe + 59 - 365
+    }
+
+    fn days_in_month(y: u64, m: u64) -> u64 {
+        match m {
+            2 if y.is_multiple_of(4) && (!y.is_multiple_of(100) || y.is_multiple_of(400)) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    #[cfg(feature = "datetime")]
+    fn parse_date_time(s: &[u8], format: char) -> Option<(i64, i32, Option<i64>, usize)> {
+        use crate::TimeSpan_::*;
+        let mut pos = 0;
+        let mut day_of_week = None;
+        let (y, mo, d) = match format {
+            '\0' | 'G' => {
+                let mo = parse_fixed(s, &mut pos, 2)?;
+                expect(s, &mut pos, b'/')?;
+                let d = parse_fixed(s, &mut pos, 2)?;
+                expect(s, &mut pos, b'/')?;
+                let y = parse_fixed(s, &mut pos, 4)?;
+                expect(s, &mut pos, b' ')?;
+                (y, mo, d)
+            }
+            'R' | 'l' => {
+                let name = |pos: usize, names: &[&str]| {
+                    let word = s.get(pos..pos + 3)?;
+                    names.iter().position(|n| {
+                        let n = n.as_bytes();
+                        let first = if format == 'l' { n[0].to_ascii_lowercase() } else { n[0] };
+                        first == word[0] && n[1..] == word[1..]
+                    })
+                };
+                day_of_week = Some(name(pos, &day_names)? as u64);
+                pos += 3;
+                expect(s, &mut pos, b',')?;
+                expect(s, &mut pos, b' ')?;
+                let d = parse_fixed(s, &mut pos, 2)?;
+                expect(s, &mut pos, b' ')?;
+                let mo = name(pos, &month_names)? as u64 + 1;
+                pos += 3;
+                expect(s, &mut pos, b' ')?;
+                let y = parse_fixed(s, &mut pos, 4)?;
+                expect(s, &mut pos, b' ')?;
+                (y, mo, d)
+            }
+            'O' => {
+                let y = parse_fixed(s, &mut pos, 4)?;
+                expect(s, &mut pos, b'-')?;
+                let mo = parse_fixed(s, &mut pos, 2)?;
+                expect(s, &mut pos, b'-')?;
+                let d = parse_fixed(s, &mut pos, 2)?;
+                expect(s, &mut pos, b'T')?;
+                (y, mo, d)
+            }
+            _ => invalid_format(),
+        };
+        let h = parse_fixed(s, &mut pos, 2)?;
+        expect(s, &mut pos, b':')?;
+        let mi = parse_fixed(s, &mut pos, 2)?;
+        expect(s, &mut pos, b':')?;
+        let sec = parse_fixed(s, &mut pos, 2)?;
+        let (mut frac, mut kind, mut offset) = (0, 0, None);
+        match format {
+            'R' | 'l' => {
+                let gmt: &[u8] = if format == 'R' { b" GMT" } else { b" gmt" };
+                s.get(pos..pos + 4).filter(|w| *w == gmt)?;
+                pos += 4;
+            }
+            'O' => {
+                expect(s, &mut pos, b'.')?;
+                frac = parse_fixed(s, &mut pos, 7)?;
+                match s.get(pos) {
+                    Some(b'Z') => {
+                        pos += 1;
+                        kind = 1;
+                    }
+                    Some(&c) if c == b'+' || c == b'-' => {
+                        pos += 1;
+                        let oh = parse_fixed(s, &mut pos, 2)?;
+                        expect(s, &mut pos, b':')?;
+                        let om = parse_fixed(s, &mut pos, 2)?;
+                        if oh > 14 || om > 59 {
+                            return None;
+                        }
+                        let minutes = (oh * 60 + om) as i64;
+                        offset = Some(if c == b'-' { -minutes } else { minutes } * ticks_per_minute);
+                        kind = 2;
+                    }
+                    _ => (),
+                }
+            }
+            _ => (),
+        }
+        if y == 0 || mo == 0 || mo > 12 || d == 0 || d > days_in_month(y, mo) || h > 23 || mi > 59 || sec > 59 {
+            return None;
+        }
+        let days = days_from_civil(y, mo, d);
+        // the day of the week must match the date (0001-01-01 was a Monday)
+        if day_of_week.is_some_and(|dow| (days + 1) % 7 != dow) {
+            return None;
+        }
+        let ticks = days as i64 * ticks_per_day
+            + h as i64 * ticks_per_hour
+            + mi as i64 * ticks_per_minute
+            + sec as i64 * ticks_per_second
+            + frac as i64;
+        Some((ticks, kind, offset, pos))
+    }
+
+    #[cfg(feature = "datetime")]
+    impl Utf8Parse for crate::DateTime_::DateTime {
+        fn parse_utf8(s: &[u8], format: char) -> Option<(Self, usize)> {
+            use crate::DateTime_::DateTime;
+            let (ticks, kind, offset, n) = parse_date_time(s, format)?;
+            let dt = match offset {
+                // a time with an offset is converted to local time
+                Some(offset) => DateTime::new_ticks_kind(ticks - offset, 1).toLocalTime(),
+                None => DateTime::new_ticks_kind(ticks, kind),
+            };
+            Some((dt, n))
+        }
+    }
+}
@@ -3,9 +3,13 @@ module Fable_Library_Rust
 open Fable.Core.Rust
 
 let _imports () =
+    importAll "./Arena.rs"
+    importAll "./Assert.rs"
     importAll "./Async.rs"
     importAll "./BigInt.rs"
     importAll "./BitConverter.rs"
+    importAll "./Calendar.rs"
+    importAll "./Char.rs"
     importAll "./Convert.rs"
     importAll "./DateOnly.rs"
     importAll "./DateTime.rs"
@@ -13,15 +17,28 @@ let _imports () =
     importAll "./Decimal.rs"
     importAll "./Encoding.rs"
     importAll "./Exception.rs"
+    importAll "./Ffi.rs"
+    importAll "./Globalization.rs"
     importAll "./Guid.rs"
     importAll "./HashMap.rs"
     importAll "./HashSet.rs"
     importAll "./Interop.rs"
+    importAll "./IO.rs"
+    importAll "./Metrics.rs"
     importAll "./Native.rs"
     importAll "./NativeArray.rs"
     importAll "./Numeric.rs"
+    importAll "./ObjectPool.rs"
+    importAll "./PeriodicTimer.rs"
+    importAll "./Printf.rs"
+    importAll "./Random.rs"
+    importAll "./RateLimiting.rs"
     importAll "./RegExp.rs"
+    importAll "./Stopwatch.rs"
     importAll "./String.rs"
     importAll "./TimeOnly.rs"
     importAll "./TimeSpan.rs"
+    importAll "./TimeZoneInfo.rs"
+    importAll "./Timer.rs"
+    importAll "./Utf8Text.rs"
     ()
@@ -353,6 +353,101 @@ let ``Decimal floor works`` () =
 let ``Decimal pown works`` () =
     pown 2.2M 3 |> equal 10.648M
 
+[<Fact>]
+let ``Decimal round with MidpointRounding works`` () =
+    Math.Round(2.5M, MidpointRounding.AwayFromZero) |> equal 3M
+    Math.Round(-2.5M, MidpointRounding.AwayFromZero) |> equal -3M
+    Math.Round(2.5M, MidpointRounding.ToEven) |> equal 2M
+    Math.Round(2.59M, MidpointRounding.ToZero) |> equal 2M
+    Math.Round(2.345M, 2, MidpointRounding.AwayFromZero) |> equal 2.35M
+    Math.Round(2.345M, 2, MidpointRounding.ToEven) |> equal 2.34M
+    Math.Round(-2.341M, 2, MidpointRounding.ToNegativeInfinity) |> equal -2.35M
+    Math.Round(2.341M, 2, MidpointRounding.ToPositiveInfinity) |> equal 2.35M
+    Decimal.Round(2.5M, MidpointRounding.AwayFromZero) |> equal 3M
+
+[<Fact>]
+let ``Decimal round with invalid digits fails`` () =
+    throwsAnyError (fun () -> Math.Round(1.5M, 29))
+    throwsAnyError (fun () -> Math.Round(1.5M, -1))
+
+[<Fact>]
+let ``Decimal results keep the scale`` () =
+    string 1.10M |> equal "1.10"
+    string (1.10M * 2M) |> equal "2.20"
+    string (1.10M + 2.205M) |> equal "3.305"
+    string (1.10M - 1.10M) |> equal "0.00"
+    string (Math.Round(1.20M, 3)) |> equal "1.20"
+    string (Math.Round(1.255M, 2)) |> equal "1.26"
+    string (Decimal.Floor 1.50M) |> equal "1"
+    string (Decimal.Truncate -0.5M) |> equal "0"
+
+[<Fact>]
+let ``Decimal GetBits keeps the sign and scale`` () =
+    Decimal.GetBits(-1M) |> equal [| 1; 0; 0; Int32.MinValue |]
+    Decimal.GetBits(-1.5M) |> equal [| 15; 0; 0; Int32.MinValue ||| (1 <<< 16) |]
+    Decimal.GetBits(1.10M) |> equal [| 110; 0; 0; 2 <<< 16 |]
+    Decimal(Decimal.GetBits(-1.5M)) |> equal -1.5M
+
+[<Fact>]
+let ``Decimal constructor with invalid bits fails`` () =
+    throwsAnyError (fun () -> Decimal([| 1; 0; 0; 1 |]))
+    throwsAnyError (fun () -> Decimal([| 1; 0; 0; 29 <<< 16 |]))
+    throwsAnyError (fun () -> Decimal(1, 0, 0, false, 29uy))
+
+[<Fact>]
+let ``Decimal from float keeps the significant digits`` () =
+    decimal 0.1 |> equal 0.1M
+    decimal 0.1f |> equal 0.1M
+    decimal 0.1234567890123456789 |> equal 0.123456789012346M
+    decimal 123456789012345678.0 |> equal 123456789012346000M
+    decimal 1.5e-27 |> equal 0.0000000000000000000000000015M
+
+[<Fact>]
+let ``Decimal division by zero fails`` () =
+    throwsError "Attempted to divide by zero." (fun () -> Decimal.Divide(1M, 0M))
+    throwsError "Attempted to divide by zero." (fun () -> Decimal.Remainder(1M, 0M))
+
+[<Fact>]
+let ``Decimal overflow fails`` () =
+    throwsError "Value was either too large or too small for a Decimal." (fun () -> Decimal.Multiply(Decimal.MaxValue, 2M))
+    throwsAnyError (fun () -> Decimal.Add(Decimal.MaxValue, 1M))
+    throwsAnyError (fun () -> decimal 1e29)
+    throwsAnyError (fun () -> int8 200M)
+
+[<Fact>]
+let ``Checked arithmetic operators work`` () =
+    Checked.(+) 2 3 |> equal 5
+    Checked.(-) 5uy 3uy |> equal 2uy
+    Checked.(*) -4L 3L |> equal -12L
+    Checked.(~-) 5s |> equal -5s
+    Checked.(+) 1.5 2.0 |> equal 3.5
+
+[<Fact>]
+let ``Checked arithmetic operators fail on overflow`` () =
+    let msg = "Arithmetic operation resulted in an overflow."
+    throwsError msg (fun () -> Checked.(+) Int32.MaxValue 1)
+    throwsError msg (fun () -> Checked.(-) 0u 1u)
+    throwsError msg (fun () -> Checked.(*) Int64.MaxValue 2L)
+    throwsError msg (fun () -> Checked.(~-) SByte.MinValue)
+    Checked.(+) Double.MaxValue Double.MaxValue |> equal Double.PositiveInfinity
+
+[<Fact>]
+let ``Checked conversions work`` () =
+    Checked.int 2.9 |> equal 2
+    Checked.int -2.9 |> equal -2
+    Checked.byte 255L |> equal 255uy
+    Checked.int64 Int32.MinValue |> equal -2147483648L
+    Checked.uint32 42 |> equal 42u
+
+[<Fact>]
+let ``Checked conversions fail on overflow`` () =
+    let msg = "Arithmetic operation resulted in an overflow."
+    throwsError msg (fun () -> Checked.byte 256)
+    throwsError msg (fun () -> Checked.uint32 -1)
+    throwsError msg (fun () -> Checked.int 3e9)
+    throwsError msg (fun () -> Checked.int Double.NaN)
+    throwsError msg (fun () -> Checked.sbyte 128uy)
+
 [<Fact>]
 let ``Int64 Infix add can be generated`` () =
     4L + 2L |> equal 6L
@@ -882,20 +977,33 @@ let ``decr works`` () =
     i.Value <- i.Value - 1
     i.Value |> equal 2
 
-// [<Fact>]
-// let ``System.Random works`` () =
-//     let rnd = Random()
-//     let x = rnd.Next(5)
-//     (x >= 0 && x < 5) |> equal true
-//     let y = rnd.NextDouble()
-//     (y >= 0.0 && y < 1.0) |> equal true
-
-// // Note: Test could fail sometime during life of universe, if it picks all zeroes.
-// [<Fact>]
-// let ``System.Random.NextBytes works`` () =
-//     let buffer = Array.create 16 0uy // guid-sized buffer
-//     Random().NextBytes(buffer)
-//     buffer = Array.create 16 0uy |> equal false
+[<Fact>]
+let ``System.Random works`` () =
+    let rnd = Random()
+    let x = rnd.Next(5)
+    (x >= 0 && x < 5) |> equal true
+    let y = rnd.NextDouble()
+    (y >= 0.0 && y < 1.0) |> equal true
+
+[<Fact>]
+let ``System.Random with range works`` () =
+    let rnd = Random()
+    let x = rnd.Next(-3, 3)
+    (x >= -3 && x < 3) |> equal true
+    rnd.Next(7, 7) |> equal 7
+
+[<Fact>]
+let ``System.Random with seed is deterministic`` () =
+    let rnd1 = Random(42)
+    let rnd2 = Random(42)
+    rnd1.Next() |> equal (rnd2.Next())
+
+// Note: Test could fail sometime during life of universe, if it picks all zeroes.
+[<Fact>]
+let ``System.Random.NextBytes works`` () =
+    let buffer = Array.create 16 0uy // guid-sized buffer
+    Random().NextBytes(buffer)
+    buffer = Array.create 16 0uy |> equal false
 
 [<Fact>]
 let ``Long integer equality works`` () =
@@ -979,6 +1087,62 @@ let ``Big integer from byte array works`` () =
     Numerics.BigInteger([|231uy; 216uy; 2uy; 164uy; 86uy; 149uy; 8uy; 199uy; 62uy; 0uy; 92uy|]) |> equal 111222333444555666777888999I
     Numerics.BigInteger([|25uy; 39uy; 253uy; 91uy; 169uy; 106uy; 247uy; 56uy; 193uy; 255uy; 163uy|]) |> equal -111222333444555666777888999I
 
+[<Fact>]
+let ``Big integer byte array with sign and endianness works`` () =
+    (128I).ToByteArray(isUnsigned=true, isBigEndian=true) |> equal [|128uy|]
+    (-256I).ToByteArray(isUnsigned=false, isBigEndian=true) |> equal [|255uy; 0uy|]
+    (128I).GetByteCount() |> equal 2
+    (128I).GetByteCount(true) |> equal 1
+    Numerics.BigInteger([|1uy; 0uy|], isUnsigned=true, isBigEndian=true) |> equal 256I
+    Numerics.BigInteger([|255uy|], isUnsigned=true, isBigEndian=false) |> equal 255I
+    throwsAnyError (fun () -> (-1I).ToByteArray(isUnsigned=true, isBigEndian=false))
+
+[<Fact>]
+let ``BigInt.ModPow works`` () =
+    bigint.ModPow(4I, 13I, 497I) |> equal 445I
+    bigint.ModPow(-2I, 3I, 5I) |> equal -3I
+    bigint.ModPow(4I, 13I, -497I) |> equal 445I
+    bigint.ModPow(7I, 0I, 1I) |> equal 0I
+    throwsAnyError (fun () -> bigint.ModPow(2I, -1I, 5I))
+    throwsAnyError (fun () -> bigint.ModPow(2I, 3I, 0I))
+
+[<Fact>]
+let ``BigInt.GreatestCommonDivisor works`` () =
+    bigint.GreatestCommonDivisor(-12I, 18I) |> equal 6I
+    bigint.GreatestCommonDivisor(0I, 7I) |> equal 7I
+    bigint.GreatestCommonDivisor(0I, 0I) |> equal 0I
+
+[<Fact>]
+let ``BigInt.Log works with large values`` () =
+    Math.Round(bigint.Log10(bigint.Pow(10I, 400)), 6) |> equal 400.0
+    Math.Round(bigint.Log(bigint.Pow(2I, 2000), 2.0), 6) |> equal 2000.0
+    bigint.Log(8I, 1.0) |> Double.IsNaN |> equal true
+    bigint.Log(-8I) |> Double.IsNaN |> equal true
+    bigint.Log 0I |> equal Double.NegativeInfinity
+
+[<Fact>]
+let ``BigInt.Parse works`` () =
+    bigint.Parse("123456789012345678901234567890") |> equal 123456789012345678901234567890I
+    bigint.Parse(" -1,234 ", Globalization.NumberStyles.Integer ||| Globalization.NumberStyles.AllowThousands) |> equal -1234I
+    bigint.Parse("FF", Globalization.NumberStyles.HexNumber) |> equal -1I
+    bigint.Parse("0FF", Globalization.NumberStyles.HexNumber) |> equal 255I
+    bigint "0x1F" |> equal 31I
+    bigint "0b101" |> equal 5I
+    throwsAnyError (fun () -> bigint.Parse("1.5"))
+    bigint.TryParse("0FF", Globalization.NumberStyles.HexNumber, Globalization.CultureInfo.InvariantCulture) |> equal (true, 255I)
+
+[<Fact>]
+let ``BigInt bitwise operators work`` () =
+    (-6I &&& 5I) |> equal 0I
+    (-6I ||| 5I) |> equal -1I
+    (-6I ^^^ 5I) |> equal -1I
+    ~~~5I |> equal -6I
+    (1I <<< 70) |> equal 1180591620717411303424I
+    (-5I >>> 1) |> equal -3I
+    (-5I <<< -1) |> equal -3I
+    bigint.IsPow2 0I |> equal false
+    bigint.IsPow2 64I |> equal true
+
 [<Fact>]
 let ``Member values of decimal type can be compared`` () = // See #747
     1M < 2M |> equal true
@@ -675,6 +675,12 @@ let ``Array.countBy works`` () =
     let ys = xs |> Array.countBy (fun x -> x % 2)
     ys |> equal [|(1, 2); (0, 2)|]
 
+[<Fact>]
+let ``Array.countBy and groupBy keep first occurrence order`` () =
+    let xs = [|3; 1; 4; 1; 5; 9; 2; 6; 5; 3; 5|]
+    xs |> Array.countBy id |> equal [|(3, 2); (1, 2); (4, 1); (5, 3); (9, 1); (2, 1); (6, 1)|]
+    xs |> Array.groupBy (fun x -> x % 3) |> equal [|(0, [|3; 9; 6; 3|]); (1, [|1; 4; 1|]); (2, [|5; 2; 5; 5|])|]
+
 [<Fact>]
 let ``Array.map works`` () =
     let xs = [|1.|]
@@ -688,6 +694,21 @@ let ``Array.map doesn't execute side effects twice`` () = // See #1140
     [| i (); i (); i () |] |> Array.map (fun x -> x + 1) |> ignore
     equal 3 c
 
+[<Fact>]
+let ``Array.map with closures works`` () =
+    let k = 10
+    let xs = [|1; 2; 3|]
+    xs |> Array.map (fun x -> x * k) |> equal [|10; 20; 30|]
+    let f = fun x -> x + k
+    xs |> Array.map f |> equal [|11; 12; 13|]
+
+[<Fact>]
+let ``Array.iter with mutable closures works`` () =
+    let xs = [|1; 2; 3|]
+    let mutable sum = 0
+    xs |> Array.iter (fun x -> sum <- sum + x + xs.Length)
+    sum |> equal 15
+
 [<Fact>]
 let ``Array.map2 works`` () =
     let xs = [|1.|]
@@ -863,6 +884,16 @@ let ``Array.sort works`` () =
     ys |> Array.sort |> equal [|"B"; "a"; "c"; "d"|]
     xs[0] |> equal 3 // Make sure there is no side effects
 
+[<Fact>]
+let ``Array pipelines don't modify shared arrays`` () =
+    let xs = [|3; 4; 1; -3; 2; 10|]
+    let ys = xs |> Array.map (fun x -> x * 2) |> Array.filter (fun x -> x > 0) |> Array.sort
+    ys |> equal [|2; 4; 6; 8; 20|]
+    let zs = Array.append (Array.map id xs) xs
+    zs |> equal [|3; 4; 1; -3; 2; 10; 3; 4; 1; -3; 2; 10|]
+    xs |> Array.filter (fun x -> x > 2) |> equal [|3; 4; 10|]
+    xs |> equal [|3; 4; 1; -3; 2; 10|]
+
 [<Fact>]
 let ``Array.sort with tuples works`` () =
     let xs = [|3; 1; 1; -3|]
@@ -918,6 +949,21 @@ let ``Array.sum with numbers works`` () =
     let xs = [|1.; 2.|]
     xs |> Array.sum |> equal 3.
 
+[<Fact>]
+let ``Array numeric aggregates work with large arrays`` () =
+    let xs = Array.init 1003 (fun i -> i + 1)
+    xs |> Array.sum |> equal 503506
+    xs |> Array.min |> equal 1
+    xs |> Array.max |> equal 1003
+    xs |> Array.contains 777 |> equal true
+    xs |> Array.contains 0 |> equal false
+    let ys = Array.init 101 float
+    ys |> Array.average |> equal 50.
+    Array.fill ys 10 5 -1.
+    ys |> Array.min |> equal -1.
+    ys[15] |> equal 15.
+    [| 255uy; 2uy |] |> Array.sum |> equal 1uy
+
 [<Fact>]
 let ``Array.sumBy with numbers works`` () =
     let xs = [|1.; 2.|]
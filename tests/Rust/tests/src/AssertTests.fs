@@ -0,0 +1,45 @@
+module Fable.Tests.AssertTests
+
+open System
+open Util.Testing
+
+type Assert = Xunit.Assert
+
+[<Fact>]
+let ``Assert.Equal works`` () =
+    Assert.Equal(3, 1 + 2)
+    Assert.Equal("ab", "a" + "b")
+    Assert.NotEqual(3, 4)
+
+[<Fact>]
+let ``Assert.Equal with precision works`` () =
+    Assert.Equal(1.2345, 1.2349, 2)
+    Assert.Equal(1.0, 1.05, 0.1)
+
+[<Fact>]
+let ``Assert.Equal with arrays works`` () =
+    Assert.Equal<int>([| 1; 2; 3 |], [| 1; 2; 3 |])
+
+[<Fact>]
+let ``Assert.True and Assert.False work`` () =
+    Assert.True(1 < 2)
+    Assert.False(1 > 2)
+
+[<Fact>]
+let ``Assertion failures can be caught`` () =
+    throwsAnyError (fun () -> Assert.Equal(1, 2))
+    throwsAnyError (fun () -> Assert.Equal<int>([| 1; 2 |], [| 1; 3 |]))
+    throwsAnyError (fun () -> Assert.True(false))
+
+[<Fact>]
+let ``Assert.Throws checks the exception type`` () =
+    Assert.Throws<ArgumentException>(Action(fun () -> raise (ArgumentException("bad")))) |> ignore
+    throwsAnyError (fun () ->
+        Assert.Throws<ArgumentException>(Action(fun () -> raise (InvalidOperationException("bad")))) |> ignore)
+    throwsAnyError (fun () -> Assert.Throws<ArgumentException>(Action(fun () -> ())) |> ignore)
+
+[<Fact>]
+let ``Assert.Throws checks the parameter name`` () =
+    Assert.Throws<ArgumentException>("x", Action(fun () -> raise (ArgumentException("bad", "x")))) |> ignore
+    throwsAnyError (fun () ->
+        Assert.Throws<ArgumentException>("y", Action(fun () -> raise (ArgumentException("bad", "x")))) |> ignore)
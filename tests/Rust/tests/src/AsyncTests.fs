@@ -93,6 +93,36 @@ let shouldExecuteMutationOnTask () =
     do comp.Result
     x |> equal 1
 
+#if FABLE_COMPILER
+open Fable.Core.RustInterop
+
+[<Fact>]
+let shouldAwaitRustFutureInAsync () =
+    let comp = async {
+        let! x = Async.AwaitFuture(emitRustExpr () "Box::pin(async { 42 })")
+        return x + 1
+    }
+    comp |> Async.RunSynchronously |> equal 43
+
+[<Fact>]
+let shouldAwaitRustFutureInTask () =
+    let comp = task {
+        let! x = Future.toTask (emitRustExpr () "Box::pin(async { 2 })")
+        return x * 3
+    }
+    comp.Result |> equal 6
+
+[<Fact>]
+let shouldConvertAsyncToRustFuture () =
+    let fut = Async.ToFuture(async { return 5 })
+    Async.AwaitFuture fut |> Async.RunSynchronously |> equal 5
+
+[<Fact>]
+let shouldConvertTaskToRustFuture () =
+    let fut = Future.ofTask (Task.FromResult "done")
+    Async.AwaitFuture fut |> Async.RunSynchronously |> equal "done"
+#endif
+
 // [<Fact>]
 // let ``should execute mutation on thread unsafe`` () =
 //     let mutable x = 1
@@ -161,6 +191,71 @@ let ``Lock should return result`` () =
     let res = lock o (fun () -> { x = "42"})
     res.x |> equal "42"
 
+[<Fact>]
+let ``PeriodicTimer.WaitForNextTickAsync works`` () =
+    use timer = new PeriodicTimer(System.TimeSpan.FromMilliseconds(20.))
+    let sw = System.Diagnostics.Stopwatch.StartNew()
+    timer.WaitForNextTickAsync().AsTask().Result |> equal true
+    timer.WaitForNextTickAsync().AsTask().Result |> equal true
+    sw.ElapsedMilliseconds >= 40L |> equal true
+
+[<Fact>]
+let ``PeriodicTimer.Dispose completes pending waits with false`` () =
+    let timer = new PeriodicTimer(System.TimeSpan.FromSeconds(10.))
+    let pending = timer.WaitForNextTickAsync().AsTask()
+    timer.Dispose()
+    pending.Result |> equal false
+    timer.WaitForNextTickAsync().AsTask().Result |> equal false
+
+[<Fact>]
+let ``PeriodicTimer validates the period`` () =
+    (fun () -> new PeriodicTimer(System.TimeSpan.Zero) |> ignore)
+    |> throwsErrorContaining "period"
+
+[<Fact>]
+let ``System.Threading.Timer calls back periodically`` () =
+    let mutable count = 0
+    let timer = new Timer((fun state -> count <- count + 1), null, 0, 20)
+    Thread.Sleep(70)
+    timer.Dispose()
+    count >= 2 |> equal true
+
+[<Fact>]
+let ``System.Threading.Timer.Change works`` () =
+    let mutable count = 0
+    use timer = new Timer((fun _ -> count <- count + 1), null, Timeout.Infinite, Timeout.Infinite)
+    Thread.Sleep(30)
+    count |> equal 0
+    timer.Change(System.TimeSpan.FromMilliseconds(10.), System.TimeSpan.Zero) |> equal true
+    Thread.Sleep(60)
+    count |> equal 1
+
+[<Fact>]
+let ``System.Timers.Timer raises Elapsed`` () =
+    let mutable count = 0
+    use timer = new System.Timers.Timer(20.)
+    timer.Elapsed.Add(fun _ -> count <- count + 1)
+    timer.AutoReset <- false
+    timer.Enabled |> equal false
+    timer.Start()
+    timer.Enabled |> equal true
+    Thread.Sleep(70)
+    count |> equal 1
+    timer.Enabled |> equal false
+
+[<Fact>]
+let ``System.Timers.Timer.Stop works`` () =
+    let mutable count = 0
+    use timer = new System.Timers.Timer(20.)
+    timer.Elapsed.Add(fun _ -> count <- count + 1)
+    timer.Start()
+    Thread.Sleep(70)
+    timer.Stop()
+    let elapsed = count
+    elapsed >= 2 |> equal true
+    Thread.Sleep(40)
+    count |> equal elapsed
+
 //[<Fact>]
 // let testShouldMutateAndLock () =
 //     let o = new System.Object()
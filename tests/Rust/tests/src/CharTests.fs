@@ -1,6 +1,8 @@
 module Fable.Tests.CharTests
 
 open System
+open System.Globalization
+open System.Text
 open Util.Testing
 
 [<Fact>]
@@ -33,17 +35,78 @@ let ``Char.ToLowerInvariant works`` () =
 let ``Char.ToString works`` () =
     Char.ToString('b') |> equal "b"
 
-// [<Fact>]
-// let ``Char.GetUnicodeCategory works`` () =
-//     Char.GetUnicodeCategory('a') |> equal UnicodeCategory.LowercaseLetter
-//     Char.GetUnicodeCategory('1') |> equal UnicodeCategory.DecimalDigitNumber
+[<Fact>]
+let ``Char.GetUnicodeCategory works`` () =
+    Char.GetUnicodeCategory('a') |> equal UnicodeCategory.LowercaseLetter
+    Char.GetUnicodeCategory('1') |> equal UnicodeCategory.DecimalDigitNumber
 
-// [<Fact>]
-// let ``Char.GetUnicodeCategory with two args works`` () =
-//     let str = "Ba6"
-//     Char.GetUnicodeCategory(str,0) |> int |> equal 0 //UnicodeCategory.UppercaseLetter
-//     Char.GetUnicodeCategory(str,1) |> int |> equal 1 //UnicodeCategory.LowercaseLetter
-//     Char.GetUnicodeCategory(str,2) |> int |> equal 8 //UnicodeCategory.DecimalDigitNumber
+[<Fact>]
+let ``Char.GetUnicodeCategory with two args works`` () =
+    let str = "Ba6"
+    Char.GetUnicodeCategory(str,0) |> int |> equal 0 //UnicodeCategory.UppercaseLetter
+    Char.GetUnicodeCategory(str,1) |> int |> equal 1 //UnicodeCategory.LowercaseLetter
+    Char.GetUnicodeCategory(str,2) |> int |> equal 8 //UnicodeCategory.DecimalDigitNumber
+
+[<Fact>]
+let ``Char.GetUnicodeCategory works with non-Latin characters`` () =
+    Char.GetUnicodeCategory('Ж') |> equal UnicodeCategory.UppercaseLetter
+    Char.GetUnicodeCategory('ǅ') |> equal UnicodeCategory.TitlecaseLetter
+    Char.GetUnicodeCategory('ʰ') |> equal UnicodeCategory.ModifierLetter
+    Char.GetUnicodeCategory('家') |> equal UnicodeCategory.OtherLetter
+    Char.GetUnicodeCategory('\u0301') |> equal UnicodeCategory.NonSpacingMark
+    Char.GetUnicodeCategory('٣') |> equal UnicodeCategory.DecimalDigitNumber
+    Char.GetUnicodeCategory('Ⅻ') |> equal UnicodeCategory.LetterNumber
+    Char.GetUnicodeCategory('½') |> equal UnicodeCategory.OtherNumber
+    Char.GetUnicodeCategory('\u3000') |> equal UnicodeCategory.SpaceSeparator
+    Char.GetUnicodeCategory('\u2028') |> equal UnicodeCategory.LineSeparator
+    Char.GetUnicodeCategory('\u200B') |> equal UnicodeCategory.Format
+    Char.GetUnicodeCategory('\uE000') |> equal UnicodeCategory.PrivateUse
+    Char.GetUnicodeCategory('‿') |> equal UnicodeCategory.ConnectorPunctuation
+    Char.GetUnicodeCategory('—') |> equal UnicodeCategory.DashPunctuation
+    Char.GetUnicodeCategory('「') |> equal UnicodeCategory.OpenPunctuation
+    Char.GetUnicodeCategory('」') |> equal UnicodeCategory.ClosePunctuation
+    Char.GetUnicodeCategory('«') |> equal UnicodeCategory.InitialQuotePunctuation
+    Char.GetUnicodeCategory('»') |> equal UnicodeCategory.FinalQuotePunctuation
+    Char.GetUnicodeCategory('¿') |> equal UnicodeCategory.OtherPunctuation
+    Char.GetUnicodeCategory('∑') |> equal UnicodeCategory.MathSymbol
+    Char.GetUnicodeCategory('€') |> equal UnicodeCategory.CurrencySymbol
+    Char.GetUnicodeCategory('^') |> equal UnicodeCategory.ModifierSymbol
+    Char.GetUnicodeCategory('©') |> equal UnicodeCategory.OtherSymbol
+    Char.GetUnicodeCategory('\u0378') |> equal UnicodeCategory.OtherNotAssigned
+
+[<Fact>]
+let ``CharUnicodeInfo.GetUnicodeCategory works`` () =
+    CharUnicodeInfo.GetUnicodeCategory('β') |> equal UnicodeCategory.LowercaseLetter
+    CharUnicodeInfo.GetUnicodeCategory("a€", 1) |> equal UnicodeCategory.CurrencySymbol
+
+[<Fact>]
+let ``Char classification works with non-Latin characters`` () =
+    Char.IsLetter('Ж') |> equal true
+    Char.IsLetter('٣') |> equal false
+    Char.IsDigit('٣') |> equal true
+    Char.IsDigit('½') |> equal false
+    Char.IsNumber('½') |> equal true
+    Char.IsNumber('Ⅻ') |> equal true
+    Char.IsLetterOrDigit('٣') |> equal true
+    Char.IsLetterOrDigit('\u0301') |> equal false
+    Char.IsUpper('Ж') |> equal true
+    Char.IsUpper('ǅ') |> equal false
+    Char.IsLower('ж') |> equal true
+    Char.IsLower('ʰ') |> equal false
+    Char.IsPunctuation('«') |> equal true
+    Char.IsPunctuation('「') |> equal true
+    Char.IsPunctuation('€') |> equal false
+    Char.IsSymbol('€') |> equal true
+    Char.IsSymbol('∑') |> equal true
+    Char.IsSymbol('©') |> equal true
+    Char.IsSymbol('.') |> equal false
+    Char.IsSeparator('\u3000') |> equal true
+    Char.IsSeparator('\u2029') |> equal true
+    Char.IsSeparator('	') |> equal false
+    Char.IsWhiteSpace('\u3000') |> equal true
+    Char.IsWhiteSpace('\u2028') |> equal true
+    Char.IsWhiteSpace('\u200B') |> equal false
+    Char.IsControl('\u200B') |> equal false
 
 [<Fact>]
 let ``Char.IsControl works`` () =
@@ -186,6 +249,56 @@ let ``Char.IsWhitespace works with two args`` () =
     Char.IsWhiteSpace(input, 0) |> equal true
     Char.IsWhiteSpace(input, 1) |> equal true
 
+[<Fact>]
+let ``Rune works`` () =
+    let r = Rune('a')
+    r.Value |> equal 97
+    r.IsAscii |> equal true
+    r.IsBmp |> equal true
+    r.Utf8SequenceLength |> equal 1
+    r.ToString() |> equal "a"
+    let emoji = Rune(0x1F600)
+    emoji.Value |> equal 0x1F600
+    emoji.IsAscii |> equal false
+    emoji.IsBmp |> equal false
+    emoji.Plane |> equal 1
+    emoji.Utf8SequenceLength |> equal 4
+    emoji.Utf16SequenceLength |> equal 2
+    emoji.ToString() |> equal "😀"
+    Rune.ReplacementChar.Value |> equal 0xFFFD
+
+[<Fact>]
+let ``Rune.IsValid and Rune.TryCreate work`` () =
+    Rune.IsValid(0x10FFFF) |> equal true
+    Rune.IsValid(0xD800) |> equal false
+    Rune.IsValid(0x110000) |> equal false
+    Rune.IsValid(-1) |> equal false
+    let ok, r = Rune.TryCreate(0x41)
+    ok |> equal true
+    r.Value |> equal 0x41
+    let ok, _ = Rune.TryCreate(0xDC00)
+    ok |> equal false
+    throwsAnyError (fun () -> Rune(0x110000))
+
+[<Fact>]
+let ``Rune classification works`` () =
+    Rune.IsLetter(Rune('Ж')) |> equal true
+    Rune.IsDigit(Rune(0x663)) |> equal true
+    Rune.IsWhiteSpace(Rune(0x3000)) |> equal true
+    Rune.IsUpper(Rune('a')) |> equal false
+    Rune.GetUnicodeCategory(Rune(0x1F600)) |> equal UnicodeCategory.OtherSymbol
+    Rune.GetUnicodeCategory(Rune(0x1D400)) |> equal UnicodeCategory.UppercaseLetter
+    Rune.ToUpperInvariant(Rune('é')).Value |> equal (int 'É')
+    Rune.ToLowerInvariant(Rune('Ж')).Value |> equal (int 'ж')
+
+[<Fact>]
+let ``String.EnumerateRunes works`` () =
+    "a😀b".EnumerateRunes()
+    |> Seq.map (fun r -> r.Value)
+    |> Seq.toArray
+    |> equal [| 97; 0x1F600; 98 |]
+    Rune.GetRuneAt("a😀b", 1).Value |> equal 0x1F600
+
 // [<Fact>]
 // let ``Char.IsHighSurrogate works`` () =
 //     Char.IsHighSurrogate('a') |> equal false
@@ -7,6 +7,7 @@ type UTest = A of int | B of int
 type RTest = { a: int; b: int }
 type STest = struct val A: int; new(a: int) = { A = a }; end
 type OTest(a) = member val A = a with get, set
+type KTest = { id: System.Guid; span: System.TimeSpan; date: System.DateOnly }
 
 // [<CustomEquality; CustomComparison>]
 // type UTest2 =
@@ -634,6 +635,15 @@ let ``Unchecked.compare works`` () =
     Unchecked.compare [2] [3] |> equal -1
     Unchecked.compare [3] [2] |> equal 1
 
+[<Fact>]
+let ``Unchecked.defaultof works`` () =
+    Unchecked.defaultof<int> |> equal 0
+    Unchecked.defaultof<float> |> equal 0.0
+    Unchecked.defaultof<bool> |> equal false
+    Unchecked.defaultof<int option> |> equal None
+    Unchecked.defaultof<struct (int * bool)> |> equal (struct (0, false))
+    Unchecked.defaultof<STest>.A |> equal 0
+
 [<Fact>]
 let ``DU comparison works`` () =
     let hasStatusReached expectedStatus status =
@@ -747,3 +757,30 @@ let ``LanguagePrimitives.FloatWithMeasure works`` () =
 let ``LanguagePrimitives.DecimalWithMeasure works`` () =
     let distance: decimal<m> = LanguagePrimitives.DecimalWithMeasure 1.0m
     distance |> equal 1.0m<m>
+
+[<Fact>]
+let ``Library value types work as Set and Map keys`` () =
+    let g1 = System.Guid.Parse("00000001-0000-0000-0000-000000000000")
+    let g2 = System.Guid.Parse("00000000-ffff-0000-0000-000000000000")
+    compare g1 g2 |> equal 1
+    Set.ofList [ g1; g2; g1 ] |> Set.toList |> equal [ g2; g1 ]
+    let spans = [ System.TimeSpan.FromHours(2.); System.TimeSpan.FromMinutes(5.) ]
+    spans |> List.sort |> List.head |> equal (System.TimeSpan.FromMinutes(5.))
+    let m = Map [ System.DateOnly(2020, 1, 2), "b"; System.DateOnly(2020, 1, 1), "a" ]
+    m |> Map.toList |> List.map snd |> equal [ "a"; "b" ]
+
+[<Fact>]
+let ``Records with library value type fields can be compared`` () =
+    let k1 = { id = System.Guid.Empty; span = System.TimeSpan.Zero; date = System.DateOnly(2020, 1, 1) }
+    let k2 = { k1 with span = System.TimeSpan.FromSeconds(1.) }
+    compare k1 k2 |> equal -1
+    k1 = { k1 with id = System.Guid.Empty } |> equal true
+    Set.ofList [ k2; k1 ] |> Set.minElement |> equal k1
+
+[<Fact>]
+let ``DateTime comparison ignores the kind`` () =
+    let d1 = System.DateTime(2020, 1, 1, 0, 0, 0, System.DateTimeKind.Utc)
+    let d2 = System.DateTime(2020, 1, 1, 0, 0, 0, System.DateTimeKind.Local)
+    d1 = d2 |> equal true
+    compare d1 d2 |> equal 0
+    hash d1 = hash d2 |> equal true
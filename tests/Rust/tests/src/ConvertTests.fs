@@ -45,6 +45,30 @@ let ``System.Decimal.TryParse works`` () =
     Decimal.TryParse "9SayWhat12Huh" |> equal (false, 0.0M)
     Decimal.TryParse "-1.5" |> equal (true, -1.5M)
 
+[<Fact>]
+let ``System.Decimal.Parse with NumberStyles works`` () =
+    Decimal.Parse("1,234.5") |> equal 1234.5M
+    Decimal.Parse("  -1.5  ") |> equal -1.5M
+    Decimal.Parse("1.5-", NumberStyles.Number) |> equal -1.5M
+    Decimal.Parse("(1.5)", NumberStyles.Number ||| NumberStyles.AllowParentheses) |> equal -1.5M
+    Decimal.Parse("¤1.5", NumberStyles.Currency) |> equal 1.5M
+    Decimal.Parse("1.5E2", NumberStyles.Float) |> equal 150M
+    throwsAnyError (fun () -> Decimal.Parse("1,234", NumberStyles.Float))
+    throwsAnyError (fun () -> Decimal.Parse("(1.5)", NumberStyles.Number))
+    throwsAnyError (fun () -> Decimal.Parse("1.5E2", NumberStyles.Number))
+    throwsAnyError (fun () -> Decimal.Parse("FF", NumberStyles.HexNumber))
+
+[<Fact>]
+let ``System.Double.Parse with NumberStyles works`` () =
+    Double.Parse("1,234.5") |> equal 1234.5
+    Double.Parse("-1.5e3") |> equal -1500.0
+    Double.Parse("1,234.5", NumberStyles.AllowThousands ||| NumberStyles.AllowDecimalPoint) |> equal 1234.5
+    Double.Parse("(2.5)", NumberStyles.Any) |> equal -2.5
+    Double.Parse("NaN") |> Double.IsNaN |> equal true
+    Double.Parse("-Infinity") |> equal Double.NegativeInfinity
+    throwsAnyError (fun () -> Double.Parse("1.5", NumberStyles.Integer))
+    throwsAnyError (fun () -> Double.Parse("(2.5)", NumberStyles.Float))
+
 [<Fact>]
 let ``System.Single.Parse works`` () =
     Single.Parse "1.5" |> equal 1.5f
@@ -160,6 +184,46 @@ let ``System.Int64.Parse with hex works`` () =
     throwsAnyError (fun () -> Int64.Parse("5foo", NumberStyles.HexNumber))
     throwsAnyError (fun () -> Int64.Parse("foo5", NumberStyles.HexNumber))
 
+[<Fact>]
+let ``System.Int32.Parse with NumberStyles works`` () =
+    Int32.Parse("1,000", NumberStyles.AllowThousands) |> equal 1000
+    Int32.Parse(" (42) ", NumberStyles.Integer ||| NumberStyles.AllowParentheses) |> equal -42
+    Int32.Parse("42-", NumberStyles.AllowTrailingSign) |> equal -42
+    Int32.Parse("1.00", NumberStyles.Number) |> equal 1
+    Int32.Parse(" FFFFFFFF ", NumberStyles.HexNumber) |> equal -1
+    UInt32.Parse("FFFFFFFF", NumberStyles.HexNumber) |> equal 4294967295u
+    throwsAnyError (fun () -> Int32.Parse("1,000"))
+    throwsAnyError (fun () -> Int32.Parse("1.5", NumberStyles.Number))
+    throwsAnyError (fun () -> Int32.Parse("0xFF", NumberStyles.HexNumber))
+
+[<Fact>]
+let ``Integer Parse accepts whitespace and sign`` () =
+    Int16.Parse("\t+42\n") |> equal 42s
+    SByte.Parse(" -128 ") |> equal -128y
+    Byte.Parse(" +255") |> equal 255uy
+    UInt32.Parse("-0") |> equal 0u
+    UInt64.Parse("18446744073709551615") |> equal 18446744073709551615UL
+    UInt16.Parse(" ff ", NumberStyles.HexNumber) |> equal 255us
+
+[<Fact>]
+let ``Integer Parse fails like .NET`` () =
+    throwsError "Value was either too large or too small for an Int32." (fun () -> Int32.Parse("99999999999"))
+    throwsError "Value was either too large or too small for a UInt32." (fun () -> UInt32.Parse("-1"))
+    throwsError "Value was either too large or too small for an unsigned byte." (fun () -> Byte.Parse("256"))
+    throwsError "Value was either too large or too small for a signed byte." (fun () -> SByte.Parse("1FF", NumberStyles.HexNumber))
+    throwsError "Value was either too large or too small for a UInt64." (fun () -> UInt64.Parse("18446744073709551616"))
+    throwsError "The input string '12a' was not in a correct format." (fun () -> Int32.Parse("12a"))
+    throwsError "The input string '- 1' was not in a correct format." (fun () -> Int64.Parse("- 1"))
+    throwsError "The input string '' was not in a correct format." (fun () -> UInt16.Parse(""))
+
+[<Fact>]
+let ``Integer TryParse fails like .NET`` () =
+    Int16.TryParse "70000" |> equal (false, 0s)
+    Byte.TryParse "-1" |> equal (false, 0uy)
+    UInt64.TryParse " 42 " |> equal (true, 42UL)
+    SByte.TryParse("-80", NumberStyles.HexNumber, CultureInfo.InvariantCulture) |> equal (false, 0y)
+    SByte.TryParse("80", NumberStyles.HexNumber, CultureInfo.InvariantCulture) |> equal (true, -128y)
+
 [<Fact>]
 let ``System.Int64.TryParse works`` () =
     Int64.TryParse "99" |> equal (true, 99L)
@@ -989,6 +1053,26 @@ let ``Convert.FromHexString works`` () =
     Convert.FromHexString("FAFBFCFDFE")
     |> equal [| 250uy; 251uy; 252uy; 253uy; 254uy |]
 
+[<Fact>]
+let ``Convert.ToHexString with offset and length works`` () =
+    let bytes = [| 0x0Auy; 0x1Buy; 0x2Cuy; 0x3Duy |]
+    Convert.ToHexString(bytes, 1, 2) |> equal "1B2C"
+    Convert.ToHexString(bytes, 4, 0) |> equal ""
+    Convert.ToHexString([||]) |> equal ""
+    throwsAnyError (fun () -> Convert.ToHexString(bytes, 3, 2))
+    throwsAnyError (fun () -> Convert.ToHexString(bytes, 0, -1))
+
+[<Fact>]
+let ``Convert.FromHexString is case-insensitive`` () =
+    Convert.FromHexString("0a1BfF") |> equal [| 0x0Auy; 0x1Buy; 0xFFuy |]
+    Convert.FromHexString("") |> equal [||]
+
+[<Fact>]
+let ``Convert.FromHexString rejects invalid input`` () =
+    throwsAnyError (fun () -> Convert.FromHexString("ABC"))
+    throwsAnyError (fun () -> Convert.FromHexString("0G"))
+    throwsAnyError (fun () -> Convert.FromHexString(" 0A"))
+
 [<Fact>]
 let ``Convert.ToBase64String works`` () =
     let bytes = [| 2uy; 4uy; 6uy; 8uy; 10uy; 12uy; 14uy; 16uy; 18uy; 20uy |]
@@ -1000,6 +1084,54 @@ let ``Convert.FromBase64String works`` () =
     Convert.FromBase64String("AgQGCAoMDhASFA==")
     |> equal [| 2uy; 4uy; 6uy; 8uy; 10uy; 12uy; 14uy; 16uy; 18uy; 20uy |]
 
+[<Fact>]
+let ``Convert.ToBase64String handles padding and empty input`` () =
+    Convert.ToBase64String([||]) |> equal ""
+    Convert.ToBase64String([| 102uy |]) |> equal "Zg=="
+    Convert.ToBase64String([| 102uy; 111uy |]) |> equal "Zm8="
+    Convert.ToBase64String([| 102uy; 111uy; 111uy |]) |> equal "Zm9v"
+    Convert.ToBase64String([| 0xFBuy; 0xFFuy |]) |> equal "+/8="
+
+[<Fact>]
+let ``Convert.ToBase64String with offset and length works`` () =
+    let bytes = [| 2uy; 4uy; 6uy; 8uy; 10uy |]
+    Convert.ToBase64String(bytes, 1, 3) |> equal "BAYI"
+    Convert.ToBase64String(bytes, 5, 0) |> equal ""
+    throwsAnyError (fun () -> Convert.ToBase64String(bytes, 3, 3))
+    throwsAnyError (fun () -> Convert.ToBase64String(bytes, -1, 2))
+
+[<Fact>]
+let ``Convert.ToBase64String with InsertLineBreaks works`` () =
+    let options = Base64FormattingOptions.InsertLineBreaks
+    let s = Convert.ToBase64String(Array.create 60 0uy, options)
+    s.Length |> equal 82
+    s.Split("\r\n") |> Array.map (fun line -> line.Length) |> equal [| 76; 4 |]
+    Convert.ToBase64String(Array.create 57 0uy, options).Length |> equal 76
+    Convert.ToBase64String(Array.create 60 0uy, 3, 57, options).Length |> equal 76
+    Convert.ToBase64String(Array.create 60 0uy, Base64FormattingOptions.None).Length |> equal 80
+
+[<Fact>]
+let ``Convert.FromBase64String ignores whitespace`` () =
+    Convert.FromBase64String(" AgQG\r\nCAoM DhAS\tFA= = ")
+    |> equal [| 2uy; 4uy; 6uy; 8uy; 10uy; 12uy; 14uy; 16uy; 18uy; 20uy |]
+    let s = Convert.ToBase64String(Array.init 100 byte, Base64FormattingOptions.InsertLineBreaks)
+    Convert.FromBase64String(s) |> equal (Array.init 100 byte)
+    Convert.FromBase64String("") |> equal [||]
+
+[<Fact>]
+let ``Convert.FromBase64String rejects invalid input`` () =
+    throwsAnyError (fun () -> Convert.FromBase64String("AgQ"))
+    throwsAnyError (fun () -> Convert.FromBase64String("A==="))
+    throwsAnyError (fun () -> Convert.FromBase64String("AA=A"))
+    throwsAnyError (fun () -> Convert.FromBase64String("AA==AAAA"))
+    throwsAnyError (fun () -> Convert.FromBase64String("AgQ!"))
+
+[<Fact>]
+let ``Convert.FromBase64CharArray works`` () =
+    let chars = "xxAgQGyy".ToCharArray()
+    Convert.FromBase64CharArray(chars, 2, 4) |> equal [| 2uy; 4uy; 6uy |]
+    throwsAnyError (fun () -> Convert.FromBase64CharArray(chars, 6, 4))
+
 //-------------------------------------
 // System.BitConverter
 //-------------------------------------
@@ -1385,3 +1517,123 @@ let ``Encoding.UTF8.GetString for range works`` () =
     let bytes = [| 0x7Auy; 0x61uy; 0xCCuy; 0x86uy; 0xC7uy; 0xBDuy; 0xCEuy; 0xB2uy; 0xF1uy; 0x8Fuy; 0xB3uy; 0xBFuy |]
     System.Text.Encoding.UTF8.GetString(bytes, 6, 6)
     |> equal "\u03B2\uD8FF\uDCFF"
+
+[<Fact>]
+let ``Encoding.UTF8.GetString replaces invalid bytes`` () =
+    System.Text.Encoding.UTF8.GetString([| 0x61uy; 0xFFuy; 0x62uy |])
+    |> equal "a�b"
+    System.Text.Encoding.UTF8.GetCharCount([| 0x61uy; 0xFFuy; 0x62uy |])
+    |> equal 3
+
+[<Fact>]
+let ``UTF8Encoding with throwOnInvalidBytes works`` () =
+    let enc = System.Text.UTF8Encoding(false, true)
+    enc.GetString([| 0x61uy; 0x62uy |]) |> equal "ab"
+    throwsAnyError (fun () -> enc.GetString([| 0x61uy; 0xFFuy |]))
+
+//-------------------------------------
+// System.Text.Encoding ASCII, Latin1 and BigEndianUnicode
+//-------------------------------------
+
+[<Fact>]
+let ``Encoding.ASCII works`` () =
+    let enc = System.Text.Encoding.ASCII
+    enc.GetBytes("abc") |> equal [| 0x61uy; 0x62uy; 0x63uy |]
+    enc.GetBytes("aé") |> equal [| 0x61uy; 0x3Fuy |]
+    enc.GetByteCount("aé") |> equal 2
+    enc.GetString([| 0x41uy; 0xC3uy; 0x42uy |]) |> equal "A?B"
+    enc.GetMaxByteCount(10) |> equal 11
+    enc.GetMaxCharCount(10) |> equal 10
+
+[<Fact>]
+let ``Encoding.Latin1 works`` () =
+    let enc = System.Text.Encoding.Latin1
+    enc.GetBytes("aé€") |> equal [| 0x61uy; 0xE9uy; 0x3Fuy |]
+    enc.GetString([| 0x61uy; 0xE9uy; 0xFFuy |]) |> equal "aéÿ"
+    enc.GetCharCount([| 0x61uy; 0xE9uy |]) |> equal 2
+
+[<Fact>]
+let ``Encoding.BigEndianUnicode works`` () =
+    let enc = System.Text.Encoding.BigEndianUnicode
+    enc.GetBytes("aé") |> equal [| 0x00uy; 0x61uy; 0x00uy; 0xE9uy |]
+    enc.GetString([| 0x00uy; 0x61uy; 0x00uy; 0xE9uy |]) |> equal "aé"
+
+[<Fact>]
+let ``Encoding.Unicode.GetString replaces a trailing odd byte`` () =
+    System.Text.Encoding.Unicode.GetString([| 0x41uy; 0x00uy; 0x42uy |])
+    |> equal "A�"
+
+[<Fact>]
+let ``Encoding.GetPreamble works`` () =
+    System.Text.Encoding.UTF8.GetPreamble() |> equal [| 0xEFuy; 0xBBuy; 0xBFuy |]
+    System.Text.UTF8Encoding(false).GetPreamble() |> equal [||]
+    System.Text.Encoding.Unicode.GetPreamble() |> equal [| 0xFFuy; 0xFEuy |]
+    System.Text.Encoding.BigEndianUnicode.GetPreamble() |> equal [| 0xFEuy; 0xFFuy |]
+    System.Text.UnicodeEncoding(false, false).GetPreamble() |> equal [||]
+    System.Text.Encoding.ASCII.GetPreamble() |> equal [||]
+
+[<Fact>]
+let ``Encoding.CodePage and WebName work`` () =
+    System.Text.Encoding.UTF8.CodePage |> equal 65001
+    System.Text.Encoding.Unicode.CodePage |> equal 1200
+    System.Text.Encoding.BigEndianUnicode.CodePage |> equal 1201
+    System.Text.Encoding.ASCII.CodePage |> equal 20127
+    System.Text.Encoding.Latin1.CodePage |> equal 28591
+    System.Text.Encoding.UTF8.WebName |> equal "utf-8"
+    System.Text.Encoding.ASCII.WebName |> equal "us-ascii"
+    System.Text.Encoding.Latin1.WebName |> equal "iso-8859-1"
+
+[<Fact>]
+let ``Encoding.GetEncoding works for built-in encodings`` () =
+    System.Text.Encoding.GetEncoding("us-ascii").GetBytes("aé") |> equal [| 0x61uy; 0x3Fuy |]
+    System.Text.Encoding.GetEncoding(28591).GetBytes("é") |> equal [| 0xE9uy |]
+    System.Text.Encoding.GetEncoding("utf-16BE").GetBytes("a") |> equal [| 0x00uy; 0x61uy |]
+
+//-------------------------------------
+// System.Text.Encoding.GetEncoding
+//-------------------------------------
+
+let private registerCodePages () =
+#if !FABLE_COMPILER
+    System.Text.Encoding.RegisterProvider(System.Text.CodePagesEncodingProvider.Instance)
+#endif
+    ()
+
+[<Fact>]
+let ``Encoding.GetEncoding windows-1252 works`` () =
+    registerCodePages ()
+    let enc = System.Text.Encoding.GetEncoding("windows-1252")
+    let bytes = enc.GetBytes("café €")
+    bytes |> equal [| 0x63uy; 0x61uy; 0x66uy; 0xE9uy; 0x20uy; 0x80uy |]
+    enc.GetString(bytes) |> equal "café €"
+
+[<Fact>]
+let ``Encoding.GetEncoding by codepage works`` () =
+    registerCodePages ()
+    let enc = System.Text.Encoding.GetEncoding(932)
+    let bytes = enc.GetBytes("日本")
+    bytes |> equal [| 0x93uy; 0xFAuy; 0x96uy; 0x7Buy |]
+    enc.GetString(bytes) |> equal "日本"
+    enc.GetByteCount("a日本") |> equal 5
+
+[<Fact>]
+let ``Encoding.GetEncoding Shift-JIS works`` () =
+    registerCodePages ()
+    let enc = System.Text.Encoding.GetEncoding("shift_jis")
+    enc.GetString([| 0x82uy; 0xA0uy; 0x41uy |]) |> equal "あA"
+
+[<Fact>]
+let ``Encoding.GetEncoding replaces unmappable chars`` () =
+    registerCodePages ()
+    let enc = System.Text.Encoding.GetEncoding(1251)
+    enc.GetBytes("Я☃") |> equal [| 0xDFuy; 0x3Fuy |]
+
+[<Fact>]
+let ``Encoding.GetEncoding utf-8 works`` () =
+    let enc = System.Text.Encoding.GetEncoding("utf-8")
+    enc.GetBytes("é") |> equal [| 0xC3uy; 0xA9uy |]
+    System.Text.Encoding.GetEncoding(65001).GetString([| 0xC3uy; 0xA9uy |]) |> equal "é"
+
+[<Fact>]
+let ``Encoding.GetEncoding with unknown name fails`` () =
+    throwsAnyError (fun () -> System.Text.Encoding.GetEncoding("not-an-encoding") |> ignore)
@@ -132,6 +132,15 @@ let ``AddDays works`` () =
     let date = DateOnly (1453, 5, 29)
     date.AddDays -29 |> equal (DateOnly (1453, 4, 30))
 
+[<Fact>]
+let ``DateOnly arithmetic out of range throws`` () =
+    (fun () -> DateOnly.MaxValue.AddDays 1) |> throwsErrorContaining "Value to add was out of range"
+    (fun () -> DateOnly.MinValue.AddDays -1) |> throwsErrorContaining "Value to add was out of range"
+    (fun () -> DateOnly.MaxValue.AddMonths 1) |> throwsErrorContaining "un-representable"
+    (fun () -> DateOnly.MinValue.AddYears 10001) |> throwsErrorContaining "+/-10000"
+    (fun () -> DateOnly.FromDayNumber -1) |> throwsErrorContaining "Day number must be between"
+    (fun () -> DateOnly(2023, 2, 29)) |> throwsErrorContaining "un-representable"
+
 [<Fact>]
 let ``ToString works`` () =
     let date = DateOnly.MaxValue
@@ -163,8 +172,8 @@ let ``Parse parses valid DateOnly`` () =
     // test (DateOnly (2001, 1, 1)) "1-01-01   "
     // test (DateOnly (2005, 1, 1)) "01/1/5"
     // test (DateOnly (2001, 5, 1)) "5-01-01"
-    // test (DateOnly (2000, 11, 30)) "2000-11-30"
-    // test (DateOnly (2000, 11, 30)) "11/30/2000"
+    test (DateOnly (2000, 11, 30)) "2000-11-30"
+    test (DateOnly (2000, 11, 30)) "11/30/2000"
     // test (DateOnly (2020, 1, 3)) "01/03/20"
     // test (DateOnly (1999, 1, 3)) "01,03,99"
     // #if NET8_0_OR_GREATER
@@ -190,16 +199,32 @@ let ``TryParse returns false for invalid DateOnly`` () =
         let isValid, _ = DateOnly.TryParse(s, CultureInfo.InvariantCulture, DateTimeStyles.None)
         isValid |> equal false
     test "4"
-    // test "0000-2-5"
-    // test "2000-2-30"
-    // test "2000-2?2"
-    // test "13/01/2000"
-    // test "01/00/2000"
-    // test "0/10/2000"
-    // test "20/2000"
-    // test "20/200"
+    test "0000-2-5"
+    test "2000-2-30"
+    test "2000-2?2"
+    test "13/01/2000"
+    test "01/00/2000"
+    test "0/10/2000"
+    test "20/2000"
+    test "20/200"
     // test "200/20"
 
+[<Fact>]
+let ``ParseExact and TryParseExact work`` () =
+    DateOnly.ParseExact("2024/01/05", "yyyy/MM/dd", CultureInfo.InvariantCulture) |> equal (DateOnly(2024, 1, 5))
+    DateOnly.ParseExact("2024-01-05", "o", CultureInfo.InvariantCulture) |> equal (DateOnly(2024, 1, 5))
+    DateOnly.ParseExact("05.01.2024", [| "yyyy-MM-dd"; "dd.MM.yyyy" |], CultureInfo.InvariantCulture, DateTimeStyles.None)
+    |> equal (DateOnly(2024, 1, 5))
+    let isValid, _ = DateOnly.TryParseExact("2024/02/30", "yyyy/MM/dd", CultureInfo.InvariantCulture, DateTimeStyles.None)
+    isValid |> equal false
+
+[<Fact>]
+let ``Parse errors match .NET`` () =
+    (fun () -> DateOnly.Parse("nope", CultureInfo.InvariantCulture))
+    |> throwsErrorContaining "was not recognized as a valid DateOnly"
+    (fun () -> DateOnly.Parse("2024-01-05 10:00", CultureInfo.InvariantCulture))
+    |> throwsErrorContaining "contains parts which are not specific to the DateOnly"
+
 [<Fact>]
 let ``Comparison works`` () =
     equal true (DateOnly (2000, 1, 1) < DateOnly (2000, 1, 2))
@@ -17,21 +17,38 @@ let thatYearSeconds (dt: DateTimeOffset) =
 let thatYearMilliseconds (dt: DateTimeOffset) =
     (dt - DateTimeOffset(dt.Year, 1, 1, 0, 0, 0, TimeSpan.Zero)).TotalMilliseconds
 
-// [<Fact>]
-// let ``DateTimeOffset.ToString with custom format works`` () =
-//     DateTimeOffset(2014, 9, 11, 16, 37, 0, TimeSpan.Zero).ToString("HH:mm", CultureInfo.InvariantCulture)
-//     |> equal "16:37"
+[<Fact>]
+let ``DateTimeOffset.ToString with custom format works`` () =
+    DateTimeOffset(2014, 9, 11, 16, 37, 0, TimeSpan.Zero).ToString("HH:mm", CultureInfo.InvariantCulture)
+    |> equal "16:37"
 
 [<Fact>]
 let ``DateTimeOffset.ToString without separator works`` () = // See #1131
     DateTimeOffset(2017, 9, 5, 0, 0, 0, TimeSpan.Zero).ToString("yyyyMM")
     |> equal "201709"
 
-// [<Fact>]
-// let ``DateTimeOffset.ToString with Roundtrip format works for Utc`` () =
-//     let str = DateTimeOffset(2014, 9, 11, 16, 37, 2, TimeSpan.Zero).ToString("O")
-//     System.Text.RegularExpressions.Regex.Replace(str, "0{3,}", "000")
-//     |> equal "2014-09-11T16:37:02.000+00:00"
+[<Fact>]
+let ``DateTimeOffset.ToString with Roundtrip format works for Utc`` () =
+    let str = DateTimeOffset(2014, 9, 11, 16, 37, 2, TimeSpan.Zero).ToString("O")
+    System.Text.RegularExpressions.Regex.Replace(str, "0{3,}", "000")
+    |> equal "2014-09-11T16:37:02.000+00:00"
+
+[<Fact>]
+let ``DateTimeOffset.ToString with offset formats works`` () =
+    let d = DateTimeOffset(2014, 9, 11, 16, 37, 2, 250, TimeSpan.FromHours -5.5)
+    d.ToString("o", CultureInfo.InvariantCulture) |> equal "2014-09-11T16:37:02.2500000-05:30"
+    d.ToString("yyyy-MM-dd HH:mm zzz", CultureInfo.InvariantCulture) |> equal "2014-09-11 16:37 -05:30"
+    d.ToString(CultureInfo.InvariantCulture) |> equal "09/11/2014 16:37:02 -05:30"
+    d.ToString("u", CultureInfo.InvariantCulture) |> equal "2014-09-11 22:07:02Z"
+
+[<Fact>]
+let ``DateTimeOffset.ParseExact keeps the offset`` () =
+    let d = DateTimeOffset(2014, 9, 11, 16, 37, 2, 250, TimeSpan.FromHours -5.5)
+    let d2 = DateTimeOffset.ParseExact(d.ToString("o"), "o", CultureInfo.InvariantCulture)
+    d2.EqualsExact d |> equal true
+    let d3 = DateTimeOffset.ParseExact("2014/09/11 16:37 +02:00", "yyyy/MM/dd HH:mm zzz", CultureInfo.InvariantCulture)
+    d3.Offset |> equal (TimeSpan.FromHours 2.)
+    d3.Hour |> equal 16
 
 [<Fact>]
 let ``DateTimeOffset from Year 1 to 99 works`` () =
@@ -193,6 +210,21 @@ let ``DateTimeOffset.ToUnixTimeMilliseconds works`` () =
     let d = DateTimeOffset(2017, 11, 10, 0, 0, 0, TimeSpan.Zero)
     d.ToUnixTimeMilliseconds() |> equal 1510272000000L
 
+[<Fact>]
+let ``DateTimeOffset Unix time conversions round trip`` () =
+    DateTimeOffset.FromUnixTimeSeconds(-62135596800L) |> equal DateTimeOffset.MinValue
+    DateTimeOffset.MinValue.ToUnixTimeSeconds() |> equal -62135596800L
+    DateTimeOffset.FromUnixTimeMilliseconds(-1L).ToUnixTimeMilliseconds() |> equal -1L
+    DateTimeOffset.UnixEpoch.AddTicks(-1L).ToUnixTimeSeconds() |> equal -1L
+    DateTimeOffset(2017, 11, 10, 2, 0, 0, TimeSpan.FromHours 2.).ToUnixTimeSeconds() |> equal 1510272000L
+
+[<Fact>]
+let ``DateTimeOffset.FromUnixTimeSeconds out of range throws`` () =
+    (fun () -> DateTimeOffset.FromUnixTimeSeconds(253402300800L))
+    |> throwsErrorContaining "Valid values are between -62135596800 and 253402300799"
+    (fun () -> DateTimeOffset.FromUnixTimeMilliseconds(-62135596800001L))
+    |> throwsErrorContaining "Valid values are between"
+
 [<Fact>]
 let ``DateTimeOffset.LocalDateTime works`` () =
     let d = DateTimeOffset(2014, 10, 9, 13, 23, 30, TimeSpan.Zero)
@@ -209,6 +241,34 @@ let ``DateTimeOffset.UtcDateTime works`` () =
     equal d.Month d2.Month
     equal d.Day d2.Day
 
+[<Fact>]
+let ``DateTimeOffset accessors return the right Kind`` () =
+    let d = DateTimeOffset(2014, 10, 9, 13, 23, 30, TimeSpan.FromHours(2.))
+    d.DateTime.Kind |> equal DateTimeKind.Unspecified
+    d.DateTime.Hour |> equal 13
+    d.UtcDateTime.Kind |> equal DateTimeKind.Utc
+    d.UtcDateTime.Hour |> equal 11
+    d.LocalDateTime.Kind |> equal DateTimeKind.Local
+    d.LocalDateTime.ToUniversalTime() |> equal d.UtcDateTime
+
+[<Fact>]
+let ``Implicit conversion from Utc DateTime works`` () =
+    let dt = DateTime(2014, 10, 9, 13, 23, 30, DateTimeKind.Utc)
+    let d = DateTimeOffset.op_Implicit dt
+    d.Offset |> equal TimeSpan.Zero
+    d.UtcDateTime |> equal dt
+
+[<Fact>]
+let ``Implicit conversion from Local and Unspecified DateTime works`` () =
+    let dt = DateTime(2014, 10, 9, 13, 23, 30, DateTimeKind.Local)
+    let d = DateTimeOffset.op_Implicit dt
+    d.Offset |> equal (dt - dt.ToUniversalTime())
+    d.DateTime.Hour |> equal 13
+    let dt = DateTime(2014, 10, 9, 13, 23, 30)
+    let d2 = DateTimeOffset.op_Implicit dt
+    d2.Offset |> equal d.Offset
+    d2.LocalDateTime |> equal (DateTime(2014, 10, 9, 13, 23, 30, DateTimeKind.Local))
+
 [<Fact>]
 let ``DateTimeOffset.UtcTicks works`` () =
     let d = DateTimeOffset(2014, 10, 9, 13, 23, 30, 999, TimeSpan.Zero)
@@ -226,17 +286,17 @@ let ``DateTimeOffset.UtcNow works`` () =
     let d = DateTimeOffset.UtcNow
     d > DateTimeOffset.MinValue |> equal true
 
-// [<Fact>]
-// let ``DateTimeOffset.Parse Now works`` () =
-//     let d = DateTimeOffset.Now
-//     let d2 = DateTimeOffset.Parse(d.ToString("o"))
-//     d2 |> equal d
+[<Fact>]
+let ``DateTimeOffset.Parse Now works`` () =
+    let d = DateTimeOffset.Now
+    let d2 = DateTimeOffset.Parse(d.ToString("o"))
+    d2 |> equal d
 
-// [<Fact>]
-// let ``DateTimeOffset.Parse UtcNow works`` () =
-//     let d = DateTimeOffset.UtcNow
-//     let d2 = DateTimeOffset.Parse(d.ToString("o"))
-//     d2 |> equal d
+[<Fact>]
+let ``DateTimeOffset.Parse UtcNow works`` () =
+    let d = DateTimeOffset.UtcNow
+    let d2 = DateTimeOffset.Parse(d.ToString("o"))
+    d2 |> equal d
 
 [<Fact>]
 let ``DateTimeOffset.Parse works`` () =
@@ -247,30 +307,30 @@ let ``DateTimeOffset.Parse works`` () =
     d.Minute |> equal 50
     d.Second |> equal 34
 
-// [<Fact>]
-// let ``DateTimeOffset.Parse with time-only string works`` () = // See #1045
-//     let d = DateTimeOffset.Parse("13:50:34")
-//     d.Hour + d.Minute + d.Second |> equal 97
-//     let d = DateTimeOffset.Parse("1:5:34 AM")
-//     d.Hour + d.Minute + d.Second |> equal 40
-//     let d = DateTimeOffset.Parse("1:5:34 PM")
-//     d.Hour + d.Minute + d.Second |> equal 52
+[<Fact>]
+let ``DateTimeOffset.Parse with time-only string works`` () = // See #1045
+    let d = DateTimeOffset.Parse("13:50:34")
+    d.Hour + d.Minute + d.Second |> equal 97
+    let d = DateTimeOffset.Parse("1:5:34 AM")
+    d.Hour + d.Minute + d.Second |> equal 40
+    let d = DateTimeOffset.Parse("1:5:34 PM")
+    d.Hour + d.Minute + d.Second |> equal 52
 
-// [<Fact>]
-// let ``DateTimeOffset.Parse with only date and offset works`` () = // See #1422
-//     let d = DateTimeOffset.Parse("05/01/2008 +03:00")
-//     d.Year + d.Month + d.Day |> equal 2014
-//     d.Offset |> equal (TimeSpan.FromHours(3.))
+[<Fact>]
+let ``DateTimeOffset.Parse with only date and offset works`` () = // See #1422
+    let d = DateTimeOffset.Parse("05/01/2008 +03:00")
+    d.Year + d.Month + d.Day |> equal 2014
+    d.Offset |> equal (TimeSpan.FromHours(3.))
 
-// [<Fact>]
-// let ``DateTimeOffset.Parse doesn't confuse day and offset`` () =
-//     let d = DateTimeOffset.Parse("2021-11-15")
-//     d.Year |> equal 2021
-//     d.Month |> equal 11
-//     d.Day |> equal 15
-//     d.Offset = (TimeSpan.FromHours(1.) + TimeSpan.FromMinutes(5.)) |> equal false
-//     d.Offset = (TimeSpan.FromHours(1.) + TimeSpan.FromMinutes(50.)) |> equal false
-//     d.Offset = (TimeSpan.FromHours(15.)) |> equal false
+[<Fact>]
+let ``DateTimeOffset.Parse doesn't confuse day and offset`` () =
+    let d = DateTimeOffset.Parse("2021-11-15")
+    d.Year |> equal 2021
+    d.Month |> equal 11
+    d.Day |> equal 15
+    d.Offset = (TimeSpan.FromHours(1.) + TimeSpan.FromMinutes(5.)) |> equal false
+    d.Offset = (TimeSpan.FromHours(1.) + TimeSpan.FromMinutes(50.)) |> equal false
+    d.Offset = (TimeSpan.FromHours(15.)) |> equal false
 //     let d = DateTimeOffset.Parse("2021-11-08-08")
 //     d.Year + d.Month + d.Day |> equal 2040
 //     d.Offset |> equal (TimeSpan.FromHours(-8.))
@@ -522,6 +582,25 @@ let ``DateTimeOffset.EqualsExact works`` () =
     let d2 = DateTimeOffset(2014, 10, 9, 14, 23, 30, 234, TimeSpan.FromHours(1.))
     d1.Equals(d2) |> equal true
     d1.EqualsExact(d2) |> equal false
+    d1.EqualsExact(d2.ToOffset(TimeSpan.Zero)) |> equal true
+
+[<Fact>]
+let ``DateTimeOffset.ToOffset keeps the instant and changes the offset`` () =
+    let d = DateTimeOffset(2024, 1, 5, 10, 0, 0, TimeSpan.FromHours 2.)
+    let d2 = d.ToOffset(TimeSpan.FromHours -5.)
+    d2 |> equal d
+    d2.Hour |> equal 3
+    d2.Offset |> equal (TimeSpan.FromHours -5.)
+    d2.EqualsExact d |> equal false
+
+[<Fact>]
+let ``DateTimeOffset out of range throws`` () =
+    (fun () -> DateTimeOffset.MinValue.ToOffset(TimeSpan.FromHours -1.))
+    |> throwsErrorContaining "un-representable DateTime"
+    (fun () -> DateTimeOffset(1, 1, 1, 0, 0, 0, TimeSpan.FromHours 1.))
+    |> throwsErrorContaining "The UTC time represented when the offset is applied"
+    (fun () -> DateTimeOffset.UtcNow.ToOffset(TimeSpan.FromHours 15.))
+    |> throwsErrorContaining "Offset must be within plus or minus 14 hours"
 
 [<Fact>]
 let ``DateTimeOffset Inequality works`` () =
@@ -33,11 +33,30 @@ let ``DateTime.ToString with milliseconds`` () = // See #1726
     DateTime(2014, 9, 11, 16, 37, 11, 345).ToString("ss.fff")
     |> equal "11.345"
 
-// [<Fact>]
-// let ``DateTime.ToString with Round-trip format works for Utc`` () =
-//     let str = DateTime(2014, 9, 11, 16, 37, 2, DateTimeKind.Utc).ToString("O")
-//     System.Text.RegularExpressions.Regex.Replace(str, "0{3,}", "000")
-//     |> equal "2014-09-11T16:37:02.000Z"
+[<Fact>]
+let ``DateTime.ToString with custom format specifiers works`` () =
+    let d = DateTime(2014, 9, 11, 16, 37, 2, 345)
+    d.ToString("ddd, dd MMM yyyy HH:mm:ss 'GMT'") |> equal "Thu, 11 Sep 2014 16:37:02 GMT"
+    d.ToString("M/d/yy h:mm tt") |> equal "9/11/14 4:37 PM"
+    d.ToString("dddd MMMM") |> equal "Thursday September"
+    d.ToString("HH\\hmm") |> equal "16h37"
+    d.ToString("fffffff") |> equal "3450000"
+
+[<Fact>]
+let ``DateTime.ToString with standard formats works`` () =
+    let d = DateTime(2014, 9, 11, 16, 37, 2, 345)
+    d.ToString("o") |> equal "2014-09-11T16:37:02.3450000"
+    d.ToString("r") |> equal "Thu, 11 Sep 2014 16:37:02 GMT"
+    d.ToString("s") |> equal "2014-09-11T16:37:02"
+    d.ToString("u") |> equal "2014-09-11 16:37:02Z"
+    let utc = DateTime(2014, 9, 11, 16, 37, 2, DateTimeKind.Utc)
+    utc.ToString("r") |> equal "Thu, 11 Sep 2014 16:37:02 GMT"
+    utc.ToString("u") |> equal "2014-09-11 16:37:02Z"
+
+[<Fact>]
+let ``DateTime.ToString with Round-trip format works for Utc`` () =
+    DateTime(2014, 9, 11, 16, 37, 2, DateTimeKind.Utc).ToString("O")
+    |> equal "2014-09-11T16:37:02.0000000Z"
 
 // TODO
 // Next test is disabled because it's depends on the time zone of the machine
@@ -116,6 +135,16 @@ let ``DateTime.Parse without offset works`` () =
     dt.Second |> equal 3
     dt.Millisecond |> equal 4
 
+[<Fact>]
+let ``DateTime.Parse accepts general formats`` () =
+    DateTime.Parse("2024-05-01") |> equal (DateTime(2024, 5, 1))
+    DateTime.Parse("05/01/2024") |> equal (DateTime(2024, 5, 1))
+    DateTime.Parse("05/01/2024 13:00") |> equal (DateTime(2024, 5, 1, 13, 0, 0))
+    DateTime.Parse("05/01/2024 1:00 PM") |> equal (DateTime(2024, 5, 1, 13, 0, 0))
+    DateTime.Parse("2024-05-01 13:00") |> equal (DateTime(2024, 5, 1, 13, 0, 0))
+    DateTime.Parse("13:30").TimeOfDay |> equal (TimeSpan(13, 30, 0))
+    DateTime.Parse("1:30 PM").Date |> equal DateTime.Today
+
 [<Fact>]
 let ``DateTime.Parse with offset works`` () =
     let dtStr = "2016-07-07T01:02:03.004-05:00"
@@ -124,6 +153,26 @@ let ``DateTime.Parse with offset works`` () =
     dt.Kind |> equal DateTimeKind.Local
     dt |> equal dto.LocalDateTime
 
+[<Fact>]
+let ``DateTime.Parse sets Kind correctly`` () =
+    let inv = CultureInfo.InvariantCulture
+    let dt = DateTime.Parse("2016-07-07T01:02:03")
+    dt.Kind |> equal DateTimeKind.Unspecified
+    dt.Hour |> equal 1
+    DateTime.Parse("2016-07-07T01:02:03Z").Kind |> equal DateTimeKind.Local
+    let dt = DateTime.Parse("2016-07-07T01:02:03Z", inv, DateTimeStyles.RoundtripKind)
+    dt.Kind |> equal DateTimeKind.Utc
+    dt.Hour |> equal 1
+    let dt = DateTime.Parse("2016-07-07T01:02:03-05:00", inv, DateTimeStyles.AdjustToUniversal)
+    dt.Kind |> equal DateTimeKind.Utc
+    dt.Hour |> equal 6
+    let dt = DateTime.Parse("2016-07-07T01:02:03", inv, DateTimeStyles.AssumeUniversal ||| DateTimeStyles.AdjustToUniversal)
+    dt.Kind |> equal DateTimeKind.Utc
+    dt.Hour |> equal 1
+    let dt = DateTime.Parse("2016-07-07T01:02:03", inv, DateTimeStyles.AssumeLocal)
+    dt.Kind |> equal DateTimeKind.Local
+    dt.Hour |> equal 1
+
 [<Fact>]
 let ``DateTime.Hour works`` () =
     let d = DateTime(2014, 10, 9, 13, 23, 30, DateTimeKind.Local)
@@ -192,6 +241,16 @@ let ``DateTime constructor from Ticks works`` () =
     equal 0 d.Hour
     equal 0 d.Minute
 
+[<Fact>]
+let ``DateTime constructor from Ticks keeps the kind`` () =
+    DateTime(624059424000000000L).Kind |> equal DateTimeKind.Unspecified
+    DateTime(624059424000000000L, DateTimeKind.Utc).Kind |> equal DateTimeKind.Utc
+    let d = DateTime(624059424000000000L, DateTimeKind.Local)
+    d.Kind |> equal DateTimeKind.Local
+    DateTime(d.Ticks, d.Kind) |> equal d
+    (fun () -> DateTime(-1L))
+    |> throwsErrorContaining "Ticks must be between DateTime.MinValue.Ticks and DateTime.MaxValue.Ticks"
+
 [<Fact>]
 let ``DateTime.Ticks does not care about kind`` () =
     let d1 = DateTime(2014, 10, 9, 13, 23, 30, 500, DateTimeKind.Local)
@@ -289,14 +348,14 @@ let ``DateTime.Parse with provider works`` () =
     d.Year + d.Month + d.Day + d.Hour + d.Minute
     |> equal 2096
 
-// [<Fact>]
-// let ``DateTime.Parse with time-only string works`` () = // See #1045
-//     let d = DateTime.Parse("13:50:34", CultureInfo.InvariantCulture)
-//     d.Hour + d.Minute + d.Second |> equal 97
-//     let d = DateTime.Parse("1:5:34 AM", CultureInfo.InvariantCulture)
-//     d.Hour + d.Minute + d.Second |> equal 40
-//     let d = DateTime.Parse("1:5:34 PM", CultureInfo.InvariantCulture)
-//     d.Hour + d.Minute + d.Second |> equal 52
+[<Fact>]
+let ``DateTime.Parse with time-only string works`` () = // See #1045
+    let d = DateTime.Parse("13:50:34", CultureInfo.InvariantCulture)
+    d.Hour + d.Minute + d.Second |> equal 97
+    let d = DateTime.Parse("1:5:34 AM", CultureInfo.InvariantCulture)
+    d.Hour + d.Minute + d.Second |> equal 40
+    let d = DateTime.Parse("1:5:34 PM", CultureInfo.InvariantCulture)
+    d.Hour + d.Minute + d.Second |> equal 52
 
 [<Fact>]
 let ``DateTime.TryParse works`` () =
@@ -306,13 +365,82 @@ let ``DateTime.TryParse works`` () =
         | false, _ -> false
     f "foo" |> equal false
     f "9/10/2014 1:50:34 PM" |> equal true
-    // f "1:50:34" |> equal true //TODO:
+    f "1:50:34" |> equal true
+
+[<Fact>]
+let ``Parsing doesn't succeed for invalid dates`` () =
+    let invalidAmericanDate = "13/1/2020"
+    let r, _date = DateTime.TryParse(invalidAmericanDate, CultureInfo.InvariantCulture, DateTimeStyles.None)
+    r |> equal false
+
+[<Fact>]
+let ``DateTime.ToString with culture works`` () =
+    let d = DateTime(2024, 3, 5, 14, 7, 9)
+    d.ToString("D", CultureInfo("fr-FR")) |> equal "mardi 5 mars 2024"
+    d.ToString("D", CultureInfo("de-DE")) |> equal "Dienstag, 5. März 2024"
+    d.ToString("D", CultureInfo("en-US")) |> equal "Tuesday, March 5, 2024"
+    d.ToString("d", CultureInfo("en-US")) |> equal "3/5/2024"
+    d.ToString(CultureInfo("en-US")) |> equal "3/5/2024 2:07:09 PM"
+    d.ToString("G", CultureInfo("de-DE")) |> equal "05.03.2024 14:07:09"
+    d.ToString("f", CultureInfo("es-ES")) |> equal "martes, 5 de marzo de 2024 14:07"
+    d.ToString("M", CultureInfo("en-US")) |> equal "March 5"
+    d.ToString("Y", CultureInfo("fr-FR")) |> equal "mars 2024"
+    d.ToString("s", CultureInfo("fr-FR")) |> equal "2024-03-05T14:07:09"
+
+[<Fact>]
+let ``DateTime.ToString with DateTimeFormatInfo works`` () =
+    let d = DateTime(2024, 3, 5, 14, 7, 9)
+    let dtfi = CultureInfo("fr-FR").DateTimeFormat
+    d.ToString("D", dtfi) |> equal "mardi 5 mars 2024"
+    d.ToString("dddd d MMMM", dtfi) |> equal "mardi 5 mars"
+    d.ToString(DateTimeFormatInfo.InvariantInfo) |> equal "03/05/2024 14:07:09"
+    d.ToString("hh:mm tt", CultureInfo("en-US").DateTimeFormat) |> equal "02:07 PM"
+
+[<Fact>]
+let ``DateTime.ToString with culture and custom format works`` () =
+    let d = DateTime(2024, 3, 5, 14, 7, 9)
+    d.ToString("dddd dd MMM yyyy hh:mm tt", CultureInfo("en-US")) |> equal "Tuesday 05 Mar 2024 02:07 PM"
+    d.ToString("ddd d MMMM", CultureInfo("it-IT")) |> equal "mar 5 marzo"
+    d.ToString("yyyy/MM/dd", CultureInfo("de-DE")) |> equal "2024.03.05"
+    d.ToString("HH:mm", CultureInfo.InvariantCulture) |> equal "14:07"
+
+[<Fact>]
+let ``DateTime.Parse with culture works`` () =
+    let d = DateTime.Parse("mardi 5 mars 2024", CultureInfo("fr-FR"))
+    d |> equal (DateTime(2024, 3, 5))
+    let d = DateTime.Parse("05.03.2024 14:07:09", CultureInfo("de-DE"))
+    d |> equal (DateTime(2024, 3, 5, 14, 7, 9))
+    let d = DateTime.Parse("3/5/2024 2:07:09 PM", CultureInfo("en-US"))
+    d |> equal (DateTime(2024, 3, 5, 14, 7, 9))
+    let d = DateTime.Parse("5 de marzo de 2024", CultureInfo("es-ES"))
+    d |> equal (DateTime(2024, 3, 5))
+
+[<Fact>]
+let ``DateTime.TryParse with culture works`` () =
+    let r, _ = DateTime.TryParse("32.13.2024", CultureInfo("de-DE"), DateTimeStyles.None)
+    r |> equal false
+    let r, d = DateTime.TryParse("5 mars 2024", CultureInfo("fr-FR"), DateTimeStyles.None)
+    r |> equal true
+    d.Month |> equal 3
+
+[<Fact>]
+let ``DateTime.ParseExact works`` () =
+    let d = DateTime.ParseExact("20240501134502", "yyyyMMddHHmmss", null)
+    d |> equal (DateTime(2024, 5, 1, 13, 45, 2))
+    let d = DateTime.ParseExact("01/05/2024", [| "MM-dd"; "dd/MM/yyyy" |], CultureInfo.InvariantCulture, DateTimeStyles.None)
+    d |> equal (DateTime(2024, 5, 1))
+    let d = DateTime.ParseExact("1 mai 2024", "d MMMM yyyy", CultureInfo("fr-FR"))
+    d |> equal (DateTime(2024, 5, 1))
+    let d = DateTime.ParseExact("Wed, 01 May 2024 13:45:02 GMT", "r", CultureInfo.InvariantCulture)
+    d.Hour |> equal 13
 
-// [<Fact>]
-// let ``Parsing doesn't succeed for invalid dates`` () =
-//     let invalidAmericanDate = "13/1/2020"
-//     let r, _date = DateTime.TryParse(invalidAmericanDate, CultureInfo.InvariantCulture, DateTimeStyles.None)
-//     r |> equal false
+[<Fact>]
+let ``DateTime.TryParseExact works`` () =
+    let r, _ = DateTime.TryParseExact("2024-05-01", "yyyyMMdd", CultureInfo.InvariantCulture, DateTimeStyles.None)
+    r |> equal false
+    let r, d = DateTime.TryParseExact("2024-05-01 1:05 PM", "yyyy-MM-dd h:mm tt", CultureInfo.InvariantCulture, DateTimeStyles.None)
+    r |> equal true
+    d |> equal (DateTime(2024, 5, 1, 13, 5, 0))
 
 [<Fact>]
 let ``DateTime.Today works`` () =
@@ -325,6 +453,49 @@ let ``DateTime.ToUniversalTime works`` () =
     d.ToUniversalTime().Kind <> d.Kind
     |> equal true
 
+[<Fact>]
+let ``DateTime local conversions round-trip across the year`` () =
+    for month in 1..12 do
+        let d = DateTime(2024, month, 15, 12, 0, 0, DateTimeKind.Utc)
+        d.ToLocalTime().ToUniversalTime() |> equal d
+        d.IsDaylightSavingTime() |> equal false
+    DateTime.MinValue.ToLocalTime().Year |> equal 1
+    DateTime.MaxValue.ToUniversalTime().Year |> equal 9999
+
+[<Fact>]
+let ``DateTime.IsDaylightSavingTime works`` () =
+    let winter = DateTime(2024, 1, 15, 12, 0, 0, DateTimeKind.Local)
+    let summer = DateTime(2024, 7, 15, 12, 0, 0, DateTimeKind.Local)
+    // at most one of them is in DST, whatever the hemisphere
+    (winter.IsDaylightSavingTime() && summer.IsDaylightSavingTime())
+    |> equal false
+
+[<Fact>]
+let ``DateTime.ToOADate and FromOADate work`` () =
+    let d = DateTime(2024, 5, 1, 6, 0, 0)
+    d.ToOADate() |> equal 45413.25
+    DateTime.FromOADate(45413.25) |> equal d
+    let d = DateTime(1899, 12, 29, 18, 0, 0)
+    d.ToOADate() |> equal -1.75
+    DateTime.FromOADate(-1.75) |> equal d
+    DateTime.MinValue.ToOADate() |> equal 0.
+    DateTime.FromOADate(0.) |> equal (DateTime(1899, 12, 30))
+    (fun () -> DateTime.FromOADate(3e6))
+    |> throwsErrorContaining "Not a legal OleAut date."
+
+[<Fact>]
+let ``DateTime.ToFileTime and FromFileTime work`` () =
+    let d = DateTime(2024, 5, 1, 0, 0, 0, DateTimeKind.Utc)
+    d.ToFileTimeUtc() |> equal 133589952000000000L
+    DateTime.FromFileTimeUtc(133589952000000000L) |> equal d
+    DateTime.FromFileTimeUtc(133589952000000000L).Kind |> equal DateTimeKind.Utc
+    DateTime.FromFileTimeUtc(0L) |> equal (DateTime(1601, 1, 1))
+    let local = DateTime.FromFileTime(d.ToFileTime())
+    local.Kind |> equal DateTimeKind.Local
+    local.ToUniversalTime() |> equal d
+    (fun () -> DateTime.FromFileTimeUtc(-1L))
+    |> throwsErrorContaining "Not a valid Win32 FileTime."
+
 [<Fact>]
 let ``DateTime.SpecifyKind works`` () = // See #1844
     let d = DateTime(2014, 10, 9, 13, 23, 30, DateTimeKind.Local)
@@ -351,6 +522,20 @@ let ``DateTime.DayOfWeek works`` () =
     let d = DateTime(2014, 10, 9)
     d.DayOfWeek |> equal DayOfWeek.Thursday
 
+[<Fact>]
+let ``DayOfWeek ToString and pattern matching work`` () =
+    let d = DateTime(2014, 10, 6)
+    d.DayOfWeek.ToString() |> equal "Monday"
+    string (d.AddDays(6.).DayOfWeek) |> equal "Sunday"
+    let isWeekend =
+        match d.AddDays(5.).DayOfWeek with
+        | DayOfWeek.Saturday
+        | DayOfWeek.Sunday -> true
+        | _ -> false
+    isWeekend |> equal true
+    compare DayOfWeek.Sunday DayOfWeek.Monday |> equal -1
+    int d.DayOfWeek |> equal 1
+
 [<Fact>]
 let ``DateTime.DayOfYear works`` () =
     let d = DateTime(2014, 10, 9)
@@ -427,6 +612,36 @@ let ``DateTime.AddMonths works`` () =
     test -20 2050
     test -100 2046
 
+[<Fact>]
+let ``DateTime.AddMonths clamps the day`` () =
+    DateTime(2024, 1, 31).AddMonths(1) |> equal (DateTime(2024, 2, 29))
+    DateTime(2023, 3, 31).AddMonths(-1) |> equal (DateTime(2023, 2, 28))
+    DateTime(2024, 2, 29).AddYears(1) |> equal (DateTime(2025, 2, 28))
+
+[<Fact>]
+let ``DateTime constructors validate their arguments`` () =
+    (fun () -> DateTime(2023, 2, 29))
+    |> throwsErrorContaining "Year, Month, and Day parameters describe an un-representable DateTime"
+    (fun () -> DateTime(2023, 13, 1))
+    |> throwsErrorContaining "Year, Month, and Day parameters describe an un-representable DateTime"
+    (fun () -> DateTime(2023, 1, 1, 24, 0, 0))
+    |> throwsErrorContaining "Hour, Minute, and Second parameters describe an un-representable DateTime"
+    (fun () -> DateTime(2023, 1, 1, 0, 0, 0, 1000))
+    |> throwsErrorContaining "Valid values are between 0 and 999, inclusive"
+
+[<Fact>]
+let ``DateTime arithmetic out of range throws`` () =
+    (fun () -> DateTime.MaxValue.AddDays(1.))
+    |> throwsErrorContaining "un-representable DateTime"
+    (fun () -> DateTime.MinValue.AddTicks(-1L))
+    |> throwsErrorContaining "un-representable DateTime"
+    (fun () -> DateTime(9999, 12, 1).AddMonths(1))
+    |> throwsErrorContaining "un-representable DateTime"
+    (fun () -> DateTime.MinValue.AddMonths(200000))
+    |> throwsErrorContaining "Months value must be between +/-120000"
+    (fun () -> DateTime.DaysInMonth(2023, 13))
+    |> throwsErrorContaining "Month must be between one and twelve"
+
 [<Fact>]
 let ``DateTime.AddDays works`` () =
     let test v expected =
@@ -581,6 +796,25 @@ let ``DateTime Inequality works`` () =
     test -1000. true
     test 0. false
 
+[<Fact>]
+let ``DateTime.GetHashCode agrees with Equals`` () =
+    let utc = DateTime(2014, 10, 9, 13, 23, 30, DateTimeKind.Utc)
+    let unspecified = DateTime(2014, 10, 9, 13, 23, 30)
+    utc.Equals(unspecified) |> equal true
+    utc.GetHashCode() |> equal (unspecified.GetHashCode())
+    utc.GetHashCode() |> notEqual (utc.AddTicks(1L).GetHashCode())
+
+[<Fact>]
+let ``DateTime can be used as a dictionary key`` () =
+    let d1 = DateTime(2014, 10, 9, 0, 0, 0, DateTimeKind.Utc)
+    let dict = Collections.Generic.Dictionary<DateTime, string>()
+    dict[d1] <- "a"
+    dict[d1.AddDays(1.)] <- "b"
+    dict[DateTime(2014, 10, 9)] |> equal "a"
+    dict.ContainsKey(DateTime(2014, 10, 10, 0, 0, 0, DateTimeKind.Local)) |> equal true
+    let set = Collections.Generic.HashSet<DateTime>([ d1; DateTime(2014, 10, 9); d1.AddDays(1.) ])
+    set.Count |> equal 2
+
 [<Fact>]
 let ``DateTime TimeOfDay works`` () =
     let d = DateTime(2014, 10, 9, 13, 23, 30, 1, DateTimeKind.Utc)
@@ -593,3 +827,28 @@ let ``DateTime TimeOfDay works`` () =
 let ``Adding days to a local date works even if daylight saving time changes`` () =
     let dt = DateTime(2019, 10, 20, 0, 0, 0, DateTimeKind.Local)
     dt.AddDays(9.).Day |> equal 29
+
+[<Fact>]
+let ``DateTime can be used as map key and in sets`` () =
+    let d = DateTime(2000, 1, 2, 0, 0, 0, DateTimeKind.Utc)
+    let m = [ d, "a" ] |> Map.ofList
+    Map.tryFind (DateTime(2000, 1, 1).AddDays(1.)) m |> equal (Some "a")
+    Map.containsKey (DateTime(1999, 1, 1)) m |> equal false
+    let s = Set.ofList [ d.AddDays(2.); d; d.AddDays(1.); DateTime(2000, 1, 2) ]
+    s.Count |> equal 3
+    Set.minElement s |> equal d
+    [ d.AddDays(1.); d; d.AddDays(-1.) ] |> List.sort |> equal [ d.AddDays(-1.); d; d.AddDays(1.) ]
+
+[<Fact>]
+let ``ISOWeek works`` () =
+    ISOWeek.GetWeekOfYear(DateTime(2021, 1, 1)) |> equal 53
+    ISOWeek.GetYear(DateTime(2021, 1, 1)) |> equal 2020
+    ISOWeek.GetWeekOfYear(DateTime(2024, 12, 30)) |> equal 1
+    ISOWeek.GetYear(DateTime(2024, 12, 30)) |> equal 2025
+    ISOWeek.GetWeeksInYear(2020) |> equal 53
+    ISOWeek.GetWeeksInYear(2021) |> equal 52
+    ISOWeek.GetYearStart(2021) |> equal (DateTime(2021, 1, 4))
+    ISOWeek.GetYearEnd(2020) |> equal (DateTime(2021, 1, 4).AddTicks(-1L))
+    ISOWeek.ToDateTime(2020, 53, DayOfWeek.Friday) |> equal (DateTime(2021, 1, 1))
+    ISOWeek.ToDateTime(2021, 1, DayOfWeek.Sunday) |> equal (DateTime(2021, 1, 10))
+    throwsErrorContaining "(Parameter 'week')" (fun () -> ISOWeek.ToDateTime(2021, 54, DayOfWeek.Monday) |> ignore)
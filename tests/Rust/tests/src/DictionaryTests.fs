@@ -24,6 +24,15 @@ let ``Dictionary ctor works`` () =
     let dict = Dictionary<int, int>()
     dict.Count |> equal 0
 
+[<Fact>]
+let ``Dictionary EnsureCapacity works`` () =
+    let dict = Dictionary<int, int>(10)
+    dict.EnsureCapacity(1000) >= 1000 |> equal true
+    for i in 1..1000 do dict.Add(i, i)
+    dict.Count |> equal 1000
+    dict.TrimExcess()
+    dict[500] |> equal 500
+
 [<Fact>]
 let ``Dictionary ctor with capacity works`` () =
     let dict = Dictionary<int, int>(10)
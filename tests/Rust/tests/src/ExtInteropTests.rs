@@ -36,6 +36,13 @@ pub mod ExtInteropTests {
             let res: List<i32> = raw.iter().collect();
             assert_eq!(res, expected);
         }
+
+        #[test]
+        pub fn can_convert_from_owned_vec_and_slice() {
+            let expected = cons(1, cons(2, singleton(3)));
+            assert_eq!(List::from(vec![1, 2, 3]), expected);
+            assert_eq!(List::from(&[1, 2, 3][..]), expected);
+        }
     }
 
     pub mod ArrayTests {
@@ -50,10 +57,21 @@ pub mod ExtInteropTests {
             let tgt: Vec<i32> = arr.clone().into();
             assert_eq!(raw, tgt);
         }
+
+        #[test]
+        pub fn can_convert_from_slice_and_fixed_array() {
+            let arr = Array::from(&[1, 2, 3][..]);
+            assert_eq!(arr, Array::from([1, 2, 3]));
+            let fixed: [i32; 3] = arr.clone().try_into().unwrap();
+            assert_eq!(fixed, [1, 2, 3]);
+            let res: Result<[i32; 2], _> = arr.try_into();
+            assert!(res.is_err());
+        }
     }
 
     pub mod SetTests {
         use fable_library_rust::Set_::Set;
+        use std::collections::BTreeSet;
 
         #[test]
         pub fn can_interop_between_set_and_vec() {
@@ -71,11 +89,20 @@ pub mod ExtInteropTests {
             let res: Set<i32> = raw.into_iter().collect();
             assert_eq!(res, expected);
         }
+
+        #[test]
+        pub fn can_interop_between_set_and_btreeset() {
+            let raw = BTreeSet::from([3, 1, 2]);
+            let set = Set::from(raw.clone());
+            assert_eq!(set, Set::from(&vec![1, 2, 3]));
+            assert_eq!(BTreeSet::from(set), raw);
+        }
     }
 
     pub mod MapTests {
         use fable_library_rust::Map_::Map;
         use fable_library_rust::String_::string;
+        use std::collections::BTreeMap;
 
         #[test]
         pub fn can_interop_between_map_and_vec() {
@@ -101,5 +128,408 @@ pub mod ExtInteropTests {
             let res: Map<string, i32> = raw.into_iter().collect();
             assert_eq!(res, expected);
         }
+
+        #[test]
+        pub fn can_interop_between_map_and_btreemap() {
+            let raw = BTreeMap::from([(string("b"), 2), (string("a"), 1)]);
+            let map = Map::from(raw.clone());
+            assert_eq!(map, Map::from(vec![(string("a"), 1), (string("b"), 2)]));
+            assert_eq!(BTreeMap::from(map), raw);
+        }
+    }
+
+    pub mod HashMapTests {
+        use fable_library_rust::HashMap_::HashMap;
+        use fable_library_rust::String_::string;
+
+        #[test]
+        pub fn can_interop_between_dictionary_and_hashmap() {
+            let raw = std::collections::HashMap::from([(string("a"), 1), (string("b"), 2)]);
+            let dict = HashMap::from(raw.clone());
+            assert_eq!(dict.len(), 2);
+            let tgt: std::collections::HashMap<string, i32> = dict.into();
+            assert_eq!(tgt, raw);
+        }
+    }
+
+    pub mod StringTests {
+        use fable_library_rust::String_::{fromStatic, string};
+
+        #[test]
+        pub fn can_interop_between_string_and_std_string() {
+            let s = string::from(String::from("abc"));
+            assert_eq!(s, string("abc"));
+            assert_eq!(String::from(&s), "abc");
+            let tgt: String = s.into();
+            assert_eq!(tgt, "abc");
+        }
+
+        // without enum_string literals are copied to the heap
+        #[cfg(feature = "enum_string")]
+        #[test]
+        pub fn static_strings_borrow_the_literal() {
+            const LIT: &str = "a string literal that is too long to be inlined";
+            let s = fromStatic(LIT);
+            assert_eq!(s, string(LIT));
+            assert_eq!(s.as_ptr(), LIT.as_ptr());
+        }
+    }
+
+    pub mod OptionTests {
+        use fable_library_rust::String_::string;
+
+        // F# options and results are compiled to std Option and Result
+        #[test]
+        pub fn can_map_option_and_result_to_std_types() {
+            let opt: Option<string> = Some(string("a"));
+            let res: Result<String, string> = Ok(string("b")).map(String::from);
+            assert_eq!(opt.map(String::from), Some(String::from("a")));
+            assert_eq!(res, Ok(String::from("b")));
+        }
+    }
+
+    pub mod SeqTests {
+        use fable_library_rust::NativeArray_::Array;
+        use fable_library_rust::Native_::{Lrc, LrcPtr};
+        use fable_library_rust::SeqExt::{seq, seq_to_iter, to_seq, IEnumerable, IEnumerator};
+        use fable_library_rust::Seq_::{length, toArray};
+
+        struct Evens(i32);
+
+        impl IEnumerable<i32> for Evens {
+            fn GetEnumerator(&self) -> LrcPtr<dyn IEnumerator<i32>> {
+                to_seq((0..self.0).map(|x| x * 2)).GetEnumerator()
+            }
+        }
+
+        #[test]
+        pub fn can_pass_collection_as_seq() {
+            let xs = to_seq(vec![1, 2, 3]);
+            assert_eq!(length(xs.clone()), 3);
+            assert_eq!(toArray(xs.clone()), Array::from(vec![1, 2, 3]));
+            assert_eq!(seq_to_iter(&xs).sum::<i32>(), 6);
+        }
+
+        #[test]
+        pub fn can_pass_custom_type_as_seq() {
+            let xs: seq<i32> = LrcPtr::from(Lrc::new(Evens(3)) as Lrc<dyn IEnumerable<i32>>);
+            assert_eq!(toArray(xs), Array::from(vec![0, 2, 4]));
+        }
+    }
+
+    pub mod FromStrTests {
+        use fable_library_rust::DateOnly_::DateOnly;
+        use fable_library_rust::DateTimeOffset_::DateTimeOffset;
+        use fable_library_rust::DateTime_::DateTime;
+        use fable_library_rust::Decimal_::decimal;
+        use fable_library_rust::Guid_::Guid;
+        use fable_library_rust::TimeOnly_::TimeOnly;
+        use fable_library_rust::TimeSpan_::TimeSpan;
+
+        #[test]
+        pub fn can_parse_date_and_time_types() -> Result<(), Box<dyn std::error::Error>> {
+            let dt = "2024-01-01T10:20:30".parse::<DateTime>()?;
+            assert_eq!((dt.year(), dt.hour()), (2024, 10));
+            let dto: DateTimeOffset = "2024-01-01T10:20:30+02:00".parse()?;
+            assert_eq!(dto.hour(), 10);
+            let d: DateOnly = " 2024-02-03 ".parse()?;
+            assert_eq!(d.day(), 3);
+            let t: TimeOnly = "10:20:30".parse()?;
+            assert_eq!(t.minute(), 20);
+            let ts: TimeSpan = "1.02:03:04".parse()?;
+            assert_eq!((ts.days(), ts.hours()), (1, 2));
+            assert!("not a date".parse::<DateTime>().is_err());
+            assert!("not a timespan".parse::<TimeSpan>().is_err());
+            Ok(())
+        }
+
+        #[test]
+        pub fn can_parse_leap_seconds() -> Result<(), Box<dyn std::error::Error>> {
+            let dt: DateTime = "2016-12-31 23:59:60.5".parse()?;
+            assert_eq!((dt.second(), dt.millisecond(), dt.nanosecond()), (59, 500, 500_000_000));
+            let dto: DateTimeOffset = "2016-12-31T23:59:60.25Z".parse()?;
+            assert_eq!((dto.second(), dto.millisecond()), (59, 250));
+            assert_eq!(dto.utcTicks(), "2016-12-31T23:59:59.25Z".parse::<DateTimeOffset>()?.utcTicks());
+            assert_eq!("23:59:60".parse::<TimeOnly>()?.second(), 59);
+            Ok(())
+        }
+
+        #[test]
+        pub fn can_parse_guid_and_decimal() -> Result<(), Box<dyn std::error::Error>> {
+            let g: Guid = "96258006-c4ba-4a7f-80c4-de7f2b2898c5".parse()?;
+            assert_eq!(g.to_string(), "96258006-c4ba-4a7f-80c4-de7f2b2898c5");
+            let m = "1.5".parse::<decimal>().unwrap();
+            assert_eq!(m.to_string(), "1.5");
+            Ok(())
+        }
+    }
+
+    pub mod TryParseTests {
+        use fable_library_rust::Convert_::{try_parse_rs, ParseError};
+        use fable_library_rust::DateTime_::DateTime;
+        use fable_library_rust::Guid_;
+        use fable_library_rust::TimeSpan_::TimeSpan;
+
+        #[test]
+        pub fn can_parse_numbers_with_results() -> Result<(), ParseError> {
+            assert_eq!(try_parse_rs::<i32>(" 42 ")?, 42);
+            assert_eq!(try_parse_rs::<f64>("1.5")?, 1.5);
+            let e = try_parse_rs::<u8>("300").unwrap_err();
+            assert_eq!(e.message(), "The input string '300' was not in a correct format.");
+            Ok(())
+        }
+
+        #[test]
+        pub fn can_parse_dates_and_guids_with_results() -> Result<(), ParseError> {
+            assert_eq!(DateTime::try_parse_rs("2024-01-01T10:20:30")?.hour(), 10);
+            assert!(DateTime::try_parse_rs("not a date").is_err());
+            assert_eq!(TimeSpan::try_parse_rs("1.02:03:04")?.hours(), 2);
+            let e = TimeSpan::try_parse_rs("not a timespan").unwrap_err();
+            assert_eq!(e.to_string(), "String 'not a timespan' was not recognized as a valid TimeSpan.");
+            let g = Guid_::try_parse_rs("96258006-c4ba-4a7f-80c4-de7f2b2898c5")?;
+            assert_eq!(g.to_string(), "96258006-c4ba-4a7f-80c4-de7f2b2898c5");
+            assert!(Guid_::try_parse_rs("not a guid").is_err());
+            Ok(())
+        }
+    }
+
+    #[cfg(not(any(feature = "no_std", target_family = "wasm")))]
+    pub mod FileSystemTests {
+        use fable_library_rust::DateTime_::DateTime;
+        use fable_library_rust::FileSystem_::{getLastWriteTimeUtc, setLastWriteTimeUtc, FileInfo};
+        use fable_library_rust::String_::fromString;
+
+        #[test]
+        pub fn can_get_and_set_file_times() {
+            let path = std::env::temp_dir().join("fable_file_times_test.txt");
+            std::fs::write(&path, b"abc").unwrap();
+            let p = fromString(path.to_string_lossy().to_string());
+            let written = getLastWriteTimeUtc(p.clone());
+            assert_eq!(written.kind(), 1);
+            assert!((DateTime::utcNow().ticks() - written.ticks()).abs() < 600_000_000);
+            let t = DateTime::new_ymdhms_milli_kind(2020, 5, 6, 7, 8, 9, 123, 1);
+            setLastWriteTimeUtc(p.clone(), t);
+            let fi = FileInfo::new(p);
+            assert_eq!((fi.length(), fi.lastWriteTimeUtc()), (3, t));
+            std::fs::remove_file(&path).unwrap();
+            assert!(!fi.exists());
+        }
+    }
+
+    #[cfg(not(any(feature = "no_std", target_family = "wasm")))] // panics abort on wasm
+    pub mod FfiTests {
+        use fable_library_rust::Ffi_::*;
+        use fable_library_rust::String_::{string, toUpper};
+        use std::ffi::{c_char, CStr, CString};
+
+        extern "C" fn to_upper(s: *const c_char, out: *mut *mut c_char) -> ErrorCode {
+            let f = || {
+                let s = unsafe { string_from_c(s) }?;
+                string_to_c(toUpper(s))
+            };
+            unsafe { catch_panic(out, f) }
+        }
+
+        extern "C" fn fail(out: *mut i32) -> ErrorCode {
+            unsafe { catch_panic(out, || -> Result<i32, ErrorCode> { panic!("Oops") }) }
+        }
+
+        #[test]
+        pub fn can_marshal_strings() {
+            let input = CString::new("abc").unwrap();
+            let mut out: *mut c_char = std::ptr::null_mut();
+            assert_eq!(to_upper(input.as_ptr(), &mut out), ErrorCode::Ok);
+            assert_eq!(unsafe { CStr::from_ptr(out) }.to_str().unwrap(), "ABC");
+            unsafe { fable_free_string(out) };
+            assert_eq!(to_upper(std::ptr::null(), &mut out), ErrorCode::NullPointer);
+        }
+
+        #[test]
+        pub fn can_catch_panics_at_boundary() {
+            let mut out = 0;
+            assert_eq!(fail(&mut out), ErrorCode::Exception);
+            let message = unsafe { CStr::from_ptr(fable_last_error_message()) };
+            assert_eq!(message.to_str().unwrap(), "Oops");
+        }
+
+        #[test]
+        pub fn can_pass_opaque_handles() {
+            let handle = Handle::new(string("abc"));
+            assert_eq!(unsafe { handle.as_ref() }.unwrap().as_str(), "abc");
+            unsafe { handle.free() };
+            assert!(Handle::<string>::null().is_null());
+        }
+    }
+
+    pub mod TimeProviderTests {
+        use fable_library_rust::DateTimeOffset_::DateTimeOffset;
+        use fable_library_rust::DateTime_::{resetTimeProvider, setTimeProvider, DateTime, FakeTimeProvider};
+        use fable_library_rust::TimeSpan_::TimeSpan;
+
+        #[test]
+        pub fn fake_time_provider_drives_now_and_utc_now() {
+            // the provider is global, so the fake clock starts at the real time
+            // and is only advanced a little, for the tests running meanwhile
+            let start = DateTimeOffset::utcNow();
+            let fake = FakeTimeProvider::new(start);
+            setTimeProvider(fake.clone());
+            fake.advance(TimeSpan::from_seconds(2.0));
+            let (utcNow, now) = (DateTime::utcNow(), DateTime::now());
+            resetTimeProvider();
+            assert_eq!(utcNow, start.utcDateTime().add(TimeSpan::from_seconds(2.0)));
+            assert_eq!(now, utcNow.toLocalTime());
+            assert_ne!(DateTime::utcNow(), utcNow);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    pub mod SerdeTests {
+        use fable_library_rust::DateTime_::DateTime;
+        use fable_library_rust::String_::string;
+        use fable_library_rust::TimeSpan_::TimeSpan;
+
+        #[test]
+        pub fn timespan_round_trips_as_iso8601_duration() {
+            let cases = [
+                (TimeSpan::new_ticks(0), "\"PT0S\""),
+                (TimeSpan::new_dhms(1, 2, 30, 0), "\"P1DT2H30M\""),
+                (TimeSpan::new_dhms_milli(0, 0, 0, 1, 250), "\"PT1.25S\""),
+                (TimeSpan::from_ticks(1), "\"PT0.0000001S\""),
+                (TimeSpan::from_ticks(-5_000_000), "\"-PT0.5S\""),
+                (TimeSpan::new_dhms(-3, -4, 0, -5), "\"-P3DT4H5S\""),
+            ];
+            for (ts, expected) in cases {
+                let json = serde_json::to_string(&ts).unwrap();
+                assert_eq!(json, expected);
+                assert_eq!(serde_json::from_str::<TimeSpan>(&json).unwrap(), ts);
+            }
+        }
+
+        #[test]
+        pub fn timespan_deserializes_other_duration_forms() {
+            let parse = |s: &str| serde_json::from_str::<TimeSpan>(s).unwrap();
+            assert_eq!(parse("\"P1W\""), TimeSpan::new_dhms(7, 0, 0, 0));
+            assert_eq!(parse("\"PT1,5S\""), TimeSpan::new_dhms_milli(0, 0, 0, 1, 500));
+            assert_eq!(parse("\"1.02:30:00\""), TimeSpan::new_dhms(1, 2, 30, 0));
+            assert!(serde_json::from_str::<TimeSpan>("\"P1Y\"").is_err());
+        }
+
+        #[test]
+        pub fn datetime_round_trips_each_kind() {
+            // Unspecified, Utc, Local
+            for kind in [0, 1, 2] {
+                let dt = DateTime::new_ticks_kind(638_000_000_001_234_567, kind);
+                let json = serde_json::to_string(&dt).unwrap();
+                assert_eq!(json, format!("\"{}\"", dt.toString(string("o"))));
+                let back = serde_json::from_str::<DateTime>(&json).unwrap();
+                assert_eq!((back.ticks(), back.kind()), (dt.ticks(), kind));
+            }
+        }
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    pub mod MetricsTests {
+        use fable_library_rust::Metrics_::{addListener, removeListener, Instrument, Meter, MeterListener, Tag};
+        use fable_library_rust::NativeArray_::Array;
+        use fable_library_rust::Native_::{Any, Lrc, LrcPtr};
+        use fable_library_rust::String_::string;
+        use std::sync::{Arc, Mutex};
+
+        const METER_NAME: &str = "ExtInteropTests.Metrics";
+
+        type Recorded = Arc<Mutex<Vec<(String, f64, Vec<(String, String)>)>>>;
+
+        struct Recorder(Recorded);
+
+        impl MeterListener for Recorder {
+            fn measurement_recorded(&self, instrument: &Instrument, value: f64, tags: &[(string, string)]) {
+                // other tests may record measurements at the same time
+                if instrument.meterName().as_str() == METER_NAME {
+                    let tags = tags.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+                    self.0.lock().unwrap().push((instrument.name().to_string(), value, tags));
+                }
+            }
+        }
+
+        fn tag<T: Any>(key: &'static str, value: T) -> Tag {
+            let value: Lrc<dyn Any> = Lrc::new(value);
+            (string(key), LrcPtr::from(value))
+        }
+
+        #[test]
+        pub fn listeners_receive_measurements_and_tags() {
+            let recorded: Recorded = Arc::default();
+            let listener = addListener(Recorder(recorded.clone()));
+            let meter = Meter::new(string(METER_NAME), string("1.0"));
+            let counter = meter.createCounter(string("requests"), string(""), string(""));
+            let histogram = meter.createHistogram(string("latency"), string("ms"), string(""));
+
+            counter.add(2, Array::from([tag("route", string("/home")), tag("ok", true)]));
+            histogram.record(12.5, Array::from([tag("status", 200)]));
+            removeListener(&listener);
+            counter.add(1, Array::from([]));
+            meter.dispose();
+
+            let tags = |kvs: &[(&str, &str)]| kvs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+            assert_eq!(
+                *recorded.lock().unwrap(),
+                vec![
+                    ("requests".to_string(), 2.0, tags(&[("route", "/home"), ("ok", "true")])),
+                    ("latency".to_string(), 12.5, tags(&[("status", "200")])),
+                ]
+            );
+        }
+    }
+
+    #[cfg(all(feature = "arena", not(feature = "no_std")))]
+    pub mod ArenaTests {
+        use fable_library_rust::Arena_::{withArena, ArenaAlloc};
+        use fable_library_rust::Native_::Func0;
+        use fable_library_rust::String_::{fromString, string};
+
+        #[global_allocator]
+        static ALLOC: ArenaAlloc = ArenaAlloc;
+
+        fn addr<T>(x: &T) -> usize {
+            x as *const T as usize
+        }
+
+        #[test]
+        pub fn nested_scopes_share_the_bump_chunk() {
+            let (a, b, c) = withArena(Func0::new(|| {
+                let inner = Func0::new(|| Box::new(2u64));
+                let a = Box::new(1u64);
+                let b = withArena(inner);
+                let c = Box::new(3u64);
+                (addr(&*a), addr(&*b), addr(&*c))
+            }));
+            assert_eq!((b - a, c - b), (16, 16));
+        }
+
+        #[test]
+        pub fn new_scopes_start_a_new_chunk() {
+            let x = withArena(Func0::new(|| Box::new(1u64)));
+            let y = withArena(Func0::new(|| Box::new(2u64)));
+            // the escaped value keeps its chunk alive, but it is no longer bumped
+            assert_eq!((*x, *y), (1, 2));
+            assert_ne!(addr(&*y), addr(&*x) + 16);
+        }
+
+        #[test]
+        pub fn values_escaping_the_scope_stay_valid() {
+            let escaped = withArena(Func0::new(|| {
+                let s = fromString(format!("{} {}", "a string that is not inlined", 42));
+                // move well past the first chunk
+                for i in 0..10_000u64 {
+                    let tmp = Box::new([i; 4]);
+                    assert_eq!(tmp[3], i);
+                }
+                let v: Vec<i32> = (0..100).collect();
+                (s, v)
+            }));
+            withArena(Func0::new(|| (0..1000).map(|i| Box::new(i)).count()));
+            assert_eq!(escaped.0, string("a string that is not inlined 42"));
+            assert_eq!(escaped.1.iter().sum::<i32>(), 4950);
+        }
     }
 }
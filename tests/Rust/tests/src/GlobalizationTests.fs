@@ -0,0 +1,280 @@
+module Fable.Tests.GlobalizationTests
+
+open System
+open System.Globalization
+open Util.Testing
+
+[<Fact>]
+let ``CultureInfo.InvariantCulture works`` () =
+    let culture = CultureInfo.InvariantCulture
+    culture.Name |> equal ""
+    culture.TwoLetterISOLanguageName |> equal "iv"
+    culture.NumberFormat.NumberDecimalSeparator |> equal "."
+    culture.NumberFormat.NumberGroupSeparator |> equal ","
+    culture.DateTimeFormat.ShortDatePattern |> equal "MM/dd/yyyy"
+    culture.DateTimeFormat.LongTimePattern |> equal "HH:mm:ss"
+
+[<Fact>]
+let ``CultureInfo.GetCultureInfo works`` () =
+    let culture = CultureInfo.GetCultureInfo("de-DE")
+    culture.Name |> equal "de-DE"
+    culture.TwoLetterISOLanguageName |> equal "de"
+    culture.IsNeutralCulture |> equal false
+    culture.Parent.Name |> equal "de"
+    culture.Parent.IsNeutralCulture |> equal true
+    culture.Parent.Parent.Name |> equal ""
+
+[<Fact>]
+let ``CultureInfo.Parent works for Dutch`` () =
+    CultureInfo("nl-NL").Parent.Name |> equal "nl"
+    CultureInfo("nl-NL").Parent.IsNeutralCulture |> equal true
+    CultureInfo("nl-NL").Parent.Parent.Name |> equal ""
+
+[<Fact>]
+let ``CultureInfo constructor works`` () =
+    let culture = CultureInfo("en-US")
+    culture.Name |> equal "en-US"
+    culture.EnglishName |> equal "English (United States)"
+    culture.ToString() |> equal "en-US"
+
+[<Fact>]
+let ``CultureInfo with unknown name throws`` () =
+    throwsAnyError (fun () -> CultureInfo("xx-YY") |> ignore)
+
+[<Fact>]
+let ``NumberFormatInfo works`` () =
+    let nfi = CultureInfo("fr-FR").NumberFormat
+    nfi.NumberDecimalSeparator |> equal ","
+    nfi.NegativeSign |> equal "-"
+    nfi.CurrencySymbol |> equal "€"
+    nfi.NumberGroupSizes |> equal [| 3 |]
+    NumberFormatInfo.InvariantInfo.CurrencySymbol |> equal "¤"
+
+[<Fact>]
+let ``DateTimeFormatInfo works`` () =
+    let dtfi = CultureInfo("de-DE").DateTimeFormat
+    dtfi.DateSeparator |> equal "."
+    dtfi.ShortDatePattern |> equal "dd.MM.yyyy"
+    dtfi.GetMonthName(3) |> equal "März"
+    dtfi.GetAbbreviatedDayName(DayOfWeek.Monday) |> equal "Mo."
+    dtfi.MonthNames.Length |> equal 13
+    dtfi.FirstDayOfWeek |> equal DayOfWeek.Monday
+    DateTimeFormatInfo.InvariantInfo.GetDayName(DayOfWeek.Sunday) |> equal "Sunday"
+
+[<Fact>]
+let ``CompareInfo.Compare works`` () =
+    let ci = CultureInfo("en-US").CompareInfo
+    ci.Compare("apple", "Banana") |> equal -1
+    ci.Compare("a", "A") |> equal -1
+    ci.Compare("resume", "résumé") |> equal -1
+    ci.Compare("résumé", "resumes") |> equal -1
+    ci.Compare("e\u0301", "é") |> equal 0
+
+[<Fact>]
+let ``CompareInfo.Compare with options works`` () =
+    let ci = CultureInfo.InvariantCulture.CompareInfo
+    ci.Compare("a", "A", CompareOptions.IgnoreCase) |> equal 0
+    ci.Compare("resume", "résumé", CompareOptions.IgnoreNonSpace) |> equal 0
+    ci.Compare("Straße", "strasse", CompareOptions.IgnoreCase) |> equal 0
+    ci.Compare("a-b", "ab", CompareOptions.IgnoreSymbols) |> equal 0
+    ci.Compare("a", "B", CompareOptions.Ordinal) |> equal 1
+
+[<Fact>]
+let ``CompareInfo tailoring works`` () =
+    CultureInfo("en-US").CompareInfo.Compare("ñu", "nz") |> equal -1
+    CultureInfo("es-ES").CompareInfo.Compare("ñu", "nz") |> equal 1
+
+[<Fact>]
+let ``CompareInfo.IndexOf works`` () =
+    let ci = CultureInfo("fr-FR").CompareInfo
+    ci.IndexOf("Crème brûlée", "brulee") |> equal -1
+    ci.IndexOf("Crème brûlée", "brulee", CompareOptions.IgnoreNonSpace) |> equal 6
+    ci.IndexOf("Crème brûlée", 'E', CompareOptions.IgnoreCase ||| CompareOptions.IgnoreNonSpace) |> equal 2
+    ci.LastIndexOf("abcabc", "bc") |> equal 4
+
+[<Fact>]
+let ``CompareInfo.IsPrefix and IsSuffix work`` () =
+    let ci = CultureInfo.InvariantCulture.CompareInfo
+    ci.IsPrefix("Éclair", "ec") |> equal false
+    ci.IsPrefix("Éclair", "ec", CompareOptions.IgnoreCase ||| CompareOptions.IgnoreNonSpace) |> equal true
+    ci.IsSuffix("Éclair", "AIR") |> equal false
+    ci.IsSuffix("Éclair", "AIR", CompareOptions.IgnoreCase) |> equal true
+
+[<Fact>]
+let ``CompareInfo.GetSortKey works`` () =
+    let ci = CultureInfo("de-DE").CompareInfo
+    let words = [| "Zebra"; "Äpfel"; "apfel"; "Birne" |]
+    words
+    |> Array.sortWith (fun a b -> SortKey.Compare(ci.GetSortKey(a), ci.GetSortKey(b)))
+    |> equal [| "apfel"; "Äpfel"; "Birne"; "Zebra" |]
+    ci.GetSortKey("Birne").OriginalString |> equal "Birne"
+
+[<Fact>]
+let ``Double.Parse with culture works`` () =
+    Double.Parse("1.5", CultureInfo.InvariantCulture) |> equal 1.5
+    Double.Parse("1,5", CultureInfo("de-DE")) |> equal 1.5
+    Double.Parse("-1.234,5", CultureInfo("de-DE")) |> equal -1234.5
+    Double.Parse("1,234.5", NumberStyles.Any, CultureInfo("en-US")) |> equal 1234.5
+
+[<Fact>]
+let ``Double.TryParse with culture works`` () =
+    let culture = CultureInfo("fr-FR")
+    Double.TryParse("2,25", NumberStyles.Float, culture) |> equal (true, 2.25)
+    Double.TryParse("foo", NumberStyles.Float, culture) |> equal (false, 0.0)
+
+[<Fact>]
+let ``Decimal.Parse with culture works`` () =
+    Decimal.Parse("12,5", CultureInfo("it-IT")) |> equal 12.5M
+    Decimal.Parse("1.234,56", CultureInfo("de-DE")) |> equal 1234.56M
+    Decimal.Parse("1.234,56 €", NumberStyles.Currency, CultureInfo("de-DE")) |> equal 1234.56M
+    Decimal.Parse("($1,234.50)", NumberStyles.Currency, CultureInfo("en-US")) |> equal -1234.50M
+    Decimal.TryParse("1,234.56", NumberStyles.Number, CultureInfo("de-DE")) |> equal (false, 0M)
+
+[<Fact>]
+let ``Number ToString with culture works`` () =
+    (1.5).ToString(CultureInfo.InvariantCulture) |> equal "1.5"
+    (1.5).ToString(CultureInfo("de-DE")) |> equal "1,5"
+    (-2.25).ToString(CultureInfo("fr-FR")) |> equal "-2,25"
+    (42).ToString(CultureInfo("de-DE")) |> equal "42"
+    (1.5M).ToString(CultureInfo("es-ES")) |> equal "1,5"
+
+[<Fact>]
+let ``RegionInfo works`` () =
+    let region = RegionInfo("en-US")
+    region.Name |> equal "en-US"
+    region.TwoLetterISORegionName |> equal "US"
+    region.ThreeLetterISORegionName |> equal "USA"
+    region.ISOCurrencySymbol |> equal "USD"
+    region.CurrencySymbol |> equal "$"
+    region.IsMetric |> equal false
+    RegionInfo("DE").Name |> equal "DE"
+    RegionInfo("ja-JP").CurrencySymbol |> equal "￥"
+    RegionInfo("fr-FR").CurrencyEnglishName |> equal "Euro"
+
+[<Fact>]
+let ``RegionInfo with unknown name throws`` () =
+    throwsAnyError (fun () -> RegionInfo("XY") |> ignore)
+
+[<Fact>]
+let ``Calendar.GetWeekOfYear works`` () =
+    let cal = GregorianCalendar()
+    cal.GetWeekOfYear(DateTime(2024, 1, 1), CalendarWeekRule.FirstDay, DayOfWeek.Sunday) |> equal 1
+    cal.GetWeekOfYear(DateTime(2021, 12, 31), CalendarWeekRule.FirstDay, DayOfWeek.Sunday) |> equal 53
+    cal.GetWeekOfYear(DateTime(2021, 1, 1), CalendarWeekRule.FirstFullWeek, DayOfWeek.Sunday) |> equal 52
+    cal.GetWeekOfYear(DateTime(2021, 1, 1), CalendarWeekRule.FirstFourDayWeek, DayOfWeek.Monday) |> equal 53
+    cal.GetWeekOfYear(DateTime(2021, 1, 4), CalendarWeekRule.FirstFourDayWeek, DayOfWeek.Monday) |> equal 1
+    // unlike ISO weeks, the last days of a year are never in week 1
+    cal.GetWeekOfYear(DateTime(2024, 12, 30), CalendarWeekRule.FirstFourDayWeek, DayOfWeek.Monday) |> equal 53
+
+[<Fact>]
+let ``Calendar.GetWeekOfYear works with culture week rules`` () =
+    let weekOfYear (name: string) (date: DateTime) =
+        let dtfi = CultureInfo(name).DateTimeFormat
+        dtfi.Calendar.GetWeekOfYear(date, dtfi.CalendarWeekRule, dtfi.FirstDayOfWeek)
+    weekOfYear "en-US" (DateTime(2021, 1, 3)) |> equal 2
+    weekOfYear "de-DE" (DateTime(2021, 1, 3)) |> equal 53
+    CultureInfo.InvariantCulture.Calendar.GetWeekOfYear(DateTime(2021, 1, 3), CalendarWeekRule.FirstDay, DayOfWeek.Sunday)
+    |> equal 2
+
+[<Fact>]
+let ``Calendar members work`` () =
+    let cal = CultureInfo.InvariantCulture.Calendar
+    cal.GetYear(DateTime(2024, 2, 29)) |> equal 2024
+    cal.GetDayOfYear(DateTime(2024, 2, 29)) |> equal 60
+    cal.GetDaysInMonth(2024, 2) |> equal 29
+    cal.GetDaysInYear(2023) |> equal 365
+    cal.IsLeapYear(2024) |> equal true
+    cal.AddWeeks(DateTime(2024, 2, 29), 1) |> equal (DateTime(2024, 3, 7))
+    throwsAnyError (fun () -> cal.GetDaysInMonth(2024, 13) |> ignore)
+
+[<Fact>]
+let ``Currency format works`` () =
+    (1234.5).ToString("C", CultureInfo("en-US")) |> equal "$1,234.50"
+    (-1234.5).ToString("C", CultureInfo("en-US")) |> equal "-$1,234.50"
+    (1234567.891).ToString("C2", CultureInfo("de-DE")) |> equal "1.234.567,89\u00A0€"
+    (-5).ToString("c0", CultureInfo("fr-FR")) |> equal "-5\u00A0€"
+    (1234).ToString("C", CultureInfo("ja-JP")) |> equal "￥1,234"
+    (12.0).ToString("C", CultureInfo.InvariantCulture) |> equal "¤12.00"
+    (-12.0).ToString("C", CultureInfo.InvariantCulture) |> equal "(¤12.00)"
+
+[<Fact>]
+let ``Currency format rounds half away from zero`` () =
+    (0.125).ToString("C2", CultureInfo("en-US")) |> equal "$0.13"
+    (2.5).ToString("C0", CultureInfo("en-US")) |> equal "$3"
+    (-2.5).ToString("C0", CultureInfo("en-US")) |> equal "-$3"
+
+[<Fact>]
+let ``Currency format keeps large integers exact`` () =
+    Int64.MaxValue.ToString("C0", CultureInfo("en-US")) |> equal "$9,223,372,036,854,775,807"
+    UInt64.MaxValue.ToString("C0", CultureInfo("en-US")) |> equal "$18,446,744,073,709,551,615"
+    Int64.MinValue.ToString("C", CultureInfo("en-US")) |> equal "-$9,223,372,036,854,775,808.00"
+
+[<Fact>]
+let ``Decimal currency format works`` () =
+    (1234.565M).ToString("C", CultureInfo("en-GB")) |> equal "£1,234.57"
+    (-0.5M).ToString("C0", CultureInfo("en-US")) |> equal "-$1"
+    (999M).ToString("C", CultureInfo("pt-BR")) |> equal "R$\u00A0999,00"
+
+[<Fact>]
+let ``Standard numeric formats work`` () =
+    let inv = CultureInfo.InvariantCulture
+    (1234).ToString("N", inv) |> equal "1,234.00"
+    (-1234567L).ToString("N0", inv) |> equal "-1,234,567"
+    (1234.5678).ToString("N2", inv) |> equal "1,234.57"
+    (0.125).ToString("F2", inv) |> equal "0.13"
+    (1.005).ToString("F2", inv) |> equal "1.00"
+    (-1).ToString("F2", inv) |> equal "-1.00"
+    (-42).ToString("D5", inv) |> equal "-00042"
+    (-1y).ToString("X4", inv) |> equal "00FF"
+    (12345.6789).ToString("e2", inv) |> equal "1.23e+004"
+    (1234).ToString("E0", inv) |> equal "1E+003"
+    (0.25).ToString("P", inv) |> equal "25.00 %"
+    (-0.25).ToString("P1", inv) |> equal "-25.0 %"
+    (12345).ToString("G3", inv) |> equal "1.23E+04"
+    (123.456).ToString("G4", inv) |> equal "123.5"
+    (0.00001).ToString("G", inv) |> equal "1E-05"
+    (System.UInt64.MaxValue).ToString("N0", inv) |> equal "18,446,744,073,709,551,615"
+    (nan).ToString("N", inv) |> equal "NaN"
+    throwsAnyError (fun () -> (1.5).ToString("D", inv) |> ignore)
+
+[<Fact>]
+let ``Standard numeric formats use culture`` () =
+    (0.5).ToString("P0", CultureInfo("en-US")) |> equal "50%"
+    (-1234.5).ToString("N1", CultureInfo("de-DE")) |> equal "-1.234,5"
+    (1.5).ToString("E1", CultureInfo("de-DE")) |> equal "1,5E+000"
+    (1234.5f).ToString("F1", CultureInfo("fr-FR")) |> equal "1234,5"
+
+[<Fact>]
+let ``Decimal standard numeric formats work`` () =
+    let inv = CultureInfo.InvariantCulture
+    (1.50M).ToString("G", inv) |> equal "1.50"
+    (1.50M).ToString("G2", inv) |> equal "1.5"
+    (-1234.565M).ToString("N2", inv) |> equal "-1,234.57"
+    (-0.001M).ToString("F2", inv) |> equal "0.00"
+    (0.075M).ToString("P1", inv) |> equal "7.5 %"
+    String.Format(inv, "{0:N1}", 1234.56M) |> equal "1,234.6"
+
+[<Fact>]
+let ``Custom numeric formats work`` () =
+    let inv = CultureInfo.InvariantCulture
+    (1234567.891).ToString("#,##0.00", inv) |> equal "1,234,567.89"
+    (-1234.5).ToString("#,##0.00", inv) |> equal "-1,234.50"
+    (0.12345).ToString("0.##%", inv) |> equal "12.35%"
+    (0.25).ToString("0.0\u2030", inv) |> equal "250.0\u2030"
+    (1234567890).ToString("#,##0,,M", inv) |> equal "1,235M"
+    (12345).ToString("0.00E+00", inv) |> equal "1.23E+04"
+    (0.000123).ToString("0.0e0", inv) |> equal "1.2e-4"
+    (42).ToString("'#'0 \\%", inv) |> equal "#42 %"
+    (0.45).ToString("#.#", inv) |> equal ".5"
+    (19.99M).ToString("0000.000", inv) |> equal "0019.990"
+
+[<Fact>]
+let ``Custom numeric format sections work`` () =
+    let inv = CultureInfo.InvariantCulture
+    (5).ToString("#;(#);zero", inv) |> equal "5"
+    (-5).ToString("#;(#);zero", inv) |> equal "(5)"
+    (0).ToString("#;(#);zero", inv) |> equal "zero"
+    (-5).ToString("#;;zero", inv) |> equal "-5"
+    (0.001).ToString("0.0;(0.0);nil", inv) |> equal "nil"
+    (-1234.5M).ToString("$#,##0.00;($#,##0.00)", inv) |> equal "($1,234.50)"
+    (1234.5).ToString("#,##0.00", CultureInfo("de-DE")) |> equal "1.234,50"
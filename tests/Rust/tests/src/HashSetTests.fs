@@ -30,6 +30,15 @@ let ``HashSet ctor with capacity works`` () =
     let xs = HashSet<int>(10)
     xs.Count |> equal 0
 
+[<Fact>]
+let ``HashSet EnsureCapacity works`` () =
+    let xs = HashSet<int>(10, HashIdentity.Structural)
+    xs.EnsureCapacity(1000) >= 1000 |> equal true
+    for i in 1..1000 do xs.Add(i % 500) |> ignore
+    xs.Count |> equal 500
+    xs.TrimExcess()
+    xs.Contains(499) |> equal true
+
 [<Fact>]
 let ``HashSet ctor from IEnumerable works`` () =
     let s = List.toSeq [1;2;2;3]
@@ -295,6 +295,11 @@ let ``Map.ofArray works`` () =
     let xs = Map.ofArray [|1,1.; 2,4.; 3,9.; 4,16.|]
     equal 4 xs.Count
 
+[<Fact>]
+let ``Map.ofList with duplicate keys works`` () =
+    let xs = Map.ofList [3,"a"; 1,"b"; 3,"c"; 2,"d"; 1,"e"]
+    xs |> Map.toList |> equal [1,"e"; 2,"d"; 3,"c"]
+
 [<Fact>]
 let ``Map.ofSeq works`` () =
     let xs = Map.ofSeq [1,1.; 2,4.; 3,9.; 4,16.]
@@ -0,0 +1,49 @@
+[<Fable.Core.Rust.OuterAttr("cfg", [|"not(feature = \"no_std\")"|])>]
+module Fable.Tests.MetricsTests
+
+open Util.Testing
+open System
+open System.Collections.Generic
+open System.Diagnostics.Metrics
+
+[<Fact>]
+let ``Meter name and version work`` () =
+    use meter = new Meter("Fable.Tests.Meter", "1.0")
+    meter.Name |> equal "Fable.Tests.Meter"
+    meter.Version |> equal "1.0"
+
+[<Fact>]
+let ``Instruments keep their name, unit and description`` () =
+    use meter = new Meter("Fable.Tests.Instruments")
+    let counter = meter.CreateCounter<int>("requests", "{request}", "Number of requests")
+    counter.Name |> equal "requests"
+    counter.Unit |> equal "{request}"
+    counter.Description |> equal "Number of requests"
+    counter.IsObservable |> equal false
+    let histogram = meter.CreateHistogram<float>("latency", "ms")
+    histogram.Name |> equal "latency"
+    histogram.Unit |> equal "ms"
+
+[<Fact>]
+let ``Measurements can be recorded without listeners`` () =
+    use meter = new Meter("Fable.Tests.Measurements")
+    let counter = meter.CreateCounter<int64>("requests")
+    counter.Enabled |> equal false
+    counter.Add(1L)
+    counter.Add(2L, KeyValuePair("route", box "/"))
+    counter.Add(3L, KeyValuePair("route", box "/"), KeyValuePair("code", box 200))
+    let upDown = meter.CreateUpDownCounter<int>("connections")
+    upDown.Add(-1)
+    let histogram = meter.CreateHistogram<float>("latency")
+    histogram.Record(1.5, [| KeyValuePair("route", box "/") |])
+    histogram.Enabled |> equal false
+
+[<Fact>]
+let ``Observable instruments work`` () =
+    use meter = new Meter("Fable.Tests.Observable")
+    let gauge = meter.CreateObservableGauge<int>("queue", Func<int>(fun () -> 7), "{item}", "Queue length")
+    gauge.Name |> equal "queue"
+    gauge.Unit |> equal "{item}"
+    gauge.IsObservable |> equal true
+    let counter = meter.CreateObservableCounter<float>("cpu", Func<float>(fun () -> 0.5))
+    counter.IsObservable |> equal true
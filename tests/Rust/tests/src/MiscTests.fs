@@ -473,6 +473,8 @@ type ValueType =
     val public X : int
   end
 
+type NamedRecord = { Name: string; Count: int }
+
 type MutableFoo =
     { mutable x: int }
 
@@ -1159,6 +1161,12 @@ let ``Unchecked.defaultof works`` () =
     Unchecked.defaultof<ValueType> |> equal x
     x.X |> equal 0
 
+[<Fact>]
+let ``Unchecked.defaultof works with records`` () =
+    let r = Unchecked.defaultof<NamedRecord>
+    r.Name |> equal ""
+    r.Count |> equal 0
+
 [<Fact>]
 let ``Unchecked.defaultof works with tuples`` () = // See #2491
     // TODO: Non-struct tuples
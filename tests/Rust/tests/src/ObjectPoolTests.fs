@@ -0,0 +1,76 @@
+module Fable.Tests.ObjectPoolTests
+
+open Util.Testing
+open System.Text
+open Microsoft.Extensions.ObjectPool
+
+type BuilderPolicy() =
+    let mutable created = 0
+    member _.Created = created
+
+    interface IPooledObjectPolicy<StringBuilder> with
+        member _.Create() =
+            created <- created + 1
+            StringBuilder()
+
+        member _.Return(sb) =
+            sb.Clear() |> ignore
+            true
+
+[<Fact>]
+let ``DefaultObjectPool reuses returned objects`` () =
+    let policy = BuilderPolicy()
+    let pool = DefaultObjectPool<StringBuilder>(policy)
+    let sb = pool.Get()
+    sb.Append("hello") |> ignore
+    pool.Return(sb)
+    let sb2 = pool.Get()
+    sb2.ToString() |> equal ""
+    policy.Created |> equal 1
+
+[<Fact>]
+let ``DefaultObjectPool creates new objects when empty`` () =
+    let policy = BuilderPolicy()
+    let pool = DefaultObjectPool<StringBuilder>(policy)
+    let sb1 = pool.Get()
+    let sb2 = pool.Get()
+    policy.Created |> equal 2
+    pool.Return(sb1)
+    pool.Return(sb2)
+    pool.Get() |> ignore
+    pool.Get() |> ignore
+    policy.Created |> equal 2
+
+[<Fact>]
+let ``DefaultObjectPool respects maximumRetained`` () =
+    let mutable created = 0
+    let policy =
+        { new IPooledObjectPolicy<int[]> with
+            member _.Create() =
+                created <- created + 1
+                Array.zeroCreate 4
+            member _.Return(_) = true }
+    let pool = DefaultObjectPool<int[]>(policy, 1)
+    let a = pool.Get()
+    let b = pool.Get()
+    pool.Return(a)
+    pool.Return(b)
+    pool.Get() |> ignore
+    pool.Get() |> ignore
+    created |> equal 3
+
+[<Fact>]
+let ``DefaultObjectPool drops objects rejected by the policy`` () =
+    let mutable created = 0
+    let policy =
+        { new IPooledObjectPolicy<ResizeArray<int>> with
+            member _.Create() =
+                created <- created + 1
+                ResizeArray()
+            member _.Return(xs) = xs.Count = 0 }
+    let pool = ObjectPool.Create(policy)
+    let xs = pool.Get()
+    xs.Add(1)
+    pool.Return(xs)
+    pool.Get() |> ignore
+    created |> equal 2
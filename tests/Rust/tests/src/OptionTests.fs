@@ -166,6 +166,40 @@ let ``ValueOption.IsSome,IsNone works`` () =
     o2.IsNone |> equal false
     o2.IsSome |> equal true
 
+[<Fact>]
+let ``ValueOption pattern matching works`` () =
+    let describe (o: int voption) =
+        match o with
+        | ValueSome x when x > 0 -> "positive"
+        | ValueSome _ -> "other"
+        | ValueNone -> "none"
+    describe (ValueSome 3) |> equal "positive"
+    describe (ValueSome -3) |> equal "other"
+    describe ValueNone |> equal "none"
+    (ValueSome 2).Value |> equal 2
+
+[<Fact>]
+let ``ValueOption module functions work`` () =
+    ValueSome 2 |> ValueOption.map (fun i -> i + 1) |> equal (ValueSome 3)
+    ValueSome 2 |> ValueOption.bind (fun i -> if i > 1 then ValueSome i else ValueNone) |> equal (ValueSome 2)
+    ValueSome 2 |> ValueOption.filter (fun i -> i > 2) |> equal ValueNone
+    ValueNone |> ValueOption.defaultValue 5 |> equal 5
+    ValueNone |> ValueOption.defaultWith (fun () -> 6) |> equal 6
+    ValueSome 1 |> ValueOption.orElse (ValueSome 2) |> equal (ValueSome 1)
+    ValueSome 3 |> ValueOption.fold (+) 1 |> equal 4
+    ValueSome 3 |> ValueOption.exists (fun i -> i = 3) |> equal true
+    ValueSome 3 |> ValueOption.contains 3 |> equal true
+    ValueSome 3 |> ValueOption.toList |> equal [3]
+    ValueSome 3 |> ValueOption.toArray |> equal [|3|]
+    (ValueSome 2, ValueSome 3) ||> ValueOption.map2 (+) |> equal (ValueSome 5)
+
+[<Fact>]
+let ``ValueOption converts to and from Option`` () =
+    ValueSome 4 |> ValueOption.toOption |> equal (Some 4)
+    Some 4 |> ValueOption.ofOption |> equal (ValueSome 4)
+    Some 4 |> Option.toValueOption |> equal (ValueSome 4)
+    ValueNone |> Option.ofValueOption |> equal (None: int option)
+
 [<Fact>]
 let ``Option.iter works`` () =
     let mutable res = 0
@@ -414,6 +448,17 @@ let ``Option.map ignore generates Some ()`` () = // See #1923
     let myOtherSome = mySome |> Option.map ignore
     equal mySome myOtherSome
 
+[<Fact>]
+let ``Option.ofObj and Option.toObj work`` () =
+    Option.ofObj "a" |> equal (Some "a")
+    Some "b" |> Option.toObj |> equal "b"
+    (None: string option) |> Option.toObj |> equal null
+
+[<Fact>]
+let ``Option.ofNullable and Option.toNullable work`` () =
+    Option.ofNullable (System.Nullable 3) |> equal (Some 3)
+    (Option.toNullable (Some 3)).Value |> equal 3
+
 // [<Fact>]
 // let ``Some (box null) |> Option.isSome evals to true`` () = // See #1948
 //     Some (box null) |> Option.isSome |> equal true
@@ -0,0 +1,83 @@
+[<Fable.Core.Rust.OuterAttr("cfg", [|"not(feature = \"no_std\")"|])>]
+module Fable.Tests.RateLimitingTests
+
+open Util.Testing
+open System
+open System.Threading.RateLimiting
+open Fable.Core.Rust
+
+[<Fact>]
+let ``TokenBucketRateLimiter works`` () =
+    let options =
+        TokenBucketRateLimiterOptions(
+            TokenLimit = 2,
+            TokensPerPeriod = 1,
+            ReplenishmentPeriod = TimeSpan.FromSeconds(1.),
+            AutoReplenishment = false
+        )
+    use limiter = new TokenBucketRateLimiter(options)
+    limiter.AttemptAcquire().IsAcquired |> equal true
+    limiter.AttemptAcquire().IsAcquired |> equal true
+    limiter.AttemptAcquire().IsAcquired |> equal false
+    limiter.TryReplenish() |> equal true
+    limiter.AttemptAcquire().IsAcquired |> equal true
+    let stats = limiter.GetStatistics()
+    stats.TotalSuccessfulLeases |> equal 3L
+    stats.TotalFailedLeases |> equal 1L
+
+[<Fact>]
+let ``FixedWindowRateLimiter works`` () =
+    let options =
+        FixedWindowRateLimiterOptions(
+            PermitLimit = 3,
+            Window = TimeSpan.FromMinutes(1.),
+            AutoReplenishment = false
+        )
+    use limiter = new FixedWindowRateLimiter(options)
+    limiter.AttemptAcquire(3).IsAcquired |> equal true
+    limiter.AttemptAcquire(1).IsAcquired |> equal false
+    limiter.TryReplenish() |> equal true
+    limiter.GetStatistics().CurrentAvailablePermits |> equal 3L
+
+[<Fact>]
+let ``SlidingWindowRateLimiter works`` () =
+    let options =
+        SlidingWindowRateLimiterOptions(
+            PermitLimit = 2,
+            SegmentsPerWindow = 2,
+            Window = TimeSpan.FromMinutes(1.),
+            AutoReplenishment = false
+        )
+    use limiter = new SlidingWindowRateLimiter(options)
+    limiter.AttemptAcquire().IsAcquired |> equal true
+    limiter.TryReplenish() |> ignore
+    limiter.AttemptAcquire().IsAcquired |> equal true
+    limiter.AttemptAcquire().IsAcquired |> equal false
+    limiter.TryReplenish() |> ignore
+    limiter.GetStatistics().CurrentAvailablePermits |> equal 1L
+
+[<Fact>]
+let ``ConcurrencyLimiter releases permits on dispose`` () =
+    let options = ConcurrencyLimiterOptions(PermitLimit = 1)
+    use limiter = new ConcurrencyLimiter(options)
+    let lease = limiter.AttemptAcquire()
+    lease.IsAcquired |> equal true
+    limiter.AttemptAcquire().IsAcquired |> equal false
+    lease.Dispose()
+    limiter.AttemptAcquire().IsAcquired |> equal true
+
+[<Fact; OuterAttr("cfg", [|"feature = \"threaded\""|])>]
+let ``AcquireAsync respects the queue limit`` () =
+    let options =
+        ConcurrencyLimiterOptions(
+            PermitLimit = 1,
+            QueueLimit = 1,
+            QueueProcessingOrder = QueueProcessingOrder.OldestFirst
+        )
+    use limiter = new ConcurrencyLimiter(options)
+    let lease = limiter.AcquireAsync().AsTask().Result
+    lease.IsAcquired |> equal true
+    let queued = limiter.AcquireAsync().AsTask()
+    limiter.AcquireAsync().AsTask().Result.IsAcquired |> equal false
+    lease.Dispose()
+    queued.Result.IsAcquired |> equal true
@@ -55,14 +55,30 @@ let ``Regex.IsMatch with IgnoreCase and Multiline works`` () =
 
 [<Fact>]
 let ``Regex.Escape works`` () =
-    // TODO: a few chars are not escaped (e.g. # and white space)
     Regex.Escape(@"\*+?|{[()^$") |> equal @"\\\*\+\?\|\{\[\(\)\^\$"
     Regex.Escape(@"C:\Temp") |> equal @"C:\\Temp"
+    Regex.Escape("a b#c.d]}") |> equal @"a\ b\#c\.d]}"
+    Regex.Escape("a\tb\nc") |> equal @"a\tb\nc"
 
 [<Fact>]
 let ``Regex.Unescape works`` () =
     Regex.Unescape(@"\\\*\+\?\|\{\[\(\)\^\$") |> equal @"\*+?|{[()^$"
     Regex.Unescape(@"C:\\Temp") |> equal @"C:\Temp"
+    Regex.Unescape(@"a\ b\#c\tx\x41\u00e9") |> equal "a b#c\txA\u00e9"
+
+[<Fact>]
+let ``Regex.Unescape with unrecognized escape fails`` () =
+    throwsAnyError (fun () -> Regex.Unescape(@"\q"))
+
+[<Fact>]
+let ``Regex.CacheSize works`` () =
+    let size = Regex.CacheSize
+    size |> equal 15
+    Regex.CacheSize <- 2
+    for i in 1 .. 5 do
+        Regex.IsMatch("a" + string i, "a" + string i) |> equal true
+    Regex.CacheSize |> equal 2
+    Regex.CacheSize <- size
 
 [<Fact>]
 let ``Regex instance IsMatch works`` () =
@@ -643,3 +659,96 @@ let ``doesn't succeed when not existing group without any named groups`` () =
     let actual = r.Replace(text, replace)
 
     actual |> equal expected
+
+[<Fact>]
+let ``Regex.Matches handles empty matches`` () =
+    let test input pattern expected =
+        Regex.Matches(input, pattern)
+        |> Seq.cast<Match>
+        |> Seq.map (fun m -> m.Value)
+        |> Seq.toArray
+        |> equal (List.toArray expected)
+    test "aaa" @".*" ["aaa"; ""]
+    test "ab" @"" [""; ""; ""]
+
+[<Fact>]
+let ``Regex.Matches is enumerated lazily`` () =
+    let ms = Regex.Matches("a1b22c333", @"\d+")
+    ms |> Seq.head |> fun m -> m.Value |> equal "1"
+    ms |> Seq.map (fun m -> m.Index) |> Seq.toList |> equal [1; 3; 6]
+    ms.Count |> equal 3
+    ms[2].Value |> equal "333"
+
+[<Fact>]
+let ``Match indices count characters`` () =
+    let m = Regex.Match("héllo wörld", @"w\w+")
+    m.Index |> equal 6
+    m.Length |> equal 5
+    m.Value |> equal "wörld"
+
+[<Fact>]
+let ``Unnamed groups are numbered before named groups`` () =
+    let r = Regex(@"(?<year>\d{4})-(\d{2})")
+    r.GetGroupNames() |> equal [| "0"; "1"; "year" |]
+    r.GroupNumberFromName("year") |> equal 2
+    r.GroupNameFromNumber(1) |> equal "1"
+    let m = r.Match("2024-05")
+    m.Groups[1].Value |> equal "05"
+    m.Groups[2].Value |> equal "2024"
+    m.Groups["year"].Name |> equal "year"
+
+[<Fact>]
+let ``Group.Captures works`` () =
+    let m = Regex.Match("ab12", @"(?<num>\d+)")
+    let g = m.Groups["num"]
+    g.Captures.Count |> equal 1
+    g.Captures[0].Value |> equal "12"
+    g.Captures[0].Index |> equal 2
+    m.Groups["nothing"].Captures.Count |> equal 0
+
+[<Fact>]
+let ``Regex.Replace with .Net substitutions works`` () =
+    let r = Regex(@"(?<year>\d{4})-(\d{2})")
+    r.Replace("on 2024-05!", "$1/${year}") |> equal "on 05/2024!"
+    r.Replace("on 2024-05!", "[$&]") |> equal "on [2024-05]!"
+    r.Replace("on 2024-05!", "$$1") |> equal "on $1!"
+    r.Replace("on 2024-05!", "$1a") |> equal "on 05a!"
+    r.Replace("on 2024-05!", "$3") |> equal "on $3!"
+    r.Replace("on 2024-05!", "$`|$'") |> equal "on on |!!"
+    r.Replace("on 2024-05!", "$+") |> equal "on 2024!"
+
+[<Fact>]
+let ``Regex.Split includes captured groups`` () =
+    Regex.Split("a1b2c", @"(\d)") |> equal [| "a"; "1"; "b"; "2"; "c" |]
+    Regex.Split("a-b_c", @"-|(_)") |> equal [| "a"; "b"; "_"; "c" |]
+    Regex.Split("abc", "") |> equal [| ""; "a"; "b"; "c"; "" |]
+
+[<Fact>]
+let ``RegexOptions.ExplicitCapture works`` () =
+    let m = Regex.Match("ab", "(a)(?<n>b)", RegexOptions.ExplicitCapture)
+    m.Groups.Count |> equal 2
+    m.Groups["n"].Value |> equal "b"
+
+[<Fact>]
+let ``RegexOptions.IgnorePatternWhitespace works`` () =
+    let pattern = @"
+        (?<num> \d+ )   # digits
+        [ ]             # a space
+        (?<word> \w+ )  # a word"
+    let m = Regex.Match("12 apples", pattern, RegexOptions.IgnorePatternWhitespace)
+    m.Groups["num"].Value |> equal "12"
+    m.Groups["word"].Value |> equal "apples"
+
+[<Fact>]
+let ``.Net pattern syntax works`` () =
+    Regex.IsMatch("ABC", "(?i)abc") |> equal true
+    Regex.IsMatch("aBC", "a(?i:bc)") |> equal true
+    Regex.IsMatch("ABC", "a(?i:bc)") |> equal false
+    Regex.IsMatch("abc", @"\Aabc\z") |> equal true
+    Regex.IsMatch("abc", @"abc\Z") |> equal true
+    Regex.IsMatch("abc", "a(?#comment)bc") |> equal true
+    Regex.IsMatch("a{b", "a{b") |> equal true
+    Regex.Match("xab", "(?'n'a)b").Groups["n"].Value |> equal "a"
+    Regex.Match("ab", "(?n)(a)(b)").Groups.Count |> equal 1
+    Regex.IsMatch("b", "^[a-z-[aeiou]]$") |> equal true
+    Regex.IsMatch("e", "^[a-z-[aeiou]]$") |> equal false
@@ -64,6 +64,18 @@ let ``ResizeArray.Count works`` () =
     xs.Add(1.); xs.Add(2.); xs.Add(3.); xs.Add(4.); xs.Add(5.)
     xs.Count |> equal 5
 
+[<Fact>]
+let ``ResizeArray.Capacity works`` () =
+    let xs = ResizeArray<int>(10)
+    xs.Capacity >= 10 |> equal true
+    xs.EnsureCapacity(50) >= 50 |> equal true
+    xs.Add(1); xs.Add(2)
+    xs.TrimExcess()
+    xs.Capacity |> equal 2
+    xs.Capacity <- 8
+    xs.Capacity |> equal 8
+    xs.Count |> equal 2
+
 // [<Fact>]
 // let ``ResizeArray.ConvertAll works`` () =
 //     let xs = ResizeArray<_> [1.; 2.; 3.; 4.]
@@ -48,6 +48,48 @@ let ``Bind function can be generated`` () =
     let res: Result<int, int> = Ok 10 |> Result.bind Error
     res |> equal (Error 10)
 
+[<Fact>]
+let ``Result.isOk and Result.isError work`` () =
+    let ok: Result<int, string> = Ok 1
+    let error: Result<int, string> = Error "e"
+    Result.isOk ok |> equal true
+    Result.isError ok |> equal false
+    Result.isOk error |> equal false
+    Result.isError error |> equal true
+
+[<Fact>]
+let ``Result.defaultValue and Result.defaultWith work`` () =
+    let ok: Result<int, string> = Ok 1
+    let error: Result<int, string> = Error "abc"
+    ok |> Result.defaultValue 5 |> equal 1
+    error |> Result.defaultValue 5 |> equal 5
+    error |> Result.defaultWith (fun e -> e.Length) |> equal 3
+
+[<Fact>]
+let ``Result folding functions work`` () =
+    let ok: Result<int, string> = Ok 2
+    let error: Result<int, string> = Error "e"
+    ok |> Result.count |> equal 1
+    error |> Result.count |> equal 0
+    ok |> Result.exists (fun x -> x = 2) |> equal true
+    error |> Result.forall (fun x -> x = 3) |> equal true
+    ok |> Result.contains 2 |> equal true
+    ok |> Result.fold (+) 1 |> equal 3
+    Result.foldBack (+) ok 1 |> equal 3
+    let mutable res = 0
+    ok |> Result.iter (fun x -> res <- x)
+    res |> equal 2
+
+[<Fact>]
+let ``Result conversion functions work`` () =
+    let ok: Result<int, string> = Ok 2
+    let error: Result<int, string> = Error "e"
+    ok |> Result.toOption |> equal (Some 2)
+    error |> Result.toOption |> equal None
+    ok |> Result.toValueOption |> equal (ValueSome 2)
+    ok |> Result.toList |> equal [2]
+    error |> Result.toArray |> equal [||]
+
 [<Fact>]
 let ``Nesting Result in pattern matching works`` () =
     Ok 5 |> Foo |> foo |> equal true
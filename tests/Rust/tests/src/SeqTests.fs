@@ -179,6 +179,22 @@ let ``Seq.choose works`` () =
     sumFirstTwo zs
     |> equal 7.
 
+[<Fact>]
+let ``Seq.map, filter and choose pipelines work`` () =
+    let mutable calls = 0
+    let double x = calls <- calls + 1; x * 2
+    let ys =
+        seq {1..10}
+        |> Seq.map double
+        |> Seq.filter (fun x -> x % 3 <> 0)
+        |> Seq.choose (fun x -> if x > 4 then Some(string x) else None)
+        |> Seq.map (fun s -> s + "!")
+    calls |> equal 0
+    ys |> Seq.toList |> equal ["8!"; "10!"; "14!"; "16!"; "20!"]
+    calls |> equal 10
+    ys |> Seq.truncate 2 |> Seq.toList |> equal ["8!"; "10!"]
+    calls |> equal 15
+
 // [<Fact>]
 // let ``Seq.choose works with generic arguments`` () =
 //     let res = testSeqChoose [ Some [ 5 ] ]
@@ -920,6 +936,15 @@ let ``Seq.countBy works`` () =
     let ys = xs |> Seq.countBy (fun x -> x % 2)
     ys |> Seq.toArray |> equal [|(1, 2); (0, 2)|]
 
+[<Fact>]
+let ``Seq set-like operations work with large sequences`` () =
+    let xs = seq { for i in 1 .. 100_000 -> i % 1000 }
+    xs |> Seq.distinct |> Seq.length |> equal 1000
+    xs |> Seq.distinct |> Seq.take 3 |> Seq.toList |> equal [1; 2; 3]
+    xs |> Seq.countBy id |> Seq.head |> equal (1, 100)
+    xs |> Seq.except [1 .. 999] |> Seq.toList |> equal [0]
+    xs |> Seq.groupBy (fun x -> x % 10) |> Seq.map (snd >> Seq.length) |> Seq.distinct |> Seq.toList |> equal [10_000]
+
 [<Fact>]
 let ``Seq.distinct works`` () =
     let xs = [1; 1; 1; 2; 2; 3; 3]
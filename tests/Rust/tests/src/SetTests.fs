@@ -261,6 +261,22 @@ let ``Set.difference works`` () =
     let zs = Set.difference xs ys
     zs.Count |> equal 2
 
+[<Fact>]
+let ``Set operations on large sets work`` () =
+    let xs = Set.ofList [ for i in 1 .. 1000 -> i * 2 ]
+    let ys = Set.ofList [ for i in 1 .. 1000 -> i * 3 ]
+    let zs = Set.union xs ys
+    zs.Count |> equal 1667
+    zs |> Set.toList |> List.truncate 5 |> equal [2; 3; 4; 6; 8]
+    let zs = Set.intersect xs ys
+    zs.Count |> equal 333
+    zs |> Set.minElement |> equal 6
+    zs |> Set.maxElement |> equal 1998
+    let zs = Set.difference xs ys
+    zs.Count |> equal 667
+    Set.contains 6 zs |> equal false
+    Set.contains 4 zs |> equal true
+
 [<Fact>]
 let ``Set (-) works`` () =
     let xs = set [1.; 2.; 3.; 4.]
@@ -0,0 +1,57 @@
+[<Fable.Core.Rust.OuterAttr("cfg", [|"not(feature = \"no_std\")"|])>]
+module Fable.Tests.StopwatchTests
+
+open Util.Testing
+open System
+open System.Diagnostics
+open System.Threading
+
+[<Fact>]
+let ``Stopwatch.Frequency works`` () =
+    Stopwatch.Frequency > 0L |> equal true
+
+[<Fact>]
+let ``Stopwatch.GetTimestamp is monotonic`` () =
+    let t1 = Stopwatch.GetTimestamp()
+    Thread.Sleep(10)
+    let t2 = Stopwatch.GetTimestamp()
+    let elapsed = float (t2 - t1) / float Stopwatch.Frequency
+    elapsed >= 0.01 |> equal true
+
+[<Fact>]
+let ``Stopwatch.StartNew and Stop work`` () =
+    let sw = Stopwatch.StartNew()
+    sw.IsRunning |> equal true
+    Thread.Sleep(20)
+    sw.Stop()
+    sw.IsRunning |> equal false
+    let ms = sw.ElapsedMilliseconds
+    ms >= 20L |> equal true
+    sw.Elapsed >= TimeSpan.FromMilliseconds(20.) |> equal true
+    Thread.Sleep(10)
+    sw.ElapsedMilliseconds |> equal ms
+
+[<Fact>]
+let ``Stopwatch.Start resumes measuring`` () =
+    let sw = Stopwatch()
+    sw.IsRunning |> equal false
+    sw.ElapsedTicks |> equal 0L
+    sw.Start()
+    Thread.Sleep(10)
+    sw.Stop()
+    let ticks = sw.ElapsedTicks
+    sw.Start()
+    Thread.Sleep(10)
+    sw.Stop()
+    sw.ElapsedTicks > ticks |> equal true
+
+[<Fact>]
+let ``Stopwatch.Reset and Restart work`` () =
+    let sw = Stopwatch.StartNew()
+    Thread.Sleep(20)
+    sw.Restart()
+    sw.IsRunning |> equal true
+    sw.ElapsedMilliseconds < 20L |> equal true
+    sw.Reset()
+    sw.IsRunning |> equal false
+    sw.Elapsed |> equal TimeSpan.Zero
@@ -0,0 +1,85 @@
+[<Fable.Core.Rust.OuterAttr("cfg", [|"not(feature = \"no_std\")"|])>]
+module Fable.Tests.StreamTests
+
+open Util.Testing
+open System
+open System.IO
+
+let readAll (stream: Stream) =
+    let buffer = Array.zeroCreate<byte> (int stream.Length)
+    stream.Position <- 0L
+    stream.Read(buffer, 0, buffer.Length) |> ignore
+    buffer
+
+[<Fact>]
+let ``MemoryStream write and read works`` () =
+    use ms = new MemoryStream()
+    ms.Write([| 1uy; 2uy; 3uy |], 0, 3)
+    ms.WriteByte(4uy)
+    ms.Length |> equal 4L
+    ms.Position |> equal 4L
+    ms.Position <- 1L
+    ms.ReadByte() |> equal 2
+    ms.Seek(-1L, SeekOrigin.End) |> equal 3L
+    ms.ReadByte() |> equal 4
+    ms.ReadByte() |> equal -1
+    ms.ToArray() |> equal [| 1uy; 2uy; 3uy; 4uy |]
+
+[<Fact>]
+let ``MemoryStream from buffer works`` () =
+    let ms = new MemoryStream([| 5uy; 6uy; 7uy |])
+    ms.CanRead |> equal true
+    ms.CanSeek |> equal true
+    readAll ms |> equal [| 5uy; 6uy; 7uy |]
+    ms.SetLength(2L)
+    ms.ToArray() |> equal [| 5uy; 6uy |]
+
+[<Fact>]
+let ``MemoryStream writes through to its buffer`` () =
+    let buffer = [| 1uy; 2uy; 3uy |]
+    let ms = new MemoryStream(buffer)
+    ms.Position <- 1L
+    ms.WriteByte(9uy)
+    buffer |> equal [| 1uy; 9uy; 3uy |]
+    ms.Length |> equal 3L
+    ms.SetLength(1L)
+    ms.SetLength(3L)
+    buffer |> equal [| 1uy; 0uy; 0uy |]
+    ms.Position <- 3L
+    throwsAnyError (fun () -> ms.WriteByte(4uy))
+    throwsAnyError (fun () -> ms.SetLength(4L))
+
+[<Fact>]
+let ``Stream.CopyTo works`` () =
+    let source = new MemoryStream([| 1uy; 2uy |])
+    let dest = new MemoryStream()
+    source.CopyTo(dest)
+    dest.ToArray() |> equal [| 1uy; 2uy |]
+
+[<Fact>]
+let ``Stream.CopyTo the same stream works`` () =
+    let ms = new MemoryStream()
+    ms.Write([| 1uy; 2uy; 3uy |], 0, 3)
+    ms.Position <- 1L
+    ms.CopyTo(ms)
+    ms.ToArray() |> equal [| 1uy; 2uy; 3uy; 2uy; 3uy |]
+
+[<Fact>]
+let ``Disposed streams can't be read`` () =
+    let ms = new MemoryStream([| 1uy |])
+    ms.Dispose()
+    ms.CanRead |> equal false
+    throwsAnyError (fun () -> ms.ReadByte())
+
+[<Fact>]
+let ``File times of a missing file are the FileTime epoch`` () =
+    let path = "missing-file-for-fable-tests.txt"
+    File.Exists(path) |> equal false
+    File.GetLastWriteTimeUtc(path) |> equal (DateTime.FromFileTimeUtc(0L))
+    File.GetCreationTimeUtc(path) |> equal (DateTime(1601, 1, 1, 0, 0, 0, DateTimeKind.Utc))
+    let fi = FileInfo(path)
+    fi.Exists |> equal false
+    fi.Name |> equal path
+    fi.LastWriteTimeUtc.Year |> equal 1601
+    throwsAnyError (fun () -> fi.Length |> ignore)
+    throwsAnyError (fun () -> File.SetLastWriteTimeUtc(path, DateTime.UtcNow))
@@ -22,7 +22,9 @@ let formatPrefix = "Person at coordinates"
 [<Literal>]
 let fullFormat = formatPrefix + formatCoordinateBody
 
-// type MyUnion = Bar of int * int | Foo1 of float | Foo3 | Foo4 of MyUnion
+type MyUnion = Bar of int * int | Foo1 of float | Foo3 | Foo4 of MyUnion
+
+type MyRecord = { A: int; B: string; C: (int * float) option }
 
 // type Test(i: int) =
 //     override __.ToString() = string(i + i)
@@ -113,6 +115,65 @@ let ``StringBuilder.Append works with various overloads`` () =
     let actual = sb.ToString().Replace(",", ".").ToLower()
     actual |> equal "aaabcd/true5.234"
 
+[<Fact>]
+let ``StringBuilder.Append works with repeat count and char ranges`` () =
+    let sb = Text.StringBuilder()
+    sb.Append('-', 3).Append("abcdef".ToCharArray(), 2, 3) |> ignore
+    sb.ToString() |> equal "---cde"
+
+[<Fact>]
+let ``StringBuilder.AppendJoin works`` () =
+    let sb = Text.StringBuilder("[")
+    sb.AppendJoin(", ", [| "a"; "b"; "c" |]).AppendJoin('|', [| "d"; "e" |]) |> ignore
+    sb.ToString() |> equal "[a, b, cd|e"
+
+[<Fact>]
+let ``StringBuilder.Insert works`` () =
+    let sb = Text.StringBuilder("Hello World")
+    sb.Insert(5, ",").Insert(0, '>').Insert(sb.Length, 42).Insert(1, "ab", 2) |> ignore
+    sb.ToString() |> equal ">ababHello, World42"
+
+[<Fact>]
+let ``StringBuilder.Remove works`` () =
+    let sb = Text.StringBuilder("Hello, World")
+    sb.Remove(5, 7).Remove(0, 1) |> ignore
+    sb.ToString() |> equal "ello"
+
+[<Fact>]
+let ``StringBuilder.Replace works`` () =
+    let sb = Text.StringBuilder("one two one two")
+    sb.Replace("one", "1").Replace('t', 'T') |> ignore
+    sb.ToString() |> equal "1 Two 1 Two"
+    sb.Replace("Two", "2", 0, 5).Replace('1', '!', 4, 5) |> ignore
+    sb.ToString() |> equal "1 2 ! Two"
+
+[<Fact>]
+let ``StringBuilder.Chars works`` () =
+    let sb = Text.StringBuilder("abc")
+    sb.Chars(1) |> equal 'b'
+    sb.Chars(1) <- 'X'
+    sb.[2] <- 'Y'
+    sb.ToString() |> equal "aXY"
+
+[<Fact>]
+let ``StringBuilder.Length can be set`` () =
+    let sb = Text.StringBuilder("Hello World")
+    sb.Length <- 5
+    sb.ToString() |> equal "Hello"
+    sb.Length <- 7
+    sb.ToString() |> equal "Hello\000\000"
+    sb.Append("!").Length |> equal 8
+
+[<Fact>]
+let ``StringBuilder.Capacity works`` () =
+    let sb = Text.StringBuilder(32)
+    sb.Capacity >= 32 |> equal true
+    sb.EnsureCapacity(100) >= 100 |> equal true
+    sb.Append("abc") |> ignore
+    sb.Capacity <- 3
+    sb.Capacity |> equal 3
+    sb.ToString() |> equal "abc"
+
 [<Fact>]
 let ``StringBuilder.AppendFormat works`` () =
     let sb = Text.StringBuilder()
@@ -125,6 +186,26 @@ let ``StringBuilder.AppendFormat with provider works`` () =
     sb.AppendFormat(CultureInfo.InvariantCulture, "Hello{0}World{1}", " ", "!") |> ignore
     sb.ToString() |> equal "Hello World!"
 
+[<Fact>]
+let ``CompositeFormat works`` () =
+    let cf = Text.CompositeFormat.Parse("{0} + {1,3} = {2,-3}|")
+    cf.MinimumArgumentCount |> equal 3
+    cf.Format |> equal "{0} + {1,3} = {2,-3}|"
+    String.Format(CultureInfo.InvariantCulture, cf, 1, 2, 3) |> equal "1 +   2 = 3  |"
+    String.Format(null, cf, "a", "b", "c") |> equal "a +   b = c  |"
+
+[<Fact>]
+let ``CompositeFormat applies format specifiers`` () =
+    let cf = Text.CompositeFormat.Parse("{0:N2} [{1,5:F1}] {2:D3}")
+    String.Format(CultureInfo.InvariantCulture, cf, 1234.5, 3.14159, 7) |> equal "1,234.50 [  3.1] 007"
+
+[<Fact>]
+let ``StringBuilder.AppendFormat with CompositeFormat works`` () =
+    let cf = Text.CompositeFormat.Parse("Hello{0}World{1}")
+    let sb = Text.StringBuilder()
+    sb.AppendFormat(CultureInfo.InvariantCulture, cf, " ", "!") |> ignore
+    sb.ToString() |> equal "Hello World!"
+
 [<Fact>]
 let ``kprintf works`` () =
     let f (s:string) = s + "XX"
@@ -245,13 +326,30 @@ let ``sprintf with percent symbols in arguments works`` () = // See #329
 let ``Fix #2398: Exception when two successive string format placeholders and value of first one ends in '%'`` () =
     sprintf "%c%s" '%' "text" |> equal "%text"
 
-// [<Fact>]
-// let ``Unions with sprintf %A`` () =
-//     Bar(1,5) |> sprintf "%A" |> equal "Bar (1, 5)"
-//     Foo1 4.5 |> sprintf "%A" |> equal "Foo1 4.5"
-//     Foo4 Foo3 |> sprintf "%A" |> equal "Foo4 Foo3"
-//     Foo4(Foo1 4.5) |> sprintf "%A" |> equal "Foo4 (Foo1 4.5)"
-//     Foo3 |> sprintf "%A" |> equal "Foo3"
+[<Fact>]
+let ``Unions with sprintf %A`` () =
+    Bar(1,5) |> sprintf "%A" |> equal "Bar (1, 5)"
+    Foo1 4.5 |> sprintf "%A" |> equal "Foo1 4.5"
+    Foo4 Foo3 |> sprintf "%A" |> equal "Foo4 Foo3"
+    Foo4(Foo1 4.5) |> sprintf "%A" |> equal "Foo4 (Foo1 4.5)"
+    Foo3 |> sprintf "%A" |> equal "Foo3"
+
+[<Fact>]
+let ``Records with sprintf %A`` () =
+    { A = 1; B = "x"; C = Some(2, 1.5) } |> sprintf "%A" |> equal "{ A = 1\n  B = \"x\"\n  C = Some (2, 1.5) }"
+    { A = 1; B = "x"; C = None } |> sprintf "%A" |> equal "{ A = 1\n  B = \"x\"\n  C = None }"
+
+[<Fact>]
+let ``sprintf \"%A\" with options, tuples and arrays works`` () =
+    sprintf "%A" (Some 1) |> equal "Some 1"
+    sprintf "%A" (Some (Some 1L)) |> equal "Some (Some 1L)"
+    sprintf "%A" (1, "a", 'c') |> equal "(1, \"a\", 'c')"
+    sprintf "%A" [| 1; 2 |] |> equal "[|1; 2|]"
+    sprintf "%A" 1.0 |> equal "1.0"
+    sprintf "%A" (Ok 3: Result<int, string>) |> equal "Ok 3"
+    sprintf "%A" (Map [ 1, "a" ]) |> equal "map [(1, \"a\")]"
+    sprintf "%A" (set [ 2; 1 ]) |> equal "set [1; 2]"
+    sprintf "[%5A]" 1 |> equal "[    1]"
 
 // [<Fact>]
 // let ``Unions with string operator`` () =
@@ -387,19 +485,35 @@ let ``interpolated string with double braces should be unescaped`` () =
 let ``interpolated string with format and double braces should be unescaped`` () =
     $"{{ %.2f{100.4566666} }}" |> equal "{ 100.46 }"
 
+[<Fact>]
+let ``interpolated string with alignment works`` () =
+    let name = "ab"
+    $"[{name,5}]" |> equal "[   ab]"
+    $"[{name,-5}]" |> equal "[ab   ]"
+    $"[{42,4}|{42,-4}]" |> equal "[  42|42  ]"
+    $"[{name,1}]" |> equal "[ab]"
+
+[<Fact>]
+let ``interpolated string with format specifiers works`` () =
+    let x = 3.14159
+    $"{x:F2}" |> equal "3.14"
+    $"[{x,8:F2}]" |> equal "[    3.14]"
+    $"[{x,-8:F3}]" |> equal "[3.142   ]"
+    $"{255:X4} {42:D5} {-7:D3}" |> equal "00FF 00042 -007"
+
 [<Fact>]
 let ``sprintf with double % should be unescaped`` () =
     sprintf "%d%%" 100 |> equal "100%"
 
-// [<Fact>]
-// let ``sprintf \"%A\" with lists works`` () =
-//     let xs = ["Hi"; "Hello"; "Hola"]
-//     (sprintf "%A" xs).Replace("\"", "") |> equal "[Hi; Hello; Hola]"
+[<Fact>]
+let ``sprintf \"%A\" with lists works`` () =
+    let xs = ["Hi"; "Hello"; "Hola"]
+    (sprintf "%A" xs).Replace("\"", "") |> equal "[Hi; Hello; Hola]"
 
-// [<Fact>]
-// let ``sprintf \"%A\" with nested lists works`` () =
-//     let xs = [["Hi"]; ["Hello"]; ["Hola"]]
-//     (sprintf "%A" xs).Replace("\"", "") |> equal "[[Hi]; [Hello]; [Hola]]"
+[<Fact>]
+let ``sprintf \"%A\" with nested lists works`` () =
+    let xs = [["Hi"]; ["Hello"]; ["Hola"]]
+    (sprintf "%A" xs).Replace("\"", "") |> equal "[[Hi]; [Hello]; [Hola]]"
 
 // [<Fact>]
 // let ``sprintf \"%A\" with sequences works`` () =
@@ -437,7 +551,9 @@ let ``sprintf integers with sign and padding works`` () = // See #1931
     sprintf "%+04i" -1 |> equal "-001"
     sprintf "%5d" -5 |> equal "   -5"
     sprintf "%5d" -5L |> equal "   -5"
-    // sprintf "%- 4i" 5 |> equal " 5  " //TODO:
+    sprintf "%- 4i" 5 |> equal " 5  "
+    sprintf "% d" 5 |> equal " 5"
+    sprintf "% d" -5 |> equal "-5"
 
 [<Fact>]
 let ``test format string can use and compose string literals`` =
@@ -447,16 +563,42 @@ let ``test format string can use and compose string literals`` =
     equal "(0.250000,0.750000)" renderedCoordinates
     equal "Person at coordinates(0.250000,0.750000)" renderedText
 
-// [<Fact>]
-// let ``parameterized padding works`` () = // See #2336
-//     sprintf "[%*s][%*s]" 6 "Hello" 5 "Foo"
-//     |> equal "[ Hello][  Foo]"
+[<Fact>]
+let ``parameterized padding works`` () = // See #2336
+    sprintf "[%*s][%*s]" 6 "Hello" 5 "Foo"
+    |> equal "[ Hello][  Foo]"
 
-// [<Fact>]
-// let ``String.Format combining padding and zeroes pattern works`` () =
-//     String.Format(CultureInfo.InvariantCulture, "{0:++0.00++}", -5000.5657) |> equal "-++5000.57++"
-//     String.Format(CultureInfo.InvariantCulture, "{0:000.00}foo", 5) |> equal "005.00foo"
-//     String.Format(CultureInfo.InvariantCulture, "{0,-8:000.00}foo", 12.456) |> equal "012.46  foo"
+[<Fact>]
+let ``parameterized padding works with numbers`` () =
+    sprintf "%*d|" 5 42 |> equal "   42|"
+    sprintf "%-*d|" 5 42 |> equal "42   |"
+    sprintf "%0*d" 5 -42 |> equal "-0042"
+    sprintf "%*.2f" 8 3.14159 |> equal "    3.14"
+
+[<Fact>]
+let ``sprintf exponent and general formats work`` () =
+    sprintf "%e" 1.5 |> equal "1.500000e+000"
+    sprintf "%E" 12345.678 |> equal "1.234568E+004"
+    sprintf "%.2e" -0.000123 |> equal "-1.23e-004"
+    sprintf "%+.2e" 1234.5 |> equal "+1.23e+003"
+    sprintf "%g" 0.0001 |> equal "0.0001"
+    sprintf "%g" 1e10 |> equal "1e+10"
+    sprintf "%.3g" 3.14159 |> equal "3.14"
+    sprintf "%G" 1.5e-7 |> equal "1.5E-07"
+
+[<Fact>]
+let ``sprintf octal char and width formats work`` () =
+    sprintf "%o" 8 |> equal "10"
+    sprintf "%c%c" 'a' 'b' |> equal "ab"
+    sprintf "%10.3f" 3.14159 |> equal "     3.142"
+    sprintf "%-8.3f|" 3.14159 |> equal "3.142   |"
+    sprintf "%08.3f" -3.14159 |> equal "-003.142"
+
+[<Fact>]
+let ``String.Format combining padding and zeroes pattern works`` () =
+    String.Format(CultureInfo.InvariantCulture, "{0:++0.00++}", -5000.5657) |> equal "-++5000.57++"
+    String.Format(CultureInfo.InvariantCulture, "{0:000.00}foo", 5) |> equal "005.00foo"
+    String.Format(CultureInfo.InvariantCulture, "{0,-8:000.00}foo", 12.456) |> equal "012.46  foo"
 
 [<Fact>]
 let ``String.Format {0:x} works`` () =
@@ -473,46 +615,71 @@ let ``String.Format {0:x} works`` () =
     String.Format(CultureInfo.InvariantCulture, "1L <<< 63: {0:x}", (1L <<< 63)) |> equal "1L <<< 63: 8000000000000000"
     String.Format(CultureInfo.InvariantCulture, "1uL <<< 63: {0:x}", (1uL <<< 63)) |> equal "1uL <<< 63: 8000000000000000"
 
-// [<Fact>]
-// let ``String.Format {0:x} with precision works`` () =
-//     String.Format(CultureInfo.InvariantCulture, "#{0:X3}", 0xC149D) |> equal "#C149D"
-//     String.Format(CultureInfo.InvariantCulture, "#{0:X6}", 0xC149D) |> equal "#0C149D"
+[<Fact>]
+let ``String.Format {0:x} with precision works`` () =
+    String.Format(CultureInfo.InvariantCulture, "#{0:X3}", 0xC149D) |> equal "#C149D"
+    String.Format(CultureInfo.InvariantCulture, "#{0:X6}", 0xC149D) |> equal "#0C149D"
 
-// [<Fact>]
-// let ``String.Format works with thousands separator`` () =
-//     String.Format(CultureInfo.InvariantCulture, "{0}", 12343235354.6547757) |> equal "12343235354.654776"
-//     String.Format(CultureInfo.InvariantCulture, "{0:#,##.000}", 12343235354.6547757) |> equal "12,343,235,354.655"
-//     String.Format(CultureInfo.InvariantCulture, "{0:#,##.000}", 12343235354.6547757M) |> equal "12,343,235,354.655"
-//     String.Format(CultureInfo.InvariantCulture, "{0:#,##.00}", 123.456) |> equal "123.46"
-//     String.Format(CultureInfo.InvariantCulture, "{0:#,##.00}", 123.456M) |> equal "123.46"
-//     String.Format(CultureInfo.InvariantCulture, "{0:#,##.00}", 123438192123.456M) |> equal "123,438,192,123.46"
-//     String.Format(CultureInfo.InvariantCulture, "{0:#,##}", 1.456M) |> equal "1"
-//     String.Format(CultureInfo.InvariantCulture, "{0:0,0}", 1.456M) |> equal "01"
+[<Fact>]
+let ``String.Format with alignment works`` () =
+    String.Format("[{0,5}]", "ab") |> equal "[   ab]"
+    String.Format("[{0,-5}]", "ab") |> equal "[ab   ]"
+    String.Format("[{0,2}]", "abc") |> equal "[abc]"
+    String.Format("[{0,6:X4}|{1,-9:N1}]", 255, 1234.56) |> equal "[  00FF|1,234.6  ]"
 
-// [<Fact>]
-// let ``String.Format can omit decimal digits`` () =
-//     String.Format(CultureInfo.InvariantCulture, "{0:#,##}", 12343235354.6547757) |> equal "12,343,235,355"
-//     String.Format(CultureInfo.InvariantCulture, "{0:0,00}", 12343235354.6547757) |> equal "12,343,235,355"
-//     String.Format(CultureInfo.InvariantCulture, "{0:0,00}", 12343235354.) |> equal "12,343,235,354"
-//     String.Format(CultureInfo.InvariantCulture, "{0:#}", 12343235354.) |> equal "12343235354"
-//     String.Format(CultureInfo.InvariantCulture, "{0:0}", 12343235354.) |> equal "12343235354"
+[<Fact>]
+let ``String.Format with format specifiers works`` () =
+    String.Format(CultureInfo.InvariantCulture, "{0:N2}", 1234567.891) |> equal "1,234,567.89"
+    String.Format(CultureInfo.InvariantCulture, "{0:N0}", -1234) |> equal "-1,234"
+    String.Format(CultureInfo.InvariantCulture, "{0:X8}", 255) |> equal "000000FF"
+    String.Format(CultureInfo.InvariantCulture, "{0:D5}", 42) |> equal "00042"
+    String.Format(CultureInfo.InvariantCulture, "{0:F3}", 3.14159) |> equal "3.142"
+    String.Format(CultureInfo.InvariantCulture, "{0:E2}", 12345.6789) |> equal "1.23E+004"
+    String.Format("{0:yyyy-MM-dd}", DateTime(2024, 2, 29)) |> equal "2024-02-29"
+    String.Format("{0:X}", "not formattable") |> equal "not formattable"
 
-//     String.Format(CultureInfo.InvariantCulture, "{0:#,#}", 12343235354.6547757M) |> equal "12,343,235,355"
-//     String.Format(CultureInfo.InvariantCulture, "{0:0,0}", 12343235354.6547757M) |> equal "12,343,235,355"
+[<Fact>]
+let ``String.Format with escaped braces and reordered arguments works`` () =
+    String.Format("{{{0}}}", 42) |> equal "{42}"
+    String.Format("{1} {0} {1}", "a", "b") |> equal "b a b"
+    String.Format("{0} = {0:X2}", 10) |> equal "10 = 0A"
+    String.Format("{2}{1}{0}", 1, 2, 3, 4) |> equal "321"
+
+[<Fact>]
+let ``String.Format works with thousands separator`` () =
+    String.Format(CultureInfo.InvariantCulture, "{0}", 12343235354.6547757) |> equal "12343235354.654776"
+    String.Format(CultureInfo.InvariantCulture, "{0:#,##.000}", 12343235354.6547757) |> equal "12,343,235,354.655"
+    String.Format(CultureInfo.InvariantCulture, "{0:#,##.000}", 12343235354.6547757M) |> equal "12,343,235,354.655"
+    String.Format(CultureInfo.InvariantCulture, "{0:#,##.00}", 123.456) |> equal "123.46"
+    String.Format(CultureInfo.InvariantCulture, "{0:#,##.00}", 123.456M) |> equal "123.46"
+    String.Format(CultureInfo.InvariantCulture, "{0:#,##.00}", 123438192123.456M) |> equal "123,438,192,123.46"
+    String.Format(CultureInfo.InvariantCulture, "{0:#,##}", 1.456M) |> equal "1"
+    String.Format(CultureInfo.InvariantCulture, "{0:0,0}", 1.456M) |> equal "01"
 
-//     String.Format(CultureInfo.InvariantCulture, "{0:0,0}", 1234323535) |> equal "1,234,323,535"
-//     String.Format(CultureInfo.InvariantCulture, "{0:#}", 1234323535) |> equal "1234323535"
-//     String.Format(CultureInfo.InvariantCulture, "{0:0}", 1234323535) |> equal "1234323535"
+[<Fact>]
+let ``String.Format can omit decimal digits`` () =
+    String.Format(CultureInfo.InvariantCulture, "{0:#,##}", 12343235354.6547757) |> equal "12,343,235,355"
+    String.Format(CultureInfo.InvariantCulture, "{0:0,00}", 12343235354.6547757) |> equal "12,343,235,355"
+    String.Format(CultureInfo.InvariantCulture, "{0:0,00}", 12343235354.) |> equal "12,343,235,354"
+    String.Format(CultureInfo.InvariantCulture, "{0:#}", 12343235354.) |> equal "12343235354"
+    String.Format(CultureInfo.InvariantCulture, "{0:0}", 12343235354.) |> equal "12343235354"
 
-//     String.Format(CultureInfo.InvariantCulture, "{0:0,0}", 12343235354M) |> equal "12,343,235,354"
-//     String.Format(CultureInfo.InvariantCulture, "{0:0,0}", 343235354M) |> equal "343,235,354"
-//     String.Format(CultureInfo.InvariantCulture, "{0:#}", 12343235354M) |> equal "12343235354"
-//     String.Format(CultureInfo.InvariantCulture, "{0:0}", 12343235354M) |> equal "12343235354"
+    String.Format(CultureInfo.InvariantCulture, "{0:#,#}", 12343235354.6547757M) |> equal "12,343,235,355"
+    String.Format(CultureInfo.InvariantCulture, "{0:0,0}", 12343235354.6547757M) |> equal "12,343,235,355"
 
-// [<Fact>]
-// let ``ToString formatted works with decimals`` () = // See #2276
-//     let decimal = 78.6M
-//     decimal.ToString("0.000").Replace(",", ".") |> equal "78.600"
+    String.Format(CultureInfo.InvariantCulture, "{0:0,0}", 1234323535) |> equal "1,234,323,535"
+    String.Format(CultureInfo.InvariantCulture, "{0:#}", 1234323535) |> equal "1234323535"
+    String.Format(CultureInfo.InvariantCulture, "{0:0}", 1234323535) |> equal "1234323535"
+
+    String.Format(CultureInfo.InvariantCulture, "{0:0,0}", 12343235354M) |> equal "12,343,235,354"
+    String.Format(CultureInfo.InvariantCulture, "{0:0,0}", 343235354M) |> equal "343,235,354"
+    String.Format(CultureInfo.InvariantCulture, "{0:#}", 12343235354M) |> equal "12343235354"
+    String.Format(CultureInfo.InvariantCulture, "{0:0}", 12343235354M) |> equal "12343235354"
+
+[<Fact>]
+let ``ToString formatted works with decimals`` () = // See #2276
+    let decimal = 78.6M
+    decimal.ToString("0.000").Replace(",", ".") |> equal "78.600"
 
 // [<Fact>]
 // let ``Printf works with generic argument`` () =
@@ -741,6 +908,15 @@ let ``String.Equals with comparison works`` () =
     "ABC".Equals("abc", StringComparison.Ordinal) |> equal false
     "ABC".Equals("abc", StringComparison.OrdinalIgnoreCase) |> equal true
 
+[<Fact>]
+let ``String case-insensitive comparisons work with non-ASCII chars`` () =
+    String.Equals("ÄÖÜ", "äöü", StringComparison.OrdinalIgnoreCase) |> equal true
+    String.Equals("straße", "STRASSE", StringComparison.OrdinalIgnoreCase) |> equal false
+    String.Compare("ÉCOLE", "école", StringComparison.OrdinalIgnoreCase) |> equal 0
+    "Ünïcode".StartsWith("üNÏ", StringComparison.OrdinalIgnoreCase) |> equal true
+    "Ünïcode".EndsWith("CODE", StringComparison.OrdinalIgnoreCase) |> equal true
+    "de".EndsWith("CODE", StringComparison.OrdinalIgnoreCase) |> equal false
+
 [<Fact>]
 let ``String.CompareOrdinal works`` () =
     String.CompareOrdinal("abc", "abc") = 0 |> equal true
@@ -756,21 +932,21 @@ let ``String.CompareOrdinal substring works`` () =
 
 [<Fact>]
 let ``String.CompareTo works`` () =
-    // "ABC".CompareTo("abc") > 0 |> equal true // TODO: culture
+    "ABC".CompareTo("abc") > 0 |> equal true
     "abc".CompareTo("abc") = 0 |> equal true
     "abc".CompareTo("abd") < 0 |> equal true
     "bbc".CompareTo("abd") > 0 |> equal true
 
 [<Fact>]
 let ``String.Compare works`` () =
-    // String.Compare("ABC", "abc") |> equal 1 // TODO: culture
+    String.Compare("ABC", "abc") |> equal 1
     String.Compare("abc", "abc") |> equal 0
     String.Compare("abc", "abd") |> equal -1
     String.Compare("bbc", "abd") |> equal 1
 
 [<Fact>]
 let ``String.Compare case-insensitive works`` () =
-    // String.Compare("ABC", "abc", false) |> equal 1 // TODO: culture
+    String.Compare("ABC", "abc", false) |> equal 1
     String.Compare("ABC", "abc", true) |> equal 0
     String.Compare("ABC", "abd", true) |> equal -1
     String.Compare("BBC", "abd", true) |> equal 1
@@ -780,18 +956,81 @@ let ``String.Compare substring works`` () =
     String.Compare("abc", 0, "bcd", 0, 3) |> equal -1
     String.Compare("abc", 1, "bcd", 0, 2) |> equal 0
 
+[<Fact>]
+let ``String.Compare with culture works`` () =
+    String.Compare("ñu", "nz", CultureInfo("es-ES"), CompareOptions.None) |> equal 1
+    String.Compare("ñu", "nz", CultureInfo("en-US"), CompareOptions.None) |> equal -1
+    String.Compare("résumé", "RESUME", CultureInfo.InvariantCulture, CompareOptions.IgnoreCase ||| CompareOptions.IgnoreNonSpace) |> equal 0
+    String.Compare("ABC", "abc", true, CultureInfo.InvariantCulture) |> equal 0
+    String.Compare("xABC", 1, "yabc", 1, 3, false, CultureInfo.InvariantCulture) |> equal 1
+    String.Compare("xñu", 1, "ynz", 1, 2, CultureInfo("es-ES"), CompareOptions.None) |> equal 1
+
+[<Fact>]
+let ``Sorting with String.Compare is culture-sensitive`` () =
+    [ "banana"; "Apple"; "cherry"; "apple"; "Zebra" ]
+    |> List.sortWith (fun a b -> String.Compare(a, b))
+    |> equal [ "apple"; "Apple"; "banana"; "cherry"; "Zebra" ]
+
 [<Fact>]
 let ``String.Compare with comparison works`` () =
-    // String.Compare("ABC", "abc", StringComparison.InvariantCulture) > 0 |> equal true
+    String.Compare("ABC", "abc", StringComparison.InvariantCulture) > 0 |> equal true
     String.Compare("ABC", "abc", StringComparison.Ordinal) < 0 |> equal true
     String.Compare("ABC", "abc", StringComparison.OrdinalIgnoreCase) |> equal 0
 
 [<Fact>]
 let ``String.Compare substring with comparison works`` () =
-    // String.Compare("ABC", 1, "bcd", 0, 2, StringComparison.InvariantCulture) > 0 |> equal true
+    String.Compare("ABC", 1, "bcd", 0, 2, StringComparison.InvariantCulture) > 0 |> equal true
     String.Compare("ABC", 1, "bcd", 0, 2, StringComparison.Ordinal) < 0 |> equal true
     String.Compare("ABC", 1, "bcd", 0, 2, StringComparison.OrdinalIgnoreCase) |> equal 0
 
+[<Fact>]
+let ``String.Compare with culture comparison works`` () =
+    String.Compare("a", "B", StringComparison.InvariantCulture) < 0 |> equal true
+    String.Compare("a", "B", StringComparison.Ordinal) > 0 |> equal true
+    String.Compare("ABC", "abc", StringComparison.InvariantCultureIgnoreCase) |> equal 0
+    String.Compare("résumé", "resume", StringComparison.InvariantCulture) > 0 |> equal true
+
+[<Fact>]
+let ``String methods with runtime comparison work`` () =
+    let compare (comparison: StringComparison) =
+        String.Equals("ÉCOLE", "école", comparison),
+        "Hello World".StartsWith("hello", comparison),
+        "Hello World".EndsWith("WORLD", comparison),
+        "Hello World".Contains("O W", comparison),
+        "Hello World".IndexOf("o", 5, comparison),
+        "Hello World".LastIndexOf("L", comparison)
+    compare StringComparison.Ordinal |> equal (false, false, false, false, 7, -1)
+    compare StringComparison.OrdinalIgnoreCase |> equal (true, true, true, true, 7, 9)
+    compare StringComparison.InvariantCultureIgnoreCase |> equal (true, true, true, true, 7, 9)
+    compare StringComparison.CurrentCulture |> equal (false, false, false, false, 7, -1)
+
+[<Fact>]
+let ``String.IndexOf with comparison works`` () =
+    "abcABCabc".IndexOf("B", StringComparison.OrdinalIgnoreCase) |> equal 1
+    "abcABCabc".IndexOf("B", 2, StringComparison.OrdinalIgnoreCase) |> equal 4
+    "abcABCabc".IndexOf("c", 3, 3, StringComparison.InvariantCultureIgnoreCase) |> equal 5
+    "abcABCabc".LastIndexOf("A", StringComparison.Ordinal) |> equal 3
+    "abcABCabc".LastIndexOf("b", 5, StringComparison.OrdinalIgnoreCase) |> equal 4
+    "abcABCabc".LastIndexOf("a", 5, 4, StringComparison.OrdinalIgnoreCase) |> equal 3
+
+[<Fact>]
+let ``String.Intern works`` () =
+    let s1 = String('x', 40) + string 1
+    let s2 = String('x', 40) + string 1
+    let i1 = String.Intern(s1)
+    let i2 = String.Intern(s2)
+    i1 |> equal s1
+    i2 |> equal s2
+    String.IsInterned(s2) |> equal i1
+    String.IsNullOrEmpty(String.IsInterned(String('y', 40))) |> equal true
+
+[<Fact>]
+let ``String.Intern keeps strings interned`` () =
+    let s = String.Intern(String('z', 40))
+    for i in 1 .. 10000 do
+        String.Intern("key" + string i) |> ignore
+    String.IsInterned(String('z', 40)) |> equal s
+
 [<Fact>]
 let ``String.IsNullOrEmpty works`` () =
     String.IsNullOrEmpty("") |> equal true
@@ -862,10 +1101,30 @@ let ``String.Split with single separator works`` () =
 let ``String.Split with multiple char args works`` () =
     "a;b,c".Split(',', ';') |> equal [|"a"; "b"; "c"|]
 
-// [<Fact>]
-// let ``String.Split with string array works`` () =
-//     "a;b,c".Split([|","; ";"|], StringSplitOptions.None)
-//     |> equal [|"a"; "b"; "c"|]
+[<Fact>]
+let ``String.Split with string array works`` () =
+    "a;b,c".Split([|","; ";"|], StringSplitOptions.None)
+    |> equal [|"a"; "b"; "c"|]
+    "a<>b<c>d".Split([|"<"; "<>"; ">"|], StringSplitOptions.None)
+    |> equal [|"a"; ""; "b"; "c"; "d"|]
+    "a<>b<c>d".Split([|"<>"; "<"; ">"|], StringSplitOptions.None)
+    |> equal [|"a"; "b"; "c"; "d"|]
+    "a b,c".Split([|""; ","|], StringSplitOptions.None)
+    |> equal [|"a b"; "c"|]
+    "a b,c".Split([|""|], StringSplitOptions.None)
+    |> equal [|"a b,c"|]
+    "a b,c".Split(([||]: string[]), StringSplitOptions.None)
+    |> equal [|"a"; "b,c"|]
+
+[<Fact>]
+let ``String.Split with leading and trailing separators works`` () =
+    ",a,,b,".Split(',') |> equal [|""; "a"; ""; "b"; ""|]
+    ",a,,b,".Split(',', StringSplitOptions.RemoveEmptyEntries) |> equal [|"a"; "b"|]
+    ",".Split(',') |> equal [|""; ""|]
+    "".Split(',') |> equal [|""|]
+    "".Split(',', StringSplitOptions.RemoveEmptyEntries) |> equal [||]
+    " , ".Split(',', StringSplitOptions.TrimEntries) |> equal [|""; ""|]
+    " , ".Split(',', StringSplitOptions.RemoveEmptyEntries ||| StringSplitOptions.TrimEntries) |> equal [||]
 
 [<Fact>]
 let ``String.Split with RemoveEmptyEntries works`` () =
@@ -910,6 +1169,13 @@ let ``String.Split with count works`` () =
     |> equal [|"a-b-c"|]
     "a-b-c".Split("", Int32.MaxValue)
     |> equal [|"a-b-c"|]
+    "a-b-c".Split('-', 0) |> equal [||]
+    ",a,b".Split(',', 1, StringSplitOptions.RemoveEmptyEntries)
+    |> equal [|",a,b"|]
+    " a b ".Split(' ', 1, StringSplitOptions.TrimEntries)
+    |> equal [|"a b"|]
+    "a b  c".Split(([||]: char[]), 2)
+    |> equal [|"a"; "b  c"|]
 
 [<Fact>]
 let ``String.Split with count and consecutive separators works`` () =
@@ -931,23 +1197,23 @@ let ``String.Split with count and TrimEntries works`` () =
     "a;,b,c;d".Split([|','; ';'|], 3, StringSplitOptions.TrimEntries)
     |> equal [|"a"; ""; "b,c;d"|]
 
-// [<Fact>]
-// let ``String.Split with count and RemoveEmptyEntries works`` () =
-//     " a-- b- c ".Split("-", 2, StringSplitOptions.RemoveEmptyEntries)
-//     |> equal [|" a"; " b- c "|]
-//     " a-- b- c ".Split('-', 3, StringSplitOptions.TrimEntries)
-//     |> equal [|"a"; ""; "b- c"|]
-//     "a;,b,c;d".Split([|','; ';'|], 3, StringSplitOptions.RemoveEmptyEntries)
-//     |> equal [|"a";"b";"c;d"|]
+[<Fact>]
+let ``String.Split with count and RemoveEmptyEntries works`` () =
+    " a-- b- c ".Split("-", 2, StringSplitOptions.RemoveEmptyEntries)
+    |> equal [|" a"; " b- c "|]
+    " a-- b- c ".Split('-', 3, StringSplitOptions.TrimEntries)
+    |> equal [|"a"; ""; "b- c"|]
+    "a;,b,c;d".Split([|','; ';'|], 3, StringSplitOptions.RemoveEmptyEntries)
+    |> equal [|"a";"b";"c;d"|]
 
-// [<Fact>]
-// let ``String.Split with count, RemoveEmptyEntries and TrimEntries works`` () =
-//     " a-- b- c ".Split([| "-" |], 2, StringSplitOptions.RemoveEmptyEntries ||| StringSplitOptions.TrimEntries)
-//     |> equal [|"a"; "b- c"|]
-//     " a-- b- c ".Split([| '-' |], 3, StringSplitOptions.RemoveEmptyEntries ||| StringSplitOptions.TrimEntries)
-//     |> equal  [|"a"; "b"; "c"|]
-//     "a;,b,c;d".Split([|','; ';'|], 3, StringSplitOptions.RemoveEmptyEntries ||| StringSplitOptions.TrimEntries)
-//     |> equal [|"a";"b";"c;d"|]
+[<Fact>]
+let ``String.Split with count, RemoveEmptyEntries and TrimEntries works`` () =
+    " a-- b- c ".Split([| "-" |], 2, StringSplitOptions.RemoveEmptyEntries ||| StringSplitOptions.TrimEntries)
+    |> equal [|"a"; "b- c"|]
+    " a-- b- c ".Split([| '-' |], 3, StringSplitOptions.RemoveEmptyEntries ||| StringSplitOptions.TrimEntries)
+    |> equal  [|"a"; "b"; "c"|]
+    "a;,b,c;d".Split([|','; ';'|], 3, StringSplitOptions.RemoveEmptyEntries ||| StringSplitOptions.TrimEntries)
+    |> equal [|"a";"b";"c;d"|]
 
 [<Fact>]
 let ``String.Replace works`` () =
@@ -1134,6 +1400,82 @@ let ``String.ToUpperInvariant works`` () =
 let ``String.ToLowerInvariant works`` () =
     "aBc".ToLowerInvariant() |> equal "abc"
 
+[<Fact>]
+let ``String.Normalize works`` () =
+    let composed = "caf\u00e9"
+    let decomposed = "cafe\u0301"
+    (composed = decomposed) |> equal false
+    decomposed.Normalize() |> equal composed
+    composed.Normalize(Text.NormalizationForm.FormD) |> equal decomposed
+    "\ufb01le".Normalize(Text.NormalizationForm.FormKC) |> equal "file"
+    "\u2460".Normalize(Text.NormalizationForm.FormKD) |> equal "1"
+    "abc".Normalize() |> equal "abc"
+
+[<Fact>]
+let ``String.IsNormalized works`` () =
+    "caf\u00e9".IsNormalized() |> equal true
+    "cafe\u0301".IsNormalized() |> equal false
+    "cafe\u0301".IsNormalized(Text.NormalizationForm.FormD) |> equal true
+    "\ufb01".IsNormalized(Text.NormalizationForm.FormC) |> equal true
+    "\ufb01".IsNormalized(Text.NormalizationForm.FormKC) |> equal false
+
+[<Fact>]
+let ``StringInfo.LengthInTextElements works`` () =
+    StringInfo("abc").LengthInTextElements |> equal 3
+    StringInfo("").LengthInTextElements |> equal 0
+    StringInfo("e\u0301le\u0300ve").LengthInTextElements |> equal 5
+    StringInfo("\r\n").LengthInTextElements |> equal 1
+    StringInfo("👍🏽👨‍👩‍👧").LengthInTextElements |> equal 2
+    StringInfo("🇫🇷🇩🇪").LengthInTextElements |> equal 2
+
+[<Fact>]
+let ``StringInfo.SubstringByTextElements works`` () =
+    let si = StringInfo("ae\u0301👍🏽z")
+    si.String |> equal "ae\u0301👍🏽z"
+    si.SubstringByTextElements(1) |> equal "e\u0301👍🏽z"
+    si.SubstringByTextElements(1, 2) |> equal "e\u0301👍🏽"
+    si.SubstringByTextElements(3, 1) |> equal "z"
+    throwsAnyError (fun () -> si.SubstringByTextElements(4))
+    throwsAnyError (fun () -> si.SubstringByTextElements(1, 4))
+
+[<Fact>]
+let ``StringInfo.GetNextTextElement works`` () =
+    StringInfo.GetNextTextElement("e\u0301x") |> equal "e\u0301"
+    StringInfo.GetNextTextElement("abc", 1) |> equal "b"
+    StringInfo.GetNextTextElement("abc", 3) |> equal ""
+    StringInfo.GetNextTextElementLength("e\u0301x") |> equal 2
+    throwsAnyError (fun () -> StringInfo.GetNextTextElement("abc", 4))
+
+[<Fact>]
+let ``StringInfo.ParseCombiningCharacters works`` () =
+    StringInfo.ParseCombiningCharacters("ae\u0301b\u0308\u0323c")
+    |> equal [| 0; 1; 3; 6 |]
+
+[<Fact>]
+let ``StringInfo.GetTextElementEnumerator works`` () =
+    let e = StringInfo.GetTextElementEnumerator("ae\u0301b")
+    let elements = ResizeArray()
+    while e.MoveNext() do
+        elements.Add((e.ElementIndex, e.GetTextElement()))
+    elements |> Seq.toList |> equal [ (0, "a"); (1, "e\u0301"); (3, "b") ]
+    e.MoveNext() |> equal false
+    e.Reset()
+    e.MoveNext() |> equal true
+    e.Current |> unbox<string> |> equal "a"
+    let e = StringInfo.GetTextElementEnumerator("ae\u0301b", 1)
+    e.MoveNext() |> equal true
+    e.GetTextElement() |> equal "e\u0301"
+    e.ElementIndex |> equal 1
+
+[<Fact>]
+let ``String case mappings are one to one`` () =
+    "straße".ToUpperInvariant() |> equal "STRAßE"
+    "ΣΑΣ".ToLowerInvariant() |> equal "σασ"
+    "ÀÉÎ".ToLower(CultureInfo.InvariantCulture) |> equal "àéî"
+    "àéî".ToUpper(CultureInfo.InvariantCulture) |> equal "ÀÉÎ"
+    Char.ToUpperInvariant('ß') |> equal 'ß'
+    Char.ToUpper('é', CultureInfo.InvariantCulture) |> equal 'É'
+
 [<Fact>]
 let ``String.Length works`` () =
     "AbC".Length |> equal 3
@@ -1267,6 +1609,12 @@ let ``String.concat with long seq works`` () =
     let s = String.concat "" a
     s.Length |> equal n
 
+[<Fact>]
+let ``String.concat with separator works`` () =
+    String.concat ", " [|"á"; ""; "ü"|] |> equal "á, , ü"
+    String.concat ", " (seq { yield "á"; yield ""; yield "ü" }) |> equal "á, , ü"
+    String.concat ", " Seq.empty |> equal ""
+
 [<Fact>]
 let ``String.Remove works`` () =
     "abcd".Remove(2) |> equal "ab"
@@ -1446,6 +1794,42 @@ let ``String.filter with Char.IsDigit as a predicate doesn't hang`` () =
 // #endif
 //     ()
 
+[<Fact>]
+let ``FormattableString captures the holes`` () =
+    let orderAmount = 100
+    let s: FormattableString = $"You owe: {orderAmount} {3} {5 = 5}"
+    s.Format |> equal "You owe: {0} {1} {2}"
+    s.ArgumentCount |> equal 3
+    s.GetArgument(0) |> unbox<int> |> equal 100
+    s.GetArgument(2) |> unbox<bool> |> equal true
+    s.GetArguments().Length |> equal 3
+    s.ToString() |> equal "You owe: 100 3 True"
+    let s2: FormattableString = $"""{5 + 2}This is "{"really"}" awesome!"""
+    s2.Format |> equal "{0}This is \"{1}\" awesome!"
+    s2.GetArgument(1) |> unbox<string> |> equal "really"
+    let s3: FormattableString = $"""I have no holes"""
+    s3.ArgumentCount |> equal 0
+    s3.ToString() |> equal "I have no holes"
+
+[<Fact>]
+let ``FormattableString.Invariant works`` () =
+    let x = 42
+    FormattableString.Invariant($"x = {x}") |> equal "x = 42"
+
+[<Fact>]
+let ``FormattableString applies alignment and format specifiers`` () =
+    let price = 3.14159
+    let s: FormattableString = $"[{price,8:F2}] [{42,-4}] [{price:N1}]"
+    s.Format |> equal "[{0,8:F2}] [{1,-4}] [{2:N1}]"
+    s.ToString() |> equal "[    3.14] [42  ] [3.1]"
+
+[<Fact>]
+let ``String.Create works`` () =
+    let s = String.Create(4, 'a', fun chars c ->
+        for i in 0 .. chars.Length - 1 do
+            chars[i] <- char (int c + i))
+    s |> equal "abcd"
+
 // #if FABLE_COMPILER
 // [<Fact>]
 // let ``Can use FormattableString.GetStrings() extension`` () =
@@ -134,6 +134,26 @@ let ``Add with wrapped days works`` () =
     equal t newT
     equal 3 wrappedDays
 
+[<Fact>]
+let ``AddHours and AddMinutes with wrapped days work`` () =
+    let t = TimeOnly (6, 0, 0)
+
+    let newT, wrappedDays = t.AddHours 18.
+    equal TimeOnly.MinValue newT
+    equal 1 wrappedDays
+
+    let newT, wrappedDays = t.AddHours -31.
+    equal (TimeOnly (23, 0)) newT
+    equal -2 wrappedDays
+
+    let newT, wrappedDays = t.AddMinutes 59.
+    equal (TimeOnly (6, 59)) newT
+    equal 0 wrappedDays
+
+    let newT, wrappedDays = t.AddMinutes -361.
+    equal (TimeOnly (23, 59)) newT
+    equal -1 wrappedDays
+
 [<Fact>]
 let ``Subtract works`` () =
     let left = TimeOnly (6, 0)
@@ -174,11 +194,11 @@ let ``ToString works`` () =
     t1.ToString("R", CultureInfo.InvariantCulture) |> equal "05:00:02"
     t2.ToString("R", CultureInfo.InvariantCulture) |> equal "14:02:00"
 
-    // t1.ToString("o", CultureInfo.InvariantCulture) |> equal "05:00:02.0220000"
-    // t2.ToString("o", CultureInfo.InvariantCulture) |> equal "14:02:00.0000000"
+    t1.ToString("o", CultureInfo.InvariantCulture) |> equal "05:00:02.0220000"
+    t2.ToString("o", CultureInfo.InvariantCulture) |> equal "14:02:00.0000000"
 
-    // t1.ToString("O", CultureInfo.InvariantCulture) |> equal "05:00:02.0220000"
-    // t2.ToString("O", CultureInfo.InvariantCulture) |> equal "14:02:00.0000000"
+    t1.ToString("O", CultureInfo.InvariantCulture) |> equal "05:00:02.0220000"
+    t2.ToString("O", CultureInfo.InvariantCulture) |> equal "14:02:00.0000000"
 
     t1.ToString("t", CultureInfo.InvariantCulture) |> equal "05:00"
     t2.ToString("t", CultureInfo.InvariantCulture) |> equal "14:02"
@@ -186,20 +206,23 @@ let ``ToString works`` () =
     t1.ToString("T", CultureInfo.InvariantCulture) |> equal "05:00:02"
     t2.ToString("T", CultureInfo.InvariantCulture) |> equal "14:02:00"
 
+    t1.ToString("HH'h'mm", CultureInfo.InvariantCulture) |> equal "05h00"
+    t1.ToString("hh:mm:ss.fff tt", CultureInfo.InvariantCulture) |> equal "05:00:02.022 AM"
+
 [<Fact>]
 let ``Parse parses valid TimeOnly`` () =
     equal (TimeOnly (23, 0)) (TimeOnly.Parse "23:00:00")
-    // equal (TimeOnly (23, 0)) (TimeOnly.Parse "23:00")
-    // equal (TimeOnly (3, 0)) (TimeOnly.Parse "3:00")
-    // equal (TimeOnly (0, 0, 5)) (TimeOnly.Parse "00:0:5   ")
-    // equal TimeOnly.MinValue (TimeOnly.Parse "   0   :   0    : 0   ")
-    // equal (TimeOnly (0, 40, 5, 3)) (TimeOnly.Parse "00:40:5.003")
-    // equal (TimeOnly (0, 0, 5, 3)) (TimeOnly.Parse "00:0:5.003")
-    // equal (TimeOnly 50031000L) (TimeOnly.Parse "00:0:5.0031")
-    // equal (TimeOnly 50031321L) (TimeOnly.Parse "00:0:5.00313213213213")
-    // equal (TimeOnly (0, 0, 59, 30)) (TimeOnly.Parse "00:  0:  59.03")
-    // equal (TimeOnly (0, 3, 5, 300)) (TimeOnly.Parse "00 :03:5.3")
-    // equal (TimeOnly (2, 0, 5, 300)) (TimeOnly.Parse "  02:0:5.30")
+    equal (TimeOnly (23, 0)) (TimeOnly.Parse "23:00")
+    equal (TimeOnly (3, 0)) (TimeOnly.Parse "3:00")
+    equal (TimeOnly (0, 0, 5)) (TimeOnly.Parse "00:0:5   ")
+    equal TimeOnly.MinValue (TimeOnly.Parse "   0   :   0    : 0   ")
+    equal (TimeOnly (0, 40, 5, 3)) (TimeOnly.Parse "00:40:5.003")
+    equal (TimeOnly (0, 0, 5, 3)) (TimeOnly.Parse "00:0:5.003")
+    equal (TimeOnly 50031000L) (TimeOnly.Parse "00:0:5.0031")
+    equal (TimeOnly 50031321L) (TimeOnly.Parse "00:0:5.00313213213213")
+    equal (TimeOnly (0, 0, 59, 30)) (TimeOnly.Parse "00:  0:  59.03")
+    equal (TimeOnly (0, 3, 5, 300)) (TimeOnly.Parse "00 :03:5.3")
+    equal (TimeOnly (2, 0, 5, 300)) (TimeOnly.Parse "  02:0:5.30")
 
 [<Fact>]
 let ``TryParse returns false for invalid TimeOnly`` () =
@@ -211,7 +234,7 @@ let ``TryParse returns false for invalid TimeOnly`` () =
     test "24:00"
     test "22:60"
     test "002:10"
-    // test "22:50:60" // TODO:
+    test "22:50:60"
     test "-04:00"
     // test "02:00:00,333" // not invalid in NET8_0
     test "02:00:00:33"
@@ -224,17 +247,31 @@ let ``TryParse returns true and correct TimeOnly for valid TimeOnly`` () =
         t |> equal expected
 
     test (TimeOnly (23, 0)) "23:00:00"
-    // test (TimeOnly (23, 0)) "23:00"
-    // test (TimeOnly (3, 0)) "3:00"
-    // test (TimeOnly (0, 0, 5)) "00:0:5"
-    // test (TimeOnly.MinValue) "0:0:0"
-    // test (TimeOnly (0, 40, 5, 3)) "00:40:5.003"
-    // test (TimeOnly (0, 0, 59, 30)) "00:0:59.03"
-    // test (TimeOnly (0, 3, 5, 300)) "00:03:5.3"
-    // test (TimeOnly (2, 0, 5, 300)) "02:0:5.30"
-    // test (TimeOnly (0, 0, 5, 3)) "00:0:5.003"
-    // test (TimeOnly 50031000L) "00:0:5.0031"
-    // test (TimeOnly 50031321L) "00:0:5.00313213213213"
+    test (TimeOnly (23, 0)) "23:00"
+    test (TimeOnly (3, 0)) "3:00"
+    test (TimeOnly (0, 0, 5)) "00:0:5"
+    test (TimeOnly.MinValue) "0:0:0"
+    test (TimeOnly (0, 40, 5, 3)) "00:40:5.003"
+    test (TimeOnly (0, 0, 59, 30)) "00:0:59.03"
+    test (TimeOnly (0, 3, 5, 300)) "00:03:5.3"
+    test (TimeOnly (2, 0, 5, 300)) "02:0:5.30"
+    test (TimeOnly (0, 0, 5, 3)) "00:0:5.003"
+    test (TimeOnly 50031000L) "00:0:5.0031"
+    test (TimeOnly 50031321L) "00:0:5.00313213213213"
+
+[<Fact>]
+let ``ParseExact works`` () =
+    TimeOnly.ParseExact("10h30", "HH'h'mm", CultureInfo.InvariantCulture)
+    |> equal (TimeOnly (10, 30))
+    TimeOnly.ParseExact("10:30:15.5000000", "o", CultureInfo.InvariantCulture)
+    |> equal (TimeOnly (10, 30, 15, 500))
+    let isValid, _ = TimeOnly.TryParseExact("10:30", "HH:mm:ss", CultureInfo.InvariantCulture, DateTimeStyles.None)
+    isValid |> equal false
+
+[<Fact>]
+let ``Parse with a date throws`` () =
+    (fun () -> TimeOnly.Parse("2024-01-05 10:00", CultureInfo.InvariantCulture))
+    |> throwsErrorContaining "not specific to the TimeOnly"
 
 [<Fact>]
 let ``Comparison works`` () =
@@ -34,24 +34,41 @@ let ``TimeSpan.ToString(\"c\", CultureInfo.InvariantCulture) works`` () =
     TimeSpan.FromDays(18.).ToString("c", CultureInfo.InvariantCulture) |> equal "18.00:00:00"
     TimeSpan.FromMilliseconds(25.).ToString("c", CultureInfo.InvariantCulture) |> equal "00:00:00.0250000"
 
-// [<Fact>]
-// let ``TimeSpan.ToString(\"g\", CultureInfo.InvariantCulture) works`` () =
-//     TimeSpan(0L).ToString("g", CultureInfo.InvariantCulture) |> equal "0:00:00"
-//     TimeSpan.FromSeconds(12345.).ToString("g", CultureInfo.InvariantCulture) |> equal "3:25:45"
-//     TimeSpan.FromDays(18.).ToString("g", CultureInfo.InvariantCulture) |> equal "18:0:00:00"
-//     TimeSpan.FromMilliseconds(25.).ToString("g", CultureInfo.InvariantCulture) |> equal "0:00:00.025"
+[<Fact>]
+let ``TimeSpan.ToString(\"g\", CultureInfo.InvariantCulture) works`` () =
+    TimeSpan(0L).ToString("g", CultureInfo.InvariantCulture) |> equal "0:00:00"
+    TimeSpan.FromSeconds(12345.).ToString("g", CultureInfo.InvariantCulture) |> equal "3:25:45"
+    TimeSpan.FromDays(18.).ToString("g", CultureInfo.InvariantCulture) |> equal "18:0:00:00"
+    TimeSpan.FromMilliseconds(25.).ToString("g", CultureInfo.InvariantCulture) |> equal "0:00:00.025"
 
-// [<Fact>]
-// let ``TimeSpan.ToString(\"G\", CultureInfo.InvariantCulture) works`` () =
-//     TimeSpan(0L).ToString("G", CultureInfo.InvariantCulture) |> equal "0:00:00:00.0000000"
-//     TimeSpan.FromSeconds(12345.).ToString("G", CultureInfo.InvariantCulture) |> equal "0:03:25:45.0000000"
-//     TimeSpan.FromDays(18.).ToString("G", CultureInfo.InvariantCulture) |> equal "18:00:00:00.0000000"
-//     TimeSpan.FromMilliseconds(25.).ToString("G", CultureInfo.InvariantCulture) |> equal "0:00:00:00.0250000"
+[<Fact>]
+let ``TimeSpan.ToString(\"G\", CultureInfo.InvariantCulture) works`` () =
+    TimeSpan(0L).ToString("G", CultureInfo.InvariantCulture) |> equal "0:00:00:00.0000000"
+    TimeSpan.FromSeconds(12345.).ToString("G", CultureInfo.InvariantCulture) |> equal "0:03:25:45.0000000"
+    TimeSpan.FromDays(18.).ToString("G", CultureInfo.InvariantCulture) |> equal "18:00:00:00.0000000"
+    TimeSpan.FromMilliseconds(25.).ToString("G", CultureInfo.InvariantCulture) |> equal "0:00:00:00.0250000"
 
-// [<Fact>]
-// let ``TimeSpan.ToString with custom format works`` () =
-//     TimeSpan.FromMinutes(234.).ToString("hh\:mm\:ss")
-//     |> equal "03:54:00"
+[<Fact>]
+let ``TimeSpan.ToString with custom format works`` () =
+    TimeSpan.FromMinutes(234.).ToString("hh\:mm\:ss")
+    |> equal "03:54:00"
+
+[<Fact>]
+let ``TimeSpan.ToString with custom format specifiers works`` () =
+    let ts = TimeSpan(1, 2, 3, 4, 50)
+    ts.ToString(@"d\.hh\:mm\:ss\.fff") |> equal "1.02:03:04.050"
+    ts.ToString("hh':'mm") |> equal "02:03"
+    ts.Negate().ToString(@"hh\:mm") |> equal "02:03"
+    ts.ToString("%h") |> equal "2"
+    ts.ToString(@"dddd\ FFFFF") |> equal "0001 05"
+    TimeSpan(0, 1, 2).ToString(@"ss\.FFF") |> equal "02."
+    (fun () -> ts.ToString("hh:mm")) |> throwsErrorContaining "Input string was not in a correct format"
+    (fun () -> ts.ToString("h")) |> throwsErrorContaining "Input string was not in a correct format"
+
+[<Fact>]
+let ``TimeSpan.ToString(\"g\") uses the culture decimal separator`` () =
+    TimeSpan(1, 2, 3, 4, 500).ToString("g", CultureInfo("fr-FR")) |> equal "1:2:03:04,5"
+    TimeSpan(1, 2, 3, 4, 500).ToString("G", CultureInfo("fr-FR")) |> equal "1:02:03:04,5000000"
 
 [<Fact>]
 let ``TimeSpan constructors work`` () =
@@ -202,6 +219,27 @@ let ``TimeSpan Division works`` () =
     test_float -3000. 1.5 -2000.
     test_float 0. 1000. 0.
 
+[<Fact>]
+let ``TimeSpan multiplication by a float on the left works`` () =
+    let t = TimeSpan(1, 0, 0)
+    1.5 * t |> equal (TimeSpan(1, 30, 0))
+    t * 1.5 |> equal (1.5 * t)
+
+[<Fact>]
+let ``TimeSpan multiplication and division round ticks to even`` () =
+    TimeSpan(5L) * 0.5 |> equal (TimeSpan(2L))
+    TimeSpan(5L) * 0.7 |> equal (TimeSpan(4L))
+    TimeSpan(7L) / 2. |> equal (TimeSpan(4L))
+
+[<Fact>]
+let ``TimeSpan multiplication and division overflow throws`` () =
+    (fun () -> TimeSpan.MaxValue * 2.)
+    |> throwsErrorContaining "TimeSpan overflowed because the duration is too long."
+    (fun () -> TimeSpan(1L) / 0.)
+    |> throwsErrorContaining "TimeSpan overflowed because the duration is too long."
+    (fun () -> TimeSpan(1L) * nan)
+    |> throwsErrorContaining "TimeSpan does not accept floating point Not-a-Number values."
+
 [<Fact>]
 let ``TimeSpan Comparison works`` () =
     let test ms1 ms2 expected =
@@ -545,6 +583,44 @@ let ``TimeSpan 10.12:00 TryParse works`` () =
     equal status true
     equal actual expected
 
+[<Fact>]
+let ``TimeSpan negative and days with colon parse works`` () =
+    TimeSpan.Parse(" -1.02:03 ") |> equal (TimeSpan(-1, -2, -3, 0))
+    TimeSpan.Parse("1:02:03:04.5") |> equal (TimeSpan(1, 2, 3, 4, 500))
+    TimeSpan.Parse("10675199.02:48:05.4775807") |> equal TimeSpan.MaxValue
+    TimeSpan.Parse("-10675199.02:48:05.4775808") |> equal TimeSpan.MinValue
+
+[<Fact>]
+let ``TimeSpan out of range components TryParse fails`` () =
+    let test (s: string) =
+        let status, _ = TimeSpan.TryParse(s)
+        equal false status
+
+    test "0:0:60"
+    test "0:0:0.12345678"
+    test "10675200"
+    test "10675199.02:48:05.4775808"
+
+[<Fact>]
+let ``TimeSpan out of range components parse fails`` () =
+    (fun () -> TimeSpan.Parse("0:60:0"))
+    |> throwsErrorContaining "at least one of the numeric components is out of range"
+
+[<Fact>]
+let ``TimeSpan.ParseExact with standard formats works`` () =
+    TimeSpan.ParseExact("1.02:03:04.5", "c", CultureInfo.InvariantCulture)
+    |> equal (TimeSpan(1, 2, 3, 4, 500))
+    TimeSpan.ParseExact("1:02:03:04.5", "g", CultureInfo.InvariantCulture)
+    |> equal (TimeSpan(1, 2, 3, 4, 500))
+    TimeSpan.ParseExact("0:02:03:04.0000000", "G", CultureInfo.InvariantCulture)
+    |> equal (TimeSpan(2, 3, 4))
+    TimeSpan.ParseExact("1:02:03:04.5", [| "c"; "g" |], CultureInfo.InvariantCulture)
+    |> equal (TimeSpan(1, 2, 3, 4, 500))
+    let status, _ = TimeSpan.TryParseExact("02:03", "c", CultureInfo.InvariantCulture)
+    equal false status
+    let status, _ = TimeSpan.TryParseExact("2:03:04", "G", CultureInfo.InvariantCulture)
+    equal false status
+
 [<Fact>]
 let ``TimeSpan 00:00:00.1 Parse handle correctly the milliseconds`` () =
     let actual = TimeSpan.Parse("00:00:00.1").TotalMilliseconds
@@ -689,3 +765,14 @@ let ``TimeSpan.Days works with negative TimeSpan`` () =
     let actual = TimeSpan.Parse("-1.23:45:06.78999").Days
     let expected = -1
     equal actual expected
+
+[<Fact>]
+let ``TimeSpan can be used as map key and in sets`` () =
+    let m = [ TimeSpan.FromMinutes(90.), "a" ] |> Map.ofList
+    Map.tryFind (TimeSpan(1, 30, 0)) m |> equal (Some "a")
+    let s = Set.ofList [ TimeSpan.FromHours(1.); TimeSpan.FromHours(-1.); TimeSpan(1, 0, 0) ]
+    s.Count |> equal 2
+    Set.minElement s |> equal (TimeSpan.FromHours(-1.))
+    [ TimeSpan.FromSeconds(3.); TimeSpan.Zero; TimeSpan.FromSeconds(-3.) ]
+    |> List.sort
+    |> equal [ TimeSpan.FromSeconds(-3.); TimeSpan.Zero; TimeSpan.FromSeconds(3.) ]
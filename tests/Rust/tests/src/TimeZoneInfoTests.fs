@@ -0,0 +1,68 @@
+module Fable.Tests.TimeZoneInfoTests
+
+open System
+open Util.Testing
+
+// IANA time zones other than Local and UTC need the `timezones` feature
+
+[<Fact>]
+let ``TimeZoneInfo.Utc works`` () =
+    let utc = TimeZoneInfo.Utc
+    utc.Id |> equal "UTC"
+    utc.BaseUtcOffset |> equal TimeSpan.Zero
+    utc.SupportsDaylightSavingTime |> equal false
+    TimeZoneInfo.FindSystemTimeZoneById("UTC").Id |> equal "UTC"
+
+[<Fact>]
+let ``TimeZoneInfo.ConvertTimeFromUtc works`` () =
+    let d = DateTime(2024, 7, 1, 12, 0, 0, DateTimeKind.Utc)
+    let local = TimeZoneInfo.ConvertTimeFromUtc(d, TimeZoneInfo.Local)
+    local.Kind |> equal DateTimeKind.Local
+    local |> equal (d.ToLocalTime())
+    let utc = TimeZoneInfo.ConvertTimeFromUtc(d, TimeZoneInfo.Utc)
+    utc.Kind |> equal DateTimeKind.Utc
+    utc |> equal d
+
+[<Fact>]
+let ``TimeZoneInfo.ConvertTime works`` () =
+    let d = DateTime(2024, 1, 15, 9, 30, 0, DateTimeKind.Local)
+    let utc = TimeZoneInfo.ConvertTime(d, TimeZoneInfo.Utc)
+    utc.Kind |> equal DateTimeKind.Utc
+    utc |> equal (d.ToUniversalTime())
+    TimeZoneInfo.ConvertTime(utc, TimeZoneInfo.Local) |> equal d
+    TimeZoneInfo.ConvertTimeToUtc(d) |> equal utc
+    TimeZoneInfo.ConvertTime(utc, TimeZoneInfo.Utc, TimeZoneInfo.Local) |> equal d
+
+[<Fact>]
+let ``TimeZoneInfo.GetUtcOffset works`` () =
+    let d = DateTime(2024, 1, 15, 12, 0, 0, DateTimeKind.Utc)
+    TimeZoneInfo.Utc.GetUtcOffset(d) |> equal TimeSpan.Zero
+    let local = d.ToLocalTime()
+    TimeZoneInfo.Local.GetUtcOffset(d) |> equal (local - d)
+
+[<Fact>]
+let ``TimeZoneInfo.ConvertTime with DateTimeOffset works`` () =
+    let dto = DateTimeOffset(2024, 1, 15, 12, 0, 0, TimeSpan.FromHours(2.))
+    let utc = TimeZoneInfo.ConvertTime(dto, TimeZoneInfo.Utc)
+    utc.Offset |> equal TimeSpan.Zero
+    utc.Hour |> equal 10
+    utc |> equal dto
+
+[<Fact>]
+let ``TimeZoneInfo.FindSystemTimeZoneById with unknown id throws`` () =
+    (fun () -> TimeZoneInfo.FindSystemTimeZoneById("Mars/Olympus_Mons"))
+    |> throwsErrorContaining "was not found on the local computer"
+
+[<Fact>]
+let ``TimeZoneInfo.ConvertTime checks the DateTime kind`` () =
+    let d = DateTime(2024, 1, 15, 12, 0, 0, DateTimeKind.Utc)
+    (fun () -> TimeZoneInfo.ConvertTime(d, TimeZoneInfo.Local, TimeZoneInfo.Utc))
+    |> throwsErrorContaining "did not have the Kind property set correctly"
+
+[<Fact>]
+let ``Local conversions work after TimeZoneInfo.ClearCachedData`` () =
+    let d = DateTime(2024, 1, 15, 12, 0, 0, DateTimeKind.Utc)
+    let local = d.ToLocalTime()
+    TimeZoneInfo.ClearCachedData()
+    d.ToLocalTime() |> equal local
+    local.ToUniversalTime() |> equal d
@@ -103,3 +103,34 @@ let ``Can create tuples of single element`` () =
     let t1b = Tuple.Create "b"
     t1.Item1 |> equal 4
     t1b.Item1 |> equal "b"
+
+[<Fact>]
+let ``Tuples with more than 7 items work`` () =
+    let t = (1, 2, 3, 4, 5, 6, 7, 8, 9)
+    let (a, _, _, _, _, _, g, h, i) = t
+    a + g + h + i |> equal 25
+    t |> equal (1, 2, 3, 4, 5, 6, 7, 8, 9)
+    compare t (1, 2, 3, 4, 5, 6, 7, 8, 10) |> equal -1
+
+[<Fact>]
+let ``Tuples with more than 12 items work`` () =
+    let t = struct (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16)
+    let struct (a, _, _, _, _, _, _, _, _, _, _, _, _, _, o, p) = t
+    a + o + p |> equal 32
+    t |> equal (struct (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16))
+
+[<Fact>]
+let ``Tuple Rest works`` () =
+    let t = Tuple.Create(1, 2, 3, 4, 5, 6, 7, 8)
+    t.Item7 |> equal 7
+    t.Rest.Item1 |> equal 8
+    let v = ValueTuple<_, _, _, _, _, _, _, _>(1, 2, 3, 4, 5, 6, 7, struct (8, 9))
+    v |> equal (struct (1, 2, 3, 4, 5, 6, 7, 8, 9))
+
+[<Fact>]
+let ``ITuple works`` () =
+    let t = ("a", 2, 3.0) :> System.Runtime.CompilerServices.ITuple
+    t.Length |> equal 3
+    t.[1] |> unbox<int> |> equal 2
+    let mutable idx = 0
+    t.[idx] |> unbox<string> |> equal "a"
@@ -0,0 +1,104 @@
+module Fable.Tests.Utf8TextTests
+
+open Util.Testing
+open System
+open System.Buffers
+open System.Buffers.Text
+
+let private utf8 (bytes: byte[]) (count: int) =
+    Text.Encoding.UTF8.GetString(bytes, 0, count)
+
+[<Fact>]
+let ``StandardFormat works`` () =
+    let f = StandardFormat('X', 4uy)
+    f.Symbol |> equal 'X'
+    f.Precision |> equal 4uy
+    f.HasPrecision |> equal true
+    f.ToString() |> equal "X4"
+    StandardFormat.Parse("N2").Precision |> equal 2uy
+    StandardFormat().IsDefault |> equal true
+
+[<Fact>]
+let ``Utf8Formatter.TryFormat works with integers`` () =
+    let buffer = Array.zeroCreate<byte> 32
+    let mutable written = 0
+    Utf8Formatter.TryFormat(-12345, Span buffer, &written) |> equal true
+    utf8 buffer written |> equal "-12345"
+    Utf8Formatter.TryFormat(255, Span buffer, &written, StandardFormat('X', 4uy)) |> equal true
+    utf8 buffer written |> equal "00FF"
+    Utf8Formatter.TryFormat(1234567, Span buffer, &written, StandardFormat('N', 0uy)) |> equal true
+    utf8 buffer written |> equal "1,234,567"
+
+[<Fact>]
+let ``Utf8Formatter.TryFormat fails when destination is too small`` () =
+    let buffer = Array.zeroCreate<byte> 2
+    let mutable written = 0
+    Utf8Formatter.TryFormat(12345, Span buffer, &written) |> equal false
+    written |> equal 0
+
+[<Fact>]
+let ``Utf8Formatter.TryFormat works with floats`` () =
+    let buffer = Array.zeroCreate<byte> 32
+    let mutable written = 0
+    Utf8Formatter.TryFormat(1.5, Span buffer, &written) |> equal true
+    utf8 buffer written |> equal "1.5"
+    Utf8Formatter.TryFormat(3.14159, Span buffer, &written, StandardFormat('F', 2uy)) |> equal true
+    utf8 buffer written |> equal "3.14"
+
+[<Fact>]
+let ``Utf8Formatter.TryFormat works with Guid and TimeSpan`` () =
+    let buffer = Array.zeroCreate<byte> 64
+    let mutable written = 0
+    let g = Guid.Parse("96258006-c4ba-4a7f-80c4-de7f2b2898c5")
+    Utf8Formatter.TryFormat(g, Span buffer, &written, StandardFormat('N')) |> equal true
+    utf8 buffer written |> equal "96258006c4ba4a7f80c4de7f2b2898c5"
+    Utf8Formatter.TryFormat(TimeSpan(1, 2, 3, 4), Span buffer, &written) |> equal true
+    utf8 buffer written |> equal "1.02:03:04"
+
+[<Fact>]
+let ``Utf8Parser.TryParse works with integers`` () =
+    let source = Text.Encoding.UTF8.GetBytes("-42 rest")
+    let mutable value = 0
+    let mutable consumed = 0
+    Utf8Parser.TryParse(ReadOnlySpan source, &value, &consumed) |> equal true
+    value |> equal -42
+    consumed |> equal 3
+
+[<Fact>]
+let ``Utf8Parser.TryParse works with hex format`` () =
+    let source = Text.Encoding.UTF8.GetBytes("ff")
+    let mutable value = 0
+    let mutable consumed = 0
+    Utf8Parser.TryParse(ReadOnlySpan source, &value, &consumed, 'X') |> equal true
+    value |> equal 255
+    consumed |> equal 2
+
+[<Fact>]
+let ``Utf8Parser.TryParse works with floats`` () =
+    let source = Text.Encoding.UTF8.GetBytes("1.25e2")
+    let mutable value = 0.
+    let mutable consumed = 0
+    Utf8Parser.TryParse(ReadOnlySpan source, &value, &consumed) |> equal true
+    value |> equal 125.
+    consumed |> equal 6
+
+[<Fact>]
+let ``Utf8Parser.TryParse works with Guid and TimeSpan`` () =
+    let mutable g = Guid.Empty
+    let mutable ts = TimeSpan.Zero
+    let mutable consumed = 0
+    let source = Text.Encoding.UTF8.GetBytes("96258006-c4ba-4a7f-80c4-de7f2b2898c5")
+    Utf8Parser.TryParse(ReadOnlySpan source, &g, &consumed) |> equal true
+    g |> equal (Guid.Parse("96258006-c4ba-4a7f-80c4-de7f2b2898c5"))
+    consumed |> equal 36
+    let source = Text.Encoding.UTF8.GetBytes("1.02:03:04")
+    Utf8Parser.TryParse(ReadOnlySpan source, &ts, &consumed) |> equal true
+    ts |> equal (TimeSpan(1, 2, 3, 4))
+
+[<Fact>]
+let ``Utf8Parser.TryParse fails on invalid input`` () =
+    let source = Text.Encoding.UTF8.GetBytes("abc")
+    let mutable value = 0
+    let mutable consumed = 0
+    Utf8Parser.TryParse(ReadOnlySpan source, &value, &consumed) |> equal false
+    consumed |> equal 0